@@ -0,0 +1,2 @@
+-- Add down migration script here
+ALTER TABLE "recipes" DROP COLUMN owner_id;
@@ -0,0 +1,2 @@
+-- Add up migration script here
+ALTER TABLE "recipes" ADD COLUMN owner_id uuid REFERENCES "users" ("id") ON DELETE SET NULL;
@@ -0,0 +1,6 @@
+-- Add down migration script here
+DROP INDEX ingredients_diet_violations_idx;
+DROP INDEX ingredients_recipes_ingredient_id_idx;
+DROP INDEX recipes_search_vector_idx;
+ALTER TABLE "recipes" DROP COLUMN search_vector;
+DROP FUNCTION recipe_search_vector;
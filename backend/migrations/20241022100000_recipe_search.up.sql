@@ -0,0 +1,18 @@
+-- Add up migration script here
+-- `array_to_string` is only STABLE, so it can't be used in a generated column directly
+CREATE FUNCTION recipe_search_vector(name text, description text, steps text[])
+RETURNS tsvector
+LANGUAGE sql IMMUTABLE PARALLEL SAFE
+AS $$
+  SELECT
+    setweight(to_tsvector('english', coalesce(name, '')), 'A') ||
+    setweight(to_tsvector('english', coalesce(description, '')), 'B') ||
+    setweight(to_tsvector('english', coalesce(array_to_string(steps, ' '), '')), 'C')
+$$;
+
+ALTER TABLE "recipes" ADD COLUMN search_vector tsvector
+  GENERATED ALWAYS AS (recipe_search_vector(name, description, steps)) STORED;
+
+CREATE INDEX recipes_search_vector_idx ON "recipes" USING GIN (search_vector);
+CREATE INDEX ingredients_recipes_ingredient_id_idx ON "ingredients_recipes" (ingredient_id);
+CREATE INDEX ingredients_diet_violations_idx ON "ingredients" USING GIN (diet_violations);
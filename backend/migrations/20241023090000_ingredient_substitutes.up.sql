@@ -0,0 +1,13 @@
+-- Add up migration script here
+CREATE TABLE "ingredient_substitutes" (
+  "ingredient_id" uuid NOT NULL REFERENCES "ingredients" ("id") ON DELETE CASCADE,
+  "substitute_id" uuid NOT NULL REFERENCES "ingredients" ("id") ON DELETE CASCADE,
+  "ratio" double precision NOT NULL DEFAULT 1 CHECK ("ratio" > 0),
+  "note" text,
+  "created_at" TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT timezone('utc', now()),
+  "updated_at" TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT timezone('utc', now()),
+  PRIMARY KEY ("ingredient_id", "substitute_id"),
+  CHECK ("ingredient_id" <> "substitute_id")
+);
+
+CREATE INDEX ingredient_substitutes_substitute_id_idx ON "ingredient_substitutes" ("substitute_id");
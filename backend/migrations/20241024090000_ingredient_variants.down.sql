@@ -0,0 +1,6 @@
+-- Add down migration script here
+DROP INDEX IF EXISTS ingredients_parent_id_idx;
+
+ALTER TABLE "ingredients"
+DROP CONSTRAINT IF EXISTS "ingredients_parent_id_check",
+DROP COLUMN IF EXISTS "parent_id";
@@ -0,0 +1,6 @@
+-- Add up migration script here
+ALTER TABLE "ingredients"
+ADD COLUMN "parent_id" uuid REFERENCES "ingredients" ("id") ON DELETE SET NULL,
+ADD CONSTRAINT "ingredients_parent_id_check" CHECK ("parent_id" <> "id");
+
+CREATE INDEX ingredients_parent_id_idx ON "ingredients" ("parent_id");
@@ -0,0 +1,4 @@
+-- Add down migration script here
+DROP INDEX IF EXISTS outbox_pending_idx;
+
+DROP TABLE IF EXISTS "outbox";
@@ -0,0 +1,16 @@
+-- Add up migration script here
+CREATE TABLE "outbox" (
+  "id" uuid PRIMARY KEY,
+  "topic" text NOT NULL,
+  "key" text NOT NULL,
+  "payload" jsonb NOT NULL,
+  "attempts" integer NOT NULL DEFAULT 0,
+  "last_error" text,
+  "created_at" timestamptz NOT NULL DEFAULT now(),
+  "available_at" timestamptz NOT NULL DEFAULT now(),
+  "published_at" timestamptz
+);
+
+CREATE INDEX outbox_pending_idx ON "outbox" ("available_at")
+WHERE
+  "published_at" IS NULL;
@@ -0,0 +1,10 @@
+-- Add up migration script here
+CREATE TABLE "recipe_revisions" (
+  "recipe_id" uuid NOT NULL REFERENCES "recipes" ("id") ON DELETE CASCADE,
+  "number" integer NOT NULL,
+  "author_id" uuid,
+  "created_at" timestamptz NOT NULL DEFAULT now(),
+  "change" jsonb NOT NULL,
+  "snapshot" jsonb NOT NULL,
+  PRIMARY KEY ("recipe_id", "number")
+);
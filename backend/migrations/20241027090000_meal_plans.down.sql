@@ -0,0 +1,3 @@
+-- Add down migration script here
+DROP TABLE IF EXISTS "planned_meals";
+DROP TABLE IF EXISTS "meal_plans";
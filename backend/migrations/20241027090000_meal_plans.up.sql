@@ -0,0 +1,18 @@
+-- Add up migration script here
+CREATE TABLE "meal_plans" (
+  "id" uuid PRIMARY KEY,
+  "owner_id" uuid NOT NULL REFERENCES "users" ("id") ON DELETE CASCADE,
+  "starts_on" date NOT NULL,
+  "created_at" timestamptz NOT NULL DEFAULT now(),
+  "updated_at" timestamptz NOT NULL DEFAULT now(),
+  CONSTRAINT "meal_plans_owner_id_starts_on_key" UNIQUE ("owner_id", "starts_on")
+);
+
+CREATE TABLE "planned_meals" (
+  "meal_plan_id" uuid NOT NULL REFERENCES "meal_plans" ("id") ON DELETE CASCADE,
+  "date" date NOT NULL,
+  "slot" text NOT NULL,
+  "recipe_id" uuid NOT NULL REFERENCES "recipes" ("id") ON DELETE CASCADE,
+  "servings" integer NOT NULL CHECK ("servings" > 0),
+  PRIMARY KEY ("meal_plan_id", "date", "slot", "recipe_id")
+);
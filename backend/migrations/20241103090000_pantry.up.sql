@@ -0,0 +1,8 @@
+-- Add up migration script here
+CREATE TABLE "pantry_items" (
+  "owner_id" uuid NOT NULL REFERENCES "users" ("id") ON DELETE CASCADE,
+  "ingredient_id" uuid NOT NULL REFERENCES "ingredients" ("id") ON DELETE CASCADE,
+  "amount" json NOT NULL,
+  "expires_on" date,
+  PRIMARY KEY ("owner_id", "ingredient_id")
+);
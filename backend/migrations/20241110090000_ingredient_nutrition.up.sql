@@ -0,0 +1,13 @@
+-- Add up migration script here
+CREATE TABLE "ingredient_nutrition" (
+  "ingredient_id" uuid PRIMARY KEY REFERENCES "ingredients" ("id") ON DELETE CASCADE,
+  "energy_kcal" double precision NOT NULL CHECK ("energy_kcal" >= 0),
+  "protein" double precision NOT NULL CHECK ("protein" >= 0),
+  "fat" double precision NOT NULL CHECK ("fat" >= 0),
+  "carbohydrates" double precision NOT NULL CHECK ("carbohydrates" >= 0),
+  "fiber" double precision NOT NULL CHECK ("fiber" >= 0),
+  "sugar" double precision NOT NULL CHECK ("sugar" >= 0),
+  "salt" double precision NOT NULL CHECK ("salt" >= 0),
+  "density" double precision CHECK ("density" > 0),
+  "updated_at" TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT timezone('utc', now())
+);
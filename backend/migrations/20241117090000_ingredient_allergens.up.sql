@@ -0,0 +1,2 @@
+-- Add up migration script here
+ALTER TABLE "ingredients" ADD COLUMN "allergens" varchar[] NOT NULL DEFAULT '{}';
@@ -0,0 +1,4 @@
+-- Add down migration script here
+DROP INDEX IF EXISTS outbox_published_idx;
+
+DROP INDEX IF EXISTS outbox_pending_key_idx;
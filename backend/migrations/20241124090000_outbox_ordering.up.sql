@@ -0,0 +1,8 @@
+-- Add up migration script here
+CREATE INDEX outbox_pending_key_idx ON "outbox" ("key", "id")
+WHERE
+  "published_at" IS NULL;
+
+CREATE INDEX outbox_published_idx ON "outbox" ("published_at")
+WHERE
+  "published_at" IS NOT NULL;
@@ -0,0 +1,2 @@
+DELETE FROM ingredient_nutrition
+WHERE ingredient_id = $1;
@@ -0,0 +1,2 @@
+DELETE FROM ingredient_substitutes
+WHERE ingredient_id = $1 AND substitute_id = $2;
@@ -1,2 +1,2 @@
-SELECT id, name, description, diet_violations
+SELECT id, name, description, diet_violations, parent_id, allergens
 FROM ingredients;
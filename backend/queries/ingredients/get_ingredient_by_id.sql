@@ -1,3 +1,3 @@
-SELECT id, name, description, diet_violations
+SELECT id, name, description, diet_violations, parent_id, allergens
 FROM ingredients
 WHERE id = $1;
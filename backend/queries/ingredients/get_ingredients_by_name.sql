@@ -0,0 +1,3 @@
+SELECT id, name, description, diet_violations, parent_id, allergens
+FROM ingredients
+WHERE lower(name) = ANY($1);
@@ -0,0 +1,5 @@
+SELECT id, name, description, diet_violations, parent_id, allergens
+FROM ingredients
+WHERE $1::uuid IS NULL OR id < $1
+ORDER BY id DESC
+LIMIT $2;
@@ -0,0 +1,7 @@
+SELECT id, name, description, diet_violations, parent_id, allergens
+FROM ingredients
+WHERE
+    $1::uuid IS NULL
+    OR (name, id) > (SELECT c.name, c.id FROM ingredients AS c WHERE c.id = $1)
+ORDER BY name ASC, id ASC
+LIMIT $2;
@@ -0,0 +1,12 @@
+SELECT
+ingredient_id,
+energy_kcal,
+protein,
+fat,
+carbohydrates,
+fiber,
+sugar,
+salt,
+density
+FROM ingredient_nutrition
+WHERE ingredient_id = ANY($1);
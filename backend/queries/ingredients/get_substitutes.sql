@@ -0,0 +1,15 @@
+SELECT
+s.ingredient_id,
+s.ratio,
+s.note,
+i.id,
+i.name,
+i.description,
+i.diet_violations,
+i.parent_id,
+i.allergens
+FROM ingredient_substitutes AS s
+JOIN ingredients AS i
+    ON i.id = s.substitute_id
+WHERE s.ingredient_id = ANY($1)
+ORDER BY s.ingredient_id, i.name;
@@ -0,0 +1,19 @@
+WITH RECURSIVE variants AS (
+    SELECT id, name, description, diet_violations, parent_id, allergens
+    FROM ingredients
+    WHERE parent_id = $1
+    UNION
+    SELECT i.id, i.name, i.description, i.diet_violations, i.parent_id, i.allergens
+    FROM ingredients AS i
+    JOIN variants AS v
+        ON i.parent_id = v.id
+)
+SELECT
+id AS "id!",
+name AS "name!",
+description AS "description!",
+diet_violations AS "diet_violations!",
+parent_id,
+allergens AS "allergens!"
+FROM variants
+ORDER BY name ASC;
@@ -1,3 +1,3 @@
-INSERT INTO ingredients (id, name, description, diet_violations)
-VALUES ($1, $2, $3, $4)
-RETURNING id, name, description, diet_violations;
+INSERT INTO ingredients (id, name, description, diet_violations, parent_id, allergens)
+VALUES ($1, $2, $3, $4, $5, $6)
+RETURNING id, name, description, diet_violations, parent_id, allergens;
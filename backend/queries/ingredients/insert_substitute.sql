@@ -0,0 +1,2 @@
+INSERT INTO ingredient_substitutes (ingredient_id, substitute_id, ratio, note)
+VALUES ($1, $2, $3, $4);
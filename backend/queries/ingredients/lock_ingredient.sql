@@ -0,0 +1,4 @@
+SELECT id, name, description, diet_violations, parent_id, allergens
+FROM ingredients
+WHERE id = $1
+FOR UPDATE;
@@ -0,0 +1,23 @@
+INSERT INTO ingredient_nutrition (
+    ingredient_id,
+    energy_kcal,
+    protein,
+    fat,
+    carbohydrates,
+    fiber,
+    sugar,
+    salt,
+    density
+)
+VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+ON CONFLICT (ingredient_id) DO UPDATE
+SET
+    energy_kcal = excluded.energy_kcal,
+    protein = excluded.protein,
+    fat = excluded.fat,
+    carbohydrates = excluded.carbohydrates,
+    fiber = excluded.fiber,
+    sugar = excluded.sugar,
+    salt = excluded.salt,
+    density = excluded.density,
+    updated_at = timezone('utc', now());
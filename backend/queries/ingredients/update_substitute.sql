@@ -0,0 +1,6 @@
+UPDATE ingredient_substitutes
+SET
+    ratio = $3,
+    note = $4,
+    updated_at = timezone('utc', now())
+WHERE ingredient_id = $1 AND substitute_id = $2;
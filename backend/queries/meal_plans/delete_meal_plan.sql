@@ -0,0 +1,2 @@
+DELETE FROM meal_plans
+WHERE meal_plans.id = $1;
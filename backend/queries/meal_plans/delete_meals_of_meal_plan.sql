@@ -0,0 +1,2 @@
+DELETE FROM planned_meals
+WHERE planned_meals.meal_plan_id = $1;
@@ -0,0 +1,8 @@
+SELECT
+mp.id,
+mp.owner_id,
+mp.starts_on,
+mp.created_at,
+mp.updated_at
+FROM meal_plans AS mp
+WHERE mp.id = $1
@@ -0,0 +1,9 @@
+SELECT
+mp.id,
+mp.owner_id,
+mp.starts_on,
+mp.created_at,
+mp.updated_at
+FROM meal_plans AS mp
+WHERE mp.owner_id = $1
+ORDER BY mp.starts_on
@@ -0,0 +1,8 @@
+SELECT
+pm.meal_plan_id,
+pm.date,
+pm.slot,
+pm.recipe_id,
+pm.servings
+FROM planned_meals AS pm
+WHERE pm.meal_plan_id = ANY($1)
@@ -0,0 +1,3 @@
+INSERT INTO meal_plans (id, owner_id, starts_on)
+VALUES ($1, $2, $3)
+RETURNING id, owner_id, starts_on, created_at, updated_at;
@@ -0,0 +1,4 @@
+INSERT INTO planned_meals
+(meal_plan_id, date, slot, recipe_id, servings)
+VALUES
+($1, $2, $3, $4, $5);
@@ -0,0 +1,4 @@
+UPDATE meal_plans
+SET starts_on = $2, updated_at = now()
+WHERE id = $1
+RETURNING id, owner_id, starts_on, created_at, updated_at;
@@ -0,0 +1,37 @@
+UPDATE outbox
+SET
+  available_at = now() + make_interval(secs => $2)
+WHERE
+  id IN (
+    SELECT
+      pending.id
+    FROM
+      outbox AS pending
+    WHERE
+      pending.published_at IS NULL
+      AND pending.available_at <= now()
+      -- Messages about the same entity wait for the earlier ones which are being retried or published elsewhere
+      AND NOT EXISTS (
+        SELECT
+          1
+        FROM
+          outbox AS earlier
+        WHERE
+          earlier.key = pending.key
+          AND earlier.id < pending.id
+          AND earlier.published_at IS NULL
+          AND earlier.available_at > now()
+      )
+    ORDER BY
+      pending.id
+    LIMIT
+      $1
+    FOR UPDATE
+      SKIP LOCKED
+  )
+RETURNING
+  id,
+  topic,
+  key,
+  payload,
+  attempts
@@ -0,0 +1,3 @@
+DELETE FROM outbox
+WHERE
+  published_at <= now() - make_interval(secs => $1)
@@ -0,0 +1,4 @@
+INSERT INTO
+  outbox (id, topic, key, payload)
+VALUES
+  ($1, $2, $3, $4)
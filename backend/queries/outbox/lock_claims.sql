@@ -0,0 +1,2 @@
+SELECT
+  pg_advisory_xact_lock(hashtext('outbox_claims'))
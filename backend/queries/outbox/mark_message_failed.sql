@@ -0,0 +1,7 @@
+UPDATE outbox
+SET
+  attempts = attempts + 1,
+  last_error = $2,
+  available_at = now() + make_interval(secs => $3)
+WHERE
+  id = $1
@@ -0,0 +1,5 @@
+UPDATE outbox
+SET
+  published_at = now()
+WHERE
+  id = $1
@@ -0,0 +1,2 @@
+DELETE FROM pantry_items
+WHERE owner_id = $1 AND ingredient_id = $2;
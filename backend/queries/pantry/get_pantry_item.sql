@@ -0,0 +1,16 @@
+SELECT
+p.owner_id,
+p.amount,
+p.expires_on,
+(
+    i.id,
+    i.name,
+    i.description,
+    i.diet_violations,
+    i.parent_id,
+    i.allergens
+) as "ingredient!: IngredientModel"
+FROM pantry_items AS p
+JOIN ingredients AS i
+    ON i.id = p.ingredient_id
+WHERE p.owner_id = $1 AND p.ingredient_id = $2
@@ -0,0 +1,2 @@
+INSERT INTO pantry_items (owner_id, ingredient_id, amount, expires_on)
+VALUES ($1, $2, $3, $4);
@@ -0,0 +1,3 @@
+UPDATE pantry_items
+SET amount = $3, expires_on = $4
+WHERE owner_id = $1 AND ingredient_id = $2;
@@ -0,0 +1,3 @@
+SELECT COUNT(DISTINCT recipe_id) AS "count!"
+FROM ingredients_recipes
+WHERE ingredient_id = $1;
@@ -6,5 +6,6 @@ r.steps,
 r.time,
 r.servings,
 r.created_at,
-r.updated_at
+r.updated_at,
+r.owner_id
 FROM recipes AS r
@@ -7,7 +7,9 @@ ir.optional,
     i.id,
     i.name,
     i.description,
-    i.diet_violations
+    i.diet_violations,
+    i.parent_id,
+    i.allergens
 ) as "ingredient!: IngredientModel"
 FROM ingredients_recipes AS ir
 JOIN ingredients AS i
@@ -0,0 +1,3 @@
+SELECT max(rr.number) AS number
+FROM recipe_revisions AS rr
+WHERE rr.recipe_id = $1
@@ -0,0 +1,14 @@
+SELECT
+r.id,
+r.name,
+r.description,
+r.steps,
+r.time,
+r.servings,
+r.created_at,
+r.updated_at,
+r.owner_id
+FROM recipes AS r
+WHERE $1::uuid IS NULL OR r.id < $1
+ORDER BY r.id DESC
+LIMIT $2
@@ -0,0 +1,16 @@
+SELECT
+r.id,
+r.name,
+r.description,
+r.steps,
+r.time,
+r.servings,
+r.created_at,
+r.updated_at,
+r.owner_id
+FROM recipes AS r
+WHERE
+    $1::uuid IS NULL
+    OR (r.name, r.id) > (SELECT c.name, c.id FROM recipes AS c WHERE c.id = $1)
+ORDER BY r.name ASC, r.id ASC
+LIMIT $2
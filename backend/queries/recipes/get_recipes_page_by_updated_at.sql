@@ -0,0 +1,16 @@
+SELECT
+r.id,
+r.name,
+r.description,
+r.steps,
+r.time,
+r.servings,
+r.created_at,
+r.updated_at,
+r.owner_id
+FROM recipes AS r
+WHERE
+    $1::uuid IS NULL
+    OR (r.updated_at, r.id) < (SELECT c.updated_at, c.id FROM recipes AS c WHERE c.id = $1)
+ORDER BY r.updated_at DESC, r.id DESC
+LIMIT $2
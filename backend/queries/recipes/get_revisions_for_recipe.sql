@@ -0,0 +1,10 @@
+SELECT
+rr.recipe_id,
+rr.number,
+rr.author_id,
+rr.created_at,
+rr.change,
+rr.snapshot
+FROM recipe_revisions AS rr
+WHERE rr.recipe_id = $1
+ORDER BY rr.number ASC
@@ -0,0 +1,2 @@
+INSERT INTO recipe_revisions (recipe_id, number, author_id, created_at, change, snapshot)
+VALUES ($1, $2, $3, $4, $5, $6)
@@ -0,0 +1,4 @@
+SELECT r.updated_at
+FROM recipes AS r
+WHERE r.id = $1
+FOR UPDATE
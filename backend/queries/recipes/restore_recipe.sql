@@ -0,0 +1,9 @@
+UPDATE recipes
+SET
+name = $2,
+description = $3,
+steps = $4,
+time = $5,
+servings = $6,
+updated_at = timezone('utc', now())
+WHERE id = $1
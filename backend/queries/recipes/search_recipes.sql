@@ -0,0 +1,53 @@
+SELECT
+r.id,
+r.name,
+r.description,
+r.steps,
+r.time,
+r.servings,
+r.created_at,
+r.updated_at,
+r.owner_id
+FROM recipes AS r
+WHERE
+    ($1::text IS NULL OR r.search_vector @@ websearch_to_tsquery('english', $1))
+    AND (
+        cardinality($2::uuid[]) = 0
+        OR (
+            SELECT COUNT(DISTINCT f.root_id)
+            FROM ingredients_recipes AS ir
+            JOIN unnest($2::uuid[], $6::uuid[]) AS f (ingredient_id, root_id)
+                ON f.ingredient_id = ir.ingredient_id
+            WHERE ir.recipe_id = r.id
+        ) = (SELECT COUNT(DISTINCT root_id) FROM unnest($6::uuid[]) AS root_id)
+    )
+    AND NOT EXISTS (
+        SELECT 1
+        FROM ingredients_recipes AS ir
+        WHERE ir.recipe_id = r.id AND ir.ingredient_id = ANY($3::uuid[])
+    )
+    AND NOT EXISTS (
+        SELECT 1
+        FROM ingredients_recipes AS ir
+        JOIN ingredients AS i
+            ON i.id = ir.ingredient_id
+        WHERE ir.recipe_id = r.id AND i.diet_violations && $4::varchar[]
+    )
+    AND NOT EXISTS (
+        SELECT 1
+        FROM ingredients_recipes AS ir
+        JOIN ingredients AS i
+            ON i.id = ir.ingredient_id
+        WHERE ir.recipe_id = r.id AND i.allergens && $7::varchar[]
+    )
+    AND (
+        $5::bigint IS NULL
+        OR (
+            SELECT COALESCE(SUM((t.value->>'secs')::bigint), 0)
+            FROM json_each(r.time) AS t
+        ) <= $5
+    )
+ORDER BY
+    ts_rank(r.search_vector, websearch_to_tsquery('english', COALESCE($1, ''))) DESC,
+    r.id DESC
+LIMIT $8
@@ -0,0 +1,3 @@
+SELECT id, username, email, password_hash
+FROM users
+WHERE id = $1;
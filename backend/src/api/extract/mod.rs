@@ -1,3 +1,7 @@
 mod json;
+mod precondition;
+mod user;
 
 pub use json::*;
+pub use precondition::*;
+pub use user::*;
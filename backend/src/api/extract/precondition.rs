@@ -0,0 +1,82 @@
+use axum::{
+    async_trait,
+    extract::FromRequestParts,
+    http::{header, request::Parts},
+};
+use reqwest::StatusCode;
+
+use crate::api::errors::api::ApiError;
+
+/// An extractor for the `If-Match` header, rejecting requests which don't say which version they change.
+/// Holds the versions of the strong entity tags in the header, or nothing for `*`, which matches any version.
+/// Weak entity tags never match, as `If-Match` uses the strong comparison.
+pub struct IfMatch(pub Option<Vec<String>>);
+
+/// The value of the `ETag` header for the given version.
+pub fn etag(version: &str) -> String {
+    format!("\"{version}\"")
+}
+
+fn precondition_required() -> ApiError {
+    ApiError {
+        kind: "PRECONDITION_REQUIRED",
+        status: StatusCode::PRECONDITION_REQUIRED,
+        message: "The If-Match header needs to hold the ETag of the version you're changing"
+            .to_string(),
+    }
+}
+
+/// Parses a comma-separated list of entity tags, leaving out the weak ones.
+/// Returns `None` when the list is malformed.
+fn strong_entity_tags(value: &str) -> Option<Vec<String>> {
+    let mut tags = vec![];
+    let mut rest = value;
+
+    loop {
+        rest = rest.trim_start_matches([',', ' ', '\t']);
+        if rest.is_empty() {
+            return Some(tags);
+        }
+
+        let (weak, tag) = match rest.strip_prefix("W/") {
+            Some(tag) => (true, tag),
+            None => (false, rest),
+        };
+        // Entity tags can hold commas, so the list is split on the quotes instead
+        let (version, after) = tag.strip_prefix('"')?.split_once('"')?;
+        if !(after.is_empty() || after.starts_with([',', ' ', '\t'])) {
+            return None;
+        }
+
+        if !weak {
+            tags.push(version.to_string());
+        }
+        rest = after;
+    }
+}
+
+#[async_trait]
+impl<S: Send + Sync> FromRequestParts<S> for IfMatch {
+    type Rejection = ApiError;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let value = parts
+            .headers
+            .get(header::IF_MATCH)
+            .and_then(|value| value.to_str().ok())
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .ok_or_else(precondition_required)?;
+
+        if value == "*" {
+            return Ok(Self(None));
+        }
+
+        let versions = strong_entity_tags(value).ok_or_else(precondition_required)?;
+
+        Ok(Self(Some(versions)))
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,33 @@
+use super::strong_entity_tags;
+
+#[test]
+fn entity_tag_lists_are_parsed() {
+    let cases = [
+        (r#""1""#, Some(vec!["1"])),
+        (r#""1", "2""#, Some(vec!["1", "2"])),
+        (r#""1","2""#, Some(vec!["1", "2"])),
+        (r#""a,b", "c""#, Some(vec!["a,b", "c"])),
+        (r#""""#, Some(vec![""])),
+    ];
+
+    for (value, expected) in cases {
+        let expected = expected.map(|tags| tags.into_iter().map(String::from).collect());
+        assert_eq!(strong_entity_tags(value), expected, "{value}");
+    }
+}
+
+#[test]
+fn weak_entity_tags_are_left_out() {
+    assert_eq!(strong_entity_tags(r#"W/"1""#), Some(vec![]));
+    assert_eq!(
+        strong_entity_tags(r#"W/"1", "2""#),
+        Some(vec!["2".to_string()])
+    );
+}
+
+#[test]
+fn malformed_entity_tags_are_rejected() {
+    for value in ["1", r#""1"#, r#""1"2"#, r#"W/1"#, r#""1" x"#] {
+        assert_eq!(strong_entity_tags(value), None, "{value}");
+    }
+}
@@ -0,0 +1,68 @@
+use axum::{async_trait, extract::FromRequestParts, http::request::Parts};
+use reqwest::StatusCode;
+use tower_sessions::Session;
+use uuid::Uuid;
+
+use crate::{
+    api::{errors::api::ApiError, AppState},
+    domain::{
+        entities::user::User,
+        queries::users::get_by_id::{get_user_by_id, GetUserError},
+    },
+};
+
+/// The session key under which the ID of the logged in user is kept.
+pub const USER_ID_SESSION_KEY: &str = "user_id";
+
+/// An extractor that resolves the user attached to the current session, rejecting anonymous requests.
+pub struct CurrentUser(pub User);
+
+fn unauthorized() -> ApiError {
+    ApiError {
+        kind: "UNAUTHORIZED",
+        status: StatusCode::UNAUTHORIZED,
+        message: "You need to be logged in to do this".to_string(),
+    }
+}
+
+#[async_trait]
+impl FromRequestParts<AppState> for CurrentUser {
+    type Rejection = ApiError;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &AppState,
+    ) -> Result<Self, Self::Rejection> {
+        let session =
+            Session::from_request_parts(parts, state)
+                .await
+                .map_err(|(status, message)| ApiError {
+                    kind: "SESSION",
+                    status,
+                    message: message.to_string(),
+                })?;
+
+        let user_id: Uuid = session
+            .get(USER_ID_SESSION_KEY)
+            .await
+            .map_err(|e| ApiError {
+                kind: "SESSION",
+                status: StatusCode::INTERNAL_SERVER_ERROR,
+                message: e.to_string(),
+            })?
+            .ok_or_else(unauthorized)?;
+
+        let user = get_user_by_id(state.user_repository.clone(), &user_id)
+            .await
+            .map_err(|e| match e {
+                GetUserError::NotFound(_) => unauthorized(),
+                e => ApiError {
+                    kind: "INTERNAL",
+                    status: StatusCode::INTERNAL_SERVER_ERROR,
+                    message: e.to_string(),
+                },
+            })?;
+
+        Ok(Self(user))
+    }
+}
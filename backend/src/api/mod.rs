@@ -1,24 +1,48 @@
 mod errors;
 mod extract;
 mod middleware;
+mod openapi;
 mod routes;
 
 use std::sync::Arc;
 
 use crate::api::middleware::trace_extractor::OtelAxumLayer;
+use crate::configuration::MessagingSettings;
 use crate::domain::{
     repositories::{
         ingredients::{
             in_memory::InMemoryIngredientRepository, postgres::PostgresIngredientRepository,
             IngredientRepository, IngredientRepositoryService,
         },
+        meal_plans::{
+            in_memory::InMemoryMealPlanRepository, postgres::PostgresMealPlanRepository,
+            MealPlanRepository, MealPlanRepositoryService,
+        },
+        outbox::{
+            in_memory::InMemoryOutboxRepository, postgres::PostgresOutboxRepository,
+            OutboxRepository,
+        },
+        pantry::{
+            in_memory::InMemoryPantryRepository, postgres::PostgresPantryRepository,
+            PantryRepository, PantryRepositoryService,
+        },
         recipe::{
             in_memory::InMemoryRecipeRepository, postgres::PostgresRecipeRepository,
             RecipeRepository, RecipeRepositoryService,
         },
+        users::{
+            in_memory::InMemoryUserRepository, postgres::PostgresUserRepository, UserRepository,
+            UserRepositoryService,
+        },
     },
     services::message::{
-        kafka::KafkaMessageService, stub::StubMessageService, MessageService, MessageServiceImpl,
+        consumer::{
+            channel::channel, handlers::default_handlers, kafka::KafkaMessageConsumer,
+            MessageConsumer, MessageDispatcher, MessageHandlers,
+        },
+        kafka::KafkaMessageService,
+        relay::OutboxRelay,
+        MessageService, MessageServiceImpl,
     },
 };
 use axum::{
@@ -27,34 +51,108 @@ use axum::{
 };
 use axum_tracing_opentelemetry::middleware::OtelInResponseLayer;
 use color_eyre::Result;
+use secrecy::{ExposeSecret, Secret};
 use sqlx::PgPool;
+use tower_sessions::{
+    cookie::{time::Duration, Key},
+    service::SignedCookie,
+    Expiry, MemoryStore, SessionManagerLayer, SessionStore,
+};
+use tower_sessions_redis_store::{
+    fred::prelude::{ClientLike, RedisConfig, RedisPool},
+    RedisStore,
+};
 
-use self::routes::{ingredients::*, recipes::*};
+use self::openapi::openapi_route;
+use self::routes::{ingredients::*, meal_plans::*, pantry::*, recipes::*, users::*};
 
 pub struct App {
     router: Router,
+    relay: Option<OutboxRelay>,
+    consumer: Option<MessageDispatcher>,
 }
 
 #[derive(Clone)]
 pub struct AppState {
     pub ingredient_repository: IngredientRepositoryService,
     pub recipe_repository: RecipeRepositoryService,
+    pub user_repository: UserRepositoryService,
+    pub meal_plan_repository: MealPlanRepositoryService,
+    pub pantry_repository: PantryRepositoryService,
     pub message_service: MessageServiceImpl,
 }
 
 impl App {
     fn get_router() -> Router<AppState> {
-        Router::new()
+        let router = Router::new()
             .route("/ingredient", get(get_all_ingredients_route))
             .route("/ingredient", post(create_ingredient_route))
+            .route("/ingredient/bulk", post(import_ingredients_route))
+            .route("/ingredient/export", get(export_ingredients_route))
+            .route("/ingredient/tree", get(get_ingredient_tree_route))
             .route("/ingredient/:id", get(get_ingredient_by_id_route))
             .route("/ingredient/:id", put(update_ingredient_route))
             .route("/ingredient/:id", delete(delete_ingredient_route))
+            .route(
+                "/ingredient/:id/nutrition",
+                get(get_ingredient_nutrition_route),
+            )
+            .route(
+                "/ingredient/:id/nutrition",
+                put(set_ingredient_nutrition_route),
+            )
+            .route(
+                "/ingredient/:id/nutrition",
+                delete(delete_ingredient_nutrition_route),
+            )
+            .route(
+                "/ingredient/:id/substitutes",
+                get(get_ingredient_substitutes_route),
+            )
+            .route(
+                "/ingredient/:id/substitutes",
+                post(add_ingredient_substitute_route),
+            )
+            .route(
+                "/ingredient/:id/substitutes/:substitute_id",
+                put(update_ingredient_substitute_route),
+            )
+            .route(
+                "/ingredient/:id/substitutes/:substitute_id",
+                delete(delete_ingredient_substitute_route),
+            )
             .route("/recipe", get(get_all_recipes_route))
             .route("/recipe", post(create_recipe_route))
+            .route("/recipe/search", get(search_recipes_route))
+            .route("/recipe/cookable", get(get_cookable_recipes_route))
+            .route("/recipe/import/jsonld", post(import_jsonld_recipe_route))
+            .route(
+                "/recipe/import/cooklang",
+                post(import_cooklang_recipe_route),
+            )
             .route("/recipe/:id", get(get_recipe_by_id_route))
             .route("/recipe/:id", put(update_recipe_route))
             .route("/recipe/:id", delete(delete_recipe_route))
+            .route("/recipe/:id/scaled", get(get_scaled_recipe_route))
+            .route("/recipe/:id/export", get(export_recipe_route))
+            .route("/recipe/:id/nutrition", get(get_recipe_nutrition_route))
+            .route(
+                "/recipe/:id/substitutions",
+                get(suggest_substitutions_route),
+            )
+            .route("/recipe/:id/revisions", get(get_recipe_revisions_route))
+            .route(
+                "/recipe/:id/revisions/diff",
+                get(diff_recipe_revisions_route),
+            )
+            .route(
+                "/recipe/:id/revisions/:revision",
+                get(get_recipe_revision_route),
+            )
+            .route(
+                "/recipe/:id/revisions/:revision/revert",
+                post(revert_recipe_route),
+            )
             .route(
                 "/recipe/:id/ingredient",
                 post(add_ingredient_to_recipe_route),
@@ -67,37 +165,138 @@ impl App {
                 "/recipe/:recipe_id/ingredient/:ingredient_id",
                 put(update_ingredient_in_recipe_route),
             )
+            .route("/shopping-list", post(get_shopping_list_route))
+            .route("/meal-plan", get(get_all_meal_plans_route))
+            .route("/meal-plan", post(create_meal_plan_route))
+            .route("/meal-plan/:id", get(get_meal_plan_by_id_route))
+            .route("/meal-plan/:id", put(update_meal_plan_route))
+            .route("/meal-plan/:id", delete(delete_meal_plan_route))
+            .route(
+                "/meal-plan/:id/shopping-list",
+                get(get_meal_plan_shopping_list_route),
+            )
+            .route("/pantry", get(get_pantry_route))
+            .route("/pantry", post(add_pantry_item_route))
+            .route("/pantry/:ingredient_id", put(update_pantry_item_route))
+            .route("/pantry/:ingredient_id", delete(delete_pantry_item_route))
+            .route("/user/signup", post(signup_route))
+            .route("/user/login", post(login_route))
+            .route("/user/logout", post(logout_route))
+            .route("/user/me", get(me_route))
+            .route("/openapi.json", get(openapi_route));
+
+        #[cfg(feature = "swagger-ui")]
+        let router = router.merge(
+            utoipa_swagger_ui::SwaggerUi::new("/docs")
+                .config(utoipa_swagger_ui::Config::from("/openapi.json")),
+        );
+
+        router
             .layer(OtelInResponseLayer)
             .layer(OtelAxumLayer::default())
     }
 
-    pub fn new(
+    pub fn new<S: SessionStore + Clone>(
         irs: Arc<Box<dyn IngredientRepository>>,
         rrs: Arc<Box<dyn RecipeRepository>>,
+        urs: Arc<Box<dyn UserRepository>>,
+        mrs: Arc<Box<dyn MealPlanRepository>>,
+        prs: Arc<Box<dyn PantryRepository>>,
         ms: MessageServiceImpl,
+        sessions: SessionManagerLayer<S, SignedCookie>,
     ) -> Result<Self> {
         let state = AppState {
             ingredient_repository: irs,
             recipe_repository: rrs,
+            user_repository: urs,
+            meal_plan_repository: mrs,
+            pantry_repository: prs,
             message_service: ms,
         };
-        let router = Self::get_router().with_state(state);
+        let router = Self::get_router().layer(sessions).with_state(state);
+
+        Ok(App {
+            router,
+            relay: None,
+            consumer: None,
+        })
+    }
 
-        Ok(App { router })
+    /// Publishes the messages from the outbox in the background while the app is being served.
+    pub fn with_relay(mut self, relay: OutboxRelay) -> Self {
+        self.relay = Some(relay);
+        self
+    }
+
+    /// Handles the messages from the message bus in the background while the app is being served.
+    pub fn with_consumer(mut self, consumer: MessageDispatcher) -> Self {
+        self.consumer = Some(consumer);
+        self
     }
 
-    pub async fn serve(&self, listener: tokio::net::TcpListener) -> Result<()> {
+    /// Serves the app until the process is asked to stop.
+    /// Requests that are in flight by then get finished, and so does the message being consumed.
+    pub async fn serve(self, listener: tokio::net::TcpListener) -> Result<()> {
+        if let Some(relay) = self.relay {
+            relay.spawn();
+        }
+        let consumer = self.consumer.map(MessageDispatcher::spawn);
+
         let addr = listener.local_addr()?;
         log::info!("Serving on {}:{}", addr.ip(), addr.port());
-        axum::serve(listener, self.router.clone()).await?;
+        axum::serve(listener, self.router)
+            .with_graceful_shutdown(shutdown_signal())
+            .await?;
+
+        if let Some(consumer) = consumer {
+            consumer.shutdown().await?;
+        }
+
         Ok(())
     }
 }
 
+/// Resolves once the process gets either Ctrl+C or SIGTERM
+async fn shutdown_signal() {
+    let ctrl_c = async {
+        if let Err(e) = tokio::signal::ctrl_c().await {
+            tracing::error!("Could not listen for Ctrl+C: {}", e);
+            std::future::pending::<()>().await;
+        }
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        match tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate()) {
+            Ok(mut signal) => {
+                signal.recv().await;
+            }
+            Err(e) => {
+                tracing::error!("Could not listen for SIGTERM: {}", e);
+                std::future::pending::<()>().await;
+            }
+        }
+    };
+
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => {},
+        _ = terminate => {},
+    }
+
+    tracing::info!("Shutting down");
+}
+
 #[derive(Default, Clone)]
 pub struct AppBuilder {
     postgres_db: Option<PgPool>,
-    kafka: Option<String>,
+    messaging: Option<MessagingSettings>,
+    redis: Option<String>,
+    session_key: Option<Secret<String>>,
+    /// Shared by the in-memory repositories and the relay, so the relay sees what the repositories write
+    in_memory_outbox: InMemoryOutboxRepository,
 }
 
 impl AppBuilder {
@@ -107,8 +306,21 @@ impl AppBuilder {
         self
     }
 
-    pub fn with_kafka(mut self, address: &str) -> Self {
-        self.kafka = Some(address.to_string());
+    /// Uses Kafka for messaging, unless it's disabled in the settings
+    pub fn with_messaging(mut self, settings: MessagingSettings) -> Self {
+        self.messaging = Some(settings);
+
+        self
+    }
+
+    pub fn with_redis_session_store(mut self, connection_string: &str) -> Self {
+        self.redis = Some(connection_string.to_string());
+
+        self
+    }
+
+    pub fn with_session_key(mut self, key: Secret<String>) -> Self {
+        self.session_key = Some(key);
 
         self
     }
@@ -121,7 +333,7 @@ impl AppBuilder {
             tracing::warn!(
                 "You are using a debug service, please move to something that is actually working."
             );
-            Box::new(InMemoryIngredientRepository::new())
+            Box::new(InMemoryIngredientRepository::new().with_outbox(self.in_memory_outbox.clone()))
         }
     }
 
@@ -133,29 +345,135 @@ impl AppBuilder {
             tracing::warn!(
                 "You are using a debug service, please move to something that is actually working."
             );
-            Box::new(InMemoryRecipeRepository::new())
+            Box::new(InMemoryRecipeRepository::new().with_outbox(self.in_memory_outbox.clone()))
+        }
+    }
+
+    fn get_user_repository(&self) -> Box<dyn UserRepository> {
+        if let Some(postgres_db) = &self.postgres_db {
+            tracing::info!("Using Postgres for user database");
+            Box::new(PostgresUserRepository::new(postgres_db.clone()))
+        } else {
+            tracing::warn!(
+                "You are using a debug service, please move to something that is actually working."
+            );
+            Box::new(InMemoryUserRepository::new())
+        }
+    }
+
+    fn get_meal_plan_repository(&self) -> Box<dyn MealPlanRepository> {
+        if let Some(postgres_db) = &self.postgres_db {
+            tracing::info!("Using Postgres for meal plan database");
+            Box::new(PostgresMealPlanRepository::new(postgres_db.clone()))
+        } else {
+            tracing::warn!(
+                "You are using a debug service, please move to something that is actually working."
+            );
+            Box::new(InMemoryMealPlanRepository::new())
+        }
+    }
+
+    fn get_pantry_repository(&self) -> Box<dyn PantryRepository> {
+        if let Some(postgres_db) = &self.postgres_db {
+            tracing::info!("Using Postgres for pantry database");
+            Box::new(PostgresPantryRepository::new(postgres_db.clone()))
+        } else {
+            tracing::warn!(
+                "You are using a debug service, please move to something that is actually working."
+            );
+            Box::new(InMemoryPantryRepository::new())
+        }
+    }
+
+    fn get_outbox_repository(&self) -> Box<dyn OutboxRepository> {
+        if let Some(postgres_db) = &self.postgres_db {
+            tracing::info!("Using Postgres for the outbox");
+            Box::new(PostgresOutboxRepository::new(postgres_db.clone()))
+        } else {
+            Box::new(self.in_memory_outbox.clone())
         }
     }
 
-    fn get_message_service(&self) -> Result<Box<dyn MessageService>> {
-        if let Some(kafka_url) = &self.kafka {
+    fn get_session_key(&self) -> Result<Key> {
+        if let Some(key) = &self.session_key {
+            Ok(Key::try_from(key.expose_secret().as_bytes())?)
+        } else {
+            tracing::warn!("No session key was provided, generating a random one. Sessions will not survive a restart.");
+            Ok(Key::generate())
+        }
+    }
+
+    fn get_session_layer<S: SessionStore + Clone>(
+        &self,
+        store: S,
+    ) -> Result<SessionManagerLayer<S, SignedCookie>> {
+        Ok(SessionManagerLayer::new(store)
+            .with_expiry(Expiry::OnInactivity(Duration::days(7)))
+            .with_signed(self.get_session_key()?))
+    }
+
+    fn get_messaging(&self) -> Result<(Box<dyn MessageService>, Box<dyn MessageConsumer>)> {
+        if let Some(settings) = self.messaging.as_ref().filter(|s| s.enabled) {
             tracing::info!("Using Kafka messaging service");
-            Ok(Box::new(KafkaMessageService::new(kafka_url)?))
+            Ok((
+                Box::new(KafkaMessageService::new(settings)?),
+                Box::new(KafkaMessageConsumer::new(settings)?),
+            ))
         } else {
-            tracing::info!("Using a stub messaging service");
+            tracing::info!("Using an in-process messaging service");
             tracing::warn!(
                 "You are using a debug service, please move to something that is actually working."
             );
-            Ok(Box::new(StubMessageService))
+            let (service, consumer) = channel();
+            Ok((Box::new(service), Box::new(consumer)))
+        }
+    }
+
+    fn get_message_handlers(
+        &self,
+        irs: IngredientRepositoryService,
+        rrs: RecipeRepositoryService,
+    ) -> MessageHandlers {
+        if self.postgres_db.is_some() {
+            // The Postgres recipes read their ingredients from the ingredients table, so there's nothing to refresh
+            tracing::info!("Postgres keeps the recipes up to date, not consuming any messages");
+            MessageHandlers::new()
+        } else {
+            default_handlers(irs, rrs)
         }
     }
 
     pub fn build(self) -> Result<App> {
         let irs = Arc::new(self.get_ingredient_recipe_repository());
         let rrs = Arc::new(self.get_recipe_repository());
-        let ms = Arc::new(self.get_message_service()?);
+        let urs = Arc::new(self.get_user_repository());
+        let mrs = Arc::new(self.get_meal_plan_repository());
+        let prs = Arc::new(self.get_pantry_repository());
+        let (ms, consumer) = self.get_messaging()?;
+        let ms: MessageServiceImpl = Arc::new(ms);
+        let relay = OutboxRelay::new(Arc::new(self.get_outbox_repository()), ms.clone());
+        let handlers = self.get_message_handlers(irs.clone(), rrs.clone());
+        // Without handlers, subscribing would only add an idle member to the consumer group
+        let consumer = (!handlers.is_empty()).then(|| MessageDispatcher::new(consumer, handlers));
+
+        let app = if let Some(redis_url) = &self.redis {
+            tracing::info!("Using Redis for session storage");
+            let pool = RedisPool::new(RedisConfig::from_url(redis_url)?, None, None, None, 6)?;
+            pool.connect();
+            let sessions = self.get_session_layer(RedisStore::new(pool))?;
+            App::new(irs, rrs, urs, mrs, prs, ms, sessions)?.with_relay(relay)
+        } else {
+            tracing::warn!(
+                "You are using a debug service, please move to something that is actually working."
+            );
+            let sessions = self.get_session_layer(MemoryStore::default())?;
+            App::new(irs, rrs, urs, mrs, prs, ms, sessions)?.with_relay(relay)
+        };
 
-        App::new(irs, rrs, ms)
+        Ok(match consumer {
+            Some(consumer) => app.with_consumer(consumer),
+            None => app,
+        })
     }
 
     pub fn new() -> Self {
@@ -0,0 +1,164 @@
+use axum::{response::IntoResponse, Json};
+use common::{
+    error::ErrorMessageWithString,
+    user::{CreateNewUserDTO, LoginUserDTO, UserDataDTO},
+    AddPantryItemDTO, CookableRecipeDTO, CreateIngredientDTO, CreateIngredientSubstituteDTO,
+    CreateMealPlanDTO, CreateRecipeDTO, CreateShoppingListDTO, ImportedRecipeDTO,
+    IngredientAmountDTO, IngredientChangeDTO, IngredientDTO, IngredientImportReportDTO,
+    IngredientImportRowDTO, IngredientNutritionDTO, IngredientSubstituteDTO, IngredientTreeDTO,
+    IngredientUnitDTO, IngredientWithAmountDTO, MealPlanDTO, MealSlotDTO, NutritionFactsDTO,
+    PagedIngredientsDTO, PagedRecipesDTO, PantryItemDTO, PlannedMealDTO, RecipeDTO, RecipeDiffDTO,
+    RecipeNutritionDTO, RecipeRevisionDTO, RecipeRevisionDetailsDTO, RecipeSnapshotDTO,
+    RevisionChangeDTO, ServingsChangeDTO, ServingsTypeDTO, ShoppingListDTO, ShoppingListItemDTO,
+    ShoppingListRecipeDTO, SnapshotIngredientDTO, StepsChangeDTO, SubstitutionSuggestionDTO,
+    SuggestedSubstituteDTO, TextChangeDTO, TimeChangeDTO, UpdateIngredientDTO,
+    UpdateIngredientSubstituteDTO, UpdateMealPlanDTO, UpdatePantryItemDTO, UpdateRecipeDTO,
+};
+use utoipa::{
+    openapi::security::{ApiKey, ApiKeyValue, SecurityScheme},
+    Modify, OpenApi,
+};
+
+use super::routes::{ingredients, meal_plans, pantry, recipes, users};
+
+#[derive(OpenApi)]
+#[openapi(
+    info(title = "deepdi.sh", description = "Recipes, and the ingredients they're made of"),
+    paths(
+        ingredients::get_all_ingredients_route,
+        ingredients::create_ingredient_route,
+        ingredients::import_ingredients_route,
+        ingredients::export_ingredients_route,
+        ingredients::get_ingredient_tree_route,
+        ingredients::get_ingredient_by_id_route,
+        ingredients::update_ingredient_route,
+        ingredients::delete_ingredient_route,
+        ingredients::get_ingredient_nutrition_route,
+        ingredients::set_ingredient_nutrition_route,
+        ingredients::delete_ingredient_nutrition_route,
+        ingredients::get_ingredient_substitutes_route,
+        ingredients::add_ingredient_substitute_route,
+        ingredients::update_ingredient_substitute_route,
+        ingredients::delete_ingredient_substitute_route,
+        recipes::get_all_recipes_route,
+        recipes::create_recipe_route,
+        recipes::search_recipes_route,
+        recipes::get_cookable_recipes_route,
+        recipes::import_jsonld_recipe_route,
+        recipes::import_cooklang_recipe_route,
+        recipes::get_recipe_by_id_route,
+        recipes::update_recipe_route,
+        recipes::delete_recipe_route,
+        recipes::get_scaled_recipe_route,
+        recipes::export_recipe_route,
+        recipes::get_recipe_nutrition_route,
+        recipes::suggest_substitutions_route,
+        recipes::get_recipe_revisions_route,
+        recipes::diff_recipe_revisions_route,
+        recipes::get_recipe_revision_route,
+        recipes::revert_recipe_route,
+        recipes::add_ingredient_to_recipe_route,
+        recipes::delete_ingredient_from_recipe_route,
+        recipes::update_ingredient_in_recipe_route,
+        recipes::get_shopping_list_route,
+        meal_plans::get_all_meal_plans_route,
+        meal_plans::create_meal_plan_route,
+        meal_plans::get_meal_plan_by_id_route,
+        meal_plans::update_meal_plan_route,
+        meal_plans::delete_meal_plan_route,
+        meal_plans::get_meal_plan_shopping_list_route,
+        pantry::get_pantry_route,
+        pantry::add_pantry_item_route,
+        pantry::update_pantry_item_route,
+        pantry::delete_pantry_item_route,
+        users::signup_route,
+        users::login_route,
+        users::logout_route,
+        users::me_route,
+    ),
+    components(schemas(
+        ErrorMessageWithString,
+        CreateIngredientDTO,
+        IngredientDTO,
+        IngredientTreeDTO,
+        UpdateIngredientDTO,
+        IngredientSubstituteDTO,
+        CreateIngredientSubstituteDTO,
+        UpdateIngredientSubstituteDTO,
+        PagedIngredientsDTO,
+        IngredientImportRowDTO,
+        IngredientImportReportDTO,
+        CreateRecipeDTO,
+        RecipeDTO,
+        UpdateRecipeDTO,
+        ServingsTypeDTO,
+        IngredientAmountDTO,
+        IngredientWithAmountDTO,
+        IngredientUnitDTO,
+        SubstitutionSuggestionDTO,
+        SuggestedSubstituteDTO,
+        NutritionFactsDTO,
+        IngredientNutritionDTO,
+        RecipeNutritionDTO,
+        PagedRecipesDTO,
+        ImportedRecipeDTO,
+        RevisionChangeDTO,
+        RecipeRevisionDTO,
+        RecipeRevisionDetailsDTO,
+        RecipeSnapshotDTO,
+        SnapshotIngredientDTO,
+        RecipeDiffDTO,
+        TextChangeDTO,
+        StepsChangeDTO,
+        TimeChangeDTO,
+        ServingsChangeDTO,
+        IngredientChangeDTO,
+        CreateShoppingListDTO,
+        ShoppingListRecipeDTO,
+        ShoppingListDTO,
+        ShoppingListItemDTO,
+        MealSlotDTO,
+        PlannedMealDTO,
+        CreateMealPlanDTO,
+        UpdateMealPlanDTO,
+        MealPlanDTO,
+        PantryItemDTO,
+        AddPantryItemDTO,
+        UpdatePantryItemDTO,
+        CookableRecipeDTO,
+        CreateNewUserDTO,
+        LoginUserDTO,
+        UserDataDTO,
+    )),
+    modifiers(&SessionCookie),
+    tags(
+        (name = "ingredients"),
+        (name = "recipes"),
+        (name = "meal_plans", description = "Planning the meals of a week, and shopping for them"),
+        (name = "pantry", description = "The ingredients a user has at home, and what they are enough for"),
+        (name = "users", description = "Signing up and logging in, the session is kept in a cookie"),
+    )
+)]
+pub struct ApiDoc;
+
+/// The session cookie set when signing up or logging in
+struct SessionCookie;
+
+impl Modify for SessionCookie {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        if let Some(components) = openapi.components.as_mut() {
+            components.add_security_scheme(
+                "session",
+                SecurityScheme::ApiKey(ApiKey::Cookie(ApiKeyValue::new("id"))),
+            );
+        }
+    }
+}
+
+#[tracing::instrument("[ROUTE] Getting the OpenAPI document")]
+pub async fn openapi_route() -> impl IntoResponse {
+    Json(ApiDoc::openapi())
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,149 @@
+use serde_json::Value;
+use strum::VariantNames;
+use utoipa::OpenApi;
+
+use super::ApiDoc;
+use crate::domain::entities::{ingredient::IngredientSort, recipe::RecipeSort};
+
+fn collect_refs(value: &Value, refs: &mut Vec<String>) {
+    match value {
+        Value::Object(map) => {
+            if let Some(Value::String(reference)) = map.get("$ref") {
+                refs.push(reference.clone());
+            }
+            map.values().for_each(|v| collect_refs(v, refs));
+        }
+        Value::Array(values) => values.iter().for_each(|v| collect_refs(v, refs)),
+        _ => {}
+    }
+}
+
+#[test]
+fn every_route_is_documented() {
+    // Keep in sync with `App::get_router`
+    let routes = [
+        ("get", "/ingredient"),
+        ("post", "/ingredient"),
+        ("post", "/ingredient/bulk"),
+        ("get", "/ingredient/export"),
+        ("get", "/ingredient/tree"),
+        ("get", "/ingredient/{id}"),
+        ("put", "/ingredient/{id}"),
+        ("delete", "/ingredient/{id}"),
+        ("get", "/ingredient/{id}/nutrition"),
+        ("put", "/ingredient/{id}/nutrition"),
+        ("delete", "/ingredient/{id}/nutrition"),
+        ("get", "/ingredient/{id}/substitutes"),
+        ("post", "/ingredient/{id}/substitutes"),
+        ("put", "/ingredient/{id}/substitutes/{substitute_id}"),
+        ("delete", "/ingredient/{id}/substitutes/{substitute_id}"),
+        ("get", "/recipe"),
+        ("post", "/recipe"),
+        ("get", "/recipe/search"),
+        ("get", "/recipe/cookable"),
+        ("post", "/recipe/import/jsonld"),
+        ("post", "/recipe/import/cooklang"),
+        ("get", "/recipe/{id}"),
+        ("put", "/recipe/{id}"),
+        ("delete", "/recipe/{id}"),
+        ("get", "/recipe/{id}/scaled"),
+        ("get", "/recipe/{id}/export"),
+        ("get", "/recipe/{id}/nutrition"),
+        ("get", "/recipe/{id}/substitutions"),
+        ("get", "/recipe/{id}/revisions"),
+        ("get", "/recipe/{id}/revisions/diff"),
+        ("get", "/recipe/{id}/revisions/{revision}"),
+        ("post", "/recipe/{id}/revisions/{revision}/revert"),
+        ("post", "/recipe/{id}/ingredient"),
+        ("delete", "/recipe/{recipe_id}/ingredient/{ingredient_id}"),
+        ("put", "/recipe/{recipe_id}/ingredient/{ingredient_id}"),
+        ("post", "/shopping-list"),
+        ("get", "/meal-plan"),
+        ("post", "/meal-plan"),
+        ("get", "/meal-plan/{id}"),
+        ("put", "/meal-plan/{id}"),
+        ("delete", "/meal-plan/{id}"),
+        ("get", "/meal-plan/{id}/shopping-list"),
+        ("get", "/pantry"),
+        ("post", "/pantry"),
+        ("put", "/pantry/{ingredient_id}"),
+        ("delete", "/pantry/{ingredient_id}"),
+        ("post", "/user/signup"),
+        ("post", "/user/login"),
+        ("post", "/user/logout"),
+        ("get", "/user/me"),
+    ];
+
+    let document = serde_json::to_value(ApiDoc::openapi()).unwrap();
+    let paths = document["paths"].as_object().unwrap();
+
+    for (method, path) in routes {
+        assert!(
+            paths.get(path).and_then(|p| p.get(method)).is_some(),
+            "{} {} is not documented",
+            method.to_uppercase(),
+            path
+        );
+    }
+
+    let documented: usize = paths.values().map(|p| p.as_object().unwrap().len()).sum();
+    assert_eq!(documented, routes.len());
+}
+
+#[test]
+fn every_referenced_schema_exists() {
+    let document = serde_json::to_value(ApiDoc::openapi()).unwrap();
+    let mut refs = vec![];
+    collect_refs(&document, &mut refs);
+
+    assert!(!refs.is_empty());
+    for reference in refs {
+        let name = reference.trim_start_matches("#/components/schemas/");
+        assert!(
+            document["components"]["schemas"].get(name).is_some(),
+            "{} is referenced, but is not one of the schemas",
+            reference
+        );
+    }
+}
+
+#[test]
+fn authenticated_routes_need_a_session() {
+    let document = serde_json::to_value(ApiDoc::openapi()).unwrap();
+
+    let me = &document["paths"]["/user/me"]["get"];
+    assert_eq!(me["security"][0]["session"], serde_json::json!([]));
+    assert!(me["responses"].get("401").is_some());
+    assert_eq!(
+        document["components"]["securitySchemes"]["session"]["in"],
+        "cookie"
+    );
+}
+
+#[test]
+fn listings_document_their_own_sort_options() {
+    let document = serde_json::to_value(ApiDoc::openapi()).unwrap();
+
+    for (path, options) in [
+        ("/ingredient", IngredientSort::VARIANTS),
+        ("/recipe", RecipeSort::VARIANTS),
+    ] {
+        let parameters = document["paths"][path]["get"]["parameters"]
+            .as_array()
+            .unwrap();
+        let sort: Vec<_> = parameters.iter().filter(|p| p["name"] == "sort").collect();
+        assert_eq!(sort.len(), 1, "GET {} documents sort more than once", path);
+
+        let description = sort[0]["description"].as_str().unwrap();
+        for option in options {
+            assert!(
+                description.contains(&format!("`{option}`")),
+                "GET {} doesn't document sorting by {}",
+                path,
+                option
+            );
+        }
+        // Nothing but the options is quoted, so there's no option which doesn't exist
+        assert_eq!(description.matches('`').count(), 2 * options.len());
+    }
+}
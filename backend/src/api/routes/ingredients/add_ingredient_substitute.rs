@@ -0,0 +1,67 @@
+use axum::{
+    extract::{Path, State},
+    response::IntoResponse,
+};
+use common::{CreateIngredientSubstituteDTO, IngredientSubstituteDTO};
+use reqwest::StatusCode;
+use uuid::Uuid;
+
+use crate::{
+    api::{errors::MakeError, extract::Json, AppState},
+    domain::commands::ingredients::substitutes::add::{add_substitute, AddSubstituteError},
+};
+
+impl MakeError<String> for AddSubstituteError {
+    fn get_kind(&self) -> String {
+        self.as_ref().to_string()
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::NotFound(_) => StatusCode::NOT_FOUND,
+            Self::Conflict(_) => StatusCode::CONFLICT,
+            Self::SelfSubstitute | Self::ValidationError(_) => StatusCode::BAD_REQUEST,
+            Self::Internal(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+impl IntoResponse for AddSubstituteError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_message()).into_response()
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/ingredient/{id}/substitutes",
+    tag = "ingredients",
+    params(("id" = Uuid, Path, description = "ID of the ingredient")),
+    request_body = CreateIngredientSubstituteDTO,
+    responses(
+        (status = 201, description = "The substitute was added", body = IngredientSubstituteDTO),
+        (status = 400, description = "The substitute is invalid, or is the ingredient itself", body = String, content_type = "text/plain"),
+        (status = 404, description = "The ingredient or the substitute does not exist", body = String, content_type = "text/plain"),
+        (status = 409, description = "The substitute was already added", body = String, content_type = "text/plain"),
+        (status = 500, description = "The substitute could not be added", body = String, content_type = "text/plain"),
+    )
+)]
+#[tracing::instrument(
+    "[ROUTE] Adding a substitute to an ingredient",
+    skip(ingredient_repository)
+)]
+pub async fn add_ingredient_substitute_route(
+    Path(ingredient_id): Path<Uuid>,
+    State(AppState {
+        ingredient_repository,
+        ..
+    }): State<AppState>,
+    Json(body): Json<CreateIngredientSubstituteDTO>,
+) -> Result<impl IntoResponse, AddSubstituteError> {
+    let result = add_substitute(ingredient_repository, &ingredient_id, &body.into()).await?;
+    let result: IngredientSubstituteDTO = result.into();
+
+    Ok((StatusCode::CREATED, Json(result)))
+}
@@ -1,9 +1,15 @@
-use axum::{extract::State, response::IntoResponse};
-use common::IngredientDTO;
+use axum::{
+    extract::{Query, State},
+    response::IntoResponse,
+};
+use common::{IngredientDTO, PageParamsDTO, PagedDTO};
 
 use crate::{
     api::{errors::MakeError, extract::Json, AppState},
-    domain::queries::ingredients::get_all::{get_all_ingredients, GetAllIngredientsError},
+    domain::{
+        entities::pagination::PageRequest,
+        queries::ingredients::get_all::{get_all_ingredients, GetAllIngredientsError},
+    },
 };
 
 impl MakeError<String> for GetAllIngredientsError {
@@ -24,6 +30,20 @@ impl IntoResponse for GetAllIngredientsError {
     }
 }
 
+#[utoipa::path(
+    get,
+    path = "/ingredient",
+    tag = "ingredients",
+    params(
+        ("limit" = Option<u32>, Query, description = "How many ingredients to return, 20 by default and 100 at most"),
+        ("cursor" = Option<Uuid>, Query, description = "The `next_cursor` returned with the previous page"),
+        ("sort" = Option<String>, Query, description = "Either `name` or `created_at`, the newest first by default"),
+    ),
+    responses(
+        (status = 200, description = "A page of ingredients", body = PagedIngredientsDTO),
+        (status = 400, description = "The pagination parameters are invalid, or the ingredients could not be fetched", body = String, content_type = "text/plain"),
+    )
+)]
 #[tracing::instrument(
     "[ROUTE] Getting all available ingredients",
     skip(ingredient_repository)
@@ -33,9 +53,11 @@ pub async fn get_all_ingredients_route(
         ingredient_repository,
         ..
     }): State<AppState>,
-) -> Result<Json<Vec<IngredientDTO>>, GetAllIngredientsError> {
+    Query(params): Query<PageParamsDTO>,
+) -> Result<Json<PagedDTO<IngredientDTO>>, GetAllIngredientsError> {
     tracing::info!("Getting all ingredients");
-    let result = get_all_ingredients(ingredient_repository).await?;
+    let request = PageRequest::try_from(params)?;
+    let result = get_all_ingredients(ingredient_repository, &request).await?;
 
-    Ok(Json(result.iter().map(IngredientDTO::from).collect()))
+    Ok(Json(result.into()))
 }
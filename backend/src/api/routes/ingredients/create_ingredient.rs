@@ -27,11 +27,20 @@ impl IntoResponse for CreateIngredientError {
     }
 }
 
-#[tracing::instrument("[ROUTE] Creating a new ingredient", skip(ingredient_repository, message_service))]
+#[utoipa::path(
+    post,
+    path = "/ingredient",
+    tag = "ingredients",
+    request_body = CreateIngredientDTO,
+    responses(
+        (status = 201, description = "The ingredient was created", body = IngredientDTO),
+        (status = 400, description = "The ingredient is invalid, or could not be created", body = String, content_type = "text/plain"),
+    )
+)]
+#[tracing::instrument("[ROUTE] Creating a new ingredient", skip(ingredient_repository))]
 pub async fn create_ingredient_route(
     State(AppState {
         ingredient_repository,
-        message_service,
         ..
     }): State<AppState>,
     Json(body): Json<CreateIngredientDTO>,
@@ -40,8 +49,10 @@ pub async fn create_ingredient_route(
         name: &body.name,
         description: &body.description,
         diet_violations: body.diet_violations.unwrap_or_default(),
+        parent_id: body.parent_id,
+        allergens: body.allergens.unwrap_or_default(),
     };
-    let result = create_ingredient(ingredient_repository, message_service, &input).await?;
+    let result = create_ingredient(ingredient_repository, &input).await?;
     let result: IngredientDTO = result.into();
 
     Ok((StatusCode::CREATED, Json(result)))
@@ -6,7 +6,7 @@ use reqwest::StatusCode;
 use uuid::Uuid;
 
 use crate::{
-    api::{errors::MakeError, AppState},
+    api::{errors::MakeError, extract::IfMatch, AppState},
     domain::commands::ingredients::delete::{delete_ingredient, DeleteIngredientError},
 };
 
@@ -21,6 +21,7 @@ impl MakeError<String> for DeleteIngredientError {
         match self {
             Self::NotFound(_) => StatusCode::NOT_FOUND,
             Self::InUseByRecipe => StatusCode::CONFLICT,
+            Self::Conflict(_) => StatusCode::PRECONDITION_FAILED,
             _ => StatusCode::INTERNAL_SERVER_ERROR,
         }
     }
@@ -32,24 +33,41 @@ impl IntoResponse for DeleteIngredientError {
     }
 }
 
+#[utoipa::path(
+    delete,
+    path = "/ingredient/{id}",
+    tag = "ingredients",
+    params(
+        ("id" = Uuid, Path, description = "ID of the ingredient"),
+        ("If-Match" = String, Header, description = "ETag of the version being changed, or `*` for any version"),
+    ),
+    responses(
+        (status = 200, description = "The ingredient was deleted"),
+        (status = 404, description = "The ingredient does not exist", body = String, content_type = "text/plain"),
+        (status = 409, description = "The ingredient is used by a recipe", body = String, content_type = "text/plain"),
+        (status = 412, description = "The ingredient has been changed since it was fetched", body = String, content_type = "text/plain"),
+        (status = 428, description = "The If-Match header is missing", body = ErrorMessageWithString),
+        (status = 500, description = "The ingredient could not be deleted", body = String, content_type = "text/plain"),
+    )
+)]
 #[tracing::instrument(
     "[ROUTE] Deleting an ingredient",
-    skip(ingredient_repository, recipe_repository, message_service)
+    skip(ingredient_repository, recipe_repository)
 )]
 pub async fn delete_ingredient_route(
     State(AppState {
         ingredient_repository,
         recipe_repository,
-        message_service,
         ..
     }): State<AppState>,
     Path(ingredient_id): Path<Uuid>,
+    IfMatch(versions): IfMatch,
 ) -> Result<(), DeleteIngredientError> {
     delete_ingredient(
         ingredient_repository,
         recipe_repository,
-        message_service,
         &ingredient_id,
+        versions.as_deref(),
     )
     .await?;
 
@@ -0,0 +1,59 @@
+use axum::{
+    extract::{Path, State},
+    response::IntoResponse,
+};
+use reqwest::StatusCode;
+use uuid::Uuid;
+
+use crate::{
+    api::{errors::MakeError, AppState},
+    domain::commands::ingredients::nutrition::delete::{delete_nutrition, DeleteNutritionError},
+};
+
+impl MakeError<String> for DeleteNutritionError {
+    fn get_kind(&self) -> String {
+        self.as_ref().to_string()
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::NotFound(_) | Self::NutritionNotFound(_) => StatusCode::NOT_FOUND,
+            Self::Internal(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+impl IntoResponse for DeleteNutritionError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_message()).into_response()
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/ingredient/{id}/nutrition",
+    tag = "ingredients",
+    params(("id" = Uuid, Path, description = "ID of the ingredient")),
+    responses(
+        (status = 200, description = "The nutrition facts were removed"),
+        (status = 404, description = "The ingredient does not exist, or has no nutrition facts", body = String, content_type = "text/plain"),
+        (status = 500, description = "The nutrition facts could not be removed", body = String, content_type = "text/plain"),
+    )
+)]
+#[tracing::instrument(
+    "[ROUTE] Removing nutrition facts of an ingredient",
+    skip(ingredient_repository)
+)]
+pub async fn delete_ingredient_nutrition_route(
+    Path(ingredient_id): Path<Uuid>,
+    State(AppState {
+        ingredient_repository,
+        ..
+    }): State<AppState>,
+) -> Result<(), DeleteNutritionError> {
+    delete_nutrition(ingredient_repository, &ingredient_id).await?;
+
+    Ok(())
+}
@@ -0,0 +1,64 @@
+use axum::{
+    extract::{Path, State},
+    response::IntoResponse,
+};
+use reqwest::StatusCode;
+use uuid::Uuid;
+
+use crate::{
+    api::{errors::MakeError, AppState},
+    domain::commands::ingredients::substitutes::delete::{
+        delete_substitute, DeleteSubstituteError,
+    },
+};
+
+impl MakeError<String> for DeleteSubstituteError {
+    fn get_kind(&self) -> String {
+        self.as_ref().to_string()
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::NotFound(_) | Self::SubstituteNotFound(_) => StatusCode::NOT_FOUND,
+            Self::Internal(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+impl IntoResponse for DeleteSubstituteError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_message()).into_response()
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/ingredient/{id}/substitutes/{substitute_id}",
+    tag = "ingredients",
+    params(
+        ("id" = Uuid, Path, description = "ID of the ingredient"),
+        ("substitute_id" = Uuid, Path, description = "ID of the substitute"),
+    ),
+    responses(
+        (status = 200, description = "The substitute was removed"),
+        (status = 404, description = "The ingredient does not exist, or the substitute is not one of its substitutes", body = String, content_type = "text/plain"),
+        (status = 500, description = "The substitute could not be removed", body = String, content_type = "text/plain"),
+    )
+)]
+#[tracing::instrument(
+    "[ROUTE] Removing a substitute from an ingredient",
+    skip(ingredient_repository)
+)]
+pub async fn delete_ingredient_substitute_route(
+    Path((ingredient_id, substitute_id)): Path<(Uuid, Uuid)>,
+    State(AppState {
+        ingredient_repository,
+        ..
+    }): State<AppState>,
+) -> Result<(), DeleteSubstituteError> {
+    delete_substitute(ingredient_repository, &ingredient_id, &substitute_id).await?;
+
+    Ok(())
+}
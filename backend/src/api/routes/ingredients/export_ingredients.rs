@@ -0,0 +1,62 @@
+use std::str::FromStr;
+
+use axum::{
+    extract::{Query, State},
+    http::header,
+    response::IntoResponse,
+};
+use common::ExportIngredientsParamsDTO;
+use reqwest::StatusCode;
+use strum::VariantNames;
+
+use crate::api::{errors::MakeError, AppState};
+use crate::domain::entities::ingredient::formats::IngredientFormat;
+use crate::domain::queries::ingredients::export::{export_ingredients, ExportIngredientsError};
+
+impl MakeError<String> for ExportIngredientsError {
+    fn get_kind(&self) -> String {
+        self.as_ref().to_string()
+    }
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::InvalidFormat(_) => StatusCode::BAD_REQUEST,
+            Self::Unknown(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for ExportIngredientsError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/ingredient/export",
+    tag = "ingredients",
+    params(ExportIngredientsParamsDTO),
+    responses(
+        (status = 200, description = "Every ingredient, in the same format as the bulk import takes", body = Vec<CreateIngredientDTO>, content_type = ["application/json", "text/csv"]),
+        (status = 400, description = "The format is invalid", body = ErrorMessageWithString),
+        (status = 500, description = "The ingredients could not be exported", body = ErrorMessageWithString),
+    )
+)]
+#[tracing::instrument("[ROUTE] Exporting ingredients", skip(ingredient_repository))]
+pub async fn export_ingredients_route(
+    State(AppState {
+        ingredient_repository,
+        ..
+    }): State<AppState>,
+    Query(params): Query<ExportIngredientsParamsDTO>,
+) -> Result<impl IntoResponse, ExportIngredientsError> {
+    let format = IngredientFormat::from_str(&params.format)
+        .map_err(|_| ExportIngredientsError::InvalidFormat(IngredientFormat::VARIANTS))?;
+
+    let document = export_ingredients(ingredient_repository, format).await?;
+
+    Ok(([(header::CONTENT_TYPE, format.content_type())], document))
+}
@@ -1,13 +1,21 @@
 use axum::{
     extract::{Path, State},
+    http::header,
     response::IntoResponse,
 };
 use common::IngredientDTO;
 use uuid::Uuid;
 
 use crate::{
-    api::{errors::MakeError, extract::Json, AppState},
-    domain::queries::ingredients::get_by_id::{get_ingredient_by_id, GetIngredientError},
+    api::{
+        errors::MakeError,
+        extract::{etag, Json},
+        AppState,
+    },
+    domain::queries::ingredients::{
+        get_by_id::{get_ingredient_by_id, GetIngredientError},
+        get_substitutes::get_ingredient_substitutes,
+    },
 };
 
 impl MakeError<String> for GetIngredientError {
@@ -28,6 +36,16 @@ impl IntoResponse for GetIngredientError {
     }
 }
 
+#[utoipa::path(
+    get,
+    path = "/ingredient/{id}",
+    tag = "ingredients",
+    params(("id" = Uuid, Path, description = "ID of the ingredient")),
+    responses(
+        (status = 200, description = "The ingredient, together with its substitutes", body = IngredientDTO, headers(("ETag" = String, description = "The current version of the ingredient"))),
+        (status = 400, description = "The ingredient does not exist, or could not be fetched", body = String, content_type = "text/plain"),
+    )
+)]
 #[tracing::instrument("[ROUTE] Getting ingredient by ID", skip(ingredient_repository))]
 pub async fn get_ingredient_by_id_route(
     Path(ingredient_id): Path<Uuid>,
@@ -35,8 +53,13 @@ pub async fn get_ingredient_by_id_route(
         ingredient_repository,
         ..
     }): State<AppState>,
-) -> Result<Json<IngredientDTO>, GetIngredientError> {
-    let result = get_ingredient_by_id(ingredient_repository, &ingredient_id).await?;
+) -> Result<impl IntoResponse, GetIngredientError> {
+    let result = get_ingredient_by_id(ingredient_repository.clone(), &ingredient_id).await?;
+    let substitutes = get_ingredient_substitutes(ingredient_repository, &ingredient_id).await?;
 
-    Ok(Json(result.into()))
+    let version = result.version();
+    let mut result: IngredientDTO = result.into();
+    result.substitutes = Some(substitutes.into_iter().map(Into::into).collect());
+
+    Ok(([(header::ETAG, etag(&version))], Json(result)))
 }
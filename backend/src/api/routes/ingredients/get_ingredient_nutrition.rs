@@ -0,0 +1,62 @@
+use axum::{
+    extract::{Path, State},
+    response::IntoResponse,
+};
+use common::IngredientNutritionDTO;
+use reqwest::StatusCode;
+use uuid::Uuid;
+
+use crate::{
+    api::{errors::MakeError, extract::Json, AppState},
+    domain::queries::ingredients::get_nutrition::{
+        get_ingredient_nutrition, GetIngredientNutritionError,
+    },
+};
+
+impl MakeError<String> for GetIngredientNutritionError {
+    fn get_kind(&self) -> String {
+        self.as_ref().to_string()
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::NotFound(_) | Self::NutritionNotFound(_) => StatusCode::NOT_FOUND,
+            Self::Internal(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+impl IntoResponse for GetIngredientNutritionError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_message()).into_response()
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/ingredient/{id}/nutrition",
+    tag = "ingredients",
+    params(("id" = Uuid, Path, description = "ID of the ingredient")),
+    responses(
+        (status = 200, description = "The nutrition facts of 100 g of the ingredient", body = IngredientNutritionDTO),
+        (status = 404, description = "The ingredient does not exist, or has no nutrition facts", body = String, content_type = "text/plain"),
+        (status = 500, description = "The nutrition facts could not be fetched", body = String, content_type = "text/plain"),
+    )
+)]
+#[tracing::instrument(
+    "[ROUTE] Getting nutrition facts of an ingredient",
+    skip(ingredient_repository)
+)]
+pub async fn get_ingredient_nutrition_route(
+    Path(ingredient_id): Path<Uuid>,
+    State(AppState {
+        ingredient_repository,
+        ..
+    }): State<AppState>,
+) -> Result<Json<IngredientNutritionDTO>, GetIngredientNutritionError> {
+    let result = get_ingredient_nutrition(ingredient_repository, &ingredient_id).await?;
+
+    Ok(Json(result.into()))
+}
@@ -0,0 +1,62 @@
+use axum::{
+    extract::{Path, State},
+    response::IntoResponse,
+};
+use common::IngredientSubstituteDTO;
+use reqwest::StatusCode;
+use uuid::Uuid;
+
+use crate::{
+    api::{errors::MakeError, extract::Json, AppState},
+    domain::queries::ingredients::get_substitutes::{
+        get_ingredient_substitutes, GetIngredientSubstitutesError,
+    },
+};
+
+impl MakeError<String> for GetIngredientSubstitutesError {
+    fn get_kind(&self) -> String {
+        self.as_ref().to_string()
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::NotFound(_) => StatusCode::NOT_FOUND,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+impl IntoResponse for GetIngredientSubstitutesError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_message()).into_response()
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/ingredient/{id}/substitutes",
+    tag = "ingredients",
+    params(("id" = Uuid, Path, description = "ID of the ingredient")),
+    responses(
+        (status = 200, description = "The substitutes of the ingredient", body = Vec<IngredientSubstituteDTO>),
+        (status = 404, description = "The ingredient does not exist", body = String, content_type = "text/plain"),
+        (status = 500, description = "The substitutes could not be fetched", body = String, content_type = "text/plain"),
+    )
+)]
+#[tracing::instrument(
+    "[ROUTE] Getting substitutes of an ingredient",
+    skip(ingredient_repository)
+)]
+pub async fn get_ingredient_substitutes_route(
+    Path(ingredient_id): Path<Uuid>,
+    State(AppState {
+        ingredient_repository,
+        ..
+    }): State<AppState>,
+) -> Result<Json<Vec<IngredientSubstituteDTO>>, GetIngredientSubstitutesError> {
+    let result = get_ingredient_substitutes(ingredient_repository, &ingredient_id).await?;
+
+    Ok(Json(result.into_iter().map(Into::into).collect()))
+}
@@ -0,0 +1,49 @@
+use axum::{extract::State, response::IntoResponse};
+use common::IngredientTreeDTO;
+
+use crate::{
+    api::{errors::MakeError, extract::Json, AppState},
+    domain::queries::ingredients::get_tree::{get_ingredient_tree, GetIngredientTreeError},
+};
+
+impl MakeError<String> for GetIngredientTreeError {
+    fn get_kind(&self) -> String {
+        self.as_ref().to_string()
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+    fn get_status_code(&self) -> reqwest::StatusCode {
+        reqwest::StatusCode::INTERNAL_SERVER_ERROR
+    }
+}
+
+impl IntoResponse for GetIngredientTreeError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_message()).into_response()
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/ingredient/tree",
+    tag = "ingredients",
+    responses(
+        (status = 200, description = "Every ingredient without a parent, together with its variants", body = Vec<IngredientTreeDTO>),
+        (status = 500, description = "The ingredients could not be fetched", body = String, content_type = "text/plain"),
+    )
+)]
+#[tracing::instrument(
+    "[ROUTE] Getting ingredients arranged by their variants",
+    skip(ingredient_repository)
+)]
+pub async fn get_ingredient_tree_route(
+    State(AppState {
+        ingredient_repository,
+        ..
+    }): State<AppState>,
+) -> Result<Json<Vec<IngredientTreeDTO>>, GetIngredientTreeError> {
+    let result = get_ingredient_tree(ingredient_repository).await?;
+
+    Ok(Json(result.into_iter().map(Into::into).collect()))
+}
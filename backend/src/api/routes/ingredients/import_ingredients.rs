@@ -0,0 +1,121 @@
+use axum::{
+    extract::State,
+    http::{header, HeaderMap},
+    response::IntoResponse,
+};
+use common::{IngredientImportReportDTO, IngredientImportRowDTO};
+use reqwest::StatusCode;
+
+use crate::api::{errors::MakeError, extract::Json, AppState};
+use crate::domain::commands::ingredients::{
+    create::CreateIngredient,
+    import::{import_ingredients, ImportIngredientsError, IngredientImportOutcome},
+};
+use crate::domain::entities::ingredient::formats::IngredientFormat;
+
+impl MakeError<String> for ImportIngredientsError {
+    fn get_kind(&self) -> String {
+        self.as_ref().to_string()
+    }
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::Format(_) => StatusCode::BAD_REQUEST,
+            Self::Unknown(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for ImportIngredientsError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+impl From<IngredientImportOutcome> for IngredientImportRowDTO {
+    fn from(value: IngredientImportOutcome) -> Self {
+        let status = value.as_ref().to_string();
+
+        match value {
+            IngredientImportOutcome::Created(ingredient) => Self {
+                status,
+                ingredient: Some(ingredient.into()),
+                message: None,
+            },
+            IngredientImportOutcome::Conflict(field) => Self {
+                status,
+                ingredient: None,
+                message: Some(format!(
+                    "An ingredient with the same {field} already exists"
+                )),
+            },
+            IngredientImportOutcome::Invalid(message) => Self {
+                status,
+                ingredient: None,
+                message: Some(message),
+            },
+        }
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/ingredient/bulk",
+    tag = "ingredients",
+    request_body(
+        content = Vec<CreateIngredientDTO>,
+        content_type = "application/json",
+        description = "A JSON array of ingredients. With `Content-Type: text/csv`, a CSV file with the same columns and the diets separated by semicolons"
+    ),
+    responses(
+        (status = 200, description = "The ingredients were imported, every one of them has a row in the report", body = IngredientImportReportDTO),
+        (status = 400, description = "The document could not be read", body = ErrorMessageWithString),
+        (status = 500, description = "The ingredients could not be imported", body = ErrorMessageWithString),
+    )
+)]
+#[tracing::instrument(
+    "[ROUTE] Importing ingredients",
+    skip(ingredient_repository, headers, body)
+)]
+pub async fn import_ingredients_route(
+    State(AppState {
+        ingredient_repository,
+        ..
+    }): State<AppState>,
+    headers: HeaderMap,
+    body: String,
+) -> Result<impl IntoResponse, ImportIngredientsError> {
+    let content_type = headers
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or_default();
+    let ingredients = IngredientFormat::from_content_type(content_type).parse(&body)?;
+
+    let input: Vec<CreateIngredient> = ingredients
+        .iter()
+        .map(|ingredient| CreateIngredient {
+            name: &ingredient.name,
+            description: &ingredient.description,
+            diet_violations: ingredient.diet_violations.clone().unwrap_or_default(),
+            parent_id: ingredient.parent_id,
+            allergens: ingredient.allergens.clone().unwrap_or_default(),
+        })
+        .collect();
+
+    let rows: Vec<IngredientImportRowDTO> = import_ingredients(ingredient_repository, &input)
+        .await?
+        .into_iter()
+        .map(Into::into)
+        .collect();
+
+    let count = |status: &str| rows.iter().filter(|row| row.status == status).count();
+
+    Ok(Json(IngredientImportReportDTO {
+        created: count("created"),
+        conflicts: count("conflict"),
+        invalid: count("invalid"),
+        rows,
+    }))
+}
@@ -1,11 +1,31 @@
+mod add_ingredient_substitute;
 mod all_ingredients;
 mod create_ingredient;
 mod delete_ingredient;
+mod delete_ingredient_nutrition;
+mod delete_ingredient_substitute;
+mod export_ingredients;
 mod get_ingredient_by_id;
+mod get_ingredient_nutrition;
+mod get_ingredient_substitutes;
+mod get_ingredient_tree;
+mod import_ingredients;
+mod set_ingredient_nutrition;
 mod update_ingredient;
+mod update_ingredient_substitute;
 
+pub use add_ingredient_substitute::*;
 pub use all_ingredients::*;
 pub use create_ingredient::*;
 pub use delete_ingredient::*;
+pub use delete_ingredient_nutrition::*;
+pub use delete_ingredient_substitute::*;
+pub use export_ingredients::*;
 pub use get_ingredient_by_id::*;
+pub use get_ingredient_nutrition::*;
+pub use get_ingredient_substitutes::*;
+pub use get_ingredient_tree::*;
+pub use import_ingredients::*;
+pub use set_ingredient_nutrition::*;
 pub use update_ingredient::*;
+pub use update_ingredient_substitute::*;
@@ -0,0 +1,64 @@
+use axum::{
+    extract::{Path, State},
+    response::IntoResponse,
+};
+use common::IngredientNutritionDTO;
+use reqwest::StatusCode;
+use uuid::Uuid;
+
+use crate::{
+    api::{errors::MakeError, extract::Json, AppState},
+    domain::commands::ingredients::nutrition::set::{set_nutrition, SetNutritionError},
+};
+
+impl MakeError<String> for SetNutritionError {
+    fn get_kind(&self) -> String {
+        self.as_ref().to_string()
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::NotFound(_) => StatusCode::NOT_FOUND,
+            Self::ValidationError(_) => StatusCode::BAD_REQUEST,
+            Self::Internal(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+impl IntoResponse for SetNutritionError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_message()).into_response()
+    }
+}
+
+#[utoipa::path(
+    put,
+    path = "/ingredient/{id}/nutrition",
+    tag = "ingredients",
+    params(("id" = Uuid, Path, description = "ID of the ingredient")),
+    request_body = IngredientNutritionDTO,
+    responses(
+        (status = 200, description = "The nutrition facts of the ingredient were set", body = IngredientNutritionDTO),
+        (status = 400, description = "Some of the values are negative, or the density is not positive", body = String, content_type = "text/plain"),
+        (status = 404, description = "The ingredient does not exist", body = String, content_type = "text/plain"),
+        (status = 500, description = "The nutrition facts could not be set", body = String, content_type = "text/plain"),
+    )
+)]
+#[tracing::instrument(
+    "[ROUTE] Setting nutrition facts of an ingredient",
+    skip(ingredient_repository)
+)]
+pub async fn set_ingredient_nutrition_route(
+    Path(ingredient_id): Path<Uuid>,
+    State(AppState {
+        ingredient_repository,
+        ..
+    }): State<AppState>,
+    Json(body): Json<IngredientNutritionDTO>,
+) -> Result<Json<IngredientNutritionDTO>, SetNutritionError> {
+    let result = set_nutrition(ingredient_repository, &ingredient_id, body.into()).await?;
+
+    Ok(Json(result.into()))
+}
@@ -1,12 +1,17 @@
 use axum::{
     extract::{Path, State},
+    http::header,
     response::IntoResponse,
 };
 use common::{IngredientDTO, UpdateIngredientDTO};
 use uuid::Uuid;
 
 use crate::{
-    api::{errors::MakeError, extract::Json, AppState},
+    api::{
+        errors::MakeError,
+        extract::{etag, IfMatch, Json},
+        AppState,
+    },
     domain::commands::ingredients::update::{update_ingredient, UpdateIngredientError},
 };
 
@@ -20,6 +25,10 @@ impl MakeError<String> for UpdateIngredientError {
     fn get_status_code(&self) -> reqwest::StatusCode {
         match self {
             Self::NotFound(_) => reqwest::StatusCode::NOT_FOUND,
+            Self::ParentNotFound(_) | Self::CyclicParent(_) | Self::ValidationError(_) => {
+                reqwest::StatusCode::BAD_REQUEST
+            }
+            Self::Conflict(_) => reqwest::StatusCode::PRECONDITION_FAILED,
             _ => reqwest::StatusCode::INTERNAL_SERVER_ERROR,
         }
     }
@@ -31,6 +40,24 @@ impl IntoResponse for UpdateIngredientError {
     }
 }
 
+#[utoipa::path(
+    put,
+    path = "/ingredient/{id}",
+    tag = "ingredients",
+    params(
+        ("id" = Uuid, Path, description = "ID of the ingredient"),
+        ("If-Match" = String, Header, description = "ETag of the version being changed, or `*` for any version"),
+    ),
+    request_body = UpdateIngredientDTO,
+    responses(
+        (status = 200, description = "The updated ingredient", body = IngredientDTO, headers(("ETag" = String, description = "The new version of the ingredient"))),
+        (status = 400, description = "The changes are invalid, i.e. an unknown allergen, or the new parent does not exist or would make the ingredient its own ancestor", body = String, content_type = "text/plain"),
+        (status = 404, description = "The ingredient does not exist", body = String, content_type = "text/plain"),
+        (status = 412, description = "The ingredient has been changed since it was fetched", body = String, content_type = "text/plain"),
+        (status = 428, description = "The If-Match header is missing", body = ErrorMessageWithString),
+        (status = 500, description = "The ingredient could not be updated", body = String, content_type = "text/plain"),
+    )
+)]
 #[tracing::instrument("[ROUTE] Updating an existing ingredient", skip(ingredient_repository))]
 pub async fn update_ingredient_route(
     Path(ingredient_id): Path<Uuid>,
@@ -38,9 +65,19 @@ pub async fn update_ingredient_route(
         ingredient_repository,
         ..
     }): State<AppState>,
+    IfMatch(versions): IfMatch,
     Json(body): Json<UpdateIngredientDTO>,
-) -> Result<Json<IngredientDTO>, UpdateIngredientError> {
-    let result = update_ingredient(ingredient_repository, ingredient_id, &body.into()).await?;
+) -> Result<impl IntoResponse, UpdateIngredientError> {
+    let result = update_ingredient(
+        ingredient_repository,
+        ingredient_id,
+        &body.into(),
+        versions.as_deref(),
+    )
+    .await?;
 
-    Ok(Json(result.into()))
+    Ok((
+        [(header::ETAG, etag(&result.version()))],
+        Json(IngredientDTO::from(result)),
+    ))
 }
@@ -0,0 +1,75 @@
+use axum::{
+    extract::{Path, State},
+    response::IntoResponse,
+};
+use common::{IngredientSubstituteDTO, UpdateIngredientSubstituteDTO};
+use reqwest::StatusCode;
+use uuid::Uuid;
+
+use crate::{
+    api::{errors::MakeError, extract::Json, AppState},
+    domain::commands::ingredients::substitutes::update::{
+        update_substitute, UpdateSubstituteError,
+    },
+};
+
+impl MakeError<String> for UpdateSubstituteError {
+    fn get_kind(&self) -> String {
+        self.as_ref().to_string()
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::NotFound(_) | Self::SubstituteNotFound(_) => StatusCode::NOT_FOUND,
+            Self::ChangesetEmpty | Self::ValidationError(_) => StatusCode::BAD_REQUEST,
+            Self::Internal(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+impl IntoResponse for UpdateSubstituteError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_message()).into_response()
+    }
+}
+
+#[utoipa::path(
+    put,
+    path = "/ingredient/{id}/substitutes/{substitute_id}",
+    tag = "ingredients",
+    params(
+        ("id" = Uuid, Path, description = "ID of the ingredient"),
+        ("substitute_id" = Uuid, Path, description = "ID of the substitute"),
+    ),
+    request_body = UpdateIngredientSubstituteDTO,
+    responses(
+        (status = 200, description = "The updated substitute", body = IngredientSubstituteDTO),
+        (status = 400, description = "Nothing to change, or the changes are invalid", body = String, content_type = "text/plain"),
+        (status = 404, description = "The ingredient does not exist, or the substitute is not one of its substitutes", body = String, content_type = "text/plain"),
+        (status = 500, description = "The substitute could not be updated", body = String, content_type = "text/plain"),
+    )
+)]
+#[tracing::instrument(
+    "[ROUTE] Updating a substitute of an ingredient",
+    skip(ingredient_repository)
+)]
+pub async fn update_ingredient_substitute_route(
+    Path((ingredient_id, substitute_id)): Path<(Uuid, Uuid)>,
+    State(AppState {
+        ingredient_repository,
+        ..
+    }): State<AppState>,
+    Json(body): Json<UpdateIngredientSubstituteDTO>,
+) -> Result<Json<IngredientSubstituteDTO>, UpdateSubstituteError> {
+    let result = update_substitute(
+        ingredient_repository,
+        &ingredient_id,
+        &substitute_id,
+        &body.into(),
+    )
+    .await?;
+
+    Ok(Json(result.into()))
+}
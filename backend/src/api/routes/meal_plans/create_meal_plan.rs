@@ -0,0 +1,69 @@
+use axum::{extract::State, response::IntoResponse};
+use common::{CreateMealPlanDTO, MealPlanDTO};
+use reqwest::StatusCode;
+
+use crate::api::errors::MakeError;
+use crate::api::extract::{CurrentUser, Json};
+use crate::api::AppState;
+use crate::domain::commands::meal_plans::create::{create_meal_plan, CreateMealPlanError};
+
+impl MakeError<String> for CreateMealPlanError {
+    fn get_kind(&self) -> String {
+        self.as_ref().to_string()
+    }
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::RecipeNotFound(_) | Self::Validation(_) => StatusCode::BAD_REQUEST,
+            Self::Conflict(_) => StatusCode::CONFLICT,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for CreateMealPlanError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/meal-plan",
+    tag = "meal_plans",
+    request_body = CreateMealPlanDTO,
+    responses(
+        (status = 201, description = "The meal plan was created", body = MealPlanDTO),
+        (status = 400, description = "Some of the recipes do not exist, or a meal is not in the week of the plan", body = ErrorMessageWithString),
+        (status = 401, description = "Not logged in", body = ErrorMessageWithString),
+        (status = 409, description = "There already is a meal plan starting on that day", body = ErrorMessageWithString),
+        (status = 500, description = "The meal plan could not be created", body = ErrorMessageWithString),
+    ),
+    security(("session" = []))
+)]
+#[tracing::instrument(
+    "[ROUTE] Creating a new meal plan",
+    skip(meal_plan_repository, recipe_repository, user)
+)]
+pub async fn create_meal_plan_route(
+    State(AppState {
+        meal_plan_repository,
+        recipe_repository,
+        ..
+    }): State<AppState>,
+    CurrentUser(user): CurrentUser,
+    Json(body): Json<CreateMealPlanDTO>,
+) -> Result<impl IntoResponse, CreateMealPlanError> {
+    let result: MealPlanDTO = create_meal_plan(
+        meal_plan_repository,
+        recipe_repository,
+        &user.id,
+        body.into(),
+    )
+    .await?
+    .into();
+
+    Ok((StatusCode::CREATED, Json(result)))
+}
@@ -0,0 +1,63 @@
+use axum::{
+    extract::{Path, State},
+    response::IntoResponse,
+};
+use reqwest::StatusCode;
+use uuid::Uuid;
+
+use crate::{
+    api::{errors::MakeError, extract::CurrentUser, AppState},
+    domain::commands::meal_plans::delete::{delete_meal_plan, DeleteMealPlanError},
+};
+
+impl MakeError<String> for DeleteMealPlanError {
+    fn get_kind(&self) -> String {
+        self.as_ref().to_string()
+    }
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::NotFound(_) => StatusCode::NOT_FOUND,
+            Self::Forbidden(_) => StatusCode::FORBIDDEN,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for DeleteMealPlanError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/meal-plan/{id}",
+    tag = "meal_plans",
+    params(
+        ("id" = Uuid, Path, description = "ID of the meal plan"),
+    ),
+    responses(
+        (status = 200, description = "The meal plan was deleted"),
+        (status = 401, description = "Not logged in", body = ErrorMessageWithString),
+        (status = 403, description = "The meal plan belongs to someone else", body = ErrorMessageWithString),
+        (status = 404, description = "The meal plan does not exist", body = ErrorMessageWithString),
+        (status = 500, description = "The meal plan could not be deleted", body = ErrorMessageWithString),
+    ),
+    security(("session" = []))
+)]
+#[tracing::instrument("[ROUTE] Deleting a meal plan", skip(meal_plan_repository, user))]
+pub async fn delete_meal_plan_route(
+    State(AppState {
+        meal_plan_repository,
+        ..
+    }): State<AppState>,
+    CurrentUser(user): CurrentUser,
+    Path(id): Path<Uuid>,
+) -> Result<(), DeleteMealPlanError> {
+    delete_meal_plan(meal_plan_repository, &user.id, &id).await?;
+
+    Ok(())
+}
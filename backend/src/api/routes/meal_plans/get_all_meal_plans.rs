@@ -0,0 +1,50 @@
+use axum::{extract::State, response::IntoResponse};
+use common::MealPlanDTO;
+use reqwest::StatusCode;
+
+use crate::api::errors::MakeError;
+use crate::api::extract::{CurrentUser, Json};
+use crate::api::AppState;
+use crate::domain::queries::meal_plans::get_all::{get_all_meal_plans, GetMealPlansError};
+
+impl MakeError<String> for GetMealPlansError {
+    fn get_kind(&self) -> String {
+        self.as_ref().to_string()
+    }
+    fn get_status_code(&self) -> StatusCode {
+        StatusCode::INTERNAL_SERVER_ERROR
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for GetMealPlansError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/meal-plan",
+    tag = "meal_plans",
+    responses(
+        (status = 200, description = "The meal plans of the current user, the earliest ones first", body = Vec<MealPlanDTO>),
+        (status = 401, description = "Not logged in", body = ErrorMessageWithString),
+        (status = 500, description = "The meal plans could not be fetched", body = ErrorMessageWithString),
+    ),
+    security(("session" = []))
+)]
+#[tracing::instrument("[ROUTE] Getting all meal plans", skip(meal_plan_repository, user))]
+pub async fn get_all_meal_plans_route(
+    State(AppState {
+        meal_plan_repository,
+        ..
+    }): State<AppState>,
+    CurrentUser(user): CurrentUser,
+) -> Result<Json<Vec<MealPlanDTO>>, GetMealPlansError> {
+    let result = get_all_meal_plans(meal_plan_repository, &user.id).await?;
+
+    Ok(Json(result.into_iter().map(Into::into).collect()))
+}
@@ -0,0 +1,64 @@
+use axum::{
+    extract::{Path, State},
+    response::IntoResponse,
+};
+use common::MealPlanDTO;
+use reqwest::StatusCode;
+use uuid::Uuid;
+
+use crate::api::errors::MakeError;
+use crate::api::extract::{CurrentUser, Json};
+use crate::api::AppState;
+use crate::domain::queries::meal_plans::get_by_id::{get_meal_plan_by_id, GetMealPlanError};
+
+impl MakeError<String> for GetMealPlanError {
+    fn get_kind(&self) -> String {
+        self.as_ref().to_string()
+    }
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::NotFound(_) => StatusCode::NOT_FOUND,
+            Self::Forbidden(_) => StatusCode::FORBIDDEN,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for GetMealPlanError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/meal-plan/{id}",
+    tag = "meal_plans",
+    params(
+        ("id" = Uuid, Path, description = "ID of the meal plan"),
+    ),
+    responses(
+        (status = 200, description = "The meal plan", body = MealPlanDTO),
+        (status = 401, description = "Not logged in", body = ErrorMessageWithString),
+        (status = 403, description = "The meal plan belongs to someone else", body = ErrorMessageWithString),
+        (status = 404, description = "The meal plan does not exist", body = ErrorMessageWithString),
+        (status = 500, description = "The meal plan could not be fetched", body = ErrorMessageWithString),
+    ),
+    security(("session" = []))
+)]
+#[tracing::instrument("[ROUTE] Getting a meal plan by ID", skip(meal_plan_repository, user))]
+pub async fn get_meal_plan_by_id_route(
+    State(AppState {
+        meal_plan_repository,
+        ..
+    }): State<AppState>,
+    CurrentUser(user): CurrentUser,
+    Path(id): Path<Uuid>,
+) -> Result<Json<MealPlanDTO>, GetMealPlanError> {
+    let result = get_meal_plan_by_id(meal_plan_repository, &user.id, &id).await?;
+
+    Ok(Json(result.into()))
+}
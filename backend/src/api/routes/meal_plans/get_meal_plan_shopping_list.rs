@@ -0,0 +1,71 @@
+use axum::{
+    extract::{Path, State},
+    response::IntoResponse,
+};
+use common::ShoppingListDTO;
+use reqwest::StatusCode;
+use uuid::Uuid;
+
+use crate::api::errors::MakeError;
+use crate::api::extract::{CurrentUser, Json};
+use crate::api::AppState;
+use crate::domain::queries::meal_plans::shopping_list::{
+    get_meal_plan_shopping_list, GetMealPlanShoppingListError,
+};
+
+impl MakeError<String> for GetMealPlanShoppingListError {
+    fn get_kind(&self) -> String {
+        self.as_ref().to_string()
+    }
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::NotFound(_) => StatusCode::NOT_FOUND,
+            Self::Forbidden(_) => StatusCode::FORBIDDEN,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for GetMealPlanShoppingListError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/meal-plan/{id}/shopping-list",
+    tag = "meal_plans",
+    params(
+        ("id" = Uuid, Path, description = "ID of the meal plan"),
+    ),
+    responses(
+        (status = 200, description = "Everything needed for the meals of the week, with each ingredient listed once", body = ShoppingListDTO),
+        (status = 401, description = "Not logged in", body = ErrorMessageWithString),
+        (status = 403, description = "The meal plan belongs to someone else", body = ErrorMessageWithString),
+        (status = 404, description = "The meal plan does not exist", body = ErrorMessageWithString),
+        (status = 500, description = "The shopping list could not be made", body = ErrorMessageWithString),
+    ),
+    security(("session" = []))
+)]
+#[tracing::instrument(
+    "[ROUTE] Making a shopping list for a meal plan",
+    skip(meal_plan_repository, recipe_repository, user)
+)]
+pub async fn get_meal_plan_shopping_list_route(
+    State(AppState {
+        meal_plan_repository,
+        recipe_repository,
+        ..
+    }): State<AppState>,
+    CurrentUser(user): CurrentUser,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ShoppingListDTO>, GetMealPlanShoppingListError> {
+    let result =
+        get_meal_plan_shopping_list(meal_plan_repository, recipe_repository, &user.id, &id).await?;
+
+    Ok(Json(result.into()))
+}
@@ -0,0 +1,13 @@
+mod create_meal_plan;
+mod delete_meal_plan;
+mod get_all_meal_plans;
+mod get_meal_plan_by_id;
+mod get_meal_plan_shopping_list;
+mod update_meal_plan;
+
+pub use create_meal_plan::*;
+pub use delete_meal_plan::*;
+pub use get_all_meal_plans::*;
+pub use get_meal_plan_by_id::*;
+pub use get_meal_plan_shopping_list::*;
+pub use update_meal_plan::*;
@@ -0,0 +1,81 @@
+use axum::{
+    extract::{Path, State},
+    response::IntoResponse,
+};
+use common::{MealPlanDTO, UpdateMealPlanDTO};
+use reqwest::StatusCode;
+use uuid::Uuid;
+
+use crate::api::errors::MakeError;
+use crate::api::extract::{CurrentUser, Json};
+use crate::api::AppState;
+use crate::domain::commands::meal_plans::update::{update_meal_plan, UpdateMealPlanError};
+
+impl MakeError<String> for UpdateMealPlanError {
+    fn get_kind(&self) -> String {
+        self.as_ref().to_string()
+    }
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::NotFound(_) => StatusCode::NOT_FOUND,
+            Self::Forbidden(_) => StatusCode::FORBIDDEN,
+            Self::RecipeNotFound(_) | Self::Validation(_) => StatusCode::BAD_REQUEST,
+            Self::Conflict(_) => StatusCode::CONFLICT,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for UpdateMealPlanError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+#[utoipa::path(
+    put,
+    path = "/meal-plan/{id}",
+    tag = "meal_plans",
+    params(
+        ("id" = Uuid, Path, description = "ID of the meal plan"),
+    ),
+    request_body = UpdateMealPlanDTO,
+    responses(
+        (status = 200, description = "The meal plan was updated", body = MealPlanDTO),
+        (status = 400, description = "Some of the recipes do not exist, or a meal is not in the week of the plan", body = ErrorMessageWithString),
+        (status = 401, description = "Not logged in", body = ErrorMessageWithString),
+        (status = 403, description = "The meal plan belongs to someone else", body = ErrorMessageWithString),
+        (status = 404, description = "The meal plan does not exist", body = ErrorMessageWithString),
+        (status = 409, description = "There already is a meal plan starting on that day", body = ErrorMessageWithString),
+        (status = 500, description = "The meal plan could not be updated", body = ErrorMessageWithString),
+    ),
+    security(("session" = []))
+)]
+#[tracing::instrument(
+    "[ROUTE] Updating a meal plan",
+    skip(meal_plan_repository, recipe_repository, user)
+)]
+pub async fn update_meal_plan_route(
+    State(AppState {
+        meal_plan_repository,
+        recipe_repository,
+        ..
+    }): State<AppState>,
+    CurrentUser(user): CurrentUser,
+    Path(id): Path<Uuid>,
+    Json(body): Json<UpdateMealPlanDTO>,
+) -> Result<Json<MealPlanDTO>, UpdateMealPlanError> {
+    let result = update_meal_plan(
+        meal_plan_repository,
+        recipe_repository,
+        &user.id,
+        &id,
+        body.into(),
+    )
+    .await?;
+
+    Ok(Json(result.into()))
+}
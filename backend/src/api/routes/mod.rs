@@ -1,2 +1,5 @@
 pub mod ingredients;
+pub mod meal_plans;
+pub mod pantry;
 pub mod recipes;
+pub mod users;
@@ -0,0 +1,69 @@
+use axum::{extract::State, response::IntoResponse};
+use common::{AddPantryItemDTO, PantryItemDTO};
+use reqwest::StatusCode;
+
+use crate::api::errors::MakeError;
+use crate::api::extract::{CurrentUser, Json};
+use crate::api::AppState;
+use crate::domain::commands::pantry::add::{add_pantry_item, AddPantryItemError};
+
+impl MakeError<String> for AddPantryItemError {
+    fn get_kind(&self) -> String {
+        self.as_ref().to_string()
+    }
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::IngredientNotFound(_) | Self::InvalidAmount => StatusCode::BAD_REQUEST,
+            Self::Conflict(_) => StatusCode::CONFLICT,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for AddPantryItemError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/pantry",
+    tag = "pantry",
+    request_body = AddPantryItemDTO,
+    responses(
+        (status = 201, description = "The ingredient was added to the pantry", body = PantryItemDTO),
+        (status = 400, description = "The ingredient does not exist, or the amount is not above zero", body = ErrorMessageWithString),
+        (status = 401, description = "Not logged in", body = ErrorMessageWithString),
+        (status = 409, description = "The ingredient already is in the pantry", body = ErrorMessageWithString),
+        (status = 500, description = "The ingredient could not be added", body = ErrorMessageWithString),
+    ),
+    security(("session" = []))
+)]
+#[tracing::instrument(
+    "[ROUTE] Adding an ingredient to the pantry",
+    skip(pantry_repository, ingredient_repository, user)
+)]
+pub async fn add_pantry_item_route(
+    State(AppState {
+        pantry_repository,
+        ingredient_repository,
+        ..
+    }): State<AppState>,
+    CurrentUser(user): CurrentUser,
+    Json(body): Json<AddPantryItemDTO>,
+) -> Result<impl IntoResponse, AddPantryItemError> {
+    let result: PantryItemDTO = add_pantry_item(
+        pantry_repository,
+        ingredient_repository,
+        &user.id,
+        body.into(),
+    )
+    .await?
+    .into();
+
+    Ok((StatusCode::CREATED, Json(result)))
+}
@@ -0,0 +1,60 @@
+use axum::{
+    extract::{Path, State},
+    response::IntoResponse,
+};
+use reqwest::StatusCode;
+use uuid::Uuid;
+
+use crate::{
+    api::{errors::MakeError, extract::CurrentUser, AppState},
+    domain::commands::pantry::delete::{delete_pantry_item, DeletePantryItemError},
+};
+
+impl MakeError<String> for DeletePantryItemError {
+    fn get_kind(&self) -> String {
+        self.as_ref().to_string()
+    }
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::NotFound(_) => StatusCode::NOT_FOUND,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for DeletePantryItemError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/pantry/{ingredient_id}",
+    tag = "pantry",
+    params(
+        ("ingredient_id" = Uuid, Path, description = "ID of the ingredient in the pantry"),
+    ),
+    responses(
+        (status = 200, description = "The ingredient was taken out of the pantry"),
+        (status = 401, description = "Not logged in", body = ErrorMessageWithString),
+        (status = 404, description = "The ingredient is not in the pantry", body = ErrorMessageWithString),
+        (status = 500, description = "The pantry item could not be deleted", body = ErrorMessageWithString),
+    ),
+    security(("session" = []))
+)]
+#[tracing::instrument("[ROUTE] Deleting a pantry item", skip(pantry_repository, user))]
+pub async fn delete_pantry_item_route(
+    State(AppState {
+        pantry_repository, ..
+    }): State<AppState>,
+    CurrentUser(user): CurrentUser,
+    Path(ingredient_id): Path<Uuid>,
+) -> Result<(), DeletePantryItemError> {
+    delete_pantry_item(pantry_repository, &user.id, &ingredient_id).await?;
+
+    Ok(())
+}
@@ -0,0 +1,9 @@
+mod add_pantry_item;
+mod delete_pantry_item;
+mod get_pantry;
+mod update_pantry_item;
+
+pub use add_pantry_item::*;
+pub use delete_pantry_item::*;
+pub use get_pantry::*;
+pub use update_pantry_item::*;
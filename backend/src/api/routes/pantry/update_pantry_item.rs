@@ -0,0 +1,66 @@
+use axum::{
+    extract::{Path, State},
+    response::IntoResponse,
+};
+use common::{PantryItemDTO, UpdatePantryItemDTO};
+use reqwest::StatusCode;
+use uuid::Uuid;
+
+use crate::api::errors::MakeError;
+use crate::api::extract::{CurrentUser, Json};
+use crate::api::AppState;
+use crate::domain::commands::pantry::update::{update_pantry_item, UpdatePantryItemError};
+
+impl MakeError<String> for UpdatePantryItemError {
+    fn get_kind(&self) -> String {
+        self.as_ref().to_string()
+    }
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::NotFound(_) => StatusCode::NOT_FOUND,
+            Self::InvalidAmount => StatusCode::BAD_REQUEST,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for UpdatePantryItemError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+#[utoipa::path(
+    put,
+    path = "/pantry/{ingredient_id}",
+    tag = "pantry",
+    params(
+        ("ingredient_id" = Uuid, Path, description = "ID of the ingredient in the pantry"),
+    ),
+    request_body = UpdatePantryItemDTO,
+    responses(
+        (status = 200, description = "The pantry item was updated", body = PantryItemDTO),
+        (status = 400, description = "The amount is not above zero", body = ErrorMessageWithString),
+        (status = 401, description = "Not logged in", body = ErrorMessageWithString),
+        (status = 404, description = "The ingredient is not in the pantry", body = ErrorMessageWithString),
+        (status = 500, description = "The pantry item could not be updated", body = ErrorMessageWithString),
+    ),
+    security(("session" = []))
+)]
+#[tracing::instrument("[ROUTE] Updating a pantry item", skip(pantry_repository, user))]
+pub async fn update_pantry_item_route(
+    State(AppState {
+        pantry_repository, ..
+    }): State<AppState>,
+    CurrentUser(user): CurrentUser,
+    Path(ingredient_id): Path<Uuid>,
+    Json(body): Json<UpdatePantryItemDTO>,
+) -> Result<Json<PantryItemDTO>, UpdatePantryItemError> {
+    let result =
+        update_pantry_item(pantry_repository, &user.id, &ingredient_id, body.into()).await?;
+
+    Ok(Json(result.into()))
+}
@@ -1,5 +1,6 @@
 use axum::{
     extract::{Path, State},
+    http::header,
     response::IntoResponse,
 };
 use common::{IngredientAmountDTO, RecipeDTO};
@@ -7,7 +8,11 @@ use reqwest::StatusCode;
 use uuid::Uuid;
 
 use crate::{
-    api::{errors::MakeError, extract::Json, AppState},
+    api::{
+        errors::MakeError,
+        extract::{etag, CurrentUser, IfMatch, Json},
+        AppState,
+    },
     domain::{
         commands::recipes::ingredients::add::{
             add_ingredient_to_recipe, AddIngredientToRecipeError,
@@ -26,6 +31,8 @@ impl MakeError<String> for AddIngredientToRecipeError {
         match self {
             Self::GetIngredient(GetIngredientByIdError::NotFound(_)) => StatusCode::BAD_REQUEST,
             Self::GetRecipe(GetRecipeByIdError::NotFound(_)) => StatusCode::NOT_FOUND,
+            Self::Forbidden(_) => StatusCode::FORBIDDEN,
+            Self::Conflict(_) => StatusCode::PRECONDITION_FAILED,
             _ => StatusCode::INTERNAL_SERVER_ERROR,
         }
     }
@@ -40,23 +47,51 @@ impl IntoResponse for AddIngredientToRecipeError {
     }
 }
 
+#[utoipa::path(
+    post,
+    path = "/recipe/{id}/ingredient",
+    tag = "recipes",
+    params(
+        ("id" = Uuid, Path, description = "ID of the recipe"),
+        ("If-Match" = String, Header, description = "ETag of the version being changed, or `*` for any version"),
+    ),
+    request_body = IngredientAmountDTO,
+    responses(
+        (status = 200, description = "The recipe with the ingredient added", body = RecipeDTO, headers(("ETag" = String, description = "The new version of the recipe"))),
+        (status = 400, description = "The ingredient does not exist", body = ErrorMessageWithString),
+        (status = 401, description = "Not logged in", body = ErrorMessageWithString),
+        (status = 403, description = "The recipe belongs to someone else", body = ErrorMessageWithString),
+        (status = 404, description = "The recipe does not exist", body = ErrorMessageWithString),
+        (status = 412, description = "The recipe has been changed since it was fetched", body = ErrorMessageWithString),
+        (status = 428, description = "The If-Match header is missing", body = ErrorMessageWithString),
+        (status = 500, description = "The ingredient could not be added", body = ErrorMessageWithString),
+    ),
+    security(("session" = []))
+)]
 pub async fn add_ingredient_to_recipe_route(
     State(AppState {
         recipe_repository,
         ingredient_repository,
         ..
     }): State<AppState>,
+    CurrentUser(user): CurrentUser,
     Path(recipe_id): Path<Uuid>,
+    IfMatch(versions): IfMatch,
     Json(body): Json<IngredientAmountDTO>,
-) -> Result<Json<RecipeDTO>, AddIngredientToRecipeError> {
+) -> Result<impl IntoResponse, AddIngredientToRecipeError> {
     let ingredient_to_add = body.into();
     let result = add_ingredient_to_recipe(
         recipe_repository,
         ingredient_repository,
+        &user.id,
         &recipe_id,
         ingredient_to_add,
+        versions.as_deref(),
     )
     .await?;
 
-    Ok(Json(result.into()))
+    Ok((
+        [(header::ETAG, etag(&result.version()))],
+        Json(RecipeDTO::from(result)),
+    ))
 }
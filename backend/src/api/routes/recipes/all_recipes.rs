@@ -1,21 +1,60 @@
-use axum::{extract::State, Json};
-use common::RecipeDTO;
+use axum::{
+    extract::{Query, State},
+    response::IntoResponse,
+    Json,
+};
+use common::{PageParamsDTO, PagedDTO, RecipeDTO};
+use reqwest::StatusCode;
 
-use crate::domain::queries::recipes::get_by_id::GetRecipeError;
-use crate::{api::AppState, domain::queries::recipes::get_all::get_all_recipes};
+use crate::api::{errors::MakeError, AppState};
+use crate::domain::entities::pagination::PageRequest;
+use crate::domain::queries::recipes::get_all::{get_all_recipes, GetAllRecipesError};
 
+impl MakeError<String> for GetAllRecipesError {
+    fn get_kind(&self) -> String {
+        self.as_ref().to_string()
+    }
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::Pagination(_) => StatusCode::BAD_REQUEST,
+            Self::Unknown(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for GetAllRecipesError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/recipe",
+    tag = "recipes",
+    params(
+        ("limit" = Option<u32>, Query, description = "How many recipes to return, 20 by default and 100 at most"),
+        ("cursor" = Option<Uuid>, Query, description = "The `next_cursor` returned with the previous page"),
+        ("sort" = Option<String>, Query, description = "One of `name`, `created_at` or `updated_at`, the newest first by default"),
+    ),
+    responses(
+        (status = 200, description = "A page of recipes", body = PagedRecipesDTO),
+        (status = 400, description = "The pagination parameters are invalid", body = ErrorMessageWithString),
+        (status = 500, description = "The recipes could not be fetched", body = ErrorMessageWithString),
+    )
+)]
 #[tracing::instrument("[ROUTE] Getting all recipes", skip(recipe_repository))]
 pub async fn get_all_recipes_route(
     State(AppState {
         recipe_repository, ..
     }): State<AppState>,
-) -> Result<Json<Vec<RecipeDTO>>, GetRecipeError> {
-    let result: Vec<RecipeDTO> = get_all_recipes(recipe_repository)
-        .await
-        .map_err(|e| GetRecipeError::Unknown(e.into()))?
-        .into_iter()
-        .map(RecipeDTO::from)
-        .collect();
+    Query(params): Query<PageParamsDTO>,
+) -> Result<Json<PagedDTO<RecipeDTO>>, GetAllRecipesError> {
+    let request = PageRequest::try_from(params)?;
+    let result = get_all_recipes(recipe_repository, &request).await?;
 
-    Ok(axum::Json(result))
+    Ok(axum::Json(result.into()))
 }
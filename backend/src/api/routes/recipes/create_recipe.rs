@@ -3,7 +3,7 @@ use common::{CreateRecipeDTO, RecipeDTO};
 use reqwest::StatusCode;
 
 use crate::api::errors::MakeError;
-use crate::api::extract::Json;
+use crate::api::extract::{CurrentUser, Json};
 use crate::api::AppState;
 use crate::domain::commands::recipes::create::{create_recipe, CreateRecipe, CreateRecipeError};
 use crate::domain::entities::recipe::IngredientAmountData;
@@ -29,9 +29,22 @@ impl IntoResponse for CreateRecipeError {
     }
 }
 
+#[utoipa::path(
+    post,
+    path = "/recipe",
+    tag = "recipes",
+    request_body = CreateRecipeDTO,
+    responses(
+        (status = 201, description = "The recipe was created", body = RecipeDTO),
+        (status = 400, description = "Some of the ingredients do not exist", body = ErrorMessageWithString),
+        (status = 401, description = "Not logged in", body = ErrorMessageWithString),
+        (status = 500, description = "The recipe could not be created", body = ErrorMessageWithString),
+    ),
+    security(("session" = []))
+)]
 #[tracing::instrument(
     "[ROUTE] Creating a new recipe",
-    skip(ingredient_repository, recipe_repository)
+    skip(ingredient_repository, recipe_repository, user)
 )]
 pub async fn create_recipe_route(
     State(AppState {
@@ -39,6 +52,7 @@ pub async fn create_recipe_route(
         recipe_repository,
         ..
     }): State<AppState>,
+    CurrentUser(user): CurrentUser,
     Json(body): Json<CreateRecipeDTO>,
 ) -> Result<impl IntoResponse, CreateRecipeError> {
     let ingredients: Vec<IngredientAmountData> = body
@@ -65,9 +79,10 @@ pub async fn create_recipe_route(
         ingredients,
     };
 
-    let result: RecipeDTO = create_recipe(recipe_repository, ingredient_repository, &input)
-        .await?
-        .into();
+    let result: RecipeDTO =
+        create_recipe(recipe_repository, ingredient_repository, &user.id, &input)
+            .await?
+            .into();
 
     Ok((StatusCode::CREATED, Json(result)))
 }
@@ -6,7 +6,11 @@ use reqwest::StatusCode;
 use uuid::Uuid;
 
 use crate::{
-    api::{errors::MakeError, AppState},
+    api::{
+        errors::MakeError,
+        extract::{CurrentUser, IfMatch},
+        AppState,
+    },
     domain::commands::recipes::ingredients::delete::{
         delete_ingredient_from_recipe, DeleteIngredientFromRecipeError,
     },
@@ -19,6 +23,8 @@ impl MakeError<String> for DeleteIngredientFromRecipeError {
     fn get_status_code(&self) -> StatusCode {
         match self {
             DeleteIngredientFromRecipeError::RecipeNotFoundError(_) => StatusCode::NOT_FOUND,
+            DeleteIngredientFromRecipeError::Forbidden(_) => StatusCode::FORBIDDEN,
+            DeleteIngredientFromRecipeError::Conflict(_) => StatusCode::PRECONDITION_FAILED,
             DeleteIngredientFromRecipeError::RecipeHasNoIngredientError(_) => {
                 StatusCode::BAD_REQUEST
             }
@@ -39,13 +45,44 @@ impl IntoResponse for DeleteIngredientFromRecipeError {
     }
 }
 
+#[utoipa::path(
+    delete,
+    path = "/recipe/{recipe_id}/ingredient/{ingredient_id}",
+    tag = "recipes",
+    params(
+        ("recipe_id" = Uuid, Path, description = "ID of the recipe"),
+        ("ingredient_id" = Uuid, Path, description = "ID of the ingredient"),
+        ("If-Match" = String, Header, description = "ETag of the version being changed, or `*` for any version"),
+    ),
+    responses(
+        (status = 200, description = "The ingredient was removed from the recipe"),
+        (status = 400, description = "The ingredient is not in the recipe", body = ErrorMessageWithString),
+        (status = 401, description = "Not logged in", body = ErrorMessageWithString),
+        (status = 403, description = "The recipe belongs to someone else", body = ErrorMessageWithString),
+        (status = 404, description = "The recipe does not exist", body = ErrorMessageWithString),
+        (status = 412, description = "The recipe has been changed since it was fetched", body = ErrorMessageWithString),
+        (status = 422, description = "The ingredient is the last one in the recipe", body = ErrorMessageWithString),
+        (status = 428, description = "The If-Match header is missing", body = ErrorMessageWithString),
+        (status = 500, description = "The ingredient could not be removed", body = ErrorMessageWithString),
+    ),
+    security(("session" = []))
+)]
 pub async fn delete_ingredient_from_recipe_route(
     State(AppState {
         recipe_repository, ..
     }): State<AppState>,
+    CurrentUser(user): CurrentUser,
     Path((recipe_id, ingredient_id)): Path<(Uuid, Uuid)>,
+    IfMatch(versions): IfMatch,
 ) -> Result<(), DeleteIngredientFromRecipeError> {
-    delete_ingredient_from_recipe(recipe_repository, &recipe_id, &ingredient_id).await?;
+    delete_ingredient_from_recipe(
+        recipe_repository,
+        &user.id,
+        &recipe_id,
+        &ingredient_id,
+        versions.as_deref(),
+    )
+    .await?;
 
     Ok(())
 }
@@ -6,7 +6,11 @@ use reqwest::StatusCode;
 use uuid::Uuid;
 
 use crate::{
-    api::{errors::MakeError, AppState},
+    api::{
+        errors::MakeError,
+        extract::{CurrentUser, IfMatch},
+        AppState,
+    },
     domain::commands::recipes::delete::{delete_recipe, DeleteRecipeError},
 };
 
@@ -17,6 +21,8 @@ impl MakeError<String> for DeleteRecipeError {
     fn get_status_code(&self) -> StatusCode {
         match self {
             Self::NotFound(_) => StatusCode::NOT_FOUND,
+            Self::Forbidden(_) => StatusCode::FORBIDDEN,
+            Self::Conflict(_) => StatusCode::PRECONDITION_FAILED,
             _ => StatusCode::INTERNAL_SERVER_ERROR,
         }
     }
@@ -31,14 +37,35 @@ impl IntoResponse for DeleteRecipeError {
     }
 }
 
-#[tracing::instrument("[ROUTE] Deleting a recipe", skip(recipe_repository))]
+#[utoipa::path(
+    delete,
+    path = "/recipe/{id}",
+    tag = "recipes",
+    params(
+        ("id" = Uuid, Path, description = "ID of the recipe"),
+        ("If-Match" = String, Header, description = "ETag of the version being changed, or `*` for any version"),
+    ),
+    responses(
+        (status = 200, description = "The recipe was deleted"),
+        (status = 401, description = "Not logged in", body = ErrorMessageWithString),
+        (status = 403, description = "The recipe belongs to someone else", body = ErrorMessageWithString),
+        (status = 404, description = "The recipe does not exist", body = ErrorMessageWithString),
+        (status = 412, description = "The recipe has been changed since it was fetched", body = ErrorMessageWithString),
+        (status = 428, description = "The If-Match header is missing", body = ErrorMessageWithString),
+        (status = 500, description = "The recipe could not be deleted", body = ErrorMessageWithString),
+    ),
+    security(("session" = []))
+)]
+#[tracing::instrument("[ROUTE] Deleting a recipe", skip(recipe_repository, user))]
 pub async fn delete_recipe_route(
     State(AppState {
         recipe_repository, ..
     }): State<AppState>,
+    CurrentUser(user): CurrentUser,
     Path(recipe_id): Path<Uuid>,
+    IfMatch(versions): IfMatch,
 ) -> Result<(), DeleteRecipeError> {
-    delete_recipe(recipe_repository, &recipe_id).await?;
+    delete_recipe(recipe_repository, &user.id, &recipe_id, versions.as_deref()).await?;
 
     Ok(())
 }
@@ -0,0 +1,36 @@
+use axum::{
+    extract::{Path, Query, State},
+    Json,
+};
+use common::{DiffRevisionsParamsDTO, RecipeDiffDTO};
+use uuid::Uuid;
+
+use crate::api::AppState;
+use crate::domain::queries::recipes::revisions::{diff_revisions, GetRevisionsError};
+
+#[utoipa::path(
+    get,
+    path = "/recipe/{id}/revisions/diff",
+    tag = "recipes",
+    params(
+        ("id" = Uuid, Path, description = "ID of the recipe"),
+        DiffRevisionsParamsDTO,
+    ),
+    responses(
+        (status = 200, description = "How the recipe changed from one revision to the other", body = RecipeDiffDTO),
+        (status = 404, description = "The recipe or one of the revisions does not exist", body = ErrorMessageWithString),
+        (status = 500, description = "The revisions could not be compared", body = ErrorMessageWithString),
+    )
+)]
+#[tracing::instrument("[ROUTE] Diffing revisions of a recipe", skip(recipe_repository))]
+pub async fn diff_recipe_revisions_route(
+    State(AppState {
+        recipe_repository, ..
+    }): State<AppState>,
+    Path(recipe_id): Path<Uuid>,
+    Query(params): Query<DiffRevisionsParamsDTO>,
+) -> Result<Json<RecipeDiffDTO>, GetRevisionsError> {
+    let diff = diff_revisions(recipe_repository, &recipe_id, params.from, params.to).await?;
+
+    Ok(Json(diff.into()))
+}
@@ -0,0 +1,68 @@
+use std::str::FromStr;
+
+use axum::{
+    extract::{Path, Query, State},
+    http::header,
+    response::IntoResponse,
+};
+use common::ExportRecipeParamsDTO;
+use reqwest::StatusCode;
+use strum::VariantNames;
+use uuid::Uuid;
+
+use crate::api::{errors::MakeError, AppState};
+use crate::domain::entities::recipe::formats::ExportFormat;
+use crate::domain::queries::recipes::export::{export_recipe, ExportRecipeError};
+
+impl MakeError<String> for ExportRecipeError {
+    fn get_kind(&self) -> String {
+        self.as_ref().to_string()
+    }
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::NotFound(_) => StatusCode::NOT_FOUND,
+            Self::InvalidFormat(_) => StatusCode::BAD_REQUEST,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for ExportRecipeError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/recipe/{id}/export",
+    tag = "recipes",
+    params(
+        ("id" = Uuid, Path, description = "ID of the recipe"),
+        ExportRecipeParamsDTO,
+    ),
+    responses(
+        (status = 200, description = "The recipe as a schema.org JSON-LD document, a Markdown document or a Cooklang recipe", body = String, content_type = ["application/ld+json", "text/markdown", "text/plain"]),
+        (status = 400, description = "The format is invalid", body = ErrorMessageWithString),
+        (status = 404, description = "The recipe does not exist", body = ErrorMessageWithString),
+        (status = 500, description = "The recipe could not be exported", body = ErrorMessageWithString),
+    )
+)]
+#[tracing::instrument("[ROUTE] Exporting a recipe", skip(recipe_repository))]
+pub async fn export_recipe_route(
+    State(AppState {
+        recipe_repository, ..
+    }): State<AppState>,
+    Path(recipe_id): Path<Uuid>,
+    Query(params): Query<ExportRecipeParamsDTO>,
+) -> Result<impl IntoResponse, ExportRecipeError> {
+    let format = ExportFormat::from_str(&params.format)
+        .map_err(|_| ExportRecipeError::InvalidFormat(ExportFormat::VARIANTS))?;
+
+    let document = export_recipe(recipe_repository, &recipe_id, format).await?;
+
+    Ok(([(header::CONTENT_TYPE, format.content_type())], document))
+}
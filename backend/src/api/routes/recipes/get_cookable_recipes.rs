@@ -0,0 +1,61 @@
+use axum::{extract::State, response::IntoResponse};
+use chrono::Utc;
+use common::CookableRecipeDTO;
+use reqwest::StatusCode;
+
+use crate::api::errors::MakeError;
+use crate::api::extract::{CurrentUser, Json};
+use crate::api::AppState;
+use crate::domain::queries::recipes::cookable::{get_cookable_recipes, GetCookableRecipesError};
+
+impl MakeError<String> for GetCookableRecipesError {
+    fn get_kind(&self) -> String {
+        self.as_ref().to_string()
+    }
+    fn get_status_code(&self) -> StatusCode {
+        StatusCode::INTERNAL_SERVER_ERROR
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for GetCookableRecipesError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/recipe/cookable",
+    tag = "recipes",
+    responses(
+        (status = 200, description = "Every recipe, the ones the pantry of the current user covers best first, along with what is missing for each", body = Vec<CookableRecipeDTO>),
+        (status = 401, description = "Not logged in", body = ErrorMessageWithString),
+        (status = 500, description = "The recipes could not be ranked", body = ErrorMessageWithString),
+    ),
+    security(("session" = []))
+)]
+#[tracing::instrument(
+    "[ROUTE] Getting cookable recipes",
+    skip(recipe_repository, pantry_repository, user)
+)]
+pub async fn get_cookable_recipes_route(
+    State(AppState {
+        recipe_repository,
+        pantry_repository,
+        ..
+    }): State<AppState>,
+    CurrentUser(user): CurrentUser,
+) -> Result<Json<Vec<CookableRecipeDTO>>, GetCookableRecipesError> {
+    let result = get_cookable_recipes(
+        recipe_repository,
+        pantry_repository,
+        &user.id,
+        Utc::now().date_naive(),
+    )
+    .await?;
+
+    Ok(Json(result.into_iter().map(Into::into).collect()))
+}
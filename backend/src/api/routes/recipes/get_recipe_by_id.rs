@@ -1,13 +1,18 @@
+use std::str::FromStr;
+
 use axum::{
-    extract::{Path, State},
+    extract::{Path, Query, State},
+    http::header,
     response::IntoResponse,
     Json,
 };
-use common::RecipeDTO;
+use common::{RecipeDTO, RecipeViewParamsDTO};
 use reqwest::StatusCode;
+use strum::VariantNames;
 use uuid::Uuid;
 
-use crate::api::{errors::MakeError, AppState};
+use crate::api::{errors::MakeError, extract::etag, AppState};
+use crate::domain::entities::recipe::units::UnitSystem;
 use crate::domain::queries::recipes::get_by_id::{get_recipe_by_id, GetRecipeError};
 
 impl MakeError<String> for GetRecipeError {
@@ -17,6 +22,7 @@ impl MakeError<String> for GetRecipeError {
     fn get_status_code(&self) -> StatusCode {
         match self {
             Self::NotFound(_) => StatusCode::NOT_FOUND,
+            Self::InvalidUnitSystem(_) => StatusCode::BAD_REQUEST,
             _ => StatusCode::INTERNAL_SERVER_ERROR,
         }
     }
@@ -31,14 +37,44 @@ impl IntoResponse for GetRecipeError {
     }
 }
 
+#[utoipa::path(
+    get,
+    path = "/recipe/{id}",
+    tag = "recipes",
+    params(
+        ("id" = Uuid, Path, description = "ID of the recipe"),
+        RecipeViewParamsDTO,
+    ),
+    responses(
+        (status = 200, description = "The recipe", body = RecipeDTO, headers(("ETag" = String, description = "The current version of the recipe"))),
+        (status = 400, description = "The unit system is invalid", body = ErrorMessageWithString),
+        (status = 404, description = "The recipe does not exist", body = ErrorMessageWithString),
+        (status = 500, description = "The recipe could not be fetched", body = ErrorMessageWithString),
+    )
+)]
 #[tracing::instrument("[ROUTE] Getting a recipe by ID", skip(recipe_repository))]
 pub async fn get_recipe_by_id_route(
     State(AppState {
         recipe_repository, ..
     }): State<AppState>,
     Path(recipe_id): Path<Uuid>,
-) -> Result<Json<RecipeDTO>, GetRecipeError> {
-    let result = get_recipe_by_id(recipe_repository, &recipe_id).await?;
+    Query(params): Query<RecipeViewParamsDTO>,
+) -> Result<impl IntoResponse, GetRecipeError> {
+    let units = params
+        .units
+        .map(|units| UnitSystem::from_str(&units))
+        .transpose()
+        .map_err(|_| GetRecipeError::InvalidUnitSystem(UnitSystem::VARIANTS))?;
+
+    let mut result = get_recipe_by_id(recipe_repository, &recipe_id).await?;
+    let version = result.version();
+
+    if let Some(units) = units {
+        result = result.to_unit_system(units);
+    }
 
-    Ok(axum::Json(result.into()))
+    Ok((
+        [(header::ETAG, etag(&version))],
+        Json(RecipeDTO::from(result)),
+    ))
 }
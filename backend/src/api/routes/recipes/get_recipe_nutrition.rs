@@ -0,0 +1,61 @@
+use axum::{
+    extract::{Path, State},
+    response::IntoResponse,
+};
+use common::RecipeNutritionDTO;
+use reqwest::StatusCode;
+use uuid::Uuid;
+
+use crate::api::{errors::MakeError, extract::Json, AppState};
+use crate::domain::queries::recipes::nutrition::{get_recipe_nutrition, GetRecipeNutritionError};
+
+impl MakeError<String> for GetRecipeNutritionError {
+    fn get_kind(&self) -> String {
+        self.as_ref().to_string()
+    }
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::NotFound(_) => StatusCode::NOT_FOUND,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for GetRecipeNutritionError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/recipe/{id}/nutrition",
+    tag = "recipes",
+    params(
+        ("id" = Uuid, Path, description = "ID of the recipe"),
+    ),
+    responses(
+        (status = 200, description = "The nutrition facts of the whole recipe and of a serving, along with the ingredients which could not be counted", body = RecipeNutritionDTO),
+        (status = 404, description = "The recipe does not exist", body = ErrorMessageWithString),
+        (status = 500, description = "The nutrition facts could not be computed", body = ErrorMessageWithString),
+    )
+)]
+#[tracing::instrument(
+    "[ROUTE] Getting nutrition of a recipe",
+    skip(recipe_repository, ingredient_repository)
+)]
+pub async fn get_recipe_nutrition_route(
+    State(AppState {
+        recipe_repository,
+        ingredient_repository,
+        ..
+    }): State<AppState>,
+    Path(recipe_id): Path<Uuid>,
+) -> Result<Json<RecipeNutritionDTO>, GetRecipeNutritionError> {
+    let result = get_recipe_nutrition(recipe_repository, ingredient_repository, &recipe_id).await?;
+
+    Ok(Json(result.into()))
+}
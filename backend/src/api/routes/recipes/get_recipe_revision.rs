@@ -0,0 +1,35 @@
+use axum::{
+    extract::{Path, State},
+    Json,
+};
+use common::RecipeRevisionDetailsDTO;
+use uuid::Uuid;
+
+use crate::api::AppState;
+use crate::domain::queries::recipes::revisions::{get_revision, GetRevisionsError};
+
+#[utoipa::path(
+    get,
+    path = "/recipe/{id}/revisions/{revision}",
+    tag = "recipes",
+    params(
+        ("id" = Uuid, Path, description = "ID of the recipe"),
+        ("revision" = i32, Path, description = "Number of the revision"),
+    ),
+    responses(
+        (status = 200, description = "The revision, along with how the recipe looked after it", body = RecipeRevisionDetailsDTO),
+        (status = 404, description = "The recipe or the revision does not exist", body = ErrorMessageWithString),
+        (status = 500, description = "The revision could not be fetched", body = ErrorMessageWithString),
+    )
+)]
+#[tracing::instrument("[ROUTE] Getting a revision of a recipe", skip(recipe_repository))]
+pub async fn get_recipe_revision_route(
+    State(AppState {
+        recipe_repository, ..
+    }): State<AppState>,
+    Path((recipe_id, revision)): Path<(Uuid, i32)>,
+) -> Result<Json<RecipeRevisionDetailsDTO>, GetRevisionsError> {
+    let revision = get_revision(recipe_repository, &recipe_id, revision).await?;
+
+    Ok(Json(revision.into()))
+}
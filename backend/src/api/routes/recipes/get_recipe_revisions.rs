@@ -0,0 +1,55 @@
+use axum::{
+    extract::{Path, State},
+    response::IntoResponse,
+    Json,
+};
+use common::RecipeRevisionDTO;
+use reqwest::StatusCode;
+use uuid::Uuid;
+
+use crate::api::{errors::MakeError, AppState};
+use crate::domain::queries::recipes::revisions::{get_revisions, GetRevisionsError};
+
+impl MakeError<String> for GetRevisionsError {
+    fn get_kind(&self) -> String {
+        self.as_ref().to_string()
+    }
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::NotFound(_) | Self::RevisionNotFound(_) => StatusCode::NOT_FOUND,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for GetRevisionsError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/recipe/{id}/revisions",
+    tag = "recipes",
+    params(("id" = Uuid, Path, description = "ID of the recipe")),
+    responses(
+        (status = 200, description = "Every revision of the recipe, the oldest one first", body = [RecipeRevisionDTO]),
+        (status = 404, description = "The recipe does not exist", body = ErrorMessageWithString),
+        (status = 500, description = "The revisions could not be fetched", body = ErrorMessageWithString),
+    )
+)]
+#[tracing::instrument("[ROUTE] Getting the revisions of a recipe", skip(recipe_repository))]
+pub async fn get_recipe_revisions_route(
+    State(AppState {
+        recipe_repository, ..
+    }): State<AppState>,
+    Path(recipe_id): Path<Uuid>,
+) -> Result<Json<Vec<RecipeRevisionDTO>>, GetRevisionsError> {
+    let revisions = get_revisions(recipe_repository, &recipe_id).await?;
+
+    Ok(Json(revisions.iter().map(Into::into).collect()))
+}
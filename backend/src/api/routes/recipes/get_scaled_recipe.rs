@@ -0,0 +1,61 @@
+use axum::{
+    extract::{Path, Query, State},
+    response::IntoResponse,
+    Json,
+};
+use common::{RecipeDTO, ScaleRecipeParamsDTO};
+use reqwest::StatusCode;
+use uuid::Uuid;
+
+use crate::api::{errors::MakeError, AppState};
+use crate::domain::queries::recipes::get_scaled::{get_scaled_recipe, GetScaledRecipeError};
+
+impl MakeError<String> for GetScaledRecipeError {
+    fn get_kind(&self) -> String {
+        self.as_ref().to_string()
+    }
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::NotFound(_) => StatusCode::NOT_FOUND,
+            Self::InvalidServings => StatusCode::BAD_REQUEST,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for GetScaledRecipeError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/recipe/{id}/scaled",
+    tag = "recipes",
+    params(
+        ("id" = Uuid, Path, description = "ID of the recipe"),
+        ScaleRecipeParamsDTO,
+    ),
+    responses(
+        (status = 200, description = "The recipe with its ingredients scaled to the servings", body = RecipeDTO),
+        (status = 400, description = "The amount of servings is invalid", body = ErrorMessageWithString),
+        (status = 404, description = "The recipe does not exist", body = ErrorMessageWithString),
+        (status = 500, description = "The recipe could not be scaled", body = ErrorMessageWithString),
+    )
+)]
+#[tracing::instrument("[ROUTE] Getting a scaled recipe", skip(recipe_repository))]
+pub async fn get_scaled_recipe_route(
+    State(AppState {
+        recipe_repository, ..
+    }): State<AppState>,
+    Path(recipe_id): Path<Uuid>,
+    Query(ScaleRecipeParamsDTO { servings }): Query<ScaleRecipeParamsDTO>,
+) -> Result<Json<RecipeDTO>, GetScaledRecipeError> {
+    let result = get_scaled_recipe(recipe_repository, &recipe_id, servings).await?;
+
+    Ok(axum::Json(result.into()))
+}
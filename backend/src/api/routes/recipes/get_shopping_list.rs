@@ -0,0 +1,56 @@
+use axum::{extract::State, response::IntoResponse};
+use common::{CreateShoppingListDTO, ShoppingListDTO};
+use reqwest::StatusCode;
+
+use crate::api::{errors::MakeError, extract::Json, AppState};
+use crate::domain::queries::recipes::shopping_list::{
+    get_shopping_list, GetShoppingListError, ShoppingListRecipe,
+};
+
+impl MakeError<String> for GetShoppingListError {
+    fn get_kind(&self) -> String {
+        self.as_ref().to_string()
+    }
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::NotFound(_) => StatusCode::NOT_FOUND,
+            Self::NoRecipes | Self::InvalidServings => StatusCode::BAD_REQUEST,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for GetShoppingListError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/shopping-list",
+    tag = "recipes",
+    request_body = CreateShoppingListDTO,
+    responses(
+        (status = 200, description = "Everything needed for the recipes, with each ingredient listed once", body = ShoppingListDTO),
+        (status = 400, description = "There are no recipes, or the amount of servings is invalid", body = ErrorMessageWithString),
+        (status = 404, description = "One of the recipes does not exist", body = ErrorMessageWithString),
+        (status = 500, description = "The shopping list could not be made", body = ErrorMessageWithString),
+    )
+)]
+#[tracing::instrument("[ROUTE] Making a shopping list", skip(recipe_repository))]
+pub async fn get_shopping_list_route(
+    State(AppState {
+        recipe_repository, ..
+    }): State<AppState>,
+    Json(body): Json<CreateShoppingListDTO>,
+) -> Result<Json<ShoppingListDTO>, GetShoppingListError> {
+    let recipes: Vec<ShoppingListRecipe> = body.recipes.into_iter().map(Into::into).collect();
+
+    let result = get_shopping_list(recipe_repository, &recipes).await?;
+
+    Ok(Json(result.into()))
+}
@@ -0,0 +1,52 @@
+use axum::{extract::State, response::IntoResponse};
+use common::ImportedRecipeDTO;
+use reqwest::StatusCode;
+
+use crate::api::extract::{CurrentUser, Json};
+use crate::api::AppState;
+use crate::domain::commands::recipes::import::{import_recipe, ImportRecipeError};
+use crate::domain::entities::recipe::formats::cooklang;
+
+#[utoipa::path(
+    post,
+    path = "/recipe/import/cooklang",
+    tag = "recipes",
+    request_body(
+        content = String,
+        content_type = "text/plain",
+        description = "A Cooklang recipe, titled with `>> title: ...`"
+    ),
+    responses(
+        (status = 201, description = "The recipe was imported, along with the ingredients which could not be", body = ImportedRecipeDTO),
+        (status = 400, description = "The document does not contain a usable recipe", body = ErrorMessageWithString),
+        (status = 401, description = "Not logged in", body = ErrorMessageWithString),
+        (status = 500, description = "The recipe could not be imported", body = ErrorMessageWithString),
+    ),
+    security(("session" = []))
+)]
+#[tracing::instrument(
+    "[ROUTE] Importing a recipe from Cooklang",
+    skip(ingredient_repository, recipe_repository, user, body)
+)]
+pub async fn import_cooklang_recipe_route(
+    State(AppState {
+        ingredient_repository,
+        recipe_repository,
+        ..
+    }): State<AppState>,
+    CurrentUser(user): CurrentUser,
+    body: String,
+) -> Result<impl IntoResponse, ImportRecipeError> {
+    let imported = cooklang::parse(&body)?;
+
+    let result =
+        import_recipe(recipe_repository, ingredient_repository, &user.id, imported).await?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(ImportedRecipeDTO {
+            recipe: result.recipe.into(),
+            unparsed_ingredients: result.unparsed_ingredients,
+        }),
+    ))
+}
@@ -0,0 +1,74 @@
+use axum::{extract::State, response::IntoResponse};
+use common::ImportedRecipeDTO;
+use reqwest::StatusCode;
+
+use crate::api::errors::MakeError;
+use crate::api::extract::{CurrentUser, Json};
+use crate::api::AppState;
+use crate::domain::commands::recipes::import::{import_recipe, ImportRecipeError};
+use crate::domain::entities::recipe::formats::jsonld;
+
+impl MakeError<String> for ImportRecipeError {
+    fn get_kind(&self) -> String {
+        self.as_ref().to_string()
+    }
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::JsonLd(_) | Self::Cooklang(_) | Self::Validation(_) => StatusCode::BAD_REQUEST,
+            Self::Unknown(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for ImportRecipeError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/recipe/import/jsonld",
+    tag = "recipes",
+    request_body(
+        content = String,
+        content_type = "application/ld+json",
+        description = "A schema.org recipe, or an HTML page embedding one"
+    ),
+    responses(
+        (status = 201, description = "The recipe was imported, along with the ingredient lines which could not be", body = ImportedRecipeDTO),
+        (status = 400, description = "The document does not contain a usable recipe", body = ErrorMessageWithString),
+        (status = 401, description = "Not logged in", body = ErrorMessageWithString),
+        (status = 500, description = "The recipe could not be imported", body = ErrorMessageWithString),
+    ),
+    security(("session" = []))
+)]
+#[tracing::instrument(
+    "[ROUTE] Importing a recipe from JSON-LD",
+    skip(ingredient_repository, recipe_repository, user, body)
+)]
+pub async fn import_jsonld_recipe_route(
+    State(AppState {
+        ingredient_repository,
+        recipe_repository,
+        ..
+    }): State<AppState>,
+    CurrentUser(user): CurrentUser,
+    body: String,
+) -> Result<impl IntoResponse, ImportRecipeError> {
+    let imported = jsonld::parse(&body)?;
+
+    let result =
+        import_recipe(recipe_repository, ingredient_repository, &user.id, imported).await?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(ImportedRecipeDTO {
+            recipe: result.recipe.into(),
+            unparsed_ingredients: result.unparsed_ingredients,
+        }),
+    ))
+}
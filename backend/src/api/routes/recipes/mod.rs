@@ -1,17 +1,43 @@
 mod add_ingredient_to_recipe;
+mod all_recipes;
 mod create_recipe;
 mod delete_ingredient_from_recipe;
 mod delete_recipe;
+mod diff_recipe_revisions;
+mod export_recipe;
+mod get_cookable_recipes;
 mod get_recipe_by_id;
+mod get_recipe_nutrition;
+mod get_recipe_revision;
+mod get_recipe_revisions;
+mod get_scaled_recipe;
+mod get_shopping_list;
+mod import_cooklang_recipe;
+mod import_jsonld_recipe;
+mod revert_recipe;
+mod search_recipes;
+mod suggest_substitutions;
 mod update_ingredient_in_recipe;
 mod update_recipe;
-mod all_recipes;
 
 pub use add_ingredient_to_recipe::*;
-pub use create_recipe::create_recipe_route;
+pub use all_recipes::*;
+pub use create_recipe::*;
 pub use delete_ingredient_from_recipe::*;
 pub use delete_recipe::*;
+pub use diff_recipe_revisions::*;
+pub use export_recipe::*;
+pub use get_cookable_recipes::*;
 pub use get_recipe_by_id::*;
+pub use get_recipe_nutrition::*;
+pub use get_recipe_revision::*;
+pub use get_recipe_revisions::*;
+pub use get_scaled_recipe::*;
+pub use get_shopping_list::*;
+pub use import_cooklang_recipe::*;
+pub use import_jsonld_recipe::*;
+pub use revert_recipe::*;
+pub use search_recipes::*;
+pub use suggest_substitutions::*;
 pub use update_ingredient_in_recipe::*;
 pub use update_recipe::*;
-pub use all_recipes::*;
@@ -0,0 +1,87 @@
+use axum::extract::{Path, State};
+use axum::http::header;
+use axum::response::IntoResponse;
+use common::RecipeDTO;
+use reqwest::StatusCode;
+use uuid::Uuid;
+
+use crate::api::errors::MakeError;
+use crate::api::extract::{etag, CurrentUser, IfMatch, Json};
+use crate::api::AppState;
+use crate::domain::commands::recipes::revert::{revert_recipe, RevertRecipeError};
+
+impl MakeError<String> for RevertRecipeError {
+    fn get_kind(&self) -> String {
+        self.as_ref().to_string()
+    }
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::NotFound(_) | Self::RevisionNotFound(_) => StatusCode::NOT_FOUND,
+            Self::Forbidden(_) => StatusCode::FORBIDDEN,
+            Self::IngredientsMissing(_) => StatusCode::CONFLICT,
+            Self::ValidationError(_) => StatusCode::BAD_REQUEST,
+            Self::Conflict(_) => StatusCode::PRECONDITION_FAILED,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for RevertRecipeError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/recipe/{id}/revisions/{revision}/revert",
+    tag = "recipes",
+    params(
+        ("id" = Uuid, Path, description = "ID of the recipe"),
+        ("revision" = i32, Path, description = "Number of the revision to go back to"),
+        ("If-Match" = String, Header, description = "ETag of the version being changed, or `*` for any version"),
+    ),
+    responses(
+        (status = 200, description = "The recipe as it was in the revision", body = RecipeDTO, headers(("ETag" = String, description = "The new version of the recipe"))),
+        (status = 401, description = "Not logged in", body = ErrorMessageWithString),
+        (status = 403, description = "The recipe belongs to someone else", body = ErrorMessageWithString),
+        (status = 404, description = "The recipe or the revision does not exist", body = ErrorMessageWithString),
+        (status = 409, description = "Some of the ingredients of the revision have been deleted since", body = ErrorMessageWithString),
+        (status = 412, description = "The recipe has been changed since it was fetched", body = ErrorMessageWithString),
+        (status = 428, description = "The If-Match header is missing", body = ErrorMessageWithString),
+        (status = 500, description = "The recipe could not be reverted", body = ErrorMessageWithString),
+    ),
+    security(("session" = []))
+)]
+#[tracing::instrument(
+    "[ROUTE] Reverting a recipe",
+    skip(recipe_repository, ingredient_repository, user)
+)]
+pub async fn revert_recipe_route(
+    State(AppState {
+        recipe_repository,
+        ingredient_repository,
+        ..
+    }): State<AppState>,
+    CurrentUser(user): CurrentUser,
+    Path((recipe_id, revision)): Path<(Uuid, i32)>,
+    IfMatch(versions): IfMatch,
+) -> Result<impl IntoResponse, RevertRecipeError> {
+    let recipe = revert_recipe(
+        recipe_repository,
+        ingredient_repository,
+        &user.id,
+        &recipe_id,
+        revision,
+        versions.as_deref(),
+    )
+    .await?;
+
+    Ok((
+        [(header::ETAG, etag(&recipe.version()))],
+        Json(RecipeDTO::from(recipe)),
+    ))
+}
@@ -0,0 +1,67 @@
+use axum::{
+    extract::{Query, State},
+    response::IntoResponse,
+    Json,
+};
+use common::{RecipeDTO, RecipeSearchParamsDTO};
+use reqwest::StatusCode;
+
+use crate::api::{errors::MakeError, AppState};
+use crate::domain::entities::recipe::RecipeSearchQuery;
+use crate::domain::queries::recipes::search::{search_recipes, SearchRecipesError};
+
+impl MakeError<String> for SearchRecipesError {
+    fn get_kind(&self) -> String {
+        self.as_ref().to_string()
+    }
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::InvalidIngredientId(_) => StatusCode::BAD_REQUEST,
+            Self::ValidationError(_) => StatusCode::BAD_REQUEST,
+            Self::Unknown(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for SearchRecipesError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/recipe/search",
+    tag = "recipes",
+    params(RecipeSearchParamsDTO),
+    responses(
+        (status = 200, description = "Up to `limit` of the matching recipes, the most relevant ones first", body = Vec<RecipeDTO>),
+        (status = 400, description = "The search parameters are invalid", body = ErrorMessageWithString),
+        (status = 500, description = "The recipes could not be searched", body = ErrorMessageWithString),
+    )
+)]
+#[tracing::instrument(
+    "[ROUTE] Searching recipes",
+    skip(recipe_repository, ingredient_repository)
+)]
+pub async fn search_recipes_route(
+    State(AppState {
+        recipe_repository,
+        ingredient_repository,
+        ..
+    }): State<AppState>,
+    Query(params): Query<RecipeSearchParamsDTO>,
+) -> Result<Json<Vec<RecipeDTO>>, SearchRecipesError> {
+    let query = RecipeSearchQuery::try_from(params)?;
+
+    let result = search_recipes(recipe_repository, ingredient_repository, &query)
+        .await?
+        .into_iter()
+        .map(RecipeDTO::from)
+        .collect();
+
+    Ok(axum::Json(result))
+}
@@ -0,0 +1,70 @@
+use axum::{
+    extract::{Path, Query, State},
+    response::IntoResponse,
+    Json,
+};
+use common::{SubstitutionParamsDTO, SubstitutionSuggestionDTO};
+use reqwest::StatusCode;
+use uuid::Uuid;
+
+use crate::api::{errors::MakeError, AppState};
+use crate::domain::queries::recipes::suggest_substitutions::{
+    parse_diets, suggest_substitutions, SuggestSubstitutionsError,
+};
+
+impl MakeError<String> for SuggestSubstitutionsError {
+    fn get_kind(&self) -> String {
+        self.as_ref().to_string()
+    }
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::NotFound(_) => StatusCode::NOT_FOUND,
+            Self::NoDiets | Self::ValidationError(_) => StatusCode::BAD_REQUEST,
+            Self::Unknown(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for SuggestSubstitutionsError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/recipe/{id}/substitutions",
+    tag = "recipes",
+    params(
+        ("id" = Uuid, Path, description = "ID of the recipe"),
+        SubstitutionParamsDTO,
+    ),
+    responses(
+        (status = 200, description = "The ingredients violating the diets, together with the substitutes that don't", body = Vec<SubstitutionSuggestionDTO>),
+        (status = 400, description = "No diets were given, or some of them are invalid", body = ErrorMessageWithString),
+        (status = 404, description = "The recipe does not exist", body = ErrorMessageWithString),
+        (status = 500, description = "The substitutions could not be suggested", body = ErrorMessageWithString),
+    )
+)]
+#[tracing::instrument(
+    "[ROUTE] Suggesting substitutions for a recipe",
+    skip(recipe_repository, ingredient_repository)
+)]
+pub async fn suggest_substitutions_route(
+    State(AppState {
+        recipe_repository,
+        ingredient_repository,
+        ..
+    }): State<AppState>,
+    Path(recipe_id): Path<Uuid>,
+    Query(params): Query<SubstitutionParamsDTO>,
+) -> Result<Json<Vec<SubstitutionSuggestionDTO>>, SuggestSubstitutionsError> {
+    let diets = parse_diets(params)?;
+    let result =
+        suggest_substitutions(recipe_repository, ingredient_repository, &recipe_id, &diets).await?;
+
+    Ok(axum::Json(result.into_iter().map(Into::into).collect()))
+}
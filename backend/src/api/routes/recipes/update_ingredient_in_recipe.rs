@@ -1,5 +1,6 @@
 use axum::{
     extract::{Path, State},
+    http::header,
     response::IntoResponse,
 };
 use common::{IngredientUnitDTO, RecipeDTO};
@@ -7,7 +8,11 @@ use reqwest::StatusCode;
 use uuid::Uuid;
 
 use crate::{
-    api::{errors::MakeError, extract::Json, AppState},
+    api::{
+        errors::MakeError,
+        extract::{etag, CurrentUser, IfMatch, Json},
+        AppState,
+    },
     domain::{
         commands::recipes::ingredients::update::{
             update_ingredient_in_recipe, UpdateIngredientInRecipeError,
@@ -27,6 +32,8 @@ impl MakeError<String> for UpdateIngredientInRecipeError {
             | UpdateIngredientInRecipeError::GetRecipe(GetRecipeByIdError::NotFound(_)) => {
                 StatusCode::NOT_FOUND
             }
+            UpdateIngredientInRecipeError::Forbidden(_) => StatusCode::FORBIDDEN,
+            UpdateIngredientInRecipeError::Conflict(_) => StatusCode::PRECONDITION_FAILED,
             _ => StatusCode::INTERNAL_SERVER_ERROR,
         }
     }
@@ -41,16 +48,49 @@ impl IntoResponse for UpdateIngredientInRecipeError {
     }
 }
 
+#[utoipa::path(
+    put,
+    path = "/recipe/{recipe_id}/ingredient/{ingredient_id}",
+    tag = "recipes",
+    params(
+        ("recipe_id" = Uuid, Path, description = "ID of the recipe"),
+        ("ingredient_id" = Uuid, Path, description = "ID of the ingredient"),
+        ("If-Match" = String, Header, description = "ETag of the version being changed, or `*` for any version"),
+    ),
+    request_body = IngredientUnitDTO,
+    responses(
+        (status = 200, description = "The recipe with the new amount of the ingredient", body = RecipeDTO, headers(("ETag" = String, description = "The new version of the recipe"))),
+        (status = 401, description = "Not logged in", body = ErrorMessageWithString),
+        (status = 403, description = "The recipe belongs to someone else", body = ErrorMessageWithString),
+        (status = 404, description = "The recipe does not exist, or the ingredient is not in it", body = ErrorMessageWithString),
+        (status = 412, description = "The recipe has been changed since it was fetched", body = ErrorMessageWithString),
+        (status = 428, description = "The If-Match header is missing", body = ErrorMessageWithString),
+        (status = 500, description = "The amount could not be changed", body = ErrorMessageWithString),
+    ),
+    security(("session" = []))
+)]
 pub async fn update_ingredient_in_recipe_route(
     State(AppState {
         recipe_repository, ..
     }): State<AppState>,
+    CurrentUser(user): CurrentUser,
     Path((recipe_id, ingredient_id)): Path<(Uuid, Uuid)>,
+    IfMatch(versions): IfMatch,
     Json(body): Json<IngredientUnitDTO>,
-) -> Result<Json<RecipeDTO>, UpdateIngredientInRecipeError> {
+) -> Result<impl IntoResponse, UpdateIngredientInRecipeError> {
     let amount: IngredientUnit = body.into();
-    let recipe =
-        update_ingredient_in_recipe(recipe_repository, &recipe_id, &ingredient_id, amount).await?;
+    let recipe = update_ingredient_in_recipe(
+        recipe_repository,
+        &user.id,
+        &recipe_id,
+        &ingredient_id,
+        amount,
+        versions.as_deref(),
+    )
+    .await?;
 
-    Ok(Json(recipe.into()))
+    Ok((
+        [(header::ETAG, etag(&recipe.version()))],
+        Json(RecipeDTO::from(recipe)),
+    ))
 }
@@ -1,11 +1,12 @@
 use axum::extract::Path;
+use axum::http::header;
 use axum::{extract::State, response::IntoResponse};
 use common::{RecipeDTO, UpdateRecipeDTO};
 use reqwest::StatusCode;
 use uuid::Uuid;
 
 use crate::api::errors::MakeError;
-use crate::api::extract::Json;
+use crate::api::extract::{etag, CurrentUser, IfMatch, Json};
 use crate::api::AppState;
 use crate::domain::commands::recipes::update::{update_recipe, UpdateRecipeError};
 
@@ -16,8 +17,10 @@ impl MakeError<String> for UpdateRecipeError {
     fn get_status_code(&self) -> StatusCode {
         match self {
             Self::NotFound(_) => StatusCode::NOT_FOUND,
+            Self::Forbidden(_) => StatusCode::FORBIDDEN,
             Self::ValidationError(_) => StatusCode::BAD_REQUEST,
             Self::ChangesetEmpty => StatusCode::BAD_REQUEST,
+            Self::Conflict(_) => StatusCode::PRECONDITION_FAILED,
             _ => StatusCode::INTERNAL_SERVER_ERROR,
         }
     }
@@ -32,15 +35,48 @@ impl IntoResponse for UpdateRecipeError {
     }
 }
 
-#[tracing::instrument("[ROUTE] Creating a new recipe", skip(recipe_repository))]
+#[utoipa::path(
+    put,
+    path = "/recipe/{id}",
+    tag = "recipes",
+    params(
+        ("id" = Uuid, Path, description = "ID of the recipe"),
+        ("If-Match" = String, Header, description = "ETag of the version being changed, or `*` for any version"),
+    ),
+    request_body = UpdateRecipeDTO,
+    responses(
+        (status = 200, description = "The updated recipe", body = RecipeDTO, headers(("ETag" = String, description = "The new version of the recipe"))),
+        (status = 400, description = "Nothing to change, or the changes are invalid", body = ErrorMessageWithString),
+        (status = 401, description = "Not logged in", body = ErrorMessageWithString),
+        (status = 403, description = "The recipe belongs to someone else", body = ErrorMessageWithString),
+        (status = 404, description = "The recipe does not exist", body = ErrorMessageWithString),
+        (status = 412, description = "The recipe has been changed since it was fetched", body = ErrorMessageWithString),
+        (status = 428, description = "The If-Match header is missing", body = ErrorMessageWithString),
+        (status = 500, description = "The recipe could not be updated", body = ErrorMessageWithString),
+    ),
+    security(("session" = []))
+)]
+#[tracing::instrument("[ROUTE] Creating a new recipe", skip(recipe_repository, user))]
 pub async fn update_recipe_route(
     State(AppState {
         recipe_repository, ..
     }): State<AppState>,
+    CurrentUser(user): CurrentUser,
     Path(recipe_id): Path<Uuid>,
+    IfMatch(versions): IfMatch,
     Json(body): Json<UpdateRecipeDTO>,
-) -> Result<Json<RecipeDTO>, UpdateRecipeError> {
-    let recipe = update_recipe(recipe_repository, &recipe_id, body.into()).await?;
+) -> Result<impl IntoResponse, UpdateRecipeError> {
+    let recipe = update_recipe(
+        recipe_repository,
+        &user.id,
+        &recipe_id,
+        body.into(),
+        versions.as_deref(),
+    )
+    .await?;
 
-    Ok(Json(recipe.into()))
+    Ok((
+        [(header::ETAG, etag(&recipe.version()))],
+        Json(RecipeDTO::from(recipe)),
+    ))
 }
@@ -0,0 +1,71 @@
+use axum::{extract::State, response::IntoResponse};
+use common::user::{LoginUserDTO, UserDataDTO};
+use reqwest::StatusCode;
+use tower_sessions::Session;
+
+use crate::{
+    api::{
+        errors::MakeError,
+        extract::{Json, USER_ID_SESSION_KEY},
+        AppState,
+    },
+    domain::commands::users::login::{login_user, LoginUser, LoginUserError},
+};
+
+impl MakeError<String> for LoginUserError {
+    fn get_kind(&self) -> String {
+        self.as_ref().to_string()
+    }
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::NotFound(_) => StatusCode::NOT_FOUND,
+            Self::WrongPassword => StatusCode::BAD_REQUEST,
+            Self::Internal(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for LoginUserError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/user/login",
+    tag = "users",
+    request_body = LoginUserDTO,
+    responses(
+        (status = 200, description = "The user was logged in", body = UserDataDTO),
+        (status = 400, description = "The password is wrong", body = ErrorMessageWithString),
+        (status = 404, description = "The user does not exist", body = ErrorMessageWithString),
+        (status = 500, description = "The user could not be logged in", body = ErrorMessageWithString),
+    )
+)]
+#[tracing::instrument("[ROUTE] Logging a user in", skip(user_repository, session, body))]
+pub async fn login_route(
+    State(AppState {
+        user_repository, ..
+    }): State<AppState>,
+    session: Session,
+    Json(body): Json<LoginUserDTO>,
+) -> Result<Json<UserDataDTO>, LoginUserError> {
+    let input = LoginUser {
+        username: &body.username,
+        password: &body.password,
+    };
+    let user = login_user(user_repository, &input).await?;
+
+    // Issue a fresh session ID on login, so a session ID obtained beforehand can't be reused
+    session.cycle_id().await.map_err(eyre::Error::from)?;
+    session
+        .insert(USER_ID_SESSION_KEY, user.id)
+        .await
+        .map_err(eyre::Error::from)?;
+
+    Ok(Json(user.into()))
+}
@@ -0,0 +1,25 @@
+use axum::response::IntoResponse;
+use reqwest::StatusCode;
+use tower_sessions::Session;
+
+use crate::api::errors::api::ApiError;
+
+#[utoipa::path(
+    post,
+    path = "/user/logout",
+    tag = "users",
+    responses(
+        (status = 204, description = "The user was logged out"),
+        (status = 500, description = "The session could not be removed", body = ErrorMessageWithString),
+    )
+)]
+#[tracing::instrument("[ROUTE] Logging a user out", skip(session))]
+pub async fn logout_route(session: Session) -> Result<impl IntoResponse, ApiError> {
+    session.flush().await.map_err(|e| ApiError {
+        kind: "SESSION",
+        status: StatusCode::INTERNAL_SERVER_ERROR,
+        message: e.to_string(),
+    })?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
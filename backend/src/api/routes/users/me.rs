@@ -0,0 +1,18 @@
+use common::user::UserDataDTO;
+
+use crate::api::extract::{CurrentUser, Json};
+
+#[utoipa::path(
+    get,
+    path = "/user/me",
+    tag = "users",
+    responses(
+        (status = 200, description = "The logged in user", body = UserDataDTO),
+        (status = 401, description = "Not logged in", body = ErrorMessageWithString),
+    ),
+    security(("session" = []))
+)]
+#[tracing::instrument("[ROUTE] Getting the logged in user", skip(user))]
+pub async fn me_route(CurrentUser(user): CurrentUser) -> Json<UserDataDTO> {
+    Json(user.into())
+}
@@ -0,0 +1,9 @@
+mod login;
+mod logout;
+mod me;
+mod signup;
+
+pub use login::*;
+pub use logout::*;
+pub use me::*;
+pub use signup::*;
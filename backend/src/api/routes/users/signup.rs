@@ -0,0 +1,72 @@
+use axum::{extract::State, response::IntoResponse};
+use common::user::{CreateNewUserDTO, UserDataDTO};
+use reqwest::StatusCode;
+use tower_sessions::Session;
+
+use crate::{
+    api::{
+        errors::MakeError,
+        extract::{Json, USER_ID_SESSION_KEY},
+        AppState,
+    },
+    domain::commands::users::create::{create_user, CreateUser, CreateUserError},
+};
+
+impl MakeError<String> for CreateUserError {
+    fn get_kind(&self) -> String {
+        self.as_ref().to_string()
+    }
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::EmptyField(_) | Self::InvalidFormat(_) => StatusCode::BAD_REQUEST,
+            Self::Conflict(_) => StatusCode::CONFLICT,
+            Self::Internal(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for CreateUserError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/user/signup",
+    tag = "users",
+    request_body = CreateNewUserDTO,
+    responses(
+        (status = 200, description = "The user was created and logged in", body = UserDataDTO),
+        (status = 400, description = "Some of the fields are empty or invalid", body = ErrorMessageWithString),
+        (status = 409, description = "The username or email is taken", body = ErrorMessageWithString),
+        (status = 500, description = "The user could not be created", body = ErrorMessageWithString),
+    )
+)]
+#[tracing::instrument("[ROUTE] Signing up a new user", skip(user_repository, session, body))]
+pub async fn signup_route(
+    State(AppState {
+        user_repository, ..
+    }): State<AppState>,
+    session: Session,
+    Json(body): Json<CreateNewUserDTO>,
+) -> Result<Json<UserDataDTO>, CreateUserError> {
+    let input = CreateUser {
+        username: &body.username,
+        email: &body.email,
+        password: &body.password,
+    };
+    let user = create_user(user_repository, &input).await?;
+
+    // Same as on login, a session ID obtained before signing up shouldn't carry over
+    session.cycle_id().await.map_err(eyre::Error::from)?;
+    session
+        .insert(USER_ID_SESSION_KEY, user.id)
+        .await
+        .map_err(eyre::Error::from)?;
+
+    Ok(Json(user.into()))
+}
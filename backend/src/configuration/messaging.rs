@@ -0,0 +1,186 @@
+use rdkafka::ClientConfig;
+use secrecy::{ExposeSecret, Secret};
+use serde::Deserialize;
+use serde_aux::field_attributes::deserialize_number_from_string;
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct MessagingSettings {
+    /// When disabled, messages are passed around inside the backend and never reach Kafka
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+    /// Comma-separated list of `host:port` pairs
+    pub brokers: String,
+    #[serde(default = "default_client_id")]
+    pub client_id: String,
+    /// Every instance of the backend in the same group shares the work of handling the messages
+    #[serde(default = "default_consumer_group")]
+    pub consumer_group: String,
+    #[serde(default)]
+    pub acks: Acks,
+    /// Prepended to the name of every topic, so that several environments can share a cluster
+    #[serde(default)]
+    pub topic_prefix: String,
+    /// Messages the handlers fail on end up here, with the original topic and the error as headers
+    #[serde(default = "default_dead_letter_topic")]
+    pub dead_letter_topic: String,
+    #[serde(
+        default = "default_message_timeout_ms",
+        deserialize_with = "deserialize_number_from_string"
+    )]
+    pub message_timeout_ms: u64,
+    #[serde(
+        default = "default_request_timeout_ms",
+        deserialize_with = "deserialize_number_from_string"
+    )]
+    pub request_timeout_ms: u64,
+    #[serde(
+        default = "default_session_timeout_ms",
+        deserialize_with = "deserialize_number_from_string"
+    )]
+    pub session_timeout_ms: u64,
+    #[serde(default)]
+    pub ssl: bool,
+    /// Certificate of the CA that signed the certificates of the brokers, if it's not one of the system ones
+    pub ssl_ca_location: Option<String>,
+    pub sasl: Option<SaslSettings>,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct SaslSettings {
+    pub mechanism: SaslMechanism,
+    pub username: String,
+    pub password: Secret<String>,
+}
+
+#[derive(Deserialize, Clone, Copy, Debug, PartialEq, strum::Display)]
+pub enum SaslMechanism {
+    #[serde(rename = "PLAIN")]
+    #[strum(serialize = "PLAIN")]
+    Plain,
+    #[serde(rename = "SCRAM-SHA-256")]
+    #[strum(serialize = "SCRAM-SHA-256")]
+    ScramSha256,
+    #[serde(rename = "SCRAM-SHA-512")]
+    #[strum(serialize = "SCRAM-SHA-512")]
+    ScramSha512,
+}
+
+/// How many brokers have to confirm a message before it's considered sent
+#[derive(Deserialize, Clone, Copy, Debug, Default, PartialEq, strum::Display)]
+#[serde(rename_all = "lowercase")]
+pub enum Acks {
+    #[strum(serialize = "0")]
+    None,
+    #[strum(serialize = "1")]
+    Leader,
+    #[default]
+    #[strum(serialize = "all")]
+    All,
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+fn default_client_id() -> String {
+    "deepdish-backend".to_string()
+}
+
+fn default_consumer_group() -> String {
+    "deepdish-backend".to_string()
+}
+
+fn default_dead_letter_topic() -> String {
+    "deepdish.dead_letter".to_string()
+}
+
+fn default_message_timeout_ms() -> u64 {
+    50_000
+}
+
+fn default_request_timeout_ms() -> u64 {
+    30_000
+}
+
+fn default_session_timeout_ms() -> u64 {
+    45_000
+}
+
+impl MessagingSettings {
+    /// Settings with nothing but the brokers set, everything else is left at its default
+    pub fn with_brokers(brokers: &str) -> Self {
+        Self {
+            enabled: default_enabled(),
+            brokers: brokers.to_string(),
+            client_id: default_client_id(),
+            consumer_group: default_consumer_group(),
+            acks: Acks::default(),
+            topic_prefix: String::new(),
+            dead_letter_topic: default_dead_letter_topic(),
+            message_timeout_ms: default_message_timeout_ms(),
+            request_timeout_ms: default_request_timeout_ms(),
+            session_timeout_ms: default_session_timeout_ms(),
+            ssl: false,
+            ssl_ca_location: None,
+            sasl: None,
+        }
+    }
+
+    /// The name of the topic on the cluster
+    pub fn topic(&self, topic: &str) -> String {
+        format!("{}{}", self.topic_prefix, topic)
+    }
+
+    fn security_protocol(&self) -> &'static str {
+        match (self.ssl, self.sasl.is_some()) {
+            (false, false) => "plaintext",
+            (true, false) => "ssl",
+            (false, true) => "sasl_plaintext",
+            (true, true) => "sasl_ssl",
+        }
+    }
+
+    fn client_config(&self) -> ClientConfig {
+        let mut config = ClientConfig::new();
+        config
+            .set("bootstrap.servers", &self.brokers)
+            .set("client.id", &self.client_id)
+            .set("security.protocol", self.security_protocol());
+
+        if let Some(ca_location) = &self.ssl_ca_location {
+            config.set("ssl.ca.location", ca_location);
+        }
+
+        if let Some(sasl) = &self.sasl {
+            config
+                .set("sasl.mechanism", sasl.mechanism.to_string())
+                .set("sasl.username", &sasl.username)
+                .set("sasl.password", sasl.password.expose_secret());
+        }
+
+        config
+    }
+
+    pub fn producer_config(&self) -> ClientConfig {
+        let mut config = self.client_config();
+        config
+            .set("acks", self.acks.to_string())
+            .set("message.timeout.ms", self.message_timeout_ms.to_string())
+            .set("request.timeout.ms", self.request_timeout_ms.to_string());
+
+        config
+    }
+
+    /// Offsets are stored by hand once a message was handled, and committed periodically from there
+    pub fn consumer_config(&self) -> ClientConfig {
+        let mut config = self.client_config();
+        config
+            .set("group.id", &self.consumer_group)
+            .set("enable.auto.commit", "true")
+            .set("enable.auto.offset.store", "false")
+            .set("auto.offset.reset", "earliest")
+            .set("session.timeout.ms", self.session_timeout_ms.to_string());
+
+        config
+    }
+}
@@ -1,3 +1,7 @@
+mod messaging;
+
+pub use messaging::*;
+
 use eyre::eyre;
 use figment::{
     providers::{Env, Format, Yaml},
@@ -45,6 +49,7 @@ pub struct Settings {
     pub database: Option<DatabaseSettings>,
     pub application: ApplicationSettings,
     pub session: SessionSettings,
+    pub messaging: Option<MessagingSettings>,
 }
 
 #[derive(Deserialize, Clone, Debug)]
@@ -79,6 +84,17 @@ pub struct SessionSettings {
 }
 
 impl Settings {
+    /// Where the settings come from, the later sources overriding the earlier ones.
+    fn figment() -> Figment {
+        Figment::new()
+            .merge(Yaml::file("config/base.yaml"))
+            .merge(Yaml::file("config/dev.yaml").profile("dev"))
+            .merge(Yaml::file("config/prod.yaml").profile("prod"))
+            // Nested settings are separated with a double underscore, e.g. `DEEPDISH_MESSAGING__BROKERS`.
+            // They're global, so that they take precedence over the files of every profile.
+            .merge(Env::prefixed("DEEPDISH_").split("__").global())
+    }
+
     #[coverage(off)]
     pub fn get() -> color_eyre::Result<Self> {
         let environment: Environment = std::env::var("APP_ENV")
@@ -86,13 +102,7 @@ impl Settings {
             .try_into()
             .map_err(|e| eyre::eyre!("{}", e))?;
 
-        let figment = Figment::new()
-            .merge(Yaml::file("config/base.yaml"))
-            .merge(Env::prefixed("DEEPDISH_"))
-            .merge(Yaml::file("config/dev.yaml").profile("dev"))
-            .merge(Yaml::file("config/prod.yaml").profile("prod"));
-
-        figment
+        Self::figment()
             .select(environment)
             .extract()
             .map_err(|_| eyre!("Could not load config file"))
@@ -143,3 +153,6 @@ impl ApplicationSettings {
         Ok(TcpListener::bind((self.host.clone(), self.port)).await?)
     }
 }
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,102 @@
+use figment::{
+    providers::{Format, Yaml},
+    Figment, Jail,
+};
+
+use super::{Acks, MessagingSettings, SaslMechanism, Settings};
+
+fn messaging_settings(yaml: &str) -> MessagingSettings {
+    Figment::new()
+        .merge(Yaml::string(yaml))
+        .extract_inner("messaging")
+        .unwrap()
+}
+
+#[test]
+fn messaging_settings_have_defaults() {
+    let settings = messaging_settings(
+        r#"
+messaging:
+  brokers: localhost:9092
+"#,
+    );
+
+    assert!(settings.enabled);
+    assert_eq!(settings.acks, Acks::All);
+    assert_eq!(
+        settings.topic("deepdish.recipe.created"),
+        "deepdish.recipe.created"
+    );
+
+    let producer = settings.producer_config();
+    assert_eq!(producer.get("bootstrap.servers"), Some("localhost:9092"));
+    assert_eq!(producer.get("security.protocol"), Some("plaintext"));
+    assert_eq!(producer.get("acks"), Some("all"));
+    assert_eq!(producer.get("message.timeout.ms"), Some("50000"));
+    assert_eq!(producer.get("sasl.mechanism"), None);
+}
+
+#[test]
+fn messaging_settings_are_passed_to_kafka() {
+    let settings = messaging_settings(
+        r#"
+messaging:
+  brokers: broker-1:9093,broker-2:9093
+  client_id: deepdish-test
+  consumer_group: deepdish-test-group
+  acks: leader
+  topic_prefix: staging.
+  session_timeout_ms: "10000"
+  ssl: true
+  sasl:
+    mechanism: SCRAM-SHA-512
+    username: deepdish
+    password: hunter2
+"#,
+    );
+
+    assert_eq!(
+        settings.topic("deepdish.recipe.created"),
+        "staging.deepdish.recipe.created"
+    );
+    assert_eq!(
+        settings.sasl.as_ref().map(|s| s.mechanism),
+        Some(SaslMechanism::ScramSha512)
+    );
+
+    let producer = settings.producer_config();
+    assert_eq!(producer.get("client.id"), Some("deepdish-test"));
+    assert_eq!(producer.get("acks"), Some("1"));
+    assert_eq!(producer.get("security.protocol"), Some("sasl_ssl"));
+    assert_eq!(producer.get("sasl.mechanism"), Some("SCRAM-SHA-512"));
+    assert_eq!(producer.get("sasl.password"), Some("hunter2"));
+
+    let consumer = settings.consumer_config();
+    assert_eq!(consumer.get("group.id"), Some("deepdish-test-group"));
+    assert_eq!(consumer.get("session.timeout.ms"), Some("10000"));
+    assert_eq!(consumer.get("acks"), None);
+}
+
+#[test]
+// The error type is figment's own, which is large but only ever returned to the test harness
+#[allow(clippy::result_large_err)]
+fn environment_variables_override_the_profile_files() {
+    Jail::expect_with(|jail| {
+        jail.create_dir("config")?;
+        jail.create_file("config/base.yaml", "")?;
+        jail.create_file("config/dev.yaml", "")?;
+        jail.create_file(
+            "config/prod.yaml",
+            "messaging:\n  brokers: localhost:9092\n",
+        )?;
+        jail.set_env("DEEPDISH_MESSAGING__BROKERS", "kafka-1:9093,kafka-2:9093");
+
+        let settings: MessagingSettings = Settings::figment()
+            .select("prod")
+            .extract_inner("messaging")?;
+
+        assert_eq!(settings.brokers, "kafka-1:9093,kafka-2:9093");
+
+        Ok(())
+    });
+}
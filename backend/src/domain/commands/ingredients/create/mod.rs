@@ -2,9 +2,9 @@ use uuid::Uuid;
 
 use crate::domain::entities::ingredient::*;
 use crate::domain::repositories::ingredients::{
-    errors::InsertIngredientError, IngredientRepositoryService,
+    errors::{GetIngredientByIdError, InsertIngredientError},
+    IngredientRepositoryService,
 };
-use crate::domain::services::message::MessageServiceImpl;
 
 use self::errors::ValidationError;
 use self::types::DietViolations;
@@ -17,6 +17,10 @@ pub enum CreateIngredientError {
         "A conflict has occured - an ingredient with field {0} of the given value already exists."
     )]
     Conflict(String),
+    #[error("The parent ingredient with ID {0} does not exist")]
+    ParentNotFound(Uuid),
+    #[error(transparent)]
+    Invalid(ValidationError),
     #[error(transparent)]
     Internal(#[from] eyre::Error),
 }
@@ -34,6 +38,7 @@ impl From<ValidationError> for CreateIngredientError {
     fn from(value: ValidationError) -> Self {
         match value {
             ValidationError::EmptyField(field) => Self::EmptyField(field[0]),
+            e @ ValidationError::DoesNotMatch(..) => Self::Invalid(e),
             e => Self::Internal(e.into()),
         }
     }
@@ -44,6 +49,8 @@ pub struct CreateIngredient<'a> {
     pub name: &'a str,
     pub description: &'a str,
     pub diet_violations: Vec<String>,
+    pub parent_id: Option<Uuid>,
+    pub allergens: Vec<String>,
 }
 
 impl<'a> TryFrom<&CreateIngredient<'a>> for Ingredient {
@@ -60,19 +67,28 @@ impl<'a> TryFrom<&CreateIngredient<'a>> for Ingredient {
                 .filter_map(|x| DietViolations::try_from(x).ok())
                 .collect::<Vec<_>>()
                 .into(),
+            parent_id: value.parent_id,
+            allergens: value.allergens.clone().try_into()?,
         })
     }
 }
 
-#[tracing::instrument("[COMMAND] Creating a new ingredient", skip(repo, message_service))]
+#[tracing::instrument("[COMMAND] Creating a new ingredient", skip(repo))]
 pub async fn create_ingredient(
     repo: IngredientRepositoryService,
-    message_service: MessageServiceImpl,
     input: &CreateIngredient<'_>,
 ) -> Result<Ingredient, CreateIngredientError> {
     let ingredient = Ingredient::try_from(input)?;
+
+    // A new ingredient has no variants yet, so it's enough to check that the parent exists
+    if let Some(parent_id) = ingredient.parent_id {
+        repo.get_by_id(&parent_id).await.map_err(|e| match e {
+            GetIngredientByIdError::NotFound(id) => CreateIngredientError::ParentNotFound(id),
+            e => CreateIngredientError::Internal(e.into()),
+        })?;
+    }
+
     let ingredient = repo.insert(ingredient).await?;
-    message_service.ingredient_added(&ingredient).await?;
     Ok(ingredient)
 }
 
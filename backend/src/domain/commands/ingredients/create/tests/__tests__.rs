@@ -5,13 +5,13 @@ use uuid::Uuid;
 use crate::domain::{
     commands::ingredients::create::{create_ingredient, CreateIngredient, CreateIngredientError},
     entities::ingredient::{
-        types::{DietViolations, WhichDiets},
+        errors::ValidationError,
+        types::{Allergen, Allergens, DietViolations, WhichDiets},
         Ingredient,
     },
     repositories::ingredients::{
         IngredientRepository, IngredientRepositoryService, MockIngredientRepository,
     },
-    services::message::{stub::StubMessageService, MessageService, MessageServiceImpl},
 };
 
 pub async fn creates_an_ingredient() {
@@ -19,6 +19,8 @@ pub async fn creates_an_ingredient() {
         name: "Tomato",
         description: "Description of a tomato",
         diet_violations: vec!["Vegan".into()],
+        parent_id: None,
+        allergens: vec![],
     };
 
     let mut mock = MockIngredientRepository::new();
@@ -29,13 +31,14 @@ pub async fn creates_an_ingredient() {
             name: i.name,
             description: i.description,
             diet_violations: i.diet_violations,
+            parent_id: None,
+            allergens: Allergens::new(),
         })
     });
 
     let repo: IngredientRepositoryService = Arc::new(Box::new(mock));
-    let ms: MessageServiceImpl = Arc::new(Box::new(StubMessageService));
 
-    let when = create_ingredient(repo.clone(), ms, &given).await.unwrap();
+    let when = create_ingredient(repo.clone(), &given).await.unwrap();
 
     // THEN
 
@@ -44,20 +47,18 @@ pub async fn creates_an_ingredient() {
     assert!(when.diet_violations.contains(&DietViolations::Vegan));
 }
 
-pub async fn incorrect_diets_do_not_get_included(
-    repo: impl IngredientRepository,
-    message_service: impl MessageService,
-) {
+pub async fn incorrect_diets_do_not_get_included(repo: impl IngredientRepository) {
     let given = CreateIngredient {
         name: "Tomato",
         description: "Description of a tomato",
         diet_violations: vec!["Vegan".into(), "INVALID DIET".into()],
+        parent_id: None,
+        allergens: vec![],
     };
 
     let repo: IngredientRepositoryService = Arc::new(Box::new(repo));
-    let ms: MessageServiceImpl = Arc::new(Box::new(message_service));
 
-    let when = create_ingredient(repo.clone(), ms, &given).await.unwrap();
+    let when = create_ingredient(repo.clone(), &given).await.unwrap();
 
     // THEN
 
@@ -65,44 +66,72 @@ pub async fn incorrect_diets_do_not_get_included(
     assert_eq!(when.diet_violations.len(), 1);
 }
 
-pub async fn empty_name_fails(
-    repo: impl IngredientRepository,
-    message_service: impl MessageService,
-) {
+pub async fn allergens_are_deduplicated(repo: impl IngredientRepository) {
+    let given = CreateIngredient {
+        name: "Pesto",
+        description: "Basil, pine nuts and parmesan",
+        diet_violations: vec![],
+        parent_id: None,
+        allergens: vec!["nuts".into(), "Milk".into(), "milk".into()],
+    };
+
+    let repo: IngredientRepositoryService = Arc::new(Box::new(repo));
+
+    let when = create_ingredient(repo.clone(), &given).await.unwrap();
+
+    assert_eq!(when.allergens, vec![Allergen::Milk, Allergen::Nuts].into());
+}
+
+pub async fn unknown_allergens_fail(repo: impl IngredientRepository) {
+    let given = CreateIngredient {
+        name: "Pesto",
+        description: "Basil, pine nuts and parmesan",
+        diet_violations: vec![],
+        parent_id: None,
+        allergens: vec!["milk".into(), "nut".into()],
+    };
+
+    let repo: IngredientRepositoryService = Arc::new(Box::new(repo));
+
+    let when = create_ingredient(repo.clone(), &given).await.unwrap_err();
+
+    assert!(matches!(
+        when,
+        CreateIngredientError::Invalid(ValidationError::DoesNotMatch("allergens", _))
+    ));
+    assert!(repo.get_all().await.unwrap().is_empty());
+}
+
+pub async fn empty_name_fails(repo: impl IngredientRepository) {
     let given = CreateIngredient {
         name: "",
         description: "Description of a tomato",
         diet_violations: vec![],
+        parent_id: None,
+        allergens: vec![],
     };
 
     let repo: IngredientRepositoryService = Arc::new(Box::new(repo));
-    let ms: MessageServiceImpl = Arc::new(Box::new(message_service));
 
-    let when = create_ingredient(repo.clone(), ms, &given)
-        .await
-        .unwrap_err();
+    let when = create_ingredient(repo.clone(), &given).await.unwrap_err();
 
     // THEN
 
     assert!(matches!(when, CreateIngredientError::EmptyField("name")));
 }
 
-pub async fn empty_description_fails(
-    repo: impl IngredientRepository,
-    message_service: impl MessageService,
-) {
+pub async fn empty_description_fails(repo: impl IngredientRepository) {
     let given = CreateIngredient {
         name: "Tomato",
         description: "",
         diet_violations: vec![],
+        parent_id: None,
+        allergens: vec![],
     };
 
     let repo: IngredientRepositoryService = Arc::new(Box::new(repo));
-    let ms: MessageServiceImpl = Arc::new(Box::new(message_service));
 
-    let when = create_ingredient(repo.clone(), ms, &given)
-        .await
-        .unwrap_err();
+    let when = create_ingredient(repo.clone(), &given).await.unwrap_err();
 
     // THEN
 
@@ -112,22 +141,18 @@ pub async fn empty_description_fails(
     ));
 }
 
-pub async fn incorrect_ingredient_is_not_persisted(
-    repo: impl IngredientRepository,
-    message_service: impl MessageService,
-) {
+pub async fn incorrect_ingredient_is_not_persisted(repo: impl IngredientRepository) {
     let given = CreateIngredient {
         name: "",
         description: "Description of a tomato",
         diet_violations: vec![],
+        parent_id: None,
+        allergens: vec![],
     };
 
     let repo: IngredientRepositoryService = Arc::new(Box::new(repo));
-    let ms: MessageServiceImpl = Arc::new(Box::new(message_service));
 
-    let when = create_ingredient(repo.clone(), ms, &given)
-        .await
-        .unwrap_err();
+    let when = create_ingredient(repo.clone(), &given).await.unwrap_err();
 
     // THEN
 
@@ -143,26 +168,27 @@ pub async fn incorrect_ingredient_is_not_persisted(
 
 pub async fn inserting_an_ingredient_with_a_name_that_already_exists_fails(
     repo: impl IngredientRepository,
-    message_service: impl MessageService,
 ) {
     let given = Ingredient {
         id: Uuid::from_u128(1),
         name: "Ingredient name".try_into().unwrap(),
         description: "Ingredient description".try_into().unwrap(),
         diet_violations: WhichDiets::new(),
+        parent_id: None,
+        allergens: Allergens::new(),
     };
     let repo: IngredientRepositoryService = Arc::new(Box::new(repo));
-    let ms: MessageServiceImpl = Arc::new(Box::new(message_service));
 
     repo.insert(given.clone()).await.unwrap();
 
     let result = create_ingredient(
         repo,
-        ms,
         &CreateIngredient {
             name: given.name.as_str(),
             description: "This is a different description",
             diet_violations: vec![],
+            parent_id: None,
+            allergens: vec![],
         },
     )
     .await
@@ -173,3 +199,22 @@ pub async fn inserting_an_ingredient_with_a_name_that_already_exists_fails(
         CreateIngredientError::Conflict(fieldname) if fieldname == "name"
     ))
 }
+
+pub async fn creating_a_variant_of_a_missing_ingredient_fails(repo: impl IngredientRepository) {
+    let given = CreateIngredient {
+        name: "Dark soy sauce",
+        description: "Thicker and sweeter than the light one",
+        diet_violations: vec![],
+        parent_id: Some(Uuid::from_u128(1)),
+        allergens: vec![],
+    };
+
+    let repo: IngredientRepositoryService = Arc::new(Box::new(repo));
+
+    let when = create_ingredient(repo.clone(), &given).await.unwrap_err();
+
+    // THEN
+
+    assert!(matches!(when, CreateIngredientError::ParentNotFound(id) if id == Uuid::from_u128(1)));
+    assert!(repo.get_all().await.unwrap().is_empty());
+}
@@ -1,10 +1,7 @@
 mod __tests__;
 mod in_memory {
     use super::__tests__;
-    use crate::domain::{
-        repositories::ingredients::in_memory::InMemoryIngredientRepository,
-        services::message::stub::StubMessageService,
-    };
+    use crate::domain::repositories::ingredients::in_memory::InMemoryIngredientRepository;
 
     #[tokio::test]
     async fn creates_an_ingredient() {
@@ -13,93 +10,98 @@ mod in_memory {
 
     #[tokio::test]
     async fn incorrect_diets_do_not_get_included() {
-        __tests__::incorrect_diets_do_not_get_included(
-            InMemoryIngredientRepository::new(),
-            StubMessageService,
-        )
-        .await;
+        __tests__::incorrect_diets_do_not_get_included(InMemoryIngredientRepository::new()).await;
+    }
+
+    #[tokio::test]
+    async fn allergens_are_deduplicated() {
+        __tests__::allergens_are_deduplicated(InMemoryIngredientRepository::new()).await;
+    }
+
+    #[tokio::test]
+    async fn unknown_allergens_fail() {
+        __tests__::unknown_allergens_fail(InMemoryIngredientRepository::new()).await;
     }
 
     #[tokio::test]
     async fn empty_name_fails() {
-        __tests__::empty_name_fails(InMemoryIngredientRepository::new(), StubMessageService).await;
+        __tests__::empty_name_fails(InMemoryIngredientRepository::new()).await;
     }
 
     #[tokio::test]
     async fn empty_description_fails() {
-        __tests__::empty_description_fails(InMemoryIngredientRepository::new(), StubMessageService)
-            .await;
+        __tests__::empty_description_fails(InMemoryIngredientRepository::new()).await;
     }
 
     #[tokio::test]
     async fn incorrect_ingredient_is_not_persisted() {
-        __tests__::incorrect_ingredient_is_not_persisted(
-            InMemoryIngredientRepository::new(),
-            StubMessageService,
-        )
-        .await;
+        __tests__::incorrect_ingredient_is_not_persisted(InMemoryIngredientRepository::new()).await;
     }
 
     #[tokio::test]
     async fn inserting_an_ingredient_with_a_name_that_already_exists_fails() {
         let repo = InMemoryIngredientRepository::new();
-        __tests__::inserting_an_ingredient_with_a_name_that_already_exists_fails(
-            repo,
-            StubMessageService,
+        __tests__::inserting_an_ingredient_with_a_name_that_already_exists_fails(repo).await
+    }
+
+    #[tokio::test]
+    async fn creating_a_variant_of_a_missing_ingredient_fails() {
+        __tests__::creating_a_variant_of_a_missing_ingredient_fails(
+            InMemoryIngredientRepository::new(),
         )
-        .await
+        .await;
     }
 }
 
 mod sql {
     use super::__tests__;
-    use crate::domain::{
-        repositories::ingredients::postgres::PostgresIngredientRepository,
-        services::message::stub::StubMessageService,
-    };
+    use crate::domain::repositories::ingredients::postgres::PostgresIngredientRepository;
 
     use sqlx::PgPool;
 
     #[sqlx::test]
     async fn incorrect_diets_do_not_get_included(pool: PgPool) {
-        __tests__::incorrect_diets_do_not_get_included(
-            PostgresIngredientRepository::new(pool),
-            StubMessageService,
-        )
-        .await;
+        __tests__::incorrect_diets_do_not_get_included(PostgresIngredientRepository::new(pool))
+            .await;
+    }
+
+    #[sqlx::test]
+    async fn allergens_are_deduplicated(pool: PgPool) {
+        __tests__::allergens_are_deduplicated(PostgresIngredientRepository::new(pool)).await;
+    }
+
+    #[sqlx::test]
+    async fn unknown_allergens_fail(pool: PgPool) {
+        __tests__::unknown_allergens_fail(PostgresIngredientRepository::new(pool)).await;
     }
 
     #[sqlx::test]
     async fn empty_name_fails(pool: PgPool) {
-        __tests__::empty_name_fails(PostgresIngredientRepository::new(pool), StubMessageService)
-            .await;
+        __tests__::empty_name_fails(PostgresIngredientRepository::new(pool)).await;
     }
 
     #[sqlx::test]
     async fn empty_description_fails(pool: PgPool) {
-        __tests__::empty_description_fails(
-            PostgresIngredientRepository::new(pool),
-            StubMessageService,
-        )
-        .await;
+        __tests__::empty_description_fails(PostgresIngredientRepository::new(pool)).await;
     }
 
     #[sqlx::test]
     async fn incorrect_ingredient_is_not_persisted(pool: PgPool) {
-        __tests__::incorrect_ingredient_is_not_persisted(
-            PostgresIngredientRepository::new(pool),
-            StubMessageService,
-        )
-        .await;
+        __tests__::incorrect_ingredient_is_not_persisted(PostgresIngredientRepository::new(pool))
+            .await;
     }
 
     #[sqlx::test]
     async fn inserting_an_ingredient_with_a_name_that_already_exists_fails(pool: PgPool) {
         let repo = PostgresIngredientRepository::new(pool);
-        __tests__::inserting_an_ingredient_with_a_name_that_already_exists_fails(
-            repo,
-            StubMessageService,
+        __tests__::inserting_an_ingredient_with_a_name_that_already_exists_fails(repo).await
+    }
+
+    #[sqlx::test]
+    async fn creating_a_variant_of_a_missing_ingredient_fails(pool: PgPool) {
+        __tests__::creating_a_variant_of_a_missing_ingredient_fails(
+            PostgresIngredientRepository::new(pool),
         )
-        .await
+        .await;
     }
 }
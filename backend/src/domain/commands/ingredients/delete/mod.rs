@@ -1,13 +1,13 @@
 use thiserror::Error;
 use uuid::Uuid;
 
-use crate::domain::{repositories::{
+use crate::domain::repositories::{
     ingredients::{
         errors::{DeleteIngredientError as DeleteIngredientErrorInternal, GetIngredientByIdError},
         IngredientRepositoryService,
     },
     recipe::RecipeRepositoryService,
-}, services::message::MessageServiceImpl};
+};
 
 #[derive(Error, Debug, strum::AsRefStr)]
 pub enum DeleteIngredientError {
@@ -17,13 +17,19 @@ pub enum DeleteIngredientError {
     #[error("There are recipes that use this ingredient. Delete them first, then you will be able to delete this ingredient.")]
     InUseByRecipe,
 
+    #[error("The ingredient with ID {0} has been changed since you last fetched it")]
+    Conflict(Uuid),
+
     #[error(transparent)]
     UnknownError(#[from] eyre::Error),
 }
 
 impl From<DeleteIngredientErrorInternal> for DeleteIngredientError {
     fn from(value: DeleteIngredientErrorInternal) -> Self {
-        Self::UnknownError(value.into())
+        match value {
+            DeleteIngredientErrorInternal::Outdated(id) => Self::Conflict(id),
+            e => Self::UnknownError(e.into()),
+        }
     }
 }
 
@@ -36,14 +42,19 @@ impl From<GetIngredientByIdError> for DeleteIngredientError {
     }
 }
 
-#[tracing::instrument("[COMMAND] Deleting a new ingredient", skip(repo, recipe_repo, message_service))]
+/// When expected versions are given, the ingredient is only deleted if it's still in one of them.
+#[tracing::instrument("[COMMAND] Deleting a new ingredient", skip(repo, recipe_repo))]
 pub async fn delete_ingredient(
     repo: IngredientRepositoryService,
     recipe_repo: RecipeRepositoryService,
-    message_service: MessageServiceImpl,
     input: &Uuid,
+    expected_versions: Option<&[String]>,
 ) -> Result<(), DeleteIngredientError> {
     let ingredient = repo.get_by_id(input).await?;
+
+    if expected_versions.is_some_and(|versions| !versions.contains(&ingredient.version())) {
+        return Err(DeleteIngredientError::Conflict(*input));
+    }
     let recipes_with_ingredient_exist = recipe_repo
         .recipes_containing_ingredient_exist(ingredient.clone())
         .await?;
@@ -53,7 +64,6 @@ pub async fn delete_ingredient(
     };
 
     repo.delete(ingredient.clone()).await?;
-    message_service.ingredient_deleted(&ingredient).await?;
 
     Ok(())
 }
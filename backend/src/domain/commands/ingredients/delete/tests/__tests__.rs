@@ -5,33 +5,32 @@ use uuid::Uuid;
 use crate::{
     domain::{
         commands::ingredients::delete::{delete_ingredient, DeleteIngredientError},
-        entities::ingredient::{types::WhichDiets, Ingredient},
+        entities::ingredient::{
+            types::{Allergens, WhichDiets},
+            Ingredient,
+        },
         repositories::{
             ingredients::{IngredientRepository, IngredientRepositoryService},
             recipe::{RecipeRepository, RecipeRepositoryService},
         },
-        services::message::{MessageService, MessageServiceImpl},
     },
     test_utils::{ingredient_fixture, insert_all_ingredients_of_recipe, recipe_fixture},
 };
 
-pub async fn deleting_works(
-    repo: impl IngredientRepository,
-    recipe_repo: impl RecipeRepository,
-    message_service: impl MessageService,
-) {
+pub async fn deleting_works(repo: impl IngredientRepository, recipe_repo: impl RecipeRepository) {
     let repo: IngredientRepositoryService = Arc::new(Box::new(repo));
     let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
-    let ms: MessageServiceImpl = Arc::new(Box::new(message_service));
     let input = Ingredient {
         id: Uuid::from_u128(1),
         name: "Ingredient name 1".try_into().unwrap(),
         description: "Ingredient description 1".try_into().unwrap(),
         diet_violations: WhichDiets::new(),
+        parent_id: None,
+        allergens: Allergens::new(),
     };
 
     let insert_result = repo.insert(input).await.unwrap();
-    delete_ingredient(repo, recipe_repo, ms, &insert_result.id)
+    delete_ingredient(repo, recipe_repo, &insert_result.id, None)
         .await
         .unwrap();
 }
@@ -39,13 +38,11 @@ pub async fn deleting_works(
 pub async fn deleting_nonexistent_ingredient_errors(
     repo: impl IngredientRepository,
     recipe_repo: impl RecipeRepository,
-    message_service: impl MessageService,
 ) {
     let repo: IngredientRepositoryService = Arc::new(Box::new(repo));
     let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
-    let ms: MessageServiceImpl = Arc::new(Box::new(message_service));
     let ingredient = ingredient_fixture();
-    let error = delete_ingredient(repo, recipe_repo, ms, &ingredient.id)
+    let error = delete_ingredient(repo, recipe_repo, &ingredient.id, None)
         .await
         .unwrap_err();
 
@@ -58,7 +55,6 @@ pub async fn deleting_nonexistent_ingredient_errors(
 pub async fn deleting_an_ingredient_still_in_use_by_recipes_errors(
     repo: impl IngredientRepository,
     recipe_repo: impl RecipeRepository,
-    message_service: impl MessageService,
 ) {
     let recipe = recipe_fixture();
     insert_all_ingredients_of_recipe(&repo, &recipe).await;
@@ -67,9 +63,8 @@ pub async fn deleting_an_ingredient_still_in_use_by_recipes_errors(
 
     let repo: IngredientRepositoryService = Arc::new(Box::new(repo));
     let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
-    let ms: MessageServiceImpl = Arc::new(Box::new(message_service));
 
-    let error = delete_ingredient(repo, recipe_repo, ms, input)
+    let error = delete_ingredient(repo, recipe_repo, input, None)
         .await
         .unwrap_err();
 
@@ -0,0 +1,106 @@
+use std::collections::HashSet;
+
+use uuid::Uuid;
+
+use crate::domain::commands::ingredients::create::{CreateIngredient, CreateIngredientError};
+use crate::domain::entities::ingredient::{formats::IngredientFormatError, Ingredient};
+use crate::domain::repositories::ingredients::{
+    errors::{GetAllIngredientsError, InsertIngredientError},
+    IngredientRepositoryService,
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum ImportIngredientsError {
+    #[error(transparent)]
+    Format(#[from] IngredientFormatError),
+
+    #[error(transparent)]
+    Unknown(#[from] eyre::Error),
+}
+
+impl From<InsertIngredientError> for ImportIngredientsError {
+    fn from(value: InsertIngredientError) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+/// What happened to a single ingredient of the batch.
+#[derive(Debug, strum::AsRefStr)]
+#[strum(serialize_all = "snake_case")]
+pub enum IngredientImportOutcome {
+    Created(Ingredient),
+    /// An ingredient with the same value of the given field already exists
+    Conflict(String),
+    /// The ingredient couldn't be created, the reason is in the message
+    Invalid(String),
+}
+
+/// Creates all of the ingredients at once, reporting what happened to every one of them in the same order.
+/// Invalid ingredients and the ones clashing with existing ingredients are skipped, the rest are created regardless.
+/// Variants can only be added to ingredients which already exist before the import.
+#[tracing::instrument(
+    "[COMMAND] Importing ingredients",
+    skip(repo, input),
+    fields(count = input.len())
+)]
+pub async fn import_ingredients(
+    repo: IngredientRepositoryService,
+    input: &[CreateIngredient<'_>],
+) -> Result<Vec<IngredientImportOutcome>, ImportIngredientsError> {
+    let mut outcomes: Vec<Option<IngredientImportOutcome>> = vec![];
+    // Valid ingredients, along with their position in the batch
+    let mut valid: Vec<(usize, Ingredient)> = vec![];
+
+    for (index, ingredient) in input.iter().enumerate() {
+        match Ingredient::try_from(ingredient) {
+            Ok(ingredient) => {
+                outcomes.push(None);
+                valid.push((index, ingredient));
+            }
+            Err(e) => outcomes.push(Some(IngredientImportOutcome::Invalid(
+                CreateIngredientError::from(e).to_string(),
+            ))),
+        }
+    }
+
+    let parent_ids: Vec<Uuid> = valid
+        .iter()
+        .filter_map(|(_, i)| i.parent_id)
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect();
+    let missing_parents: Vec<Uuid> = match repo.get_all_by_id(&parent_ids).await {
+        Ok(_) => vec![],
+        Err(GetAllIngredientsError::MultipleIngredientsMissing(ids)) => ids,
+        Err(e) => return Err(ImportIngredientsError::Unknown(e.into())),
+    };
+
+    let (orphans, valid): (Vec<_>, Vec<_>) = valid.into_iter().partition(|(_, i)| {
+        i.parent_id
+            .is_some_and(|parent_id| missing_parents.contains(&parent_id))
+    });
+
+    for (index, ingredient) in orphans {
+        let parent_id = ingredient.parent_id.unwrap_or_default();
+        outcomes[index] = Some(IngredientImportOutcome::Invalid(
+            CreateIngredientError::ParentNotFound(parent_id).to_string(),
+        ));
+    }
+
+    let (indices, ingredients): (Vec<usize>, Vec<Ingredient>) = valid.into_iter().unzip();
+    let results = repo.insert_all(ingredients).await?;
+
+    for (index, result) in indices.into_iter().zip(results) {
+        outcomes[index] = Some(match result {
+            Ok(ingredient) => IngredientImportOutcome::Created(ingredient),
+            Err(InsertIngredientError::Conflict(field)) => IngredientImportOutcome::Conflict(field),
+            Err(e) => return Err(e.into()),
+        });
+    }
+
+    // Every ingredient has been dealt with by now
+    Ok(outcomes.into_iter().flatten().collect())
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,84 @@
+use uuid::Uuid;
+
+use crate::domain::{
+    commands::ingredients::{
+        create::CreateIngredient,
+        import::{import_ingredients, IngredientImportOutcome},
+    },
+    repositories::ingredients::{IngredientRepository, IngredientRepositoryService},
+};
+use crate::test_utils::{insert_all_ingredients_of_recipe, recipe_fixture};
+
+fn ingredient<'a>(name: &'a str, description: &'a str) -> CreateIngredient<'a> {
+    CreateIngredient {
+        name,
+        description,
+        diet_violations: vec!["vegan".into()],
+        parent_id: None,
+        allergens: vec![],
+    }
+}
+
+pub async fn imports_every_ingredient(repo: impl IngredientRepository) {
+    let repo: IngredientRepositoryService = std::sync::Arc::new(Box::new(repo));
+    let input = vec![
+        ingredient("Tomato", "Red and round"),
+        ingredient("Cucumber", "Green and long"),
+    ];
+
+    let outcomes = import_ingredients(repo.clone(), &input).await.unwrap();
+
+    assert_eq!(outcomes.len(), 2);
+    for (outcome, given) in outcomes.iter().zip(&input) {
+        match outcome {
+            IngredientImportOutcome::Created(created) => {
+                assert_eq!(created.name.as_ref(), given.name);
+                let stored = repo.get_by_id(&created.id).await.unwrap();
+                assert_eq!(&stored, created);
+            }
+            outcome => panic!("{} was not created: {outcome:?}", given.name),
+        }
+    }
+}
+
+pub async fn reports_each_ingredient_separately(repo: impl IngredientRepository) {
+    let existing = recipe_fixture();
+    insert_all_ingredients_of_recipe(&repo, &existing).await;
+    let repo: IngredientRepositoryService = std::sync::Arc::new(Box::new(repo));
+    let existing_name = existing.ingredients[0].ingredient.name.to_string();
+    let missing_parent = Uuid::from_u128(12345);
+
+    let input = vec![
+        ingredient("Tomato", "Red and round"),
+        ingredient(&existing_name, "Already there"),
+        ingredient("", "Nameless"),
+        CreateIngredient {
+            parent_id: Some(missing_parent),
+            ..ingredient("Cherry tomato", "A small tomato")
+        },
+        ingredient("Tomato", "Listed twice"),
+        CreateIngredient {
+            parent_id: Some(existing.ingredients[1].ingredient.id),
+            ..ingredient("Purple broccoli", "A variant of broccoli")
+        },
+    ];
+
+    let outcomes = import_ingredients(repo.clone(), &input).await.unwrap();
+    let kinds: Vec<&str> = outcomes.iter().map(|o| o.as_ref()).collect();
+
+    assert_eq!(
+        kinds,
+        vec!["created", "conflict", "invalid", "invalid", "conflict", "created"]
+    );
+    assert!(matches!(&outcomes[1], IngredientImportOutcome::Conflict(field) if field == "name"));
+    assert!(
+        matches!(&outcomes[3], IngredientImportOutcome::Invalid(message) if message.contains(&missing_parent.to_string()))
+    );
+    assert!(matches!(
+        &outcomes[5],
+        IngredientImportOutcome::Created(variant) if variant.parent_id == Some(existing.ingredients[1].ingredient.id)
+    ));
+
+    let all = repo.get_all().await.unwrap();
+    assert_eq!(all.len(), existing.ingredients.len() + 2);
+}
@@ -0,0 +1,33 @@
+mod __tests__;
+mod in_memory {
+    use super::__tests__;
+    use crate::domain::repositories::ingredients::in_memory::InMemoryIngredientRepository;
+
+    #[tokio::test]
+    async fn imports_every_ingredient() {
+        __tests__::imports_every_ingredient(InMemoryIngredientRepository::new()).await;
+    }
+
+    #[tokio::test]
+    async fn reports_each_ingredient_separately() {
+        __tests__::reports_each_ingredient_separately(InMemoryIngredientRepository::new()).await;
+    }
+}
+
+mod sql {
+    use super::__tests__;
+    use crate::domain::repositories::ingredients::postgres::PostgresIngredientRepository;
+
+    use sqlx::PgPool;
+
+    #[sqlx::test]
+    async fn imports_every_ingredient(pool: PgPool) {
+        __tests__::imports_every_ingredient(PostgresIngredientRepository::new(pool)).await;
+    }
+
+    #[sqlx::test]
+    async fn reports_each_ingredient_separately(pool: PgPool) {
+        __tests__::reports_each_ingredient_separately(PostgresIngredientRepository::new(pool))
+            .await;
+    }
+}
@@ -1,3 +1,6 @@
 pub mod create;
 pub mod delete;
+pub mod import;
+pub mod nutrition;
+pub mod substitutes;
 pub mod update;
@@ -0,0 +1,51 @@
+use uuid::Uuid;
+
+use crate::domain::repositories::ingredients::{
+    errors::{DeleteNutritionError as DeleteNutritionErrorInternal, GetIngredientByIdError},
+    IngredientRepositoryService,
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum DeleteNutritionError {
+    #[error("Could not find the ingredient with ID {0}")]
+    NotFound(Uuid),
+
+    #[error("The ingredient with ID {0} has no nutrition facts")]
+    NutritionNotFound(Uuid),
+
+    #[error(transparent)]
+    Internal(#[from] eyre::Error),
+}
+
+impl From<GetIngredientByIdError> for DeleteNutritionError {
+    fn from(value: GetIngredientByIdError) -> Self {
+        match value {
+            GetIngredientByIdError::NotFound(id) => Self::NotFound(id),
+            e => Self::Internal(e.into()),
+        }
+    }
+}
+
+impl From<DeleteNutritionErrorInternal> for DeleteNutritionError {
+    fn from(value: DeleteNutritionErrorInternal) -> Self {
+        match value {
+            DeleteNutritionErrorInternal::NotFound(id) => Self::NutritionNotFound(id),
+            e => Self::Internal(e.into()),
+        }
+    }
+}
+
+#[tracing::instrument("[COMMAND] Removing the nutrition facts of an ingredient", skip(repo))]
+pub async fn delete_nutrition(
+    repo: IngredientRepositoryService,
+    ingredient_id: &Uuid,
+) -> Result<(), DeleteNutritionError> {
+    let ingredient = repo.get_by_id(ingredient_id).await?;
+
+    repo.delete_nutrition(&ingredient).await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests;
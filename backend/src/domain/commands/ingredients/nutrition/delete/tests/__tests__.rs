@@ -0,0 +1,32 @@
+use std::sync::Arc;
+
+use crate::{
+    domain::{
+        commands::ingredients::nutrition::delete::{delete_nutrition, DeleteNutritionError},
+        repositories::ingredients::{IngredientRepository, IngredientRepositoryService},
+    },
+    test_utils::{ingredient_fixture, nutrition_fixture},
+};
+
+pub async fn deleting_nutrition_works(repo: impl IngredientRepository) {
+    repo.insert(ingredient_fixture()).await.unwrap();
+    repo.set_nutrition(&ingredient_fixture(), &nutrition_fixture())
+        .await
+        .unwrap();
+    let repo: IngredientRepositoryService = Arc::new(Box::new(repo));
+    let id = ingredient_fixture().id;
+
+    delete_nutrition(repo.clone(), &id).await.unwrap();
+
+    assert!(repo.get_nutrition(&[id]).await.unwrap().is_empty());
+}
+
+pub async fn deleting_missing_nutrition_fails(repo: impl IngredientRepository) {
+    repo.insert(ingredient_fixture()).await.unwrap();
+    let repo: IngredientRepositoryService = Arc::new(Box::new(repo));
+    let id = ingredient_fixture().id;
+
+    let error = delete_nutrition(repo, &id).await.unwrap_err();
+
+    assert!(matches!(error, DeleteNutritionError::NutritionNotFound(missing) if missing == id));
+}
@@ -0,0 +1,38 @@
+mod __tests__;
+
+mod in_memory {
+    use crate::domain::repositories::ingredients::in_memory::InMemoryIngredientRepository;
+
+    use super::__tests__;
+
+    #[tokio::test]
+    async fn deleting_nutrition_works() {
+        let repo = InMemoryIngredientRepository::new();
+        __tests__::deleting_nutrition_works(repo).await
+    }
+
+    #[tokio::test]
+    async fn deleting_missing_nutrition_fails() {
+        let repo = InMemoryIngredientRepository::new();
+        __tests__::deleting_missing_nutrition_fails(repo).await
+    }
+}
+
+mod sql {
+    use sqlx::PgPool;
+
+    use super::__tests__;
+    use crate::domain::repositories::ingredients::postgres::PostgresIngredientRepository;
+
+    #[sqlx::test]
+    async fn deleting_nutrition_works(pool: PgPool) {
+        let repo = PostgresIngredientRepository::new(pool.clone());
+        __tests__::deleting_nutrition_works(repo).await
+    }
+
+    #[sqlx::test]
+    async fn deleting_missing_nutrition_fails(pool: PgPool) {
+        let repo = PostgresIngredientRepository::new(pool.clone());
+        __tests__::deleting_missing_nutrition_fails(repo).await
+    }
+}
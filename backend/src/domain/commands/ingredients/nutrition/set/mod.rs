@@ -0,0 +1,56 @@
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::ingredient::{errors::ValidationError, nutrition::IngredientNutrition},
+    repositories::ingredients::{
+        errors::{GetIngredientByIdError, SetNutritionError as SetNutritionErrorInternal},
+        IngredientRepositoryService,
+    },
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum SetNutritionError {
+    #[error("Could not find the ingredient with ID {0}")]
+    NotFound(Uuid),
+
+    #[error(transparent)]
+    ValidationError(#[from] ValidationError),
+
+    #[error(transparent)]
+    Internal(#[from] eyre::Error),
+}
+
+impl From<GetIngredientByIdError> for SetNutritionError {
+    fn from(value: GetIngredientByIdError) -> Self {
+        match value {
+            GetIngredientByIdError::NotFound(id) => Self::NotFound(id),
+            e => Self::Internal(e.into()),
+        }
+    }
+}
+
+impl From<SetNutritionErrorInternal> for SetNutritionError {
+    fn from(value: SetNutritionErrorInternal) -> Self {
+        match value {
+            SetNutritionErrorInternal::NotFound(id) => Self::NotFound(id),
+            e => Self::Internal(e.into()),
+        }
+    }
+}
+
+#[tracing::instrument("[COMMAND] Setting the nutrition facts of an ingredient", skip(repo))]
+pub async fn set_nutrition(
+    repo: IngredientRepositoryService,
+    ingredient_id: &Uuid,
+    input: IngredientNutrition,
+) -> Result<IngredientNutrition, SetNutritionError> {
+    let nutrition = input.validated()?;
+    let ingredient = repo.get_by_id(ingredient_id).await?;
+
+    repo.set_nutrition(&ingredient, &nutrition).await?;
+
+    Ok(nutrition)
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,74 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        commands::ingredients::nutrition::set::{set_nutrition, SetNutritionError},
+        entities::ingredient::{
+            errors::ValidationError,
+            nutrition::{IngredientNutrition, NutritionFacts},
+        },
+        repositories::ingredients::{IngredientRepository, IngredientRepositoryService},
+    },
+    test_utils::{ingredient_fixture, nutrition_fixture},
+};
+
+pub async fn setting_nutrition_replaces_the_previous_facts(repo: impl IngredientRepository) {
+    repo.insert(ingredient_fixture()).await.unwrap();
+    let repo: IngredientRepositoryService = Arc::new(Box::new(repo));
+    let id = ingredient_fixture().id;
+
+    set_nutrition(repo.clone(), &id, nutrition_fixture())
+        .await
+        .unwrap();
+
+    let changed = IngredientNutrition {
+        per_100g: NutritionFacts {
+            energy_kcal: 250.0,
+            ..nutrition_fixture().per_100g
+        },
+        ..nutrition_fixture()
+    };
+    let result = set_nutrition(repo.clone(), &id, changed).await.unwrap();
+
+    assert_eq!(result, changed);
+    assert_eq!(
+        repo.get_nutrition(&[id]).await.unwrap().remove(&id),
+        Some(changed)
+    );
+}
+
+pub async fn setting_nutrition_of_a_missing_ingredient_fails(repo: impl IngredientRepository) {
+    let repo: IngredientRepositoryService = Arc::new(Box::new(repo));
+
+    let error = set_nutrition(repo, &Uuid::nil(), nutrition_fixture())
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, SetNutritionError::NotFound(id) if id.is_nil()));
+}
+
+pub async fn negative_nutrition_facts_are_rejected(repo: impl IngredientRepository) {
+    repo.insert(ingredient_fixture()).await.unwrap();
+    let repo: IngredientRepositoryService = Arc::new(Box::new(repo));
+
+    let error = set_nutrition(
+        repo,
+        &ingredient_fixture().id,
+        IngredientNutrition {
+            per_100g: NutritionFacts {
+                fat: -1.0,
+                ..nutrition_fixture().per_100g
+            },
+            ..nutrition_fixture()
+        },
+    )
+    .await
+    .unwrap_err();
+
+    assert!(matches!(
+        error,
+        SetNutritionError::ValidationError(ValidationError::Negative("fat"))
+    ));
+}
@@ -0,0 +1,50 @@
+mod __tests__;
+
+mod in_memory {
+    use crate::domain::repositories::ingredients::in_memory::InMemoryIngredientRepository;
+
+    use super::__tests__;
+
+    #[tokio::test]
+    async fn setting_nutrition_replaces_the_previous_facts() {
+        let repo = InMemoryIngredientRepository::new();
+        __tests__::setting_nutrition_replaces_the_previous_facts(repo).await
+    }
+
+    #[tokio::test]
+    async fn setting_nutrition_of_a_missing_ingredient_fails() {
+        let repo = InMemoryIngredientRepository::new();
+        __tests__::setting_nutrition_of_a_missing_ingredient_fails(repo).await
+    }
+
+    #[tokio::test]
+    async fn negative_nutrition_facts_are_rejected() {
+        let repo = InMemoryIngredientRepository::new();
+        __tests__::negative_nutrition_facts_are_rejected(repo).await
+    }
+}
+
+mod sql {
+    use sqlx::PgPool;
+
+    use super::__tests__;
+    use crate::domain::repositories::ingredients::postgres::PostgresIngredientRepository;
+
+    #[sqlx::test]
+    async fn setting_nutrition_replaces_the_previous_facts(pool: PgPool) {
+        let repo = PostgresIngredientRepository::new(pool.clone());
+        __tests__::setting_nutrition_replaces_the_previous_facts(repo).await
+    }
+
+    #[sqlx::test]
+    async fn setting_nutrition_of_a_missing_ingredient_fails(pool: PgPool) {
+        let repo = PostgresIngredientRepository::new(pool.clone());
+        __tests__::setting_nutrition_of_a_missing_ingredient_fails(repo).await
+    }
+
+    #[sqlx::test]
+    async fn negative_nutrition_facts_are_rejected(pool: PgPool) {
+        let repo = PostgresIngredientRepository::new(pool.clone());
+        __tests__::negative_nutrition_facts_are_rejected(repo).await
+    }
+}
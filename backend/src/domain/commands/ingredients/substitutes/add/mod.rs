@@ -0,0 +1,93 @@
+use common::CreateIngredientSubstituteDTO;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::ingredient::{errors::ValidationError, types::SubstituteRatio, IngredientSubstitute},
+    repositories::ingredients::{
+        errors::{GetIngredientByIdError, InsertSubstituteError},
+        IngredientRepositoryService,
+    },
+};
+
+#[derive(Debug, Default)]
+pub struct AddSubstitute {
+    pub substitute_id: Uuid,
+    pub ratio: Option<f64>,
+    pub note: Option<String>,
+}
+
+impl From<CreateIngredientSubstituteDTO> for AddSubstitute {
+    fn from(value: CreateIngredientSubstituteDTO) -> Self {
+        Self {
+            substitute_id: value.substitute_id,
+            ratio: value.ratio,
+            note: value.note,
+        }
+    }
+}
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum AddSubstituteError {
+    #[error("Could not find the ingredient with ID {0}")]
+    NotFound(Uuid),
+
+    #[error("An ingredient can't be a substitute for itself")]
+    SelfSubstitute,
+
+    #[error("The ingredient with ID {0} is already a substitute")]
+    Conflict(Uuid),
+
+    #[error(transparent)]
+    ValidationError(#[from] ValidationError),
+
+    #[error(transparent)]
+    Internal(#[from] eyre::Error),
+}
+
+impl From<GetIngredientByIdError> for AddSubstituteError {
+    fn from(value: GetIngredientByIdError) -> Self {
+        match value {
+            GetIngredientByIdError::NotFound(id) => Self::NotFound(id),
+            e => Self::Internal(e.into()),
+        }
+    }
+}
+
+impl From<InsertSubstituteError> for AddSubstituteError {
+    fn from(value: InsertSubstituteError) -> Self {
+        match value {
+            InsertSubstituteError::Conflict(id) => Self::Conflict(id),
+            e => Self::Internal(e.into()),
+        }
+    }
+}
+
+#[tracing::instrument("[COMMAND] Adding a substitute to an ingredient", skip(repo))]
+pub async fn add_substitute(
+    repo: IngredientRepositoryService,
+    ingredient_id: &Uuid,
+    input: &AddSubstitute,
+) -> Result<IngredientSubstitute, AddSubstituteError> {
+    if ingredient_id == &input.substitute_id {
+        return Err(AddSubstituteError::SelfSubstitute);
+    }
+
+    let ratio = match input.ratio {
+        Some(ratio) => ratio.try_into()?,
+        None => SubstituteRatio::default(),
+    };
+
+    let ingredient = repo.get_by_id(ingredient_id).await?;
+    let substitute = IngredientSubstitute {
+        substitute: repo.get_by_id(&input.substitute_id).await?,
+        ratio,
+        note: input.note.clone().filter(|note| !note.trim().is_empty()),
+    };
+
+    repo.insert_substitute(&ingredient, &substitute).await?;
+
+    Ok(substitute)
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,115 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        commands::ingredients::substitutes::add::{
+            add_substitute, AddSubstitute, AddSubstituteError,
+        },
+        entities::ingredient::errors::ValidationError,
+        repositories::ingredients::{IngredientRepository, IngredientRepositoryService},
+    },
+    test_utils::{ingredient_fixture, substitute_fixture},
+};
+
+async fn insert_ingredients(repo: impl IngredientRepository) -> IngredientRepositoryService {
+    repo.insert(ingredient_fixture()).await.unwrap();
+    repo.insert(substitute_fixture()).await.unwrap();
+
+    Arc::new(Box::new(repo))
+}
+
+pub async fn adding_a_substitute_works(repo: impl IngredientRepository) {
+    let repo = insert_ingredients(repo).await;
+
+    let input = AddSubstitute {
+        substitute_id: substitute_fixture().id,
+        ratio: Some(0.75),
+        note: Some("Marinate it first".to_string()),
+    };
+
+    add_substitute(repo.clone(), &ingredient_fixture().id, &input)
+        .await
+        .unwrap();
+
+    let result = repo
+        .get_substitutes(&[ingredient_fixture().id])
+        .await
+        .unwrap()
+        .remove(&ingredient_fixture().id)
+        .unwrap();
+
+    assert_eq!(result.len(), 1);
+    assert_eq!(result[0].substitute, substitute_fixture());
+    assert_eq!(*result[0].ratio, 0.75);
+    assert_eq!(result[0].note.as_deref(), Some("Marinate it first"));
+}
+
+pub async fn adding_a_substitute_to_itself_fails(repo: impl IngredientRepository) {
+    let repo = insert_ingredients(repo).await;
+
+    let input = AddSubstitute {
+        substitute_id: ingredient_fixture().id,
+        ..Default::default()
+    };
+
+    let error = add_substitute(repo, &ingredient_fixture().id, &input)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, AddSubstituteError::SelfSubstitute));
+}
+
+pub async fn adding_a_missing_substitute_fails(repo: impl IngredientRepository) {
+    let repo = insert_ingredients(repo).await;
+
+    let input = AddSubstitute {
+        substitute_id: Uuid::nil(),
+        ..Default::default()
+    };
+
+    let error = add_substitute(repo, &ingredient_fixture().id, &input)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, AddSubstituteError::NotFound(id) if id.is_nil()));
+}
+
+pub async fn adding_a_substitute_twice_fails(repo: impl IngredientRepository) {
+    let repo = insert_ingredients(repo).await;
+
+    let input = AddSubstitute {
+        substitute_id: substitute_fixture().id,
+        ..Default::default()
+    };
+
+    add_substitute(repo.clone(), &ingredient_fixture().id, &input)
+        .await
+        .unwrap();
+
+    let error = add_substitute(repo, &ingredient_fixture().id, &input)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, AddSubstituteError::Conflict(id) if id == substitute_fixture().id));
+}
+
+pub async fn adding_a_substitute_with_invalid_ratio_fails(repo: impl IngredientRepository) {
+    let repo = insert_ingredients(repo).await;
+
+    let input = AddSubstitute {
+        substitute_id: substitute_fixture().id,
+        ratio: Some(0.0),
+        ..Default::default()
+    };
+
+    let error = add_substitute(repo, &ingredient_fixture().id, &input)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(
+        error,
+        AddSubstituteError::ValidationError(ValidationError::NotPositive("ratio"))
+    ));
+}
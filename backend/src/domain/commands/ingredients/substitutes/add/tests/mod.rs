@@ -0,0 +1,74 @@
+mod __tests__;
+
+mod in_memory {
+    use crate::domain::repositories::ingredients::in_memory::InMemoryIngredientRepository;
+
+    use super::__tests__;
+
+    #[tokio::test]
+    async fn adding_a_substitute_works() {
+        let repo = InMemoryIngredientRepository::new();
+        __tests__::adding_a_substitute_works(repo).await
+    }
+
+    #[tokio::test]
+    async fn adding_a_substitute_to_itself_fails() {
+        let repo = InMemoryIngredientRepository::new();
+        __tests__::adding_a_substitute_to_itself_fails(repo).await
+    }
+
+    #[tokio::test]
+    async fn adding_a_missing_substitute_fails() {
+        let repo = InMemoryIngredientRepository::new();
+        __tests__::adding_a_missing_substitute_fails(repo).await
+    }
+
+    #[tokio::test]
+    async fn adding_a_substitute_twice_fails() {
+        let repo = InMemoryIngredientRepository::new();
+        __tests__::adding_a_substitute_twice_fails(repo).await
+    }
+
+    #[tokio::test]
+    async fn adding_a_substitute_with_invalid_ratio_fails() {
+        let repo = InMemoryIngredientRepository::new();
+        __tests__::adding_a_substitute_with_invalid_ratio_fails(repo).await
+    }
+}
+
+mod sql {
+    use sqlx::PgPool;
+
+    use super::__tests__;
+    use crate::domain::repositories::ingredients::postgres::PostgresIngredientRepository;
+
+    #[sqlx::test]
+    async fn adding_a_substitute_works(pool: PgPool) {
+        let repo = PostgresIngredientRepository::new(pool.clone());
+        __tests__::adding_a_substitute_works(repo).await
+    }
+
+    #[sqlx::test]
+    async fn adding_a_substitute_to_itself_fails(pool: PgPool) {
+        let repo = PostgresIngredientRepository::new(pool.clone());
+        __tests__::adding_a_substitute_to_itself_fails(repo).await
+    }
+
+    #[sqlx::test]
+    async fn adding_a_missing_substitute_fails(pool: PgPool) {
+        let repo = PostgresIngredientRepository::new(pool.clone());
+        __tests__::adding_a_missing_substitute_fails(repo).await
+    }
+
+    #[sqlx::test]
+    async fn adding_a_substitute_twice_fails(pool: PgPool) {
+        let repo = PostgresIngredientRepository::new(pool.clone());
+        __tests__::adding_a_substitute_twice_fails(repo).await
+    }
+
+    #[sqlx::test]
+    async fn adding_a_substitute_with_invalid_ratio_fails(pool: PgPool) {
+        let repo = PostgresIngredientRepository::new(pool.clone());
+        __tests__::adding_a_substitute_with_invalid_ratio_fails(repo).await
+    }
+}
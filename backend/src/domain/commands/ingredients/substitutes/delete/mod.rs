@@ -0,0 +1,52 @@
+use uuid::Uuid;
+
+use crate::domain::repositories::ingredients::{
+    errors::{DeleteSubstituteError as DeleteSubstituteErrorInternal, GetIngredientByIdError},
+    IngredientRepositoryService,
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum DeleteSubstituteError {
+    #[error("Could not find the ingredient with ID {0}")]
+    NotFound(Uuid),
+
+    #[error("The ingredient with ID {0} is not a substitute")]
+    SubstituteNotFound(Uuid),
+
+    #[error(transparent)]
+    Internal(#[from] eyre::Error),
+}
+
+impl From<GetIngredientByIdError> for DeleteSubstituteError {
+    fn from(value: GetIngredientByIdError) -> Self {
+        match value {
+            GetIngredientByIdError::NotFound(id) => Self::NotFound(id),
+            e => Self::Internal(e.into()),
+        }
+    }
+}
+
+impl From<DeleteSubstituteErrorInternal> for DeleteSubstituteError {
+    fn from(value: DeleteSubstituteErrorInternal) -> Self {
+        match value {
+            DeleteSubstituteErrorInternal::NotFound(id) => Self::SubstituteNotFound(id),
+            e => Self::Internal(e.into()),
+        }
+    }
+}
+
+#[tracing::instrument("[COMMAND] Removing a substitute from an ingredient", skip(repo))]
+pub async fn delete_substitute(
+    repo: IngredientRepositoryService,
+    ingredient_id: &Uuid,
+    substitute_id: &Uuid,
+) -> Result<(), DeleteSubstituteError> {
+    let ingredient = repo.get_by_id(ingredient_id).await?;
+
+    repo.delete_substitute(&ingredient, substitute_id).await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,48 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        commands::ingredients::substitutes::delete::{delete_substitute, DeleteSubstituteError},
+        repositories::ingredients::{IngredientRepository, IngredientRepositoryService},
+    },
+    test_utils::{ingredient_fixture, insert_substitute_fixture, substitute_fixture},
+};
+
+async fn insert_substitute(repo: impl IngredientRepository) -> IngredientRepositoryService {
+    insert_substitute_fixture(&repo, None).await;
+
+    Arc::new(Box::new(repo))
+}
+
+pub async fn deleting_a_substitute_works(repo: impl IngredientRepository) {
+    let repo = insert_substitute(repo).await;
+
+    delete_substitute(
+        repo.clone(),
+        &ingredient_fixture().id,
+        &substitute_fixture().id,
+    )
+    .await
+    .unwrap();
+
+    let result = repo
+        .get_substitutes(&[ingredient_fixture().id])
+        .await
+        .unwrap();
+
+    assert!(result
+        .get(&ingredient_fixture().id)
+        .is_none_or(|x| x.is_empty()));
+}
+
+pub async fn deleting_a_missing_substitute_fails(repo: impl IngredientRepository) {
+    let repo = insert_substitute(repo).await;
+
+    let error = delete_substitute(repo, &ingredient_fixture().id, &Uuid::nil())
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, DeleteSubstituteError::SubstituteNotFound(id) if id.is_nil()));
+}
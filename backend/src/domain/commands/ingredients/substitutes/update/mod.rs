@@ -0,0 +1,113 @@
+use common::UpdateIngredientSubstituteDTO;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::ingredient::{errors::ValidationError, IngredientSubstitute},
+    repositories::ingredients::{
+        errors::{
+            GetIngredientByIdError, GetSubstitutesError,
+            UpdateSubstituteError as UpdateSubstituteErrorInternal,
+        },
+        IngredientRepositoryService,
+    },
+};
+
+#[derive(Debug, Default)]
+pub struct UpdateSubstitute {
+    pub ratio: Option<f64>,
+    pub note: Option<String>,
+}
+
+impl From<UpdateIngredientSubstituteDTO> for UpdateSubstitute {
+    fn from(value: UpdateIngredientSubstituteDTO) -> Self {
+        Self {
+            ratio: value.ratio,
+            note: value.note,
+        }
+    }
+}
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum UpdateSubstituteError {
+    #[error("Could not find the ingredient with ID {0}")]
+    NotFound(Uuid),
+
+    #[error("The ingredient with ID {0} is not a substitute")]
+    SubstituteNotFound(Uuid),
+
+    #[error("You did not provide any changes to be made")]
+    ChangesetEmpty,
+
+    #[error(transparent)]
+    ValidationError(#[from] ValidationError),
+
+    #[error(transparent)]
+    Internal(#[from] eyre::Error),
+}
+
+impl From<GetIngredientByIdError> for UpdateSubstituteError {
+    fn from(value: GetIngredientByIdError) -> Self {
+        match value {
+            GetIngredientByIdError::NotFound(id) => Self::NotFound(id),
+            e => Self::Internal(e.into()),
+        }
+    }
+}
+
+impl From<GetSubstitutesError> for UpdateSubstituteError {
+    fn from(value: GetSubstitutesError) -> Self {
+        Self::Internal(value.into())
+    }
+}
+
+impl From<UpdateSubstituteErrorInternal> for UpdateSubstituteError {
+    fn from(value: UpdateSubstituteErrorInternal) -> Self {
+        match value {
+            UpdateSubstituteErrorInternal::NotFound(id) => Self::SubstituteNotFound(id),
+            e => Self::Internal(e.into()),
+        }
+    }
+}
+
+#[tracing::instrument("[COMMAND] Updating a substitute of an ingredient", skip(repo))]
+pub async fn update_substitute(
+    repo: IngredientRepositoryService,
+    ingredient_id: &Uuid,
+    substitute_id: &Uuid,
+    input: &UpdateSubstitute,
+) -> Result<IngredientSubstitute, UpdateSubstituteError> {
+    if input.ratio.is_none() && input.note.is_none() {
+        return Err(UpdateSubstituteError::ChangesetEmpty);
+    }
+
+    let ingredient = repo.get_by_id(ingredient_id).await?;
+
+    let current = repo
+        .get_substitutes(&[ingredient.id])
+        .await?
+        .remove(&ingredient.id)
+        .unwrap_or_default()
+        .into_iter()
+        .find(|s| &s.substitute.id == substitute_id)
+        .ok_or(UpdateSubstituteError::SubstituteNotFound(*substitute_id))?;
+
+    let updated = IngredientSubstitute {
+        ratio: match input.ratio {
+            Some(ratio) => ratio.try_into()?,
+            None => current.ratio,
+        },
+        note: match &input.note {
+            Some(note) if note.trim().is_empty() => None,
+            Some(note) => Some(note.clone()),
+            None => current.note,
+        },
+        ..current
+    };
+
+    repo.update_substitute(&ingredient, &updated).await?;
+
+    Ok(updated)
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,77 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        commands::ingredients::substitutes::update::{
+            update_substitute, UpdateSubstitute, UpdateSubstituteError,
+        },
+        repositories::ingredients::{IngredientRepository, IngredientRepositoryService},
+    },
+    test_utils::{ingredient_fixture, insert_substitute_fixture, substitute_fixture},
+};
+
+async fn insert_substitute(repo: impl IngredientRepository) -> IngredientRepositoryService {
+    insert_substitute_fixture(&repo, Some("Marinate it first".to_string())).await;
+
+    Arc::new(Box::new(repo))
+}
+
+pub async fn updating_a_substitute_works(repo: impl IngredientRepository) {
+    let repo = insert_substitute(repo).await;
+
+    let changeset = UpdateSubstitute {
+        ratio: Some(1.5),
+        ..Default::default()
+    };
+
+    update_substitute(
+        repo.clone(),
+        &ingredient_fixture().id,
+        &substitute_fixture().id,
+        &changeset,
+    )
+    .await
+    .unwrap();
+
+    let result = repo
+        .get_substitutes(&[ingredient_fixture().id])
+        .await
+        .unwrap()
+        .remove(&ingredient_fixture().id)
+        .unwrap();
+
+    assert_eq!(*result[0].ratio, 1.5);
+    assert_eq!(result[0].note.as_deref(), Some("Marinate it first"));
+}
+
+pub async fn updating_with_empty_changeset_fails(repo: impl IngredientRepository) {
+    let repo = insert_substitute(repo).await;
+
+    let error = update_substitute(
+        repo,
+        &ingredient_fixture().id,
+        &substitute_fixture().id,
+        &UpdateSubstitute::default(),
+    )
+    .await
+    .unwrap_err();
+
+    assert!(matches!(error, UpdateSubstituteError::ChangesetEmpty));
+}
+
+pub async fn updating_a_missing_substitute_fails(repo: impl IngredientRepository) {
+    let repo = insert_substitute(repo).await;
+
+    let changeset = UpdateSubstitute {
+        ratio: Some(1.5),
+        ..Default::default()
+    };
+
+    let error = update_substitute(repo, &ingredient_fixture().id, &Uuid::nil(), &changeset)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, UpdateSubstituteError::SubstituteNotFound(id) if id.is_nil()));
+}
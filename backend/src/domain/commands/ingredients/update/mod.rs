@@ -14,6 +14,8 @@ pub struct UpdateIngredient {
     pub name: Option<String>,
     pub description: Option<String>,
     pub diet_violations: Option<Vec<String>>,
+    pub parent_id: Option<Option<Uuid>>,
+    pub allergens: Option<Vec<String>>,
 }
 
 impl From<UpdateIngredientDTO> for UpdateIngredient {
@@ -22,6 +24,11 @@ impl From<UpdateIngredientDTO> for UpdateIngredient {
             name: value.name,
             description: value.description,
             diet_violations: value.diet_violations,
+            parent_id: match value.detach_parent {
+                true => Some(None),
+                false => value.parent_id.map(Some),
+            },
+            allergens: value.allergens,
         }
     }
 }
@@ -40,11 +47,17 @@ impl TryFrom<&UpdateIngredient> for IngredientChangeset {
         };
 
         let diet_violations = value.diet_violations.as_ref().map(|x| x.clone().into());
+        let allergens = match &value.allergens {
+            Some(x) => Some(x.clone().try_into()?),
+            None => None,
+        };
 
         Ok(Self {
             name,
             description,
             diet_violations,
+            parent_id: value.parent_id,
+            allergens,
         })
     }
 }
@@ -54,6 +67,17 @@ pub enum UpdateIngredientError {
     #[error("Could not find the ingredient with ID {0}")]
     NotFound(Uuid),
 
+    #[error("The parent ingredient with ID {0} does not exist")]
+    ParentNotFound(Uuid),
+
+    #[error(
+        "The ingredient with ID {0} is a variant of this ingredient, so it can't be its parent"
+    )]
+    CyclicParent(Uuid),
+
+    #[error("The ingredient with ID {0} has been changed since you last fetched it")]
+    Conflict(Uuid),
+
     #[error(transparent)]
     ValidationError(ValidationError),
 
@@ -71,7 +95,8 @@ impl From<UpdateIngredientErrorInternal> for UpdateIngredientError {
     fn from(value: UpdateIngredientErrorInternal) -> Self {
         match value {
             UpdateIngredientErrorInternal::ValidationError(v) => Self::ValidationError(v),
-            e => e.into(),
+            UpdateIngredientErrorInternal::Outdated(id) => Self::Conflict(id),
+            e => Self::Internal(e.into()),
         }
     }
 }
@@ -85,14 +110,53 @@ impl From<GetIngredientByIdError> for UpdateIngredientError {
     }
 }
 
+/// Makes sure the parent exists and that making it the parent wouldn't close a loop,
+/// which is the case when it's the ingredient itself or one of its variants.
+async fn validate_parent(
+    repo: &IngredientRepositoryService,
+    id: &Uuid,
+    parent_id: &Uuid,
+) -> Result<(), UpdateIngredientError> {
+    if parent_id == id {
+        return Err(UpdateIngredientError::CyclicParent(*parent_id));
+    }
+
+    repo.get_by_id(parent_id).await.map_err(|e| match e {
+        GetIngredientByIdError::NotFound(id) => UpdateIngredientError::ParentNotFound(id),
+        e => UpdateIngredientError::Internal(e.into()),
+    })?;
+
+    let variants = repo
+        .get_variants(id)
+        .await
+        .map_err(|e| UpdateIngredientError::Internal(e.into()))?;
+
+    if variants.iter().any(|v| &v.id == parent_id) {
+        return Err(UpdateIngredientError::CyclicParent(*parent_id));
+    }
+
+    Ok(())
+}
+
+/// When expected versions are given, the ingredient is only updated if it's still in one of them.
 #[tracing::instrument("[COMMAND] Updating an existing ingredient", skip(repo))]
 pub async fn update_ingredient(
     repo: IngredientRepositoryService,
     id: Uuid,
     input: &UpdateIngredient,
+    expected_versions: Option<&[String]>,
 ) -> Result<Ingredient, UpdateIngredientError> {
     let ingredient_to_change = repo.get_by_id(&id).await?;
 
+    if expected_versions.is_some_and(|versions| !versions.contains(&ingredient_to_change.version()))
+    {
+        return Err(UpdateIngredientError::Conflict(id));
+    }
+
+    if let Some(Some(parent_id)) = input.parent_id {
+        validate_parent(&repo, &id, &parent_id).await?;
+    }
+
     tracing::info!("Serializing input into a changeset");
     let ingredient: IngredientChangeset = input.try_into()?;
 
@@ -9,7 +9,7 @@ use crate::{
         },
         entities::ingredient::{
             errors::ValidationError,
-            types::{IngredientName, WhichDiets},
+            types::{Allergen, Allergens, IngredientName, WhichDiets},
             Ingredient,
         },
         repositories::ingredients::{IngredientRepository, IngredientRepositoryService},
@@ -28,7 +28,7 @@ pub async fn updating_an_ingredient_success(repo: impl IngredientRepository) {
 
     repo.insert(input.clone()).await.unwrap();
 
-    update_ingredient(repo.clone(), input.id, &changeset)
+    update_ingredient(repo.clone(), input.id, &changeset, None)
         .await
         .unwrap();
 
@@ -51,17 +51,19 @@ pub async fn updating_with_empty_changeset_fails(repo: impl IngredientRepository
         name: "Ingredient name 1".try_into().unwrap(),
         description: "Ingredient description 1".try_into().unwrap(),
         diet_violations: WhichDiets::new(),
+        parent_id: None,
+        allergens: Allergens::new(),
     };
     let changeset = UpdateIngredient::default();
 
     repo.insert(input.clone()).await.unwrap();
 
-    let error = update_ingredient(repo.clone(), input.id, &changeset)
+    let error = update_ingredient(repo.clone(), input.id, &changeset, None)
         .await
         .unwrap_err();
 
     assert!(
-        matches!(error, UpdateIngredientError::ValidationError(ValidationError::EmptyField(fields)) if fields == ["name", "description", "diet_violations"])
+        matches!(error, UpdateIngredientError::ValidationError(ValidationError::EmptyField(fields)) if fields == ["name", "description", "diet_violations", "parent_id", "allergens"])
     );
 }
 
@@ -73,9 +75,182 @@ pub async fn updating_a_missing_file_fails(repo: impl IngredientRepository) {
         ..Default::default()
     };
 
-    let error = update_ingredient(repo.clone(), input.id, &changeset)
+    let error = update_ingredient(repo.clone(), input.id, &changeset, None)
         .await
         .unwrap_err();
 
     assert!(matches!(error, UpdateIngredientError::NotFound(id) if id == Uuid::from_u128(64)));
 }
+
+fn soy_sauces() -> [Ingredient; 3] {
+    let ingredient = |id: u128, name: &str, parent_id: Option<u128>| Ingredient {
+        id: Uuid::from_u128(id),
+        name: name.try_into().unwrap(),
+        description: "Fermented soybeans".try_into().unwrap(),
+        diet_violations: WhichDiets::new(),
+        parent_id: parent_id.map(Uuid::from_u128),
+        allergens: Allergens::new(),
+    };
+
+    [
+        ingredient(1, "Soy sauce", None),
+        ingredient(2, "Dark soy sauce", Some(1)),
+        ingredient(3, "Mushroom dark soy sauce", Some(2)),
+    ]
+}
+
+pub async fn setting_a_parent_works(repo: impl IngredientRepository) {
+    let repo: IngredientRepositoryService = Arc::new(Box::new(repo));
+    let [soy_sauce, dark, _] = soy_sauces();
+    let light = Ingredient {
+        id: Uuid::from_u128(4),
+        name: "Light soy sauce".try_into().unwrap(),
+        ..soy_sauce.clone()
+    };
+
+    for i in [&soy_sauce, &dark, &light] {
+        repo.insert(i.clone()).await.unwrap();
+    }
+
+    let changeset = UpdateIngredient {
+        parent_id: Some(Some(soy_sauce.id)),
+        ..Default::default()
+    };
+    let result = update_ingredient(repo.clone(), light.id, &changeset, None)
+        .await
+        .unwrap();
+    assert_eq!(result.parent_id, Some(soy_sauce.id));
+
+    let changeset = UpdateIngredient {
+        parent_id: Some(None),
+        ..Default::default()
+    };
+    let result = update_ingredient(repo.clone(), dark.id, &changeset, None)
+        .await
+        .unwrap();
+    assert_eq!(result.parent_id, None);
+}
+
+pub async fn setting_a_variant_as_parent_fails(repo: impl IngredientRepository) {
+    let repo: IngredientRepositoryService = Arc::new(Box::new(repo));
+    let [soy_sauce, dark, mushroom] = soy_sauces();
+
+    for i in [&soy_sauce, &dark, &mushroom] {
+        repo.insert(i.clone()).await.unwrap();
+    }
+
+    for parent_id in [soy_sauce.id, mushroom.id] {
+        let changeset = UpdateIngredient {
+            parent_id: Some(Some(parent_id)),
+            ..Default::default()
+        };
+
+        let error = update_ingredient(repo.clone(), soy_sauce.id, &changeset, None)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(error, UpdateIngredientError::CyclicParent(id) if id == parent_id));
+    }
+
+    let changeset = UpdateIngredient {
+        parent_id: Some(Some(Uuid::nil())),
+        ..Default::default()
+    };
+    let error = update_ingredient(repo.clone(), soy_sauce.id, &changeset, None)
+        .await
+        .unwrap_err();
+    assert!(matches!(error, UpdateIngredientError::ParentNotFound(id) if id.is_nil()));
+}
+
+pub async fn setting_allergens_works(repo: impl IngredientRepository) {
+    let repo: IngredientRepositoryService = Arc::new(Box::new(repo));
+    let input = ingredient_fixture();
+
+    repo.insert(input.clone()).await.unwrap();
+
+    let changeset = UpdateIngredient {
+        allergens: Some(vec![
+            "soy".to_string(),
+            "milk".to_string(),
+            "Soy".to_string(),
+        ]),
+        ..Default::default()
+    };
+    let result = update_ingredient(repo.clone(), input.id, &changeset, None)
+        .await
+        .unwrap();
+    assert_eq!(result.allergens, vec![Allergen::Milk, Allergen::Soy].into());
+    assert_eq!(result.name, input.name);
+
+    let changeset = UpdateIngredient {
+        allergens: Some(vec![]),
+        ..Default::default()
+    };
+    let result = update_ingredient(repo.clone(), input.id, &changeset, None)
+        .await
+        .unwrap();
+    assert_eq!(result.allergens, Allergens::new());
+}
+
+pub async fn setting_unknown_allergens_fails(repo: impl IngredientRepository) {
+    let repo: IngredientRepositoryService = Arc::new(Box::new(repo));
+    let input = ingredient_fixture();
+
+    repo.insert(input.clone()).await.unwrap();
+
+    let changeset = UpdateIngredient {
+        allergens: Some(vec!["milk".to_string(), "gluten".to_string()]),
+        ..Default::default()
+    };
+    let error = update_ingredient(repo.clone(), input.id, &changeset, None)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(
+        error,
+        UpdateIngredientError::ValidationError(ValidationError::DoesNotMatch("allergens", _))
+    ));
+    assert_eq!(repo.get_by_id(&input.id).await.unwrap(), input);
+}
+
+pub async fn updating_an_outdated_version_of_an_ingredient_conflicts(
+    repo: impl IngredientRepository,
+) {
+    let repo: IngredientRepositoryService = Arc::new(Box::new(repo));
+
+    let input = ingredient_fixture();
+    let version = input.version();
+
+    repo.insert(input.clone()).await.unwrap();
+
+    let changeset = UpdateIngredient {
+        name: Some("Ingredient name changed".to_string()),
+        ..Default::default()
+    };
+    update_ingredient(
+        repo.clone(),
+        input.id,
+        &changeset,
+        Some(std::slice::from_ref(&version)),
+    )
+    .await
+    .unwrap();
+
+    let changeset = UpdateIngredient {
+        description: Some("Someone else's change".to_string()),
+        ..Default::default()
+    };
+    let error = update_ingredient(
+        repo.clone(),
+        input.id,
+        &changeset,
+        Some(std::slice::from_ref(&version)),
+    )
+    .await
+    .unwrap_err();
+
+    assert!(matches!(error, UpdateIngredientError::Conflict(id) if id == input.id));
+
+    let unchanged = repo.get_by_id(&input.id).await.unwrap();
+    assert_eq!(unchanged.description, input.description);
+}
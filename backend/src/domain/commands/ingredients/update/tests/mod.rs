@@ -22,6 +22,36 @@ mod in_memory {
         let repo = InMemoryIngredientRepository::new();
         __tests__::updating_a_missing_file_fails(repo).await
     }
+
+    #[tokio::test]
+    async fn setting_a_parent_works() {
+        let repo = InMemoryIngredientRepository::new();
+        __tests__::setting_a_parent_works(repo).await
+    }
+
+    #[tokio::test]
+    async fn setting_allergens_works() {
+        let repo = InMemoryIngredientRepository::new();
+        __tests__::setting_allergens_works(repo).await
+    }
+
+    #[tokio::test]
+    async fn setting_unknown_allergens_fails() {
+        let repo = InMemoryIngredientRepository::new();
+        __tests__::setting_unknown_allergens_fails(repo).await
+    }
+
+    #[tokio::test]
+    async fn setting_a_variant_as_parent_fails() {
+        let repo = InMemoryIngredientRepository::new();
+        __tests__::setting_a_variant_as_parent_fails(repo).await
+    }
+
+    #[tokio::test]
+    async fn updating_an_outdated_version_of_an_ingredient_conflicts() {
+        let repo = InMemoryIngredientRepository::new();
+        __tests__::updating_an_outdated_version_of_an_ingredient_conflicts(repo).await
+    }
 }
 
 mod sql {
@@ -47,4 +77,34 @@ mod sql {
         let repo = PostgresIngredientRepository::new(pool.clone());
         __tests__::updating_a_missing_file_fails(repo).await
     }
+
+    #[sqlx::test]
+    async fn setting_a_parent_works(pool: PgPool) {
+        let repo = PostgresIngredientRepository::new(pool.clone());
+        __tests__::setting_a_parent_works(repo).await
+    }
+
+    #[sqlx::test]
+    async fn setting_allergens_works(pool: PgPool) {
+        let repo = PostgresIngredientRepository::new(pool.clone());
+        __tests__::setting_allergens_works(repo).await
+    }
+
+    #[sqlx::test]
+    async fn setting_unknown_allergens_fails(pool: PgPool) {
+        let repo = PostgresIngredientRepository::new(pool.clone());
+        __tests__::setting_unknown_allergens_fails(repo).await
+    }
+
+    #[sqlx::test]
+    async fn setting_a_variant_as_parent_fails(pool: PgPool) {
+        let repo = PostgresIngredientRepository::new(pool.clone());
+        __tests__::setting_a_variant_as_parent_fails(repo).await
+    }
+
+    #[sqlx::test]
+    async fn updating_an_outdated_version_of_an_ingredient_conflicts(pool: PgPool) {
+        let repo = PostgresIngredientRepository::new(pool.clone());
+        __tests__::updating_an_outdated_version_of_an_ingredient_conflicts(repo).await
+    }
 }
@@ -0,0 +1,78 @@
+use chrono::{NaiveDate, Utc};
+use common::CreateMealPlanDTO;
+use uuid::Uuid;
+
+use crate::domain::entities::meal_plan::{errors::ValidationError, MealPlan, PlannedMeal};
+use crate::domain::repositories::{
+    meal_plans::{errors::InsertMealPlanError, MealPlanRepositoryService},
+    recipe::{errors::GetRecipeByIdError, RecipeRepositoryService},
+};
+
+use super::ensure_recipes_exist;
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum CreateMealPlanError {
+    #[error("Could not find the recipe with the ID {0}")]
+    RecipeNotFound(Uuid),
+
+    #[error("You already have a meal plan starting on {0}")]
+    Conflict(NaiveDate),
+
+    #[error(transparent)]
+    Validation(#[from] ValidationError),
+
+    #[error(transparent)]
+    Unknown(#[from] eyre::Error),
+}
+
+impl From<GetRecipeByIdError> for CreateMealPlanError {
+    fn from(value: GetRecipeByIdError) -> Self {
+        match value {
+            GetRecipeByIdError::NotFound(id) => Self::RecipeNotFound(id),
+            e => Self::Unknown(e.into()),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct CreateMealPlan {
+    pub starts_on: NaiveDate,
+    pub meals: Vec<PlannedMeal>,
+}
+
+impl From<CreateMealPlanDTO> for CreateMealPlan {
+    fn from(value: CreateMealPlanDTO) -> Self {
+        Self {
+            starts_on: value.starts_on,
+            meals: value.meals.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+pub async fn create_meal_plan(
+    meal_plan_repo: MealPlanRepositoryService,
+    recipe_repo: RecipeRepositoryService,
+    owner_id: &Uuid,
+    input: CreateMealPlan,
+) -> Result<MealPlan, CreateMealPlanError> {
+    let plan = MealPlan {
+        id: Uuid::now_v7(),
+        owner_id: *owner_id,
+        starts_on: input.starts_on,
+        meals: input.meals,
+        created_at: Utc::now(),
+        updated_at: Utc::now(),
+    }
+    .validated()?;
+
+    ensure_recipes_exist(&recipe_repo, &plan.meals).await?;
+
+    meal_plan_repo.insert(plan).await.map_err(|e| match e {
+        InsertMealPlanError::Conflict(_) => CreateMealPlanError::Conflict(input.starts_on),
+        InsertMealPlanError::ValidationError(e) => CreateMealPlanError::Validation(e),
+        e => CreateMealPlanError::Unknown(e.into()),
+    })
+}
+
+#[cfg(test)]
+mod tests;
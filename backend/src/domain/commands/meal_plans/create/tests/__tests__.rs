@@ -0,0 +1,118 @@
+use std::sync::Arc;
+
+use chrono::Days;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        commands::meal_plans::create::{create_meal_plan, CreateMealPlan, CreateMealPlanError},
+        entities::meal_plan::PlannedMeal,
+        repositories::{
+            ingredients::IngredientRepository,
+            meal_plans::{MealPlanRepository, MealPlanRepositoryService},
+            recipe::{RecipeRepository, RecipeRepositoryService},
+            users::UserRepository,
+        },
+    },
+    test_utils::{insert_meal_plan_dependencies, meal_plan_fixture, user_fixture},
+};
+
+fn input() -> CreateMealPlan {
+    let fixture = meal_plan_fixture();
+
+    CreateMealPlan {
+        starts_on: fixture.starts_on,
+        // Out of order on purpose
+        meals: fixture.meals.into_iter().rev().collect(),
+    }
+}
+
+pub async fn creating_a_meal_plan_succeeds(
+    repo: impl MealPlanRepository,
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    insert_meal_plan_dependencies(&user_repo, &recipe_repo, &ingredient_repo).await;
+    let repo: MealPlanRepositoryService = Arc::new(Box::new(repo));
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+
+    let plan = create_meal_plan(repo.clone(), recipe_repo, &user_fixture().id, input())
+        .await
+        .unwrap();
+
+    assert_eq!(plan.owner_id, user_fixture().id);
+    assert_eq!(plan.meals, meal_plan_fixture().meals);
+    assert_eq!(repo.get_by_id(&plan.id).await.unwrap().meals, plan.meals);
+}
+
+pub async fn creating_a_meal_plan_with_nonexistent_recipe_fails(
+    repo: impl MealPlanRepository,
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    insert_meal_plan_dependencies(&user_repo, &recipe_repo, &ingredient_repo).await;
+    let repo: MealPlanRepositoryService = Arc::new(Box::new(repo));
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+    let missing = Uuid::from_u128(0xdead);
+    let mut input = input();
+    input.meals.push(PlannedMeal {
+        recipe_id: missing,
+        ..input.meals[0].clone()
+    });
+
+    let error = create_meal_plan(repo.clone(), recipe_repo, &user_fixture().id, input)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, CreateMealPlanError::RecipeNotFound(id) if id == missing));
+    assert!(repo
+        .get_all_of_user(&user_fixture().id)
+        .await
+        .unwrap()
+        .is_empty());
+}
+
+pub async fn creating_a_meal_plan_outside_of_its_week_fails(
+    repo: impl MealPlanRepository,
+    recipe_repo: impl RecipeRepository,
+) {
+    let repo: MealPlanRepositoryService = Arc::new(Box::new(repo));
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+    let mut input = input();
+    input.meals[0].date = input.starts_on + Days::new(10);
+
+    let error = create_meal_plan(repo, recipe_repo, &user_fixture().id, input)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, CreateMealPlanError::Validation(_)));
+}
+
+pub async fn creating_two_plans_starting_on_the_same_day_conflicts(
+    repo: impl MealPlanRepository,
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    insert_meal_plan_dependencies(&user_repo, &recipe_repo, &ingredient_repo).await;
+    let repo: MealPlanRepositoryService = Arc::new(Box::new(repo));
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+
+    create_meal_plan(
+        repo.clone(),
+        recipe_repo.clone(),
+        &user_fixture().id,
+        input(),
+    )
+    .await
+    .unwrap();
+    let error = create_meal_plan(repo, recipe_repo, &user_fixture().id, input())
+        .await
+        .unwrap_err();
+
+    assert!(
+        matches!(error, CreateMealPlanError::Conflict(day) if day == meal_plan_fixture().starts_on)
+    );
+}
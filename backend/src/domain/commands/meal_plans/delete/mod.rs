@@ -0,0 +1,55 @@
+use uuid::Uuid;
+
+use crate::domain::repositories::meal_plans::{
+    errors::{DeleteMealPlanError as DeleteMealPlanErrorInternal, GetMealPlanByIdError},
+    MealPlanRepositoryService,
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum DeleteMealPlanError {
+    #[error("Could not find the meal plan with the ID {0}")]
+    NotFound(Uuid),
+
+    #[error("You are not allowed to delete the meal plan with ID {0}")]
+    Forbidden(Uuid),
+
+    #[error(transparent)]
+    Unknown(#[from] eyre::Error),
+}
+
+impl From<GetMealPlanByIdError> for DeleteMealPlanError {
+    fn from(value: GetMealPlanByIdError) -> Self {
+        match value {
+            GetMealPlanByIdError::NotFound(id) => Self::NotFound(id),
+            e => Self::Unknown(e.into()),
+        }
+    }
+}
+
+impl From<DeleteMealPlanErrorInternal> for DeleteMealPlanError {
+    fn from(value: DeleteMealPlanErrorInternal) -> Self {
+        match value {
+            DeleteMealPlanErrorInternal::NotFound(id) => Self::NotFound(id),
+            e => Self::Unknown(e.into()),
+        }
+    }
+}
+
+pub async fn delete_meal_plan(
+    meal_plan_repo: MealPlanRepositoryService,
+    user_id: &Uuid,
+    id: &Uuid,
+) -> Result<(), DeleteMealPlanError> {
+    let plan = meal_plan_repo.get_by_id(id).await?;
+
+    if !plan.belongs_to(user_id) {
+        return Err(DeleteMealPlanError::Forbidden(plan.id));
+    }
+
+    meal_plan_repo.delete(&plan).await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests;
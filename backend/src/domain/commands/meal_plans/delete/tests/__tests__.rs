@@ -0,0 +1,62 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        commands::meal_plans::delete::{delete_meal_plan, DeleteMealPlanError},
+        repositories::{
+            ingredients::IngredientRepository,
+            meal_plans::{MealPlanRepository, MealPlanRepositoryService},
+            recipe::RecipeRepository,
+            users::UserRepository,
+        },
+    },
+    test_utils::{insert_meal_plan_dependencies, meal_plan_fixture, user_fixture},
+};
+
+pub async fn deleting_a_meal_plan_succeeds(
+    repo: impl MealPlanRepository,
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    insert_meal_plan_dependencies(&user_repo, &recipe_repo, &ingredient_repo).await;
+    let plan = repo.insert(meal_plan_fixture()).await.unwrap();
+    let repo: MealPlanRepositoryService = Arc::new(Box::new(repo));
+
+    delete_meal_plan(repo.clone(), &user_fixture().id, &plan.id)
+        .await
+        .unwrap();
+
+    assert!(repo.get_by_id(&plan.id).await.is_err());
+}
+
+pub async fn deleting_a_nonexistent_meal_plan_fails(repo: impl MealPlanRepository) {
+    let repo: MealPlanRepositoryService = Arc::new(Box::new(repo));
+    let id = meal_plan_fixture().id;
+
+    let error = delete_meal_plan(repo, &user_fixture().id, &id)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, DeleteMealPlanError::NotFound(missing) if missing == id));
+}
+
+pub async fn deleting_someone_elses_meal_plan_is_forbidden(
+    repo: impl MealPlanRepository,
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    insert_meal_plan_dependencies(&user_repo, &recipe_repo, &ingredient_repo).await;
+    let plan = repo.insert(meal_plan_fixture()).await.unwrap();
+    let repo: MealPlanRepositoryService = Arc::new(Box::new(repo));
+
+    let error = delete_meal_plan(repo.clone(), &Uuid::from_u128(0xdead), &plan.id)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, DeleteMealPlanError::Forbidden(id) if id == plan.id));
+    assert!(repo.get_by_id(&plan.id).await.is_ok());
+}
@@ -0,0 +1,24 @@
+use std::collections::BTreeSet;
+
+use crate::domain::{
+    entities::meal_plan::PlannedMeal,
+    repositories::recipe::{errors::GetRecipeByIdError, RecipeRepositoryService},
+};
+
+pub mod create;
+pub mod delete;
+pub mod update;
+
+/// Fails with the first recipe of the meals that doesn't exist.
+async fn ensure_recipes_exist(
+    recipe_repo: &RecipeRepositoryService,
+    meals: &[PlannedMeal],
+) -> Result<(), GetRecipeByIdError> {
+    let recipe_ids: BTreeSet<_> = meals.iter().map(|m| m.recipe_id).collect();
+
+    for recipe_id in recipe_ids {
+        recipe_repo.get_by_id(&recipe_id).await?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,107 @@
+use chrono::NaiveDate;
+use common::UpdateMealPlanDTO;
+use uuid::Uuid;
+
+use crate::domain::entities::meal_plan::{errors::ValidationError, MealPlan, PlannedMeal};
+use crate::domain::repositories::{
+    meal_plans::{
+        errors::{GetMealPlanByIdError, UpdateMealPlanError as UpdateMealPlanErrorInternal},
+        MealPlanRepositoryService,
+    },
+    recipe::{errors::GetRecipeByIdError, RecipeRepositoryService},
+};
+
+use super::ensure_recipes_exist;
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum UpdateMealPlanError {
+    #[error("Could not find the meal plan with the ID {0}")]
+    NotFound(Uuid),
+
+    #[error("You are not allowed to change the meal plan with ID {0}")]
+    Forbidden(Uuid),
+
+    #[error("Could not find the recipe with the ID {0}")]
+    RecipeNotFound(Uuid),
+
+    #[error("You already have a meal plan starting on {0}")]
+    Conflict(NaiveDate),
+
+    #[error(transparent)]
+    Validation(#[from] ValidationError),
+
+    #[error(transparent)]
+    Unknown(#[from] eyre::Error),
+}
+
+impl From<GetMealPlanByIdError> for UpdateMealPlanError {
+    fn from(value: GetMealPlanByIdError) -> Self {
+        match value {
+            GetMealPlanByIdError::NotFound(id) => Self::NotFound(id),
+            e => Self::Unknown(e.into()),
+        }
+    }
+}
+
+impl From<GetRecipeByIdError> for UpdateMealPlanError {
+    fn from(value: GetRecipeByIdError) -> Self {
+        match value {
+            GetRecipeByIdError::NotFound(id) => Self::RecipeNotFound(id),
+            e => Self::Unknown(e.into()),
+        }
+    }
+}
+
+/// The meals, when given, replace all of the meals of the plan.
+#[derive(Debug, Default)]
+pub struct UpdateMealPlan {
+    pub starts_on: Option<NaiveDate>,
+    pub meals: Option<Vec<PlannedMeal>>,
+}
+
+impl From<UpdateMealPlanDTO> for UpdateMealPlan {
+    fn from(value: UpdateMealPlanDTO) -> Self {
+        Self {
+            starts_on: value.starts_on,
+            meals: value
+                .meals
+                .map(|meals| meals.into_iter().map(Into::into).collect()),
+        }
+    }
+}
+
+/// Moving the plan to another week without giving new meals only works when the meals still fit in it.
+pub async fn update_meal_plan(
+    meal_plan_repo: MealPlanRepositoryService,
+    recipe_repo: RecipeRepositoryService,
+    user_id: &Uuid,
+    id: &Uuid,
+    input: UpdateMealPlan,
+) -> Result<MealPlan, UpdateMealPlanError> {
+    let plan = meal_plan_repo.get_by_id(id).await?;
+
+    if !plan.belongs_to(user_id) {
+        return Err(UpdateMealPlanError::Forbidden(plan.id));
+    }
+
+    let plan = MealPlan {
+        starts_on: input.starts_on.unwrap_or(plan.starts_on),
+        meals: input.meals.unwrap_or(plan.meals),
+        ..plan
+    }
+    .validated()?;
+
+    ensure_recipes_exist(&recipe_repo, &plan.meals).await?;
+
+    let starts_on = plan.starts_on;
+
+    meal_plan_repo.update(plan).await.map_err(|e| match e {
+        UpdateMealPlanErrorInternal::NotFound(id) => UpdateMealPlanError::NotFound(id),
+        UpdateMealPlanErrorInternal::Conflict(_) => UpdateMealPlanError::Conflict(starts_on),
+        UpdateMealPlanErrorInternal::ValidationError(e) => UpdateMealPlanError::Validation(e),
+        e => UpdateMealPlanError::Unknown(e.into()),
+    })
+}
+
+#[cfg(test)]
+mod tests;
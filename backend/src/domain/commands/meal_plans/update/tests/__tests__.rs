@@ -0,0 +1,111 @@
+use std::sync::Arc;
+
+use chrono::Days;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        commands::meal_plans::update::{update_meal_plan, UpdateMealPlan, UpdateMealPlanError},
+        entities::meal_plan::{MealSlot, PlannedMeal},
+        repositories::{
+            ingredients::IngredientRepository,
+            meal_plans::{MealPlanRepository, MealPlanRepositoryService},
+            recipe::{RecipeRepository, RecipeRepositoryService},
+            users::UserRepository,
+        },
+    },
+    test_utils::{insert_meal_plan_dependencies, meal_plan_fixture, user_fixture},
+};
+
+pub async fn updating_a_meal_plan_succeeds(
+    repo: impl MealPlanRepository,
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    insert_meal_plan_dependencies(&user_repo, &recipe_repo, &ingredient_repo).await;
+    let plan = repo.insert(meal_plan_fixture()).await.unwrap();
+    let repo: MealPlanRepositoryService = Arc::new(Box::new(repo));
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+    let starts_on = plan.starts_on + Days::new(7);
+    let meals = vec![PlannedMeal {
+        date: starts_on + Days::new(6),
+        slot: MealSlot::Snack,
+        ..plan.meals[0].clone()
+    }];
+
+    let updated = update_meal_plan(
+        repo.clone(),
+        recipe_repo,
+        &user_fixture().id,
+        &plan.id,
+        UpdateMealPlan {
+            starts_on: Some(starts_on),
+            meals: Some(meals.clone()),
+        },
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(updated.starts_on, starts_on);
+    assert_eq!(updated.meals, meals);
+
+    let stored = repo.get_by_id(&plan.id).await.unwrap();
+    assert_eq!(stored.starts_on, starts_on);
+    assert_eq!(stored.meals, meals);
+}
+
+pub async fn updating_someone_elses_meal_plan_is_forbidden(
+    repo: impl MealPlanRepository,
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    insert_meal_plan_dependencies(&user_repo, &recipe_repo, &ingredient_repo).await;
+    let plan = repo.insert(meal_plan_fixture()).await.unwrap();
+    let repo: MealPlanRepositoryService = Arc::new(Box::new(repo));
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+
+    let error = update_meal_plan(
+        repo.clone(),
+        recipe_repo,
+        &Uuid::from_u128(0xdead),
+        &plan.id,
+        UpdateMealPlan {
+            meals: Some(vec![]),
+            ..Default::default()
+        },
+    )
+    .await
+    .unwrap_err();
+
+    assert!(matches!(error, UpdateMealPlanError::Forbidden(id) if id == plan.id));
+    assert_eq!(repo.get_by_id(&plan.id).await.unwrap().meals, plan.meals);
+}
+
+pub async fn moving_a_meal_plan_away_from_its_meals_fails(
+    repo: impl MealPlanRepository,
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    insert_meal_plan_dependencies(&user_repo, &recipe_repo, &ingredient_repo).await;
+    let plan = repo.insert(meal_plan_fixture()).await.unwrap();
+    let repo: MealPlanRepositoryService = Arc::new(Box::new(repo));
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+
+    let error = update_meal_plan(
+        repo,
+        recipe_repo,
+        &user_fixture().id,
+        &plan.id,
+        UpdateMealPlan {
+            starts_on: Some(plan.starts_on + Days::new(7)),
+            ..Default::default()
+        },
+    )
+    .await
+    .unwrap_err();
+
+    assert!(matches!(error, UpdateMealPlanError::Validation(_)));
+}
@@ -0,0 +1,103 @@
+mod __tests__;
+
+mod in_memory {
+    use crate::domain::repositories::{
+        ingredients::in_memory::InMemoryIngredientRepository,
+        meal_plans::in_memory::InMemoryMealPlanRepository,
+        recipe::in_memory::InMemoryRecipeRepository, users::in_memory::InMemoryUserRepository,
+    };
+
+    use super::__tests__;
+
+    #[tokio::test]
+    async fn updating_a_meal_plan_succeeds() {
+        let repo = InMemoryMealPlanRepository::new();
+        let recipe_repo = InMemoryRecipeRepository::new();
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let user_repo = InMemoryUserRepository::new();
+        __tests__::updating_a_meal_plan_succeeds(repo, recipe_repo, ingredient_repo, user_repo)
+            .await
+    }
+
+    #[tokio::test]
+    async fn updating_someone_elses_meal_plan_is_forbidden() {
+        let repo = InMemoryMealPlanRepository::new();
+        let recipe_repo = InMemoryRecipeRepository::new();
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let user_repo = InMemoryUserRepository::new();
+        __tests__::updating_someone_elses_meal_plan_is_forbidden(
+            repo,
+            recipe_repo,
+            ingredient_repo,
+            user_repo,
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn moving_a_meal_plan_away_from_its_meals_fails() {
+        let repo = InMemoryMealPlanRepository::new();
+        let recipe_repo = InMemoryRecipeRepository::new();
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let user_repo = InMemoryUserRepository::new();
+        __tests__::moving_a_meal_plan_away_from_its_meals_fails(
+            repo,
+            recipe_repo,
+            ingredient_repo,
+            user_repo,
+        )
+        .await
+    }
+}
+
+mod sql {
+    use sqlx::PgPool;
+
+    use crate::domain::repositories::{
+        ingredients::postgres::PostgresIngredientRepository,
+        meal_plans::postgres::PostgresMealPlanRepository,
+        recipe::postgres::PostgresRecipeRepository, users::postgres::PostgresUserRepository,
+    };
+
+    use super::__tests__;
+
+    #[sqlx::test]
+    async fn updating_a_meal_plan_succeeds(pool: PgPool) {
+        let repo = PostgresMealPlanRepository::new(pool.clone());
+        let recipe_repo = PostgresRecipeRepository::new(pool.clone());
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let user_repo = PostgresUserRepository::new(pool.clone());
+        __tests__::updating_a_meal_plan_succeeds(repo, recipe_repo, ingredient_repo, user_repo)
+            .await
+    }
+
+    #[sqlx::test]
+    async fn updating_someone_elses_meal_plan_is_forbidden(pool: PgPool) {
+        let repo = PostgresMealPlanRepository::new(pool.clone());
+        let recipe_repo = PostgresRecipeRepository::new(pool.clone());
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let user_repo = PostgresUserRepository::new(pool.clone());
+        __tests__::updating_someone_elses_meal_plan_is_forbidden(
+            repo,
+            recipe_repo,
+            ingredient_repo,
+            user_repo,
+        )
+        .await
+    }
+
+    #[sqlx::test]
+    async fn moving_a_meal_plan_away_from_its_meals_fails(pool: PgPool) {
+        let repo = PostgresMealPlanRepository::new(pool.clone());
+        let recipe_repo = PostgresRecipeRepository::new(pool.clone());
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let user_repo = PostgresUserRepository::new(pool.clone());
+        __tests__::moving_a_meal_plan_away_from_its_meals_fails(
+            repo,
+            recipe_repo,
+            ingredient_repo,
+            user_repo,
+        )
+        .await
+    }
+}
@@ -0,0 +1,86 @@
+use chrono::NaiveDate;
+use common::AddPantryItemDTO;
+use uuid::Uuid;
+
+use crate::domain::entities::{pantry::PantryItem, recipe::IngredientUnit};
+use crate::domain::repositories::{
+    ingredients::{errors::GetIngredientByIdError, IngredientRepositoryService},
+    pantry::{errors::InsertPantryItemError, PantryRepositoryService},
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum AddPantryItemError {
+    #[error("Could not find the ingredient with the ID {0}")]
+    IngredientNotFound(Uuid),
+
+    #[error("The ingredient with ID {0} is already in your pantry")]
+    Conflict(Uuid),
+
+    #[error("The amount in the pantry has to be above zero")]
+    InvalidAmount,
+
+    #[error(transparent)]
+    Unknown(#[from] eyre::Error),
+}
+
+impl From<GetIngredientByIdError> for AddPantryItemError {
+    fn from(value: GetIngredientByIdError) -> Self {
+        match value {
+            GetIngredientByIdError::NotFound(id) => Self::IngredientNotFound(id),
+            e => Self::Unknown(e.into()),
+        }
+    }
+}
+
+impl From<InsertPantryItemError> for AddPantryItemError {
+    fn from(value: InsertPantryItemError) -> Self {
+        match value {
+            InsertPantryItemError::Conflict(id) => Self::Conflict(id),
+            e => Self::Unknown(e.into()),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct AddPantryItem {
+    pub ingredient_id: Uuid,
+    pub amount: IngredientUnit,
+    pub expires_on: Option<NaiveDate>,
+}
+
+impl From<AddPantryItemDTO> for AddPantryItem {
+    fn from(value: AddPantryItemDTO) -> Self {
+        Self {
+            ingredient_id: value.ingredient_id,
+            amount: value.amount.into(),
+            expires_on: value.expires_on,
+        }
+    }
+}
+
+pub async fn add_pantry_item(
+    pantry_repo: PantryRepositoryService,
+    ingredient_repo: IngredientRepositoryService,
+    owner_id: &Uuid,
+    input: AddPantryItem,
+) -> Result<PantryItem, AddPantryItemError> {
+    if input.amount.amount() <= 0.0 {
+        return Err(AddPantryItemError::InvalidAmount);
+    }
+
+    let ingredient = ingredient_repo.get_by_id(&input.ingredient_id).await?;
+
+    let item = pantry_repo
+        .insert(PantryItem {
+            owner_id: *owner_id,
+            ingredient,
+            amount: input.amount,
+            expires_on: input.expires_on,
+        })
+        .await?;
+
+    Ok(item)
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,117 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        commands::pantry::add::{add_pantry_item, AddPantryItem, AddPantryItemError},
+        entities::recipe::IngredientUnit,
+        repositories::{
+            ingredients::{IngredientRepository, IngredientRepositoryService},
+            pantry::{PantryRepository, PantryRepositoryService},
+            users::UserRepository,
+        },
+    },
+    test_utils::{insert_pantry_dependencies, pantry_item_fixture, user_fixture},
+};
+
+fn input() -> AddPantryItem {
+    let fixture = pantry_item_fixture();
+
+    AddPantryItem {
+        ingredient_id: fixture.ingredient.id,
+        amount: fixture.amount,
+        expires_on: fixture.expires_on,
+    }
+}
+
+pub async fn adding_a_pantry_item_succeeds(
+    repo: impl PantryRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    insert_pantry_dependencies(&user_repo, &ingredient_repo).await;
+    let repo: PantryRepositoryService = Arc::new(Box::new(repo));
+    let ingredient_repo: IngredientRepositoryService = Arc::new(Box::new(ingredient_repo));
+
+    let item = add_pantry_item(repo.clone(), ingredient_repo, &user_fixture().id, input())
+        .await
+        .unwrap();
+
+    assert_eq!(item, pantry_item_fixture());
+    assert_eq!(
+        repo.get_all_of_user(&user_fixture().id).await.unwrap(),
+        vec![pantry_item_fixture()]
+    );
+}
+
+pub async fn adding_the_same_ingredient_twice_conflicts(
+    repo: impl PantryRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    insert_pantry_dependencies(&user_repo, &ingredient_repo).await;
+    let repo: PantryRepositoryService = Arc::new(Box::new(repo));
+    let ingredient_repo: IngredientRepositoryService = Arc::new(Box::new(ingredient_repo));
+
+    add_pantry_item(
+        repo.clone(),
+        ingredient_repo.clone(),
+        &user_fixture().id,
+        input(),
+    )
+    .await
+    .unwrap();
+    let error = add_pantry_item(repo, ingredient_repo, &user_fixture().id, input())
+        .await
+        .unwrap_err();
+
+    assert!(
+        matches!(error, AddPantryItemError::Conflict(id) if id == pantry_item_fixture().ingredient.id)
+    );
+}
+
+pub async fn adding_a_nonexistent_ingredient_fails(
+    repo: impl PantryRepository,
+    ingredient_repo: impl IngredientRepository,
+) {
+    let repo: PantryRepositoryService = Arc::new(Box::new(repo));
+    let ingredient_repo: IngredientRepositoryService = Arc::new(Box::new(ingredient_repo));
+    let missing = Uuid::from_u128(0xdead);
+
+    let error = add_pantry_item(
+        repo,
+        ingredient_repo,
+        &user_fixture().id,
+        AddPantryItem {
+            ingredient_id: missing,
+            ..input()
+        },
+    )
+    .await
+    .unwrap_err();
+
+    assert!(matches!(error, AddPantryItemError::IngredientNotFound(id) if id == missing));
+}
+
+pub async fn adding_nothing_to_the_pantry_fails(
+    repo: impl PantryRepository,
+    ingredient_repo: impl IngredientRepository,
+) {
+    let repo: PantryRepositoryService = Arc::new(Box::new(repo));
+    let ingredient_repo: IngredientRepositoryService = Arc::new(Box::new(ingredient_repo));
+
+    let error = add_pantry_item(
+        repo,
+        ingredient_repo,
+        &user_fixture().id,
+        AddPantryItem {
+            amount: IngredientUnit::Grams(0.0),
+            ..input()
+        },
+    )
+    .await
+    .unwrap_err();
+
+    assert!(matches!(error, AddPantryItemError::InvalidAmount));
+}
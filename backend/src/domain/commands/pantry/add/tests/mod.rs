@@ -0,0 +1,83 @@
+mod __tests__;
+
+mod in_memory {
+    use crate::domain::repositories::{
+        ingredients::in_memory::InMemoryIngredientRepository,
+        pantry::in_memory::InMemoryPantryRepository, users::in_memory::InMemoryUserRepository,
+    };
+
+    use super::__tests__;
+
+    #[tokio::test]
+    async fn adding_a_pantry_item_succeeds() {
+        let repo = InMemoryPantryRepository::new();
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let user_repo = InMemoryUserRepository::new();
+        __tests__::adding_a_pantry_item_succeeds(repo, ingredient_repo, user_repo).await
+    }
+
+    #[tokio::test]
+    async fn adding_the_same_ingredient_twice_conflicts() {
+        let repo = InMemoryPantryRepository::new();
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let user_repo = InMemoryUserRepository::new();
+        __tests__::adding_the_same_ingredient_twice_conflicts(repo, ingredient_repo, user_repo)
+            .await
+    }
+
+    #[tokio::test]
+    async fn adding_a_nonexistent_ingredient_fails() {
+        let repo = InMemoryPantryRepository::new();
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        __tests__::adding_a_nonexistent_ingredient_fails(repo, ingredient_repo).await
+    }
+
+    #[tokio::test]
+    async fn adding_nothing_to_the_pantry_fails() {
+        let repo = InMemoryPantryRepository::new();
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        __tests__::adding_nothing_to_the_pantry_fails(repo, ingredient_repo).await
+    }
+}
+
+mod sql {
+    use sqlx::PgPool;
+
+    use crate::domain::repositories::{
+        ingredients::postgres::PostgresIngredientRepository,
+        pantry::postgres::PostgresPantryRepository, users::postgres::PostgresUserRepository,
+    };
+
+    use super::__tests__;
+
+    #[sqlx::test]
+    async fn adding_a_pantry_item_succeeds(pool: PgPool) {
+        let repo = PostgresPantryRepository::new(pool.clone());
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let user_repo = PostgresUserRepository::new(pool.clone());
+        __tests__::adding_a_pantry_item_succeeds(repo, ingredient_repo, user_repo).await
+    }
+
+    #[sqlx::test]
+    async fn adding_the_same_ingredient_twice_conflicts(pool: PgPool) {
+        let repo = PostgresPantryRepository::new(pool.clone());
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let user_repo = PostgresUserRepository::new(pool.clone());
+        __tests__::adding_the_same_ingredient_twice_conflicts(repo, ingredient_repo, user_repo)
+            .await
+    }
+
+    #[sqlx::test]
+    async fn adding_a_nonexistent_ingredient_fails(pool: PgPool) {
+        let repo = PostgresPantryRepository::new(pool.clone());
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        __tests__::adding_a_nonexistent_ingredient_fails(repo, ingredient_repo).await
+    }
+
+    #[sqlx::test]
+    async fn adding_nothing_to_the_pantry_fails(pool: PgPool) {
+        let repo = PostgresPantryRepository::new(pool.clone());
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        __tests__::adding_nothing_to_the_pantry_fails(repo, ingredient_repo).await
+    }
+}
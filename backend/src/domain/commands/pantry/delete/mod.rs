@@ -0,0 +1,48 @@
+use uuid::Uuid;
+
+use crate::domain::repositories::pantry::{
+    errors::{DeletePantryItemError as DeletePantryItemErrorInternal, GetPantryItemError},
+    PantryRepositoryService,
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum DeletePantryItemError {
+    #[error("The ingredient with ID {0} is not in your pantry")]
+    NotFound(Uuid),
+
+    #[error(transparent)]
+    Unknown(#[from] eyre::Error),
+}
+
+impl From<GetPantryItemError> for DeletePantryItemError {
+    fn from(value: GetPantryItemError) -> Self {
+        match value {
+            GetPantryItemError::NotFound(id) => Self::NotFound(id),
+            e => Self::Unknown(e.into()),
+        }
+    }
+}
+
+impl From<DeletePantryItemErrorInternal> for DeletePantryItemError {
+    fn from(value: DeletePantryItemErrorInternal) -> Self {
+        match value {
+            DeletePantryItemErrorInternal::NotFound(id) => Self::NotFound(id),
+            e => Self::Unknown(e.into()),
+        }
+    }
+}
+
+pub async fn delete_pantry_item(
+    pantry_repo: PantryRepositoryService,
+    owner_id: &Uuid,
+    ingredient_id: &Uuid,
+) -> Result<(), DeletePantryItemError> {
+    let item = pantry_repo.get(owner_id, ingredient_id).await?;
+
+    pantry_repo.delete(&item).await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests;
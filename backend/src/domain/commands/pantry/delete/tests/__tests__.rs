@@ -0,0 +1,55 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        commands::pantry::delete::{delete_pantry_item, DeletePantryItemError},
+        repositories::{
+            ingredients::IngredientRepository,
+            pantry::{PantryRepository, PantryRepositoryService},
+            users::UserRepository,
+        },
+    },
+    test_utils::{insert_pantry_dependencies, pantry_item_fixture, user_fixture},
+};
+
+pub async fn deleting_a_pantry_item_succeeds(
+    repo: impl PantryRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    insert_pantry_dependencies(&user_repo, &ingredient_repo).await;
+    let item = repo.insert(pantry_item_fixture()).await.unwrap();
+    let repo: PantryRepositoryService = Arc::new(Box::new(repo));
+
+    delete_pantry_item(repo.clone(), &user_fixture().id, &item.ingredient.id)
+        .await
+        .unwrap();
+
+    assert!(repo
+        .get_all_of_user(&user_fixture().id)
+        .await
+        .unwrap()
+        .is_empty());
+}
+
+pub async fn deleting_from_someone_elses_pantry_fails(
+    repo: impl PantryRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    insert_pantry_dependencies(&user_repo, &ingredient_repo).await;
+    let item = repo.insert(pantry_item_fixture()).await.unwrap();
+    let repo: PantryRepositoryService = Arc::new(Box::new(repo));
+
+    let error = delete_pantry_item(repo.clone(), &Uuid::from_u128(0xdead), &item.ingredient.id)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, DeletePantryItemError::NotFound(id) if id == item.ingredient.id));
+    assert!(repo
+        .get(&user_fixture().id, &item.ingredient.id)
+        .await
+        .is_ok());
+}
@@ -0,0 +1,53 @@
+mod __tests__;
+
+mod in_memory {
+    use crate::domain::repositories::{
+        ingredients::in_memory::InMemoryIngredientRepository,
+        pantry::in_memory::InMemoryPantryRepository, users::in_memory::InMemoryUserRepository,
+    };
+
+    use super::__tests__;
+
+    #[tokio::test]
+    async fn deleting_a_pantry_item_succeeds() {
+        let repo = InMemoryPantryRepository::new();
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let user_repo = InMemoryUserRepository::new();
+        __tests__::deleting_a_pantry_item_succeeds(repo, ingredient_repo, user_repo).await
+    }
+
+    #[tokio::test]
+    async fn deleting_from_someone_elses_pantry_fails() {
+        let repo = InMemoryPantryRepository::new();
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let user_repo = InMemoryUserRepository::new();
+        __tests__::deleting_from_someone_elses_pantry_fails(repo, ingredient_repo, user_repo).await
+    }
+}
+
+mod sql {
+    use sqlx::PgPool;
+
+    use crate::domain::repositories::{
+        ingredients::postgres::PostgresIngredientRepository,
+        pantry::postgres::PostgresPantryRepository, users::postgres::PostgresUserRepository,
+    };
+
+    use super::__tests__;
+
+    #[sqlx::test]
+    async fn deleting_a_pantry_item_succeeds(pool: PgPool) {
+        let repo = PostgresPantryRepository::new(pool.clone());
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let user_repo = PostgresUserRepository::new(pool.clone());
+        __tests__::deleting_a_pantry_item_succeeds(repo, ingredient_repo, user_repo).await
+    }
+
+    #[sqlx::test]
+    async fn deleting_from_someone_elses_pantry_fails(pool: PgPool) {
+        let repo = PostgresPantryRepository::new(pool.clone());
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let user_repo = PostgresUserRepository::new(pool.clone());
+        __tests__::deleting_from_someone_elses_pantry_fails(repo, ingredient_repo, user_repo).await
+    }
+}
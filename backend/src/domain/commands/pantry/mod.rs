@@ -0,0 +1,3 @@
+pub mod add;
+pub mod delete;
+pub mod update;
@@ -0,0 +1,80 @@
+use chrono::NaiveDate;
+use common::UpdatePantryItemDTO;
+use uuid::Uuid;
+
+use crate::domain::entities::{pantry::PantryItem, recipe::IngredientUnit};
+use crate::domain::repositories::pantry::{
+    errors::{GetPantryItemError, UpdatePantryItemError as UpdatePantryItemErrorInternal},
+    PantryRepositoryService,
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum UpdatePantryItemError {
+    #[error("The ingredient with ID {0} is not in your pantry")]
+    NotFound(Uuid),
+
+    #[error("The amount in the pantry has to be above zero")]
+    InvalidAmount,
+
+    #[error(transparent)]
+    Unknown(#[from] eyre::Error),
+}
+
+impl From<GetPantryItemError> for UpdatePantryItemError {
+    fn from(value: GetPantryItemError) -> Self {
+        match value {
+            GetPantryItemError::NotFound(id) => Self::NotFound(id),
+            e => Self::Unknown(e.into()),
+        }
+    }
+}
+
+impl From<UpdatePantryItemErrorInternal> for UpdatePantryItemError {
+    fn from(value: UpdatePantryItemErrorInternal) -> Self {
+        match value {
+            UpdatePantryItemErrorInternal::NotFound(id) => Self::NotFound(id),
+            e => Self::Unknown(e.into()),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct UpdatePantryItem {
+    pub amount: IngredientUnit,
+    pub expires_on: Option<NaiveDate>,
+}
+
+impl From<UpdatePantryItemDTO> for UpdatePantryItem {
+    fn from(value: UpdatePantryItemDTO) -> Self {
+        Self {
+            amount: value.amount.into(),
+            expires_on: value.expires_on,
+        }
+    }
+}
+
+pub async fn update_pantry_item(
+    pantry_repo: PantryRepositoryService,
+    owner_id: &Uuid,
+    ingredient_id: &Uuid,
+    input: UpdatePantryItem,
+) -> Result<PantryItem, UpdatePantryItemError> {
+    if input.amount.amount() <= 0.0 {
+        return Err(UpdatePantryItemError::InvalidAmount);
+    }
+
+    let item = pantry_repo.get(owner_id, ingredient_id).await?;
+
+    let item = pantry_repo
+        .update(PantryItem {
+            amount: input.amount,
+            expires_on: input.expires_on,
+            ..item
+        })
+        .await?;
+
+    Ok(item)
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,70 @@
+use std::sync::Arc;
+
+use chrono::NaiveDate;
+
+use crate::{
+    domain::{
+        commands::pantry::update::{update_pantry_item, UpdatePantryItem, UpdatePantryItemError},
+        entities::{pantry::PantryItem, recipe::IngredientUnit},
+        repositories::{
+            ingredients::IngredientRepository,
+            pantry::{PantryRepository, PantryRepositoryService},
+            users::UserRepository,
+        },
+    },
+    test_utils::{insert_pantry_dependencies, pantry_item_fixture, user_fixture},
+};
+
+pub async fn updating_a_pantry_item_succeeds(
+    repo: impl PantryRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    insert_pantry_dependencies(&user_repo, &ingredient_repo).await;
+    let item = repo.insert(pantry_item_fixture()).await.unwrap();
+    let repo: PantryRepositoryService = Arc::new(Box::new(repo));
+    let expected = PantryItem {
+        amount: IngredientUnit::Grams(50.0),
+        expires_on: NaiveDate::from_ymd_opt(2024, 11, 1),
+        ..item.clone()
+    };
+
+    let result = update_pantry_item(
+        repo.clone(),
+        &user_fixture().id,
+        &item.ingredient.id,
+        UpdatePantryItem {
+            amount: IngredientUnit::Grams(50.0),
+            expires_on: NaiveDate::from_ymd_opt(2024, 11, 1),
+        },
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(result, expected);
+    assert_eq!(
+        repo.get(&user_fixture().id, &item.ingredient.id)
+            .await
+            .unwrap(),
+        expected
+    );
+}
+
+pub async fn updating_an_item_that_is_not_in_the_pantry_fails(repo: impl PantryRepository) {
+    let repo: PantryRepositoryService = Arc::new(Box::new(repo));
+    let id = pantry_item_fixture().ingredient.id;
+
+    let error = update_pantry_item(
+        repo,
+        &user_fixture().id,
+        &id,
+        UpdatePantryItem {
+            amount: IngredientUnit::Grams(50.0),
+            expires_on: None,
+        },
+    )
+    .await
+    .unwrap_err();
+
+    assert!(matches!(error, UpdatePantryItemError::NotFound(missing) if missing == id));
+}
@@ -1,7 +1,7 @@
 use std::collections::BTreeMap;
 
 use chrono::{DateTime, Utc};
-use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 use uuid::Uuid;
 
 use crate::domain::entities::recipe::IngredientAmountData;
@@ -78,32 +78,35 @@ impl From<Recipe> for CreateRecipe {
 pub async fn create_recipe(
     recipe_repo: RecipeRepositoryService,
     ingredient_repo: IngredientRepositoryService,
+    owner_id: &Uuid,
     input: &CreateRecipe,
 ) -> Result<Recipe, CreateRecipeError> {
     let ingredient_ids: Vec<Uuid> = input.ingredients.iter().map(|i| i.ingredient_id).collect();
 
-    let ingredients_in_recipe: Vec<_> = ingredient_repo
+    let ingredients = ingredient_repo
         .get_all_by_id(&ingredient_ids)
         .await
-        .map_err(CreateRecipeError::from)?
-        .into_par_iter()
-        .zip(&input.ingredients)
-        .map(
-            |(
-                ingredient,
-                IngredientAmountData {
-                    amount,
-                    optional,
-                    notes,
-                    ..
-                },
-            )| {
-                IngredientWithAmount {
-                    ingredient,
+        .map_err(CreateRecipeError::from)?;
+
+    // The ingredients don't come back in any particular order
+    let ingredients_in_recipe: Vec<_> = input
+        .ingredients
+        .par_iter()
+        .filter_map(
+            |IngredientAmountData {
+                 ingredient_id,
+                 amount,
+                 optional,
+                 notes,
+             }| {
+                let ingredient = ingredients.iter().find(|i| &i.id == ingredient_id)?;
+
+                Some(IngredientWithAmount {
+                    ingredient: ingredient.clone(),
                     amount: amount.clone(),
                     notes: notes.clone(),
                     optional: *optional,
-                }
+                })
             },
         )
         .collect();
@@ -122,6 +125,7 @@ pub async fn create_recipe(
             ingredients: ingredients_in_recipe.try_into()?,
             time: input.time.clone(),
             servings: input.servings.clone(),
+            owner_id: Some(*owner_id),
             created_at,
             updated_at,
         })
@@ -1,4 +1,4 @@
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 
 use pretty_assertions::assert_eq;
 use uuid::Uuid;
@@ -8,10 +8,12 @@ use crate::{
         commands::recipes::create::{create_recipe, CreateRecipeError},
         repositories::{
             ingredients::{IngredientRepository, IngredientRepositoryService},
+            outbox::OutboxRepository,
             recipe::{errors::InsertRecipeError, RecipeRepository, RecipeRepositoryService},
+            users::UserRepository,
         },
     },
-    test_utils::{insert_all_ingredients_of_recipe, recipe_fixture},
+    test_utils::{insert_all_ingredients_of_recipe, recipe_fixture, user_fixture},
 };
 
 pub async fn create_recipe_without_proper_ingredients_errors(
@@ -22,9 +24,14 @@ pub async fn create_recipe_without_proper_ingredients_errors(
     let ingredient_repo: IngredientRepositoryService = Arc::new(Box::new(ing_repo));
     let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(repo));
 
-    let result = create_recipe(recipe_repo, ingredient_repo, &recipe.into())
-        .await
-        .unwrap_err();
+    let result = create_recipe(
+        recipe_repo,
+        ingredient_repo,
+        &user_fixture().id,
+        &recipe.into(),
+    )
+    .await
+    .unwrap_err();
 
     assert!(matches!(result, CreateRecipeError::IngredientsNotFound(_)));
 }
@@ -32,20 +39,28 @@ pub async fn create_recipe_without_proper_ingredients_errors(
 pub async fn create_recipe_with_proper_ingredients(
     repo: impl RecipeRepository,
     ing_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
 ) {
     let recipe = recipe_fixture();
+    let owner = user_repo.insert(user_fixture()).await.unwrap();
 
     insert_all_ingredients_of_recipe(&ing_repo, &recipe).await;
 
     let ingredient_repo: IngredientRepositoryService = Arc::new(Box::new(ing_repo));
     let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(repo));
 
-    let result = create_recipe(recipe_repo, ingredient_repo, &recipe.clone().into())
-        .await
-        .unwrap();
+    let result = create_recipe(
+        recipe_repo,
+        ingredient_repo,
+        &owner.id,
+        &recipe.clone().into(),
+    )
+    .await
+    .unwrap();
 
     assert_eq!(Uuid::get_version(&result.id), Some(uuid::Version::SortRand));
     assert_eq!(&result.name, "Hoisin Tofu and Broccoli");
+    assert_eq!(result.owner_id, Some(owner.id));
 
     assert_eq!(result.ingredients.len(), recipe.ingredients.len())
 }
@@ -64,3 +79,34 @@ pub async fn inserting_recipe_with_same_id_fails(
 
     assert!(matches!(error, InsertRecipeError::Conflict(a) if a == "recipe id"));
 }
+
+pub async fn creating_a_recipe_writes_a_message_to_the_outbox(
+    repo: impl RecipeRepository,
+    ing_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+    outbox: impl OutboxRepository,
+) {
+    let recipe = recipe_fixture();
+    let owner = user_repo.insert(user_fixture()).await.unwrap();
+
+    insert_all_ingredients_of_recipe(&ing_repo, &recipe).await;
+
+    let ingredient_repo: IngredientRepositoryService = Arc::new(Box::new(ing_repo));
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(repo));
+
+    let result = create_recipe(recipe_repo, ingredient_repo, &owner.id, &recipe.into())
+        .await
+        .unwrap();
+
+    let messages = outbox
+        .claim_pending(100, Duration::from_secs(60))
+        .await
+        .unwrap();
+    let message = messages
+        .iter()
+        .find(|m| m.topic == "deepdish.recipe.created")
+        .unwrap();
+
+    assert_eq!(message.payload["type"], "recipe.created");
+    assert_eq!(message.payload["payload"]["id"], result.id.to_string());
+}
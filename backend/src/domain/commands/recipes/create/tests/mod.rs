@@ -4,15 +4,18 @@ mod in_memory {
 
     use crate::domain::repositories::{
         ingredients::in_memory::InMemoryIngredientRepository,
-        recipe::in_memory::InMemoryRecipeRepository,
+        outbox::in_memory::InMemoryOutboxRepository, recipe::in_memory::InMemoryRecipeRepository,
+        users::in_memory::InMemoryUserRepository,
     };
 
     #[tokio::test]
     async fn create_recipe_with_proper_ingredients() {
         let ingredient_repo = InMemoryIngredientRepository::new();
         let recipe_repo = InMemoryRecipeRepository::new();
+        let user_repo = InMemoryUserRepository::new();
 
-        __tests__::create_recipe_with_proper_ingredients(recipe_repo, ingredient_repo).await;
+        __tests__::create_recipe_with_proper_ingredients(recipe_repo, ingredient_repo, user_repo)
+            .await;
     }
 
     #[tokio::test]
@@ -30,6 +33,22 @@ mod in_memory {
         let ingredient_repo = InMemoryIngredientRepository::new();
         __tests__::inserting_recipe_with_same_id_fails(repo, ingredient_repo).await
     }
+
+    #[tokio::test]
+    async fn creating_a_recipe_writes_a_message_to_the_outbox() {
+        let outbox = InMemoryOutboxRepository::new();
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let recipe_repo = InMemoryRecipeRepository::new().with_outbox(outbox.clone());
+        let user_repo = InMemoryUserRepository::new();
+
+        __tests__::creating_a_recipe_writes_a_message_to_the_outbox(
+            recipe_repo,
+            ingredient_repo,
+            user_repo,
+            outbox,
+        )
+        .await;
+    }
 }
 
 mod sql {
@@ -38,15 +57,18 @@ mod sql {
 
     use crate::domain::repositories::{
         ingredients::postgres::PostgresIngredientRepository,
-        recipe::postgres::PostgresRecipeRepository,
+        outbox::postgres::PostgresOutboxRepository, recipe::postgres::PostgresRecipeRepository,
+        users::postgres::PostgresUserRepository,
     };
 
     #[sqlx::test]
     async fn create_recipe_with_proper_ingredients(pool: PgPool) {
         let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
         let recipe_repo = PostgresRecipeRepository::new(pool.clone());
+        let user_repo = PostgresUserRepository::new(pool.clone());
 
-        __tests__::create_recipe_with_proper_ingredients(recipe_repo, ingredient_repo).await;
+        __tests__::create_recipe_with_proper_ingredients(recipe_repo, ingredient_repo, user_repo)
+            .await;
     }
 
     #[sqlx::test]
@@ -64,4 +86,20 @@ mod sql {
         let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
         __tests__::inserting_recipe_with_same_id_fails(repo, ingredient_repo).await
     }
+
+    #[sqlx::test]
+    async fn creating_a_recipe_writes_a_message_to_the_outbox(pool: PgPool) {
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let recipe_repo = PostgresRecipeRepository::new(pool.clone());
+        let user_repo = PostgresUserRepository::new(pool.clone());
+        let outbox = PostgresOutboxRepository::new(pool.clone());
+
+        __tests__::creating_a_recipe_writes_a_message_to_the_outbox(
+            recipe_repo,
+            ingredient_repo,
+            user_repo,
+            outbox,
+        )
+        .await;
+    }
 }
@@ -10,13 +10,22 @@ pub enum DeleteRecipeError {
     #[error("Could not found the recipe with the ID {0}")]
     NotFound(Uuid),
 
+    #[error("You are not allowed to delete the recipe with ID {0}")]
+    Forbidden(Uuid),
+
+    #[error("The recipe with ID {0} has been changed since you last fetched it")]
+    Conflict(Uuid),
+
     #[error(transparent)]
     Unknown(#[from] eyre::Error),
 }
 
 impl From<DeleteRecipeErrorInternal> for DeleteRecipeError {
     fn from(value: DeleteRecipeErrorInternal) -> Self {
-        Self::Unknown(value.into())
+        match value {
+            DeleteRecipeErrorInternal::Outdated(id) => Self::Conflict(id),
+            e => Self::Unknown(e.into()),
+        }
     }
 }
 
@@ -29,12 +38,23 @@ impl From<GetRecipeByIdError> for DeleteRecipeError {
     }
 }
 
+/// When expected versions are given, the recipe is only deleted if it's still in one of them.
 pub async fn delete_recipe(
     recipe_repo: RecipeRepositoryService,
+    user_id: &Uuid,
     input: &Uuid,
+    expected_versions: Option<&[String]>,
 ) -> Result<(), DeleteRecipeError> {
     let recipe = recipe_repo.get_by_id(input).await?;
 
+    if !recipe.can_be_modified_by(user_id) {
+        return Err(DeleteRecipeError::Forbidden(recipe.id));
+    }
+
+    if expected_versions.is_some_and(|versions| !versions.contains(&recipe.version())) {
+        return Err(DeleteRecipeError::Conflict(recipe.id));
+    }
+
     recipe_repo.delete(&recipe).await?;
 
     Ok(())
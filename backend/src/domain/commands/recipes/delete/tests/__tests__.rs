@@ -1,35 +1,120 @@
 use std::sync::Arc;
 
+use common::events::Event;
+use uuid::Uuid;
+
 use crate::{
     domain::{
         commands::recipes::delete::{delete_recipe, DeleteRecipeError},
+        entities::recipe::Recipe,
         repositories::{
             ingredients::IngredientRepository,
+            outbox::OutboxRepository,
             recipe::{RecipeRepository, RecipeRepositoryService},
+            users::UserRepository,
         },
     },
-    test_utils::{insert_all_ingredients_of_recipe, recipe_fixture},
+    test_utils::{
+        insert_all_ingredients_of_recipe, owned_recipe_fixture, pending_events, recipe_fixture,
+        user_fixture,
+    },
 };
 
 pub async fn deleting_a_recipe_succeeds(
     repo: impl RecipeRepository,
     ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
 ) {
     let repo: RecipeRepositoryService = Arc::new(Box::new(repo));
-    let recipe = recipe_fixture();
+    let recipe = owned_recipe_fixture(&user_repo).await;
 
     insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
 
     repo.insert(recipe.clone()).await.unwrap();
 
-    delete_recipe(repo, &recipe.id).await.unwrap();
+    delete_recipe(repo, &user_fixture().id, &recipe.id, None)
+        .await
+        .unwrap();
 }
 
 pub async fn deleting_a_nonexistent_recipe_fails(repo: impl RecipeRepository) {
     let repo: RecipeRepositoryService = Arc::new(Box::new(repo));
 
     let recipe = recipe_fixture();
-    let result = delete_recipe(repo, &recipe.id).await.unwrap_err();
+    let result = delete_recipe(repo, &user_fixture().id, &recipe.id, None)
+        .await
+        .unwrap_err();
 
     assert!(matches!(result, DeleteRecipeError::NotFound(id) if id == recipe.id))
 }
+
+pub async fn deleting_someone_elses_recipe_is_forbidden(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let owner = user_repo.insert(user_fixture()).await.unwrap();
+    let repo: RecipeRepositoryService = Arc::new(Box::new(repo));
+    let recipe = Recipe {
+        owner_id: Some(owner.id),
+        ..recipe_fixture()
+    };
+
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
+
+    repo.insert(recipe.clone()).await.unwrap();
+
+    let result = delete_recipe(repo.clone(), &Uuid::from_u128(0xdead), &recipe.id, None)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(result, DeleteRecipeError::Forbidden(id) if id == recipe.id));
+    assert!(repo.get_by_id(&recipe.id).await.is_ok());
+}
+
+pub async fn owner_can_delete_their_recipe(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let owner = user_repo.insert(user_fixture()).await.unwrap();
+    let repo: RecipeRepositoryService = Arc::new(Box::new(repo));
+    let recipe = Recipe {
+        owner_id: Some(owner.id),
+        ..recipe_fixture()
+    };
+
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
+
+    repo.insert(recipe.clone()).await.unwrap();
+
+    delete_recipe(repo.clone(), &owner.id, &recipe.id, None)
+        .await
+        .unwrap();
+
+    assert!(repo.get_by_id(&recipe.id).await.is_err());
+}
+
+pub async fn deleting_a_recipe_writes_a_message_to_the_outbox(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+    outbox: impl OutboxRepository,
+) {
+    let repo: RecipeRepositoryService = Arc::new(Box::new(repo));
+    let recipe = owned_recipe_fixture(&user_repo).await;
+
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
+
+    repo.insert(recipe.clone()).await.unwrap();
+
+    delete_recipe(repo, &user_fixture().id, &recipe.id, None)
+        .await
+        .unwrap();
+
+    let events = pending_events(&outbox).await;
+
+    assert!(events
+        .iter()
+        .any(|event| matches!(event, Event::RecipeDeleted(r) if r.id == recipe.id.to_string())));
+}
@@ -3,7 +3,8 @@ mod __tests__;
 mod in_memory {
     use crate::domain::repositories::{
         ingredients::in_memory::InMemoryIngredientRepository,
-        recipe::in_memory::InMemoryRecipeRepository,
+        outbox::in_memory::InMemoryOutboxRepository, recipe::in_memory::InMemoryRecipeRepository,
+        users::in_memory::InMemoryUserRepository,
     };
 
     use super::__tests__;
@@ -12,7 +13,8 @@ mod in_memory {
     async fn deleting_a_recipe_succeeds() {
         let repo = InMemoryRecipeRepository::new();
         let ingredient_repo = InMemoryIngredientRepository::new();
-        __tests__::deleting_a_recipe_succeeds(repo, ingredient_repo).await
+        let user_repo = InMemoryUserRepository::new();
+        __tests__::deleting_a_recipe_succeeds(repo, ingredient_repo, user_repo).await
     }
 
     #[tokio::test]
@@ -20,6 +22,38 @@ mod in_memory {
         let repo = InMemoryRecipeRepository::new();
         __tests__::deleting_a_nonexistent_recipe_fails(repo).await
     }
+
+    #[tokio::test]
+    async fn deleting_someone_elses_recipe_is_forbidden() {
+        let repo = InMemoryRecipeRepository::new();
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let user_repo = InMemoryUserRepository::new();
+        __tests__::deleting_someone_elses_recipe_is_forbidden(repo, ingredient_repo, user_repo)
+            .await
+    }
+
+    #[tokio::test]
+    async fn owner_can_delete_their_recipe() {
+        let repo = InMemoryRecipeRepository::new();
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let user_repo = InMemoryUserRepository::new();
+        __tests__::owner_can_delete_their_recipe(repo, ingredient_repo, user_repo).await
+    }
+
+    #[tokio::test]
+    async fn deleting_a_recipe_writes_a_message_to_the_outbox() {
+        let outbox = InMemoryOutboxRepository::new();
+        let repo = InMemoryRecipeRepository::new().with_outbox(outbox.clone());
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let user_repo = InMemoryUserRepository::new();
+        __tests__::deleting_a_recipe_writes_a_message_to_the_outbox(
+            repo,
+            ingredient_repo,
+            user_repo,
+            outbox,
+        )
+        .await
+    }
 }
 
 mod sql {
@@ -27,7 +61,8 @@ mod sql {
 
     use crate::domain::repositories::{
         ingredients::postgres::PostgresIngredientRepository,
-        recipe::postgres::PostgresRecipeRepository,
+        outbox::postgres::PostgresOutboxRepository, recipe::postgres::PostgresRecipeRepository,
+        users::postgres::PostgresUserRepository,
     };
 
     use super::__tests__;
@@ -36,7 +71,8 @@ mod sql {
     async fn deleting_a_recipe_succeeds(pool: PgPool) {
         let repo = PostgresRecipeRepository::new(pool.clone());
         let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
-        __tests__::deleting_a_recipe_succeeds(repo, ingredient_repo).await
+        let user_repo = PostgresUserRepository::new(pool.clone());
+        __tests__::deleting_a_recipe_succeeds(repo, ingredient_repo, user_repo).await
     }
 
     #[sqlx::test]
@@ -44,4 +80,36 @@ mod sql {
         let repo = PostgresRecipeRepository::new(pool.clone());
         __tests__::deleting_a_nonexistent_recipe_fails(repo).await
     }
+
+    #[sqlx::test]
+    async fn deleting_someone_elses_recipe_is_forbidden(pool: PgPool) {
+        let repo = PostgresRecipeRepository::new(pool.clone());
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let user_repo = PostgresUserRepository::new(pool.clone());
+        __tests__::deleting_someone_elses_recipe_is_forbidden(repo, ingredient_repo, user_repo)
+            .await
+    }
+
+    #[sqlx::test]
+    async fn owner_can_delete_their_recipe(pool: PgPool) {
+        let repo = PostgresRecipeRepository::new(pool.clone());
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let user_repo = PostgresUserRepository::new(pool.clone());
+        __tests__::owner_can_delete_their_recipe(repo, ingredient_repo, user_repo).await
+    }
+
+    #[sqlx::test]
+    async fn deleting_a_recipe_writes_a_message_to_the_outbox(pool: PgPool) {
+        let repo = PostgresRecipeRepository::new(pool.clone());
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let user_repo = PostgresUserRepository::new(pool.clone());
+        let outbox = PostgresOutboxRepository::new(pool);
+        __tests__::deleting_a_recipe_writes_a_message_to_the_outbox(
+            repo,
+            ingredient_repo,
+            user_repo,
+            outbox,
+        )
+        .await
+    }
 }
@@ -0,0 +1,217 @@
+use eyre::eyre;
+use uuid::Uuid;
+
+use crate::domain::commands::ingredients::create::{
+    create_ingredient, CreateIngredient, CreateIngredientError,
+};
+use crate::domain::entities::ingredient::Ingredient;
+use crate::domain::entities::recipe::{
+    errors::ValidationError,
+    formats::{cooklang::CooklangError, jsonld::JsonLdError, ImportedRecipe},
+    IngredientAmountData, Recipe, RecipeSteps,
+};
+use crate::domain::repositories::{
+    ingredients::{errors::GetAllIngredientsError, IngredientRepositoryService},
+    recipe::RecipeRepositoryService,
+};
+
+use super::create::{create_recipe, CreateRecipe, CreateRecipeError};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum ImportRecipeError {
+    #[error(transparent)]
+    JsonLd(#[from] JsonLdError),
+
+    #[error(transparent)]
+    Cooklang(#[from] CooklangError),
+
+    #[error(transparent)]
+    Validation(#[from] ValidationError),
+
+    #[error(transparent)]
+    Unknown(#[from] eyre::Report),
+}
+
+impl From<CreateRecipeError> for ImportRecipeError {
+    fn from(value: CreateRecipeError) -> Self {
+        match value {
+            CreateRecipeError::Validation(e) => Self::Validation(e),
+            e => Self::Unknown(e.into()),
+        }
+    }
+}
+
+impl From<CreateIngredientError> for ImportRecipeError {
+    fn from(value: CreateIngredientError) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+impl From<GetAllIngredientsError> for ImportRecipeError {
+    fn from(value: GetAllIngredientsError) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+/// The imported recipe, together with the ingredient lines which didn't make it into it.
+#[derive(Debug)]
+pub struct RecipeImport {
+    pub recipe: Recipe,
+    pub unparsed_ingredients: Vec<String>,
+}
+
+/// Creates the recipe, matching its ingredients with the known ones by name.
+/// Ingredients nobody has heard of yet are created along the way, and deleted again if the recipe can't be created.
+/// An ingredient listed more than once is added up if the amounts are in the same unit,
+/// and otherwise reported as unparsed, just like the lines which couldn't be parsed in the first place.
+#[tracing::instrument(
+    "[COMMAND] Importing a recipe",
+    skip(recipe_repo, ingredient_repo, imported),
+    fields(name = imported.name)
+)]
+pub async fn import_recipe(
+    recipe_repo: RecipeRepositoryService,
+    ingredient_repo: IngredientRepositoryService,
+    owner_id: &Uuid,
+    imported: ImportedRecipe,
+) -> Result<RecipeImport, ImportRecipeError> {
+    // Checked before any ingredient gets created, as these would make creating the recipe fail anyway
+    RecipeSteps::try_from(imported.steps.clone())?;
+    if imported.ingredients.is_empty() {
+        return Err(ValidationError::EmptyField(vec!["ingredients"]).into());
+    }
+
+    let mut created = vec![];
+    let result = import_with_ingredients(
+        recipe_repo,
+        ingredient_repo.clone(),
+        owner_id,
+        imported,
+        &mut created,
+    )
+    .await;
+
+    if result.is_err() {
+        remove_created_ingredients(&ingredient_repo, created).await;
+    }
+
+    result
+}
+
+/// Does the actual import, keeping track of the ingredients it has created in `created`.
+async fn import_with_ingredients(
+    recipe_repo: RecipeRepositoryService,
+    ingredient_repo: IngredientRepositoryService,
+    owner_id: &Uuid,
+    imported: ImportedRecipe,
+    created: &mut Vec<Ingredient>,
+) -> Result<RecipeImport, ImportRecipeError> {
+    let mut unparsed_ingredients = imported.unparsed_ingredients;
+
+    let names: Vec<String> = imported
+        .ingredients
+        .iter()
+        .map(|i| i.name.to_lowercase())
+        .collect();
+    let mut known: Vec<Ingredient> = ingredient_repo.get_all_by_name(&names).await?;
+
+    let mut ingredients: Vec<IngredientAmountData> = vec![];
+
+    for line in imported.ingredients {
+        let existing = known
+            .iter()
+            .find(|i| i.name.to_lowercase() == line.name.to_lowercase())
+            .cloned();
+
+        let ingredient = match existing {
+            Some(ingredient) => ingredient,
+            None => {
+                let description = format!("Added when importing the recipe \"{}\"", imported.name);
+                let input = CreateIngredient {
+                    name: &line.name,
+                    description: &description,
+                    diet_violations: vec![],
+                    parent_id: None,
+                    allergens: vec![],
+                };
+
+                let ingredient = match create_ingredient(ingredient_repo.clone(), &input).await {
+                    Ok(ingredient) => {
+                        created.push(ingredient.clone());
+                        ingredient
+                    }
+                    // Someone else has created it in the meantime, e.g. in an import of their own
+                    Err(CreateIngredientError::Conflict(_)) => ingredient_repo
+                        .get_all_by_name(&[line.name.to_lowercase()])
+                        .await?
+                        .into_iter()
+                        .next()
+                        .ok_or_else(|| {
+                            eyre!(
+                                "The ingredient {} already exists, but could not be found",
+                                line.name
+                            )
+                        })?,
+                    Err(e) => return Err(e.into()),
+                };
+                known.push(ingredient.clone());
+
+                ingredient
+            }
+        };
+
+        match ingredients
+            .iter_mut()
+            .find(|i| i.ingredient_id == ingredient.id)
+        {
+            None => ingredients.push(IngredientAmountData {
+                ingredient_id: ingredient.id,
+                amount: line.amount,
+                optional: line.optional,
+                notes: line.notes,
+            }),
+            Some(duplicate) => match duplicate.amount.plus(&line.amount) {
+                Some(amount) => duplicate.amount = amount,
+                None => unparsed_ingredients.push(line.name),
+            },
+        }
+    }
+
+    let input = CreateRecipe {
+        name: imported.name,
+        description: imported.description,
+        steps: imported.steps,
+        time: imported.time,
+        ingredients,
+        servings: imported.servings,
+    };
+
+    let recipe = create_recipe(recipe_repo, ingredient_repo, owner_id, &input).await?;
+
+    Ok(RecipeImport {
+        recipe,
+        unparsed_ingredients,
+    })
+}
+
+/// Deletes the ingredients created for an import that failed, so they aren't left behind without a recipe.
+async fn remove_created_ingredients(
+    ingredient_repo: &IngredientRepositoryService,
+    created: Vec<Ingredient>,
+) {
+    for ingredient in created {
+        let id = ingredient.id;
+
+        // A concurrent import might already use it, in which case it stays
+        if let Err(e) = ingredient_repo.delete(ingredient).await {
+            tracing::warn!(
+                "Could not delete the ingredient {} created for a failed import: {}",
+                id,
+                e
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,247 @@
+use std::{
+    collections::BTreeMap,
+    sync::{Arc, Mutex},
+};
+
+use uuid::Uuid;
+
+use pretty_assertions::assert_eq;
+
+use crate::{
+    domain::{
+        commands::recipes::import::{import_recipe, ImportRecipeError},
+        entities::recipe::{
+            errors::ValidationError, formats::ImportedRecipe, IngredientUnit, ServingsType,
+        },
+        repositories::{
+            ingredients::{
+                errors::InsertIngredientError, IngredientRepository, IngredientRepositoryService,
+                MockIngredientRepository,
+            },
+            recipe::{RecipeRepository, RecipeRepositoryService},
+            users::UserRepository,
+        },
+    },
+    test_utils::{ingredient_fixture, user_fixture},
+};
+
+fn imported_recipe(lines: &[&str]) -> ImportedRecipe {
+    let (ingredients, unparsed_ingredients) = ImportedRecipe::parse_ingredients(lines.to_vec());
+
+    ImportedRecipe {
+        name: "Pancakes".to_string(),
+        description: "Fluffy".to_string(),
+        steps: vec!["Mix everything".to_string(), "Fry".to_string()],
+        time: BTreeMap::new(),
+        servings: ServingsType::Exact(4),
+        ingredients,
+        unparsed_ingredients,
+    }
+}
+
+pub async fn importing_a_recipe_reuses_known_ingredients_and_creates_the_rest(
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let owner = user_repo.insert(user_fixture()).await.unwrap();
+    let known = ingredient_repo.insert(ingredient_fixture()).await.unwrap();
+
+    let ingredient_repo: IngredientRepositoryService = Arc::new(Box::new(ingredient_repo));
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+
+    let line = format!("200 g {}", known.name.to_uppercase());
+    let result = import_recipe(
+        recipe_repo,
+        ingredient_repo.clone(),
+        &owner.id,
+        imported_recipe(&[&line, "2 eggs"]),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(result.recipe.name, "Pancakes");
+    assert_eq!(result.recipe.owner_id, Some(owner.id));
+    assert!(result.unparsed_ingredients.is_empty());
+
+    let ingredients = &result.recipe.ingredients;
+    let reused = ingredients
+        .iter()
+        .find(|i| i.ingredient.id == known.id)
+        .unwrap();
+    assert_eq!(reused.amount, IngredientUnit::Grams(200.0));
+
+    let created = ingredients
+        .iter()
+        .find(|i| i.ingredient.id != known.id)
+        .unwrap();
+    assert_eq!(created.ingredient.name.to_string(), "eggs");
+    assert_eq!(
+        ingredient_repo
+            .get_by_id(&created.ingredient.id)
+            .await
+            .unwrap(),
+        created.ingredient
+    );
+}
+
+pub async fn importing_a_recipe_reports_the_ingredients_it_could_not_add(
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let owner = user_repo.insert(user_fixture()).await.unwrap();
+
+    let ingredient_repo: IngredientRepositoryService = Arc::new(Box::new(ingredient_repo));
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+
+    let result = import_recipe(
+        recipe_repo,
+        ingredient_repo,
+        &owner.id,
+        imported_recipe(&[
+            "1 tbsp butter",
+            "salt to taste",
+            "2 tbsp butter, for frying",
+            "20 g butter",
+        ]),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(result.unparsed_ingredients, vec!["salt to taste", "butter"]);
+
+    let ingredients = &result.recipe.ingredients;
+    assert_eq!(ingredients.len(), 1);
+    assert_eq!(ingredients[0].amount, IngredientUnit::Teaspoons(9.0));
+}
+
+pub async fn importing_a_recipe_without_any_usable_ingredients_fails(
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let owner = user_repo.insert(user_fixture()).await.unwrap();
+
+    let ingredient_repo: IngredientRepositoryService = Arc::new(Box::new(ingredient_repo));
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+
+    let error = import_recipe(
+        recipe_repo,
+        ingredient_repo,
+        &owner.id,
+        imported_recipe(&["salt to taste"]),
+    )
+    .await
+    .unwrap_err();
+
+    assert!(matches!(
+        error,
+        ImportRecipeError::Validation(ValidationError::EmptyField(fields)) if fields == vec!["ingredients"]
+    ));
+}
+
+pub async fn importing_a_recipe_without_steps_creates_no_ingredients(
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let owner = user_repo.insert(user_fixture()).await.unwrap();
+
+    let ingredient_repo: IngredientRepositoryService = Arc::new(Box::new(ingredient_repo));
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+
+    let error = import_recipe(
+        recipe_repo,
+        ingredient_repo.clone(),
+        &owner.id,
+        ImportedRecipe {
+            steps: vec![" ".to_string()],
+            ..imported_recipe(&["2 eggs"])
+        },
+    )
+    .await
+    .unwrap_err();
+
+    assert!(matches!(
+        error,
+        ImportRecipeError::Validation(ValidationError::EmptyField(fields)) if fields == vec!["steps"]
+    ));
+    assert!(ingredient_repo
+        .get_all_by_name(&["eggs".to_string()])
+        .await
+        .unwrap()
+        .is_empty());
+}
+
+pub async fn a_failed_import_deletes_the_ingredients_it_created(
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+) {
+    let ingredient_repo: IngredientRepositoryService = Arc::new(Box::new(ingredient_repo));
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+
+    // Nobody has this ID, so the recipe can't be stored
+    let owner_id = Uuid::from_u128(0xdead);
+    import_recipe(
+        recipe_repo,
+        ingredient_repo.clone(),
+        &owner_id,
+        imported_recipe(&["2 eggs"]),
+    )
+    .await
+    .unwrap_err();
+
+    assert!(ingredient_repo
+        .get_all_by_name(&["eggs".to_string()])
+        .await
+        .unwrap()
+        .is_empty());
+}
+
+pub async fn importing_an_ingredient_someone_else_has_just_created_reuses_it(
+    recipe_repo: impl RecipeRepository,
+    user_repo: impl UserRepository,
+) {
+    let owner = user_repo.insert(user_fixture()).await.unwrap();
+    let eggs = ingredient_fixture();
+
+    // The ingredient doesn't exist yet when the import looks for it, but does once it tries to create it
+    let created_concurrently = Arc::new(Mutex::new(false));
+    let mut ingredient_repo = MockIngredientRepository::new();
+    ingredient_repo.expect_get_all_by_name().returning({
+        let created_concurrently = created_concurrently.clone();
+        let eggs = eggs.clone();
+        move |_| match *created_concurrently.lock().unwrap() {
+            true => Ok(vec![eggs.clone()]),
+            false => Ok(vec![]),
+        }
+    });
+    ingredient_repo
+        .expect_insert()
+        .times(1)
+        .returning(move |_| {
+            *created_concurrently.lock().unwrap() = true;
+            Err(InsertIngredientError::Conflict("name".to_string()))
+        });
+    ingredient_repo.expect_get_all_by_id().returning({
+        let eggs = eggs.clone();
+        move |_| Ok(vec![eggs.clone()])
+    });
+    ingredient_repo.expect_delete().never();
+
+    let ingredient_repo: IngredientRepositoryService = Arc::new(Box::new(ingredient_repo));
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+
+    let result = import_recipe(
+        recipe_repo,
+        ingredient_repo,
+        &owner.id,
+        imported_recipe(&[&format!("2 {}", eggs.name)]),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(result.recipe.ingredients.len(), 1);
+    assert_eq!(result.recipe.ingredients[0].ingredient.id, eggs.id);
+}
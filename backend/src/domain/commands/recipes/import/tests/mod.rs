@@ -0,0 +1,153 @@
+mod __tests__;
+mod in_memory {
+    use super::__tests__;
+
+    use crate::domain::repositories::{
+        ingredients::in_memory::InMemoryIngredientRepository,
+        recipe::in_memory::InMemoryRecipeRepository, users::in_memory::InMemoryUserRepository,
+    };
+
+    #[tokio::test]
+    async fn importing_a_recipe_reuses_known_ingredients_and_creates_the_rest() {
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let recipe_repo = InMemoryRecipeRepository::new();
+        let user_repo = InMemoryUserRepository::new();
+
+        __tests__::importing_a_recipe_reuses_known_ingredients_and_creates_the_rest(
+            recipe_repo,
+            ingredient_repo,
+            user_repo,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    async fn importing_a_recipe_reports_the_ingredients_it_could_not_add() {
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let recipe_repo = InMemoryRecipeRepository::new();
+        let user_repo = InMemoryUserRepository::new();
+
+        __tests__::importing_a_recipe_reports_the_ingredients_it_could_not_add(
+            recipe_repo,
+            ingredient_repo,
+            user_repo,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    async fn importing_a_recipe_without_any_usable_ingredients_fails() {
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let recipe_repo = InMemoryRecipeRepository::new();
+        let user_repo = InMemoryUserRepository::new();
+
+        __tests__::importing_a_recipe_without_any_usable_ingredients_fails(
+            recipe_repo,
+            ingredient_repo,
+            user_repo,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    async fn importing_a_recipe_without_steps_creates_no_ingredients() {
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let recipe_repo = InMemoryRecipeRepository::new();
+        let user_repo = InMemoryUserRepository::new();
+
+        __tests__::importing_a_recipe_without_steps_creates_no_ingredients(
+            recipe_repo,
+            ingredient_repo,
+            user_repo,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    async fn importing_an_ingredient_someone_else_has_just_created_reuses_it() {
+        let recipe_repo = InMemoryRecipeRepository::new();
+        let user_repo = InMemoryUserRepository::new();
+
+        __tests__::importing_an_ingredient_someone_else_has_just_created_reuses_it(
+            recipe_repo,
+            user_repo,
+        )
+        .await;
+    }
+}
+
+mod sql {
+    use super::__tests__;
+    use sqlx::PgPool;
+
+    use crate::domain::repositories::{
+        ingredients::postgres::PostgresIngredientRepository,
+        recipe::postgres::PostgresRecipeRepository, users::postgres::PostgresUserRepository,
+    };
+
+    #[sqlx::test]
+    async fn importing_a_recipe_reuses_known_ingredients_and_creates_the_rest(pool: PgPool) {
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let recipe_repo = PostgresRecipeRepository::new(pool.clone());
+        let user_repo = PostgresUserRepository::new(pool.clone());
+
+        __tests__::importing_a_recipe_reuses_known_ingredients_and_creates_the_rest(
+            recipe_repo,
+            ingredient_repo,
+            user_repo,
+        )
+        .await;
+    }
+
+    #[sqlx::test]
+    async fn importing_a_recipe_reports_the_ingredients_it_could_not_add(pool: PgPool) {
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let recipe_repo = PostgresRecipeRepository::new(pool.clone());
+        let user_repo = PostgresUserRepository::new(pool.clone());
+
+        __tests__::importing_a_recipe_reports_the_ingredients_it_could_not_add(
+            recipe_repo,
+            ingredient_repo,
+            user_repo,
+        )
+        .await;
+    }
+
+    #[sqlx::test]
+    async fn importing_a_recipe_without_any_usable_ingredients_fails(pool: PgPool) {
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let recipe_repo = PostgresRecipeRepository::new(pool.clone());
+        let user_repo = PostgresUserRepository::new(pool.clone());
+
+        __tests__::importing_a_recipe_without_any_usable_ingredients_fails(
+            recipe_repo,
+            ingredient_repo,
+            user_repo,
+        )
+        .await;
+    }
+
+    #[sqlx::test]
+    async fn importing_a_recipe_without_steps_creates_no_ingredients(pool: PgPool) {
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let recipe_repo = PostgresRecipeRepository::new(pool.clone());
+        let user_repo = PostgresUserRepository::new(pool.clone());
+
+        __tests__::importing_a_recipe_without_steps_creates_no_ingredients(
+            recipe_repo,
+            ingredient_repo,
+            user_repo,
+        )
+        .await;
+    }
+
+    // Only Postgres refuses recipes of owners who don't exist
+    #[sqlx::test]
+    async fn a_failed_import_deletes_the_ingredients_it_created(pool: PgPool) {
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let recipe_repo = PostgresRecipeRepository::new(pool.clone());
+
+        __tests__::a_failed_import_deletes_the_ingredients_it_created(recipe_repo, ingredient_repo)
+            .await;
+    }
+}
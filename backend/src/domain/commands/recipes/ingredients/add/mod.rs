@@ -22,23 +22,44 @@ pub enum AddIngredientToRecipeError {
     #[error(transparent)]
     GetRecipe(#[from] GetRecipeByIdError),
 
+    #[error("You are not allowed to modify the recipe with ID {0}")]
+    Forbidden(Uuid),
+
+    #[error("The recipe with ID {0} has been changed since you last fetched it")]
+    Conflict(Uuid),
+
     #[error(transparent)]
     Unknown(#[from] eyre::Report),
 }
 
 impl From<AddIngredientIntoRecipeErrorInternal> for AddIngredientToRecipeError {
     fn from(value: AddIngredientIntoRecipeErrorInternal) -> Self {
-        Self::Unknown(value.into())
+        match value {
+            AddIngredientIntoRecipeErrorInternal::Outdated(id) => Self::Conflict(id),
+            e => Self::Unknown(e.into()),
+        }
     }
 }
 
+/// When expected versions are given, the ingredient is only added if the recipe is still in one of them.
 pub async fn add_ingredient_to_recipe(
     recipe_repo: RecipeRepositoryService,
     ingredient_repo: IngredientRepositoryService,
+    user_id: &Uuid,
     recipe_id: &Uuid,
     ingredient_amount: IngredientAmountData,
+    expected_versions: Option<&[String]>,
 ) -> Result<Recipe, AddIngredientToRecipeError> {
     let recipe = recipe_repo.get_by_id(recipe_id).await?;
+
+    if !recipe.can_be_modified_by(user_id) {
+        return Err(AddIngredientToRecipeError::Forbidden(recipe.id));
+    }
+
+    if expected_versions.is_some_and(|versions| !versions.contains(&recipe.version())) {
+        return Err(AddIngredientToRecipeError::Conflict(recipe.id));
+    }
+
     let ingredient = ingredient_repo
         .get_by_id(&ingredient_amount.ingredient_id)
         .await?;
@@ -58,6 +79,7 @@ pub async fn add_ingredient_to_recipe(
                 optional,
                 notes,
             },
+            user_id,
         )
         .await?;
 
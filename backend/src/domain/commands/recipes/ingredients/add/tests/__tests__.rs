@@ -1,29 +1,36 @@
 use std::{collections::HashSet, sync::Arc};
 
+use common::events::Event;
+use uuid::Uuid;
+
 use crate::{
     domain::{
-        commands::recipes::ingredients::add::{
-            add_ingredient_to_recipe, AddIngredientToRecipeError,
+        commands::recipes::{
+            ingredients::add::{add_ingredient_to_recipe, AddIngredientToRecipeError},
+            update::update_recipe,
         },
         entities::recipe::{IngredientAmountData, IngredientUnit, IngredientWithAmount},
         repositories::{
             ingredients::{
                 errors::GetIngredientByIdError, IngredientRepository, IngredientRepositoryService,
             },
+            outbox::OutboxRepository,
             recipe::{RecipeRepository, RecipeRepositoryService},
+            users::UserRepository,
         },
     },
     test_utils::{
         ingredient_fixture, insert_all_ingredients, insert_all_ingredients_of_recipe,
-        recipe_fixture,
+        owned_recipe_fixture, pending_events, recipe_changeset, user_fixture,
     },
 };
 
 pub async fn adding_an_ingredient_to_a_recipe_works(
     repo: impl RecipeRepository,
     ing_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
 ) {
-    let recipe = recipe_fixture();
+    let recipe = owned_recipe_fixture(&user_repo).await;
     let ingredient = IngredientWithAmount {
         ingredient: ingredient_fixture(),
         amount: IngredientUnit::Grams(666.0),
@@ -42,10 +49,16 @@ pub async fn adding_an_ingredient_to_a_recipe_works(
 
     recipe_repo.insert(recipe.clone()).await.unwrap();
 
-    let updated_recipe =
-        add_ingredient_to_recipe(recipe_repo, ingredient_repo, &recipe.id, ingredient_payload)
-            .await
-            .unwrap();
+    let updated_recipe = add_ingredient_to_recipe(
+        recipe_repo,
+        ingredient_repo,
+        &user_fixture().id,
+        &recipe.id,
+        ingredient_payload,
+        None,
+    )
+    .await
+    .unwrap();
 
     let expected: HashSet<_> = all_ingredients
         .iter()
@@ -61,8 +74,9 @@ pub async fn adding_an_ingredient_to_a_recipe_works(
 pub async fn adding_a_nonexistent_ingredient_to_a_recipe_errors(
     recipe_repo: impl RecipeRepository,
     ing_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
 ) {
-    let recipe = recipe_fixture();
+    let recipe = owned_recipe_fixture(&user_repo).await;
     let ingredient = IngredientWithAmount {
         ingredient: ingredient_fixture(),
         amount: IngredientUnit::Grams(666.0),
@@ -78,13 +92,151 @@ pub async fn adding_a_nonexistent_ingredient_to_a_recipe_errors(
 
     let ingredient_payload = IngredientAmountData::from(ingredient.clone());
 
-    let error =
-        add_ingredient_to_recipe(recipe_repo, ingredient_repo, &recipe.id, ingredient_payload)
-            .await
-            .unwrap_err();
+    let error = add_ingredient_to_recipe(
+        recipe_repo,
+        ingredient_repo,
+        &user_fixture().id,
+        &recipe.id,
+        ingredient_payload,
+        None,
+    )
+    .await
+    .unwrap_err();
 
     assert!(matches!(
         error,
         AddIngredientToRecipeError::GetIngredient(GetIngredientByIdError::NotFound(_))
     ))
 }
+
+pub async fn adding_an_ingredient_to_someone_elses_recipe_is_forbidden(
+    repo: impl RecipeRepository,
+    ing_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let recipe = owned_recipe_fixture(&user_repo).await;
+    let ingredient = IngredientWithAmount {
+        ingredient: ingredient_fixture(),
+        amount: IngredientUnit::Grams(666.0),
+        notes: None,
+        optional: true,
+    };
+    let mut all_ingredients = recipe.ingredients.to_vec().clone();
+    all_ingredients.push(ingredient.clone());
+
+    insert_all_ingredients(&ing_repo, &all_ingredients).await;
+    repo.insert(recipe.clone()).await.unwrap();
+
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(repo));
+    let ingredient_repo: IngredientRepositoryService = Arc::new(Box::new(ing_repo));
+
+    let error = add_ingredient_to_recipe(
+        recipe_repo.clone(),
+        ingredient_repo,
+        &Uuid::from_u128(0xdead),
+        &recipe.id,
+        IngredientAmountData::from(ingredient),
+        None,
+    )
+    .await
+    .unwrap_err();
+
+    assert!(matches!(error, AddIngredientToRecipeError::Forbidden(id) if id == recipe.id));
+    let unchanged = recipe_repo.get_by_id(&recipe.id).await.unwrap();
+    assert_eq!(unchanged.ingredients.len(), recipe.ingredients.len());
+}
+
+pub async fn adding_an_ingredient_to_an_outdated_version_of_a_recipe_conflicts(
+    repo: impl RecipeRepository,
+    ing_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let recipe = owned_recipe_fixture(&user_repo).await;
+    let ingredient = IngredientWithAmount {
+        ingredient: ingredient_fixture(),
+        amount: IngredientUnit::Grams(666.0),
+        notes: None,
+        optional: true,
+    };
+    let mut all_ingredients = recipe.ingredients.to_vec();
+    all_ingredients.push(ingredient.clone());
+    insert_all_ingredients(&ing_repo, &all_ingredients).await;
+
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(repo));
+    let ingredient_repo: IngredientRepositoryService = Arc::new(Box::new(ing_repo));
+
+    recipe_repo.insert(recipe.clone()).await.unwrap();
+    let version = recipe_repo.get_by_id(&recipe.id).await.unwrap().version();
+
+    update_recipe(
+        recipe_repo.clone(),
+        &user_fixture().id,
+        &recipe.id,
+        recipe_changeset(),
+        None,
+    )
+    .await
+    .unwrap();
+
+    let error = add_ingredient_to_recipe(
+        recipe_repo.clone(),
+        ingredient_repo,
+        &user_fixture().id,
+        &recipe.id,
+        IngredientAmountData::from(ingredient),
+        Some(std::slice::from_ref(&version)),
+    )
+    .await
+    .unwrap_err();
+
+    assert!(matches!(error, AddIngredientToRecipeError::Conflict(id) if id == recipe.id));
+
+    let unchanged = recipe_repo.get_by_id(&recipe.id).await.unwrap();
+    assert_eq!(unchanged.ingredients.len(), recipe.ingredients.len());
+}
+
+pub async fn adding_an_ingredient_writes_a_message_to_the_outbox(
+    repo: impl RecipeRepository,
+    ing_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+    outbox: impl OutboxRepository,
+) {
+    let recipe = owned_recipe_fixture(&user_repo).await;
+    let ingredient = IngredientWithAmount {
+        ingredient: ingredient_fixture(),
+        amount: IngredientUnit::Grams(666.0),
+        notes: None,
+        optional: true,
+    };
+
+    insert_all_ingredients_of_recipe(&ing_repo, &recipe).await;
+    ing_repo.insert(ingredient_fixture()).await.unwrap();
+
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(repo));
+    let ingredient_repo: IngredientRepositoryService = Arc::new(Box::new(ing_repo));
+
+    recipe_repo.insert(recipe.clone()).await.unwrap();
+
+    add_ingredient_to_recipe(
+        recipe_repo,
+        ingredient_repo,
+        &user_fixture().id,
+        &recipe.id,
+        IngredientAmountData::from(ingredient.clone()),
+        None,
+    )
+    .await
+    .unwrap();
+
+    let added = pending_events(&outbox)
+        .await
+        .into_iter()
+        .find_map(|event| match event {
+            Event::RecipeIngredientAdded(added) => Some(added),
+            _ => None,
+        })
+        .unwrap();
+
+    assert_eq!(added.recipe_id, recipe.id);
+    assert_eq!(added.ingredient, ingredient.into());
+}
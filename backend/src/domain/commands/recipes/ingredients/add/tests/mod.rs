@@ -4,21 +4,70 @@ mod in_memory {
     use super::__tests__;
     use crate::domain::repositories::{
         ingredients::in_memory::InMemoryIngredientRepository,
-        recipe::in_memory::InMemoryRecipeRepository,
+        outbox::in_memory::InMemoryOutboxRepository, recipe::in_memory::InMemoryRecipeRepository,
+        users::in_memory::InMemoryUserRepository,
     };
 
     #[tokio::test]
     async fn adding_an_ingredient_to_a_recipe_works() {
         let ingredient_repo = InMemoryIngredientRepository::new();
         let repo = InMemoryRecipeRepository::new();
-        __tests__::adding_an_ingredient_to_a_recipe_works(repo, ingredient_repo).await
+        let user_repo = InMemoryUserRepository::new();
+        __tests__::adding_an_ingredient_to_a_recipe_works(repo, ingredient_repo, user_repo).await
     }
 
     #[tokio::test]
     async fn adding_a_nonexistent_ingredient_to_a_recipe_errors() {
         let ingredient_repo = InMemoryIngredientRepository::new();
         let repo = InMemoryRecipeRepository::new();
-        __tests__::adding_a_nonexistent_ingredient_to_a_recipe_errors(repo, ingredient_repo).await
+        let user_repo = InMemoryUserRepository::new();
+        __tests__::adding_a_nonexistent_ingredient_to_a_recipe_errors(
+            repo,
+            ingredient_repo,
+            user_repo,
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn adding_an_ingredient_to_someone_elses_recipe_is_forbidden() {
+        let repo = InMemoryRecipeRepository::new();
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let user_repo = InMemoryUserRepository::new();
+        __tests__::adding_an_ingredient_to_someone_elses_recipe_is_forbidden(
+            repo,
+            ingredient_repo,
+            user_repo,
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn adding_an_ingredient_to_an_outdated_version_of_a_recipe_conflicts() {
+        let recipe_repo = InMemoryRecipeRepository::new();
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let user_repo = InMemoryUserRepository::new();
+        __tests__::adding_an_ingredient_to_an_outdated_version_of_a_recipe_conflicts(
+            recipe_repo,
+            ingredient_repo,
+            user_repo,
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn adding_an_ingredient_writes_a_message_to_the_outbox() {
+        let outbox = InMemoryOutboxRepository::new();
+        let repo = InMemoryRecipeRepository::new().with_outbox(outbox.clone());
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let user_repo = InMemoryUserRepository::new();
+        __tests__::adding_an_ingredient_writes_a_message_to_the_outbox(
+            repo,
+            ingredient_repo,
+            user_repo,
+            outbox,
+        )
+        .await
     }
 }
 
@@ -28,20 +77,69 @@ mod sql {
 
     use crate::domain::repositories::{
         ingredients::postgres::PostgresIngredientRepository,
-        recipe::postgres::PostgresRecipeRepository,
+        outbox::postgres::PostgresOutboxRepository, recipe::postgres::PostgresRecipeRepository,
+        users::postgres::PostgresUserRepository,
     };
 
     #[sqlx::test]
     async fn adding_an_ingredient_to_a_recipe_works(pool: PgPool) {
         let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
-        let repo = PostgresRecipeRepository::new(pool);
-        __tests__::adding_an_ingredient_to_a_recipe_works(repo, ingredient_repo).await
+        let repo = PostgresRecipeRepository::new(pool.clone());
+        let user_repo = PostgresUserRepository::new(pool);
+        __tests__::adding_an_ingredient_to_a_recipe_works(repo, ingredient_repo, user_repo).await
     }
 
     #[sqlx::test]
     async fn adding_a_nonexistent_ingredient_to_a_recipe_errors(pool: PgPool) {
         let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
-        let repo = PostgresRecipeRepository::new(pool);
-        __tests__::adding_a_nonexistent_ingredient_to_a_recipe_errors(repo, ingredient_repo).await
+        let repo = PostgresRecipeRepository::new(pool.clone());
+        let user_repo = PostgresUserRepository::new(pool);
+        __tests__::adding_a_nonexistent_ingredient_to_a_recipe_errors(
+            repo,
+            ingredient_repo,
+            user_repo,
+        )
+        .await
+    }
+
+    #[sqlx::test]
+    async fn adding_an_ingredient_to_someone_elses_recipe_is_forbidden(pool: PgPool) {
+        let repo = PostgresRecipeRepository::new(pool.clone());
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let user_repo = PostgresUserRepository::new(pool);
+        __tests__::adding_an_ingredient_to_someone_elses_recipe_is_forbidden(
+            repo,
+            ingredient_repo,
+            user_repo,
+        )
+        .await
+    }
+
+    #[sqlx::test]
+    async fn adding_an_ingredient_to_an_outdated_version_of_a_recipe_conflicts(pool: PgPool) {
+        let recipe_repo = PostgresRecipeRepository::new(pool.clone());
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let user_repo = PostgresUserRepository::new(pool);
+        __tests__::adding_an_ingredient_to_an_outdated_version_of_a_recipe_conflicts(
+            recipe_repo,
+            ingredient_repo,
+            user_repo,
+        )
+        .await
+    }
+
+    #[sqlx::test]
+    async fn adding_an_ingredient_writes_a_message_to_the_outbox(pool: PgPool) {
+        let repo = PostgresRecipeRepository::new(pool.clone());
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let user_repo = PostgresUserRepository::new(pool.clone());
+        let outbox = PostgresOutboxRepository::new(pool);
+        __tests__::adding_an_ingredient_writes_a_message_to_the_outbox(
+            repo,
+            ingredient_repo,
+            user_repo,
+            outbox,
+        )
+        .await
     }
 }
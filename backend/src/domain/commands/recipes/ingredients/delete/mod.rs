@@ -13,6 +13,12 @@ pub enum DeleteIngredientFromRecipeError {
     #[error("Could not found recipe with ID {0}")]
     RecipeNotFoundError(Uuid),
 
+    #[error("You are not allowed to modify the recipe with ID {0}")]
+    Forbidden(Uuid),
+
+    #[error("The recipe with ID {0} has been changed since you last fetched it")]
+    Conflict(Uuid),
+
     #[error("The recipe has no ingredient with ID of {0}")]
     RecipeHasNoIngredientError(Uuid),
 
@@ -29,6 +35,7 @@ impl From<DeleteIngredientFromRecipeErrorInternal> for DeleteIngredientFromRecip
             DeleteIngredientFromRecipeErrorInternal::ValidationError(
                 ValidationError::EmptyField(field),
             ) if field == vec!["steps"] => Self::LastIngredientError,
+            DeleteIngredientFromRecipeErrorInternal::Outdated(id) => Self::Conflict(id),
             e => e.into(),
         }
     }
@@ -43,13 +50,24 @@ impl From<GetRecipeByIdError> for DeleteIngredientFromRecipeError {
     }
 }
 
+/// When expected versions are given, the ingredient is only deleted if the recipe is still in one of them.
 pub async fn delete_ingredient_from_recipe(
     recipe_repo: RecipeRepositoryService,
+    user_id: &Uuid,
     recipe_id: &Uuid,
     ingredient_id: &Uuid,
+    expected_versions: Option<&[String]>,
 ) -> Result<(), DeleteIngredientFromRecipeError> {
     let recipe = recipe_repo.get_by_id(recipe_id).await?;
 
+    if !recipe.can_be_modified_by(user_id) {
+        return Err(DeleteIngredientFromRecipeError::Forbidden(recipe.id));
+    }
+
+    if expected_versions.is_some_and(|versions| !versions.contains(&recipe.version())) {
+        return Err(DeleteIngredientFromRecipeError::Conflict(recipe.id));
+    }
+
     if recipe.ingredients.len() == 1 {
         return Err(DeleteIngredientFromRecipeError::LastIngredientError);
     };
@@ -63,7 +81,7 @@ pub async fn delete_ingredient_from_recipe(
         })?;
 
     recipe_repo
-        .delete_ingredient(&recipe, ingredient_in_recipe)
+        .delete_ingredient(&recipe, ingredient_in_recipe, user_id)
         .await?;
 
     Ok(())
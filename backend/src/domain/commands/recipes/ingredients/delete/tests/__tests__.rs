@@ -1,25 +1,34 @@
 use crate::domain::{
-    commands::recipes::ingredients::delete::{
-        delete_ingredient_from_recipe, DeleteIngredientFromRecipeError,
+    commands::recipes::{
+        ingredients::delete::{delete_ingredient_from_recipe, DeleteIngredientFromRecipeError},
+        update::update_recipe,
     },
     repositories::recipe::RecipeRepositoryService,
 };
+use common::events::Event;
 use std::sync::Arc;
 use uuid::Uuid;
 
 use crate::{
     domain::{
         entities::recipe::{IngredientWithAmount, Recipe},
-        repositories::{ingredients::IngredientRepository, recipe::RecipeRepository},
+        repositories::{
+            ingredients::IngredientRepository, outbox::OutboxRepository, recipe::RecipeRepository,
+            users::UserRepository,
+        },
+    },
+    test_utils::{
+        ingredient_fixture, insert_all_ingredients_of_recipe, owned_recipe_fixture, pending_events,
+        recipe_changeset, recipe_fixture, user_fixture,
     },
-    test_utils::{ingredient_fixture, insert_all_ingredients_of_recipe, recipe_fixture},
 };
 
 pub async fn deleting_an_existing_ingredient_works(
     repo: impl RecipeRepository,
     ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
 ) {
-    let initial_recipe = recipe_fixture();
+    let initial_recipe = owned_recipe_fixture(&user_repo).await;
     insert_all_ingredients_of_recipe(&ingredient_repo, &initial_recipe).await;
     repo.insert(initial_recipe.clone()).await.unwrap();
 
@@ -28,8 +37,10 @@ pub async fn deleting_an_existing_ingredient_works(
     let repo: RecipeRepositoryService = Arc::new(Box::new(repo));
     delete_ingredient_from_recipe(
         repo.clone(),
+        &user_fixture().id,
         &initial_recipe.id,
         &ingredient_to_delete.ingredient.id,
+        None,
     )
     .await
     .unwrap();
@@ -42,17 +53,23 @@ pub async fn deleting_an_existing_ingredient_works(
 pub async fn deleting_an_ingredient_that_doesnt_appear_in_recipe_errors(
     repo: impl RecipeRepository,
     ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
 ) {
-    let initial_recipe = recipe_fixture();
+    let initial_recipe = owned_recipe_fixture(&user_repo).await;
     insert_all_ingredients_of_recipe(&ingredient_repo, &initial_recipe).await;
     repo.insert(initial_recipe.clone()).await.unwrap();
 
     let repo: RecipeRepositoryService = Arc::new(Box::new(repo));
 
-    let error =
-        delete_ingredient_from_recipe(repo.clone(), &initial_recipe.id, &Uuid::from_u128(999))
-            .await
-            .unwrap_err();
+    let error = delete_ingredient_from_recipe(
+        repo.clone(),
+        &user_fixture().id,
+        &initial_recipe.id,
+        &Uuid::from_u128(999),
+        None,
+    )
+    .await
+    .unwrap_err();
 
     assert!(
         matches!(error, DeleteIngredientFromRecipeError::RecipeHasNoIngredientError(id) if id == Uuid::from_u128(999))
@@ -67,9 +84,15 @@ pub async fn deleting_an_ingredient_in_recipe_that_doesnt_exist_errors(
     insert_all_ingredients_of_recipe(&ingredient_repo, &initial_recipe).await;
 
     let repo: RecipeRepositoryService = Arc::new(Box::new(repo));
-    let error = delete_ingredient_from_recipe(repo.clone(), &initial_recipe.id, &Uuid::nil())
-        .await
-        .unwrap_err();
+    let error = delete_ingredient_from_recipe(
+        repo.clone(),
+        &user_fixture().id,
+        &initial_recipe.id,
+        &Uuid::nil(),
+        None,
+    )
+    .await
+    .unwrap_err();
 
     assert!(
         matches!(error, DeleteIngredientFromRecipeError::RecipeNotFoundError(id) if id == initial_recipe.id)
@@ -79,6 +102,7 @@ pub async fn deleting_an_ingredient_in_recipe_that_doesnt_exist_errors(
 pub async fn deleting_the_last_ingredient_in_recipe_errors(
     repo: impl RecipeRepository,
     ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
 ) {
     let ingredient = IngredientWithAmount {
         ingredient: ingredient_fixture(),
@@ -89,7 +113,7 @@ pub async fn deleting_the_last_ingredient_in_recipe_errors(
 
     let initial_recipe = Recipe {
         ingredients: vec![ingredient.clone()].try_into().unwrap(),
-        ..recipe_fixture()
+        ..owned_recipe_fixture(&user_repo).await
     };
 
     insert_all_ingredients_of_recipe(&ingredient_repo, &initial_recipe).await;
@@ -101,8 +125,10 @@ pub async fn deleting_the_last_ingredient_in_recipe_errors(
     let repo: RecipeRepositoryService = Arc::new(Box::new(repo));
     let error = delete_ingredient_from_recipe(
         repo.clone(),
+        &user_fixture().id,
         &initial_recipe.id,
         &ingredient_to_delete.ingredient.id,
+        None,
     )
     .await
     .unwrap_err();
@@ -112,3 +138,111 @@ pub async fn deleting_the_last_ingredient_in_recipe_errors(
         DeleteIngredientFromRecipeError::LastIngredientError
     ))
 }
+
+pub async fn deleting_an_ingredient_from_someone_elses_recipe_is_forbidden(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let initial_recipe = owned_recipe_fixture(&user_repo).await;
+    insert_all_ingredients_of_recipe(&ingredient_repo, &initial_recipe).await;
+    repo.insert(initial_recipe.clone()).await.unwrap();
+
+    let ingredient_to_delete = initial_recipe.ingredients.first().unwrap();
+
+    let repo: RecipeRepositoryService = Arc::new(Box::new(repo));
+    let error = delete_ingredient_from_recipe(
+        repo.clone(),
+        &Uuid::from_u128(0xdead),
+        &initial_recipe.id,
+        &ingredient_to_delete.ingredient.id,
+        None,
+    )
+    .await
+    .unwrap_err();
+
+    assert!(
+        matches!(error, DeleteIngredientFromRecipeError::Forbidden(id) if id == initial_recipe.id)
+    );
+    let recipe = repo.get_by_id(&initial_recipe.id).await.unwrap();
+    assert_eq!(recipe.ingredients.len(), initial_recipe.ingredients.len());
+}
+
+pub async fn deleting_an_ingredient_from_an_outdated_version_of_a_recipe_conflicts(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let initial_recipe = owned_recipe_fixture(&user_repo).await;
+    insert_all_ingredients_of_recipe(&ingredient_repo, &initial_recipe).await;
+    repo.insert(initial_recipe.clone()).await.unwrap();
+
+    let repo: RecipeRepositoryService = Arc::new(Box::new(repo));
+    let version = repo.get_by_id(&initial_recipe.id).await.unwrap().version();
+
+    update_recipe(
+        repo.clone(),
+        &user_fixture().id,
+        &initial_recipe.id,
+        recipe_changeset(),
+        None,
+    )
+    .await
+    .unwrap();
+
+    let error = delete_ingredient_from_recipe(
+        repo.clone(),
+        &user_fixture().id,
+        &initial_recipe.id,
+        &initial_recipe.ingredients[0].ingredient.id,
+        Some(std::slice::from_ref(&version)),
+    )
+    .await
+    .unwrap_err();
+
+    assert!(
+        matches!(error, DeleteIngredientFromRecipeError::Conflict(id) if id == initial_recipe.id)
+    );
+
+    let unchanged = repo.get_by_id(&initial_recipe.id).await.unwrap();
+    assert_eq!(
+        unchanged.ingredients.len(),
+        initial_recipe.ingredients.len()
+    );
+}
+
+pub async fn deleting_an_ingredient_writes_a_message_to_the_outbox(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+    outbox: impl OutboxRepository,
+) {
+    let recipe = owned_recipe_fixture(&user_repo).await;
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
+    repo.insert(recipe.clone()).await.unwrap();
+
+    let ingredient_to_delete = recipe.ingredients.first().unwrap();
+
+    let repo: RecipeRepositoryService = Arc::new(Box::new(repo));
+    delete_ingredient_from_recipe(
+        repo,
+        &user_fixture().id,
+        &recipe.id,
+        &ingredient_to_delete.ingredient.id,
+        None,
+    )
+    .await
+    .unwrap();
+
+    let removed = pending_events(&outbox)
+        .await
+        .into_iter()
+        .find_map(|event| match event {
+            Event::RecipeIngredientRemoved(removed) => Some(removed),
+            _ => None,
+        })
+        .unwrap();
+
+    assert_eq!(removed.recipe_id, recipe.id);
+    assert_eq!(removed.ingredient, ingredient_to_delete.clone().into());
+}
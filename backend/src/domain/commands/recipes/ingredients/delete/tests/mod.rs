@@ -3,23 +3,28 @@ mod __tests__;
 mod in_memory {
     use crate::domain::repositories::{
         ingredients::in_memory::InMemoryIngredientRepository,
-        recipe::in_memory::InMemoryRecipeRepository,
+        outbox::in_memory::InMemoryOutboxRepository, recipe::in_memory::InMemoryRecipeRepository,
+        users::in_memory::InMemoryUserRepository,
     };
 
     #[tokio::test]
     async fn deleting_an_existing_ingredient_works() {
         let repo = InMemoryRecipeRepository::new();
         let ingredient_repo = InMemoryIngredientRepository::new();
-        super::__tests__::deleting_an_existing_ingredient_works(repo, ingredient_repo).await;
+        let user_repo = InMemoryUserRepository::new();
+        super::__tests__::deleting_an_existing_ingredient_works(repo, ingredient_repo, user_repo)
+            .await;
     }
 
     #[tokio::test]
     async fn deleting_an_ingredient_that_doesnt_appear_in_recipe_errors() {
         let repo = InMemoryRecipeRepository::new();
         let ingredient_repo = InMemoryIngredientRepository::new();
+        let user_repo = InMemoryUserRepository::new();
         super::__tests__::deleting_an_ingredient_that_doesnt_appear_in_recipe_errors(
             repo,
             ingredient_repo,
+            user_repo,
         )
         .await;
     }
@@ -39,8 +44,54 @@ mod in_memory {
     async fn deleting_the_last_ingredient_in_recipe_errors() {
         let repo = InMemoryRecipeRepository::new();
         let ingredient_repo = InMemoryIngredientRepository::new();
-        super::__tests__::deleting_the_last_ingredient_in_recipe_errors(repo, ingredient_repo)
-            .await;
+        let user_repo = InMemoryUserRepository::new();
+        super::__tests__::deleting_the_last_ingredient_in_recipe_errors(
+            repo,
+            ingredient_repo,
+            user_repo,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    async fn deleting_an_ingredient_from_someone_elses_recipe_is_forbidden() {
+        let repo = InMemoryRecipeRepository::new();
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let user_repo = InMemoryUserRepository::new();
+        super::__tests__::deleting_an_ingredient_from_someone_elses_recipe_is_forbidden(
+            repo,
+            ingredient_repo,
+            user_repo,
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn deleting_an_ingredient_from_an_outdated_version_of_a_recipe_conflicts() {
+        let recipe_repo = InMemoryRecipeRepository::new();
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let user_repo = InMemoryUserRepository::new();
+        super::__tests__::deleting_an_ingredient_from_an_outdated_version_of_a_recipe_conflicts(
+            recipe_repo,
+            ingredient_repo,
+            user_repo,
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn deleting_an_ingredient_writes_a_message_to_the_outbox() {
+        let outbox = InMemoryOutboxRepository::new();
+        let repo = InMemoryRecipeRepository::new().with_outbox(outbox.clone());
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let user_repo = InMemoryUserRepository::new();
+        super::__tests__::deleting_an_ingredient_writes_a_message_to_the_outbox(
+            repo,
+            ingredient_repo,
+            user_repo,
+            outbox,
+        )
+        .await
     }
 }
 
@@ -49,23 +100,28 @@ mod sql {
 
     use crate::domain::repositories::{
         ingredients::postgres::PostgresIngredientRepository,
-        recipe::postgres::PostgresRecipeRepository,
+        outbox::postgres::PostgresOutboxRepository, recipe::postgres::PostgresRecipeRepository,
+        users::postgres::PostgresUserRepository,
     };
 
     #[sqlx::test]
     async fn deleting_an_existing_ingredient_works(pool: PgPool) {
         let repo = PostgresRecipeRepository::new(pool.clone());
         let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
-        super::__tests__::deleting_an_existing_ingredient_works(repo, ingredient_repo).await;
+        let user_repo = PostgresUserRepository::new(pool.clone());
+        super::__tests__::deleting_an_existing_ingredient_works(repo, ingredient_repo, user_repo)
+            .await;
     }
 
     #[sqlx::test]
     async fn deleting_an_ingredient_that_doesnt_appear_in_recipe_errors(pool: PgPool) {
         let repo = PostgresRecipeRepository::new(pool.clone());
         let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let user_repo = PostgresUserRepository::new(pool.clone());
         super::__tests__::deleting_an_ingredient_that_doesnt_appear_in_recipe_errors(
             repo,
             ingredient_repo,
+            user_repo,
         )
         .await;
     }
@@ -85,7 +141,53 @@ mod sql {
     async fn deleting_the_last_ingredient_in_recipe_errors(pool: PgPool) {
         let repo = PostgresRecipeRepository::new(pool.clone());
         let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
-        super::__tests__::deleting_the_last_ingredient_in_recipe_errors(repo, ingredient_repo)
-            .await;
+        let user_repo = PostgresUserRepository::new(pool.clone());
+        super::__tests__::deleting_the_last_ingredient_in_recipe_errors(
+            repo,
+            ingredient_repo,
+            user_repo,
+        )
+        .await;
+    }
+
+    #[sqlx::test]
+    async fn deleting_an_ingredient_from_someone_elses_recipe_is_forbidden(pool: PgPool) {
+        let repo = PostgresRecipeRepository::new(pool.clone());
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let user_repo = PostgresUserRepository::new(pool);
+        super::__tests__::deleting_an_ingredient_from_someone_elses_recipe_is_forbidden(
+            repo,
+            ingredient_repo,
+            user_repo,
+        )
+        .await
+    }
+
+    #[sqlx::test]
+    async fn deleting_an_ingredient_from_an_outdated_version_of_a_recipe_conflicts(pool: PgPool) {
+        let recipe_repo = PostgresRecipeRepository::new(pool.clone());
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let user_repo = PostgresUserRepository::new(pool);
+        super::__tests__::deleting_an_ingredient_from_an_outdated_version_of_a_recipe_conflicts(
+            recipe_repo,
+            ingredient_repo,
+            user_repo,
+        )
+        .await
+    }
+
+    #[sqlx::test]
+    async fn deleting_an_ingredient_writes_a_message_to_the_outbox(pool: PgPool) {
+        let repo = PostgresRecipeRepository::new(pool.clone());
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let user_repo = PostgresUserRepository::new(pool.clone());
+        let outbox = PostgresOutboxRepository::new(pool);
+        super::__tests__::deleting_an_ingredient_writes_a_message_to_the_outbox(
+            repo,
+            ingredient_repo,
+            user_repo,
+            outbox,
+        )
+        .await
     }
 }
@@ -20,24 +20,44 @@ pub enum UpdateIngredientInRecipeError {
     #[error("Could not find ingredient with ID {0} in this recipe.")]
     MissingIngredient(Uuid),
 
+    #[error("You are not allowed to modify the recipe with ID {0}")]
+    Forbidden(Uuid),
+
+    #[error("The recipe with ID {0} has been changed since you last fetched it")]
+    Conflict(Uuid),
+
     #[error(transparent)]
     Unknown(#[from] eyre::Report),
 }
 
 impl From<UpdateIngredientInRecipeErrorInternal> for UpdateIngredientInRecipeError {
     fn from(value: UpdateIngredientInRecipeErrorInternal) -> Self {
-        Self::Unknown(value.into())
+        match value {
+            UpdateIngredientInRecipeErrorInternal::Outdated(id) => Self::Conflict(id),
+            e => Self::Unknown(e.into()),
+        }
     }
 }
 
+/// When expected versions are given, the amount is only changed if the recipe is still in one of them.
 pub async fn update_ingredient_in_recipe(
     recipe_repo: RecipeRepositoryService,
+    user_id: &Uuid,
     recipe_id: &Uuid,
     ingredient_id: &Uuid,
     amount: IngredientUnit,
+    expected_versions: Option<&[String]>,
 ) -> Result<Recipe, UpdateIngredientInRecipeError> {
     let recipe = recipe_repo.get_by_id(recipe_id).await?;
 
+    if !recipe.can_be_modified_by(user_id) {
+        return Err(UpdateIngredientInRecipeError::Forbidden(recipe.id));
+    }
+
+    if expected_versions.is_some_and(|versions| !versions.contains(&recipe.version())) {
+        return Err(UpdateIngredientInRecipeError::Conflict(recipe.id));
+    }
+
     let ingredient_in_recipe = &recipe
         .ingredients
         .iter()
@@ -47,7 +67,7 @@ pub async fn update_ingredient_in_recipe(
         ))?;
 
     recipe_repo
-        .update_ingredient_amount(&recipe, ingredient_in_recipe, &amount)
+        .update_ingredient_amount(&recipe, ingredient_in_recipe, &amount, user_id)
         .await?;
 
     let recipe = recipe_repo.get_by_id(recipe_id).await?;
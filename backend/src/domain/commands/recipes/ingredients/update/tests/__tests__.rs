@@ -1,25 +1,33 @@
+use common::events::Event;
 use uuid::Uuid;
 
 use crate::{
     domain::{
-        commands::recipes::ingredients::update::{
-            update_ingredient_in_recipe, UpdateIngredientInRecipeError,
+        commands::recipes::{
+            ingredients::update::{update_ingredient_in_recipe, UpdateIngredientInRecipeError},
+            update::update_recipe,
         },
         entities::recipe::IngredientUnit,
         repositories::{
             ingredients::IngredientRepository,
+            outbox::OutboxRepository,
             recipe::{errors::GetRecipeByIdError, RecipeRepository, RecipeRepositoryService},
+            users::UserRepository,
         },
     },
-    test_utils::{insert_all_ingredients_of_recipe, recipe_fixture},
+    test_utils::{
+        insert_all_ingredients_of_recipe, owned_recipe_fixture, pending_events, recipe_changeset,
+        recipe_fixture, user_fixture,
+    },
 };
 use std::sync::Arc;
 
 pub async fn updating_ingredient_in_recipe_works(
     recipe_repo: impl RecipeRepository,
     ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
 ) {
-    let initial_recipe = recipe_fixture();
+    let initial_recipe = owned_recipe_fixture(&user_repo).await;
     insert_all_ingredients_of_recipe(&ingredient_repo, &initial_recipe).await;
     recipe_repo.insert(initial_recipe.clone()).await.unwrap();
 
@@ -30,9 +38,11 @@ pub async fn updating_ingredient_in_recipe_works(
 
     let result = update_ingredient_in_recipe(
         recipe_repo,
+        &user_fixture().id,
         &initial_recipe.id,
         &ingredient_to_update.ingredient.id,
         amount.clone(),
+        None,
     )
     .await
     .unwrap();
@@ -54,9 +64,11 @@ pub async fn updating_ingredient_in_nonexistent_recipe_errors(
 
     let error = update_ingredient_in_recipe(
         recipe_repo,
+        &user_fixture().id,
         &initial_recipe.id,
         &ingredient_to_update.ingredient.id,
         amount.clone(),
+        None,
     )
     .await
     .unwrap_err();
@@ -69,8 +81,9 @@ pub async fn updating_ingredient_in_nonexistent_recipe_errors(
 pub async fn updating_nonexistent_ingredient_in_recipe_errors(
     recipe_repo: impl RecipeRepository,
     ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
 ) {
-    let initial_recipe = recipe_fixture();
+    let initial_recipe = owned_recipe_fixture(&user_repo).await;
     insert_all_ingredients_of_recipe(&ingredient_repo, &initial_recipe).await;
     recipe_repo.insert(initial_recipe.clone()).await.unwrap();
 
@@ -80,9 +93,11 @@ pub async fn updating_nonexistent_ingredient_in_recipe_errors(
 
     let error = update_ingredient_in_recipe(
         recipe_repo,
+        &user_fixture().id,
         &initial_recipe.id,
         &Uuid::from_u128(0xff),
         amount.clone(),
+        None,
     )
     .await
     .unwrap_err();
@@ -91,3 +106,125 @@ pub async fn updating_nonexistent_ingredient_in_recipe_errors(
         matches!(error, UpdateIngredientInRecipeError::MissingIngredient(id) if id == Uuid::from_u128(0xff))
     )
 }
+
+pub async fn updating_ingredient_in_someone_elses_recipe_is_forbidden(
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let initial_recipe = owned_recipe_fixture(&user_repo).await;
+    insert_all_ingredients_of_recipe(&ingredient_repo, &initial_recipe).await;
+    recipe_repo.insert(initial_recipe.clone()).await.unwrap();
+
+    let ingredient_to_update = initial_recipe.ingredients.first().unwrap();
+
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+
+    let error = update_ingredient_in_recipe(
+        recipe_repo,
+        &Uuid::from_u128(0xdead),
+        &initial_recipe.id,
+        &ingredient_to_update.ingredient.id,
+        IngredientUnit::Cups(2.0),
+        None,
+    )
+    .await
+    .unwrap_err();
+
+    assert!(
+        matches!(error, UpdateIngredientInRecipeError::Forbidden(id) if id == initial_recipe.id)
+    )
+}
+
+pub async fn updating_ingredient_in_an_outdated_version_of_a_recipe_conflicts(
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let initial_recipe = owned_recipe_fixture(&user_repo).await;
+    insert_all_ingredients_of_recipe(&ingredient_repo, &initial_recipe).await;
+    recipe_repo.insert(initial_recipe.clone()).await.unwrap();
+
+    let ingredient_to_update = initial_recipe.ingredients.first().unwrap();
+
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+    let version = recipe_repo
+        .get_by_id(&initial_recipe.id)
+        .await
+        .unwrap()
+        .version();
+
+    update_recipe(
+        recipe_repo.clone(),
+        &user_fixture().id,
+        &initial_recipe.id,
+        recipe_changeset(),
+        None,
+    )
+    .await
+    .unwrap();
+
+    let error = update_ingredient_in_recipe(
+        recipe_repo.clone(),
+        &user_fixture().id,
+        &initial_recipe.id,
+        &ingredient_to_update.ingredient.id,
+        IngredientUnit::Cups(2.0),
+        Some(std::slice::from_ref(&version)),
+    )
+    .await
+    .unwrap_err();
+
+    assert!(
+        matches!(error, UpdateIngredientInRecipeError::Conflict(id) if id == initial_recipe.id)
+    );
+
+    let unchanged = recipe_repo.get_by_id(&initial_recipe.id).await.unwrap();
+    let unchanged_ingredient = unchanged
+        .ingredients
+        .iter()
+        .find(|i| i.ingredient.id == ingredient_to_update.ingredient.id)
+        .unwrap();
+    assert_eq!(unchanged_ingredient.amount, ingredient_to_update.amount);
+}
+
+pub async fn updating_an_ingredient_writes_a_message_to_the_outbox(
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+    outbox: impl OutboxRepository,
+) {
+    let recipe = owned_recipe_fixture(&user_repo).await;
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
+    recipe_repo.insert(recipe.clone()).await.unwrap();
+
+    let ingredient_to_update = recipe.ingredients.first().unwrap();
+    let amount = IngredientUnit::Cups(2.0);
+
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+
+    update_ingredient_in_recipe(
+        recipe_repo,
+        &user_fixture().id,
+        &recipe.id,
+        &ingredient_to_update.ingredient.id,
+        amount.clone(),
+        None,
+    )
+    .await
+    .unwrap();
+
+    let change = pending_events(&outbox)
+        .await
+        .into_iter()
+        .find_map(|event| match event {
+            Event::RecipeIngredientAmountChanged(change) => Some(change),
+            _ => None,
+        })
+        .unwrap();
+
+    assert_eq!(change.recipe_id, recipe.id);
+    assert_eq!(change.old, ingredient_to_update.clone().into());
+    assert_eq!(change.new.ingredient.id, ingredient_to_update.ingredient.id);
+    assert_eq!(change.new.amount, amount.into());
+}
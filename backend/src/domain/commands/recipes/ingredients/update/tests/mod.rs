@@ -4,7 +4,8 @@ mod in_memory {
     use super::__tests__;
     use crate::domain::repositories::{
         ingredients::in_memory::InMemoryIngredientRepository,
-        recipe::in_memory::InMemoryRecipeRepository,
+        outbox::in_memory::InMemoryOutboxRepository, recipe::in_memory::InMemoryRecipeRepository,
+        users::in_memory::InMemoryUserRepository,
     };
 
     #[tokio::test]
@@ -12,7 +13,9 @@ mod in_memory {
         let recipe_repo = InMemoryRecipeRepository::new();
         let ingredient_repo = InMemoryIngredientRepository::new();
 
-        __tests__::updating_ingredient_in_recipe_works(recipe_repo, ingredient_repo).await
+        let user_repo = InMemoryUserRepository::new();
+        __tests__::updating_ingredient_in_recipe_works(recipe_repo, ingredient_repo, user_repo)
+            .await
     }
 
     #[tokio::test]
@@ -29,8 +32,54 @@ mod in_memory {
         let recipe_repo = InMemoryRecipeRepository::new();
         let ingredient_repo = InMemoryIngredientRepository::new();
 
-        __tests__::updating_nonexistent_ingredient_in_recipe_errors(recipe_repo, ingredient_repo)
-            .await
+        let user_repo = InMemoryUserRepository::new();
+        __tests__::updating_nonexistent_ingredient_in_recipe_errors(
+            recipe_repo,
+            ingredient_repo,
+            user_repo,
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn updating_ingredient_in_someone_elses_recipe_is_forbidden() {
+        let recipe_repo = InMemoryRecipeRepository::new();
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let user_repo = InMemoryUserRepository::new();
+        __tests__::updating_ingredient_in_someone_elses_recipe_is_forbidden(
+            recipe_repo,
+            ingredient_repo,
+            user_repo,
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn updating_ingredient_in_an_outdated_version_of_a_recipe_conflicts() {
+        let recipe_repo = InMemoryRecipeRepository::new();
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let user_repo = InMemoryUserRepository::new();
+        __tests__::updating_ingredient_in_an_outdated_version_of_a_recipe_conflicts(
+            recipe_repo,
+            ingredient_repo,
+            user_repo,
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn updating_an_ingredient_writes_a_message_to_the_outbox() {
+        let outbox = InMemoryOutboxRepository::new();
+        let repo = InMemoryRecipeRepository::new().with_outbox(outbox.clone());
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let user_repo = InMemoryUserRepository::new();
+        __tests__::updating_an_ingredient_writes_a_message_to_the_outbox(
+            repo,
+            ingredient_repo,
+            user_repo,
+            outbox,
+        )
+        .await
     }
 }
 
@@ -40,7 +89,8 @@ mod sql {
 
     use crate::domain::repositories::{
         ingredients::postgres::PostgresIngredientRepository,
-        recipe::postgres::PostgresRecipeRepository,
+        outbox::postgres::PostgresOutboxRepository, recipe::postgres::PostgresRecipeRepository,
+        users::postgres::PostgresUserRepository,
     };
 
     #[sqlx::test]
@@ -48,7 +98,9 @@ mod sql {
         let recipe_repo = PostgresRecipeRepository::new(pool.clone());
         let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
 
-        __tests__::updating_ingredient_in_recipe_works(recipe_repo, ingredient_repo).await
+        let user_repo = PostgresUserRepository::new(pool.clone());
+        __tests__::updating_ingredient_in_recipe_works(recipe_repo, ingredient_repo, user_repo)
+            .await
     }
 
     #[sqlx::test]
@@ -65,7 +117,53 @@ mod sql {
         let recipe_repo = PostgresRecipeRepository::new(pool.clone());
         let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
 
-        __tests__::updating_nonexistent_ingredient_in_recipe_errors(recipe_repo, ingredient_repo)
-            .await
+        let user_repo = PostgresUserRepository::new(pool.clone());
+        __tests__::updating_nonexistent_ingredient_in_recipe_errors(
+            recipe_repo,
+            ingredient_repo,
+            user_repo,
+        )
+        .await
+    }
+
+    #[sqlx::test]
+    async fn updating_ingredient_in_someone_elses_recipe_is_forbidden(pool: PgPool) {
+        let recipe_repo = PostgresRecipeRepository::new(pool.clone());
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let user_repo = PostgresUserRepository::new(pool);
+        __tests__::updating_ingredient_in_someone_elses_recipe_is_forbidden(
+            recipe_repo,
+            ingredient_repo,
+            user_repo,
+        )
+        .await
+    }
+
+    #[sqlx::test]
+    async fn updating_ingredient_in_an_outdated_version_of_a_recipe_conflicts(pool: PgPool) {
+        let recipe_repo = PostgresRecipeRepository::new(pool.clone());
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let user_repo = PostgresUserRepository::new(pool);
+        __tests__::updating_ingredient_in_an_outdated_version_of_a_recipe_conflicts(
+            recipe_repo,
+            ingredient_repo,
+            user_repo,
+        )
+        .await
+    }
+
+    #[sqlx::test]
+    async fn updating_an_ingredient_writes_a_message_to_the_outbox(pool: PgPool) {
+        let repo = PostgresRecipeRepository::new(pool.clone());
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let user_repo = PostgresUserRepository::new(pool.clone());
+        let outbox = PostgresOutboxRepository::new(pool);
+        __tests__::updating_an_ingredient_writes_a_message_to_the_outbox(
+            repo,
+            ingredient_repo,
+            user_repo,
+            outbox,
+        )
+        .await
     }
 }
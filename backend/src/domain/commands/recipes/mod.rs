@@ -1,4 +1,6 @@
 pub mod create;
 pub mod delete;
+pub mod import;
 pub mod ingredients;
+pub mod revert;
 pub mod update;
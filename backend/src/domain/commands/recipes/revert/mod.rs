@@ -0,0 +1,155 @@
+use std::collections::HashMap;
+
+use uuid::Uuid;
+
+use crate::domain::entities::recipe::errors::ValidationError;
+use crate::domain::entities::recipe::revisions::RecipeRevision;
+use crate::domain::entities::recipe::{IngredientWithAmount, Recipe};
+use crate::domain::repositories::ingredients::errors::GetAllIngredientsError;
+use crate::domain::repositories::ingredients::IngredientRepositoryService;
+use crate::domain::repositories::recipe::errors::{
+    GetRecipeByIdError, GetRecipeRevisionsError, RestoreRecipeError,
+};
+use crate::domain::repositories::recipe::RecipeRepositoryService;
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum RevertRecipeError {
+    #[error("Could not find the recipe with ID {0}")]
+    NotFound(Uuid),
+
+    #[error("You are not allowed to modify the recipe with ID {0}")]
+    Forbidden(Uuid),
+
+    #[error("The recipe with ID {0} has been changed since you last fetched it")]
+    Conflict(Uuid),
+
+    #[error("The recipe does not have a revision number {0}")]
+    RevisionNotFound(i32),
+
+    #[error(
+        "The ingredients with IDs of {0:?} no longer exist, so the revision cannot be restored"
+    )]
+    IngredientsMissing(Vec<Uuid>),
+
+    #[error(transparent)]
+    ValidationError(#[from] ValidationError),
+
+    #[error(transparent)]
+    Unknown(#[from] eyre::Error),
+}
+
+impl From<GetRecipeByIdError> for RevertRecipeError {
+    fn from(value: GetRecipeByIdError) -> Self {
+        match value {
+            GetRecipeByIdError::NotFound(id) => Self::NotFound(id),
+            GetRecipeByIdError::ValidationError(err) => err.into(),
+            err => Self::Unknown(err.into()),
+        }
+    }
+}
+
+impl From<GetRecipeRevisionsError> for RevertRecipeError {
+    fn from(value: GetRecipeRevisionsError) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+impl From<GetAllIngredientsError> for RevertRecipeError {
+    fn from(value: GetAllIngredientsError) -> Self {
+        match value {
+            GetAllIngredientsError::MultipleIngredientsMissing(ids) => {
+                Self::IngredientsMissing(ids)
+            }
+            err => Self::Unknown(err.into()),
+        }
+    }
+}
+
+impl From<RestoreRecipeError> for RevertRecipeError {
+    fn from(value: RestoreRecipeError) -> Self {
+        match value {
+            RestoreRecipeError::ValidationError(err) => err.into(),
+            RestoreRecipeError::Outdated(id) => Self::Conflict(id),
+            err => Self::Unknown(err.into()),
+        }
+    }
+}
+
+/// Brings the recipe back to how it was in the given revision, which is recorded as a new revision.
+/// Ingredients get their current names and descriptions, so ones deleted since then can't be restored.
+/// When expected versions are given, the recipe is only reverted if it's still in one of them.
+#[tracing::instrument("[COMMAND] Reverting a recipe", skip(recipe_repo, ingredient_repo))]
+pub async fn revert_recipe(
+    recipe_repo: RecipeRepositoryService,
+    ingredient_repo: IngredientRepositoryService,
+    user_id: &Uuid,
+    recipe_id: &Uuid,
+    revision: i32,
+    expected_versions: Option<&[String]>,
+) -> Result<Recipe, RevertRecipeError> {
+    let recipe = recipe_repo.get_by_id(recipe_id).await?;
+
+    if !recipe.can_be_modified_by(user_id) {
+        return Err(RevertRecipeError::Forbidden(recipe.id));
+    }
+
+    if expected_versions.is_some_and(|versions| !versions.contains(&recipe.version())) {
+        return Err(RevertRecipeError::Conflict(recipe.id));
+    }
+
+    let stored = recipe_repo.get_revisions(recipe_id).await?;
+    let snapshot = RecipeRevision::history(&recipe, stored)
+        .into_iter()
+        .find(|r| r.number == revision)
+        .ok_or(RevertRecipeError::RevisionNotFound(revision))?
+        .recipe;
+
+    let ingredient_ids: Vec<Uuid> = snapshot
+        .ingredients
+        .iter()
+        .map(|i| i.ingredient_id)
+        .collect();
+    let ingredients: HashMap<Uuid, _> = ingredient_repo
+        .get_all_by_id(&ingredient_ids)
+        .await?
+        .into_iter()
+        .map(|i| (i.id, i))
+        .collect();
+
+    let ingredients = snapshot
+        .ingredients
+        .into_iter()
+        .map(|i| {
+            let ingredient = ingredients
+                .get(&i.ingredient_id)
+                .cloned()
+                .ok_or(RevertRecipeError::IngredientsMissing(vec![i.ingredient_id]))?;
+
+            Ok(IngredientWithAmount {
+                ingredient,
+                amount: i.amount,
+                notes: i.notes,
+                optional: i.optional,
+            })
+        })
+        .collect::<Result<Vec<_>, RevertRecipeError>>()?;
+
+    let restored = Recipe {
+        name: snapshot.name,
+        description: snapshot.description,
+        steps: snapshot.steps.try_into()?,
+        ingredients: ingredients.try_into()?,
+        time: snapshot.time,
+        servings: snapshot.servings,
+        ..recipe.clone()
+    };
+
+    recipe_repo
+        .restore(&recipe, restored, revision, user_id)
+        .await?;
+
+    Ok(recipe_repo.get_by_id(recipe_id).await?)
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,228 @@
+use std::sync::Arc;
+
+use pretty_assertions::assert_eq;
+use uuid::Uuid;
+
+use crate::domain::commands::recipes::revert::{revert_recipe, RevertRecipeError};
+use crate::domain::commands::recipes::update::{update_recipe, UpdateRecipe};
+use crate::domain::entities::recipe::revisions::RevisionChange;
+use crate::domain::entities::recipe::Recipe;
+use crate::domain::repositories::ingredients::{IngredientRepository, IngredientRepositoryService};
+use crate::domain::repositories::recipe::{RecipeRepository, RecipeRepositoryService};
+use crate::domain::repositories::users::UserRepository;
+use crate::test_utils::{
+    insert_all_ingredients_of_recipe, owned_recipe_fixture, recipe_changeset, recipe_fixture,
+    user_fixture,
+};
+
+async fn setup(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    recipe: &Recipe,
+) -> (RecipeRepositoryService, IngredientRepositoryService) {
+    insert_all_ingredients_of_recipe(&ingredient_repo, recipe).await;
+    repo.insert(recipe.clone()).await.unwrap();
+
+    (
+        Arc::new(Box::new(repo)),
+        Arc::new(Box::new(ingredient_repo)),
+    )
+}
+
+pub async fn reverting_a_recipe_restores_its_contents(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let recipe = owned_recipe_fixture(&user_repo).await;
+    let user_id = user_fixture().id;
+    let (recipe_repo, ingredient_repo) = setup(repo, ingredient_repo, &recipe).await;
+
+    update_recipe(
+        recipe_repo.clone(),
+        &user_id,
+        &recipe.id,
+        recipe_changeset(),
+        None,
+    )
+    .await
+    .unwrap();
+    let updated = recipe_repo.get_by_id(&recipe.id).await.unwrap();
+    recipe_repo
+        .delete_ingredient(&updated, &updated.ingredients[0], &user_id)
+        .await
+        .unwrap();
+
+    let result = revert_recipe(
+        recipe_repo.clone(),
+        ingredient_repo,
+        &user_id,
+        &recipe.id,
+        1,
+        None,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(result.name, recipe.name);
+    assert_eq!(result.description, recipe.description);
+    assert_eq!(result.steps, recipe.steps);
+    assert_eq!(result.time, recipe.time);
+    assert_eq!(result.ingredients, recipe.ingredients);
+
+    let revisions = recipe_repo.get_revisions(&recipe.id).await.unwrap();
+    let changes: Vec<_> = revisions.iter().map(|r| r.change.clone()).collect();
+
+    assert_eq!(
+        changes,
+        vec![
+            RevisionChange::Created,
+            RevisionChange::Updated {
+                fields: vec![
+                    "name".to_string(),
+                    "description".to_string(),
+                    "steps".to_string(),
+                    "time".to_string()
+                ]
+            },
+            RevisionChange::IngredientRemoved {
+                ingredient_id: recipe.ingredients[0].ingredient.id
+            },
+            RevisionChange::Reverted { revision: 1 },
+        ]
+    );
+    assert_eq!(revisions[3].author_id, Some(user_id));
+    // Ingredients don't have a set order, so they're compared separately
+    let [mut restored, mut original] = [&revisions[3], &revisions[0]].map(|r| r.recipe.clone());
+    for snapshot in [&mut restored, &mut original] {
+        snapshot.ingredients.sort_by_key(|i| i.ingredient_id);
+    }
+    assert_eq!(restored, original);
+}
+
+pub async fn reverting_to_a_nonexistent_revision_fails(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let recipe = owned_recipe_fixture(&user_repo).await;
+    let (recipe_repo, ingredient_repo) = setup(repo, ingredient_repo, &recipe).await;
+
+    let result = revert_recipe(
+        recipe_repo,
+        ingredient_repo,
+        &user_fixture().id,
+        &recipe.id,
+        2,
+        None,
+    )
+    .await
+    .unwrap_err();
+
+    assert!(matches!(result, RevertRecipeError::RevisionNotFound(2)));
+}
+
+pub async fn reverting_someone_elses_recipe_is_forbidden(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let owner = user_repo.insert(user_fixture()).await.unwrap();
+    let recipe = Recipe {
+        owner_id: Some(owner.id),
+        ..recipe_fixture()
+    };
+    let (recipe_repo, ingredient_repo) = setup(repo, ingredient_repo, &recipe).await;
+
+    let result = revert_recipe(
+        recipe_repo,
+        ingredient_repo,
+        &Uuid::from_u128(0xdead),
+        &recipe.id,
+        1,
+        None,
+    )
+    .await
+    .unwrap_err();
+
+    assert!(matches!(result, RevertRecipeError::Forbidden(id) if id == recipe.id));
+}
+
+pub async fn reverting_to_a_revision_with_deleted_ingredients_fails(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let recipe = owned_recipe_fixture(&user_repo).await;
+    let user_id = user_fixture().id;
+    let (recipe_repo, ingredient_repo) = setup(repo, ingredient_repo, &recipe).await;
+
+    let recipe = recipe_repo.get_by_id(&recipe.id).await.unwrap();
+    let removed = recipe.ingredients[0].clone();
+    recipe_repo
+        .delete_ingredient(&recipe, &removed, &user_id)
+        .await
+        .unwrap();
+    ingredient_repo
+        .delete(removed.ingredient.clone())
+        .await
+        .unwrap();
+
+    let result = revert_recipe(recipe_repo, ingredient_repo, &user_id, &recipe.id, 1, None)
+        .await
+        .unwrap_err();
+
+    assert!(
+        matches!(result, RevertRecipeError::IngredientsMissing(ids) if ids == vec![removed.ingredient.id])
+    );
+}
+
+pub async fn reverting_an_outdated_version_of_a_recipe_conflicts(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let recipe = owned_recipe_fixture(&user_repo).await;
+    let user_id = user_fixture().id;
+    let (recipe_repo, ingredient_repo) = setup(repo, ingredient_repo, &recipe).await;
+
+    update_recipe(
+        recipe_repo.clone(),
+        &user_id,
+        &recipe.id,
+        recipe_changeset(),
+        None,
+    )
+    .await
+    .unwrap();
+    let version = recipe_repo.get_by_id(&recipe.id).await.unwrap().version();
+
+    update_recipe(
+        recipe_repo.clone(),
+        &user_id,
+        &recipe.id,
+        UpdateRecipe {
+            name: Some("Someone else's change".to_string()),
+            ..Default::default()
+        },
+        None,
+    )
+    .await
+    .unwrap();
+
+    let result = revert_recipe(
+        recipe_repo.clone(),
+        ingredient_repo,
+        &user_id,
+        &recipe.id,
+        1,
+        Some(std::slice::from_ref(&version)),
+    )
+    .await
+    .unwrap_err();
+
+    assert!(matches!(result, RevertRecipeError::Conflict(id) if id == recipe.id));
+
+    let unchanged = recipe_repo.get_by_id(&recipe.id).await.unwrap();
+    assert_eq!(unchanged.name, "Someone else's change");
+}
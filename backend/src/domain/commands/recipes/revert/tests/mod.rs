@@ -0,0 +1,121 @@
+mod __tests__;
+mod in_memory {
+    use crate::domain::repositories::{
+        ingredients::in_memory::InMemoryIngredientRepository,
+        recipe::in_memory::InMemoryRecipeRepository, users::in_memory::InMemoryUserRepository,
+    };
+
+    use super::__tests__;
+
+    #[tokio::test]
+    async fn reverting_a_recipe_restores_its_contents() {
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let repo = InMemoryRecipeRepository::new();
+        let user_repo = InMemoryUserRepository::new();
+        __tests__::reverting_a_recipe_restores_its_contents(repo, ingredient_repo, user_repo).await
+    }
+
+    #[tokio::test]
+    async fn reverting_to_a_nonexistent_revision_fails() {
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let repo = InMemoryRecipeRepository::new();
+        let user_repo = InMemoryUserRepository::new();
+        __tests__::reverting_to_a_nonexistent_revision_fails(repo, ingredient_repo, user_repo).await
+    }
+
+    #[tokio::test]
+    async fn reverting_someone_elses_recipe_is_forbidden() {
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let user_repo = InMemoryUserRepository::new();
+        let repo = InMemoryRecipeRepository::new();
+        __tests__::reverting_someone_elses_recipe_is_forbidden(repo, ingredient_repo, user_repo)
+            .await
+    }
+
+    #[tokio::test]
+    async fn reverting_to_a_revision_with_deleted_ingredients_fails() {
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let repo = InMemoryRecipeRepository::new();
+        let user_repo = InMemoryUserRepository::new();
+        __tests__::reverting_to_a_revision_with_deleted_ingredients_fails(
+            repo,
+            ingredient_repo,
+            user_repo,
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn reverting_an_outdated_version_of_a_recipe_conflicts() {
+        let recipe_repo = InMemoryRecipeRepository::new();
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let user_repo = InMemoryUserRepository::new();
+        __tests__::reverting_an_outdated_version_of_a_recipe_conflicts(
+            recipe_repo,
+            ingredient_repo,
+            user_repo,
+        )
+        .await
+    }
+}
+
+mod sql {
+    use super::__tests__;
+    use sqlx::PgPool;
+
+    use crate::domain::repositories::{
+        ingredients::postgres::PostgresIngredientRepository,
+        recipe::postgres::PostgresRecipeRepository, users::postgres::PostgresUserRepository,
+    };
+
+    #[sqlx::test]
+    async fn reverting_a_recipe_restores_its_contents(pool: PgPool) {
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let repo = PostgresRecipeRepository::new(pool.clone());
+        let user_repo = PostgresUserRepository::new(pool);
+        __tests__::reverting_a_recipe_restores_its_contents(repo, ingredient_repo, user_repo).await
+    }
+
+    #[sqlx::test]
+    async fn reverting_to_a_nonexistent_revision_fails(pool: PgPool) {
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let repo = PostgresRecipeRepository::new(pool.clone());
+        let user_repo = PostgresUserRepository::new(pool);
+        __tests__::reverting_to_a_nonexistent_revision_fails(repo, ingredient_repo, user_repo).await
+    }
+
+    #[sqlx::test]
+    async fn reverting_someone_elses_recipe_is_forbidden(pool: PgPool) {
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let user_repo = PostgresUserRepository::new(pool.clone());
+        let repo = PostgresRecipeRepository::new(pool);
+        __tests__::reverting_someone_elses_recipe_is_forbidden(repo, ingredient_repo, user_repo)
+            .await
+    }
+
+    #[sqlx::test]
+    async fn reverting_to_a_revision_with_deleted_ingredients_fails(pool: PgPool) {
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let repo = PostgresRecipeRepository::new(pool.clone());
+        let user_repo = PostgresUserRepository::new(pool);
+        __tests__::reverting_to_a_revision_with_deleted_ingredients_fails(
+            repo,
+            ingredient_repo,
+            user_repo,
+        )
+        .await
+    }
+
+    #[sqlx::test]
+    async fn reverting_an_outdated_version_of_a_recipe_conflicts(pool: PgPool) {
+        let recipe_repo = PostgresRecipeRepository::new(pool.clone());
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let user_repo = PostgresUserRepository::new(pool);
+        __tests__::reverting_an_outdated_version_of_a_recipe_conflicts(
+            recipe_repo,
+            ingredient_repo,
+            user_repo,
+        )
+        .await
+    }
+}
@@ -18,6 +18,12 @@ pub enum UpdateRecipeError {
     #[error("Could not find the ingredient with ID {0}")]
     NotFound(Uuid),
 
+    #[error("You are not allowed to modify the recipe with ID {0}")]
+    Forbidden(Uuid),
+
+    #[error("The recipe with ID {0} has been changed since you last fetched it")]
+    Conflict(Uuid),
+
     #[error(transparent)]
     ValidationError(#[from] ValidationError),
 
@@ -27,7 +33,10 @@ pub enum UpdateRecipeError {
 
 impl From<UpdateRecipeErrorInternal> for UpdateRecipeError {
     fn from(value: UpdateRecipeErrorInternal) -> Self {
-        Self::Unknown(value.into())
+        match value {
+            UpdateRecipeErrorInternal::Outdated(id) => Self::Conflict(id),
+            e => Self::Unknown(e.into()),
+        }
     }
 }
 
@@ -83,10 +92,13 @@ impl From<UpdateRecipeDTO> for UpdateRecipe {
     }
 }
 
+/// When expected versions are given, the recipe is only updated if it's still in one of them.
 pub async fn update_recipe(
     recipe_repo: RecipeRepositoryService,
+    user_id: &Uuid,
     input: &Uuid,
     update: UpdateRecipe,
+    expected_versions: Option<&[String]>,
 ) -> Result<Recipe, UpdateRecipeError> {
     let changeset: RecipeChangeset = update.try_into()?;
     if changeset.is_empty() {
@@ -95,7 +107,15 @@ pub async fn update_recipe(
 
     let recipe = recipe_repo.get_by_id(input).await?;
 
-    recipe_repo.update(&recipe, changeset).await?;
+    if !recipe.can_be_modified_by(user_id) {
+        return Err(UpdateRecipeError::Forbidden(recipe.id));
+    }
+
+    if expected_versions.is_some_and(|versions| !versions.contains(&recipe.version())) {
+        return Err(UpdateRecipeError::Conflict(recipe.id));
+    }
+
+    recipe_repo.update(&recipe, changeset, user_id).await?;
 
     let recipe = recipe_repo
         .get_by_id(input)
@@ -3,27 +3,35 @@ use std::sync::Arc;
 use std::time::Duration;
 
 use chrono::Utc;
+use common::events::Event;
 use pretty_assertions::assert_eq;
+use uuid::Uuid;
 
 use crate::domain::commands::recipes::update::{update_recipe, UpdateRecipe, UpdateRecipeError};
 use crate::domain::entities::recipe::{Recipe, ServingsType};
 use crate::domain::repositories::ingredients::IngredientRepository;
+use crate::domain::repositories::outbox::OutboxRepository;
 
 use crate::domain::repositories::recipe::{RecipeRepository, RecipeRepositoryService};
-use crate::test_utils::{insert_all_ingredients_of_recipe, recipe_changeset, recipe_fixture};
+use crate::domain::repositories::users::UserRepository;
+use crate::test_utils::{
+    insert_all_ingredients_of_recipe, owned_recipe_fixture, pending_events, recipe_changeset,
+    recipe_fixture, user_fixture,
+};
 
 pub async fn updating_a_recipe_succeeds(
     repo: impl RecipeRepository,
     ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
 ) {
     let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(repo));
-    let recipe = recipe_fixture();
+    let recipe = owned_recipe_fixture(&user_repo).await;
     let changeset = recipe_changeset();
     insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
 
     recipe_repo.insert(recipe.clone()).await.unwrap();
 
-    let result = update_recipe(recipe_repo, &recipe.id, changeset)
+    let result = update_recipe(recipe_repo, &user_fixture().id, &recipe.id, changeset, None)
         .await
         .unwrap();
 
@@ -63,7 +71,7 @@ pub async fn updating_a_nonexistent_recipe_fails(repo: impl RecipeRepository) {
         ..Default::default()
     };
 
-    let result = update_recipe(recipe_repo, &recipe.id, changeset)
+    let result = update_recipe(recipe_repo, &user_fixture().id, &recipe.id, changeset, None)
         .await
         .unwrap_err();
 
@@ -83,9 +91,118 @@ pub async fn updating_a_recipe_with_empty_changeset_errors(
     insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
     recipe_repo.insert(recipe.clone()).await.unwrap();
 
-    let result = update_recipe(recipe_repo, &recipe.id, changeset)
+    let result = update_recipe(recipe_repo, &user_fixture().id, &recipe.id, changeset, None)
         .await
         .unwrap_err();
 
     assert!(matches!(result, UpdateRecipeError::ChangesetEmpty))
 }
+
+pub async fn updating_someone_elses_recipe_is_forbidden(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let owner = user_repo.insert(user_fixture()).await.unwrap();
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(repo));
+    let recipe = Recipe {
+        owner_id: Some(owner.id),
+        ..recipe_fixture()
+    };
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
+
+    recipe_repo.insert(recipe.clone()).await.unwrap();
+
+    let result = update_recipe(
+        recipe_repo.clone(),
+        &Uuid::from_u128(0xdead),
+        &recipe.id,
+        recipe_changeset(),
+        None,
+    )
+    .await
+    .unwrap_err();
+
+    assert!(matches!(result, UpdateRecipeError::Forbidden(id) if id == recipe.id));
+
+    let unchanged = recipe_repo.get_by_id(&recipe.id).await.unwrap();
+    assert_eq!(unchanged.name, recipe.name);
+}
+
+pub async fn updating_an_outdated_version_of_a_recipe_conflicts(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(repo));
+    let recipe = owned_recipe_fixture(&user_repo).await;
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
+
+    recipe_repo.insert(recipe.clone()).await.unwrap();
+    let recipe = recipe_repo.get_by_id(&recipe.id).await.unwrap();
+    let version = recipe.version();
+
+    update_recipe(
+        recipe_repo.clone(),
+        &user_fixture().id,
+        &recipe.id,
+        recipe_changeset(),
+        Some(std::slice::from_ref(&version)),
+    )
+    .await
+    .unwrap();
+
+    let result = update_recipe(
+        recipe_repo.clone(),
+        &user_fixture().id,
+        &recipe.id,
+        UpdateRecipe {
+            name: Some("Someone else's change".to_string()),
+            ..Default::default()
+        },
+        Some(std::slice::from_ref(&version)),
+    )
+    .await
+    .unwrap_err();
+
+    assert!(matches!(result, UpdateRecipeError::Conflict(id) if id == recipe.id));
+
+    let unchanged = recipe_repo.get_by_id(&recipe.id).await.unwrap();
+    assert_eq!(unchanged.name, "WE UPDATED THIS THING");
+}
+
+pub async fn updating_a_recipe_writes_a_message_to_the_outbox(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+    outbox: impl OutboxRepository,
+) {
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(repo));
+    let recipe = owned_recipe_fixture(&user_repo).await;
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
+
+    recipe_repo.insert(recipe.clone()).await.unwrap();
+
+    update_recipe(
+        recipe_repo,
+        &user_fixture().id,
+        &recipe.id,
+        recipe_changeset(),
+        None,
+    )
+    .await
+    .unwrap();
+
+    let change = pending_events(&outbox)
+        .await
+        .into_iter()
+        .find_map(|event| match event {
+            Event::RecipeUpdated(change) => Some(change),
+            _ => None,
+        })
+        .unwrap();
+
+    assert_eq!(change.old.id, recipe.id.to_string());
+    assert_eq!(change.old.name, recipe.name);
+    assert_eq!(change.new.name, "WE UPDATED THIS THING");
+}
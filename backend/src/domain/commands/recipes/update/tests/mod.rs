@@ -2,7 +2,8 @@ mod __tests__;
 mod in_memory {
     use crate::domain::repositories::{
         ingredients::in_memory::InMemoryIngredientRepository,
-        recipe::in_memory::InMemoryRecipeRepository,
+        outbox::in_memory::InMemoryOutboxRepository, recipe::in_memory::InMemoryRecipeRepository,
+        users::in_memory::InMemoryUserRepository,
     };
 
     use super::__tests__;
@@ -11,7 +12,8 @@ mod in_memory {
     async fn updating_a_recipe_succeeds() {
         let ingredient_repo = InMemoryIngredientRepository::new();
         let repo = InMemoryRecipeRepository::new();
-        __tests__::updating_a_recipe_succeeds(repo, ingredient_repo).await
+        let user_repo = InMemoryUserRepository::new();
+        __tests__::updating_a_recipe_succeeds(repo, ingredient_repo, user_repo).await
     }
 
     #[tokio::test]
@@ -26,6 +28,43 @@ mod in_memory {
         let repo = InMemoryRecipeRepository::new();
         __tests__::updating_a_recipe_with_empty_changeset_errors(repo, ingredient_repo).await
     }
+
+    #[tokio::test]
+    async fn updating_someone_elses_recipe_is_forbidden() {
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let user_repo = InMemoryUserRepository::new();
+        let repo = InMemoryRecipeRepository::new();
+        __tests__::updating_someone_elses_recipe_is_forbidden(repo, ingredient_repo, user_repo)
+            .await
+    }
+
+    #[tokio::test]
+    async fn updating_an_outdated_version_of_a_recipe_conflicts() {
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let repo = InMemoryRecipeRepository::new();
+        let user_repo = InMemoryUserRepository::new();
+        __tests__::updating_an_outdated_version_of_a_recipe_conflicts(
+            repo,
+            ingredient_repo,
+            user_repo,
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn updating_a_recipe_writes_a_message_to_the_outbox() {
+        let outbox = InMemoryOutboxRepository::new();
+        let repo = InMemoryRecipeRepository::new().with_outbox(outbox.clone());
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let user_repo = InMemoryUserRepository::new();
+        __tests__::updating_a_recipe_writes_a_message_to_the_outbox(
+            repo,
+            ingredient_repo,
+            user_repo,
+            outbox,
+        )
+        .await
+    }
 }
 
 mod sql {
@@ -34,14 +73,16 @@ mod sql {
 
     use crate::domain::repositories::{
         ingredients::postgres::PostgresIngredientRepository,
-        recipe::postgres::PostgresRecipeRepository,
+        outbox::postgres::PostgresOutboxRepository, recipe::postgres::PostgresRecipeRepository,
+        users::postgres::PostgresUserRepository,
     };
 
     #[sqlx::test]
     async fn updating_a_recipe_succeeds(pool: PgPool) {
         let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
-        let repo = PostgresRecipeRepository::new(pool);
-        __tests__::updating_a_recipe_succeeds(repo, ingredient_repo).await
+        let repo = PostgresRecipeRepository::new(pool.clone());
+        let user_repo = PostgresUserRepository::new(pool);
+        __tests__::updating_a_recipe_succeeds(repo, ingredient_repo, user_repo).await
     }
 
     #[sqlx::test]
@@ -56,4 +97,41 @@ mod sql {
         let repo = PostgresRecipeRepository::new(pool);
         __tests__::updating_a_recipe_with_empty_changeset_errors(repo, ingredient_repo).await
     }
+
+    #[sqlx::test]
+    async fn updating_someone_elses_recipe_is_forbidden(pool: PgPool) {
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let user_repo = PostgresUserRepository::new(pool.clone());
+        let repo = PostgresRecipeRepository::new(pool);
+        __tests__::updating_someone_elses_recipe_is_forbidden(repo, ingredient_repo, user_repo)
+            .await
+    }
+
+    #[sqlx::test]
+    async fn updating_an_outdated_version_of_a_recipe_conflicts(pool: PgPool) {
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let repo = PostgresRecipeRepository::new(pool.clone());
+        let user_repo = PostgresUserRepository::new(pool);
+        __tests__::updating_an_outdated_version_of_a_recipe_conflicts(
+            repo,
+            ingredient_repo,
+            user_repo,
+        )
+        .await
+    }
+
+    #[sqlx::test]
+    async fn updating_a_recipe_writes_a_message_to_the_outbox(pool: PgPool) {
+        let repo = PostgresRecipeRepository::new(pool.clone());
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let user_repo = PostgresUserRepository::new(pool.clone());
+        let outbox = PostgresOutboxRepository::new(pool);
+        __tests__::updating_a_recipe_writes_a_message_to_the_outbox(
+            repo,
+            ingredient_repo,
+            user_repo,
+            outbox,
+        )
+        .await
+    }
 }
@@ -0,0 +1,67 @@
+use uuid::Uuid;
+
+use crate::domain::entities::user::{errors::ValidationError, types::PasswordHash, User};
+use crate::domain::repositories::users::{errors::InsertUserError, UserRepositoryService};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum CreateUserError {
+    #[error("The field {0} was empty")]
+    EmptyField(&'static str),
+    #[error("The field {0} is not formatted correctly")]
+    InvalidFormat(&'static str),
+    #[error("A conflict has occured - a user with field {0} of the given value already exists.")]
+    Conflict(String),
+    #[error(transparent)]
+    Internal(#[from] eyre::Error),
+}
+
+impl From<InsertUserError> for CreateUserError {
+    fn from(value: InsertUserError) -> Self {
+        match value {
+            InsertUserError::Conflict(field) => Self::Conflict(field),
+            e => Self::Internal(e.into()),
+        }
+    }
+}
+
+impl From<ValidationError> for CreateUserError {
+    fn from(value: ValidationError) -> Self {
+        match value {
+            ValidationError::EmptyField(field) => Self::EmptyField(field[0]),
+            ValidationError::InvalidFormat(field) => Self::InvalidFormat(field),
+            e => Self::Internal(e.into()),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct CreateUser<'a> {
+    pub username: &'a str,
+    pub email: &'a str,
+    pub password: &'a str,
+}
+
+impl<'a> TryFrom<&CreateUser<'a>> for User {
+    type Error = ValidationError;
+    fn try_from(value: &CreateUser<'a>) -> Result<Self, Self::Error> {
+        Ok(User {
+            id: Uuid::now_v7(),
+            username: value.username.try_into()?,
+            email: value.email.try_into()?,
+            password: PasswordHash::from_password(value.password)?,
+        })
+    }
+}
+
+#[tracing::instrument("[COMMAND] Creating a new user", skip(repo, input))]
+pub async fn create_user(
+    repo: UserRepositoryService,
+    input: &CreateUser<'_>,
+) -> Result<User, CreateUserError> {
+    let user = User::try_from(input)?;
+    let user = repo.insert(user).await?;
+    Ok(user)
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,125 @@
+use std::sync::Arc;
+
+use crate::domain::{
+    commands::users::create::{create_user, CreateUser, CreateUserError},
+    repositories::users::{UserRepository, UserRepositoryService},
+};
+
+pub async fn creates_a_user(repo: impl UserRepository) {
+    let given = CreateUser {
+        username: "testuser",
+        email: "testuser@example.com",
+        password: "meaningless",
+    };
+
+    let repo: UserRepositoryService = Arc::new(Box::new(repo));
+
+    let when = create_user(repo.clone(), &given).await.unwrap();
+
+    // THEN
+
+    assert_eq!(when.username.as_str(), "testuser");
+    assert_eq!(when.email.as_str(), "testuser@example.com");
+    assert!(when.password.verify("meaningless"));
+
+    let persisted = repo.get_by_id(&when.id).await.unwrap();
+    assert_eq!(persisted, when);
+}
+
+pub async fn password_is_not_stored_in_plaintext(repo: impl UserRepository) {
+    let given = CreateUser {
+        username: "testuser",
+        email: "testuser@example.com",
+        password: "meaningless",
+    };
+
+    let repo: UserRepositoryService = Arc::new(Box::new(repo));
+
+    let when = create_user(repo, &given).await.unwrap();
+
+    // THEN
+
+    assert!(!when.password.as_phc_string().contains("meaningless"));
+    assert!(!when.password.verify("meaningful"));
+}
+
+pub async fn empty_username_fails(repo: impl UserRepository) {
+    let given = CreateUser {
+        username: "",
+        email: "testuser@example.com",
+        password: "meaningless",
+    };
+
+    let repo: UserRepositoryService = Arc::new(Box::new(repo));
+
+    let when = create_user(repo, &given).await.unwrap_err();
+
+    // THEN
+
+    assert!(matches!(when, CreateUserError::EmptyField("username")));
+}
+
+pub async fn malformed_email_fails(repo: impl UserRepository) {
+    let given = CreateUser {
+        username: "testuser",
+        email: "not an email",
+        password: "meaningless",
+    };
+
+    let repo: UserRepositoryService = Arc::new(Box::new(repo));
+
+    let when = create_user(repo, &given).await.unwrap_err();
+
+    // THEN
+
+    assert!(matches!(when, CreateUserError::InvalidFormat("email")));
+}
+
+pub async fn empty_password_fails(repo: impl UserRepository) {
+    let given = CreateUser {
+        username: "testuser",
+        email: "testuser@example.com",
+        password: "",
+    };
+
+    let repo: UserRepositoryService = Arc::new(Box::new(repo));
+
+    let when = create_user(repo, &given).await.unwrap_err();
+
+    // THEN
+
+    assert!(matches!(when, CreateUserError::EmptyField("password")));
+}
+
+pub async fn creating_a_user_with_a_taken_username_fails(repo: impl UserRepository) {
+    let repo: UserRepositoryService = Arc::new(Box::new(repo));
+
+    create_user(
+        repo.clone(),
+        &CreateUser {
+            username: "testuser",
+            email: "testuser@example.com",
+            password: "meaningless",
+        },
+    )
+    .await
+    .unwrap();
+
+    let when = create_user(
+        repo,
+        &CreateUser {
+            username: "testuser",
+            email: "someoneelse@example.com",
+            password: "meaningless",
+        },
+    )
+    .await
+    .unwrap_err();
+
+    // THEN
+
+    assert!(matches!(
+        when,
+        CreateUserError::Conflict(fieldname) if fieldname == "username"
+    ));
+}
@@ -0,0 +1,74 @@
+mod __tests__;
+
+mod in_memory {
+    use super::__tests__;
+    use crate::domain::repositories::users::in_memory::InMemoryUserRepository;
+
+    #[tokio::test]
+    async fn creates_a_user() {
+        __tests__::creates_a_user(InMemoryUserRepository::new()).await;
+    }
+
+    #[tokio::test]
+    async fn password_is_not_stored_in_plaintext() {
+        __tests__::password_is_not_stored_in_plaintext(InMemoryUserRepository::new()).await;
+    }
+
+    #[tokio::test]
+    async fn empty_username_fails() {
+        __tests__::empty_username_fails(InMemoryUserRepository::new()).await;
+    }
+
+    #[tokio::test]
+    async fn malformed_email_fails() {
+        __tests__::malformed_email_fails(InMemoryUserRepository::new()).await;
+    }
+
+    #[tokio::test]
+    async fn empty_password_fails() {
+        __tests__::empty_password_fails(InMemoryUserRepository::new()).await;
+    }
+
+    #[tokio::test]
+    async fn creating_a_user_with_a_taken_username_fails() {
+        __tests__::creating_a_user_with_a_taken_username_fails(InMemoryUserRepository::new()).await;
+    }
+}
+
+mod sql {
+    use sqlx::PgPool;
+
+    use super::__tests__;
+    use crate::domain::repositories::users::postgres::PostgresUserRepository;
+
+    #[sqlx::test]
+    async fn creates_a_user(pool: PgPool) {
+        __tests__::creates_a_user(PostgresUserRepository::new(pool)).await;
+    }
+
+    #[sqlx::test]
+    async fn password_is_not_stored_in_plaintext(pool: PgPool) {
+        __tests__::password_is_not_stored_in_plaintext(PostgresUserRepository::new(pool)).await;
+    }
+
+    #[sqlx::test]
+    async fn empty_username_fails(pool: PgPool) {
+        __tests__::empty_username_fails(PostgresUserRepository::new(pool)).await;
+    }
+
+    #[sqlx::test]
+    async fn malformed_email_fails(pool: PgPool) {
+        __tests__::malformed_email_fails(PostgresUserRepository::new(pool)).await;
+    }
+
+    #[sqlx::test]
+    async fn empty_password_fails(pool: PgPool) {
+        __tests__::empty_password_fails(PostgresUserRepository::new(pool)).await;
+    }
+
+    #[sqlx::test]
+    async fn creating_a_user_with_a_taken_username_fails(pool: PgPool) {
+        __tests__::creating_a_user_with_a_taken_username_fails(PostgresUserRepository::new(pool))
+            .await;
+    }
+}
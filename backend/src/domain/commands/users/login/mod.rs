@@ -0,0 +1,44 @@
+use crate::domain::entities::user::User;
+use crate::domain::repositories::users::{errors::GetUserByUsernameError, UserRepositoryService};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum LoginUserError {
+    #[error("Could not find person")]
+    NotFound(String),
+    #[error("Wrong password")]
+    WrongPassword,
+    #[error(transparent)]
+    Internal(#[from] eyre::Error),
+}
+
+impl From<GetUserByUsernameError> for LoginUserError {
+    fn from(value: GetUserByUsernameError) -> Self {
+        match value {
+            GetUserByUsernameError::NotFound(username) => Self::NotFound(username),
+            e => Self::Internal(e.into()),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct LoginUser<'a> {
+    pub username: &'a str,
+    pub password: &'a str,
+}
+
+#[tracing::instrument("[COMMAND] Logging a user in", skip(repo, input), fields(username = input.username))]
+pub async fn login_user(
+    repo: UserRepositoryService,
+    input: &LoginUser<'_>,
+) -> Result<User, LoginUserError> {
+    let user = repo.get_by_username(input.username).await?;
+
+    if !user.password.verify(input.password) {
+        return Err(LoginUserError::WrongPassword);
+    }
+
+    Ok(user)
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,69 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::domain::{
+    commands::users::login::{login_user, LoginUser, LoginUserError},
+    entities::user::{types::PasswordHash, User},
+    repositories::users::{UserRepository, UserRepositoryService},
+};
+
+fn user_fixture() -> User {
+    User {
+        id: Uuid::from_u128(1),
+        username: "testuser".try_into().unwrap(),
+        email: "testuser@example.com".try_into().unwrap(),
+        password: PasswordHash::from_password("meaningless").unwrap(),
+    }
+}
+
+pub async fn logs_in_with_correct_password(repo: impl UserRepository) {
+    let user = repo.insert(user_fixture()).await.unwrap();
+    let repo: UserRepositoryService = Arc::new(Box::new(repo));
+
+    let when = login_user(
+        repo,
+        &LoginUser {
+            username: "testuser",
+            password: "meaningless",
+        },
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(when, user);
+}
+
+pub async fn wrong_password_fails(repo: impl UserRepository) {
+    repo.insert(user_fixture()).await.unwrap();
+    let repo: UserRepositoryService = Arc::new(Box::new(repo));
+
+    let when = login_user(
+        repo,
+        &LoginUser {
+            username: "testuser",
+            password: "meaningful",
+        },
+    )
+    .await
+    .unwrap_err();
+
+    assert!(matches!(when, LoginUserError::WrongPassword));
+    assert_eq!(when.to_string(), "Wrong password");
+}
+
+pub async fn missing_user_fails(repo: impl UserRepository) {
+    let repo: UserRepositoryService = Arc::new(Box::new(repo));
+
+    let when = login_user(
+        repo,
+        &LoginUser {
+            username: "idontexistyet",
+            password: "meaningless",
+        },
+    )
+    .await
+    .unwrap_err();
+
+    assert!(matches!(when, LoginUserError::NotFound(username) if username == "idontexistyet"));
+}
@@ -0,0 +1,43 @@
+mod __tests__;
+
+mod in_memory {
+    use super::__tests__;
+    use crate::domain::repositories::users::in_memory::InMemoryUserRepository;
+
+    #[tokio::test]
+    async fn logs_in_with_correct_password() {
+        __tests__::logs_in_with_correct_password(InMemoryUserRepository::new()).await;
+    }
+
+    #[tokio::test]
+    async fn wrong_password_fails() {
+        __tests__::wrong_password_fails(InMemoryUserRepository::new()).await;
+    }
+
+    #[tokio::test]
+    async fn missing_user_fails() {
+        __tests__::missing_user_fails(InMemoryUserRepository::new()).await;
+    }
+}
+
+mod sql {
+    use sqlx::PgPool;
+
+    use super::__tests__;
+    use crate::domain::repositories::users::postgres::PostgresUserRepository;
+
+    #[sqlx::test]
+    async fn logs_in_with_correct_password(pool: PgPool) {
+        __tests__::logs_in_with_correct_password(PostgresUserRepository::new(pool)).await;
+    }
+
+    #[sqlx::test]
+    async fn wrong_password_fails(pool: PgPool) {
+        __tests__::wrong_password_fails(PostgresUserRepository::new(pool)).await;
+    }
+
+    #[sqlx::test]
+    async fn missing_user_fails(pool: PgPool) {
+        __tests__::missing_user_fails(PostgresUserRepository::new(pool)).await;
+    }
+}
@@ -0,0 +1,145 @@
+use common::CreateIngredientDTO;
+use serde::{Deserialize, Serialize};
+use strum::{EnumString, VariantNames};
+use uuid::Uuid;
+
+use super::Ingredient;
+
+/// Separates the values of the list columns of a CSV file, e.g. `vegan;vegetarian`.
+const LIST_SEPARATOR: char = ';';
+
+fn split_column(value: &str) -> Vec<String> {
+    value
+        .split(LIST_SEPARATOR)
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum IngredientFormatError {
+    #[error("The document is not a valid JSON array of ingredients: {0}")]
+    InvalidJson(#[from] serde_json::Error),
+
+    #[error("The document is not a valid CSV file of ingredients: {0}")]
+    InvalidCsv(#[from] csv::Error),
+
+    #[error(transparent)]
+    Unknown(#[from] eyre::Error),
+}
+
+/// Formats a list of ingredients can be imported from, and exported into.
+/// Both have the fields of [`CreateIngredientDTO`], so an export can be imported somewhere else.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumString, VariantNames)]
+#[strum(serialize_all = "snake_case")]
+pub enum IngredientFormat {
+    Csv,
+    Json,
+}
+
+/// A single line of a CSV file, which can't hold a list in a single column.
+#[derive(Debug, Serialize, Deserialize)]
+struct CsvRow {
+    name: String,
+    description: String,
+    #[serde(default)]
+    diet_violations: String,
+    #[serde(default)]
+    parent_id: Option<Uuid>,
+    #[serde(default)]
+    allergens: String,
+}
+
+impl From<CsvRow> for CreateIngredientDTO {
+    fn from(value: CsvRow) -> Self {
+        Self {
+            name: value.name,
+            description: value.description,
+            diet_violations: Some(split_column(&value.diet_violations)),
+            parent_id: value.parent_id,
+            allergens: Some(split_column(&value.allergens)),
+        }
+    }
+}
+
+impl From<&Ingredient> for CreateIngredientDTO {
+    fn from(value: &Ingredient) -> Self {
+        Self {
+            name: value.name.to_string(),
+            description: value.description.to_string(),
+            diet_violations: Some(value.diet_violations.clone().into()),
+            parent_id: value.parent_id,
+            allergens: Some(value.allergens.clone().into()),
+        }
+    }
+}
+
+impl IngredientFormat {
+    /// CSV files are recognized by their content type, anything else is taken to be JSON.
+    pub fn from_content_type(content_type: &str) -> Self {
+        match content_type.split(';').next().map(str::trim) {
+            Some("text/csv") => Self::Csv,
+            _ => Self::Json,
+        }
+    }
+
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            Self::Csv => "text/csv; charset=utf-8",
+            Self::Json => "application/json",
+        }
+    }
+
+    /// Reads the ingredients in the order they're written down.
+    /// CSV files need a header, the `diet_violations`, `parent_id` and `allergens` columns can be
+    /// left out.
+    pub fn parse(&self, input: &str) -> Result<Vec<CreateIngredientDTO>, IngredientFormatError> {
+        let ingredients = match self {
+            Self::Json => serde_json::from_str(input)?,
+            Self::Csv => csv::ReaderBuilder::new()
+                .trim(csv::Trim::All)
+                .from_reader(input.as_bytes())
+                .deserialize::<CsvRow>()
+                .map(|row| row.map(Into::into))
+                .collect::<Result<_, _>>()?,
+        };
+
+        Ok(ingredients)
+    }
+
+    pub fn render(&self, ingredients: &[Ingredient]) -> Result<String, IngredientFormatError> {
+        let ingredients = ingredients.iter().map(CreateIngredientDTO::from);
+
+        match self {
+            Self::Json => Ok(serde_json::to_string(&ingredients.collect::<Vec<_>>())?),
+            Self::Csv => {
+                let mut writer = csv::Writer::from_writer(vec![]);
+
+                for ingredient in ingredients {
+                    writer.serialize(CsvRow {
+                        name: ingredient.name,
+                        description: ingredient.description,
+                        diet_violations: ingredient
+                            .diet_violations
+                            .unwrap_or_default()
+                            .join(&LIST_SEPARATOR.to_string()),
+                        parent_id: ingredient.parent_id,
+                        allergens: ingredient
+                            .allergens
+                            .unwrap_or_default()
+                            .join(&LIST_SEPARATOR.to_string()),
+                    })?;
+                }
+
+                let bytes = writer
+                    .into_inner()
+                    .map_err(|e| eyre::eyre!(e.to_string()))?;
+                Ok(String::from_utf8(bytes).map_err(|e| eyre::eyre!(e))?)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,116 @@
+use common::CreateIngredientDTO;
+use pretty_assertions::assert_eq;
+use uuid::Uuid;
+
+use crate::test_utils::ingredient_fixture;
+
+use super::{IngredientFormat, IngredientFormatError};
+
+#[test]
+fn parsing_csv_works() {
+    let input = "name,description,diet_violations,parent_id\n\
+        Beef,You killed a cow for it,gluten_free,\n\
+        Dark soy sauce, Darker than the usual one ,vegan;vegetarian,00000000-0000-0000-0000-000000000040\n";
+
+    let ingredients = IngredientFormat::Csv.parse(input).unwrap();
+
+    assert_eq!(ingredients.len(), 2);
+    assert_eq!(ingredients[0].name, "Beef");
+    assert_eq!(
+        ingredients[0].diet_violations,
+        Some(vec!["gluten_free".to_string()])
+    );
+    assert_eq!(ingredients[0].parent_id, None);
+    assert_eq!(ingredients[1].description, "Darker than the usual one");
+    assert_eq!(
+        ingredients[1].diet_violations,
+        Some(vec!["vegan".to_string(), "vegetarian".to_string()])
+    );
+    assert_eq!(ingredients[1].parent_id, Some(Uuid::from_u128(64)));
+}
+
+#[test]
+fn parsing_csv_allergens_works() {
+    let input = "name,description,allergens\n\
+        Tofu,It's tofu,soy\n\
+        Pesto,Basil and pine nuts,milk; nuts\n";
+
+    let ingredients = IngredientFormat::Csv.parse(input).unwrap();
+
+    assert_eq!(ingredients[0].allergens, Some(vec!["soy".to_string()]));
+    assert_eq!(
+        ingredients[1].allergens,
+        Some(vec!["milk".to_string(), "nuts".to_string()])
+    );
+}
+
+#[test]
+fn optional_csv_columns_can_be_left_out() {
+    let ingredients = IngredientFormat::Csv
+        .parse("name,description\nBeef,You killed a cow for it\n")
+        .unwrap();
+
+    assert_eq!(ingredients.len(), 1);
+    assert_eq!(ingredients[0].diet_violations, Some(vec![]));
+    assert_eq!(ingredients[0].parent_id, None);
+    assert_eq!(ingredients[0].allergens, Some(vec![]));
+}
+
+#[test]
+fn parsing_malformed_documents_fails() {
+    assert!(matches!(
+        IngredientFormat::Csv.parse("name\nBeef\n"),
+        Err(IngredientFormatError::InvalidCsv(_))
+    ));
+    assert!(matches!(
+        IngredientFormat::Json.parse(r#"{ "name": "Beef" }"#),
+        Err(IngredientFormatError::InvalidJson(_))
+    ));
+}
+
+#[test]
+fn rendered_ingredients_can_be_parsed_again() {
+    let ingredient = ingredient_fixture();
+    let expected = CreateIngredientDTO::from(&ingredient);
+
+    for format in [IngredientFormat::Csv, IngredientFormat::Json] {
+        let document = format.render(std::slice::from_ref(&ingredient)).unwrap();
+        let parsed = format.parse(&document).unwrap();
+
+        assert_eq!(parsed.len(), 1, "{format:?}");
+        assert_eq!(parsed[0].name, expected.name, "{format:?}");
+        assert_eq!(parsed[0].description, expected.description, "{format:?}");
+        assert_eq!(
+            parsed[0].diet_violations, expected.diet_violations,
+            "{format:?}"
+        );
+        assert_eq!(parsed[0].parent_id, expected.parent_id, "{format:?}");
+        assert_eq!(parsed[0].allergens, expected.allergens, "{format:?}");
+    }
+}
+
+#[test]
+fn rendering_csv_works() {
+    let document = IngredientFormat::Csv
+        .render(&[ingredient_fixture()])
+        .unwrap();
+
+    assert_eq!(
+        document,
+        "name,description,diet_violations,parent_id,allergens\nBeef,You killed a cow for it,gluten_free,,\n"
+    );
+}
+
+#[test]
+fn formats_are_recognized_by_their_content_type() {
+    let cases = [
+        ("text/csv", IngredientFormat::Csv),
+        ("text/csv; charset=utf-8", IngredientFormat::Csv),
+        ("application/json", IngredientFormat::Json),
+        ("", IngredientFormat::Json),
+    ];
+
+    for (content_type, expected) in cases {
+        assert_eq!(IngredientFormat::from_content_type(content_type), expected);
+    }
+}
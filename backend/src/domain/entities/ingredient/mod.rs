@@ -1,19 +1,28 @@
 pub mod errors;
+pub mod formats;
+pub mod nutrition;
 pub mod types;
 
-use common::IngredientDTO;
-use sqlx::FromRow;
+use std::collections::BTreeMap;
+
+use common::{IngredientDTO, IngredientSubstituteDTO, IngredientTreeDTO};
+use sha2::{Digest, Sha256};
+use sqlx::{
+    error::BoxDynError,
+    postgres::{types::PgRecordDecoder, PgValueRef},
+    FromRow, Postgres,
+};
+use strum::{EnumString, VariantNames};
 use uuid::Uuid;
 
 use self::{
     errors::ValidationError,
-    types::{DietViolations, IngredientDescription, IngredientName, WhichDiets},
+    types::{
+        Allergens, DietViolations, IngredientDescription, IngredientName, SubstituteRatio,
+        WhichDiets,
+    },
 };
 
-// TODO: Consider ingredients that are variants of other ingredients
-// i.e.: soy sauce (dark, light)
-
-// TODO: Include possible substitutes
 #[derive(FromRow, Debug, Clone, PartialEq, Eq)]
 pub struct Ingredient {
     pub id: Uuid,
@@ -21,6 +30,39 @@ pub struct Ingredient {
     pub description: IngredientDescription,
     // TODO: change to diet_violations for easier filtering
     pub diet_violations: WhichDiets,
+    /// The ingredient this one is a variant of, i.e. soy sauce for dark soy sauce
+    pub parent_id: Option<Uuid>,
+    pub allergens: Allergens,
+}
+
+impl Ingredient {
+    /// Identifies the current state of the ingredient, changing whenever the ingredient does.
+    /// Ingredients don't keep track of when they were changed, so it's derived from their contents.
+    pub fn version(&self) -> String {
+        let mut hasher = Sha256::new();
+
+        hasher.update(self.id.as_bytes());
+        hasher.update(self.name.as_bytes());
+        hasher.update([0]);
+        hasher.update(self.description.as_bytes());
+        hasher.update([0]);
+        for diet in Vec::<String>::from(self.diet_violations.clone()) {
+            hasher.update(diet.as_bytes());
+            hasher.update([0]);
+        }
+        if let Some(parent_id) = self.parent_id {
+            hasher.update(parent_id.as_bytes());
+        }
+        for allergen in Vec::<String>::from(self.allergens.clone()) {
+            hasher.update([1]);
+            hasher.update(allergen.as_bytes());
+        }
+
+        hasher.finalize()[..8]
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect()
+    }
 }
 
 impl From<Ingredient> for IngredientDTO {
@@ -30,6 +72,9 @@ impl From<Ingredient> for IngredientDTO {
             name: value.name.to_string(),
             description: value.description.to_string(),
             diet_violations: value.diet_violations.clone().into(),
+            allergens: value.allergens.clone().into(),
+            parent_id: value.parent_id,
+            substitutes: None,
         }
     }
 }
@@ -41,16 +86,105 @@ impl From<&Ingredient> for IngredientDTO {
             name: value.name.to_string(),
             description: value.description.to_string(),
             diet_violations: value.diet_violations.clone().into(),
+            allergens: value.allergens.clone().into(),
+            parent_id: value.parent_id,
+            substitutes: None,
+        }
+    }
+}
+
+/// An ingredient with its variants nested below it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IngredientTree {
+    pub ingredient: Ingredient,
+    pub variants: Vec<IngredientTree>,
+}
+
+impl IngredientTree {
+    /// Arranges the ingredients into trees, one for every ingredient without a parent.
+    /// Ingredients whose parent is missing from the list are treated as roots.
+    pub fn build(ingredients: Vec<Ingredient>) -> Vec<Self> {
+        let ids: Vec<Uuid> = ingredients.iter().map(|i| i.id).collect();
+        let mut children: BTreeMap<Option<Uuid>, Vec<Ingredient>> = BTreeMap::new();
+
+        for ingredient in ingredients {
+            let parent_id = ingredient.parent_id.filter(|id| ids.contains(id));
+            children.entry(parent_id).or_default().push(ingredient);
+        }
+
+        fn grow(
+            parent_id: Option<Uuid>,
+            children: &mut BTreeMap<Option<Uuid>, Vec<Ingredient>>,
+        ) -> Vec<IngredientTree> {
+            let mut nodes = children.remove(&parent_id).unwrap_or_default();
+            nodes.sort_by(|a, b| a.name.cmp(&b.name));
+
+            nodes
+                .into_iter()
+                .map(|ingredient| IngredientTree {
+                    variants: grow(Some(ingredient.id), children),
+                    ingredient,
+                })
+                .collect()
+        }
+
+        grow(None, &mut children)
+    }
+}
+
+impl From<IngredientTree> for IngredientTreeDTO {
+    fn from(value: IngredientTree) -> Self {
+        Self {
+            ingredient: value.ingredient.into(),
+            variants: value.variants.into_iter().map(Into::into).collect(),
         }
     }
 }
 
-#[derive(FromRow, Debug, Clone, PartialEq, sqlx::Decode)]
+/// An ingredient that can be used in place of another one.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IngredientSubstitute {
+    pub substitute: Ingredient,
+    pub ratio: SubstituteRatio,
+    pub note: Option<String>,
+}
+
+impl From<IngredientSubstitute> for IngredientSubstituteDTO {
+    fn from(value: IngredientSubstitute) -> Self {
+        Self {
+            substitute_id: value.substitute.id,
+            name: value.substitute.name.to_string(),
+            diet_violations: value.substitute.diet_violations.into(),
+            ratio: *value.ratio,
+            note: value.note,
+        }
+    }
+}
+
+#[derive(FromRow, Debug, Clone, PartialEq)]
 pub struct IngredientModel {
     pub id: Uuid,
     pub name: String,
     pub description: String,
     pub diet_violations: Vec<String>,
+    pub parent_id: Option<Uuid>,
+    pub allergens: Vec<String>,
+}
+
+// Written by hand, since the derived implementation can't decode optional fields of a record
+impl<'r> sqlx::Decode<'r, Postgres> for IngredientModel {
+    fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
+        let mut decoder = PgRecordDecoder::new(value)?;
+
+        Ok(Self {
+            id: decoder.try_decode()?,
+            name: decoder.try_decode()?,
+            description: decoder.try_decode()?,
+            diet_violations: decoder.try_decode()?,
+            parent_id: decoder.try_decode()?,
+            allergens: decoder.try_decode()?,
+        })
+    }
 }
 
 impl TryFrom<&IngredientModel> for Ingredient {
@@ -61,6 +195,8 @@ impl TryFrom<&IngredientModel> for Ingredient {
             name: value.name.clone().try_into()?,
             description: value.description.clone().try_into()?,
             diet_violations: value.diet_violations.clone().into(),
+            parent_id: value.parent_id,
+            allergens: Allergens::from_stored(value.allergens.clone()),
         })
     }
 }
@@ -89,6 +225,8 @@ impl From<Ingredient> for IngredientModel {
             name,
             description,
             diet_violations,
+            parent_id,
+            allergens,
         }: Ingredient,
     ) -> Self {
         Self {
@@ -96,6 +234,8 @@ impl From<Ingredient> for IngredientModel {
             name: name.to_string(),
             description: description.to_string(),
             diet_violations: diet_violations.into(),
+            parent_id,
+            allergens: allergens.into(),
         }
     }
 }
@@ -107,13 +247,30 @@ impl From<IngredientModel> for common::IngredientDTO {
             name: value.name.to_string(),
             description: value.description.to_string(),
             diet_violations: value.diet_violations,
+            allergens: value.allergens,
+            parent_id: value.parent_id,
+            substitutes: None,
         }
     }
 }
 
+/// How ingredient listings are ordered.
+/// Ingredients don't keep track of when they were updated, so they can only be sorted by name
+/// or from the newest, which the time-ordered IDs take care of.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, EnumString, VariantNames)]
+#[strum(serialize_all = "snake_case")]
+pub enum IngredientSort {
+    Name,
+    #[default]
+    CreatedAt,
+}
+
 #[derive(Debug, Default, Clone)]
 pub struct IngredientChangeset {
     pub name: Option<IngredientName>,
     pub description: Option<IngredientDescription>,
     pub diet_violations: Option<WhichDiets>,
+    /// `Some(None)` detaches the ingredient from its parent
+    pub parent_id: Option<Option<Uuid>>,
+    pub allergens: Option<Allergens>,
 }
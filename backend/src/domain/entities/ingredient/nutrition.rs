@@ -0,0 +1,194 @@
+use common::{IngredientNutritionDTO, NutritionFactsDTO};
+
+use crate::domain::entities::recipe::units::Density;
+
+use super::errors::ValidationError;
+
+/// Energy in kilocalories, everything else in grams.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct NutritionFacts {
+    pub energy_kcal: f64,
+    pub protein: f64,
+    pub fat: f64,
+    pub carbohydrates: f64,
+    pub fiber: f64,
+    pub sugar: f64,
+    pub salt: f64,
+}
+
+impl NutritionFacts {
+    fn fields(&self) -> [(&'static str, f64); 7] {
+        [
+            ("energy_kcal", self.energy_kcal),
+            ("protein", self.protein),
+            ("fat", self.fat),
+            ("carbohydrates", self.carbohydrates),
+            ("fiber", self.fiber),
+            ("sugar", self.sugar),
+            ("salt", self.salt),
+        ]
+    }
+
+    pub fn validated(self) -> Result<Self, ValidationError> {
+        match self
+            .fields()
+            .into_iter()
+            .find(|(_, value)| !value.is_finite() || *value < 0.0)
+        {
+            Some((field, _)) => Err(ValidationError::Negative(field)),
+            None => Ok(self),
+        }
+    }
+
+    /// ```
+    /// use backend::domain::entities::ingredient::nutrition::NutritionFacts;
+    ///
+    /// let facts = NutritionFacts { protein: 8.0, ..Default::default() };
+    ///
+    /// assert_eq!(facts.scaled(2.5).protein, 20.0);
+    /// ```
+    pub fn scaled(&self, factor: f64) -> Self {
+        Self {
+            energy_kcal: self.energy_kcal * factor,
+            protein: self.protein * factor,
+            fat: self.fat * factor,
+            carbohydrates: self.carbohydrates * factor,
+            fiber: self.fiber * factor,
+            sugar: self.sugar * factor,
+            salt: self.salt * factor,
+        }
+    }
+
+    pub fn plus(&self, other: &Self) -> Self {
+        Self {
+            energy_kcal: self.energy_kcal + other.energy_kcal,
+            protein: self.protein + other.protein,
+            fat: self.fat + other.fat,
+            carbohydrates: self.carbohydrates + other.carbohydrates,
+            fiber: self.fiber + other.fiber,
+            sugar: self.sugar + other.sugar,
+            salt: self.salt + other.salt,
+        }
+    }
+
+    /// Rounds every value to a tenth, which is as precise as a nutrition label gets.
+    pub fn rounded(&self) -> Self {
+        self.map(|value| (value * 10.0).round() / 10.0)
+    }
+
+    fn map(&self, f: impl Fn(f64) -> f64) -> Self {
+        Self {
+            energy_kcal: f(self.energy_kcal),
+            protein: f(self.protein),
+            fat: f(self.fat),
+            carbohydrates: f(self.carbohydrates),
+            fiber: f(self.fiber),
+            sugar: f(self.sugar),
+            salt: f(self.salt),
+        }
+    }
+}
+
+/// What 100 g of an ingredient is made of.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct IngredientNutrition {
+    pub per_100g: NutritionFacts,
+    /// Lets amounts measured by volume be weighed, they can't be counted otherwise
+    pub density: Option<Density>,
+}
+
+impl IngredientNutrition {
+    pub fn validated(self) -> Result<Self, ValidationError> {
+        if matches!(self.density, Some(Density(density)) if !density.is_finite() || density <= 0.0)
+        {
+            return Err(ValidationError::NotPositive("density"));
+        }
+
+        Ok(Self {
+            per_100g: self.per_100g.validated()?,
+            ..self
+        })
+    }
+
+    /// The nutrition facts of the given amount of grams.
+    pub fn of_grams(&self, grams: f64) -> NutritionFacts {
+        self.per_100g.scaled(grams / 100.0)
+    }
+}
+
+impl From<NutritionFacts> for NutritionFactsDTO {
+    fn from(value: NutritionFacts) -> Self {
+        Self {
+            energy_kcal: value.energy_kcal,
+            protein: value.protein,
+            fat: value.fat,
+            carbohydrates: value.carbohydrates,
+            fiber: value.fiber,
+            sugar: value.sugar,
+            salt: value.salt,
+        }
+    }
+}
+
+impl From<NutritionFactsDTO> for NutritionFacts {
+    fn from(value: NutritionFactsDTO) -> Self {
+        Self {
+            energy_kcal: value.energy_kcal,
+            protein: value.protein,
+            fat: value.fat,
+            carbohydrates: value.carbohydrates,
+            fiber: value.fiber,
+            sugar: value.sugar,
+            salt: value.salt,
+        }
+    }
+}
+
+impl From<IngredientNutrition> for IngredientNutritionDTO {
+    fn from(value: IngredientNutrition) -> Self {
+        Self {
+            per_100g: value.per_100g.into(),
+            density: value.density.map(|Density(density)| density),
+        }
+    }
+}
+
+impl From<IngredientNutritionDTO> for IngredientNutrition {
+    fn from(value: IngredientNutritionDTO) -> Self {
+        Self {
+            per_100g: value.per_100g.into(),
+            density: value.density.map(Density),
+        }
+    }
+}
+
+/// A row of the `ingredient_nutrition` table.
+#[derive(Debug, Clone)]
+pub struct IngredientNutritionModel {
+    pub ingredient_id: uuid::Uuid,
+    pub energy_kcal: f64,
+    pub protein: f64,
+    pub fat: f64,
+    pub carbohydrates: f64,
+    pub fiber: f64,
+    pub sugar: f64,
+    pub salt: f64,
+    pub density: Option<f64>,
+}
+
+impl From<IngredientNutritionModel> for IngredientNutrition {
+    fn from(value: IngredientNutritionModel) -> Self {
+        Self {
+            per_100g: NutritionFacts {
+                energy_kcal: value.energy_kcal,
+                protein: value.protein,
+                fat: value.fat,
+                carbohydrates: value.carbohydrates,
+                fiber: value.fiber,
+                sugar: value.sugar,
+                salt: value.salt,
+            },
+            density: value.density.map(Density),
+        }
+    }
+}
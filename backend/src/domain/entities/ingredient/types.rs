@@ -149,3 +149,129 @@ impl PartialEq<DietViolations> for String {
         self == &other.to_string()
     }
 }
+
+/// The major allergens which have to be labelled in the EU.
+#[derive(
+    Serialize,
+    Deserialize,
+    VariantNames,
+    sqlx::Type,
+    EnumString,
+    Display,
+    Debug,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Clone,
+    Copy,
+)]
+#[strum(serialize_all = "snake_case", ascii_case_insensitive)]
+#[serde(rename_all = "snake_case")]
+pub enum Allergen {
+    Milk,
+    Eggs,
+    /// Tree nuts, i.e. almonds or walnuts
+    Nuts,
+    Peanuts,
+    Sesame,
+    Soy,
+    Fish,
+    Crustaceans,
+    Molluscs,
+    Celery,
+    Mustard,
+    Lupin,
+    /// Sulphur dioxide and sulphites above 10 mg/kg
+    Sulphites,
+    /// Wheat, rye, barley and oats
+    CerealsWithGluten,
+}
+
+impl TryFrom<String> for Allergen {
+    type Error = ValidationError;
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::from_str(value.trim())
+            .map_err(|_| ValidationError::DoesNotMatch("allergens", Self::VARIANTS))
+    }
+}
+
+/// The allergens an ingredient contains.
+#[derive(
+    Serialize,
+    Deserialize,
+    Shrinkwrap,
+    sqlx::Type,
+    sqlx::FromRow,
+    PartialEq,
+    Eq,
+    Clone,
+    Debug,
+    Default,
+)]
+pub struct Allergens(pub Vec<Allergen>);
+
+impl Allergens {
+    pub fn new() -> Self {
+        Self(vec![])
+    }
+
+    /// Reads allergens back from storage, where they've already been validated once.
+    /// Anything unknown is skipped rather than failing the whole row.
+    pub fn from_stored(value: Vec<String>) -> Self {
+        value
+            .into_iter()
+            .filter_map(|v| Allergen::try_from(v).ok())
+            .collect::<Vec<_>>()
+            .into()
+    }
+}
+
+/// Unlike diets, an unknown allergen is an error: silently dropping a misspelled one would
+/// make recipes using the ingredient look safe for people with that allergy.
+impl TryFrom<Vec<String>> for Allergens {
+    type Error = ValidationError;
+    fn try_from(value: Vec<String>) -> Result<Self, Self::Error> {
+        Ok(value
+            .into_iter()
+            .map(Allergen::try_from)
+            .collect::<Result<Vec<_>, _>>()?
+            .into())
+    }
+}
+
+/// Sorted and without duplicates.
+impl From<Vec<Allergen>> for Allergens {
+    fn from(mut value: Vec<Allergen>) -> Self {
+        value.sort();
+        value.dedup();
+        Self(value)
+    }
+}
+
+impl From<Allergens> for Vec<String> {
+    fn from(val: Allergens) -> Self {
+        val.0.iter().map(|a| a.to_string()).collect()
+    }
+}
+
+/// How much of a substitute replaces one unit of the original ingredient.
+#[derive(Serialize, Deserialize, Shrinkwrap, Debug, Clone, Copy, PartialEq)]
+pub struct SubstituteRatio(pub f64);
+
+impl Default for SubstituteRatio {
+    fn default() -> Self {
+        Self(1.0)
+    }
+}
+
+impl TryFrom<f64> for SubstituteRatio {
+    type Error = ValidationError;
+    fn try_from(value: f64) -> Result<Self, Self::Error> {
+        if value.is_finite() && value > 0.0 {
+            Ok(Self(value))
+        } else {
+            Err(ValidationError::NotPositive("ratio"))
+        }
+    }
+}
@@ -0,0 +1,23 @@
+use chrono::NaiveDate;
+use uuid::Uuid;
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum ValidationError {
+    #[error("The meal on {0} is not in the week of the plan, which goes from {1} to {2}")]
+    OutsideOfPlan(NaiveDate, NaiveDate, NaiveDate),
+
+    #[error("A meal plan starting on {0} would end after the last day there is")]
+    EndsTooLate(NaiveDate),
+
+    #[error("A meal has to be planned for at least one serving")]
+    InvalidServings,
+
+    #[error("The recipe with ID of {0} is planned more than once for the {2} on {1}")]
+    DuplicateMeal(Uuid, NaiveDate, String),
+
+    #[error("Field {0} does not match any of: {1:?}")]
+    DoesNotMatch(&'static str, &'static [&'static str]),
+
+    #[error(transparent)]
+    Unknown(#[from] eyre::Error),
+}
@@ -0,0 +1,223 @@
+pub mod errors;
+
+use chrono::{DateTime, Days, NaiveDate, Utc};
+use common::{MealPlanDTO, MealSlotDTO, PlannedMealDTO};
+use sqlx::FromRow;
+use strum::{EnumString, VariantNames};
+use uuid::Uuid;
+
+use self::errors::ValidationError;
+
+/// The part of the day a meal is eaten in.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, EnumString, VariantNames, strum::Display,
+)]
+#[strum(serialize_all = "snake_case")]
+pub enum MealSlot {
+    Breakfast,
+    Lunch,
+    Dinner,
+    Snack,
+}
+
+impl From<MealSlotDTO> for MealSlot {
+    fn from(value: MealSlotDTO) -> Self {
+        match value {
+            MealSlotDTO::Breakfast => Self::Breakfast,
+            MealSlotDTO::Lunch => Self::Lunch,
+            MealSlotDTO::Dinner => Self::Dinner,
+            MealSlotDTO::Snack => Self::Snack,
+        }
+    }
+}
+
+impl From<MealSlot> for MealSlotDTO {
+    fn from(value: MealSlot) -> Self {
+        match value {
+            MealSlot::Breakfast => Self::Breakfast,
+            MealSlot::Lunch => Self::Lunch,
+            MealSlot::Dinner => Self::Dinner,
+            MealSlot::Snack => Self::Snack,
+        }
+    }
+}
+
+/// A recipe someone is going to make on a given day.
+/// A slot can have more than one recipe in it, e.g. a main course and a side.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlannedMeal {
+    pub date: NaiveDate,
+    pub slot: MealSlot,
+    pub recipe_id: Uuid,
+    pub servings: u16,
+}
+
+impl From<PlannedMealDTO> for PlannedMeal {
+    fn from(value: PlannedMealDTO) -> Self {
+        Self {
+            date: value.date,
+            slot: value.slot.into(),
+            recipe_id: value.recipe_id,
+            servings: value.servings,
+        }
+    }
+}
+
+impl From<PlannedMeal> for PlannedMealDTO {
+    fn from(value: PlannedMeal) -> Self {
+        Self {
+            date: value.date,
+            slot: value.slot.into(),
+            recipe_id: value.recipe_id,
+            servings: value.servings,
+        }
+    }
+}
+
+/// The meals a user is going to have during a week, starting on any day they like.
+/// Every user can only have one plan starting on a given day.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MealPlan {
+    pub id: Uuid,
+    pub owner_id: Uuid,
+    pub starts_on: NaiveDate,
+    /// Ordered by the day, and then by the slot
+    pub meals: Vec<PlannedMeal>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl MealPlan {
+    pub const DAYS: u64 = 7;
+
+    /// The last day of the plan, which doesn't exist for plans starting in the last days of the calendar.
+    /// ```rust
+    /// use backend::domain::entities::meal_plan::MealPlan;
+    /// use chrono::NaiveDate;
+    ///
+    /// let starts_on = NaiveDate::from_ymd_opt(2024, 10, 28).unwrap();
+    ///
+    /// assert_eq!(MealPlan::ends_on(starts_on).unwrap(), NaiveDate::from_ymd_opt(2024, 11, 3).unwrap());
+    /// assert!(MealPlan::ends_on(NaiveDate::MAX).is_err());
+    /// ```
+    pub fn ends_on(starts_on: NaiveDate) -> Result<NaiveDate, ValidationError> {
+        starts_on
+            .checked_add_days(Days::new(Self::DAYS - 1))
+            .ok_or(ValidationError::EndsTooLate(starts_on))
+    }
+
+    /// Makes sure every meal is in the week of the plan and planned only once, and puts them in order.
+    pub fn validated(mut self) -> Result<Self, ValidationError> {
+        let ends_on = Self::ends_on(self.starts_on)?;
+
+        if let Some(meal) = self
+            .meals
+            .iter()
+            .find(|m| m.date < self.starts_on || m.date > ends_on)
+        {
+            return Err(ValidationError::OutsideOfPlan(
+                meal.date,
+                self.starts_on,
+                ends_on,
+            ));
+        }
+
+        if self.meals.iter().any(|m| m.servings == 0) {
+            return Err(ValidationError::InvalidServings);
+        }
+
+        self.meals.sort_by_key(|m| (m.date, m.slot));
+
+        if let Some(pair) = self.meals.windows(2).find(|pair| {
+            (pair[0].date, pair[0].slot, pair[0].recipe_id)
+                == (pair[1].date, pair[1].slot, pair[1].recipe_id)
+        }) {
+            return Err(ValidationError::DuplicateMeal(
+                pair[0].recipe_id,
+                pair[0].date,
+                pair[0].slot.to_string(),
+            ));
+        }
+
+        Ok(self)
+    }
+
+    pub fn belongs_to(&self, user_id: &Uuid) -> bool {
+        &self.owner_id == user_id
+    }
+}
+
+impl From<MealPlan> for MealPlanDTO {
+    fn from(value: MealPlan) -> Self {
+        Self {
+            id: value.id,
+            owner_id: value.owner_id,
+            starts_on: value.starts_on,
+            // Plans are validated before they're stored, so they always have a last day
+            ends_on: MealPlan::ends_on(value.starts_on).unwrap_or(NaiveDate::MAX),
+            meals: value.meals.into_iter().map(Into::into).collect(),
+            created_at: value.created_at.to_rfc3339(),
+            updated_at: value.updated_at.to_rfc3339(),
+        }
+    }
+}
+
+#[derive(FromRow, Debug)]
+pub struct MealPlanModel {
+    pub id: Uuid,
+    pub owner_id: Uuid,
+    pub starts_on: NaiveDate,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(FromRow, Debug)]
+pub struct PlannedMealModel {
+    pub meal_plan_id: Uuid,
+    pub date: NaiveDate,
+    pub slot: String,
+    pub recipe_id: Uuid,
+    pub servings: i32,
+}
+
+impl TryFrom<PlannedMealModel> for PlannedMeal {
+    type Error = ValidationError;
+
+    fn try_from(value: PlannedMealModel) -> Result<Self, Self::Error> {
+        Ok(Self {
+            date: value.date,
+            slot: value
+                .slot
+                .parse()
+                .map_err(|_| ValidationError::DoesNotMatch("slot", MealSlot::VARIANTS))?,
+            recipe_id: value.recipe_id,
+            servings: value
+                .servings
+                .try_into()
+                .map_err(|_| ValidationError::InvalidServings)?,
+        })
+    }
+}
+
+impl MealPlanModel {
+    /// Puts the plan back together with the meals stored for it.
+    pub fn with_meals(self, meals: Vec<PlannedMealModel>) -> Result<MealPlan, ValidationError> {
+        let meals = meals
+            .into_iter()
+            .map(PlannedMeal::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        MealPlan {
+            id: self.id,
+            owner_id: self.owner_id,
+            starts_on: self.starts_on,
+            meals,
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+        }
+        .validated()
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,139 @@
+use chrono::{Days, NaiveDate};
+use pretty_assertions::assert_eq;
+use uuid::Uuid;
+
+use crate::test_utils::{meal_plan_fixture, user_fixture};
+
+use super::{errors::ValidationError, MealPlan, MealSlot, PlannedMeal};
+
+fn meal(date: NaiveDate, slot: MealSlot) -> PlannedMeal {
+    PlannedMeal {
+        date,
+        slot,
+        recipe_id: Uuid::nil(),
+        servings: 2,
+    }
+}
+
+#[test]
+fn meals_are_ordered_by_day_and_slot() {
+    let plan = meal_plan_fixture();
+    let monday = plan.starts_on;
+    let tuesday = monday + Days::new(1);
+
+    let plan = MealPlan {
+        meals: vec![
+            meal(tuesday, MealSlot::Breakfast),
+            meal(monday, MealSlot::Dinner),
+            meal(monday, MealSlot::Breakfast),
+        ],
+        ..plan
+    }
+    .validated()
+    .unwrap();
+
+    assert_eq!(
+        plan.meals,
+        vec![
+            meal(monday, MealSlot::Breakfast),
+            meal(monday, MealSlot::Dinner),
+            meal(tuesday, MealSlot::Breakfast),
+        ]
+    );
+}
+
+#[test]
+fn meals_outside_of_the_week_are_rejected() {
+    let plan = meal_plan_fixture();
+    let next_monday = plan.starts_on + Days::new(MealPlan::DAYS);
+    let last_sunday = plan.starts_on - Days::new(1);
+
+    for date in [next_monday, last_sunday] {
+        let error = MealPlan {
+            meals: vec![meal(date, MealSlot::Lunch)],
+            ..meal_plan_fixture()
+        }
+        .validated()
+        .unwrap_err();
+
+        assert!(matches!(error, ValidationError::OutsideOfPlan(d, _, _) if d == date));
+    }
+
+    // The last day is still a part of the plan
+    MealPlan {
+        meals: vec![meal(
+            MealPlan::ends_on(plan.starts_on).unwrap(),
+            MealSlot::Lunch,
+        )],
+        ..plan
+    }
+    .validated()
+    .unwrap();
+}
+
+#[test]
+fn plans_ending_after_the_last_day_are_rejected() {
+    let starts_on = NaiveDate::MAX - Days::new(MealPlan::DAYS - 2);
+
+    let error = MealPlan {
+        starts_on,
+        meals: vec![],
+        ..meal_plan_fixture()
+    }
+    .validated()
+    .unwrap_err();
+
+    assert!(matches!(error, ValidationError::EndsTooLate(d) if d == starts_on));
+
+    // A plan ending on the very last day is fine
+    MealPlan {
+        starts_on: NaiveDate::MAX - Days::new(MealPlan::DAYS - 1),
+        meals: vec![],
+        ..meal_plan_fixture()
+    }
+    .validated()
+    .unwrap();
+}
+
+#[test]
+fn meals_without_servings_are_rejected() {
+    let plan = meal_plan_fixture();
+
+    let error = MealPlan {
+        meals: vec![PlannedMeal {
+            servings: 0,
+            ..meal(plan.starts_on, MealSlot::Snack)
+        }],
+        ..plan
+    }
+    .validated()
+    .unwrap_err();
+
+    assert!(matches!(error, ValidationError::InvalidServings));
+}
+
+#[test]
+fn the_same_recipe_cannot_be_planned_twice_for_one_meal() {
+    let plan = meal_plan_fixture();
+
+    let error = MealPlan {
+        meals: vec![
+            meal(plan.starts_on, MealSlot::Dinner),
+            meal(plan.starts_on + Days::new(1), MealSlot::Dinner),
+            meal(plan.starts_on, MealSlot::Dinner),
+        ],
+        ..plan
+    }
+    .validated()
+    .unwrap_err();
+
+    assert!(matches!(error, ValidationError::DuplicateMeal(_, _, _)));
+}
+
+#[test]
+fn plan_belongs_to_its_owner() {
+    let plan = meal_plan_fixture();
+
+    assert!(plan.belongs_to(&user_fixture().id));
+    assert!(!plan.belongs_to(&Uuid::from_u128(0xdead)));
+}
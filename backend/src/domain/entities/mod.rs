@@ -1,2 +1,7 @@
 pub mod ingredient;
+pub mod meal_plan;
+pub mod outbox;
+pub mod pagination;
+pub mod pantry;
 pub mod recipe;
+pub mod user;
@@ -0,0 +1,127 @@
+use chrono::Utc;
+use common::events::{
+    ChangeDTO, Event, EventEnvelope, RecipeIngredientChangeDTO, RecipeIngredientDTO,
+};
+use uuid::Uuid;
+
+use crate::tracing::current_trace_context;
+
+use super::{
+    ingredient::Ingredient,
+    recipe::{IngredientWithAmount, Recipe},
+};
+
+/// A message waiting to be published to the message bus.
+/// It's stored alongside the change that caused it, so the two can't get out of sync.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OutboxMessage {
+    /// Same as the ID of the event in the payload
+    pub id: Uuid,
+    pub topic: String,
+    pub key: String,
+    /// The serialized [EventEnvelope]
+    pub payload: serde_json::Value,
+    /// How many times publishing this message has failed so far
+    pub attempts: i32,
+}
+
+impl OutboxMessage {
+    /// Wraps the event in an envelope and routes it to the topic of its type.
+    /// The key is the ID of the entity the event is about, so that its events stay in order.
+    pub fn from_event(id: &Uuid, event: Event) -> Self {
+        let event_type = event.event_type();
+        let envelope = EventEnvelope {
+            event_id: Uuid::now_v7(),
+            schema_version: event_type.schema_version(),
+            occurred_at: Utc::now(),
+            trace_context: current_trace_context(),
+            event,
+        };
+
+        Self {
+            id: envelope.event_id,
+            topic: event_type.topic().to_string(),
+            key: serde_json::json!({ "id": id.to_string() }).to_string(),
+            payload: serde_json::json!(envelope),
+            attempts: 0,
+        }
+    }
+
+    pub fn ingredient_added(ingredient: &Ingredient) -> Self {
+        Self::from_event(&ingredient.id, Event::IngredientAdded(ingredient.into()))
+    }
+
+    pub fn ingredient_updated(old_ingredient: &Ingredient, new_ingredient: &Ingredient) -> Self {
+        Self::from_event(
+            &old_ingredient.id,
+            Event::IngredientUpdated(ChangeDTO {
+                old: old_ingredient.into(),
+                new: new_ingredient.into(),
+            }),
+        )
+    }
+
+    pub fn ingredient_deleted(ingredient: &Ingredient) -> Self {
+        Self::from_event(&ingredient.id, Event::IngredientDeleted(ingredient.into()))
+    }
+
+    pub fn recipe_created(recipe: &Recipe) -> Self {
+        Self::from_event(&recipe.id, Event::RecipeCreated(recipe.clone().into()))
+    }
+
+    pub fn recipe_updated(old_recipe: &Recipe, new_recipe: &Recipe) -> Self {
+        Self::from_event(
+            &old_recipe.id,
+            Event::RecipeUpdated(ChangeDTO {
+                old: old_recipe.clone().into(),
+                new: new_recipe.clone().into(),
+            }),
+        )
+    }
+
+    pub fn recipe_deleted(recipe: &Recipe) -> Self {
+        Self::from_event(&recipe.id, Event::RecipeDeleted(recipe.clone().into()))
+    }
+
+    pub fn recipe_ingredient_added(recipe: &Recipe, ingredient: &IngredientWithAmount) -> Self {
+        Self::from_event(
+            &recipe.id,
+            Event::RecipeIngredientAdded(RecipeIngredientDTO {
+                recipe_id: recipe.id,
+                ingredient: ingredient.clone().into(),
+            }),
+        )
+    }
+
+    pub fn recipe_ingredient_removed(recipe: &Recipe, ingredient: &IngredientWithAmount) -> Self {
+        Self::from_event(
+            &recipe.id,
+            Event::RecipeIngredientRemoved(RecipeIngredientDTO {
+                recipe_id: recipe.id,
+                ingredient: ingredient.clone().into(),
+            }),
+        )
+    }
+
+    pub fn recipe_ingredient_amount_changed(
+        recipe: &Recipe,
+        old_ingredient: &IngredientWithAmount,
+        new_ingredient: &IngredientWithAmount,
+    ) -> Self {
+        Self::from_event(
+            &recipe.id,
+            Event::RecipeIngredientAmountChanged(RecipeIngredientChangeDTO {
+                recipe_id: recipe.id,
+                old: old_ingredient.clone().into(),
+                new: new_ingredient.clone().into(),
+            }),
+        )
+    }
+
+    pub fn envelope(&self) -> eyre::Result<EventEnvelope> {
+        Ok(serde_json::from_value(self.payload.clone())?)
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,33 @@
+use common::events::{Event, EventType};
+
+use crate::test_utils::ingredient_fixture;
+
+use super::OutboxMessage;
+
+#[test]
+fn messages_are_routed_by_the_type_of_their_event() {
+    let old_ingredient = ingredient_fixture();
+    let mut new_ingredient = old_ingredient.clone();
+    new_ingredient.name.0 = "New name".to_string();
+
+    let message = OutboxMessage::ingredient_updated(&old_ingredient, &new_ingredient);
+
+    assert_eq!(message.topic, "deepdish.ingredient.updated");
+    assert_eq!(
+        EventType::from_topic(&message.topic),
+        Some(EventType::IngredientUpdated)
+    );
+}
+
+#[test]
+fn the_envelope_survives_the_round_trip() {
+    let ingredient = ingredient_fixture();
+
+    let message = OutboxMessage::ingredient_added(&ingredient);
+    let envelope = message.envelope().unwrap();
+
+    assert_eq!(envelope.event_id, message.id);
+    assert_eq!(envelope.schema_version, 1);
+    assert_eq!(message.payload["type"], "ingredient.added");
+    assert_eq!(envelope.event, Event::IngredientAdded((&ingredient).into()));
+}
@@ -0,0 +1,124 @@
+use std::str::FromStr;
+
+use common::{PageParamsDTO, PagedDTO};
+use serde::Serialize;
+use strum::VariantNames;
+use uuid::Uuid;
+
+pub const DEFAULT_PAGE_SIZE: u32 = 20;
+pub const MAX_PAGE_SIZE: u32 = 100;
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum PaginationError {
+    #[error("Field sort does not match any of: {0:?}")]
+    InvalidSort(&'static [&'static str]),
+}
+
+/// Which page of a listing to fetch.
+/// The cursor is the ID of the last item on the previous page, so the next page starts right after it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PageRequest<S> {
+    pub limit: u32,
+    pub cursor: Option<Uuid>,
+    pub sort: S,
+}
+
+impl<S: Default> Default for PageRequest<S> {
+    fn default() -> Self {
+        Self {
+            limit: DEFAULT_PAGE_SIZE,
+            cursor: None,
+            sort: S::default(),
+        }
+    }
+}
+
+impl<S> TryFrom<PageParamsDTO> for PageRequest<S>
+where
+    S: FromStr + VariantNames + Default,
+{
+    type Error = PaginationError;
+
+    fn try_from(value: PageParamsDTO) -> Result<Self, Self::Error> {
+        let sort = match value.sort {
+            Some(sort) => {
+                S::from_str(&sort).map_err(|_| PaginationError::InvalidSort(S::VARIANTS))?
+            }
+            None => S::default(),
+        };
+
+        Ok(Self {
+            limit: value
+                .limit
+                .unwrap_or(DEFAULT_PAGE_SIZE)
+                .clamp(1, MAX_PAGE_SIZE),
+            cursor: value.cursor,
+            sort,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub next_cursor: Option<Uuid>,
+}
+
+impl<T> Page<T> {
+    /// Builds a page from a query that asked for one item more than the limit.
+    /// The extra item is dropped, its only purpose is telling whether there is a next page.
+    pub fn from_overfetched(mut items: Vec<T>, limit: u32, id: impl Fn(&T) -> Uuid) -> Self {
+        let limit = limit as usize;
+
+        if items.len() <= limit {
+            return Self {
+                items,
+                next_cursor: None,
+            };
+        }
+
+        items.truncate(limit);
+        let next_cursor = items.last().map(id);
+
+        Self { items, next_cursor }
+    }
+
+    /// Cuts a page out of items that are already sorted.
+    /// An unknown cursor results in an empty page.
+    pub fn from_sorted<S>(
+        items: Vec<T>,
+        request: &PageRequest<S>,
+        id: impl Fn(&T) -> Uuid,
+    ) -> Self {
+        let start = match request.cursor {
+            Some(cursor) => items
+                .iter()
+                .position(|item| id(item) == cursor)
+                .map_or(items.len(), |position| position + 1),
+            None => 0,
+        };
+
+        let items = items
+            .into_iter()
+            .skip(start)
+            .take(request.limit as usize + 1)
+            .collect();
+
+        Self::from_overfetched(items, request.limit, id)
+    }
+}
+
+impl<T, U> From<Page<T>> for PagedDTO<U>
+where
+    U: Serialize + From<T>,
+{
+    fn from(value: Page<T>) -> Self {
+        Self {
+            items: value.items.into_iter().map(U::from).collect(),
+            next_cursor: value.next_cursor,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
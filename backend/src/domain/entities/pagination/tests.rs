@@ -0,0 +1,77 @@
+use common::PageParamsDTO;
+use uuid::Uuid;
+
+use crate::domain::entities::recipe::RecipeSort;
+
+use super::{Page, PageRequest, PaginationError, MAX_PAGE_SIZE};
+
+fn ids(n: u128) -> Vec<Uuid> {
+    (1..=n).map(Uuid::from_u128).collect()
+}
+
+#[test]
+fn paginating_sorted_items_works() {
+    let request = PageRequest {
+        limit: 2,
+        cursor: None,
+        sort: RecipeSort::default(),
+    };
+
+    let first = Page::from_sorted(ids(5), &request, |id| *id);
+    assert_eq!(first.items, ids(2));
+    assert_eq!(first.next_cursor, Some(Uuid::from_u128(2)));
+
+    let request = PageRequest {
+        cursor: first.next_cursor,
+        ..request
+    };
+    let second = Page::from_sorted(ids(5), &request, |id| *id);
+    assert_eq!(second.items, vec![Uuid::from_u128(3), Uuid::from_u128(4)]);
+
+    let request = PageRequest {
+        cursor: second.next_cursor,
+        ..request
+    };
+    let last = Page::from_sorted(ids(5), &request, |id| *id);
+    assert_eq!(last.items, vec![Uuid::from_u128(5)]);
+    assert_eq!(last.next_cursor, None);
+}
+
+#[test]
+fn exact_page_has_no_next_cursor() {
+    let page = Page::from_overfetched(ids(2), 2, |id| *id);
+
+    assert_eq!(page.items, ids(2));
+    assert_eq!(page.next_cursor, None);
+}
+
+#[test]
+fn parsing_page_params_works() {
+    let request: PageRequest<RecipeSort> = PageParamsDTO {
+        limit: Some(1000),
+        cursor: Some(Uuid::nil()),
+        sort: Some("updated_at".to_string()),
+    }
+    .try_into()
+    .unwrap();
+
+    assert_eq!(
+        request,
+        PageRequest {
+            limit: MAX_PAGE_SIZE,
+            cursor: Some(Uuid::nil()),
+            sort: RecipeSort::UpdatedAt,
+        }
+    );
+}
+
+#[test]
+fn parsing_unknown_sort_errors() {
+    let error = PageRequest::<RecipeSort>::try_from(PageParamsDTO {
+        sort: Some("calories".to_string()),
+        ..Default::default()
+    })
+    .unwrap_err();
+
+    assert!(matches!(error, PaginationError::InvalidSort(_)));
+}
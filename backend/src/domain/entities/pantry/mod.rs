@@ -0,0 +1,58 @@
+use chrono::NaiveDate;
+use common::PantryItemDTO;
+use uuid::Uuid;
+
+use super::{
+    ingredient::{Ingredient, IngredientModel},
+    recipe::{errors::ValidationError, IngredientUnit},
+};
+
+/// How much of an ingredient someone has at home.
+/// Everyone has at most one item per ingredient in their pantry.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PantryItem {
+    pub owner_id: Uuid,
+    pub ingredient: Ingredient,
+    pub amount: IngredientUnit,
+    pub expires_on: Option<NaiveDate>,
+}
+
+impl PantryItem {
+    /// Items are still good on the day they expire.
+    pub fn is_expired(&self, today: NaiveDate) -> bool {
+        self.expires_on.is_some_and(|expires_on| expires_on < today)
+    }
+}
+
+impl From<PantryItem> for PantryItemDTO {
+    fn from(value: PantryItem) -> Self {
+        Self {
+            ingredient: value.ingredient.into(),
+            amount: value.amount.into(),
+            expires_on: value.expires_on,
+        }
+    }
+}
+
+pub struct PantryItemModel {
+    pub owner_id: Uuid,
+    pub ingredient: IngredientModel,
+    pub amount: serde_json::Value,
+    pub expires_on: Option<NaiveDate>,
+}
+
+impl TryFrom<PantryItemModel> for PantryItem {
+    type Error = ValidationError;
+    fn try_from(value: PantryItemModel) -> Result<Self, Self::Error> {
+        Ok(Self {
+            owner_id: value.owner_id,
+            ingredient: value.ingredient.try_into()?,
+            amount: serde_json::from_value(value.amount)
+                .map_err(|e| ValidationError::DeserializationFailed("amount", e))?,
+            expires_on: value.expires_on,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,22 @@
+use chrono::{Days, NaiveDate};
+
+use crate::test_utils::pantry_item_fixture;
+
+use super::PantryItem;
+
+#[test]
+fn items_expire_after_their_expiry_date() {
+    let today = NaiveDate::from_ymd_opt(2024, 10, 28).unwrap();
+    let item = PantryItem {
+        expires_on: Some(today),
+        ..pantry_item_fixture()
+    };
+
+    assert!(!item.is_expired(today));
+    assert!(item.is_expired(today + Days::new(1)));
+    assert!(!PantryItem {
+        expires_on: None,
+        ..item
+    }
+    .is_expired(today + Days::new(1)));
+}
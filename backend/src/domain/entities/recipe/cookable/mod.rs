@@ -0,0 +1,99 @@
+use chrono::NaiveDate;
+use common::CookableRecipeDTO;
+
+use crate::domain::entities::pantry::PantryItem;
+
+use super::{IngredientWithAmount, Recipe};
+
+/// A recipe, along with how much of it can be made from what's in a pantry.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CookableRecipe {
+    pub recipe: Recipe,
+    /// The share of the required ingredients the pantry has enough of, from 0 to 1
+    pub coverage: f64,
+    /// Required ingredients the pantry doesn't have enough of, with the amount that is still missing
+    pub missing: Vec<IngredientWithAmount>,
+}
+
+impl CookableRecipe {
+    /// Compares what the recipe needs with what's in the pantry. Optional ingredients are never needed.
+    /// An amount which can't be compared with the one in the pantry, e.g. grams against cups, counts as enough.
+    pub fn new(recipe: Recipe, pantry: &[PantryItem]) -> Self {
+        let required: Vec<&IngredientWithAmount> =
+            recipe.ingredients.iter().filter(|i| !i.optional).collect();
+
+        let missing: Vec<IngredientWithAmount> = required
+            .iter()
+            .filter_map(|needed| {
+                let Some(item) = pantry
+                    .iter()
+                    .find(|item| item.ingredient.id == needed.ingredient.id)
+                else {
+                    return Some((*needed).clone());
+                };
+
+                match needed.amount.minus(&item.amount) {
+                    Some(left) if left.amount() > 0.0 => Some(IngredientWithAmount {
+                        amount: left.to_kitchen_precision(),
+                        ..(*needed).clone()
+                    }),
+                    _ => None,
+                }
+            })
+            .collect();
+
+        let coverage = if required.is_empty() {
+            1.0
+        } else {
+            (required.len() - missing.len()) as f64 / required.len() as f64
+        };
+
+        Self {
+            recipe,
+            coverage,
+            missing,
+        }
+    }
+
+    /// Ranks the recipes by how completely the pantry covers them, then by how few ingredients are missing,
+    /// and then by their name. Items which have expired by today are left out of the pantry.
+    pub fn rank(recipes: Vec<Recipe>, pantry: &[PantryItem], today: NaiveDate) -> Vec<Self> {
+        let pantry: Vec<PantryItem> = pantry
+            .iter()
+            .filter(|item| !item.is_expired(today))
+            .cloned()
+            .collect();
+
+        let mut result: Vec<Self> = recipes
+            .into_iter()
+            .map(|recipe| Self::new(recipe, &pantry))
+            .collect();
+
+        result.sort_by(|a, b| {
+            b.coverage
+                .total_cmp(&a.coverage)
+                .then_with(|| a.missing.len().cmp(&b.missing.len()))
+                .then_with(|| {
+                    a.recipe
+                        .name
+                        .to_lowercase()
+                        .cmp(&b.recipe.name.to_lowercase())
+                })
+        });
+
+        result
+    }
+}
+
+impl From<CookableRecipe> for CookableRecipeDTO {
+    fn from(value: CookableRecipe) -> Self {
+        Self {
+            recipe: value.recipe.into(),
+            coverage: value.coverage,
+            missing: value.missing.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,110 @@
+use chrono::{Days, NaiveDate};
+use pretty_assertions::assert_eq;
+use uuid::Uuid;
+
+use crate::{
+    domain::entities::{
+        pantry::PantryItem,
+        recipe::{IngredientUnit, IngredientWithAmount, Recipe},
+    },
+    test_utils::{pantry_item_fixture, recipe_fixture},
+};
+
+use super::CookableRecipe;
+
+fn item(ingredient: &IngredientWithAmount, amount: IngredientUnit) -> PantryItem {
+    PantryItem {
+        ingredient: ingredient.ingredient.clone(),
+        amount,
+        ..pantry_item_fixture()
+    }
+}
+
+fn clove(amount: f64) -> IngredientUnit {
+    IngredientUnit::Other {
+        amount,
+        unit: "clove".to_string(),
+    }
+}
+
+#[test]
+fn missing_ingredients_are_listed_with_the_amount_still_needed() {
+    let recipe = recipe_fixture();
+    // Tofu, broccoli and garlic
+    let ingredients = recipe.ingredients.to_vec();
+    let pantry = vec![pantry_item_fixture(), item(&ingredients[2], clove(10.0))];
+
+    let result = CookableRecipe::new(recipe, &pantry);
+
+    assert_eq!(result.coverage, 1.0 / 3.0);
+    assert_eq!(
+        result.missing,
+        vec![
+            IngredientWithAmount {
+                amount: IngredientUnit::Grams(150.0),
+                ..ingredients[0].clone()
+            },
+            ingredients[1].clone(),
+        ]
+    );
+}
+
+#[test]
+fn optional_and_incomparable_ingredients_are_covered() {
+    let mut recipe = recipe_fixture();
+    recipe.ingredients[1].optional = true;
+    let ingredients = recipe.ingredients.to_vec();
+    let pantry = vec![
+        // The recipe needs grams, so there's no telling whether a cup is enough
+        item(&ingredients[0], IngredientUnit::Cups(1.0)),
+        item(&ingredients[2], clove(4.0)),
+    ];
+
+    let result = CookableRecipe::new(recipe, &pantry);
+
+    assert_eq!(result.coverage, 1.0);
+    assert!(result.missing.is_empty());
+}
+
+#[test]
+fn recipes_are_ranked_by_coverage_without_expired_items() {
+    let today = NaiveDate::from_ymd_opt(2024, 10, 28).unwrap();
+    let full = recipe_fixture();
+    let ingredients = full.ingredients.to_vec();
+    let only_garlic = Recipe {
+        id: Uuid::from_u128(1),
+        name: "Garlic on its own".to_string(),
+        ingredients: vec![ingredients[2].clone()].try_into().unwrap(),
+        ..recipe_fixture()
+    };
+    let only_tofu = Recipe {
+        id: Uuid::from_u128(2),
+        name: "Tofu on its own".to_string(),
+        ingredients: vec![ingredients[0].clone()].try_into().unwrap(),
+        ..recipe_fixture()
+    };
+    let pantry = vec![
+        PantryItem {
+            expires_on: Some(today - Days::new(1)),
+            ..item(&ingredients[0], IngredientUnit::Grams(1000.0))
+        },
+        PantryItem {
+            expires_on: Some(today),
+            ..item(&ingredients[2], clove(4.0))
+        },
+    ];
+
+    let result = CookableRecipe::rank(vec![only_tofu, full, only_garlic], &pantry, today);
+
+    assert_eq!(
+        result
+            .iter()
+            .map(|r| (r.recipe.id, r.coverage))
+            .collect::<Vec<_>>(),
+        vec![
+            (Uuid::from_u128(1), 1.0),
+            (recipe_fixture().id, 1.0 / 3.0),
+            (Uuid::from_u128(2), 0.0),
+        ]
+    );
+}
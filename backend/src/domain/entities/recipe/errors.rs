@@ -14,6 +14,9 @@ pub enum ValidationError {
     #[error("Failed to compute measurement from the following string: {0}")]
     MeasurementComputation(String),
 
+    #[error("Cannot convert {0} to {1}")]
+    IncompatibleUnits(String, String),
+
     #[error(transparent)]
     Unknown(#[from] eyre::Error),
 }
@@ -23,6 +26,8 @@ impl From<IngredientValidationError> for ValidationError {
         match value {
             IngredientValidationError::EmptyField(e) => Self::EmptyField(e),
             IngredientValidationError::DoesNotMatch(a, b) => Self::DoesNotMatch(a, b),
+            e @ (IngredientValidationError::NotPositive(_)
+            | IngredientValidationError::Negative(_)) => Self::Unknown(eyre::eyre!(e.to_string())),
             IngredientValidationError::Unknown(e) => Self::Unknown(e),
         }
     }
@@ -0,0 +1,434 @@
+//! [Cooklang](https://cooklang.org/docs/spec/), where the ingredients, cookware and timers are marked up right in the steps.
+
+use std::{collections::BTreeMap, time::Duration};
+
+use regex::{Regex, RegexBuilder};
+
+use crate::domain::entities::recipe::{units::split_amount, IngredientUnit, Recipe, ServingsType};
+
+use super::{
+    describe_duration, describe_servings, parse_servings, unit_name, ImportedRecipe,
+    IngredientLine, COOK_TIME, PIECES, PREP_TIME, TOTAL_TIME,
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum CooklangError {
+    #[error("The recipe does not have a title, add one with `>> title: ...`")]
+    MissingTitle,
+}
+
+/// Something marked up in a step, like `@ingredient{1%cup}(chopped)`, `#cookware{}` or `~timer{10%minutes}`.
+struct Component<'a> {
+    name: &'a str,
+    quantity: Option<&'a str>,
+    notes: Option<&'a str>,
+    /// How much of the text the component takes up, including the sigil
+    length: usize,
+}
+
+impl<'a> Component<'a> {
+    /// Names run until the braces if there are any before the next component or the end of the line,
+    /// otherwise they're a single word.
+    fn read(sigil: char, text: &'a str) -> Option<Self> {
+        let rest = &text[sigil.len_utf8()..];
+        let next = rest.find(['@', '#', '~', '\n']).unwrap_or(rest.len());
+
+        let (name, mut length) = match rest[..next].find('{') {
+            Some(brace) => {
+                let close = brace + rest[brace..].find('}')?;
+
+                (&rest[..close + 1], close + 1)
+            }
+            None => {
+                let end = rest
+                    .find(|c: char| !(c.is_alphanumeric() || c == '_' || c == '-'))
+                    .unwrap_or(rest.len());
+
+                (&rest[..end], end)
+            }
+        };
+
+        let (name, quantity) = match name.split_once('{') {
+            Some((name, quantity)) => (name.trim(), Some(quantity.trim_end_matches('}').trim())),
+            None => (name, None),
+        };
+
+        let notes = match (sigil, rest[length..].strip_prefix('(')) {
+            ('@', Some(notes)) => notes.find(')').map(|end| {
+                length += end + 2;
+                notes[..end].trim()
+            }),
+            _ => None,
+        };
+
+        if name.is_empty() && quantity.is_none() {
+            return None;
+        }
+
+        Some(Self {
+            name,
+            quantity: quantity.filter(|q| !q.is_empty()),
+            notes,
+            length: length + sigil.len_utf8(),
+        })
+    }
+
+    /// The quantity split into the amount and the unit, like `1/2` and `cup`.
+    fn amount_and_unit(&self) -> Option<(&'a str, Option<&'a str>)> {
+        let quantity = self.quantity?;
+
+        Some(match quantity.split_once('%') {
+            Some((amount, unit)) => (amount.trim(), Some(unit.trim())),
+            None => (quantity, None),
+        })
+    }
+}
+
+/// Removes both `-- line comments` and `[- block comments -]`.
+fn strip_comments(input: &str) -> String {
+    let mut text = input.to_string();
+
+    while let Some(start) = text.find("[-") {
+        let end = text[start..]
+            .find("-]")
+            .map_or(text.len(), |end| start + end + 2);
+        text.replace_range(start..end, "");
+    }
+
+    text.lines()
+        .map(|line| line.find("--").map_or(line, |start| &line[..start]))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Splits the metadata, given as front matter or `>> key: value` lines, from the rest of the recipe.
+fn split_metadata(input: &str) -> (BTreeMap<String, String>, String) {
+    let mut metadata = BTreeMap::new();
+    let mut body = vec![];
+    let mut add = |line: &str| {
+        if let Some((key, value)) = line.split_once(':') {
+            metadata.insert(key.trim().to_lowercase(), value.trim().to_string());
+        }
+    };
+
+    let mut lines = input.lines().peekable();
+
+    if lines.peek().is_some_and(|line| line.trim() == "---") {
+        lines.next();
+        for line in lines.by_ref() {
+            if line.trim() == "---" {
+                break;
+            }
+            add(line);
+        }
+    }
+
+    for line in lines {
+        match line.trim_start().strip_prefix(">>") {
+            Some(line) => add(line),
+            None => body.push(line),
+        }
+    }
+
+    (metadata, body.join("\n"))
+}
+
+/// Reads durations like `15 minutes`, `1 h 30 min` or just `15`, which is taken to be in minutes.
+pub fn parse_time(input: &str) -> Option<Duration> {
+    let mut seconds = 0.0;
+    let mut rest = input.trim();
+
+    if rest.is_empty() {
+        return None;
+    }
+
+    while !rest.is_empty() {
+        let number_end = rest
+            .find(|c: char| !(c.is_ascii_digit() || c == '.' || c == ',' || c == '/'))
+            .unwrap_or(rest.len());
+        let (amount, _) = split_amount(&rest[..number_end])?;
+
+        rest = rest[number_end..].trim_start();
+        let unit_end = rest
+            .find(|c: char| !c.is_alphabetic())
+            .unwrap_or(rest.len());
+
+        let multiplier = match rest[..unit_end].to_lowercase().as_str() {
+            "d" | "day" | "days" => 86_400.0,
+            "h" | "hr" | "hrs" | "hour" | "hours" => 3_600.0,
+            "" | "m" | "min" | "mins" | "minute" | "minutes" => 60.0,
+            "s" | "sec" | "secs" | "second" | "seconds" => 1.0,
+            _ => return None,
+        };
+
+        seconds += amount * multiplier;
+        rest = rest[unit_end..].trim_start_matches([' ', ',']);
+    }
+
+    Duration::try_from_secs_f64(seconds).ok()
+}
+
+/// Where the time given in the metadata ends up, if the key is about time at all.
+fn time_name(key: &str) -> Option<String> {
+    let name = match key {
+        "prep time" | "prep_time" | "time.prep" => PREP_TIME.to_string(),
+        "cook time" | "cook_time" | "time.cook" => COOK_TIME.to_string(),
+        "time" | "total time" | "total_time" | "duration" => TOTAL_TIME.to_string(),
+        key if key.ends_with(" time") => {
+            let mut chars = key.chars();
+            chars.next()?.to_uppercase().chain(chars).collect()
+        }
+        _ => return None,
+    };
+
+    Some(name)
+}
+
+/// Reads a recipe, turning the marked up ingredients into the ingredients of the recipe
+/// and everything else into plain text.
+/// Ingredients without an amount are reported as unparsed, unless they're mentioned elsewhere with one.
+/// Timers count towards the cooking time, unless the metadata says how long the recipe takes.
+pub fn parse(input: &str) -> Result<ImportedRecipe, CooklangError> {
+    let (metadata, body) = split_metadata(input);
+    let body = strip_comments(&body);
+
+    let mut ingredients: Vec<IngredientLine> = vec![];
+    let mut unparsed: Vec<(String, String)> = vec![];
+    let mut timers = Duration::ZERO;
+    let mut steps = vec![];
+
+    for paragraph in body.split("\n\n") {
+        let paragraph = paragraph
+            .lines()
+            .map(str::trim)
+            // Sections and notes
+            .filter(|line| !line.starts_with('=') && !line.starts_with('>'))
+            .filter(|line| !line.is_empty())
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        let mut step = String::new();
+        let mut rest = paragraph.as_str();
+
+        while let Some(start) = rest.find(['@', '#', '~']) {
+            step.push_str(&rest[..start]);
+            let sigil = rest[start..].chars().next().unwrap_or('@');
+
+            let Some(component) = Component::read(sigil, &rest[start..]) else {
+                step.push(sigil);
+                rest = &rest[start + sigil.len_utf8()..];
+                continue;
+            };
+            let markup = &rest[start..start + component.length];
+
+            match sigil {
+                '@' => {
+                    step.push_str(component.name);
+
+                    match ingredient(&component) {
+                        Some(line) => ingredients.push(line),
+                        None => unparsed.push((component.name.to_lowercase(), markup.to_string())),
+                    }
+                }
+                '~' => match component.amount_and_unit() {
+                    Some((amount, unit)) => {
+                        let time = format!("{} {}", amount, unit.unwrap_or_default());
+                        timers = timers.saturating_add(parse_time(&time).unwrap_or_default());
+                        step.push_str(time.trim());
+                    }
+                    None => step.push_str(component.name),
+                },
+                _ => step.push_str(component.name),
+            }
+
+            rest = &rest[start + component.length..];
+        }
+
+        step.push_str(rest);
+
+        let step = step.split_whitespace().collect::<Vec<_>>().join(" ");
+        if !step.is_empty() {
+            steps.push(step);
+        }
+    }
+
+    let name = metadata
+        .get("title")
+        .or_else(|| metadata.get("name"))
+        .filter(|title| !title.is_empty())
+        .ok_or(CooklangError::MissingTitle)?
+        .to_string();
+
+    let mut time: BTreeMap<String, Duration> = metadata
+        .iter()
+        .filter_map(|(key, value)| Some((time_name(key)?, parse_time(value)?)))
+        .collect();
+
+    if time.is_empty() && !timers.is_zero() {
+        time.insert(COOK_TIME.to_string(), timers);
+    }
+
+    let unparsed_ingredients = unparsed
+        .into_iter()
+        .filter(|(name, _)| !ingredients.iter().any(|i| &i.name.to_lowercase() == name))
+        .map(|(_, markup)| markup)
+        .collect();
+
+    Ok(ImportedRecipe {
+        name,
+        description: metadata.get("description").cloned().unwrap_or_default(),
+        steps,
+        time,
+        servings: ["servings", "serves", "yield"]
+            .iter()
+            .find_map(|key| metadata.get(*key))
+            .and_then(|servings| parse_servings(servings))
+            .unwrap_or(ServingsType::Exact(1)),
+        ingredients,
+        unparsed_ingredients,
+    })
+}
+
+fn ingredient(component: &Component) -> Option<IngredientLine> {
+    let (amount, unit) = component.amount_and_unit()?;
+    let (amount, rest) = split_amount(amount)?;
+
+    if !rest.is_empty() || component.name.is_empty() {
+        return None;
+    }
+
+    let unit = unit.filter(|unit| !unit.is_empty()).unwrap_or(PIECES);
+    let amount = IngredientUnit::from_amount(amount, unit)?;
+
+    let mut notes: Vec<&str> = component
+        .notes
+        .into_iter()
+        .flat_map(|notes| notes.split(", "))
+        .map(str::trim)
+        .filter(|note| !note.is_empty())
+        .collect();
+    let optional = notes
+        .iter()
+        .any(|note| note.eq_ignore_ascii_case("optional"));
+    notes.retain(|note| !note.eq_ignore_ascii_case("optional"));
+
+    Some(IngredientLine {
+        name: component.name.to_string(),
+        amount,
+        notes: (!notes.is_empty()).then(|| notes.join(", ")),
+        optional,
+    })
+}
+
+/// The ingredient marked up under the given name, like `@garlic{4%cloves}(minced)`.
+fn markup(name: &str, line: &IngredientLine) -> String {
+    let mut markup = format!("@{}{{{}", name, line.amount.amount());
+    if let Some(unit) = unit_name(&line.amount) {
+        markup.push('%');
+        markup.push_str(unit);
+    }
+    markup.push('}');
+
+    let notes: Vec<&str> = line
+        .optional
+        .then_some("optional")
+        .into_iter()
+        .chain(line.notes.as_deref())
+        .collect();
+    if !notes.is_empty() {
+        markup.push_str(&format!("({})", notes.join(", ")));
+    }
+
+    markup
+}
+
+fn whole_word(name: &str) -> Option<Regex> {
+    RegexBuilder::new(&format!(r"\b{}\b", regex::escape(name)))
+        .case_insensitive(true)
+        .build()
+        .ok()
+}
+
+/// Renders the recipe the way it's read back by [`parse`].
+/// Every ingredient is marked up where it's first mentioned in the steps, keeping the wording of the step.
+/// Ingredients the steps never mention are gathered in a step of their own, before all the others.
+pub fn render(recipe: &Recipe) -> String {
+    let mut document = format!(">> title: {}\n", recipe.name);
+
+    let description = recipe.description.split_whitespace().collect::<Vec<_>>();
+    if !description.is_empty() {
+        document.push_str(&format!(">> description: {}\n", description.join(" ")));
+    }
+    document.push_str(&format!(
+        ">> servings: {}\n",
+        describe_servings(&recipe.servings)
+    ));
+    for (name, duration) in &recipe.time {
+        document.push_str(&format!(
+            ">> {}: {}\n",
+            name.to_lowercase(),
+            describe_duration(duration)
+        ));
+    }
+
+    let steps = recipe.steps.as_ref();
+    // Step, start and end of every mention which is going to be marked up
+    let mut mentions: Vec<(usize, usize, usize, String)> = vec![];
+    let mut unmentioned = vec![];
+
+    for ingredient in recipe.ingredients.iter() {
+        let line = IngredientLine::from(ingredient);
+
+        let mention = whole_word(&line.name).and_then(|pattern| {
+            steps.iter().enumerate().find_map(|(index, step)| {
+                pattern
+                    .find_iter(step)
+                    .find(|found| {
+                        !mentions.iter().any(|(i, start, end, _)| {
+                            *i == index && found.start() < *end && *start < found.end()
+                        })
+                    })
+                    .map(|found| {
+                        (
+                            index,
+                            found.start(),
+                            found.end(),
+                            markup(found.as_str(), &line),
+                        )
+                    })
+            })
+        });
+
+        match mention {
+            Some(mention) => mentions.push(mention),
+            None => unmentioned.push(markup(&line.name, &line)),
+        }
+    }
+
+    mentions.sort_by_key(|(index, start, ..)| (*index, std::cmp::Reverse(*start)));
+
+    let mut steps: Vec<String> = steps.to_vec();
+    for (index, start, end, markup) in mentions {
+        steps[index].replace_range(start..end, &markup);
+    }
+
+    if !unmentioned.is_empty() {
+        let gathered = match unmentioned.as_slice() {
+            [first] => first.clone(),
+            [rest @ .., last] => format!("{} and {}", rest.join(", "), last),
+            [] => String::new(),
+        };
+        steps.insert(0, format!("Gather {gathered}."));
+    }
+
+    for step in steps {
+        document.push('\n');
+        document.push_str(step.trim());
+        document.push('\n');
+    }
+
+    document
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,221 @@
+use std::time::Duration;
+
+use pretty_assertions::assert_eq;
+
+use crate::{
+    domain::entities::recipe::{formats::tests::assert_golden, IngredientUnit, ServingsType},
+    test_utils::recipe_fixture,
+};
+
+use super::{parse, parse_time, render, CooklangError, COOK_TIME, PIECES, PREP_TIME};
+
+const RECIPE: &str = r#"---
+title: Garlic Butter Pasta
+servings: 2-3
+---
+>> description: Pasta for when there's nothing in the fridge.
+>> prep time: 5 minutes
+
+-- The water needs to be really salty
+Bring @water{2%l} to a boil with @salt in a #large pot{}.
+
+= Pasta
+
+Add @spaghetti{200%g} and cook for ~{9%minutes}.
+Meanwhile, melt @butter{2%tbsp} [- or more -] in a #pan.
+
+Add @garlic cloves{3}(minced) and @chili flakes{1%pinch}(optional).
+> Don't let the garlic burn.
+Toss everything together, season with @salt{1%tsp}.
+"#;
+
+#[test]
+fn parsing_a_recipe_works() {
+    let recipe = parse(RECIPE).unwrap();
+
+    assert_eq!(recipe.name, "Garlic Butter Pasta");
+    assert_eq!(
+        recipe.description,
+        "Pasta for when there's nothing in the fridge."
+    );
+    assert_eq!(recipe.servings, ServingsType::FromTo(2, 3));
+    assert_eq!(
+        recipe.time.into_iter().collect::<Vec<_>>(),
+        vec![(PREP_TIME.to_string(), Duration::from_secs(5 * 60))]
+    );
+    assert_eq!(
+        recipe.steps,
+        vec![
+            "Bring water to a boil with salt in a large pot.",
+            "Add spaghetti and cook for 9 minutes. Meanwhile, melt butter in a pan.",
+            "Add garlic cloves and chili flakes. Toss everything together, season with salt.",
+        ]
+    );
+    assert!(recipe.unparsed_ingredients.is_empty());
+
+    let ingredients: Vec<_> = recipe
+        .ingredients
+        .iter()
+        .map(|i| {
+            (
+                i.name.as_str(),
+                i.amount.clone(),
+                i.notes.as_deref(),
+                i.optional,
+            )
+        })
+        .collect();
+
+    assert_eq!(
+        ingredients,
+        vec![
+            ("water", IngredientUnit::Mililiters(2000.0), None, false),
+            ("spaghetti", IngredientUnit::Grams(200.0), None, false),
+            ("butter", IngredientUnit::Teaspoons(6.0), None, false),
+            (
+                "garlic cloves",
+                IngredientUnit::Other {
+                    amount: 3.0,
+                    unit: PIECES.to_string()
+                },
+                Some("minced"),
+                false
+            ),
+            (
+                "chili flakes",
+                IngredientUnit::Other {
+                    amount: 1.0,
+                    unit: "pinch".to_string()
+                },
+                None,
+                true
+            ),
+            ("salt", IngredientUnit::Teaspoons(1.0), None, false),
+        ]
+    );
+}
+
+#[test]
+fn ingredients_without_an_amount_are_kept_aside() {
+    let recipe =
+        parse(">> title: Toast\n\nToast the @bread, then spread @butter{} on it.").unwrap();
+
+    assert!(recipe.ingredients.is_empty());
+    assert_eq!(recipe.unparsed_ingredients, vec!["@bread", "@butter{}"]);
+    assert_eq!(
+        recipe.steps,
+        vec!["Toast the bread, then spread butter on it."]
+    );
+}
+
+#[test]
+fn timers_count_towards_the_cooking_time() {
+    let recipe =
+        parse(">> title: Eggs\n\nBoil the @eggs{2} for ~{7%minutes}, then cool them for ~{1%min}.")
+            .unwrap();
+
+    assert_eq!(
+        recipe.time.into_iter().collect::<Vec<_>>(),
+        vec![(COOK_TIME.to_string(), Duration::from_secs(8 * 60))]
+    );
+    assert_eq!(recipe.servings, ServingsType::Exact(1));
+    assert_eq!(
+        recipe.steps,
+        vec!["Boil the eggs for 7 minutes, then cool them for 1 min."]
+    );
+}
+
+#[test]
+fn overflowing_timers_are_skipped() {
+    let recipe = parse(
+        ">> title: Eggs\n\nBoil the @eggs{2} for ~{99999999999999999999%hours}, then cool them for ~{1%min}.",
+    )
+    .unwrap();
+
+    assert_eq!(
+        recipe.time.into_iter().collect::<Vec<_>>(),
+        vec![(COOK_TIME.to_string(), Duration::from_secs(60))]
+    );
+}
+
+#[test]
+fn ingredients_with_amounts_which_are_not_finite_are_unparsed() {
+    let recipe =
+        parse(">> title: Bread\n\nMix @flour{1e306%kg} with @water{1e308 1e308/1%ml}.").unwrap();
+
+    assert!(recipe.ingredients.is_empty());
+    assert_eq!(
+        recipe.unparsed_ingredients,
+        vec!["@flour{1e306%kg}", "@water{1e308 1e308/1%ml}"]
+    );
+}
+
+#[test]
+fn parsing_a_recipe_without_a_title_fails() {
+    let result = parse("Boil @water{1%l}.");
+
+    assert!(matches!(result, Err(CooklangError::MissingTitle)));
+}
+
+#[test]
+fn parsing_times_works() {
+    let cases = [
+        ("15 minutes", Some(15 * 60)),
+        ("15", Some(15 * 60)),
+        ("1 h 30 min", Some(90 * 60)),
+        ("1 hour, 30 minutes", Some(90 * 60)),
+        ("1/2 hour", Some(30 * 60)),
+        ("90 s", Some(90)),
+        ("2 days", Some(2 * 86_400)),
+        ("", None),
+        ("a while", None),
+        ("15 fortnights", None),
+        ("99999999999999999999 hours", None),
+    ];
+
+    for (input, expected) in cases {
+        assert_eq!(
+            parse_time(input),
+            expected.map(Duration::from_secs),
+            "parsing {input}"
+        );
+    }
+}
+
+#[test]
+fn rendering_a_recipe_matches_the_golden_file() {
+    assert_golden("recipe.cook", &render(&recipe_fixture()));
+}
+
+#[test]
+fn rendered_recipes_can_be_imported_again() {
+    let recipe = recipe_fixture();
+    let imported = parse(&render(&recipe)).unwrap();
+
+    assert_eq!(imported.name, recipe.name);
+    assert_eq!(imported.description, recipe.description);
+    assert_eq!(imported.servings, recipe.servings);
+    assert_eq!(imported.time, recipe.time);
+    assert!(imported.unparsed_ingredients.is_empty());
+
+    let mut imported: Vec<_> = imported
+        .ingredients
+        .iter()
+        .map(|i| (i.name.to_lowercase(), i.amount.clone(), i.optional))
+        .collect();
+    let mut expected: Vec<_> = recipe
+        .ingredients
+        .iter()
+        .map(|i| {
+            (
+                i.ingredient.name.0.to_lowercase(),
+                i.amount.clone(),
+                i.optional,
+            )
+        })
+        .collect();
+    imported.sort_by(|a, b| a.0.cmp(&b.0));
+    expected.sort_by(|a, b| a.0.cmp(&b.0));
+
+    assert_eq!(imported, expected);
+}
@@ -0,0 +1,310 @@
+//! [schema.org/Recipe](https://schema.org/Recipe) as embedded by most recipe websites.
+
+use std::{collections::BTreeMap, time::Duration};
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde_json::{json, Map, Value};
+
+use crate::domain::entities::recipe::{Recipe, ServingsType};
+
+use super::{
+    describe_servings, parse_servings, ImportedRecipe, IngredientLine, COOK_TIME, PREP_TIME,
+    TOTAL_TIME,
+};
+
+/// The patterns are fixed, so once the tests pass they can't fail to compile.
+#[allow(clippy::unwrap_used)]
+fn pattern(pattern: &str) -> Regex {
+    Regex::new(pattern).unwrap()
+}
+
+static SCRIPT: Lazy<Regex> = Lazy::new(|| {
+    pattern(r#"(?is)<script[^>]*type\s*=\s*["']?application/ld\+json["']?[^>]*>(.*?)</script>"#)
+});
+static TAG: Lazy<Regex> = Lazy::new(|| pattern(r"<[^>]*>"));
+static NUMERIC_ENTITY: Lazy<Regex> = Lazy::new(|| pattern(r"&#(x?)([0-9a-fA-F]+);"));
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum JsonLdError {
+    #[error("The document is not valid JSON: {0}")]
+    InvalidJson(#[from] serde_json::Error),
+
+    #[error("The document does not contain a schema.org recipe")]
+    RecipeNotFound,
+
+    #[error("The recipe does not have a name")]
+    MissingName,
+}
+
+/// Reads the recipe out of a JSON-LD document, or out of an HTML page embedding one.
+/// Recipes without any servings given are assumed to make a single serving.
+pub fn parse(input: &str) -> Result<ImportedRecipe, JsonLdError> {
+    let documents: Vec<Value> = if input.trim_start().starts_with(['{', '[']) {
+        vec![serde_json::from_str(input)?]
+    } else {
+        // Pages often embed several documents, and not all of them are necessarily valid
+        SCRIPT
+            .captures_iter(input)
+            .filter_map(|captures| serde_json::from_str(&captures[1]).ok())
+            .collect()
+    };
+
+    let recipe = documents
+        .iter()
+        .find_map(find_recipe)
+        .ok_or(JsonLdError::RecipeNotFound)?;
+
+    let name = recipe
+        .get("name")
+        .and_then(Value::as_str)
+        .map(clean_text)
+        .filter(|name| !name.is_empty())
+        .ok_or(JsonLdError::MissingName)?;
+
+    let lines: Vec<String> = recipe
+        .get("recipeIngredient")
+        .or_else(|| recipe.get("ingredients"))
+        .map(texts)
+        .unwrap_or_default();
+    let (ingredients, unparsed_ingredients) =
+        ImportedRecipe::parse_ingredients(lines.iter().map(String::as_str));
+
+    Ok(ImportedRecipe {
+        name,
+        description: recipe
+            .get("description")
+            .and_then(Value::as_str)
+            .map(clean_text)
+            .unwrap_or_default(),
+        steps: recipe
+            .get("recipeInstructions")
+            .map(steps)
+            .unwrap_or_default(),
+        time: time(recipe),
+        servings: recipe
+            .get("recipeYield")
+            .and_then(servings)
+            .unwrap_or(ServingsType::Exact(1)),
+        ingredients,
+        unparsed_ingredients,
+    })
+}
+
+/// Renders the recipe the way it's read back by [`parse`].
+/// Only the preparation and cooking time have a place of their own, though every entry counts towards the total time.
+pub fn render(recipe: &Recipe) -> Value {
+    let mut document = Map::new();
+    let mut set = |key: &str, value: Value| document.insert(key.to_string(), value);
+
+    set("@context", json!("https://schema.org"));
+    set("@type", json!("Recipe"));
+    set("identifier", json!(recipe.id));
+    set("name", json!(recipe.name));
+    set("description", json!(recipe.description));
+    set("dateCreated", json!(recipe.created_at.to_rfc3339()));
+    set("dateModified", json!(recipe.updated_at.to_rfc3339()));
+    set("recipeYield", json!(describe_servings(&recipe.servings)));
+
+    for (name, key) in [(PREP_TIME, "prepTime"), (COOK_TIME, "cookTime")] {
+        if let Some(duration) = recipe.time.get(name) {
+            set(key, json!(format_duration(duration)));
+        }
+    }
+    set("totalTime", json!(format_duration(&recipe.total_time())));
+
+    set(
+        "recipeIngredient",
+        recipe
+            .ingredients
+            .iter()
+            .map(|i| IngredientLine::from(i).to_string())
+            .collect(),
+    );
+    set(
+        "recipeInstructions",
+        recipe
+            .steps
+            .as_ref()
+            .iter()
+            .map(|step| json!({ "@type": "HowToStep", "text": step }))
+            .collect(),
+    );
+
+    Value::Object(document)
+}
+
+fn is_recipe(kind: &Value) -> bool {
+    match kind {
+        Value::String(kind) => {
+            kind == "Recipe" || kind.ends_with("/Recipe") || kind == "schema:Recipe"
+        }
+        Value::Array(kinds) => kinds.iter().any(is_recipe),
+        _ => false,
+    }
+}
+
+/// The recipe can be the document itself, or be hidden in a list or a graph of other entities.
+fn find_recipe(value: &Value) -> Option<&Value> {
+    match value {
+        Value::Array(items) => items.iter().find_map(find_recipe),
+        Value::Object(map) if map.get("@type").is_some_and(is_recipe) => Some(value),
+        Value::Object(map) => ["@graph", "mainEntity"]
+            .iter()
+            .filter_map(|key| map.get(*key))
+            .find_map(find_recipe),
+        _ => None,
+    }
+}
+
+/// Drops any markup and decodes the most common entities.
+fn clean_text(input: &str) -> String {
+    let text = TAG.replace_all(input, " ");
+    let text = NUMERIC_ENTITY.replace_all(&text, |captures: &regex::Captures| {
+        let radix = if captures[1].is_empty() { 10 } else { 16 };
+
+        u32::from_str_radix(&captures[2], radix)
+            .ok()
+            .and_then(char::from_u32)
+            .map(String::from)
+            .unwrap_or_default()
+    });
+
+    text.replace("&nbsp;", " ")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&amp;", "&")
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// A single text, or a list of them.
+fn texts(value: &Value) -> Vec<String> {
+    match value {
+        Value::String(text) => vec![clean_text(text)],
+        Value::Array(items) => items.iter().flat_map(texts).collect(),
+        _ => vec![],
+    }
+}
+
+/// Instructions can be a single text with a step on every line, a list of texts,
+/// or a list of `HowToStep`s which might be grouped into `HowToSection`s.
+fn steps(value: &Value) -> Vec<String> {
+    let steps = match value {
+        Value::String(text) => text.lines().map(clean_text).collect(),
+        Value::Array(items) => items.iter().flat_map(steps).collect(),
+        Value::Object(map) => match map.get("itemListElement") {
+            Some(items) => steps(items),
+            None => map
+                .get("text")
+                .or_else(|| map.get("name"))
+                .map(steps)
+                .unwrap_or_default(),
+        },
+        _ => vec![],
+    };
+
+    steps.into_iter().filter(|step| !step.is_empty()).collect()
+}
+
+/// Either a number, or a text like `4 servings` or `4-6 people`. Lists hold the same yield written in different ways.
+fn servings(value: &Value) -> Option<ServingsType> {
+    match value {
+        Value::Number(number) => number
+            .as_f64()
+            .map(|servings| ServingsType::Exact(servings.round() as u16)),
+        Value::String(text) => parse_servings(text),
+        Value::Array(items) => items.iter().find_map(servings),
+        _ => None,
+    }
+}
+
+/// The total time is only used when the recipe doesn't say how it's split up.
+fn time(recipe: &Value) -> BTreeMap<String, Duration> {
+    let duration = |key: &str| {
+        recipe
+            .get(key)
+            .and_then(Value::as_str)
+            .and_then(parse_duration)
+            .filter(|duration| !duration.is_zero())
+    };
+
+    let mut time: BTreeMap<String, Duration> = [(PREP_TIME, "prepTime"), (COOK_TIME, "cookTime")]
+        .into_iter()
+        .filter_map(|(name, key)| Some((name.to_string(), duration(key)?)))
+        .collect();
+
+    if time.is_empty() {
+        if let Some(total) = duration("totalTime") {
+            time.insert(TOTAL_TIME.to_string(), total);
+        }
+    }
+
+    time
+}
+
+/// Parses ISO 8601 durations, like `PT1H30M` or `P1DT2H`.
+/// Years and months are rejected, as they don't have a fixed length.
+pub fn parse_duration(input: &str) -> Option<Duration> {
+    let input = input.trim().strip_prefix('P')?;
+    let (date, time) = input.split_once('T').unwrap_or((input, ""));
+
+    let mut seconds = 0.0;
+    let mut any = false;
+
+    for (part, units) in [
+        (date, &[('W', 604_800.0), ('D', 86_400.0)][..]),
+        (time, &[('H', 3_600.0), ('M', 60.0), ('S', 1.0)][..]),
+    ] {
+        let mut number = String::new();
+
+        for c in part.chars() {
+            if c.is_ascii_digit() || c == '.' || c == ',' {
+                number.push(if c == ',' { '.' } else { c });
+                continue;
+            }
+
+            let (_, multiplier) = units.iter().find(|(unit, _)| *unit == c)?;
+            let amount: f64 = number.parse().ok()?;
+
+            seconds += amount * multiplier;
+            any = true;
+            number.clear();
+        }
+
+        if !number.is_empty() {
+            return None;
+        }
+    }
+
+    if !any {
+        return None;
+    }
+
+    Duration::try_from_secs_f64(seconds).ok()
+}
+
+/// Writes an ISO 8601 duration, like `PT1H30M`.
+pub fn format_duration(duration: &Duration) -> String {
+    let seconds = duration.as_secs();
+    let (hours, minutes, seconds) = (seconds / 3600, seconds % 3600 / 60, seconds % 60);
+
+    let mut result = "PT".to_string();
+    if hours > 0 {
+        result.push_str(&format!("{hours}H"));
+    }
+    if minutes > 0 {
+        result.push_str(&format!("{minutes}M"));
+    }
+    if seconds > 0 || (hours == 0 && minutes == 0) {
+        result.push_str(&format!("{seconds}S"));
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests;
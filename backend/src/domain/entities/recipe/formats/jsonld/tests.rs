@@ -0,0 +1,237 @@
+use std::{collections::BTreeMap, time::Duration};
+
+use pretty_assertions::assert_eq;
+
+use crate::{
+    domain::entities::recipe::{
+        formats::tests::assert_golden, IngredientUnit, Recipe, ServingsType,
+    },
+    test_utils::recipe_fixture,
+};
+
+use super::{
+    format_duration, parse, parse_duration, render, JsonLdError, COOK_TIME, PREP_TIME, TOTAL_TIME,
+};
+
+const RECIPE: &str = r#"{
+    "@context": "https://schema.org",
+    "@type": "Recipe",
+    "name": "Hoisin Tofu &amp; Broccoli",
+    "description": "<p>A quick weeknight stir fry.</p>",
+    "recipeYield": ["4", "4 servings"],
+    "prepTime": "PT15M",
+    "cookTime": "PT10M",
+    "totalTime": "PT25M",
+    "recipeIngredient": [
+        "400 g firm tofu",
+        "1 head broccoli",
+        "Salt, to taste"
+    ],
+    "recipeInstructions": [
+        {
+            "@type": "HowToSection",
+            "name": "Tofu",
+            "itemListElement": [
+                { "@type": "HowToStep", "text": "Cut the tofu into cubes." },
+                { "@type": "HowToStep", "text": "Fry until golden brown." }
+            ]
+        },
+        { "@type": "HowToStep", "text": "Add the broccoli." }
+    ]
+}"#;
+
+#[test]
+fn parsing_a_recipe_works() {
+    let recipe = parse(RECIPE).unwrap();
+
+    assert_eq!(recipe.name, "Hoisin Tofu & Broccoli");
+    assert_eq!(recipe.description, "A quick weeknight stir fry.");
+    assert_eq!(recipe.servings, ServingsType::Exact(4));
+    assert_eq!(
+        recipe.time.into_iter().collect::<Vec<_>>(),
+        vec![
+            (COOK_TIME.to_string(), Duration::from_secs(10 * 60)),
+            (PREP_TIME.to_string(), Duration::from_secs(15 * 60)),
+        ]
+    );
+    assert_eq!(
+        recipe.steps,
+        vec![
+            "Cut the tofu into cubes.",
+            "Fry until golden brown.",
+            "Add the broccoli."
+        ]
+    );
+    assert_eq!(
+        recipe
+            .ingredients
+            .iter()
+            .map(|i| (i.name.as_str(), i.amount.clone()))
+            .collect::<Vec<_>>(),
+        vec![
+            ("firm tofu", IngredientUnit::Grams(400.0)),
+            (
+                "broccoli",
+                IngredientUnit::Other {
+                    amount: 1.0,
+                    unit: "head".to_string()
+                }
+            ),
+        ]
+    );
+    assert_eq!(recipe.unparsed_ingredients, vec!["Salt, to taste"]);
+}
+
+#[test]
+fn parsing_a_recipe_embedded_in_html_works() {
+    let html = format!(
+        r#"<html><head>
+            <script type="application/ld+json">{{ "@type": "WebSite", "name": "Recipes" }}</script>
+            <script type="application/ld+json">not json at all</script>
+            <script type='application/ld+json'>
+                {{ "@context": "https://schema.org", "@graph": [{{ "@type": "WebPage" }}, {RECIPE}] }}
+            </script>
+        </head><body></body></html>"#
+    );
+
+    let recipe = parse(&html).unwrap();
+
+    assert_eq!(recipe.name, "Hoisin Tofu & Broccoli");
+    assert_eq!(recipe.ingredients.len(), 2);
+}
+
+#[test]
+fn parsing_loosely_structured_recipes_works() {
+    let recipe = parse(
+        r#"[{
+            "@type": ["Recipe", "NewsArticle"],
+            "name": "Pancakes",
+            "recipeYield": "4-6 pancakes",
+            "totalTime": "PT1H",
+            "recipeIngredient": "2 eggs",
+            "recipeInstructions": "Mix everything.\nFry.\n"
+        }]"#,
+    )
+    .unwrap();
+
+    assert_eq!(recipe.servings, ServingsType::FromTo(4, 6));
+    assert_eq!(
+        recipe.time.into_iter().collect::<Vec<_>>(),
+        vec![(TOTAL_TIME.to_string(), Duration::from_secs(60 * 60))]
+    );
+    assert_eq!(recipe.steps, vec!["Mix everything.", "Fry."]);
+    assert_eq!(recipe.ingredients[0].name, "eggs");
+    assert_eq!(recipe.description, "");
+}
+
+#[test]
+fn parsing_documents_without_a_recipe_fails() {
+    assert!(matches!(
+        parse(r#"{ "@type": "WebPage", "name": "Not a recipe" }"#),
+        Err(JsonLdError::RecipeNotFound)
+    ));
+    assert!(matches!(
+        parse("<html><body>Nothing to see</body></html>"),
+        Err(JsonLdError::RecipeNotFound)
+    ));
+    assert!(matches!(
+        parse(r#"{ "@type": "Recipe" }"#),
+        Err(JsonLdError::MissingName)
+    ));
+    assert!(matches!(parse("{ nope"), Err(JsonLdError::InvalidJson(_))));
+}
+
+#[test]
+fn parsing_durations_works() {
+    let cases = [
+        ("PT15M", Some(15 * 60)),
+        ("PT1H30M", Some(90 * 60)),
+        ("P0DT0H20M0.000S", Some(20 * 60)),
+        ("P1DT2H", Some(26 * 60 * 60)),
+        ("PT90S", Some(90)),
+        ("P1M", None),
+        ("15 minutes", None),
+        ("PT", None),
+        ("PT15", None),
+        ("P99999999999999999999W", None),
+    ];
+
+    for (input, expected) in cases {
+        assert_eq!(
+            parse_duration(input),
+            expected.map(Duration::from_secs),
+            "parsing {input}"
+        );
+    }
+}
+
+#[test]
+fn overflowing_durations_are_skipped() {
+    let recipe = parse(&RECIPE.replace("PT15M", "P99999999999999999999W")).unwrap();
+
+    assert!(!recipe.time.contains_key(PREP_TIME));
+    assert_eq!(recipe.time[COOK_TIME], Duration::from_secs(10 * 60));
+
+    let recipe = Recipe {
+        time: BTreeMap::from(
+            [PREP_TIME, COOK_TIME, TOTAL_TIME].map(|key| (key.to_string(), Duration::MAX)),
+        ),
+        ..recipe_fixture()
+    };
+
+    assert_eq!(recipe.total_time(), Duration::MAX);
+    assert!(render(&recipe)["totalTime"].is_string());
+}
+
+#[test]
+fn formatting_durations_works() {
+    let cases = [
+        (15 * 60, "PT15M"),
+        (90 * 60, "PT1H30M"),
+        (26 * 60 * 60, "PT26H"),
+        (61, "PT1M1S"),
+        (0, "PT0S"),
+    ];
+
+    for (seconds, expected) in cases {
+        let duration = Duration::from_secs(seconds);
+
+        assert_eq!(format_duration(&duration), expected);
+        assert_eq!(parse_duration(expected), Some(duration));
+    }
+}
+
+#[test]
+fn rendering_a_recipe_matches_the_golden_file() {
+    let document = render(&recipe_fixture());
+
+    assert_golden(
+        "recipe.jsonld",
+        &format!("{}\n", serde_json::to_string_pretty(&document).unwrap()),
+    );
+}
+
+#[test]
+fn rendered_recipes_can_be_imported_again() {
+    let recipe = recipe_fixture();
+    let imported = parse(&render(&recipe).to_string()).unwrap();
+
+    assert_eq!(imported.name, recipe.name);
+    assert_eq!(imported.description, recipe.description);
+    assert_eq!(imported.servings, recipe.servings);
+    assert_eq!(imported.time, recipe.time);
+    assert_eq!(imported.steps, recipe.steps.as_ref());
+    assert!(imported.unparsed_ingredients.is_empty());
+    assert_eq!(
+        imported
+            .ingredients
+            .iter()
+            .map(|i| (i.name.as_str(), i.amount.clone()))
+            .collect::<Vec<_>>(),
+        recipe
+            .ingredients
+            .iter()
+            .map(|i| (i.ingredient.name.0.as_str(), i.amount.clone()))
+            .collect::<Vec<_>>()
+    );
+}
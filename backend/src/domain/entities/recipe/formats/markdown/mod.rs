@@ -0,0 +1,38 @@
+use std::fmt::Write;
+
+use crate::domain::entities::recipe::Recipe;
+
+use super::{describe_duration, describe_servings, IngredientLine};
+
+/// Renders the recipe as a document meant to be read by people, not parsed back.
+pub fn render(recipe: &Recipe) -> String {
+    let mut document = format!("# {}\n\n", recipe.name);
+
+    if !recipe.description.trim().is_empty() {
+        let _ = writeln!(document, "{}\n", recipe.description.trim());
+    }
+
+    let _ = writeln!(
+        document,
+        "- **Servings:** {}",
+        describe_servings(&recipe.servings)
+    );
+    for (name, duration) in &recipe.time {
+        let _ = writeln!(document, "- **{}:** {}", name, describe_duration(duration));
+    }
+
+    document.push_str("\n## Ingredients\n\n");
+    for ingredient in recipe.ingredients.iter() {
+        let _ = writeln!(document, "- {}", IngredientLine::from(ingredient));
+    }
+
+    document.push_str("\n## Steps\n\n");
+    for (number, step) in recipe.steps.as_ref().iter().enumerate() {
+        let _ = writeln!(document, "{}. {}", number + 1, step.trim());
+    }
+
+    document
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,21 @@
+use crate::{
+    domain::entities::recipe::{formats::tests::assert_golden, ServingsType},
+    test_utils::recipe_fixture,
+};
+
+use super::render;
+
+#[test]
+fn rendering_a_recipe_matches_the_golden_file() {
+    assert_golden("recipe.md", &render(&recipe_fixture()));
+}
+
+#[test]
+fn rendering_a_recipe_with_a_range_of_servings_works() {
+    let recipe = crate::domain::entities::recipe::Recipe {
+        servings: ServingsType::FromTo(2, 4),
+        ..recipe_fixture()
+    };
+
+    assert!(render(&recipe).contains("- **Servings:** 2-4\n"));
+}
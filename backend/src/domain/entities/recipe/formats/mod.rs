@@ -0,0 +1,309 @@
+pub mod cooklang;
+pub mod jsonld;
+pub mod markdown;
+
+use std::{collections::BTreeMap, fmt::Display, str::FromStr, time::Duration};
+
+use strum::{EnumString, VariantNames};
+
+use super::{
+    errors::ValidationError,
+    units::{separate_unit, split_amount},
+    IngredientUnit, IngredientWithAmount, Recipe, ServingsType,
+};
+
+/// Units which are counted rather than measured, like `4 cloves garlic`.
+/// They can't be converted into anything, but they shouldn't end up in the name of the ingredient either.
+const COUNTED_UNITS: &[&str] = &[
+    "bunch", "bunches", "can", "cans", "clove", "cloves", "dash", "dashes", "handful", "handfuls",
+    "head", "heads", "package", "packages", "piece", "pieces", "pinch", "pinches", "slice",
+    "slices", "sprig", "sprigs", "stalk", "stalks",
+];
+
+/// Used for ingredients which are just counted, like `3 eggs`.
+pub const PIECES: &str = "pieces";
+
+pub const PREP_TIME: &str = "Prep time";
+pub const COOK_TIME: &str = "Cook time";
+pub const TOTAL_TIME: &str = "Total time";
+
+/// Formats a recipe can be exported into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumString, VariantNames)]
+#[strum(serialize_all = "snake_case")]
+pub enum ExportFormat {
+    Jsonld,
+    Markdown,
+    Cooklang,
+}
+
+impl ExportFormat {
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            Self::Jsonld => "application/ld+json",
+            Self::Markdown => "text/markdown; charset=utf-8",
+            Self::Cooklang => "text/plain; charset=utf-8",
+        }
+    }
+
+    pub fn render(&self, recipe: &Recipe) -> String {
+        match self {
+            Self::Jsonld => jsonld::render(recipe).to_string(),
+            Self::Markdown => markdown::render(recipe),
+            Self::Cooklang => cooklang::render(recipe),
+        }
+    }
+}
+
+/// A recipe read from a format other than our own, before its ingredients are matched
+/// against the ones we already know about.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImportedRecipe {
+    pub name: String,
+    pub description: String,
+    pub steps: Vec<String>,
+    pub time: BTreeMap<String, std::time::Duration>,
+    pub servings: ServingsType,
+    pub ingredients: Vec<IngredientLine>,
+    /// Ingredient lines that couldn't be made sense of, exactly as they were written
+    pub unparsed_ingredients: Vec<String>,
+}
+
+impl ImportedRecipe {
+    /// Parses every line separately, so that a single odd line doesn't spoil the whole recipe.
+    pub fn parse_ingredients<'a>(
+        lines: impl IntoIterator<Item = &'a str>,
+    ) -> (Vec<IngredientLine>, Vec<String>) {
+        let mut ingredients = vec![];
+        let mut unparsed = vec![];
+
+        for line in lines.into_iter().filter(|line| !line.trim().is_empty()) {
+            match line.parse() {
+                Ok(ingredient) => ingredients.push(ingredient),
+                Err(_) => unparsed.push(line.trim().to_string()),
+            }
+        }
+
+        (ingredients, unparsed)
+    }
+}
+
+/// A single ingredient as it's written down in a recipe, like `1 1/2 cups flour, sifted`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IngredientLine {
+    pub name: String,
+    pub amount: IngredientUnit,
+    pub notes: Option<String>,
+    pub optional: bool,
+}
+
+impl From<&IngredientWithAmount> for IngredientLine {
+    fn from(value: &IngredientWithAmount) -> Self {
+        Self {
+            name: value.ingredient.name.to_string(),
+            amount: value.amount.clone(),
+            notes: value.notes.clone(),
+            optional: value.optional,
+        }
+    }
+}
+
+/// Written the same way it's parsed, e.g. `1.5 cups flour (optional), sifted`.
+/// Ingredients which are just counted are written without a unit, like `3 eggs`.
+impl Display for IngredientLine {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.amount.amount())?;
+        if let Some(unit) = unit_name(&self.amount) {
+            write!(f, " {unit}")?;
+        }
+        write!(f, " {}", self.name)?;
+        if self.optional {
+            write!(f, " (optional)")?;
+        }
+        if let Some(notes) = &self.notes {
+            write!(f, ", {notes}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// How the unit of the amount is written down, if it has one.
+fn unit_name(amount: &IngredientUnit) -> Option<&str> {
+    let name = match amount {
+        IngredientUnit::Mililiters(_) => "ml",
+        IngredientUnit::Grams(_) => "g",
+        IngredientUnit::Teaspoons(_) => "tsp",
+        IngredientUnit::Cups(amount) if *amount == 1.0 => "cup",
+        IngredientUnit::Cups(_) => "cups",
+        IngredientUnit::Other { unit, .. } if unit == PIECES => return None,
+        IngredientUnit::Other { unit, .. } => unit,
+    };
+
+    Some(name)
+}
+
+/// Reads servings written like `4`, `4 servings` or `4-6 people`.
+pub fn parse_servings(text: &str) -> Option<ServingsType> {
+    let mut numbers = vec![];
+    let mut rest = text;
+
+    while let Some(start) = rest.find(|c: char| c.is_ascii_digit()) {
+        let end = rest[start..]
+            .find(|c: char| !c.is_ascii_digit())
+            .map_or(rest.len(), |end| start + end);
+
+        numbers.push((&rest[..start], rest[start..end].parse::<u16>().ok()?));
+        rest = &rest[end..];
+    }
+
+    match numbers.as_slice() {
+        [(_, from), (between, to), ..] if ["-", "–", "to"].contains(&between.trim()) => {
+            Some(ServingsType::FromTo(*from, *to))
+        }
+        [(_, servings), ..] => Some(ServingsType::Exact(*servings)),
+        [] => None,
+    }
+}
+
+/// Either a single number, or a range like `2-4`.
+pub fn describe_servings(servings: &ServingsType) -> String {
+    match servings {
+        ServingsType::Exact(servings) => servings.to_string(),
+        ServingsType::FromTo(from, to) => format!("{from}-{to}"),
+    }
+}
+
+/// Writes durations the way people do, like `1 h 30 min`.
+pub fn describe_duration(duration: &Duration) -> String {
+    let minutes = duration.as_secs().div_ceil(60);
+    let (hours, minutes) = (minutes / 60, minutes % 60);
+
+    match (hours, minutes) {
+        (0, minutes) => format!("{minutes} min"),
+        (hours, 0) => format!("{hours} h"),
+        (hours, minutes) => format!("{hours} h {minutes} min"),
+    }
+}
+
+/// Takes everything in parentheses out of the line, e.g. `1 (14 oz) can tomatoes`.
+fn take_parenthesized(line: &str) -> (String, Vec<String>) {
+    let mut rest = String::new();
+    let mut taken = vec![];
+    let mut current = String::new();
+    let mut depth = 0;
+
+    for c in line.chars() {
+        match (c, depth) {
+            ('(', 0) => depth += 1,
+            ('(', _) => {
+                depth += 1;
+                current.push(c);
+            }
+            (')', 1) => {
+                depth -= 1;
+                taken.push(std::mem::take(&mut current));
+            }
+            (')', 0) => rest.push(c),
+            (')', _) => {
+                depth -= 1;
+                current.push(c);
+            }
+            (_, 0) => rest.push(c),
+            _ => current.push(c),
+        }
+    }
+
+    (rest, taken)
+}
+
+impl FromStr for IngredientLine {
+    type Err = ValidationError;
+
+    /// Everything after the first comma, or in parentheses, ends up in the notes.
+    /// Lines without an amount, like `salt to taste`, can't be parsed.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let fail = || ValidationError::MeasurementComputation(input.to_string());
+
+        let line = input
+            .trim()
+            .trim_start_matches(['-', '*', '•'])
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        // Decimal commas, like `0,5 l`, aren't followed by a space
+        let (main, rest) = line.split_once(", ").unwrap_or((&line, ""));
+        let (main, parenthesized) = take_parenthesized(main);
+
+        let mut notes: Vec<String> = parenthesized
+            .iter()
+            .map(String::as_str)
+            .chain(rest.split(", "))
+            .map(str::trim)
+            .filter(|note| !note.is_empty())
+            .map(str::to_string)
+            .collect();
+
+        let optional = notes
+            .iter()
+            .any(|note| note.eq_ignore_ascii_case("optional"));
+        notes.retain(|note| !note.eq_ignore_ascii_case("optional"));
+
+        let (amount, rest) = split_amount(&separate_unit(main.trim())).ok_or_else(fail)?;
+
+        let words: Vec<&str> = rest.split_whitespace().collect();
+
+        let known = |count: usize| {
+            (words.len() > count)
+                .then(|| IngredientUnit::from_known_unit(amount, &words[..count].join(" ")))
+                .flatten()
+                .map(|unit| (unit, count))
+        };
+
+        let (amount, unit_length) = known(2)
+            .or_else(|| known(1))
+            .or_else(|| {
+                words
+                    .first()
+                    .filter(|word| COUNTED_UNITS.contains(&word.to_lowercase().as_str()))
+                    .filter(|_| words.len() > 1)
+                    .map(|word| {
+                        let unit = IngredientUnit::Other {
+                            amount,
+                            unit: word.to_string(),
+                        };
+                        (unit, 1)
+                    })
+            })
+            .unwrap_or((
+                IngredientUnit::Other {
+                    amount,
+                    unit: PIECES.to_string(),
+                },
+                0,
+            ));
+        let amount = amount.validated().ok_or_else(fail)?;
+
+        let name = words[unit_length..].join(" ");
+        let name = name
+            .strip_prefix("of ")
+            .unwrap_or(&name)
+            .trim_end_matches(['.', ';', ':'])
+            .trim()
+            .to_string();
+
+        if name.is_empty() {
+            return Err(fail());
+        }
+
+        Ok(Self {
+            name,
+            amount,
+            notes: (!notes.is_empty()).then(|| notes.join(", ")),
+            optional,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests;
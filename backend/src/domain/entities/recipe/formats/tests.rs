@@ -0,0 +1,190 @@
+use std::path::PathBuf;
+
+use pretty_assertions::assert_eq;
+
+use crate::domain::entities::recipe::IngredientUnit;
+
+use super::{ExportFormat, ImportedRecipe, IngredientLine, PIECES};
+
+/// Compares the output with the file of the same name in `golden/`.
+/// Run the tests with `UPDATE_GOLDEN=1` to write the current output there instead.
+pub(super) fn assert_golden(name: &str, actual: &str) {
+    let path: PathBuf = [
+        env!("CARGO_MANIFEST_DIR"),
+        "src/domain/entities/recipe/formats/golden",
+        name,
+    ]
+    .iter()
+    .collect();
+
+    if std::env::var_os("UPDATE_GOLDEN").is_some() {
+        std::fs::write(&path, actual).unwrap();
+    }
+
+    let expected = std::fs::read_to_string(&path).unwrap();
+    assert_eq!(actual, expected, "{} is out of date", path.display());
+}
+
+fn line(name: &str, amount: IngredientUnit, notes: Option<&str>, optional: bool) -> IngredientLine {
+    IngredientLine {
+        name: name.to_string(),
+        amount,
+        notes: notes.map(str::to_string),
+        optional,
+    }
+}
+
+#[test]
+fn parsing_ingredient_lines_works() {
+    let cases = [
+        (
+            "1 1/2 cups all-purpose flour, sifted",
+            line(
+                "all-purpose flour",
+                IngredientUnit::Cups(1.5),
+                Some("sifted"),
+                false,
+            ),
+        ),
+        (
+            "200g firm tofu",
+            line("firm tofu", IngredientUnit::Grams(200.0), None, false),
+        ),
+        (
+            "2 tbsp of soy sauce",
+            line("soy sauce", IngredientUnit::Teaspoons(6.0), None, false),
+        ),
+        (
+            "4 fl oz milk",
+            line("milk", IngredientUnit::Mililiters(118.29), None, false),
+        ),
+        (
+            "0,5 l water",
+            line("water", IngredientUnit::Mililiters(500.0), None, false),
+        ),
+        (
+            "4 cloves garlic, minced",
+            line(
+                "garlic",
+                IngredientUnit::Other {
+                    amount: 4.0,
+                    unit: "cloves".to_string(),
+                },
+                Some("minced"),
+                false,
+            ),
+        ),
+        (
+            "3 eggs",
+            line(
+                "eggs",
+                IngredientUnit::Other {
+                    amount: 3.0,
+                    unit: PIECES.to_string(),
+                },
+                None,
+                false,
+            ),
+        ),
+        (
+            "- ½ tsp chili flakes (optional)",
+            line("chili flakes", IngredientUnit::Teaspoons(0.5), None, true),
+        ),
+        (
+            "1 (14 oz) can diced tomatoes, drained, optional",
+            line(
+                "diced tomatoes",
+                IngredientUnit::Other {
+                    amount: 1.0,
+                    unit: "can".to_string(),
+                },
+                Some("14 oz, drained"),
+                true,
+            ),
+        ),
+    ];
+
+    for (input, expected) in cases {
+        let result: IngredientLine = input.parse().unwrap();
+        assert_eq!(result, expected, "parsing {input}");
+    }
+}
+
+#[test]
+fn parsing_ingredient_lines_without_an_amount_or_a_name_fails() {
+    for input in ["salt to taste", "a pinch of salt", "", "2 ", "½"] {
+        assert!(
+            input.parse::<IngredientLine>().is_err(),
+            "parsing {input} should fail"
+        );
+    }
+}
+
+#[test]
+fn parsing_ingredient_lines_with_amounts_which_are_not_finite_fails() {
+    let huge = format!("1{}", "0".repeat(306));
+
+    for input in [
+        "inf½ g flour".to_string(),
+        "NaN½ eggs".to_string(),
+        format!("{huge} kg flour"),
+        format!("{huge}00 {huge}00/1 g flour"),
+    ] {
+        assert!(
+            input.parse::<IngredientLine>().is_err(),
+            "parsing {input} should fail"
+        );
+    }
+}
+
+#[test]
+fn unparseable_ingredient_lines_are_kept_aside() {
+    let (ingredients, unparsed) = ImportedRecipe::parse_ingredients([
+        "400 g firm tofu",
+        "  ",
+        " salt to taste ",
+        "1 head broccoli",
+    ]);
+
+    assert_eq!(
+        ingredients
+            .iter()
+            .map(|i| i.name.as_str())
+            .collect::<Vec<_>>(),
+        vec!["firm tofu", "broccoli"]
+    );
+    assert_eq!(unparsed, vec!["salt to taste"]);
+}
+
+#[test]
+fn ingredient_lines_are_written_the_way_they_are_parsed() {
+    for input in [
+        "1.5 cups all-purpose flour (optional), sifted",
+        "200 g firm tofu",
+        "4 cloves garlic, minced",
+        "3 eggs",
+        "1 cup milk",
+    ] {
+        let line: IngredientLine = input.parse().unwrap();
+
+        assert_eq!(line.to_string(), input);
+        assert_eq!(line.to_string().parse::<IngredientLine>().unwrap(), line);
+    }
+}
+
+#[test]
+fn parsing_export_formats_works() {
+    assert_eq!(
+        "jsonld".parse::<ExportFormat>().unwrap(),
+        ExportFormat::Jsonld
+    );
+    assert_eq!(
+        "markdown".parse::<ExportFormat>().unwrap(),
+        ExportFormat::Markdown
+    );
+    assert_eq!(
+        "cooklang".parse::<ExportFormat>().unwrap(),
+        ExportFormat::Cooklang
+    );
+    assert!("pdf".parse::<ExportFormat>().is_err());
+}
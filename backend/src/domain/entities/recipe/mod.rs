@@ -1,4 +1,10 @@
+pub mod cookable;
 pub mod errors;
+pub mod formats;
+pub mod nutrition;
+pub mod revisions;
+pub mod shopping_list;
+pub mod units;
 use chrono::{DateTime, Utc};
 use derive_more::DerefMut;
 use std::collections::{BTreeMap, BTreeSet};
@@ -6,15 +12,24 @@ use std::collections::{BTreeMap, BTreeSet};
 use common::{
     IngredientAmountDTO, IngredientUnitDTO, IngredientWithAmountDTO, RecipeDTO, ServingsTypeDTO,
 };
-use rayon::{iter::{IntoParallelRefIterator, ParallelIterator}, slice::ParallelSliceMut};
+use rayon::{
+    iter::{IntoParallelRefIterator, ParallelIterator},
+    slice::ParallelSliceMut,
+};
 use serde::{Deserialize, Serialize};
 use shrinkwraprs::Shrinkwrap;
 use sqlx::FromRow;
+use strum::{EnumString, VariantNames};
 use uuid::Uuid;
 
 use self::errors::ValidationError;
+use self::units::UnitSystem;
 
-use super::ingredient::{Ingredient, IngredientModel};
+use super::ingredient::{
+    types::{Allergen, DietViolations},
+    Ingredient, IngredientModel,
+};
+use super::pagination::DEFAULT_PAGE_SIZE;
 
 #[derive(PartialEq, Debug, Clone)]
 pub struct Recipe {
@@ -25,11 +40,93 @@ pub struct Recipe {
     pub ingredients: RecipeIngredients,
     pub time: BTreeMap<String, std::time::Duration>,
     pub servings: ServingsType,
+    pub owner_id: Option<Uuid>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
 
 impl Recipe {
+    /// Checks whether the given user is allowed to change this recipe.
+    /// Recipes without an owner, i.e. created before ownership was tracked or left behind by
+    /// a deleted user, can't be changed by anyone.
+    pub fn can_be_modified_by(&self, user_id: &Uuid) -> bool {
+        self.owner_id == Some(*user_id)
+    }
+
+    /// Identifies the current state of the recipe, changing whenever the recipe does.
+    /// Used to detect that someone else has changed the recipe in the meantime.
+    pub fn version(&self) -> String {
+        self.updated_at.timestamp_micros().to_string()
+    }
+
+    /// Sum of all the time entries, e.g. preparation and cooking time.
+    /// Saturates instead of overflowing, as imported recipes can claim absurdly long times.
+    pub fn total_time(&self) -> std::time::Duration {
+        self.time
+            .values()
+            .fold(std::time::Duration::ZERO, |acc, time| {
+                acc.saturating_add(*time)
+            })
+    }
+
+    /// A recipe is compatible with a diet when none of its ingredients violate it.
+    pub fn is_compatible_with(&self, diet: &DietViolations) -> bool {
+        !self
+            .ingredients
+            .iter()
+            .any(|i| i.ingredient.diet_violations.contains(diet))
+    }
+
+    /// A recipe is free from an allergen when none of its ingredients contain it.
+    pub fn is_free_from(&self, allergen: &Allergen) -> bool {
+        !self
+            .ingredients
+            .iter()
+            .any(|i| i.ingredient.allergens.contains(allergen))
+    }
+
+    pub fn contains_ingredient(&self, ingredient_id: &Uuid) -> bool {
+        self.ingredients
+            .iter()
+            .any(|i| &i.ingredient.id == ingredient_id)
+    }
+
+    /// Expresses every ingredient amount in the units of the given system.
+    pub fn to_unit_system(mut self, system: UnitSystem) -> Self {
+        self.ingredients
+            .iter_mut()
+            .for_each(|i| i.amount = i.amount.to_system(system));
+
+        self
+    }
+
+    /// Adjusts every ingredient amount so that the recipe yields the given amount of servings.
+    /// Ranges are scaled from their middle, so going from 2-4 to 6 servings doubles everything.
+    /// Recipes that claim to have no servings at all are treated as a single serving.
+    pub fn scaled_to(self, servings: u16) -> Self {
+        let mut recipe = self.scaled_exactly_to(servings);
+
+        recipe
+            .ingredients
+            .iter_mut()
+            .for_each(|i| i.amount = i.amount.to_kitchen_precision());
+
+        recipe
+    }
+
+    /// Same as [Recipe::scaled_to], without rounding the amounts to kitchen precision.
+    /// For amounts which still get added up, so that only the totals are rounded.
+    pub fn scaled_exactly_to(mut self, servings: u16) -> Self {
+        let factor = f64::from(servings) / self.servings.average().max(1.0);
+
+        self.ingredients
+            .iter_mut()
+            .for_each(|i| i.amount = i.amount.scaled(factor));
+        self.servings = ServingsType::Exact(servings);
+
+        self
+    }
+
     fn get_time(&self) -> BTreeMap<String, u64> {
         self.time
             .clone()
@@ -51,6 +148,20 @@ impl Recipe {
             .into_iter()
             .collect()
     }
+
+    fn get_allergens(&self) -> Vec<String> {
+        self.ingredients
+            .iter()
+            .fold(BTreeSet::new(), |mut acc, curr| {
+                curr.ingredient.allergens.iter().for_each(|allergen| {
+                    acc.insert(allergen.to_string());
+                });
+
+                acc
+            })
+            .into_iter()
+            .collect()
+    }
 }
 
 impl From<Recipe> for RecipeDTO {
@@ -66,13 +177,91 @@ impl From<Recipe> for RecipeDTO {
             steps: value.clone().steps.0,
             time: value.clone().get_time(),
             servings: value.servings.clone().into(),
+            owner_id: value.owner_id,
             updated_at: value.updated_at.to_rfc3339(),
             created_at: value.created_at.to_rfc3339(),
             diet_violations: value.get_diet_violations(),
+            allergens: value.get_allergens(),
+        }
+    }
+}
+
+/// How recipe listings are ordered.
+/// Names are sorted alphabetically, timestamps from the newest.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, EnumString, VariantNames)]
+#[strum(serialize_all = "snake_case")]
+pub enum RecipeSort {
+    Name,
+    #[default]
+    CreatedAt,
+    UpdatedAt,
+}
+
+/// Criteria for searching recipes. Empty fields don't narrow down the results.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecipeSearchQuery {
+    /// Free text matched against the name, description and steps
+    pub text: Option<String>,
+    /// The recipe has to contain all of these ingredients
+    pub including_ingredients: Vec<Uuid>,
+    /// The recipe can't contain any of these ingredients
+    pub excluding_ingredients: Vec<Uuid>,
+    /// The recipe has to be compatible with all of these diets
+    pub diets: Vec<DietViolations>,
+    /// None of the ingredients may contain any of these allergens
+    pub free_from: Vec<Allergen>,
+    pub max_total_time: Option<std::time::Duration>,
+    /// Variants of the included and excluded ingredients, which count as the ingredient itself
+    pub ingredient_variants: BTreeMap<Uuid, Vec<Uuid>>,
+    /// How many of the most relevant recipes to return
+    pub limit: u32,
+}
+
+impl Default for RecipeSearchQuery {
+    fn default() -> Self {
+        Self {
+            text: None,
+            including_ingredients: vec![],
+            excluding_ingredients: vec![],
+            diets: vec![],
+            free_from: vec![],
+            max_total_time: None,
+            ingredient_variants: BTreeMap::new(),
+            limit: DEFAULT_PAGE_SIZE,
         }
     }
 }
 
+impl RecipeSearchQuery {
+    /// The ingredient itself, followed by all of its known variants.
+    pub fn matching_ingredients<'a>(&'a self, id: &'a Uuid) -> impl Iterator<Item = &'a Uuid> {
+        std::iter::once(id).chain(self.ingredient_variants.get(id).into_iter().flatten())
+    }
+
+    /// Checks the recipe against every criterion except for the text.
+    pub fn filters_match(&self, recipe: &Recipe) -> bool {
+        self.including_ingredients.iter().all(|id| {
+            self.matching_ingredients(id)
+                .any(|id| recipe.contains_ingredient(id))
+        }) && !self
+            .excluding_ingredients
+            .iter()
+            .flat_map(|id| self.matching_ingredients(id))
+            .any(|id| recipe.contains_ingredient(id))
+            && self
+                .diets
+                .iter()
+                .all(|diet| recipe.is_compatible_with(diet))
+            && self
+                .free_from
+                .iter()
+                .all(|allergen| recipe.is_free_from(allergen))
+            && self
+                .max_total_time
+                .is_none_or(|max| recipe.total_time() <= max)
+    }
+}
+
 #[derive(Debug, Clone, Shrinkwrap, DerefMut)]
 pub struct RecipeIngredients(Vec<IngredientWithAmount>);
 
@@ -160,6 +349,15 @@ pub enum ServingsType {
     Exact(u16),
 }
 
+impl ServingsType {
+    pub fn average(&self) -> f64 {
+        match self {
+            Self::Exact(a) => f64::from(*a),
+            Self::FromTo(a, b) => (f64::from(*a) + f64::from(*b)) / 2.0,
+        }
+    }
+}
+
 impl From<ServingsType> for ServingsTypeDTO {
     fn from(value: ServingsType) -> Self {
         match value {
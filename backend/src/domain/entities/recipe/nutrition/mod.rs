@@ -0,0 +1,68 @@
+use std::collections::BTreeMap;
+
+use common::RecipeNutritionDTO;
+use uuid::Uuid;
+
+use crate::domain::entities::ingredient::{
+    nutrition::{IngredientNutrition, NutritionFacts},
+    Ingredient,
+};
+
+use super::{IngredientWithAmount, Recipe};
+
+/// What a recipe is made of, as far as the nutrition facts of its ingredients go.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecipeNutrition {
+    pub total: NutritionFacts,
+    pub per_serving: NutritionFacts,
+    /// Ingredients whose amount can't be weighed, e.g. heads of broccoli, or cups of something without a known density
+    pub unconverted: Vec<IngredientWithAmount>,
+    /// Ingredients nothing is known about
+    pub missing: Vec<Ingredient>,
+}
+
+impl RecipeNutrition {
+    /// Adds up the nutrition facts of the ingredients in the amounts the recipe uses them in.
+    /// Optional ingredients are left out, and ranges of servings are split by their average.
+    /// Recipes that claim to have no servings at all are treated as a single serving.
+    pub fn new(recipe: &Recipe, nutrition: &BTreeMap<Uuid, IngredientNutrition>) -> Self {
+        let mut total = NutritionFacts::default();
+        let mut unconverted = vec![];
+        let mut missing = vec![];
+
+        for ingredient in recipe.ingredients.iter().filter(|i| !i.optional) {
+            let Some(facts) = nutrition.get(&ingredient.ingredient.id) else {
+                missing.push(ingredient.ingredient.clone());
+                continue;
+            };
+
+            match ingredient.amount.as_grams(facts.density) {
+                Ok(grams) => total = total.plus(&facts.of_grams(grams)),
+                Err(_) => unconverted.push(ingredient.clone()),
+            }
+        }
+
+        Self {
+            total: total.rounded(),
+            per_serving: total
+                .scaled(1.0 / recipe.servings.average().max(1.0))
+                .rounded(),
+            unconverted,
+            missing,
+        }
+    }
+}
+
+impl From<RecipeNutrition> for RecipeNutritionDTO {
+    fn from(value: RecipeNutrition) -> Self {
+        Self {
+            total: value.total.into(),
+            per_serving: value.per_serving.into(),
+            unconverted: value.unconverted.into_iter().map(Into::into).collect(),
+            missing: value.missing.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
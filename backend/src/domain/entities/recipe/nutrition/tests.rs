@@ -0,0 +1,107 @@
+use std::collections::BTreeMap;
+
+use pretty_assertions::assert_eq;
+
+use crate::{
+    domain::entities::{
+        ingredient::nutrition::{IngredientNutrition, NutritionFacts},
+        recipe::{units::Density, IngredientUnit, Recipe, ServingsType},
+    },
+    test_utils::{nutrition_fixture, recipe_fixture},
+};
+
+use super::RecipeNutrition;
+
+#[test]
+fn nutrition_is_added_up_from_the_weighed_ingredients() {
+    let recipe = recipe_fixture();
+    let tofu = recipe.ingredients[0].ingredient.id;
+
+    let result = RecipeNutrition::new(&recipe, &BTreeMap::from([(tofu, nutrition_fixture())]));
+
+    // 400 g of tofu, for 4 servings
+    assert_eq!(
+        result.total,
+        NutritionFacts {
+            energy_kcal: 576.0,
+            protein: 63.2,
+            fat: 34.8,
+            carbohydrates: 11.2,
+            fiber: 9.2,
+            sugar: 2.4,
+            salt: 0.1,
+        }
+    );
+    assert_eq!(result.per_serving.energy_kcal, 144.0);
+    assert_eq!(result.per_serving.protein, 15.8);
+    assert_eq!(
+        result
+            .missing
+            .iter()
+            .map(|i| i.name.to_string())
+            .collect::<Vec<_>>(),
+        vec!["Broccoli", "Garlic"]
+    );
+    assert!(result.unconverted.is_empty());
+}
+
+#[test]
+fn amounts_which_cant_be_weighed_are_flagged() {
+    let mut recipe = recipe_fixture();
+    let broccoli = recipe.ingredients[1].ingredient.id;
+    recipe.ingredients[0].optional = true;
+    recipe.ingredients.truncate(2);
+
+    let result = RecipeNutrition::new(&recipe, &BTreeMap::from([(broccoli, nutrition_fixture())]));
+
+    assert_eq!(result.total, NutritionFacts::default());
+    assert_eq!(result.unconverted, vec![recipe.ingredients[1].clone()]);
+    // Optional tofu isn't counted, nor is it missing
+    assert!(result.missing.is_empty());
+}
+
+#[test]
+fn volumes_are_weighed_by_the_density_of_the_ingredient() {
+    let mut ingredients = recipe_fixture().ingredients.to_vec();
+    ingredients.truncate(1);
+    ingredients[0].amount = IngredientUnit::Mililiters(200.0);
+    let recipe = Recipe {
+        ingredients: ingredients.try_into().unwrap(),
+        ..recipe_fixture()
+    };
+    let tofu = recipe.ingredients[0].ingredient.id;
+
+    let without_density =
+        RecipeNutrition::new(&recipe, &BTreeMap::from([(tofu, nutrition_fixture())]));
+
+    assert_eq!(without_density.unconverted.len(), 1);
+
+    let with_density = RecipeNutrition::new(
+        &recipe,
+        &BTreeMap::from([(
+            tofu,
+            IngredientNutrition {
+                density: Some(Density(0.5)),
+                ..nutrition_fixture()
+            },
+        )]),
+    );
+
+    // 200 ml weigh 100 g
+    assert!(with_density.unconverted.is_empty());
+    assert_eq!(with_density.total.energy_kcal, 144.0);
+    assert_eq!(with_density.per_serving.energy_kcal, 36.0);
+}
+
+#[test]
+fn recipes_without_servings_count_as_a_single_serving() {
+    let recipe = Recipe {
+        servings: ServingsType::Exact(0),
+        ..recipe_fixture()
+    };
+    let tofu = recipe.ingredients[0].ingredient.id;
+
+    let result = RecipeNutrition::new(&recipe, &BTreeMap::from([(tofu, nutrition_fixture())]));
+
+    assert_eq!(result.per_serving, result.total);
+}
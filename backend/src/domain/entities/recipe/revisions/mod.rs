@@ -0,0 +1,363 @@
+use std::{collections::BTreeMap, time::Duration};
+
+use chrono::{DateTime, Utc};
+use common::{
+    IngredientChangeDTO, RecipeDiffDTO, RecipeRevisionDTO, RecipeRevisionDetailsDTO,
+    RecipeSnapshotDTO, RevisionChangeDTO, ServingsChangeDTO, SnapshotIngredientDTO, StepsChangeDTO,
+    TextChangeDTO, TimeChangeDTO,
+};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::{IngredientUnit, Recipe, ServingsType};
+
+/// The contents of a recipe at some point in time.
+/// Ingredients are kept with the name they had back then, as they may have been renamed since.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct RecipeSnapshot {
+    pub name: String,
+    pub description: String,
+    pub steps: Vec<String>,
+    pub time: BTreeMap<String, Duration>,
+    pub servings: ServingsType,
+    pub ingredients: Vec<SnapshotIngredient>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct SnapshotIngredient {
+    pub ingredient_id: Uuid,
+    pub name: String,
+    pub amount: IngredientUnit,
+    pub notes: Option<String>,
+    pub optional: bool,
+}
+
+impl From<&Recipe> for RecipeSnapshot {
+    fn from(value: &Recipe) -> Self {
+        Self {
+            name: value.name.clone(),
+            description: value.description.clone(),
+            steps: value.steps.as_ref().to_vec(),
+            time: value.time.clone(),
+            servings: value.servings.clone(),
+            ingredients: value
+                .ingredients
+                .iter()
+                .map(|i| SnapshotIngredient {
+                    ingredient_id: i.ingredient.id,
+                    name: i.ingredient.name.to_string(),
+                    amount: i.amount.clone(),
+                    notes: i.notes.clone(),
+                    optional: i.optional,
+                })
+                .collect(),
+        }
+    }
+}
+
+/// What was done to the recipe to get to a revision.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum RevisionChange {
+    Created,
+    /// Fields of the recipe itself were changed, their new values are in the snapshot
+    Updated {
+        fields: Vec<String>,
+    },
+    IngredientAdded {
+        ingredient_id: Uuid,
+    },
+    IngredientRemoved {
+        ingredient_id: Uuid,
+    },
+    IngredientAmountChanged {
+        ingredient_id: Uuid,
+    },
+    /// The recipe was brought back to how it was in an earlier revision
+    Reverted {
+        revision: i32,
+    },
+}
+
+impl RevisionChange {
+    /// Lists the fields of the recipe itself which differ, if any of them do.
+    pub fn updated(before: &Recipe, after: &Recipe) -> Option<Self> {
+        let fields: Vec<String> = [
+            ("name", before.name != after.name),
+            ("description", before.description != after.description),
+            ("steps", before.steps != after.steps),
+            ("time", before.time != after.time),
+            ("servings", before.servings != after.servings),
+        ]
+        .into_iter()
+        .filter(|(_, changed)| *changed)
+        .map(|(field, _)| field.to_string())
+        .collect();
+
+        (!fields.is_empty()).then_some(Self::Updated { fields })
+    }
+}
+
+/// An immutable record of a change to a recipe, along with how the recipe looked afterwards.
+/// Revisions are numbered from 1, in the order the changes were made.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecipeRevision {
+    pub recipe_id: Uuid,
+    pub number: i32,
+    /// The user who made the change
+    pub author_id: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub change: RevisionChange,
+    pub recipe: RecipeSnapshot,
+}
+
+impl RecipeRevision {
+    /// The first revision of a recipe, as it was created.
+    pub fn initial(recipe: &Recipe) -> Self {
+        Self {
+            recipe_id: recipe.id,
+            number: 1,
+            author_id: recipe.owner_id,
+            created_at: recipe.updated_at,
+            change: RevisionChange::Created,
+            recipe: recipe.into(),
+        }
+    }
+
+    /// All revisions of the recipe, given the ones which were stored.
+    /// Recipes which haven't changed since before revisions were tracked have just their current state.
+    pub fn history(recipe: &Recipe, stored: Vec<Self>) -> Vec<Self> {
+        if stored.is_empty() {
+            vec![Self::initial(recipe)]
+        } else {
+            stored
+        }
+    }
+
+    /// The revisions to store after the recipe changed from `before` to `after`, numbered after the `latest` one.
+    /// Recipes created before revisions were tracked don't have any yet,
+    /// so their state before the change is stored first to have something to revert to.
+    pub fn for_change(
+        latest: Option<i32>,
+        before: &Recipe,
+        after: &Recipe,
+        change: RevisionChange,
+        author_id: &Uuid,
+    ) -> Vec<Self> {
+        let mut revisions = vec![];
+
+        let latest = match latest {
+            Some(latest) => latest,
+            None => {
+                revisions.push(Self::initial(before));
+                1
+            }
+        };
+
+        revisions.push(Self {
+            recipe_id: after.id,
+            number: latest + 1,
+            author_id: Some(*author_id),
+            created_at: after.updated_at,
+            change,
+            recipe: after.into(),
+        });
+
+        revisions
+    }
+}
+
+/// The value of something in two revisions.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Change<T> {
+    pub old: T,
+    pub new: T,
+}
+
+impl<T: PartialEq> Change<T> {
+    fn of(old: T, new: T) -> Option<Self> {
+        (old != new).then_some(Self { old, new })
+    }
+}
+
+/// How a recipe differs between two revisions. Fields which are the same in both are left empty.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecipeDiff {
+    pub from: i32,
+    pub to: i32,
+    pub name: Option<Change<String>>,
+    pub description: Option<Change<String>>,
+    pub steps: Option<Change<Vec<String>>>,
+    pub time: Option<Change<BTreeMap<String, Duration>>>,
+    pub servings: Option<Change<ServingsType>>,
+    pub ingredients_added: Vec<SnapshotIngredient>,
+    pub ingredients_removed: Vec<SnapshotIngredient>,
+    /// Ingredients in both revisions, but with a different amount, notes, or whether they're optional
+    pub ingredients_changed: Vec<Change<SnapshotIngredient>>,
+}
+
+impl RecipeDiff {
+    /// Ingredients are matched by their ID, so a renamed ingredient doesn't count as a change.
+    pub fn between(from: &RecipeRevision, to: &RecipeRevision) -> Self {
+        let (old, new) = (&from.recipe, &to.recipe);
+        let find = |ingredients: &[SnapshotIngredient], id: &Uuid| {
+            ingredients.iter().find(|i| &i.ingredient_id == id).cloned()
+        };
+
+        Self {
+            from: from.number,
+            to: to.number,
+            name: Change::of(old.name.clone(), new.name.clone()),
+            description: Change::of(old.description.clone(), new.description.clone()),
+            steps: Change::of(old.steps.clone(), new.steps.clone()),
+            time: Change::of(old.time.clone(), new.time.clone()),
+            servings: Change::of(old.servings.clone(), new.servings.clone()),
+            ingredients_added: new
+                .ingredients
+                .iter()
+                .filter(|i| find(&old.ingredients, &i.ingredient_id).is_none())
+                .cloned()
+                .collect(),
+            ingredients_removed: old
+                .ingredients
+                .iter()
+                .filter(|i| find(&new.ingredients, &i.ingredient_id).is_none())
+                .cloned()
+                .collect(),
+            ingredients_changed: old
+                .ingredients
+                .iter()
+                .filter_map(|old| {
+                    let new = find(&new.ingredients, &old.ingredient_id)?;
+                    let renamed = SnapshotIngredient {
+                        name: new.name.clone(),
+                        ..old.clone()
+                    };
+
+                    (renamed != new).then(|| Change {
+                        old: old.clone(),
+                        new,
+                    })
+                })
+                .collect(),
+        }
+    }
+}
+
+fn time_to_dto(time: BTreeMap<String, Duration>) -> BTreeMap<String, u64> {
+    time.into_iter().map(|(k, v)| (k, v.as_secs())).collect()
+}
+
+impl From<SnapshotIngredient> for SnapshotIngredientDTO {
+    fn from(value: SnapshotIngredient) -> Self {
+        Self {
+            ingredient_id: value.ingredient_id,
+            name: value.name,
+            amount: value.amount.into(),
+            notes: value.notes,
+            optional: value.optional,
+        }
+    }
+}
+
+impl From<RecipeSnapshot> for RecipeSnapshotDTO {
+    fn from(value: RecipeSnapshot) -> Self {
+        Self {
+            name: value.name,
+            description: value.description,
+            steps: value.steps,
+            time: time_to_dto(value.time),
+            servings: value.servings.into(),
+            ingredients: value.ingredients.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<RevisionChange> for RevisionChangeDTO {
+    fn from(value: RevisionChange) -> Self {
+        match value {
+            RevisionChange::Created => Self::Created,
+            RevisionChange::Updated { fields } => Self::Updated { fields },
+            RevisionChange::IngredientAdded { ingredient_id } => {
+                Self::IngredientAdded { ingredient_id }
+            }
+            RevisionChange::IngredientRemoved { ingredient_id } => {
+                Self::IngredientRemoved { ingredient_id }
+            }
+            RevisionChange::IngredientAmountChanged { ingredient_id } => {
+                Self::IngredientAmountChanged { ingredient_id }
+            }
+            RevisionChange::Reverted { revision } => Self::Reverted { revision },
+        }
+    }
+}
+
+impl From<&RecipeRevision> for RecipeRevisionDTO {
+    fn from(value: &RecipeRevision) -> Self {
+        Self {
+            number: value.number,
+            author_id: value.author_id,
+            created_at: value.created_at.to_rfc3339(),
+            change: value.change.clone().into(),
+        }
+    }
+}
+
+impl From<RecipeRevision> for RecipeRevisionDetailsDTO {
+    fn from(value: RecipeRevision) -> Self {
+        Self {
+            revision: (&value).into(),
+            recipe: value.recipe.into(),
+        }
+    }
+}
+
+impl From<RecipeDiff> for RecipeDiffDTO {
+    fn from(value: RecipeDiff) -> Self {
+        Self {
+            from: value.from,
+            to: value.to,
+            name: value.name.map(|c| TextChangeDTO {
+                old: c.old,
+                new: c.new,
+            }),
+            description: value.description.map(|c| TextChangeDTO {
+                old: c.old,
+                new: c.new,
+            }),
+            steps: value.steps.map(|c| StepsChangeDTO {
+                old: c.old,
+                new: c.new,
+            }),
+            time: value.time.map(|c| TimeChangeDTO {
+                old: time_to_dto(c.old),
+                new: time_to_dto(c.new),
+            }),
+            servings: value.servings.map(|c| ServingsChangeDTO {
+                old: c.old.into(),
+                new: c.new.into(),
+            }),
+            ingredients_added: value
+                .ingredients_added
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            ingredients_removed: value
+                .ingredients_removed
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            ingredients_changed: value
+                .ingredients_changed
+                .into_iter()
+                .map(|c| IngredientChangeDTO {
+                    old: c.old.into(),
+                    new: c.new.into(),
+                })
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,149 @@
+use pretty_assertions::assert_eq;
+use uuid::Uuid;
+
+use crate::{
+    domain::entities::recipe::{IngredientUnit, Recipe, ServingsType},
+    test_utils::recipe_fixture,
+};
+
+use super::{Change, RecipeDiff, RecipeRevision, RecipeSnapshot, RevisionChange};
+
+#[test]
+fn recipes_without_revisions_get_an_initial_one_first() {
+    let before = recipe_fixture();
+    let after = Recipe {
+        name: "Tofu and Broccoli".to_string(),
+        ..before.clone()
+    };
+    let author_id = Uuid::now_v7();
+
+    let revisions = RecipeRevision::for_change(
+        None,
+        &before,
+        &after,
+        RevisionChange::Updated {
+            fields: vec!["name".to_string()],
+        },
+        &author_id,
+    );
+
+    assert_eq!(revisions.len(), 2);
+    assert_eq!(revisions[0].number, 1);
+    assert_eq!(revisions[0].change, RevisionChange::Created);
+    assert_eq!(revisions[0].recipe, RecipeSnapshot::from(&before));
+    assert_eq!(revisions[1].number, 2);
+    assert_eq!(revisions[1].author_id, Some(author_id));
+    assert_eq!(revisions[1].recipe.name, "Tofu and Broccoli");
+
+    let revisions = RecipeRevision::for_change(
+        Some(5),
+        &before,
+        &after,
+        RevisionChange::Created,
+        &author_id,
+    );
+
+    assert_eq!(revisions.len(), 1);
+    assert_eq!(revisions[0].number, 6);
+}
+
+#[test]
+fn diffing_revisions_works() {
+    let recipe = recipe_fixture();
+    let from = RecipeRevision::initial(&recipe);
+
+    let mut to = from.clone();
+    to.number = 2;
+    to.recipe.name = "Tofu and Broccoli".to_string();
+    to.recipe.servings = ServingsType::FromTo(2, 4);
+    // Renamed, which doesn't count as a change
+    to.recipe.ingredients[0].name = "Tofu".to_string();
+    to.recipe.ingredients[1].amount = IngredientUnit::Other {
+        amount: 2.0,
+        unit: "head".to_string(),
+    };
+    let removed = to.recipe.ingredients.remove(2);
+    let mut added = removed.clone();
+    added.ingredient_id = Uuid::from_u128(100);
+    to.recipe.ingredients.push(added.clone());
+
+    let diff = RecipeDiff::between(&from, &to);
+
+    assert_eq!(diff.from, 1);
+    assert_eq!(diff.to, 2);
+    assert_eq!(
+        diff.name,
+        Some(Change {
+            old: recipe.name.clone(),
+            new: "Tofu and Broccoli".to_string()
+        })
+    );
+    assert_eq!(diff.description, None);
+    assert_eq!(diff.steps, None);
+    assert_eq!(diff.time, None);
+    assert_eq!(
+        diff.servings,
+        Some(Change {
+            old: ServingsType::Exact(4),
+            new: ServingsType::FromTo(2, 4)
+        })
+    );
+    assert_eq!(diff.ingredients_added, vec![added]);
+    assert_eq!(diff.ingredients_removed, vec![removed]);
+    assert_eq!(diff.ingredients_changed.len(), 1);
+    assert_eq!(
+        diff.ingredients_changed[0].new.ingredient_id,
+        Uuid::from_u128(1)
+    );
+}
+
+#[test]
+fn diffing_a_revision_with_itself_is_empty() {
+    let revision = RecipeRevision::initial(&recipe_fixture());
+
+    let diff = RecipeDiff::between(&revision, &revision);
+
+    assert_eq!(diff.name, None);
+    assert_eq!(diff.servings, None);
+    assert!(diff.ingredients_added.is_empty());
+    assert!(diff.ingredients_removed.is_empty());
+    assert!(diff.ingredients_changed.is_empty());
+}
+
+#[test]
+fn updates_list_the_changed_fields() {
+    let before = recipe_fixture();
+    let mut after = before.clone();
+
+    assert_eq!(RevisionChange::updated(&before, &after), None);
+
+    after.description = "Tofu, but better".to_string();
+    after.servings = ServingsType::Exact(2);
+    // Ingredients have changes of their own
+    after.ingredients.pop();
+
+    assert_eq!(
+        RevisionChange::updated(&before, &after),
+        Some(RevisionChange::Updated {
+            fields: vec!["description".to_string(), "servings".to_string()]
+        })
+    );
+}
+
+#[test]
+fn changes_serialize_with_their_kind() {
+    let change = RevisionChange::IngredientAdded {
+        ingredient_id: Uuid::nil(),
+    };
+
+    let json = serde_json::to_value(&change).unwrap();
+
+    assert_eq!(
+        json,
+        serde_json::json!({ "kind": "ingredient_added", "ingredient_id": Uuid::nil() })
+    );
+    assert_eq!(
+        serde_json::from_value::<RevisionChange>(json).unwrap(),
+        change
+    );
+}
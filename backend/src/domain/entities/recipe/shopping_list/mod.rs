@@ -0,0 +1,125 @@
+use common::{ShoppingListDTO, ShoppingListItemDTO};
+
+use crate::domain::entities::ingredient::Ingredient;
+
+use super::{IngredientUnit, Recipe};
+
+/// An ingredient to buy, along with how much of it is needed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShoppingListItem {
+    pub ingredient: Ingredient,
+    /// Amounts which can't be added up with each other, e.g. grams and heads of broccoli
+    pub amounts: Vec<IngredientUnit>,
+}
+
+impl ShoppingListItem {
+    fn add(&mut self, amount: &IngredientUnit) {
+        let combined = self
+            .amounts
+            .iter_mut()
+            .find_map(|existing| Some((existing.combine(amount)?, existing)));
+
+        match combined {
+            Some((sum, existing)) => *existing = sum,
+            None => self.amounts.push(amount.clone()),
+        }
+    }
+
+    fn to_line(&self) -> String {
+        let amounts: Vec<String> = self.amounts.iter().map(IngredientUnit::describe).collect();
+
+        format!("- {}: {}", self.ingredient.name, amounts.join(", "))
+    }
+}
+
+/// Everything needed to make a couple of recipes, with each ingredient listed once.
+/// Ingredients are sorted by their name.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ShoppingList {
+    pub items: Vec<ShoppingListItem>,
+    /// Ingredients the recipes can do without. An ingredient which is optional in one recipe
+    /// but not in another is in both lists, with the amounts of the respective recipes
+    pub optional: Vec<ShoppingListItem>,
+}
+
+impl ShoppingList {
+    /// Adds up the ingredients of the recipes, in the amounts they're in.
+    /// Recipes have to be scaled to the wanted servings beforehand.
+    pub fn from_recipes(recipes: &[Recipe]) -> Self {
+        let mut list = Self::default();
+
+        for ingredient in recipes.iter().flat_map(|r| r.ingredients.iter()) {
+            let items = if ingredient.optional {
+                &mut list.optional
+            } else {
+                &mut list.items
+            };
+
+            match items
+                .iter_mut()
+                .find(|item| item.ingredient.id == ingredient.ingredient.id)
+            {
+                Some(item) => item.add(&ingredient.amount),
+                None => items.push(ShoppingListItem {
+                    ingredient: ingredient.ingredient.clone(),
+                    amounts: vec![ingredient.amount.clone()],
+                }),
+            }
+        }
+
+        for items in [&mut list.items, &mut list.optional] {
+            items.sort_by_key(|item| item.ingredient.name.0.to_lowercase());
+            items
+                .iter_mut()
+                .flat_map(|item| item.amounts.iter_mut())
+                .for_each(|amount| *amount = amount.to_kitchen_precision());
+        }
+
+        list
+    }
+
+    /// Renders the list as plain text, one ingredient per line.
+    /// ```rust
+    /// use backend::domain::entities::recipe::shopping_list::ShoppingList;
+    ///
+    /// assert_eq!(ShoppingList::default().to_text(), "");
+    /// ```
+    pub fn to_text(&self) -> String {
+        let mut lines: Vec<String> = self.items.iter().map(ShoppingListItem::to_line).collect();
+
+        if !self.optional.is_empty() {
+            if !lines.is_empty() {
+                lines.push(String::new());
+            }
+
+            lines.push("Optional:".to_string());
+            lines.extend(self.optional.iter().map(ShoppingListItem::to_line));
+        }
+
+        lines.join("\n")
+    }
+}
+
+impl From<ShoppingListItem> for ShoppingListItemDTO {
+    fn from(value: ShoppingListItem) -> Self {
+        Self {
+            ingredient: value.ingredient.into(),
+            amounts: value.amounts.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<ShoppingList> for ShoppingListDTO {
+    fn from(value: ShoppingList) -> Self {
+        let text = value.to_text();
+
+        Self {
+            items: value.items.into_iter().map(Into::into).collect(),
+            optional: value.optional.into_iter().map(Into::into).collect(),
+            text,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
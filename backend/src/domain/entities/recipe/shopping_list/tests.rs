@@ -0,0 +1,133 @@
+use pretty_assertions::assert_eq;
+use uuid::Uuid;
+
+use crate::{
+    domain::entities::{
+        ingredient::{
+            types::{Allergens, WhichDiets},
+            Ingredient,
+        },
+        recipe::{IngredientUnit, IngredientWithAmount, Recipe},
+    },
+    test_utils::recipe_fixture,
+};
+
+use super::ShoppingList;
+
+fn ingredient(id: u128, name: &str) -> Ingredient {
+    Ingredient {
+        id: Uuid::from_u128(id),
+        name: name.try_into().unwrap(),
+        description: "An ingredient".try_into().unwrap(),
+        diet_violations: WhichDiets::new(),
+        parent_id: None,
+        allergens: Allergens::new(),
+    }
+}
+
+fn recipe(ingredients: Vec<(Ingredient, IngredientUnit, bool)>) -> Recipe {
+    Recipe {
+        ingredients: ingredients
+            .into_iter()
+            .map(|(ingredient, amount, optional)| IngredientWithAmount {
+                ingredient,
+                amount,
+                optional,
+                notes: None,
+            })
+            .collect::<Vec<_>>()
+            .try_into()
+            .unwrap(),
+        ..recipe_fixture()
+    }
+}
+
+#[test]
+fn amounts_in_compatible_units_are_added_up() {
+    let milk = ingredient(1, "Milk");
+    let flour = ingredient(2, "flour");
+
+    let list = ShoppingList::from_recipes(&[
+        recipe(vec![
+            (milk.clone(), IngredientUnit::Cups(1.0), false),
+            (flour.clone(), IngredientUnit::Grams(200.0), false),
+        ]),
+        recipe(vec![
+            (milk.clone(), IngredientUnit::Mililiters(100.0), false),
+            (flour.clone(), IngredientUnit::Grams(150.0), false),
+        ]),
+    ]);
+
+    assert_eq!(list.items.len(), 2);
+    // Sorted by name, regardless of the case
+    assert_eq!(list.items[0].ingredient, flour);
+    assert_eq!(list.items[0].amounts, vec![IngredientUnit::Grams(350.0)]);
+    assert_eq!(list.items[1].ingredient, milk);
+    assert_eq!(
+        list.items[1].amounts,
+        vec![IngredientUnit::Mililiters(335.0)]
+    );
+    assert!(list.optional.is_empty());
+}
+
+#[test]
+fn amounts_in_incompatible_units_are_kept_apart() {
+    let garlic = ingredient(1, "Garlic");
+    let cloves = |amount| IngredientUnit::Other {
+        amount,
+        unit: "clove".to_string(),
+    };
+
+    let list = ShoppingList::from_recipes(&[
+        recipe(vec![(garlic.clone(), cloves(2.0), false)]),
+        recipe(vec![(garlic.clone(), IngredientUnit::Grams(10.0), false)]),
+        recipe(vec![(
+            garlic.clone(),
+            IngredientUnit::Other {
+                amount: 1.0,
+                unit: "head".to_string(),
+            },
+            false,
+        )]),
+        recipe(vec![(garlic.clone(), cloves(3.0), false)]),
+    ]);
+
+    assert_eq!(list.items.len(), 1);
+    assert_eq!(
+        list.items[0].amounts,
+        vec![
+            cloves(5.0),
+            IngredientUnit::Grams(10.0),
+            IngredientUnit::Other {
+                amount: 1.0,
+                unit: "head".to_string(),
+            },
+        ]
+    );
+    assert_eq!(list.to_text(), "- Garlic: 5 clove, 10 g, 1 head");
+}
+
+#[test]
+fn optional_ingredients_are_listed_separately() {
+    let chili = ingredient(1, "Chili flakes");
+    let rice = ingredient(2, "Rice");
+
+    let list = ShoppingList::from_recipes(&[
+        recipe(vec![
+            (chili.clone(), IngredientUnit::Teaspoons(1.0), true),
+            (rice.clone(), IngredientUnit::Grams(300.0), false),
+        ]),
+        recipe(vec![(chili.clone(), IngredientUnit::Teaspoons(0.5), false)]),
+    ]);
+
+    assert_eq!(
+        list.to_text(),
+        "- Chili flakes: 0.5 tsp\n- Rice: 300 g\n\nOptional:\n- Chili flakes: 1 tsp"
+    );
+
+    let only_optional =
+        ShoppingList::from_recipes(&[recipe(vec![(chili, IngredientUnit::Teaspoons(1.0), true)])]);
+
+    assert!(only_optional.items.is_empty());
+    assert_eq!(only_optional.to_text(), "Optional:\n- Chili flakes: 1 tsp");
+}
@@ -1,11 +1,15 @@
+use common::RecipeDTO;
 use uuid::Uuid;
 
-use crate::domain::entities::{
-    ingredient::{
-        types::{IngredientDescription, IngredientName, WhichDiets},
-        Ingredient, IngredientModel,
+use crate::{
+    domain::entities::{
+        ingredient::{
+            types::{Allergen, Allergens, IngredientDescription, IngredientName, WhichDiets},
+            Ingredient, IngredientModel,
+        },
+        recipe::errors::ValidationError,
     },
-    recipe::errors::ValidationError,
+    test_utils::recipe_fixture,
 };
 
 use super::{IngredientWithAmount, IngredientWithAmountModel};
@@ -19,6 +23,8 @@ fn converting_ingredient_with_amount_works() {
             name: "Ingredient name".to_owned(),
             description: "Ingredient description".to_owned(),
             diet_violations: vec![],
+            parent_id: None,
+            allergens: vec![],
         },
         amount: serde_json::json!({
             "grams": 20
@@ -33,6 +39,8 @@ fn converting_ingredient_with_amount_works() {
             name: IngredientName("Ingredient name".to_owned()),
             description: IngredientDescription("Ingredient description".to_owned()),
             diet_violations: WhichDiets::new(),
+            parent_id: None,
+            allergens: Allergens::new(),
         },
         amount: super::IngredientUnit::Grams(20.0),
         notes: None,
@@ -53,6 +61,8 @@ fn converting_ingredient_with_custom_amount_unit_works() {
             name: "Ingredient name".to_owned(),
             description: "Ingredient description".to_owned(),
             diet_violations: vec![],
+            parent_id: None,
+            allergens: vec![],
         },
         amount: serde_json::json!({
             "other": {
@@ -70,6 +80,8 @@ fn converting_ingredient_with_custom_amount_unit_works() {
             name: IngredientName("Ingredient name".to_owned()),
             description: IngredientDescription("Ingredient description".to_owned()),
             diet_violations: WhichDiets::new(),
+            parent_id: None,
+            allergens: Allergens::new(),
         },
         amount: super::IngredientUnit::Other {
             unit: "cloves".to_owned(),
@@ -93,6 +105,8 @@ fn converting_ingredient_with_custom_amount_unit_but_without_unit_descriptor_fai
             name: "Ingredient name".to_owned(),
             description: "Ingredient description".to_owned(),
             diet_violations: vec![],
+            parent_id: None,
+            allergens: vec![],
         },
         amount: serde_json::json!({
             "other": {
@@ -121,6 +135,8 @@ fn malformed_ingredient_amount_fails() {
             name: "Ingredient name".to_owned(),
             description: "Ingredient description".to_owned(),
             diet_violations: vec![],
+            parent_id: None,
+            allergens: vec![],
         },
         amount: serde_json::json!("10 grams"),
         notes: None,
@@ -135,3 +151,16 @@ fn malformed_ingredient_amount_fails() {
         ValidationError::DeserializationFailed("amount", _)
     ))
 }
+
+#[test]
+fn allergens_of_all_ingredients_are_collected() {
+    let mut recipe = recipe_fixture();
+    recipe.ingredients[1].ingredient.allergens = vec![Allergen::Sesame, Allergen::Soy].into();
+
+    assert!(!recipe.is_free_from(&Allergen::Soy));
+    assert!(!recipe.is_free_from(&Allergen::Sesame));
+    assert!(recipe.is_free_from(&Allergen::Milk));
+
+    let dto: RecipeDTO = recipe.into();
+    assert_eq!(dto.allergens, vec!["sesame".to_string(), "soy".to_string()]);
+}
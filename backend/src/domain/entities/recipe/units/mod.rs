@@ -0,0 +1,416 @@
+use std::str::FromStr;
+
+use strum::{EnumString, VariantNames};
+
+use super::{errors::ValidationError, IngredientUnit};
+
+pub const MILILITERS_PER_TEASPOON: f64 = 4.928_921_593_75;
+pub const MILILITERS_PER_TABLESPOON: f64 = MILILITERS_PER_TEASPOON * 3.0;
+pub const MILILITERS_PER_CUP: f64 = 236.588_236_5;
+pub const MILILITERS_PER_FLUID_OUNCE: f64 = 29.573_529_562_5;
+pub const GRAMS_PER_OUNCE: f64 = 28.349_523_125;
+pub const GRAMS_PER_POUND: f64 = 453.592_37;
+
+/// Anything smaller than a quarter of a cup is shown in teaspoons in the US system.
+const SMALLEST_US_CUP_AMOUNT: f64 = 0.25;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumString, VariantNames)]
+#[strum(serialize_all = "snake_case")]
+pub enum UnitSystem {
+    Metric,
+    Us,
+}
+
+/// What an amount measures, only units of the same kind can be converted between each other
+/// without any additional information.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnitKind {
+    Volume,
+    Mass,
+    Other,
+}
+
+/// How many grams a mililiter of an ingredient weighs.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Density(pub f64);
+
+impl Density {
+    pub const WATER: Self = Self(1.0);
+}
+
+/// Units an amount can be converted into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, strum::Display)]
+#[strum(serialize_all = "snake_case")]
+pub enum TargetUnit {
+    Mililiters,
+    Grams,
+    Teaspoons,
+    Cups,
+}
+
+impl IngredientUnit {
+    pub fn kind(&self) -> UnitKind {
+        match self {
+            Self::Mililiters(_) | Self::Teaspoons(_) | Self::Cups(_) => UnitKind::Volume,
+            Self::Grams(_) => UnitKind::Mass,
+            Self::Other { .. } => UnitKind::Other,
+        }
+    }
+
+    pub fn amount(&self) -> f64 {
+        match self {
+            Self::Mililiters(amount)
+            | Self::Grams(amount)
+            | Self::Teaspoons(amount)
+            | Self::Cups(amount)
+            | Self::Other { amount, .. } => *amount,
+        }
+    }
+
+    /// The amount followed by the unit, the way it's written down in a recipe.
+    pub fn describe(&self) -> String {
+        match self {
+            Self::Mililiters(amount) => format!("{amount} ml"),
+            Self::Grams(amount) => format!("{amount} g"),
+            Self::Teaspoons(amount) => format!("{amount} tsp"),
+            Self::Cups(amount) => format!("{amount} cups"),
+            Self::Other { amount, unit } => format!("{amount} {unit}"),
+        }
+    }
+
+    /// The amount in mililiters. Mass can only be converted when the density is known.
+    pub fn as_mililiters(&self, density: Option<Density>) -> Result<f64, ValidationError> {
+        match (self, density) {
+            (Self::Mililiters(amount), _) => Ok(*amount),
+            (Self::Teaspoons(amount), _) => Ok(amount * MILILITERS_PER_TEASPOON),
+            (Self::Cups(amount), _) => Ok(amount * MILILITERS_PER_CUP),
+            (Self::Grams(amount), Some(Density(density))) if density > 0.0 => Ok(amount / density),
+            _ => Err(ValidationError::IncompatibleUnits(
+                self.describe(),
+                TargetUnit::Mililiters.to_string(),
+            )),
+        }
+    }
+
+    /// The amount in grams. Volume can only be converted when the density is known.
+    pub fn as_grams(&self, density: Option<Density>) -> Result<f64, ValidationError> {
+        match (self.kind(), density) {
+            (UnitKind::Mass, _) => Ok(self.amount()),
+            (UnitKind::Volume, Some(Density(density))) => Ok(self.as_mililiters(None)? * density),
+            _ => Err(ValidationError::IncompatibleUnits(
+                self.describe(),
+                TargetUnit::Grams.to_string(),
+            )),
+        }
+    }
+
+    /// Converts the amount into a different unit.
+    /// ```rust
+    /// use backend::domain::entities::recipe::{units::TargetUnit, IngredientUnit};
+    ///
+    /// let result = IngredientUnit::Cups(1.0)
+    ///     .convert(TargetUnit::Teaspoons, None)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(result, IngredientUnit::Teaspoons(48.0))
+    /// ```
+    pub fn convert(
+        &self,
+        target: TargetUnit,
+        density: Option<Density>,
+    ) -> Result<Self, ValidationError> {
+        let result = match target {
+            TargetUnit::Grams => Self::Grams(self.as_grams(density)?),
+            TargetUnit::Mililiters => Self::Mililiters(self.as_mililiters(density)?),
+            TargetUnit::Teaspoons => {
+                Self::Teaspoons(self.as_mililiters(density)? / MILILITERS_PER_TEASPOON)
+            }
+            TargetUnit::Cups => Self::Cups(self.as_mililiters(density)? / MILILITERS_PER_CUP),
+        };
+
+        Ok(result.rounded())
+    }
+
+    /// Expresses the amount in the units of the given system.
+    /// Mass stays in grams, as there is no imperial mass unit to convert to, and custom units are left alone.
+    pub fn to_system(&self, system: UnitSystem) -> Self {
+        let converted = match (system, self) {
+            (UnitSystem::Metric, Self::Teaspoons(_) | Self::Cups(_)) => {
+                self.convert(TargetUnit::Mililiters, None)
+            }
+            (UnitSystem::Us, Self::Mililiters(amount)) => {
+                if amount / MILILITERS_PER_CUP < SMALLEST_US_CUP_AMOUNT {
+                    self.convert(TargetUnit::Teaspoons, None)
+                } else {
+                    self.convert(TargetUnit::Cups, None)
+                }
+            }
+            _ => Ok(self.clone()),
+        };
+
+        converted.unwrap_or_else(|_| self.clone())
+    }
+
+    /// Adds up two amounts, as long as they're in the same unit.
+    pub fn plus(&self, other: &Self) -> Option<Self> {
+        let result = match (self, other) {
+            (Self::Mililiters(a), Self::Mililiters(b)) => Self::Mililiters(a + b),
+            (Self::Grams(a), Self::Grams(b)) => Self::Grams(a + b),
+            (Self::Teaspoons(a), Self::Teaspoons(b)) => Self::Teaspoons(a + b),
+            (Self::Cups(a), Self::Cups(b)) => Self::Cups(a + b),
+            (
+                Self::Other { amount: a, unit },
+                Self::Other {
+                    amount: b,
+                    unit: other_unit,
+                },
+            ) if unit == other_unit => Self::Other {
+                amount: a + b,
+                unit: unit.clone(),
+            },
+            _ => return None,
+        };
+
+        Some(result)
+    }
+
+    /// Adds up two amounts of the same kind. Volumes in different units are added up in mililiters,
+    /// while custom units still have to be the same.
+    pub fn combine(&self, other: &Self) -> Option<Self> {
+        if let Some(sum) = self.plus(other) {
+            return Some(sum);
+        }
+
+        let a = self.as_mililiters(None).ok()?;
+        let b = other.as_mililiters(None).ok()?;
+
+        Some(Self::Mililiters(a + b).rounded())
+    }
+
+    /// Takes the other amount away, in the same way as they'd be added up with [`Self::combine`].
+    /// Never goes below zero.
+    /// ```rust
+    /// use backend::domain::entities::recipe::IngredientUnit;
+    ///
+    /// assert_eq!(IngredientUnit::Grams(300.0).minus(&IngredientUnit::Grams(100.0)), Some(IngredientUnit::Grams(200.0)));
+    /// assert_eq!(IngredientUnit::Grams(100.0).minus(&IngredientUnit::Grams(300.0)), Some(IngredientUnit::Grams(0.0)));
+    /// assert_eq!(IngredientUnit::Grams(100.0).minus(&IngredientUnit::Cups(1.0)), None);
+    /// ```
+    pub fn minus(&self, other: &Self) -> Option<Self> {
+        let mut difference = self.combine(&other.scaled(-1.0))?;
+
+        match &mut difference {
+            Self::Mililiters(amount)
+            | Self::Grams(amount)
+            | Self::Teaspoons(amount)
+            | Self::Cups(amount)
+            | Self::Other { amount, .. } => *amount = amount.max(0.0),
+        };
+
+        Some(difference)
+    }
+
+    /// Multiplies the amount, keeping the unit as it is.
+    pub fn scaled(&self, factor: f64) -> Self {
+        let mut result = self.clone();
+
+        match &mut result {
+            Self::Mililiters(amount)
+            | Self::Grams(amount)
+            | Self::Teaspoons(amount)
+            | Self::Cups(amount)
+            | Self::Other { amount, .. } => *amount *= factor,
+        };
+
+        result
+    }
+
+    /// Rounds the amount to something that can actually be measured in a kitchen.
+    /// Spoons and cups go to the nearest eighth, custom units to the nearest quarter,
+    /// and grams and mililiters get coarser the bigger they are.
+    /// Anything that isn't zero stays above zero.
+    /// ```rust
+    /// use backend::domain::entities::recipe::IngredientUnit;
+    ///
+    /// assert_eq!(IngredientUnit::Grams(123.4).to_kitchen_precision(), IngredientUnit::Grams(125.0));
+    /// assert_eq!(IngredientUnit::Teaspoons(0.3).to_kitchen_precision(), IngredientUnit::Teaspoons(0.25));
+    /// ```
+    pub fn to_kitchen_precision(&self) -> Self {
+        let round_to = |amount: f64, step: f64| {
+            let rounded = (amount / step).round() * step;
+
+            if rounded == 0.0 && amount > 0.0 {
+                step
+            } else {
+                rounded
+            }
+        };
+
+        let metric_step = |amount: f64| match amount {
+            a if a < 10.0 => 0.5,
+            a if a < 100.0 => 1.0,
+            _ => 5.0,
+        };
+
+        match self {
+            Self::Mililiters(amount) => Self::Mililiters(round_to(*amount, metric_step(*amount))),
+            Self::Grams(amount) => Self::Grams(round_to(*amount, metric_step(*amount))),
+            Self::Teaspoons(amount) => Self::Teaspoons(round_to(*amount, 0.125)),
+            Self::Cups(amount) => Self::Cups(round_to(*amount, 0.125)),
+            Self::Other { amount, unit } => Self::Other {
+                amount: round_to(*amount, 0.25),
+                unit: unit.clone(),
+            },
+        }
+    }
+
+    /// Gets rid of the floating point noise conversions leave behind
+    fn rounded(self) -> Self {
+        let round = |amount: f64| (amount * 100.0).round() / 100.0;
+
+        match self {
+            Self::Mililiters(amount) => Self::Mililiters(round(amount)),
+            Self::Grams(amount) => Self::Grams(round(amount)),
+            Self::Teaspoons(amount) => Self::Teaspoons(round(amount)),
+            Self::Cups(amount) => Self::Cups(round(amount)),
+            Self::Other { amount, unit } => Self::Other {
+                amount: round(amount),
+                unit,
+            },
+        }
+    }
+}
+
+/// Parses a single number, a fraction like `1/2`, or a vulgar fraction like `½`.
+fn parse_number(input: &str) -> Option<f64> {
+    let vulgar = |c: char| match c {
+        '¼' => Some(0.25),
+        '½' => Some(0.5),
+        '¾' => Some(0.75),
+        '⅓' => Some(1.0 / 3.0),
+        '⅔' => Some(2.0 / 3.0),
+        '⅛' => Some(0.125),
+        _ => None,
+    };
+
+    if let Some((numerator, denominator)) = input.split_once('/') {
+        let numerator: f64 = numerator.parse().ok()?;
+        let denominator: f64 = denominator.parse().ok()?;
+
+        return (denominator != 0.0)
+            .then(|| numerator / denominator)
+            .filter(|n| n.is_finite());
+    }
+
+    // Handles both `½` and `1½`
+    if let Some(last) = input.chars().last().and_then(vulgar) {
+        let whole = &input[..input.len() - input.chars().last()?.len_utf8()];
+        let whole: f64 = if whole.is_empty() {
+            0.0
+        } else {
+            whole.parse().ok()?
+        };
+
+        return Some(whole + last);
+    }
+
+    input
+        .replace(',', ".")
+        .parse()
+        .ok()
+        .filter(|n: &f64| n.is_finite())
+}
+
+/// Splits the input into the amount and whatever follows it.
+/// Mixed numbers like `1 1/2` are added together.
+pub(super) fn split_amount(input: &str) -> Option<(f64, String)> {
+    let mut words = input.split_whitespace().peekable();
+    let mut amount = parse_number(words.next()?)?;
+
+    if let Some(fraction) = words
+        .peek()
+        .filter(|word| word.contains('/') || word.chars().all(|c| !c.is_ascii()))
+        .and_then(|word| parse_number(word))
+    {
+        amount += fraction;
+        words.next();
+    }
+
+    Some((amount, words.collect::<Vec<_>>().join(" ")))
+}
+
+/// Splits inputs where the unit is glued to the amount, like `200g`.
+pub(super) fn separate_unit(input: &str) -> String {
+    match input.find(|c: char| c.is_alphabetic() && !"¼½¾⅓⅔⅛".contains(c)) {
+        Some(index) if index > 0 && !input[..index].ends_with(char::is_whitespace) => {
+            format!("{} {}", &input[..index], &input[index..])
+        }
+        _ => input.to_string(),
+    }
+}
+
+impl FromStr for IngredientUnit {
+    type Err = ValidationError;
+
+    /// Parses amounts the way they're usually written down in recipes, like `1 1/2 tbsp` or `200 g`.
+    /// Units that aren't recognised end up as [`IngredientUnit::Other`].
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let fail = || ValidationError::MeasurementComputation(input.to_string());
+
+        let (amount, unit) = split_amount(&separate_unit(input.trim())).ok_or_else(fail)?;
+
+        if unit.is_empty() {
+            return Err(fail());
+        }
+
+        Self::from_amount(amount, &unit).ok_or_else(fail)
+    }
+}
+
+impl IngredientUnit {
+    /// The amount in the given unit, converted if it's one of the units we know about.
+    /// Returns `None` when the amount doesn't end up as a finite, non-negative number.
+    pub(super) fn from_amount(amount: f64, unit: &str) -> Option<Self> {
+        Self::from_known_unit(amount, unit)
+            .unwrap_or(Self::Other {
+                amount,
+                unit: unit.to_string(),
+            })
+            .validated()
+    }
+
+    /// Keeps the amount only if it's a finite, non-negative number.
+    /// Anything else can't be computed with, nor written out as JSON and read back.
+    pub(super) fn validated(self) -> Option<Self> {
+        let amount = self.amount();
+
+        (amount.is_finite() && amount >= 0.0).then_some(self)
+    }
+
+    /// The amount in one of the units we know how to convert, if the unit is one of them.
+    pub(super) fn from_known_unit(amount: f64, unit: &str) -> Option<Self> {
+        let normalized = unit.to_lowercase();
+
+        let result = match normalized.trim_end_matches('.') {
+            "ml" | "milliliter" | "milliliters" | "millilitre" | "millilitres" | "mililiter"
+            | "mililiters" => Self::Mililiters(amount),
+            "l" | "liter" | "liters" | "litre" | "litres" => Self::Mililiters(amount * 1000.0),
+            "g" | "gram" | "grams" | "gramme" | "grammes" => Self::Grams(amount),
+            "kg" | "kilogram" | "kilograms" => Self::Grams(amount * 1000.0),
+            "oz" | "ounce" | "ounces" => Self::Grams(amount * GRAMS_PER_OUNCE).rounded(),
+            "lb" | "lbs" | "pound" | "pounds" => Self::Grams(amount * GRAMS_PER_POUND).rounded(),
+            "tsp" | "teaspoon" | "teaspoons" => Self::Teaspoons(amount),
+            "tbsp" | "tablespoon" | "tablespoons" => Self::from_tablespoons(amount),
+            "cup" | "cups" => Self::Cups(amount),
+            "fl oz" | "fluid ounce" | "fluid ounces" => {
+                Self::Mililiters(amount * MILILITERS_PER_FLUID_OUNCE).rounded()
+            }
+            _ => return None,
+        };
+
+        Some(result)
+    }
+}
+
+#[cfg(test)]
+mod tests;
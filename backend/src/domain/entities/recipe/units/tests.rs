@@ -0,0 +1,206 @@
+use crate::domain::entities::recipe::{errors::ValidationError, IngredientUnit};
+
+use super::{parse_number, Density, TargetUnit, UnitSystem};
+
+#[test]
+fn parsing_amounts_works() {
+    let cases = [
+        ("200 g", IngredientUnit::Grams(200.0)),
+        ("200g", IngredientUnit::Grams(200.0)),
+        ("1.5 kg", IngredientUnit::Grams(1500.0)),
+        ("1 1/2 tbsp", IngredientUnit::Teaspoons(4.5)),
+        ("1/2 cup", IngredientUnit::Cups(0.5)),
+        ("1½ cups", IngredientUnit::Cups(1.5)),
+        ("1 ½ tsp", IngredientUnit::Teaspoons(1.5)),
+        ("0,5 l", IngredientUnit::Mililiters(500.0)),
+        ("250 ml", IngredientUnit::Mililiters(250.0)),
+        ("1 lb", IngredientUnit::Grams(453.59)),
+        (
+            "4 Cloves",
+            IngredientUnit::Other {
+                amount: 4.0,
+                unit: "Cloves".to_string(),
+            },
+        ),
+    ];
+
+    for (input, expected) in cases {
+        let result: IngredientUnit = input.parse().unwrap();
+        assert_eq!(result, expected, "parsing {input}");
+    }
+}
+
+#[test]
+fn parsing_garbage_errors() {
+    for input in ["", "a pinch of salt", "1/0 cup", "-2 g", "3"] {
+        let error = input.parse::<IngredientUnit>().unwrap_err();
+
+        assert!(
+            matches!(&error, ValidationError::MeasurementComputation(i) if i == input),
+            "parsing {input} returned {error:?}"
+        );
+    }
+}
+
+#[test]
+fn fractions_which_overflow_are_rejected() {
+    assert_eq!(parse_number("inf/1"), None);
+    assert_eq!(parse_number("1e308/1e-308"), None);
+
+    let input = format!("1{}/0.1 g", "0".repeat(308));
+    assert!(input.parse::<IngredientUnit>().is_err());
+}
+
+#[test]
+fn amounts_which_are_not_finite_are_rejected() {
+    // Exponents get split off as units, so the overflowing amounts are written out in full
+    let huge = format!("1{}", "0".repeat(306));
+    let inputs = [
+        "inf½ g".to_string(),
+        "NaN½ g".to_string(),
+        format!("{huge} kg"),
+        format!("{huge}00 {huge}00/1 g"),
+    ];
+
+    for input in inputs {
+        let error = input.parse::<IngredientUnit>().unwrap_err();
+
+        assert!(
+            matches!(&error, ValidationError::MeasurementComputation(i) if *i == input),
+            "parsing {input} returned {error:?}"
+        );
+    }
+
+    assert_eq!(IngredientUnit::from_amount(1e306, "kg"), None);
+    assert_eq!(IngredientUnit::from_amount(f64::NAN, "g"), None);
+}
+
+#[test]
+fn converting_between_volume_units_works() {
+    let cup = IngredientUnit::Cups(1.0);
+
+    assert_eq!(
+        cup.convert(TargetUnit::Mililiters, None).unwrap(),
+        IngredientUnit::Mililiters(236.59)
+    );
+    assert_eq!(
+        IngredientUnit::Teaspoons(3.0)
+            .convert(TargetUnit::Mililiters, None)
+            .unwrap(),
+        IngredientUnit::Mililiters(14.79)
+    );
+}
+
+#[test]
+fn converting_between_volume_and_mass_needs_density() {
+    let flour = Density(0.53);
+
+    assert_eq!(
+        IngredientUnit::Cups(1.0)
+            .convert(TargetUnit::Grams, Some(flour))
+            .unwrap(),
+        IngredientUnit::Grams(125.39)
+    );
+    assert_eq!(
+        IngredientUnit::Grams(100.0)
+            .convert(TargetUnit::Mililiters, Some(Density::WATER))
+            .unwrap(),
+        IngredientUnit::Mililiters(100.0)
+    );
+
+    let error = IngredientUnit::Cups(1.0)
+        .convert(TargetUnit::Grams, None)
+        .unwrap_err();
+    assert!(matches!(error, ValidationError::IncompatibleUnits(_, _)));
+}
+
+#[test]
+fn converting_custom_units_errors() {
+    let cloves = IngredientUnit::Other {
+        amount: 2.0,
+        unit: "cloves".to_string(),
+    };
+
+    let error = cloves
+        .convert(TargetUnit::Grams, Some(Density::WATER))
+        .unwrap_err();
+
+    assert!(
+        matches!(error, ValidationError::IncompatibleUnits(from, to) if from == "2 cloves" && to == "grams")
+    );
+}
+
+#[test]
+fn converting_to_unit_systems_works() {
+    assert_eq!(
+        IngredientUnit::Cups(2.0).to_system(UnitSystem::Metric),
+        IngredientUnit::Mililiters(473.18)
+    );
+    assert_eq!(
+        IngredientUnit::Mililiters(500.0).to_system(UnitSystem::Us),
+        IngredientUnit::Cups(2.11)
+    );
+    assert_eq!(
+        IngredientUnit::Mililiters(10.0).to_system(UnitSystem::Us),
+        IngredientUnit::Teaspoons(2.03)
+    );
+    assert_eq!(
+        IngredientUnit::Grams(10.0).to_system(UnitSystem::Us),
+        IngredientUnit::Grams(10.0)
+    );
+}
+
+#[test]
+fn combining_amounts_works() {
+    let head = |amount| IngredientUnit::Other {
+        amount,
+        unit: "head".to_string(),
+    };
+
+    assert_eq!(
+        IngredientUnit::Grams(200.0).combine(&IngredientUnit::Grams(50.0)),
+        Some(IngredientUnit::Grams(250.0))
+    );
+    assert_eq!(
+        IngredientUnit::Cups(1.0).combine(&IngredientUnit::Cups(0.5)),
+        Some(IngredientUnit::Cups(1.5))
+    );
+    assert_eq!(
+        IngredientUnit::Cups(1.0).combine(&IngredientUnit::Mililiters(100.0)),
+        Some(IngredientUnit::Mililiters(336.59))
+    );
+    assert_eq!(head(1.0).combine(&head(2.0)), Some(head(3.0)));
+
+    assert_eq!(
+        IngredientUnit::Grams(200.0).combine(&IngredientUnit::Mililiters(100.0)),
+        None
+    );
+    assert_eq!(head(1.0).combine(&IngredientUnit::Grams(100.0)), None);
+    assert_eq!(
+        head(1.0).combine(&IngredientUnit::Other {
+            amount: 1.0,
+            unit: "clove".to_string()
+        }),
+        None
+    );
+}
+
+#[test]
+fn amounts_of_the_same_kind_can_be_taken_away() {
+    assert_eq!(
+        IngredientUnit::Cups(1.0).minus(&IngredientUnit::Cups(0.25)),
+        Some(IngredientUnit::Cups(0.75))
+    );
+    assert_eq!(
+        IngredientUnit::Cups(1.0).minus(&IngredientUnit::Mililiters(100.0)),
+        Some(IngredientUnit::Mililiters(136.59))
+    );
+    assert_eq!(
+        IngredientUnit::Teaspoons(1.0).minus(&IngredientUnit::Cups(1.0)),
+        Some(IngredientUnit::Mililiters(0.0))
+    );
+    assert_eq!(
+        IngredientUnit::Grams(200.0).minus(&IngredientUnit::Mililiters(100.0)),
+        None
+    );
+}
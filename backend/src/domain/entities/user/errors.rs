@@ -0,0 +1,11 @@
+#[derive(thiserror::Error, Debug)]
+pub enum ValidationError {
+    #[error("The fields {0:?} was empty")]
+    EmptyField(Vec<&'static str>),
+
+    #[error("Field {0} is not formatted correctly")]
+    InvalidFormat(&'static str),
+
+    #[error(transparent)]
+    Unknown(#[from] eyre::Error),
+}
@@ -0,0 +1,75 @@
+pub mod errors;
+pub mod types;
+
+use common::user::UserDataDTO;
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use self::{
+    errors::ValidationError,
+    types::{PasswordHash, UserEmail, Username},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct User {
+    pub id: Uuid,
+    pub username: Username,
+    pub email: UserEmail,
+    pub password: PasswordHash,
+}
+
+impl From<User> for UserDataDTO {
+    fn from(value: User) -> Self {
+        Self {
+            username: value.username.to_string(),
+            email: value.email.to_string(),
+        }
+    }
+}
+
+impl From<&User> for UserDataDTO {
+    fn from(value: &User) -> Self {
+        Self {
+            username: value.username.to_string(),
+            email: value.email.to_string(),
+        }
+    }
+}
+
+#[derive(FromRow, Debug, Clone, PartialEq)]
+pub struct UserModel {
+    pub id: Uuid,
+    pub username: String,
+    pub email: String,
+    pub password_hash: String,
+}
+
+impl TryFrom<UserModel> for User {
+    type Error = ValidationError;
+    fn try_from(value: UserModel) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: value.id,
+            username: value.username.try_into()?,
+            email: value.email.try_into()?,
+            password: value.password_hash.try_into()?,
+        })
+    }
+}
+
+impl From<User> for UserModel {
+    fn from(
+        User {
+            id,
+            username,
+            email,
+            password,
+        }: User,
+    ) -> Self {
+        Self {
+            id,
+            username: username.to_string(),
+            email: email.to_string(),
+            password_hash: password.as_phc_string().to_string(),
+        }
+    }
+}
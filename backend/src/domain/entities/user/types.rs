@@ -0,0 +1,119 @@
+use argon2::{
+    password_hash::{rand_core::OsRng, SaltString},
+    Argon2, PasswordHasher, PasswordVerifier,
+};
+use secrecy::{ExposeSecret, Secret};
+use serde::{Deserialize, Serialize};
+use shrinkwraprs::Shrinkwrap;
+
+use super::errors::ValidationError;
+
+#[derive(Serialize, Deserialize, Shrinkwrap, sqlx::Type, Debug, Clone, PartialEq, Eq, Hash)]
+#[sqlx(transparent)]
+pub struct Username(pub String);
+
+impl std::fmt::Display for Username {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl TryFrom<String> for Username {
+    type Error = ValidationError;
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        if value.is_empty() {
+            return Err(ValidationError::EmptyField(vec!["username"]));
+        }
+        Ok(Self(value))
+    }
+}
+
+impl TryFrom<&str> for Username {
+    type Error = ValidationError;
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Self::try_from(value.to_string())
+    }
+}
+
+#[derive(Serialize, Deserialize, Shrinkwrap, sqlx::Type, Debug, Clone, PartialEq, Eq, Hash)]
+#[sqlx(transparent)]
+pub struct UserEmail(pub String);
+
+impl std::fmt::Display for UserEmail {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl TryFrom<String> for UserEmail {
+    type Error = ValidationError;
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        if value.is_empty() {
+            return Err(ValidationError::EmptyField(vec!["email"]));
+        }
+
+        match value.split_once('@') {
+            Some((local, domain)) if !local.is_empty() && !domain.is_empty() => Ok(Self(value)),
+            _ => Err(ValidationError::InvalidFormat("email")),
+        }
+    }
+}
+
+impl TryFrom<&str> for UserEmail {
+    type Error = ValidationError;
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Self::try_from(value.to_string())
+    }
+}
+
+/// An Argon2 hash of the user's password, stored in the PHC string format.
+#[derive(Debug, Clone)]
+pub struct PasswordHash(Secret<String>);
+
+impl PasswordHash {
+    /// Hashes a plaintext password with a freshly generated salt.
+    pub fn from_password(password: &str) -> Result<Self, ValidationError> {
+        if password.is_empty() {
+            return Err(ValidationError::EmptyField(vec!["password"]));
+        }
+
+        let salt = SaltString::generate(&mut OsRng);
+        let hash = Argon2::default()
+            .hash_password(password.as_bytes(), &salt)
+            .map_err(|e| eyre::eyre!("Could not hash the password: {e}"))?;
+
+        Ok(Self(Secret::new(hash.to_string())))
+    }
+
+    /// Checks a plaintext password against the stored hash.
+    pub fn verify(&self, password: &str) -> bool {
+        argon2::PasswordHash::new(self.0.expose_secret())
+            .map(|hash| {
+                Argon2::default()
+                    .verify_password(password.as_bytes(), &hash)
+                    .is_ok()
+            })
+            .unwrap_or(false)
+    }
+
+    pub fn as_phc_string(&self) -> &str {
+        self.0.expose_secret()
+    }
+}
+
+impl TryFrom<String> for PasswordHash {
+    type Error = ValidationError;
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        argon2::PasswordHash::new(&value)
+            .map_err(|_| ValidationError::InvalidFormat("password"))?;
+        Ok(Self(Secret::new(value)))
+    }
+}
+
+impl PartialEq for PasswordHash {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.expose_secret() == other.0.expose_secret()
+    }
+}
+
+impl Eq for PasswordHash {}
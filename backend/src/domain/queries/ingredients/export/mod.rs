@@ -0,0 +1,43 @@
+use crate::domain::{
+    entities::ingredient::formats::{IngredientFormat, IngredientFormatError},
+    repositories::ingredients::{
+        errors::GetAllIngredientsError as GetAllIngredientsErrorInternal,
+        IngredientRepositoryService,
+    },
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum ExportIngredientsError {
+    #[error("Field format does not match any of: {0:?}")]
+    InvalidFormat(&'static [&'static str]),
+
+    #[error(transparent)]
+    Unknown(#[from] eyre::Error),
+}
+
+impl From<GetAllIngredientsErrorInternal> for ExportIngredientsError {
+    fn from(value: GetAllIngredientsErrorInternal) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+impl From<IngredientFormatError> for ExportIngredientsError {
+    fn from(value: IngredientFormatError) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+/// Renders every ingredient in the given format, sorted by name, ready to be imported somewhere else.
+#[tracing::instrument("[QUERY] Export ingredients", skip(repo))]
+pub async fn export_ingredients(
+    repo: IngredientRepositoryService,
+    format: IngredientFormat,
+) -> Result<String, ExportIngredientsError> {
+    let mut ingredients = repo.get_all().await?;
+    ingredients.sort_by(|a, b| a.name.cmp(&b.name));
+
+    Ok(format.render(&ingredients)?)
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,39 @@
+use std::sync::Arc;
+
+use crate::{
+    domain::{
+        entities::ingredient::formats::IngredientFormat,
+        queries::ingredients::export::export_ingredients,
+        repositories::ingredients::{IngredientRepository, IngredientRepositoryService},
+    },
+    test_utils::{insert_all_ingredients_of_recipe, recipe_fixture},
+};
+
+pub async fn exporting_ingredients_works(repo: impl IngredientRepository) {
+    let recipe = recipe_fixture();
+    insert_all_ingredients_of_recipe(&repo, &recipe).await;
+
+    let repo: IngredientRepositoryService = Arc::new(Box::new(repo));
+
+    let document = export_ingredients(repo.clone(), IngredientFormat::Json)
+        .await
+        .unwrap();
+    let exported = IngredientFormat::Json.parse(&document).unwrap();
+
+    let mut expected: Vec<String> = recipe
+        .ingredients
+        .iter()
+        .map(|i| i.ingredient.name.to_string())
+        .collect();
+    expected.sort();
+    assert_eq!(
+        exported.into_iter().map(|i| i.name).collect::<Vec<_>>(),
+        expected
+    );
+
+    let document = export_ingredients(repo, IngredientFormat::Csv)
+        .await
+        .unwrap();
+    assert!(document.starts_with("name,description,diet_violations,parent_id,allergens\n"));
+    assert_eq!(document.lines().count(), recipe.ingredients.len() + 1);
+}
@@ -0,0 +1,24 @@
+mod __tests__;
+
+mod in_memory {
+    use crate::domain::repositories::ingredients::in_memory::InMemoryIngredientRepository;
+
+    use super::__tests__;
+
+    #[tokio::test]
+    async fn exporting_ingredients_works() {
+        __tests__::exporting_ingredients_works(InMemoryIngredientRepository::new()).await
+    }
+}
+
+mod sql {
+    use sqlx::PgPool;
+
+    use super::__tests__;
+    use crate::domain::repositories::ingredients::postgres::PostgresIngredientRepository;
+
+    #[sqlx::test]
+    async fn exporting_ingredients_works(pool: PgPool) {
+        __tests__::exporting_ingredients_works(PostgresIngredientRepository::new(pool)).await
+    }
+}
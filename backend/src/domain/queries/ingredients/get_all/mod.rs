@@ -1,5 +1,8 @@
 use crate::domain::{
-    entities::ingredient::Ingredient,
+    entities::{
+        ingredient::{Ingredient, IngredientSort},
+        pagination::{Page, PageRequest, PaginationError},
+    },
     repositories::ingredients::{
         errors::GetAllIngredientsError as GetAllIngredientsErrorInternal,
         IngredientRepositoryService,
@@ -8,6 +11,9 @@ use crate::domain::{
 
 #[derive(thiserror::Error, Debug, strum::AsRefStr)]
 pub enum GetAllIngredientsError {
+    #[error(transparent)]
+    Pagination(#[from] PaginationError),
+
     #[error(transparent)]
     Internal(#[from] eyre::Error),
 }
@@ -21,8 +27,11 @@ impl From<GetAllIngredientsErrorInternal> for GetAllIngredientsError {
 #[tracing::instrument("[QUERY] Get all ingredients", skip(repo))]
 pub async fn get_all_ingredients(
     repo: IngredientRepositoryService,
-) -> Result<Vec<Ingredient>, GetAllIngredientsError> {
-    repo.get_all().await.map_err(GetAllIngredientsError::from)
+    request: &PageRequest<IngredientSort>,
+) -> Result<Page<Ingredient>, GetAllIngredientsError> {
+    repo.get_page(request)
+        .await
+        .map_err(GetAllIngredientsError::from)
 }
 
 #[cfg(test)]
@@ -3,9 +3,12 @@ use std::sync::Arc;
 use uuid::Uuid;
 
 use crate::domain::{
-    entities::ingredient::{
-        types::{DietViolations, IngredientDescription, IngredientName, WhichDiets},
-        Ingredient,
+    entities::{
+        ingredient::{
+            types::{Allergens, DietViolations, IngredientDescription, IngredientName, WhichDiets},
+            Ingredient, IngredientSort,
+        },
+        pagination::PageRequest,
     },
     queries::ingredients::get_all::get_all_ingredients,
     repositories::ingredients::{IngredientRepository, IngredientRepositoryService},
@@ -18,10 +21,13 @@ pub async fn returns_empty_vec_when_no_items_inside(repo: impl IngredientReposit
     let repo: IngredientRepositoryService = Arc::new(Box::new(repo));
 
     // WHEN
-    let result = get_all_ingredients(repo).await.unwrap();
+    let result = get_all_ingredients(repo, &PageRequest::default())
+        .await
+        .unwrap();
 
     // THEN
-    assert_eq!(result, vec![]);
+    assert_eq!(result.items, vec![]);
+    assert_eq!(result.next_cursor, None);
 }
 
 pub async fn returns_vec_of_items_inside(repo: impl IngredientRepository) {
@@ -32,6 +38,8 @@ pub async fn returns_vec_of_items_inside(repo: impl IngredientRepository) {
         name: IngredientName("Tomato".into()),
         description: IngredientDescription("Description of a tomato".into()),
         diet_violations: vec![DietViolations::Vegan, DietViolations::Vegetarian].into(),
+        parent_id: None,
+        allergens: Allergens::new(),
     };
 
     let given_2 = Ingredient {
@@ -39,13 +47,18 @@ pub async fn returns_vec_of_items_inside(repo: impl IngredientRepository) {
         name: IngredientName("Meat fries".into()),
         description: IngredientDescription("Description of meat fries (whatever they are)".into()),
         diet_violations: WhichDiets::new(),
+        parent_id: None,
+        allergens: Allergens::new(),
     };
 
     repo.insert(given_1.clone()).await.unwrap();
     repo.insert(given_2.clone()).await.unwrap();
 
     // WHEN
-    let mut result = get_all_ingredients(repo).await.unwrap();
+    let mut result = get_all_ingredients(repo, &PageRequest::default())
+        .await
+        .unwrap()
+        .items;
     result.sort_by_key(|k| k.id);
 
     let mut expected = vec![given_1, given_2];
@@ -54,3 +67,64 @@ pub async fn returns_vec_of_items_inside(repo: impl IngredientRepository) {
     // THEN
     assert_eq!(result, expected);
 }
+
+pub async fn paginating_ingredients_works(repo: impl IngredientRepository) {
+    // GIVEN
+    let repo: IngredientRepositoryService = Arc::new(Box::new(repo));
+    let names = ["Carrot", "Apple", "Banana"];
+    let mut given = vec![];
+
+    // IDs are time-ordered, so the later ones are the newer ones
+    for (i, name) in (1..).zip(names) {
+        let ingredient = Ingredient {
+            id: Uuid::from_u128(i),
+            name: IngredientName(name.into()),
+            description: IngredientDescription(format!("Description of {name}")),
+            diet_violations: WhichDiets::new(),
+            parent_id: None,
+            allergens: Allergens::new(),
+        };
+        repo.insert(ingredient.clone()).await.unwrap();
+        given.push(ingredient);
+    }
+
+    // WHEN
+    let request = PageRequest {
+        limit: 2,
+        cursor: None,
+        sort: IngredientSort::Name,
+    };
+    let first = get_all_ingredients(repo.clone(), &request).await.unwrap();
+    let second = get_all_ingredients(
+        repo.clone(),
+        &PageRequest {
+            cursor: first.next_cursor,
+            ..request.clone()
+        },
+    )
+    .await
+    .unwrap();
+
+    // THEN
+    let first_names: Vec<String> = first.items.iter().map(|i| i.name.to_string()).collect();
+    assert_eq!(first_names, vec!["Apple", "Banana"]);
+    assert_eq!(first.next_cursor, Some(given[2].id));
+    assert_eq!(second.items, vec![given[0].clone()]);
+    assert_eq!(second.next_cursor, None);
+
+    // WHEN
+    let newest_first = get_all_ingredients(
+        repo,
+        &PageRequest {
+            limit: 3,
+            cursor: None,
+            sort: IngredientSort::CreatedAt,
+        },
+    )
+    .await
+    .unwrap();
+
+    // THEN
+    given.reverse();
+    assert_eq!(newest_first.items, given);
+}
@@ -15,6 +15,12 @@ mod in_memory {
         let repo = InMemoryIngredientRepository::new();
         __tests__::returns_vec_of_items_inside(repo).await;
     }
+
+    #[tokio::test]
+    async fn paginating_ingredients_works() {
+        let repo = InMemoryIngredientRepository::new();
+        __tests__::paginating_ingredients_works(repo).await;
+    }
 }
 
 mod sql {
@@ -34,4 +40,10 @@ mod sql {
         let repo = PostgresIngredientRepository::new(pool);
         __tests__::returns_vec_of_items_inside(repo).await;
     }
+
+    #[sqlx::test]
+    async fn paginating_ingredients_works(pool: PgPool) {
+        let repo = PostgresIngredientRepository::new(pool);
+        __tests__::paginating_ingredients_works(repo).await;
+    }
 }
@@ -3,7 +3,10 @@ use std::sync::Arc;
 use uuid::Uuid;
 
 use crate::domain::{
-    entities::ingredient::{types::WhichDiets, Ingredient},
+    entities::ingredient::{
+        types::{Allergens, WhichDiets},
+        Ingredient,
+    },
     queries::ingredients::get_by_id::{get_ingredient_by_id, GetIngredientError},
     repositories::ingredients::{IngredientRepository, IngredientRepositoryService},
 };
@@ -14,6 +17,8 @@ pub async fn get_by_id_returns_ingredient(repo: impl IngredientRepository) {
         name: "Ingredient name".try_into().unwrap(),
         description: "Ingredient description".try_into().unwrap(),
         diet_violations: WhichDiets::new(),
+        parent_id: None,
+        allergens: Allergens::new(),
     })
     .await
     .unwrap();
@@ -0,0 +1,52 @@
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::ingredient::nutrition::IngredientNutrition,
+    repositories::ingredients::{
+        errors::{GetIngredientByIdError, GetNutritionError},
+        IngredientRepositoryService,
+    },
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum GetIngredientNutritionError {
+    #[error("Ingredient with ID {0} was not found")]
+    NotFound(Uuid),
+    #[error("The ingredient with ID {0} has no nutrition facts")]
+    NutritionNotFound(Uuid),
+    #[error(transparent)]
+    Internal(#[from] eyre::Error),
+}
+
+impl From<GetIngredientByIdError> for GetIngredientNutritionError {
+    fn from(value: GetIngredientByIdError) -> Self {
+        match value {
+            GetIngredientByIdError::NotFound(id) => Self::NotFound(id),
+            e => Self::Internal(e.into()),
+        }
+    }
+}
+
+impl From<GetNutritionError> for GetIngredientNutritionError {
+    fn from(value: GetNutritionError) -> Self {
+        Self::Internal(value.into())
+    }
+}
+
+#[tracing::instrument("[QUERY] Get nutrition facts of an ingredient", skip(repo))]
+pub async fn get_ingredient_nutrition(
+    repo: IngredientRepositoryService,
+    input: &Uuid,
+) -> Result<IngredientNutrition, GetIngredientNutritionError> {
+    let ingredient = repo.get_by_id(input).await?;
+
+    repo.get_nutrition(&[ingredient.id])
+        .await?
+        .remove(&ingredient.id)
+        .ok_or(GetIngredientNutritionError::NutritionNotFound(
+            ingredient.id,
+        ))
+}
+
+#[cfg(test)]
+mod tests;
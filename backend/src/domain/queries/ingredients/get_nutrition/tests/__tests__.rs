@@ -0,0 +1,46 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        queries::ingredients::get_nutrition::{
+            get_ingredient_nutrition, GetIngredientNutritionError,
+        },
+        repositories::ingredients::{IngredientRepository, IngredientRepositoryService},
+    },
+    test_utils::{ingredient_fixture, nutrition_fixture},
+};
+
+pub async fn getting_nutrition_returns_the_facts(repo: impl IngredientRepository) {
+    repo.insert(ingredient_fixture()).await.unwrap();
+    repo.set_nutrition(&ingredient_fixture(), &nutrition_fixture())
+        .await
+        .unwrap();
+    let repo: IngredientRepositoryService = Arc::new(Box::new(repo));
+
+    let result = get_ingredient_nutrition(repo, &ingredient_fixture().id)
+        .await
+        .unwrap();
+
+    assert_eq!(result, nutrition_fixture());
+}
+
+pub async fn getting_nutrition_distinguishes_what_is_missing(repo: impl IngredientRepository) {
+    repo.insert(ingredient_fixture()).await.unwrap();
+    let repo: IngredientRepositoryService = Arc::new(Box::new(repo));
+
+    let error = get_ingredient_nutrition(repo.clone(), &ingredient_fixture().id)
+        .await
+        .unwrap_err();
+
+    assert!(
+        matches!(error, GetIngredientNutritionError::NutritionNotFound(id) if id == ingredient_fixture().id)
+    );
+
+    let error = get_ingredient_nutrition(repo, &Uuid::nil())
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, GetIngredientNutritionError::NotFound(id) if id.is_nil()));
+}
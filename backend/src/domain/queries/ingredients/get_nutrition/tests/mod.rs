@@ -0,0 +1,38 @@
+mod __tests__;
+
+mod in_memory {
+    use crate::domain::repositories::ingredients::in_memory::InMemoryIngredientRepository;
+
+    use super::__tests__;
+
+    #[tokio::test]
+    async fn getting_nutrition_returns_the_facts() {
+        let repo = InMemoryIngredientRepository::new();
+        __tests__::getting_nutrition_returns_the_facts(repo).await
+    }
+
+    #[tokio::test]
+    async fn getting_nutrition_distinguishes_what_is_missing() {
+        let repo = InMemoryIngredientRepository::new();
+        __tests__::getting_nutrition_distinguishes_what_is_missing(repo).await
+    }
+}
+
+mod sql {
+    use sqlx::PgPool;
+
+    use super::__tests__;
+    use crate::domain::repositories::ingredients::postgres::PostgresIngredientRepository;
+
+    #[sqlx::test]
+    async fn getting_nutrition_returns_the_facts(pool: PgPool) {
+        let repo = PostgresIngredientRepository::new(pool.clone());
+        __tests__::getting_nutrition_returns_the_facts(repo).await
+    }
+
+    #[sqlx::test]
+    async fn getting_nutrition_distinguishes_what_is_missing(pool: PgPool) {
+        let repo = PostgresIngredientRepository::new(pool.clone());
+        __tests__::getting_nutrition_distinguishes_what_is_missing(repo).await
+    }
+}
@@ -0,0 +1,61 @@
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::ingredient::IngredientSubstitute,
+    queries::ingredients::get_by_id::GetIngredientError,
+    repositories::ingredients::{
+        errors::{GetIngredientByIdError, GetSubstitutesError},
+        IngredientRepositoryService,
+    },
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum GetIngredientSubstitutesError {
+    #[error("Ingredient with ID {0} was not found")]
+    NotFound(Uuid),
+    #[error(transparent)]
+    Internal(#[from] eyre::Error),
+}
+
+impl From<GetIngredientByIdError> for GetIngredientSubstitutesError {
+    fn from(value: GetIngredientByIdError) -> Self {
+        match value {
+            GetIngredientByIdError::NotFound(id) => Self::NotFound(id),
+            e => Self::Internal(e.into()),
+        }
+    }
+}
+
+impl From<GetSubstitutesError> for GetIngredientSubstitutesError {
+    fn from(value: GetSubstitutesError) -> Self {
+        Self::Internal(value.into())
+    }
+}
+
+impl From<GetIngredientSubstitutesError> for GetIngredientError {
+    fn from(value: GetIngredientSubstitutesError) -> Self {
+        match value {
+            GetIngredientSubstitutesError::NotFound(id) => Self::NotFound(id),
+            GetIngredientSubstitutesError::Internal(e) => Self::Internal(e),
+        }
+    }
+}
+
+#[tracing::instrument("[QUERY] Get substitutes of an ingredient", skip(repo))]
+pub async fn get_ingredient_substitutes(
+    repo: IngredientRepositoryService,
+    input: &Uuid,
+) -> Result<Vec<IngredientSubstitute>, GetIngredientSubstitutesError> {
+    let ingredient = repo.get_by_id(input).await?;
+
+    let result = repo
+        .get_substitutes(&[ingredient.id])
+        .await?
+        .remove(&ingredient.id)
+        .unwrap_or_default();
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,69 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        entities::ingredient::{
+            types::{Allergens, WhichDiets},
+            Ingredient, IngredientSubstitute,
+        },
+        queries::ingredients::get_substitutes::{
+            get_ingredient_substitutes, GetIngredientSubstitutesError,
+        },
+        repositories::ingredients::{IngredientRepository, IngredientRepositoryService},
+    },
+    test_utils::ingredient_fixture,
+};
+
+pub async fn get_substitutes_returns_substitutes(repo: impl IngredientRepository) {
+    let substitutes: Vec<Ingredient> = ["Seitan", "Tofu"]
+        .into_iter()
+        .enumerate()
+        .map(|(i, name)| Ingredient {
+            // The ids are in the opposite order of the names, which the substitutes are sorted by
+            id: Uuid::from_u128(101 - i as u128),
+            name: name.try_into().unwrap(),
+            description: "Plant based protein".try_into().unwrap(),
+            diet_violations: WhichDiets::new(),
+            parent_id: None,
+            allergens: Allergens::new(),
+        })
+        .collect();
+
+    repo.insert(ingredient_fixture()).await.unwrap();
+    for substitute in substitutes.iter() {
+        repo.insert(substitute.clone()).await.unwrap();
+        repo.insert_substitute(
+            &ingredient_fixture(),
+            &IngredientSubstitute {
+                substitute: substitute.clone(),
+                ratio: Default::default(),
+                note: None,
+            },
+        )
+        .await
+        .unwrap();
+    }
+
+    let repo: IngredientRepositoryService = Arc::new(Box::new(repo));
+    let result = get_ingredient_substitutes(repo, &ingredient_fixture().id)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        result.into_iter().map(|x| x.substitute).collect::<Vec<_>>(),
+        substitutes
+    );
+}
+
+pub async fn get_substitutes_returns_error_when_missing(repo: impl IngredientRepository) {
+    let repo: IngredientRepositoryService = Arc::new(Box::new(repo));
+    let result = get_ingredient_substitutes(repo, &Uuid::from_u128(1))
+        .await
+        .unwrap_err();
+
+    assert!(
+        matches!(result, GetIngredientSubstitutesError::NotFound(id) if id == Uuid::from_u128(1))
+    );
+}
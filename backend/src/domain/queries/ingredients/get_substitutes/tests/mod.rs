@@ -0,0 +1,37 @@
+mod __tests__;
+
+mod in_memory {
+    use super::__tests__;
+    use crate::domain::repositories::ingredients::in_memory::InMemoryIngredientRepository;
+
+    #[tokio::test]
+    async fn get_substitutes_returns_substitutes() {
+        let repo = InMemoryIngredientRepository::new();
+        __tests__::get_substitutes_returns_substitutes(repo).await;
+    }
+
+    #[tokio::test]
+    async fn get_substitutes_returns_error_when_missing() {
+        let repo = InMemoryIngredientRepository::new();
+        __tests__::get_substitutes_returns_error_when_missing(repo).await;
+    }
+}
+
+mod sql {
+    use sqlx::PgPool;
+
+    use super::__tests__;
+    use crate::domain::repositories::ingredients::postgres::PostgresIngredientRepository;
+
+    #[sqlx::test]
+    async fn get_substitutes_returns_substitutes(pool: PgPool) {
+        let repo = PostgresIngredientRepository::new(pool);
+        __tests__::get_substitutes_returns_substitutes(repo).await;
+    }
+
+    #[sqlx::test]
+    async fn get_substitutes_returns_error_when_missing(pool: PgPool) {
+        let repo = PostgresIngredientRepository::new(pool);
+        __tests__::get_substitutes_returns_error_when_missing(repo).await;
+    }
+}
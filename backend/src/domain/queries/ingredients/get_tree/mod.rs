@@ -0,0 +1,31 @@
+use crate::domain::{
+    entities::ingredient::IngredientTree,
+    repositories::ingredients::{
+        errors::GetAllIngredientsError as GetAllIngredientsErrorInternal,
+        IngredientRepositoryService,
+    },
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum GetIngredientTreeError {
+    #[error(transparent)]
+    Internal(#[from] eyre::Error),
+}
+
+impl From<GetAllIngredientsErrorInternal> for GetIngredientTreeError {
+    fn from(value: GetAllIngredientsErrorInternal) -> Self {
+        Self::Internal(value.into())
+    }
+}
+
+#[tracing::instrument("[QUERY] Get the tree of ingredients and their variants", skip(repo))]
+pub async fn get_ingredient_tree(
+    repo: IngredientRepositoryService,
+) -> Result<Vec<IngredientTree>, GetIngredientTreeError> {
+    let ingredients = repo.get_all().await?;
+
+    Ok(IngredientTree::build(ingredients))
+}
+
+#[cfg(test)]
+mod tests;
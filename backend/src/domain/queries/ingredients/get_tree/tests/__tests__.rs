@@ -0,0 +1,64 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::ingredient::{
+        types::{Allergens, WhichDiets},
+        Ingredient, IngredientTree,
+    },
+    queries::ingredients::get_tree::get_ingredient_tree,
+    repositories::ingredients::{IngredientRepository, IngredientRepositoryService},
+};
+
+fn ingredient(id: u128, name: &str, parent_id: Option<u128>) -> Ingredient {
+    Ingredient {
+        id: Uuid::from_u128(id),
+        name: name.try_into().unwrap(),
+        description: "Ingredient description".try_into().unwrap(),
+        diet_violations: WhichDiets::new(),
+        parent_id: parent_id.map(Uuid::from_u128),
+        allergens: Allergens::new(),
+    }
+}
+
+pub async fn get_tree_nests_variants(repo: impl IngredientRepository) {
+    let soy_sauce = ingredient(1, "Soy sauce", None);
+    let light = ingredient(2, "Light soy sauce", Some(1));
+    let dark = ingredient(3, "Dark soy sauce", Some(1));
+    let mushroom = ingredient(4, "Mushroom dark soy sauce", Some(3));
+    let garlic = ingredient(5, "Garlic", None);
+
+    for i in [&soy_sauce, &light, &dark, &mushroom, &garlic] {
+        repo.insert(i.clone()).await.unwrap();
+    }
+
+    let repo: IngredientRepositoryService = Arc::new(Box::new(repo));
+    let result = get_ingredient_tree(repo).await.unwrap();
+
+    assert_eq!(
+        result,
+        vec![
+            IngredientTree {
+                ingredient: garlic,
+                variants: vec![],
+            },
+            IngredientTree {
+                ingredient: soy_sauce,
+                variants: vec![
+                    IngredientTree {
+                        ingredient: dark,
+                        variants: vec![IngredientTree {
+                            ingredient: mushroom,
+                            variants: vec![],
+                        }],
+                    },
+                    IngredientTree {
+                        ingredient: light,
+                        variants: vec![],
+                    },
+                ],
+            },
+        ]
+    );
+}
@@ -0,0 +1,25 @@
+mod __tests__;
+
+mod in_memory {
+    use super::__tests__;
+    use crate::domain::repositories::ingredients::in_memory::InMemoryIngredientRepository;
+
+    #[tokio::test]
+    async fn get_tree_nests_variants() {
+        let repo = InMemoryIngredientRepository::new();
+        __tests__::get_tree_nests_variants(repo).await;
+    }
+}
+
+mod sql {
+    use sqlx::PgPool;
+
+    use super::__tests__;
+    use crate::domain::repositories::ingredients::postgres::PostgresIngredientRepository;
+
+    #[sqlx::test]
+    async fn get_tree_nests_variants(pool: PgPool) {
+        let repo = PostgresIngredientRepository::new(pool);
+        __tests__::get_tree_nests_variants(repo).await;
+    }
+}
@@ -1,2 +1,6 @@
+pub mod export;
 pub mod get_all;
 pub mod get_by_id;
+pub mod get_nutrition;
+pub mod get_substitutes;
+pub mod get_tree;
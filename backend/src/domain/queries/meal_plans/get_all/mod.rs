@@ -0,0 +1,31 @@
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::meal_plan::MealPlan,
+    repositories::meal_plans::{errors::GetAllMealPlansError, MealPlanRepositoryService},
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum GetMealPlansError {
+    #[error(transparent)]
+    Unknown(#[from] eyre::Error),
+}
+
+impl From<GetAllMealPlansError> for GetMealPlansError {
+    fn from(value: GetAllMealPlansError) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+/// Returns the plans of the user, the earliest ones first.
+pub async fn get_all_meal_plans(
+    meal_plan_repo: MealPlanRepositoryService,
+    user_id: &Uuid,
+) -> Result<Vec<MealPlan>, GetMealPlansError> {
+    let result = meal_plan_repo.get_all_of_user(user_id).await?;
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests;
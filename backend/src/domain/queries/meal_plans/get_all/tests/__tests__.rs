@@ -0,0 +1,53 @@
+use std::sync::Arc;
+
+use chrono::Days;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        entities::meal_plan::MealPlan,
+        queries::meal_plans::get_all::get_all_meal_plans,
+        repositories::{
+            ingredients::IngredientRepository,
+            meal_plans::{MealPlanRepository, MealPlanRepositoryService},
+            recipe::RecipeRepository,
+            users::UserRepository,
+        },
+    },
+    test_utils::{insert_meal_plan_dependencies, meal_plan_fixture, user_fixture},
+};
+
+pub async fn getting_all_meal_plans_returns_the_users_plans_in_order(
+    repo: impl MealPlanRepository,
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    insert_meal_plan_dependencies(&user_repo, &recipe_repo, &ingredient_repo).await;
+    let this_week = meal_plan_fixture();
+    let next_week = MealPlan {
+        id: Uuid::from_u128(17),
+        starts_on: this_week.starts_on + Days::new(7),
+        meals: vec![],
+        ..meal_plan_fixture()
+    };
+    repo.insert(next_week.clone()).await.unwrap();
+    repo.insert(this_week.clone()).await.unwrap();
+    let repo: MealPlanRepositoryService = Arc::new(Box::new(repo));
+
+    let result = get_all_meal_plans(repo.clone(), &user_fixture().id)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        result.iter().map(|p| p.id).collect::<Vec<_>>(),
+        vec![this_week.id, next_week.id]
+    );
+    assert_eq!(result[0].meals, this_week.meals);
+
+    let someone_elses = get_all_meal_plans(repo, &Uuid::from_u128(0xdead))
+        .await
+        .unwrap();
+
+    assert!(someone_elses.is_empty());
+}
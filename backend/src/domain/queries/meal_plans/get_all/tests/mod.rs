@@ -0,0 +1,53 @@
+mod __tests__;
+
+mod in_memory {
+    use crate::domain::repositories::{
+        ingredients::in_memory::InMemoryIngredientRepository,
+        meal_plans::in_memory::InMemoryMealPlanRepository,
+        recipe::in_memory::InMemoryRecipeRepository, users::in_memory::InMemoryUserRepository,
+    };
+
+    use super::__tests__;
+
+    #[tokio::test]
+    async fn getting_all_meal_plans_returns_the_users_plans_in_order() {
+        let repo = InMemoryMealPlanRepository::new();
+        let recipe_repo = InMemoryRecipeRepository::new();
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let user_repo = InMemoryUserRepository::new();
+        __tests__::getting_all_meal_plans_returns_the_users_plans_in_order(
+            repo,
+            recipe_repo,
+            ingredient_repo,
+            user_repo,
+        )
+        .await
+    }
+}
+
+mod sql {
+    use sqlx::PgPool;
+
+    use crate::domain::repositories::{
+        ingredients::postgres::PostgresIngredientRepository,
+        meal_plans::postgres::PostgresMealPlanRepository,
+        recipe::postgres::PostgresRecipeRepository, users::postgres::PostgresUserRepository,
+    };
+
+    use super::__tests__;
+
+    #[sqlx::test]
+    async fn getting_all_meal_plans_returns_the_users_plans_in_order(pool: PgPool) {
+        let repo = PostgresMealPlanRepository::new(pool.clone());
+        let recipe_repo = PostgresRecipeRepository::new(pool.clone());
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let user_repo = PostgresUserRepository::new(pool.clone());
+        __tests__::getting_all_meal_plans_returns_the_users_plans_in_order(
+            repo,
+            recipe_repo,
+            ingredient_repo,
+            user_repo,
+        )
+        .await
+    }
+}
@@ -0,0 +1,47 @@
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::meal_plan::MealPlan,
+    repositories::meal_plans::{
+        errors::GetMealPlanByIdError as GetMealPlanByIdErrorInternal, MealPlanRepositoryService,
+    },
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum GetMealPlanError {
+    #[error("Could not find the meal plan with the ID {0}")]
+    NotFound(Uuid),
+
+    #[error("You are not allowed to see the meal plan with ID {0}")]
+    Forbidden(Uuid),
+
+    #[error(transparent)]
+    Unknown(#[from] eyre::Error),
+}
+
+impl From<GetMealPlanByIdErrorInternal> for GetMealPlanError {
+    fn from(value: GetMealPlanByIdErrorInternal) -> Self {
+        match value {
+            GetMealPlanByIdErrorInternal::NotFound(id) => Self::NotFound(id),
+            e => Self::Unknown(e.into()),
+        }
+    }
+}
+
+/// Meal plans are private, so only their owners can get them.
+pub async fn get_meal_plan_by_id(
+    meal_plan_repo: MealPlanRepositoryService,
+    user_id: &Uuid,
+    input: &Uuid,
+) -> Result<MealPlan, GetMealPlanError> {
+    let plan = meal_plan_repo.get_by_id(input).await?;
+
+    if !plan.belongs_to(user_id) {
+        return Err(GetMealPlanError::Forbidden(plan.id));
+    }
+
+    Ok(plan)
+}
+
+#[cfg(test)]
+mod tests;
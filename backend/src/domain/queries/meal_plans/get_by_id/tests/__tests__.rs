@@ -0,0 +1,51 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        queries::meal_plans::get_by_id::{get_meal_plan_by_id, GetMealPlanError},
+        repositories::{
+            ingredients::IngredientRepository,
+            meal_plans::{MealPlanRepository, MealPlanRepositoryService},
+            recipe::RecipeRepository,
+            users::UserRepository,
+        },
+    },
+    test_utils::{insert_meal_plan_dependencies, meal_plan_fixture, user_fixture},
+};
+
+pub async fn getting_a_meal_plan_succeeds(
+    repo: impl MealPlanRepository,
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    insert_meal_plan_dependencies(&user_repo, &recipe_repo, &ingredient_repo).await;
+    let plan = repo.insert(meal_plan_fixture()).await.unwrap();
+    let repo: MealPlanRepositoryService = Arc::new(Box::new(repo));
+
+    let result = get_meal_plan_by_id(repo, &user_fixture().id, &plan.id)
+        .await
+        .unwrap();
+
+    assert_eq!(result.starts_on, plan.starts_on);
+    assert_eq!(result.meals, meal_plan_fixture().meals);
+}
+
+pub async fn getting_someone_elses_meal_plan_is_forbidden(
+    repo: impl MealPlanRepository,
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    insert_meal_plan_dependencies(&user_repo, &recipe_repo, &ingredient_repo).await;
+    let plan = repo.insert(meal_plan_fixture()).await.unwrap();
+    let repo: MealPlanRepositoryService = Arc::new(Box::new(repo));
+
+    let error = get_meal_plan_by_id(repo, &Uuid::from_u128(0xdead), &plan.id)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, GetMealPlanError::Forbidden(id) if id == plan.id));
+}
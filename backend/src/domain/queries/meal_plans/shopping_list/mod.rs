@@ -0,0 +1,71 @@
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::recipe::shopping_list::ShoppingList,
+    queries::recipes::shopping_list::{
+        get_shopping_list, GetShoppingListError, ShoppingListRecipe,
+    },
+    repositories::{meal_plans::MealPlanRepositoryService, recipe::RecipeRepositoryService},
+};
+
+use super::get_by_id::{get_meal_plan_by_id, GetMealPlanError};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum GetMealPlanShoppingListError {
+    #[error("Could not find the meal plan with the ID {0}")]
+    NotFound(Uuid),
+
+    #[error("You are not allowed to see the meal plan with ID {0}")]
+    Forbidden(Uuid),
+
+    #[error(transparent)]
+    Unknown(#[from] eyre::Error),
+}
+
+impl From<GetMealPlanError> for GetMealPlanShoppingListError {
+    fn from(value: GetMealPlanError) -> Self {
+        match value {
+            GetMealPlanError::NotFound(id) => Self::NotFound(id),
+            GetMealPlanError::Forbidden(id) => Self::Forbidden(id),
+            GetMealPlanError::Unknown(e) => Self::Unknown(e),
+        }
+    }
+}
+
+impl From<GetShoppingListError> for GetMealPlanShoppingListError {
+    fn from(value: GetShoppingListError) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+/// Everything needed for the meals of the week, each recipe scaled to the servings it's planned for.
+#[tracing::instrument(
+    "[QUERY] Get meal plan shopping list",
+    skip(meal_plan_repo, recipe_repo)
+)]
+pub async fn get_meal_plan_shopping_list(
+    meal_plan_repo: MealPlanRepositoryService,
+    recipe_repo: RecipeRepositoryService,
+    user_id: &Uuid,
+    input: &Uuid,
+) -> Result<ShoppingList, GetMealPlanShoppingListError> {
+    let plan = get_meal_plan_by_id(meal_plan_repo, user_id, input).await?;
+
+    if plan.meals.is_empty() {
+        return Ok(ShoppingList::from_recipes(&[]));
+    }
+
+    let recipes: Vec<ShoppingListRecipe> = plan
+        .meals
+        .iter()
+        .map(|meal| ShoppingListRecipe {
+            recipe_id: meal.recipe_id,
+            servings: Some(meal.servings),
+        })
+        .collect();
+
+    Ok(get_shopping_list(recipe_repo, &recipes).await?)
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,62 @@
+use std::sync::Arc;
+
+use crate::{
+    domain::{
+        entities::{meal_plan::MealPlan, recipe::shopping_list::ShoppingList},
+        queries::meal_plans::shopping_list::get_meal_plan_shopping_list,
+        repositories::{
+            ingredients::IngredientRepository,
+            meal_plans::{MealPlanRepository, MealPlanRepositoryService},
+            recipe::{RecipeRepository, RecipeRepositoryService},
+            users::UserRepository,
+        },
+    },
+    test_utils::{insert_meal_plan_dependencies, meal_plan_fixture, recipe_fixture, user_fixture},
+};
+
+pub async fn shopping_list_adds_up_the_meals_of_the_week(
+    repo: impl MealPlanRepository,
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    insert_meal_plan_dependencies(&user_repo, &recipe_repo, &ingredient_repo).await;
+    let plan = repo.insert(meal_plan_fixture()).await.unwrap();
+    let repo: MealPlanRepositoryService = Arc::new(Box::new(repo));
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+
+    let result = get_meal_plan_shopping_list(repo, recipe_repo, &user_fixture().id, &plan.id)
+        .await
+        .unwrap();
+
+    // The fixture plans the recipe for 2, and then for 4
+    let expected = ShoppingList::from_recipes(&[
+        recipe_fixture().scaled_exactly_to(2),
+        recipe_fixture().scaled_exactly_to(4),
+    ]);
+    assert_eq!(result.to_text(), expected.to_text());
+}
+
+pub async fn shopping_list_of_an_empty_plan_is_empty(
+    repo: impl MealPlanRepository,
+    recipe_repo: impl RecipeRepository,
+    user_repo: impl UserRepository,
+) {
+    user_repo.insert(user_fixture()).await.unwrap();
+    let plan = repo
+        .insert(MealPlan {
+            meals: vec![],
+            ..meal_plan_fixture()
+        })
+        .await
+        .unwrap();
+    let repo: MealPlanRepositoryService = Arc::new(Box::new(repo));
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+
+    let result = get_meal_plan_shopping_list(repo, recipe_repo, &user_fixture().id, &plan.id)
+        .await
+        .unwrap();
+
+    assert!(result.items.is_empty());
+    assert!(result.optional.is_empty());
+}
@@ -0,0 +1,34 @@
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::pantry::PantryItem,
+    repositories::pantry::{
+        errors::GetPantryError as GetPantryRepositoryError, PantryRepositoryService,
+    },
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum GetPantryError {
+    #[error(transparent)]
+    Unknown(#[from] eyre::Error),
+}
+
+impl From<GetPantryRepositoryError> for GetPantryError {
+    fn from(value: GetPantryRepositoryError) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+/// Returns everything in the pantry of the user, sorted by the name of the ingredient.
+#[tracing::instrument("[QUERY] Get the pantry of a user", skip(pantry_repo))]
+pub async fn get_pantry(
+    pantry_repo: PantryRepositoryService,
+    user_id: &Uuid,
+) -> Result<Vec<PantryItem>, GetPantryError> {
+    let result = pantry_repo.get_all_of_user(user_id).await?;
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests;
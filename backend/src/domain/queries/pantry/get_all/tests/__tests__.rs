@@ -0,0 +1,43 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        entities::{pantry::PantryItem, recipe::IngredientUnit},
+        queries::pantry::get_all::get_pantry,
+        repositories::{
+            ingredients::IngredientRepository,
+            pantry::{PantryRepository, PantryRepositoryService},
+            users::UserRepository,
+        },
+    },
+    test_utils::{insert_pantry_dependencies, pantry_item_fixture, recipe_fixture, user_fixture},
+};
+
+pub async fn getting_the_pantry_returns_the_users_items_by_name(
+    repo: impl PantryRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    insert_pantry_dependencies(&user_repo, &ingredient_repo).await;
+    let broccoli = PantryItem {
+        ingredient: recipe_fixture().ingredients[1].ingredient.clone(),
+        amount: IngredientUnit::Other {
+            unit: "head".to_string(),
+            amount: 2.0,
+        },
+        ..pantry_item_fixture()
+    };
+    repo.insert(pantry_item_fixture()).await.unwrap();
+    repo.insert(broccoli.clone()).await.unwrap();
+    let repo: PantryRepositoryService = Arc::new(Box::new(repo));
+
+    let result = get_pantry(repo.clone(), &user_fixture().id).await.unwrap();
+
+    assert_eq!(result, vec![broccoli, pantry_item_fixture()]);
+
+    let someone_elses = get_pantry(repo, &Uuid::from_u128(0xdead)).await.unwrap();
+
+    assert!(someone_elses.is_empty());
+}
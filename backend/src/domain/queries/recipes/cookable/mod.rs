@@ -0,0 +1,46 @@
+use chrono::NaiveDate;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::recipe::cookable::CookableRecipe,
+    repositories::{
+        pantry::{errors::GetPantryError, PantryRepositoryService},
+        recipe::{errors::GetAllRecipesError, RecipeRepositoryService},
+    },
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum GetCookableRecipesError {
+    #[error(transparent)]
+    Unknown(#[from] eyre::Error),
+}
+
+impl From<GetAllRecipesError> for GetCookableRecipesError {
+    fn from(value: GetAllRecipesError) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+impl From<GetPantryError> for GetCookableRecipesError {
+    fn from(value: GetPantryError) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+/// Ranks every recipe by how much of it can be made from the pantry of the user,
+/// leaving out whatever has expired by `today`.
+#[tracing::instrument("[QUERY] Get cookable recipes", skip(recipe_repo, pantry_repo))]
+pub async fn get_cookable_recipes(
+    recipe_repo: RecipeRepositoryService,
+    pantry_repo: PantryRepositoryService,
+    user_id: &Uuid,
+    today: NaiveDate,
+) -> Result<Vec<CookableRecipe>, GetCookableRecipesError> {
+    let pantry = pantry_repo.get_all_of_user(user_id).await?;
+    let recipes = recipe_repo.get_all().await?;
+
+    Ok(CookableRecipe::rank(recipes, &pantry, today))
+}
+
+#[cfg(test)]
+mod tests;
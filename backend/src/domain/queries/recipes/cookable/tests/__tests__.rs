@@ -0,0 +1,104 @@
+use std::sync::Arc;
+
+use chrono::{Days, NaiveDate};
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        entities::{
+            pantry::PantryItem,
+            recipe::{IngredientUnit, Recipe},
+        },
+        queries::recipes::cookable::get_cookable_recipes,
+        repositories::{
+            ingredients::IngredientRepository,
+            pantry::{PantryRepository, PantryRepositoryService},
+            recipe::{RecipeRepository, RecipeRepositoryService},
+            users::UserRepository,
+        },
+    },
+    test_utils::{insert_pantry_dependencies, pantry_item_fixture, recipe_fixture, user_fixture},
+};
+
+fn today() -> NaiveDate {
+    NaiveDate::from_ymd_opt(2024, 11, 3).unwrap()
+}
+
+/// A recipe which only needs 200 g of the tofu of the fixture recipe.
+fn tofu_recipe() -> Recipe {
+    let mut ingredients = recipe_fixture().ingredients.to_vec();
+    ingredients.truncate(1);
+    ingredients[0].amount = IngredientUnit::Grams(200.0);
+
+    Recipe {
+        id: Uuid::from_u128(1),
+        name: "Just tofu".to_string(),
+        ingredients: ingredients.try_into().unwrap(),
+        ..recipe_fixture()
+    }
+}
+
+pub async fn recipes_are_ranked_by_what_the_pantry_covers(
+    repo: impl RecipeRepository,
+    pantry_repo: impl PantryRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    insert_pantry_dependencies(&user_repo, &ingredient_repo).await;
+    repo.insert(recipe_fixture()).await.unwrap();
+    repo.insert(tofu_recipe()).await.unwrap();
+    pantry_repo.insert(pantry_item_fixture()).await.unwrap();
+    let repo: RecipeRepositoryService = Arc::new(Box::new(repo));
+    let pantry_repo: PantryRepositoryService = Arc::new(Box::new(pantry_repo));
+
+    let result = get_cookable_recipes(repo, pantry_repo, &user_fixture().id, today())
+        .await
+        .unwrap();
+
+    assert_eq!(
+        result.iter().map(|x| x.recipe.id).collect::<Vec<_>>(),
+        vec![tofu_recipe().id, recipe_fixture().id]
+    );
+    assert_eq!(result[0].coverage, 1.0);
+    assert!(result[0].missing.is_empty());
+
+    // 150 g of the 400 g of tofu are still missing, and there is no broccoli or garlic at all
+    let mut missing = result[1].missing.clone();
+    missing.sort_by_key(|x| x.ingredient.id);
+    assert_eq!(
+        missing.iter().map(|x| x.ingredient.id).collect::<Vec<_>>(),
+        recipe_fixture()
+            .ingredients
+            .iter()
+            .map(|x| x.ingredient.id)
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(missing[0].amount, IngredientUnit::Grams(150.0));
+}
+
+pub async fn expired_pantry_items_do_not_count(
+    repo: impl RecipeRepository,
+    pantry_repo: impl PantryRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    insert_pantry_dependencies(&user_repo, &ingredient_repo).await;
+    repo.insert(tofu_recipe()).await.unwrap();
+    pantry_repo
+        .insert(PantryItem {
+            expires_on: today().checked_sub_days(Days::new(1)),
+            ..pantry_item_fixture()
+        })
+        .await
+        .unwrap();
+    let repo: RecipeRepositoryService = Arc::new(Box::new(repo));
+    let pantry_repo: PantryRepositoryService = Arc::new(Box::new(pantry_repo));
+
+    let result = get_cookable_recipes(repo, pantry_repo, &user_fixture().id, today())
+        .await
+        .unwrap();
+
+    assert_eq!(result.len(), 1);
+    assert_eq!(result[0].coverage, 0.0);
+    assert_eq!(result[0].missing, tofu_recipe().ingredients.to_vec());
+}
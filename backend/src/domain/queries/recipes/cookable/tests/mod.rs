@@ -0,0 +1,73 @@
+mod __tests__;
+
+mod in_memory {
+    use crate::domain::repositories::{
+        ingredients::in_memory::InMemoryIngredientRepository,
+        pantry::in_memory::InMemoryPantryRepository, recipe::in_memory::InMemoryRecipeRepository,
+        users::in_memory::InMemoryUserRepository,
+    };
+
+    use super::__tests__;
+
+    #[tokio::test]
+    async fn recipes_are_ranked_by_what_the_pantry_covers() {
+        let repo = InMemoryRecipeRepository::new();
+        let pantry_repo = InMemoryPantryRepository::new();
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let user_repo = InMemoryUserRepository::new();
+        __tests__::recipes_are_ranked_by_what_the_pantry_covers(
+            repo,
+            pantry_repo,
+            ingredient_repo,
+            user_repo,
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn expired_pantry_items_do_not_count() {
+        let repo = InMemoryRecipeRepository::new();
+        let pantry_repo = InMemoryPantryRepository::new();
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let user_repo = InMemoryUserRepository::new();
+        __tests__::expired_pantry_items_do_not_count(repo, pantry_repo, ingredient_repo, user_repo)
+            .await
+    }
+}
+
+mod sql {
+    use sqlx::PgPool;
+
+    use crate::domain::repositories::{
+        ingredients::postgres::PostgresIngredientRepository,
+        pantry::postgres::PostgresPantryRepository, recipe::postgres::PostgresRecipeRepository,
+        users::postgres::PostgresUserRepository,
+    };
+
+    use super::__tests__;
+
+    #[sqlx::test]
+    async fn recipes_are_ranked_by_what_the_pantry_covers(pool: PgPool) {
+        let repo = PostgresRecipeRepository::new(pool.clone());
+        let pantry_repo = PostgresPantryRepository::new(pool.clone());
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let user_repo = PostgresUserRepository::new(pool.clone());
+        __tests__::recipes_are_ranked_by_what_the_pantry_covers(
+            repo,
+            pantry_repo,
+            ingredient_repo,
+            user_repo,
+        )
+        .await
+    }
+
+    #[sqlx::test]
+    async fn expired_pantry_items_do_not_count(pool: PgPool) {
+        let repo = PostgresRecipeRepository::new(pool.clone());
+        let pantry_repo = PostgresPantryRepository::new(pool.clone());
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let user_repo = PostgresUserRepository::new(pool.clone());
+        __tests__::expired_pantry_items_do_not_count(repo, pantry_repo, ingredient_repo, user_repo)
+            .await
+    }
+}
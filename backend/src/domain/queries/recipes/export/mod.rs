@@ -0,0 +1,48 @@
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::recipe::{errors::ValidationError, formats::ExportFormat},
+    repositories::recipe::{
+        errors::GetRecipeByIdError as GetRecipeByIdErrorInternal, RecipeRepositoryService,
+    },
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum ExportRecipeError {
+    #[error("Could not found the recipe with the ID {0}")]
+    NotFound(Uuid),
+
+    #[error("Field format does not match any of: {0:?}")]
+    InvalidFormat(&'static [&'static str]),
+
+    #[error(transparent)]
+    ValidationError(#[from] ValidationError),
+
+    #[error(transparent)]
+    Unknown(#[from] eyre::Error),
+}
+
+impl From<GetRecipeByIdErrorInternal> for ExportRecipeError {
+    fn from(value: GetRecipeByIdErrorInternal) -> Self {
+        match value {
+            GetRecipeByIdErrorInternal::NotFound(id) => Self::NotFound(id),
+            GetRecipeByIdErrorInternal::ValidationError(e) => Self::ValidationError(e),
+            GetRecipeByIdErrorInternal::UnknownError(e) => Self::Unknown(e),
+        }
+    }
+}
+
+/// Renders the recipe in the given format, ready to be served as is.
+#[tracing::instrument("[QUERY] Export recipe", skip(recipe_repo))]
+pub async fn export_recipe(
+    recipe_repo: RecipeRepositoryService,
+    id: &Uuid,
+    format: ExportFormat,
+) -> Result<String, ExportRecipeError> {
+    let recipe = recipe_repo.get_by_id(id).await?;
+
+    Ok(format.render(&recipe))
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,48 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        entities::recipe::formats::{jsonld, ExportFormat},
+        queries::recipes::export::{export_recipe, ExportRecipeError},
+        repositories::{
+            ingredients::IngredientRepository,
+            recipe::{RecipeRepository, RecipeRepositoryService},
+        },
+    },
+    test_utils::{insert_all_ingredients_of_recipe, recipe_fixture},
+};
+
+pub async fn exporting_recipe_works(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+) {
+    let recipe = recipe_fixture();
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
+    repo.insert(recipe.clone()).await.unwrap();
+
+    let repo: RecipeRepositoryService = Arc::new(Box::new(repo));
+
+    let document = export_recipe(repo.clone(), &recipe.id, ExportFormat::Jsonld)
+        .await
+        .unwrap();
+    let imported = jsonld::parse(&document).unwrap();
+    assert_eq!(imported.name, recipe.name);
+    assert_eq!(imported.ingredients.len(), recipe.ingredients.len());
+
+    let document = export_recipe(repo, &recipe.id, ExportFormat::Markdown)
+        .await
+        .unwrap();
+    assert!(document.starts_with("# Hoisin Tofu and Broccoli\n"));
+}
+
+pub async fn exporting_nonexistent_recipe_errors(repo: impl RecipeRepository) {
+    let repo: RecipeRepositoryService = Arc::new(Box::new(repo));
+
+    let error = export_recipe(repo, &Uuid::nil(), ExportFormat::Markdown)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, ExportRecipeError::NotFound(id) if id == Uuid::nil()));
+}
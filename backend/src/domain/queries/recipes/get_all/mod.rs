@@ -1,7 +1,16 @@
-use crate::domain::{entities::recipe::Recipe, repositories::recipe::RecipeRepositoryService};
+use crate::domain::{
+    entities::{
+        pagination::{Page, PageRequest, PaginationError},
+        recipe::{Recipe, RecipeSort},
+    },
+    repositories::recipe::RecipeRepositoryService,
+};
 
 #[derive(thiserror::Error, Debug, strum::AsRefStr)]
 pub enum GetAllRecipesError {
+    #[error(transparent)]
+    Pagination(#[from] PaginationError),
+
     #[error(transparent)]
     Unknown(#[from] eyre::Error),
 }
@@ -9,11 +18,15 @@ pub enum GetAllRecipesError {
 #[tracing::instrument("[QUERY] Get all recipes", skip(recipe_repo))]
 pub async fn get_all_recipes(
     recipe_repo: RecipeRepositoryService,
-) -> Result<Vec<Recipe>, GetAllRecipesError> {
+    request: &PageRequest<RecipeSort>,
+) -> Result<Page<Recipe>, GetAllRecipesError> {
     let result = recipe_repo
-        .get_all()
+        .get_page(request)
         .await
         .map_err(|e| GetAllRecipesError::Unknown(e.into()))?;
 
     Ok(result)
 }
+
+#[cfg(test)]
+mod tests;
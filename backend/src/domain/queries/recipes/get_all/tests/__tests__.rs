@@ -0,0 +1,127 @@
+use std::sync::Arc;
+
+use pretty_assertions::assert_eq;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        entities::{
+            pagination::PageRequest,
+            recipe::{Recipe, RecipeSort},
+        },
+        queries::recipes::get_all::get_all_recipes,
+        repositories::{
+            ingredients::IngredientRepository,
+            recipe::{RecipeRepository, RecipeRepositoryService},
+        },
+    },
+    test_utils::{insert_all_ingredients_of_recipe, recipe_fixture},
+};
+
+/// IDs are time-ordered, so the later recipes are the newer ones
+fn recipes() -> Vec<Recipe> {
+    ["Carrot cake", "Apple pie", "Banana bread"]
+        .into_iter()
+        .zip(1..)
+        .map(|(name, i)| Recipe {
+            id: Uuid::from_u128(i),
+            name: name.to_string(),
+            ..recipe_fixture()
+        })
+        .collect()
+}
+
+async fn setup(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+) -> RecipeRepositoryService {
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe_fixture()).await;
+
+    for recipe in recipes() {
+        repo.insert(recipe).await.unwrap();
+    }
+
+    Arc::new(Box::new(repo))
+}
+
+fn names(recipes: &[Recipe]) -> Vec<&str> {
+    recipes.iter().map(|r| r.name.as_str()).collect()
+}
+
+pub async fn returns_empty_page_when_no_items_inside(repo: impl RecipeRepository) {
+    let repo: RecipeRepositoryService = Arc::new(Box::new(repo));
+
+    let result = get_all_recipes(repo, &PageRequest::default())
+        .await
+        .unwrap();
+
+    assert!(result.items.is_empty());
+    assert_eq!(result.next_cursor, None);
+}
+
+pub async fn paginating_by_creation_works(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+) {
+    let repo = setup(repo, ingredient_repo).await;
+    let request = PageRequest {
+        limit: 2,
+        cursor: None,
+        sort: RecipeSort::CreatedAt,
+    };
+
+    let first = get_all_recipes(repo.clone(), &request).await.unwrap();
+    assert_eq!(names(&first.items), vec!["Banana bread", "Apple pie"]);
+    assert_eq!(first.next_cursor, Some(Uuid::from_u128(2)));
+
+    let second = get_all_recipes(
+        repo,
+        &PageRequest {
+            cursor: first.next_cursor,
+            ..request
+        },
+    )
+    .await
+    .unwrap();
+    assert_eq!(names(&second.items), vec!["Carrot cake"]);
+    assert_eq!(second.next_cursor, None);
+}
+
+pub async fn paginating_by_name_works(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+) {
+    let repo = setup(repo, ingredient_repo).await;
+    let request = PageRequest {
+        limit: 1,
+        cursor: None,
+        sort: RecipeSort::Name,
+    };
+
+    let mut cursor = None;
+    let mut result = vec![];
+
+    loop {
+        let page = get_all_recipes(
+            repo.clone(),
+            &PageRequest {
+                cursor,
+                ..request.clone()
+            },
+        )
+        .await
+        .unwrap();
+
+        result.extend(page.items);
+        cursor = page.next_cursor;
+
+        if cursor.is_none() {
+            break;
+        }
+    }
+
+    assert_eq!(
+        names(&result),
+        vec!["Apple pie", "Banana bread", "Carrot cake"]
+    );
+}
@@ -0,0 +1,60 @@
+mod __tests__;
+
+mod in_memory {
+    use crate::domain::repositories::{
+        ingredients::in_memory::InMemoryIngredientRepository,
+        recipe::in_memory::InMemoryRecipeRepository,
+    };
+
+    use super::__tests__;
+
+    #[tokio::test]
+    async fn returns_empty_page_when_no_items_inside() {
+        let repo = InMemoryRecipeRepository::new();
+        __tests__::returns_empty_page_when_no_items_inside(repo).await
+    }
+
+    #[tokio::test]
+    async fn paginating_by_creation_works() {
+        let repo = InMemoryRecipeRepository::new();
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        __tests__::paginating_by_creation_works(repo, ingredient_repo).await
+    }
+
+    #[tokio::test]
+    async fn paginating_by_name_works() {
+        let repo = InMemoryRecipeRepository::new();
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        __tests__::paginating_by_name_works(repo, ingredient_repo).await
+    }
+}
+
+mod sql {
+    use sqlx::PgPool;
+
+    use super::__tests__;
+    use crate::domain::repositories::{
+        ingredients::postgres::PostgresIngredientRepository,
+        recipe::postgres::PostgresRecipeRepository,
+    };
+
+    #[sqlx::test]
+    async fn returns_empty_page_when_no_items_inside(pool: PgPool) {
+        let repo = PostgresRecipeRepository::new(pool);
+        __tests__::returns_empty_page_when_no_items_inside(repo).await
+    }
+
+    #[sqlx::test]
+    async fn paginating_by_creation_works(pool: PgPool) {
+        let repo = PostgresRecipeRepository::new(pool.clone());
+        let ingredient_repo = PostgresIngredientRepository::new(pool);
+        __tests__::paginating_by_creation_works(repo, ingredient_repo).await
+    }
+
+    #[sqlx::test]
+    async fn paginating_by_name_works(pool: PgPool) {
+        let repo = PostgresRecipeRepository::new(pool.clone());
+        let ingredient_repo = PostgresIngredientRepository::new(pool);
+        __tests__::paginating_by_name_works(repo, ingredient_repo).await
+    }
+}
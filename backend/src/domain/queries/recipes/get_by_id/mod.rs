@@ -12,6 +12,9 @@ pub enum GetRecipeError {
     #[error("Could not found the recipe with the ID {0}")]
     NotFound(Uuid),
 
+    #[error("Field units does not match any of: {0:?}")]
+    InvalidUnitSystem(&'static [&'static str]),
+
     #[error(transparent)]
     ValidationError(#[from] ValidationError),
 
@@ -0,0 +1,51 @@
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::recipe::{errors::ValidationError, Recipe},
+    repositories::recipe::{
+        errors::GetRecipeByIdError as GetRecipeByIdErrorInternal, RecipeRepositoryService,
+    },
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum GetScaledRecipeError {
+    #[error("Could not found the recipe with the ID {0}")]
+    NotFound(Uuid),
+
+    #[error("A recipe has to be scaled to at least one serving")]
+    InvalidServings,
+
+    #[error(transparent)]
+    ValidationError(#[from] ValidationError),
+
+    #[error(transparent)]
+    Unknown(#[from] eyre::Error),
+}
+
+impl From<GetRecipeByIdErrorInternal> for GetScaledRecipeError {
+    fn from(value: GetRecipeByIdErrorInternal) -> Self {
+        match value {
+            GetRecipeByIdErrorInternal::NotFound(id) => Self::NotFound(id),
+            GetRecipeByIdErrorInternal::ValidationError(e) => Self::ValidationError(e),
+            GetRecipeByIdErrorInternal::UnknownError(e) => Self::Unknown(e),
+        }
+    }
+}
+
+#[tracing::instrument("[QUERY] Get scaled recipe", skip(recipe_repo))]
+pub async fn get_scaled_recipe(
+    recipe_repo: RecipeRepositoryService,
+    id: &Uuid,
+    servings: u16,
+) -> Result<Recipe, GetScaledRecipeError> {
+    if servings == 0 {
+        return Err(GetScaledRecipeError::InvalidServings);
+    }
+
+    let recipe = recipe_repo.get_by_id(id).await?;
+
+    Ok(recipe.scaled_to(servings))
+}
+
+#[cfg(test)]
+mod tests;
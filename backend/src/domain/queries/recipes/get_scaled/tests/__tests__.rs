@@ -0,0 +1,98 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        entities::recipe::{IngredientUnit, Recipe, ServingsType},
+        queries::recipes::get_scaled::{get_scaled_recipe, GetScaledRecipeError},
+        repositories::{
+            ingredients::IngredientRepository,
+            recipe::{RecipeRepository, RecipeRepositoryService},
+        },
+    },
+    test_utils::{insert_all_ingredients_of_recipe, recipe_fixture},
+};
+
+fn amounts(recipe: &Recipe) -> Vec<IngredientUnit> {
+    let mut ingredients = recipe.ingredients.to_vec();
+    ingredients.sort_by_key(|i| i.ingredient.id);
+
+    ingredients.into_iter().map(|i| i.amount).collect()
+}
+
+pub async fn scaling_recipe_works(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+) {
+    let recipe = recipe_fixture();
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
+    repo.insert(recipe.clone()).await.unwrap();
+    let repo: RecipeRepositoryService = Arc::new(Box::new(repo));
+
+    // The fixture serves 4
+    let result = get_scaled_recipe(repo, &recipe.id, 6).await.unwrap();
+
+    assert_eq!(result.servings, ServingsType::Exact(6));
+    assert_eq!(
+        amounts(&result),
+        vec![
+            IngredientUnit::Grams(600.0),
+            IngredientUnit::Other {
+                unit: "head".to_string(),
+                amount: 1.5
+            },
+            IngredientUnit::Other {
+                unit: "clove".to_string(),
+                amount: 6.0
+            },
+        ]
+    );
+}
+
+pub async fn scaling_recipe_with_servings_range_works(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+) {
+    let recipe = Recipe {
+        servings: ServingsType::FromTo(2, 4),
+        ..recipe_fixture()
+    };
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
+    repo.insert(recipe.clone()).await.unwrap();
+    let repo: RecipeRepositoryService = Arc::new(Box::new(repo));
+
+    let result = get_scaled_recipe(repo, &recipe.id, 1).await.unwrap();
+
+    assert_eq!(result.servings, ServingsType::Exact(1));
+    assert_eq!(
+        amounts(&result),
+        vec![
+            IngredientUnit::Grams(135.0),
+            IngredientUnit::Other {
+                unit: "head".to_string(),
+                amount: 0.25
+            },
+            IngredientUnit::Other {
+                unit: "clove".to_string(),
+                amount: 1.25
+            },
+        ]
+    );
+}
+
+pub async fn scaling_to_zero_servings_errors(repo: impl RecipeRepository) {
+    let repo: RecipeRepositoryService = Arc::new(Box::new(repo));
+
+    let error = get_scaled_recipe(repo, &Uuid::nil(), 0).await.unwrap_err();
+
+    assert!(matches!(error, GetScaledRecipeError::InvalidServings));
+}
+
+pub async fn scaling_nonexistent_recipe_errors(repo: impl RecipeRepository) {
+    let repo: RecipeRepositoryService = Arc::new(Box::new(repo));
+
+    let error = get_scaled_recipe(repo, &Uuid::nil(), 2).await.unwrap_err();
+
+    assert!(matches!(error, GetScaledRecipeError::NotFound(id) if id == Uuid::nil()));
+}
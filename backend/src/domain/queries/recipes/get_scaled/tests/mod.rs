@@ -0,0 +1,72 @@
+mod __tests__;
+
+mod in_memory {
+    use crate::domain::repositories::{
+        ingredients::in_memory::InMemoryIngredientRepository,
+        recipe::in_memory::InMemoryRecipeRepository,
+    };
+
+    use super::__tests__;
+
+    #[tokio::test]
+    async fn scaling_recipe_works() {
+        let repo = InMemoryRecipeRepository::new();
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        __tests__::scaling_recipe_works(repo, ingredient_repo).await
+    }
+
+    #[tokio::test]
+    async fn scaling_recipe_with_servings_range_works() {
+        let repo = InMemoryRecipeRepository::new();
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        __tests__::scaling_recipe_with_servings_range_works(repo, ingredient_repo).await
+    }
+
+    #[tokio::test]
+    async fn scaling_to_zero_servings_errors() {
+        let repo = InMemoryRecipeRepository::new();
+        __tests__::scaling_to_zero_servings_errors(repo).await
+    }
+
+    #[tokio::test]
+    async fn scaling_nonexistent_recipe_errors() {
+        let repo = InMemoryRecipeRepository::new();
+        __tests__::scaling_nonexistent_recipe_errors(repo).await
+    }
+}
+
+mod sql {
+    use sqlx::PgPool;
+
+    use super::__tests__;
+    use crate::domain::repositories::{
+        ingredients::postgres::PostgresIngredientRepository,
+        recipe::postgres::PostgresRecipeRepository,
+    };
+
+    #[sqlx::test]
+    async fn scaling_recipe_works(pool: PgPool) {
+        let repo = PostgresRecipeRepository::new(pool.clone());
+        let ingredient_repo = PostgresIngredientRepository::new(pool);
+        __tests__::scaling_recipe_works(repo, ingredient_repo).await
+    }
+
+    #[sqlx::test]
+    async fn scaling_recipe_with_servings_range_works(pool: PgPool) {
+        let repo = PostgresRecipeRepository::new(pool.clone());
+        let ingredient_repo = PostgresIngredientRepository::new(pool);
+        __tests__::scaling_recipe_with_servings_range_works(repo, ingredient_repo).await
+    }
+
+    #[sqlx::test]
+    async fn scaling_to_zero_servings_errors(pool: PgPool) {
+        let repo = PostgresRecipeRepository::new(pool);
+        __tests__::scaling_to_zero_servings_errors(repo).await
+    }
+
+    #[sqlx::test]
+    async fn scaling_nonexistent_recipe_errors(pool: PgPool) {
+        let repo = PostgresRecipeRepository::new(pool);
+        __tests__::scaling_nonexistent_recipe_errors(repo).await
+    }
+}
@@ -1,2 +1,10 @@
-pub mod get_by_id;
+pub mod cookable;
+pub mod export;
 pub mod get_all;
+pub mod get_by_id;
+pub mod get_scaled;
+pub mod nutrition;
+pub mod revisions;
+pub mod search;
+pub mod shopping_list;
+pub mod suggest_substitutions;
@@ -0,0 +1,60 @@
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::recipe::{errors::ValidationError, nutrition::RecipeNutrition},
+    repositories::{
+        ingredients::{errors::GetNutritionError, IngredientRepositoryService},
+        recipe::{
+            errors::GetRecipeByIdError as GetRecipeByIdErrorInternal, RecipeRepositoryService,
+        },
+    },
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum GetRecipeNutritionError {
+    #[error("Could not find the recipe with the ID {0}")]
+    NotFound(Uuid),
+
+    #[error(transparent)]
+    ValidationError(#[from] ValidationError),
+
+    #[error(transparent)]
+    Unknown(#[from] eyre::Error),
+}
+
+impl From<GetRecipeByIdErrorInternal> for GetRecipeNutritionError {
+    fn from(value: GetRecipeByIdErrorInternal) -> Self {
+        match value {
+            GetRecipeByIdErrorInternal::NotFound(id) => Self::NotFound(id),
+            GetRecipeByIdErrorInternal::ValidationError(e) => Self::ValidationError(e),
+            GetRecipeByIdErrorInternal::UnknownError(e) => Self::Unknown(e),
+        }
+    }
+}
+
+impl From<GetNutritionError> for GetRecipeNutritionError {
+    fn from(value: GetNutritionError) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+/// Adds up the nutrition facts of the ingredients of the recipe.
+#[tracing::instrument(
+    "[QUERY] Get nutrition of a recipe",
+    skip(recipe_repo, ingredient_repo)
+)]
+pub async fn get_recipe_nutrition(
+    recipe_repo: RecipeRepositoryService,
+    ingredient_repo: IngredientRepositoryService,
+    id: &Uuid,
+) -> Result<RecipeNutrition, GetRecipeNutritionError> {
+    let recipe = recipe_repo.get_by_id(id).await?;
+
+    let ids: Vec<Uuid> = recipe.ingredients.iter().map(|i| i.ingredient.id).collect();
+    let nutrition = ingredient_repo.get_nutrition(&ids).await?;
+
+    Ok(RecipeNutrition::new(&recipe, &nutrition))
+}
+
+#[cfg(test)]
+mod tests;
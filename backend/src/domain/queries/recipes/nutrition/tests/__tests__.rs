@@ -0,0 +1,66 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        queries::recipes::nutrition::{get_recipe_nutrition, GetRecipeNutritionError},
+        repositories::{
+            ingredients::{IngredientRepository, IngredientRepositoryService},
+            recipe::{RecipeRepository, RecipeRepositoryService},
+        },
+    },
+    test_utils::{insert_all_ingredients_of_recipe, nutrition_fixture, recipe_fixture},
+};
+
+pub async fn recipe_nutrition_uses_the_facts_of_its_ingredients(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+) {
+    let recipe = recipe_fixture();
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
+    repo.insert(recipe.clone()).await.unwrap();
+    // Tofu and broccoli, the latter is measured in heads
+    for ingredient in recipe.ingredients.iter().take(2) {
+        ingredient_repo
+            .set_nutrition(&ingredient.ingredient, &nutrition_fixture())
+            .await
+            .unwrap();
+    }
+    let repo: RecipeRepositoryService = Arc::new(Box::new(repo));
+    let ingredient_repo: IngredientRepositoryService = Arc::new(Box::new(ingredient_repo));
+
+    let result = get_recipe_nutrition(repo, ingredient_repo, &recipe.id)
+        .await
+        .unwrap();
+
+    assert_eq!(result.total.energy_kcal, 576.0);
+    assert_eq!(result.per_serving.energy_kcal, 144.0);
+    assert_eq!(
+        result
+            .unconverted
+            .iter()
+            .map(|i| i.ingredient.id)
+            .collect::<Vec<_>>(),
+        vec![recipe.ingredients[1].ingredient.id]
+    );
+    assert_eq!(
+        result.missing.iter().map(|i| i.id).collect::<Vec<_>>(),
+        vec![recipe.ingredients[2].ingredient.id]
+    );
+}
+
+pub async fn nutrition_of_a_missing_recipe_errors(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+) {
+    let repo: RecipeRepositoryService = Arc::new(Box::new(repo));
+    let ingredient_repo: IngredientRepositoryService = Arc::new(Box::new(ingredient_repo));
+    let id = Uuid::from_u128(0xdead);
+
+    let error = get_recipe_nutrition(repo, ingredient_repo, &id)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, GetRecipeNutritionError::NotFound(missing) if missing == id));
+}
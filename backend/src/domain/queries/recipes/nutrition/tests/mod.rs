@@ -0,0 +1,48 @@
+mod __tests__;
+
+mod in_memory {
+    use crate::domain::repositories::{
+        ingredients::in_memory::InMemoryIngredientRepository,
+        recipe::in_memory::InMemoryRecipeRepository,
+    };
+
+    use super::__tests__;
+
+    #[tokio::test]
+    async fn recipe_nutrition_uses_the_facts_of_its_ingredients() {
+        let repo = InMemoryRecipeRepository::new();
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        __tests__::recipe_nutrition_uses_the_facts_of_its_ingredients(repo, ingredient_repo).await
+    }
+
+    #[tokio::test]
+    async fn nutrition_of_a_missing_recipe_errors() {
+        let repo = InMemoryRecipeRepository::new();
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        __tests__::nutrition_of_a_missing_recipe_errors(repo, ingredient_repo).await
+    }
+}
+
+mod sql {
+    use sqlx::PgPool;
+
+    use super::__tests__;
+    use crate::domain::repositories::{
+        ingredients::postgres::PostgresIngredientRepository,
+        recipe::postgres::PostgresRecipeRepository,
+    };
+
+    #[sqlx::test]
+    async fn recipe_nutrition_uses_the_facts_of_its_ingredients(pool: PgPool) {
+        let repo = PostgresRecipeRepository::new(pool.clone());
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        __tests__::recipe_nutrition_uses_the_facts_of_its_ingredients(repo, ingredient_repo).await
+    }
+
+    #[sqlx::test]
+    async fn nutrition_of_a_missing_recipe_errors(pool: PgPool) {
+        let repo = PostgresRecipeRepository::new(pool.clone());
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        __tests__::nutrition_of_a_missing_recipe_errors(repo, ingredient_repo).await
+    }
+}
@@ -0,0 +1,84 @@
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::recipe::revisions::{RecipeDiff, RecipeRevision},
+    repositories::recipe::{
+        errors::{GetRecipeByIdError, GetRecipeRevisionsError},
+        RecipeRepositoryService,
+    },
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum GetRevisionsError {
+    #[error("Could not find the recipe with ID {0}")]
+    NotFound(Uuid),
+
+    #[error("The recipe does not have a revision number {0}")]
+    RevisionNotFound(i32),
+
+    #[error(transparent)]
+    Unknown(#[from] eyre::Error),
+}
+
+impl From<GetRecipeByIdError> for GetRevisionsError {
+    fn from(value: GetRecipeByIdError) -> Self {
+        match value {
+            GetRecipeByIdError::NotFound(id) => Self::NotFound(id),
+            e => Self::Unknown(e.into()),
+        }
+    }
+}
+
+impl From<GetRecipeRevisionsError> for GetRevisionsError {
+    fn from(value: GetRecipeRevisionsError) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+/// Returns every revision of the recipe, the oldest one first.
+#[tracing::instrument("[QUERY] Getting the revisions of a recipe", skip(recipe_repo))]
+pub async fn get_revisions(
+    recipe_repo: RecipeRepositoryService,
+    recipe_id: &Uuid,
+) -> Result<Vec<RecipeRevision>, GetRevisionsError> {
+    let recipe = recipe_repo.get_by_id(recipe_id).await?;
+    let stored = recipe_repo.get_revisions(recipe_id).await?;
+
+    Ok(RecipeRevision::history(&recipe, stored))
+}
+
+#[tracing::instrument("[QUERY] Getting a revision of a recipe", skip(recipe_repo))]
+pub async fn get_revision(
+    recipe_repo: RecipeRepositoryService,
+    recipe_id: &Uuid,
+    number: i32,
+) -> Result<RecipeRevision, GetRevisionsError> {
+    get_revisions(recipe_repo, recipe_id)
+        .await?
+        .into_iter()
+        .find(|r| r.number == number)
+        .ok_or(GetRevisionsError::RevisionNotFound(number))
+}
+
+/// Compares the recipe in two of its revisions. They can be given in any order,
+/// e.g. diffing from a later revision to an earlier one shows what reverting to it would change.
+#[tracing::instrument("[QUERY] Diffing revisions of a recipe", skip(recipe_repo))]
+pub async fn diff_revisions(
+    recipe_repo: RecipeRepositoryService,
+    recipe_id: &Uuid,
+    from: i32,
+    to: i32,
+) -> Result<RecipeDiff, GetRevisionsError> {
+    let revisions = get_revisions(recipe_repo, recipe_id).await?;
+    let find = |number: i32| {
+        revisions
+            .iter()
+            .find(|r| r.number == number)
+            .ok_or(GetRevisionsError::RevisionNotFound(number))
+    };
+
+    Ok(RecipeDiff::between(find(from)?, find(to)?))
+}
+
+#[cfg(test)]
+mod tests;
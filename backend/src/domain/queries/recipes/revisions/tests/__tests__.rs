@@ -0,0 +1,177 @@
+use std::sync::Arc;
+
+use pretty_assertions::assert_eq;
+use uuid::Uuid;
+
+use crate::domain::entities::recipe::revisions::{Change, RevisionChange};
+use crate::domain::entities::recipe::{IngredientUnit, RecipeChangeset};
+use crate::domain::queries::recipes::revisions::{
+    diff_revisions, get_revision, get_revisions, GetRevisionsError,
+};
+use crate::domain::repositories::ingredients::IngredientRepository;
+use crate::domain::repositories::recipe::errors::UpdateIngredientInRecipeError;
+use crate::domain::repositories::recipe::{RecipeRepository, RecipeRepositoryService};
+use crate::test_utils::{insert_all_ingredients_of_recipe, recipe_fixture, user_fixture};
+
+pub async fn every_change_is_recorded_as_a_revision(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+) {
+    let recipe = recipe_fixture();
+    let author_id = user_fixture().id;
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
+    repo.insert(recipe.clone()).await.unwrap();
+    let recipe = repo.get_by_id(&recipe.id).await.unwrap();
+
+    repo.update(
+        &recipe,
+        RecipeChangeset {
+            name: Some("Tofu and Broccoli".to_string()),
+            description: None,
+            steps: None,
+            time: None,
+            servings: None,
+        },
+        &author_id,
+    )
+    .await
+    .unwrap();
+    let updated = repo.get_by_id(&recipe.id).await.unwrap();
+    repo.update_ingredient_amount(
+        &updated,
+        &updated.ingredients[0],
+        &IngredientUnit::Grams(200.0),
+        &author_id,
+    )
+    .await
+    .unwrap();
+
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(repo));
+    let result = get_revisions(recipe_repo.clone(), &recipe.id)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        result.iter().map(|r| r.number).collect::<Vec<_>>(),
+        vec![1, 2, 3]
+    );
+    assert_eq!(result[0].change, RevisionChange::Created);
+    assert_eq!(result[0].author_id, None);
+    assert_eq!(
+        result[1].change,
+        RevisionChange::Updated {
+            fields: vec!["name".to_string()]
+        }
+    );
+    assert_eq!(result[1].author_id, Some(author_id));
+    assert_eq!(result[1].recipe.name, "Tofu and Broccoli");
+    assert_eq!(
+        result[2].change,
+        RevisionChange::IngredientAmountChanged {
+            ingredient_id: recipe.ingredients[0].ingredient.id
+        }
+    );
+
+    let revision = get_revision(recipe_repo, &recipe.id, 2).await.unwrap();
+
+    assert_eq!(revision, result[1]);
+}
+
+pub async fn diffing_revisions_works(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+) {
+    let recipe = recipe_fixture();
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
+    repo.insert(recipe.clone()).await.unwrap();
+    let recipe = repo.get_by_id(&recipe.id).await.unwrap();
+
+    let removed = recipe.ingredients[1].clone();
+    repo.delete_ingredient(&recipe, &removed, &user_fixture().id)
+        .await
+        .unwrap();
+
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(repo));
+    let result = diff_revisions(recipe_repo.clone(), &recipe.id, 1, 2)
+        .await
+        .unwrap();
+
+    assert_eq!(result.name, None);
+    assert!(result.ingredients_added.is_empty());
+    assert_eq!(
+        result
+            .ingredients_removed
+            .iter()
+            .map(|i| i.ingredient_id)
+            .collect::<Vec<_>>(),
+        vec![removed.ingredient.id]
+    );
+
+    let result = diff_revisions(recipe_repo, &recipe.id, 2, 1).await.unwrap();
+
+    assert_eq!(result.ingredients_added.len(), 1);
+    assert!(result.ingredients_removed.is_empty());
+    assert_eq!(result.steps, None::<Change<Vec<String>>>);
+}
+
+pub async fn getting_a_nonexistent_revision_fails(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+) {
+    let recipe = recipe_fixture();
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
+    repo.insert(recipe.clone()).await.unwrap();
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(repo));
+
+    let result = get_revision(recipe_repo.clone(), &recipe.id, 2)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(result, GetRevisionsError::RevisionNotFound(2)));
+
+    let result = get_revisions(recipe_repo, &Uuid::from_u128(0xdead))
+        .await
+        .unwrap_err();
+
+    assert!(matches!(result, GetRevisionsError::NotFound(id) if id == Uuid::from_u128(0xdead)));
+}
+
+pub async fn concurrent_changes_do_not_share_a_revision_number(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+) {
+    let recipe = recipe_fixture();
+    let author_id = user_fixture().id;
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
+    repo.insert(recipe.clone()).await.unwrap();
+    let recipe = repo.get_by_id(&recipe.id).await.unwrap();
+
+    let (first, second) = tokio::join!(
+        repo.update_ingredient_amount(
+            &recipe,
+            &recipe.ingredients[0],
+            &IngredientUnit::Grams(200.0),
+            &author_id,
+        ),
+        repo.update_ingredient_amount(
+            &recipe,
+            &recipe.ingredients[1],
+            &IngredientUnit::Grams(300.0),
+            &author_id,
+        )
+    );
+
+    // Whichever comes second was made to the version the first one has replaced
+    let outdated: Vec<_> = [first, second]
+        .into_iter()
+        .filter_map(Result::err)
+        .collect();
+    assert_eq!(outdated.len(), 1);
+    assert!(matches!(outdated[0], UpdateIngredientInRecipeError::Outdated(id) if id == recipe.id));
+
+    let revisions = repo.get_revisions(&recipe.id).await.unwrap();
+    assert_eq!(
+        revisions.iter().map(|r| r.number).collect::<Vec<_>>(),
+        vec![1, 2]
+    );
+}
@@ -0,0 +1,75 @@
+mod __tests__;
+mod in_memory {
+    use crate::domain::repositories::{
+        ingredients::in_memory::InMemoryIngredientRepository,
+        recipe::in_memory::InMemoryRecipeRepository,
+    };
+
+    use super::__tests__;
+
+    #[tokio::test]
+    async fn every_change_is_recorded_as_a_revision() {
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let repo = InMemoryRecipeRepository::new();
+        __tests__::every_change_is_recorded_as_a_revision(repo, ingredient_repo).await
+    }
+
+    #[tokio::test]
+    async fn diffing_revisions_works() {
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let repo = InMemoryRecipeRepository::new();
+        __tests__::diffing_revisions_works(repo, ingredient_repo).await
+    }
+
+    #[tokio::test]
+    async fn getting_a_nonexistent_revision_fails() {
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let repo = InMemoryRecipeRepository::new();
+        __tests__::getting_a_nonexistent_revision_fails(repo, ingredient_repo).await
+    }
+
+    #[tokio::test]
+    async fn concurrent_changes_do_not_share_a_revision_number() {
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let repo = InMemoryRecipeRepository::new();
+        __tests__::concurrent_changes_do_not_share_a_revision_number(repo, ingredient_repo).await
+    }
+}
+
+mod sql {
+    use super::__tests__;
+    use sqlx::PgPool;
+
+    use crate::domain::repositories::{
+        ingredients::postgres::PostgresIngredientRepository,
+        recipe::postgres::PostgresRecipeRepository,
+    };
+
+    #[sqlx::test]
+    async fn every_change_is_recorded_as_a_revision(pool: PgPool) {
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let repo = PostgresRecipeRepository::new(pool);
+        __tests__::every_change_is_recorded_as_a_revision(repo, ingredient_repo).await
+    }
+
+    #[sqlx::test]
+    async fn diffing_revisions_works(pool: PgPool) {
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let repo = PostgresRecipeRepository::new(pool);
+        __tests__::diffing_revisions_works(repo, ingredient_repo).await
+    }
+
+    #[sqlx::test]
+    async fn getting_a_nonexistent_revision_fails(pool: PgPool) {
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let repo = PostgresRecipeRepository::new(pool);
+        __tests__::getting_a_nonexistent_revision_fails(repo, ingredient_repo).await
+    }
+
+    #[sqlx::test]
+    async fn concurrent_changes_do_not_share_a_revision_number(pool: PgPool) {
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let repo = PostgresRecipeRepository::new(pool);
+        __tests__::concurrent_changes_do_not_share_a_revision_number(repo, ingredient_repo).await
+    }
+}
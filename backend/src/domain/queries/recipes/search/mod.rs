@@ -0,0 +1,125 @@
+use std::time::Duration;
+
+use common::RecipeSearchParamsDTO;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{
+        ingredient::types::{Allergen, DietViolations},
+        pagination::{DEFAULT_PAGE_SIZE, MAX_PAGE_SIZE},
+        recipe::{errors::ValidationError, Recipe, RecipeSearchQuery},
+    },
+    repositories::{
+        ingredients::{errors::GetAllIngredientsError, IngredientRepositoryService},
+        recipe::{
+            errors::SearchRecipesError as SearchRecipesErrorInternal, RecipeRepositoryService,
+        },
+    },
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum SearchRecipesError {
+    #[error("{0} is not a valid ingredient ID")]
+    InvalidIngredientId(String),
+
+    #[error(transparent)]
+    ValidationError(#[from] ValidationError),
+
+    #[error(transparent)]
+    Unknown(#[from] eyre::Error),
+}
+
+impl From<SearchRecipesErrorInternal> for SearchRecipesError {
+    fn from(value: SearchRecipesErrorInternal) -> Self {
+        match value {
+            SearchRecipesErrorInternal::ValidationError(e) => Self::ValidationError(e),
+            SearchRecipesErrorInternal::UnknownError(e) => Self::Unknown(e),
+        }
+    }
+}
+
+impl From<GetAllIngredientsError> for SearchRecipesError {
+    fn from(value: GetAllIngredientsError) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+fn split_list(value: Option<String>) -> Vec<String> {
+    value
+        .unwrap_or_default()
+        .split(',')
+        .map(str::trim)
+        .filter(|v| !v.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+fn parse_ids(value: Option<String>) -> Result<Vec<Uuid>, SearchRecipesError> {
+    split_list(value)
+        .into_iter()
+        .map(|id| Uuid::try_parse(&id).map_err(|_| SearchRecipesError::InvalidIngredientId(id)))
+        .collect()
+}
+
+impl TryFrom<RecipeSearchParamsDTO> for RecipeSearchQuery {
+    type Error = SearchRecipesError;
+
+    fn try_from(value: RecipeSearchParamsDTO) -> Result<Self, Self::Error> {
+        let diets = split_list(value.diets)
+            .into_iter()
+            .map(DietViolations::try_from)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(ValidationError::from)?;
+
+        let free_from = split_list(value.free_from)
+            .into_iter()
+            .map(Allergen::try_from)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(ValidationError::from)?;
+
+        Ok(Self {
+            text: value.q.filter(|q| !q.trim().is_empty()),
+            including_ingredients: parse_ids(value.ingredients)?,
+            excluding_ingredients: parse_ids(value.exclude_ingredients)?,
+            diets,
+            free_from,
+            max_total_time: value.max_time.map(Duration::from_secs),
+            ingredient_variants: Default::default(),
+            limit: value
+                .limit
+                .unwrap_or(DEFAULT_PAGE_SIZE)
+                .clamp(1, MAX_PAGE_SIZE),
+        })
+    }
+}
+
+/// Searching for an ingredient matches recipes which use any of its variants as well.
+#[tracing::instrument("[QUERY] Search recipes", skip(recipe_repo, ingredient_repo))]
+pub async fn search_recipes(
+    recipe_repo: RecipeRepositoryService,
+    ingredient_repo: IngredientRepositoryService,
+    query: &RecipeSearchQuery,
+) -> Result<Vec<Recipe>, SearchRecipesError> {
+    let mut query = query.clone();
+
+    for id in query
+        .including_ingredients
+        .iter()
+        .chain(query.excluding_ingredients.iter())
+    {
+        let variants = ingredient_repo.get_variants(id).await?;
+
+        if !variants.is_empty() {
+            query
+                .ingredient_variants
+                .insert(*id, variants.into_iter().map(|v| v.id).collect());
+        }
+    }
+
+    let result = recipe_repo.search(&query).await?;
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,356 @@
+use std::{collections::BTreeMap, sync::Arc, time::Duration};
+
+use common::RecipeSearchParamsDTO;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        entities::{
+            ingredient::{
+                types::{Allergen, Allergens, DietViolations, WhichDiets},
+                Ingredient, IngredientChangeset,
+            },
+            recipe::{
+                IngredientUnit, IngredientWithAmount, Recipe, RecipeSearchQuery, ServingsType,
+            },
+        },
+        queries::recipes::search::{search_recipes, SearchRecipesError},
+        repositories::{
+            ingredients::{IngredientRepository, IngredientRepositoryService},
+            recipe::{RecipeRepository, RecipeRepositoryService},
+        },
+    },
+    test_utils::{insert_all_ingredients_of_recipe, recipe_fixture},
+};
+
+fn rice_recipe() -> Recipe {
+    Recipe {
+        id: Uuid::from_u128(100),
+        name: "Steamed rice".to_string(),
+        description: "Plain rice, the way it should be".to_string(),
+        steps: vec![
+            "Rinse the rice until the water runs clear.".to_string(),
+            "Cook covered on low heat.".to_string(),
+        ]
+        .try_into()
+        .unwrap(),
+        ingredients: vec![IngredientWithAmount {
+            ingredient: Ingredient {
+                id: Uuid::from_u128(101),
+                name: "Rice".try_into().unwrap(),
+                description: "Long grain rice".try_into().unwrap(),
+                diet_violations: WhichDiets::new(),
+                parent_id: None,
+                allergens: Allergens::new(),
+            },
+            amount: IngredientUnit::Grams(200.0),
+            notes: None,
+            optional: false,
+        }]
+        .try_into()
+        .unwrap(),
+        time: BTreeMap::from([("Cook time".to_string(), Duration::from_secs(60 * 60))]),
+        servings: ServingsType::Exact(2),
+        ..recipe_fixture()
+    }
+}
+
+type Repositories = (RecipeRepositoryService, IngredientRepositoryService);
+
+async fn setup(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+) -> Repositories {
+    for recipe in [recipe_fixture(), rice_recipe()] {
+        insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
+        repo.insert(recipe).await.unwrap();
+    }
+
+    (
+        Arc::new(Box::new(repo)),
+        Arc::new(Box::new(ingredient_repo)),
+    )
+}
+
+async fn search_ids((repo, ingredient_repo): Repositories, query: RecipeSearchQuery) -> Vec<Uuid> {
+    search_recipes(repo, ingredient_repo, &query)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|r| r.id)
+        .collect()
+}
+
+pub async fn empty_search_returns_everything(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+) {
+    let repo = setup(repo, ingredient_repo).await;
+
+    let mut result = search_ids(repo, RecipeSearchQuery::default()).await;
+    result.sort();
+
+    assert_eq!(result, vec![recipe_fixture().id, rice_recipe().id]);
+}
+
+pub async fn search_results_are_limited(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+) {
+    let repo = setup(repo, ingredient_repo).await;
+
+    let result = search_ids(
+        repo,
+        RecipeSearchQuery {
+            limit: 1,
+            ..Default::default()
+        },
+    )
+    .await;
+    assert_eq!(result, vec![rice_recipe().id]);
+}
+
+pub async fn searching_by_text_works(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+) {
+    let repo = setup(repo, ingredient_repo).await;
+
+    let result = search_ids(
+        repo.clone(),
+        RecipeSearchQuery {
+            text: Some("broccoli".to_string()),
+            ..Default::default()
+        },
+    )
+    .await;
+    assert_eq!(result, vec![recipe_fixture().id]);
+
+    // Matches in the steps count as well
+    let result = search_ids(
+        repo,
+        RecipeSearchQuery {
+            text: Some("clear".to_string()),
+            ..Default::default()
+        },
+    )
+    .await;
+    assert_eq!(result, vec![rice_recipe().id]);
+}
+
+pub async fn searching_by_ingredients_works(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+) {
+    let repo = setup(repo, ingredient_repo).await;
+    let broccoli = Uuid::from_u128(1);
+
+    let result = search_ids(
+        repo.clone(),
+        RecipeSearchQuery {
+            including_ingredients: vec![broccoli, Uuid::nil()],
+            ..Default::default()
+        },
+    )
+    .await;
+    assert_eq!(result, vec![recipe_fixture().id]);
+
+    let result = search_ids(
+        repo.clone(),
+        RecipeSearchQuery {
+            including_ingredients: vec![broccoli, Uuid::from_u128(101)],
+            ..Default::default()
+        },
+    )
+    .await;
+    assert!(result.is_empty());
+
+    let result = search_ids(
+        repo,
+        RecipeSearchQuery {
+            excluding_ingredients: vec![broccoli],
+            ..Default::default()
+        },
+    )
+    .await;
+    assert_eq!(result, vec![rice_recipe().id]);
+}
+
+pub async fn searching_by_diet_works(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+) {
+    let repo = setup(repo, ingredient_repo).await;
+
+    let result = search_ids(
+        repo,
+        RecipeSearchQuery {
+            diets: vec![DietViolations::Vegan, DietViolations::GlutenFree],
+            ..Default::default()
+        },
+    )
+    .await;
+
+    assert_eq!(result, vec![rice_recipe().id]);
+}
+
+pub async fn searching_by_allergens_works(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+) {
+    let repo = setup(repo, ingredient_repo).await;
+
+    let result = search_ids(
+        repo.clone(),
+        RecipeSearchQuery {
+            free_from: vec![Allergen::Soy],
+            ..Default::default()
+        },
+    )
+    .await;
+    assert_eq!(result, vec![rice_recipe().id]);
+
+    let mut result = search_ids(
+        repo,
+        RecipeSearchQuery {
+            free_from: vec![Allergen::Milk, Allergen::Peanuts],
+            ..Default::default()
+        },
+    )
+    .await;
+    result.sort();
+    assert_eq!(result, vec![recipe_fixture().id, rice_recipe().id]);
+}
+
+pub async fn searching_by_total_time_works(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+) {
+    let repo = setup(repo, ingredient_repo).await;
+
+    let result = search_ids(
+        repo.clone(),
+        RecipeSearchQuery {
+            max_total_time: Some(Duration::from_secs(30 * 60)),
+            ..Default::default()
+        },
+    )
+    .await;
+    assert_eq!(result, vec![recipe_fixture().id]);
+
+    // The limit is inclusive
+    let result = search_ids(
+        repo,
+        RecipeSearchQuery {
+            max_total_time: Some(Duration::from_secs(25 * 60)),
+            ..Default::default()
+        },
+    )
+    .await;
+    assert_eq!(result, vec![recipe_fixture().id]);
+}
+
+pub async fn searching_by_parent_ingredient_matches_variants(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+) {
+    let grain = Ingredient {
+        id: Uuid::from_u128(200),
+        name: "Grain".try_into().unwrap(),
+        description: "Any kind of grain".try_into().unwrap(),
+        diet_violations: WhichDiets::new(),
+        parent_id: None,
+        allergens: Allergens::new(),
+    };
+    ingredient_repo.insert(grain.clone()).await.unwrap();
+
+    let (repo, ingredient_repo) = setup(repo, ingredient_repo).await;
+
+    // Rice is a variant of grain, so looking for grain finds the rice recipe
+    ingredient_repo
+        .update(
+            &ingredient_repo
+                .get_by_id(&Uuid::from_u128(101))
+                .await
+                .unwrap(),
+            IngredientChangeset {
+                parent_id: Some(Some(grain.id)),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+
+    let result = search_ids(
+        (repo.clone(), ingredient_repo.clone()),
+        RecipeSearchQuery {
+            including_ingredients: vec![grain.id],
+            ..Default::default()
+        },
+    )
+    .await;
+    assert_eq!(result, vec![rice_recipe().id]);
+
+    let result = search_ids(
+        (repo, ingredient_repo),
+        RecipeSearchQuery {
+            excluding_ingredients: vec![grain.id],
+            ..Default::default()
+        },
+    )
+    .await;
+    assert_eq!(result, vec![recipe_fixture().id]);
+}
+
+#[test]
+fn parsing_search_params_works() {
+    let params = RecipeSearchParamsDTO {
+        q: Some("tofu".to_string()),
+        ingredients: Some(format!("{}, {}", Uuid::nil(), Uuid::from_u128(1))),
+        exclude_ingredients: None,
+        diets: Some("vegan,gluten_free".to_string()),
+        free_from: Some("soy, Peanuts".to_string()),
+        max_time: Some(600),
+        limit: Some(1000),
+    };
+
+    let result: RecipeSearchQuery = params.try_into().unwrap();
+
+    assert_eq!(
+        result,
+        RecipeSearchQuery {
+            text: Some("tofu".to_string()),
+            including_ingredients: vec![Uuid::nil(), Uuid::from_u128(1)],
+            excluding_ingredients: vec![],
+            diets: vec![DietViolations::Vegan, DietViolations::GlutenFree],
+            free_from: vec![Allergen::Soy, Allergen::Peanuts],
+            max_total_time: Some(Duration::from_secs(600)),
+            ingredient_variants: BTreeMap::new(),
+            limit: 100,
+        }
+    );
+}
+
+#[test]
+fn parsing_invalid_search_params_errors() {
+    let error = RecipeSearchQuery::try_from(RecipeSearchParamsDTO {
+        ingredients: Some("not-an-id".to_string()),
+        ..Default::default()
+    })
+    .unwrap_err();
+    assert!(matches!(error, SearchRecipesError::InvalidIngredientId(id) if id == "not-an-id"));
+
+    let error = RecipeSearchQuery::try_from(RecipeSearchParamsDTO {
+        diets: Some("carnivore".to_string()),
+        ..Default::default()
+    })
+    .unwrap_err();
+    assert!(matches!(error, SearchRecipesError::ValidationError(_)));
+
+    let error = RecipeSearchQuery::try_from(RecipeSearchParamsDTO {
+        free_from: Some("pollen".to_string()),
+        ..Default::default()
+    })
+    .unwrap_err();
+    assert!(matches!(error, SearchRecipesError::ValidationError(_)));
+}
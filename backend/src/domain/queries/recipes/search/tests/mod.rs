@@ -0,0 +1,132 @@
+mod __tests__;
+
+mod in_memory {
+    use crate::domain::repositories::{
+        ingredients::in_memory::InMemoryIngredientRepository,
+        recipe::in_memory::InMemoryRecipeRepository,
+    };
+
+    use super::__tests__;
+
+    #[tokio::test]
+    async fn empty_search_returns_everything() {
+        let repo = InMemoryRecipeRepository::new();
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        __tests__::empty_search_returns_everything(repo, ingredient_repo).await
+    }
+
+    #[tokio::test]
+    async fn search_results_are_limited() {
+        let repo = InMemoryRecipeRepository::new();
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        __tests__::search_results_are_limited(repo, ingredient_repo).await
+    }
+
+    #[tokio::test]
+    async fn searching_by_text_works() {
+        let repo = InMemoryRecipeRepository::new();
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        __tests__::searching_by_text_works(repo, ingredient_repo).await
+    }
+
+    #[tokio::test]
+    async fn searching_by_ingredients_works() {
+        let repo = InMemoryRecipeRepository::new();
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        __tests__::searching_by_ingredients_works(repo, ingredient_repo).await
+    }
+
+    #[tokio::test]
+    async fn searching_by_diet_works() {
+        let repo = InMemoryRecipeRepository::new();
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        __tests__::searching_by_diet_works(repo, ingredient_repo).await
+    }
+
+    #[tokio::test]
+    async fn searching_by_allergens_works() {
+        let repo = InMemoryRecipeRepository::new();
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        __tests__::searching_by_allergens_works(repo, ingredient_repo).await
+    }
+
+    #[tokio::test]
+    async fn searching_by_total_time_works() {
+        let repo = InMemoryRecipeRepository::new();
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        __tests__::searching_by_total_time_works(repo, ingredient_repo).await
+    }
+
+    #[tokio::test]
+    async fn searching_by_parent_ingredient_matches_variants() {
+        let repo = InMemoryRecipeRepository::new();
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        __tests__::searching_by_parent_ingredient_matches_variants(repo, ingredient_repo).await
+    }
+}
+
+mod sql {
+    use sqlx::PgPool;
+
+    use super::__tests__;
+    use crate::domain::repositories::{
+        ingredients::postgres::PostgresIngredientRepository,
+        recipe::postgres::PostgresRecipeRepository,
+    };
+
+    #[sqlx::test]
+    async fn empty_search_returns_everything(pool: PgPool) {
+        let repo = PostgresRecipeRepository::new(pool.clone());
+        let ingredient_repo = PostgresIngredientRepository::new(pool);
+        __tests__::empty_search_returns_everything(repo, ingredient_repo).await
+    }
+
+    #[sqlx::test]
+    async fn search_results_are_limited(pool: PgPool) {
+        let repo = PostgresRecipeRepository::new(pool.clone());
+        let ingredient_repo = PostgresIngredientRepository::new(pool);
+        __tests__::search_results_are_limited(repo, ingredient_repo).await
+    }
+
+    #[sqlx::test]
+    async fn searching_by_text_works(pool: PgPool) {
+        let repo = PostgresRecipeRepository::new(pool.clone());
+        let ingredient_repo = PostgresIngredientRepository::new(pool);
+        __tests__::searching_by_text_works(repo, ingredient_repo).await
+    }
+
+    #[sqlx::test]
+    async fn searching_by_ingredients_works(pool: PgPool) {
+        let repo = PostgresRecipeRepository::new(pool.clone());
+        let ingredient_repo = PostgresIngredientRepository::new(pool);
+        __tests__::searching_by_ingredients_works(repo, ingredient_repo).await
+    }
+
+    #[sqlx::test]
+    async fn searching_by_diet_works(pool: PgPool) {
+        let repo = PostgresRecipeRepository::new(pool.clone());
+        let ingredient_repo = PostgresIngredientRepository::new(pool);
+        __tests__::searching_by_diet_works(repo, ingredient_repo).await
+    }
+
+    #[sqlx::test]
+    async fn searching_by_allergens_works(pool: PgPool) {
+        let repo = PostgresRecipeRepository::new(pool.clone());
+        let ingredient_repo = PostgresIngredientRepository::new(pool);
+        __tests__::searching_by_allergens_works(repo, ingredient_repo).await
+    }
+
+    #[sqlx::test]
+    async fn searching_by_total_time_works(pool: PgPool) {
+        let repo = PostgresRecipeRepository::new(pool.clone());
+        let ingredient_repo = PostgresIngredientRepository::new(pool);
+        __tests__::searching_by_total_time_works(repo, ingredient_repo).await
+    }
+
+    #[sqlx::test]
+    async fn searching_by_parent_ingredient_matches_variants(pool: PgPool) {
+        let repo = PostgresRecipeRepository::new(pool.clone());
+        let ingredient_repo = PostgresIngredientRepository::new(pool);
+        __tests__::searching_by_parent_ingredient_matches_variants(repo, ingredient_repo).await
+    }
+}
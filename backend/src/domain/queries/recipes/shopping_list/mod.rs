@@ -0,0 +1,87 @@
+use common::ShoppingListRecipeDTO;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::recipe::{errors::ValidationError, shopping_list::ShoppingList},
+    repositories::recipe::{
+        errors::GetRecipeByIdError as GetRecipeByIdErrorInternal, RecipeRepositoryService,
+    },
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum GetShoppingListError {
+    #[error("Could not find the recipe with the ID {0}")]
+    NotFound(Uuid),
+
+    #[error("A shopping list needs at least one recipe")]
+    NoRecipes,
+
+    #[error("A recipe has to be scaled to at least one serving")]
+    InvalidServings,
+
+    #[error(transparent)]
+    ValidationError(#[from] ValidationError),
+
+    #[error(transparent)]
+    Unknown(#[from] eyre::Error),
+}
+
+impl From<GetRecipeByIdErrorInternal> for GetShoppingListError {
+    fn from(value: GetRecipeByIdErrorInternal) -> Self {
+        match value {
+            GetRecipeByIdErrorInternal::NotFound(id) => Self::NotFound(id),
+            GetRecipeByIdErrorInternal::ValidationError(e) => Self::ValidationError(e),
+            GetRecipeByIdErrorInternal::UnknownError(e) => Self::Unknown(e),
+        }
+    }
+}
+
+/// A recipe to shop for, with the servings it's going to be made in.
+#[derive(Debug, Clone)]
+pub struct ShoppingListRecipe {
+    pub recipe_id: Uuid,
+    /// Keeps the servings of the recipe when missing
+    pub servings: Option<u16>,
+}
+
+impl From<ShoppingListRecipeDTO> for ShoppingListRecipe {
+    fn from(value: ShoppingListRecipeDTO) -> Self {
+        Self {
+            recipe_id: value.recipe_id,
+            servings: value.servings,
+        }
+    }
+}
+
+/// Adds up the ingredients of all the recipes, each scaled to its servings.
+/// The same recipe can be on the list more than once, e.g. to make it on two different days.
+#[tracing::instrument("[QUERY] Get shopping list", skip(recipe_repo))]
+pub async fn get_shopping_list(
+    recipe_repo: RecipeRepositoryService,
+    input: &[ShoppingListRecipe],
+) -> Result<ShoppingList, GetShoppingListError> {
+    if input.is_empty() {
+        return Err(GetShoppingListError::NoRecipes);
+    }
+
+    if input.iter().any(|r| r.servings == Some(0)) {
+        return Err(GetShoppingListError::InvalidServings);
+    }
+
+    let mut recipes = vec![];
+
+    for entry in input {
+        let recipe = recipe_repo.get_by_id(&entry.recipe_id).await?;
+
+        recipes.push(match entry.servings {
+            // The list rounds the totals, rounding each recipe as well would make them drift
+            Some(servings) => recipe.scaled_exactly_to(servings),
+            None => recipe,
+        });
+    }
+
+    Ok(ShoppingList::from_recipes(&recipes))
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,128 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        entities::recipe::{IngredientUnit, Recipe, ServingsType},
+        queries::recipes::shopping_list::{
+            get_shopping_list, GetShoppingListError, ShoppingListRecipe,
+        },
+        repositories::{
+            ingredients::IngredientRepository,
+            recipe::{RecipeRepository, RecipeRepositoryService},
+        },
+    },
+    test_utils::{insert_all_ingredients_of_recipe, recipe_fixture},
+};
+
+pub async fn shopping_list_adds_up_recipes(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+) {
+    let recipe = recipe_fixture();
+    let mut ingredients = recipe.ingredients.to_vec();
+    ingredients.sort_by_key(|i| i.ingredient.id);
+    // Tofu, and broccoli which this one can do without
+    ingredients[0].amount = IngredientUnit::Grams(200.0);
+    ingredients[1].optional = true;
+    ingredients.truncate(2);
+    let other = Recipe {
+        id: Uuid::from_u128(1),
+        ingredients: ingredients.try_into().unwrap(),
+        ..recipe_fixture()
+    };
+
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
+    repo.insert(recipe.clone()).await.unwrap();
+    repo.insert(other.clone()).await.unwrap();
+    let repo: RecipeRepositoryService = Arc::new(Box::new(repo));
+
+    let result = get_shopping_list(
+        repo,
+        &[
+            // The fixture serves 4
+            ShoppingListRecipe {
+                recipe_id: recipe.id,
+                servings: Some(8),
+            },
+            ShoppingListRecipe {
+                recipe_id: other.id,
+                servings: None,
+            },
+        ],
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(
+        result.to_text(),
+        "- Broccoli: 2 head\n- Firm tofu: 1000 g\n- Garlic: 8 clove\n\nOptional:\n- Broccoli: 1 head"
+    );
+}
+
+pub async fn shopping_list_rounds_only_the_totals(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+) {
+    let recipe = recipe_fixture();
+    let broccoli = recipe
+        .ingredients
+        .iter()
+        .find(|i| i.ingredient.name.0 == "Broccoli")
+        .unwrap()
+        .clone();
+    // A head of broccoli for 3, so a third of one per serving
+    let side = Recipe {
+        servings: ServingsType::Exact(3),
+        ingredients: vec![broccoli].try_into().unwrap(),
+        ..recipe_fixture()
+    };
+
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
+    repo.insert(side.clone()).await.unwrap();
+    let repo: RecipeRepositoryService = Arc::new(Box::new(repo));
+
+    let single_serving = ShoppingListRecipe {
+        recipe_id: side.id,
+        servings: Some(1),
+    };
+    let result = get_shopping_list(
+        repo,
+        &[
+            single_serving.clone(),
+            single_serving.clone(),
+            single_serving,
+        ],
+    )
+    .await
+    .unwrap();
+
+    // Rounding every third to a quarter first would only add up to 0.75
+    assert_eq!(result.to_text(), "- Broccoli: 1 head");
+}
+
+pub async fn shopping_list_without_recipes_errors(repo: impl RecipeRepository) {
+    let repo: RecipeRepositoryService = Arc::new(Box::new(repo));
+
+    let error = get_shopping_list(repo, &[]).await.unwrap_err();
+
+    assert!(matches!(error, GetShoppingListError::NoRecipes));
+}
+
+pub async fn shopping_list_with_nonexistent_recipe_errors(repo: impl RecipeRepository) {
+    let repo: RecipeRepositoryService = Arc::new(Box::new(repo));
+    let id = Uuid::from_u128(0xdead);
+
+    let error = get_shopping_list(
+        repo,
+        &[ShoppingListRecipe {
+            recipe_id: id,
+            servings: Some(2),
+        }],
+    )
+    .await
+    .unwrap_err();
+
+    assert!(matches!(error, GetShoppingListError::NotFound(missing) if missing == id));
+}
@@ -0,0 +1,72 @@
+mod __tests__;
+
+mod in_memory {
+    use crate::domain::repositories::{
+        ingredients::in_memory::InMemoryIngredientRepository,
+        recipe::in_memory::InMemoryRecipeRepository,
+    };
+
+    use super::__tests__;
+
+    #[tokio::test]
+    async fn shopping_list_adds_up_recipes() {
+        let repo = InMemoryRecipeRepository::new();
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        __tests__::shopping_list_adds_up_recipes(repo, ingredient_repo).await
+    }
+
+    #[tokio::test]
+    async fn shopping_list_rounds_only_the_totals() {
+        let repo = InMemoryRecipeRepository::new();
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        __tests__::shopping_list_rounds_only_the_totals(repo, ingredient_repo).await
+    }
+
+    #[tokio::test]
+    async fn shopping_list_without_recipes_errors() {
+        let repo = InMemoryRecipeRepository::new();
+        __tests__::shopping_list_without_recipes_errors(repo).await
+    }
+
+    #[tokio::test]
+    async fn shopping_list_with_nonexistent_recipe_errors() {
+        let repo = InMemoryRecipeRepository::new();
+        __tests__::shopping_list_with_nonexistent_recipe_errors(repo).await
+    }
+}
+
+mod sql {
+    use sqlx::PgPool;
+
+    use super::__tests__;
+    use crate::domain::repositories::{
+        ingredients::postgres::PostgresIngredientRepository,
+        recipe::postgres::PostgresRecipeRepository,
+    };
+
+    #[sqlx::test]
+    async fn shopping_list_adds_up_recipes(pool: PgPool) {
+        let repo = PostgresRecipeRepository::new(pool.clone());
+        let ingredient_repo = PostgresIngredientRepository::new(pool);
+        __tests__::shopping_list_adds_up_recipes(repo, ingredient_repo).await
+    }
+
+    #[sqlx::test]
+    async fn shopping_list_rounds_only_the_totals(pool: PgPool) {
+        let repo = PostgresRecipeRepository::new(pool.clone());
+        let ingredient_repo = PostgresIngredientRepository::new(pool);
+        __tests__::shopping_list_rounds_only_the_totals(repo, ingredient_repo).await
+    }
+
+    #[sqlx::test]
+    async fn shopping_list_without_recipes_errors(pool: PgPool) {
+        let repo = PostgresRecipeRepository::new(pool);
+        __tests__::shopping_list_without_recipes_errors(repo).await
+    }
+
+    #[sqlx::test]
+    async fn shopping_list_with_nonexistent_recipe_errors(pool: PgPool) {
+        let repo = PostgresRecipeRepository::new(pool);
+        __tests__::shopping_list_with_nonexistent_recipe_errors(repo).await
+    }
+}
@@ -0,0 +1,162 @@
+use common::{SubstitutionParamsDTO, SubstitutionSuggestionDTO, SuggestedSubstituteDTO};
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{
+        ingredient::{types::DietViolations, IngredientSubstitute},
+        recipe::{errors::ValidationError, IngredientUnit, IngredientWithAmount},
+    },
+    repositories::{
+        ingredients::{errors::GetSubstitutesError, IngredientRepositoryService},
+        recipe::{
+            errors::GetRecipeByIdError as GetRecipeByIdErrorInternal, RecipeRepositoryService,
+        },
+    },
+};
+
+#[derive(Debug, Clone)]
+pub struct SubstitutionSuggestion {
+    pub ingredient: IngredientWithAmount,
+    pub substitutes: Vec<SuggestedSubstitute>,
+}
+
+#[derive(Debug, Clone)]
+pub struct SuggestedSubstitute {
+    pub substitute: IngredientSubstitute,
+    pub amount: IngredientUnit,
+}
+
+impl From<SubstitutionSuggestion> for SubstitutionSuggestionDTO {
+    fn from(value: SubstitutionSuggestion) -> Self {
+        Self {
+            ingredient: value.ingredient.into(),
+            substitutes: value.substitutes.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<SuggestedSubstitute> for SuggestedSubstituteDTO {
+    fn from(value: SuggestedSubstitute) -> Self {
+        Self {
+            substitute: value.substitute.into(),
+            amount: value.amount.into(),
+        }
+    }
+}
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum SuggestSubstitutionsError {
+    #[error("Could not found the recipe with the ID {0}")]
+    NotFound(Uuid),
+
+    #[error("At least one diet has to be given")]
+    NoDiets,
+
+    #[error(transparent)]
+    ValidationError(#[from] ValidationError),
+
+    #[error(transparent)]
+    Unknown(#[from] eyre::Error),
+}
+
+impl From<GetRecipeByIdErrorInternal> for SuggestSubstitutionsError {
+    fn from(value: GetRecipeByIdErrorInternal) -> Self {
+        match value {
+            GetRecipeByIdErrorInternal::NotFound(id) => Self::NotFound(id),
+            GetRecipeByIdErrorInternal::ValidationError(e) => Self::ValidationError(e),
+            GetRecipeByIdErrorInternal::UnknownError(e) => Self::Unknown(e),
+        }
+    }
+}
+
+impl From<GetSubstitutesError> for SuggestSubstitutionsError {
+    fn from(value: GetSubstitutesError) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+pub fn parse_diets(
+    value: SubstitutionParamsDTO,
+) -> Result<Vec<DietViolations>, SuggestSubstitutionsError> {
+    let diets = value
+        .diets
+        .split(',')
+        .map(str::trim)
+        .filter(|v| !v.is_empty())
+        .map(|v| DietViolations::try_from(v.to_string()))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(ValidationError::from)?;
+
+    if diets.is_empty() {
+        return Err(SuggestSubstitutionsError::NoDiets);
+    }
+
+    Ok(diets)
+}
+
+/// For every ingredient of the recipe violating at least one of the given diets,
+/// lists the substitutes that are compatible with all of them.
+/// Ingredients without a fitting substitute are still listed, with no substitutes.
+#[tracing::instrument(
+    "[QUERY] Suggest substitutions for a recipe",
+    skip(recipe_repo, ingredient_repo)
+)]
+pub async fn suggest_substitutions(
+    recipe_repo: RecipeRepositoryService,
+    ingredient_repo: IngredientRepositoryService,
+    id: &Uuid,
+    diets: &[DietViolations],
+) -> Result<Vec<SubstitutionSuggestion>, SuggestSubstitutionsError> {
+    if diets.is_empty() {
+        return Err(SuggestSubstitutionsError::NoDiets);
+    }
+
+    let recipe = recipe_repo.get_by_id(id).await?;
+
+    let violates = |diet_violations: &[DietViolations]| {
+        diets.iter().any(|diet| diet_violations.contains(diet))
+    };
+
+    let offending: Vec<IngredientWithAmount> = recipe
+        .ingredients
+        .iter()
+        .filter(|i| violates(&i.ingredient.diet_violations))
+        .cloned()
+        .collect();
+
+    if offending.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let ids: Vec<Uuid> = offending.iter().map(|i| i.ingredient.id).collect();
+    let mut substitutes = ingredient_repo.get_substitutes(&ids).await?;
+
+    let result = offending
+        .into_iter()
+        .map(|ingredient| {
+            let substitutes = substitutes
+                .remove(&ingredient.ingredient.id)
+                .unwrap_or_default()
+                .into_iter()
+                .filter(|s| !violates(&s.substitute.diet_violations))
+                .map(|substitute| SuggestedSubstitute {
+                    amount: ingredient
+                        .amount
+                        .scaled(*substitute.ratio)
+                        .to_kitchen_precision(),
+                    substitute,
+                })
+                .collect();
+
+            SubstitutionSuggestion {
+                ingredient,
+                substitutes,
+            }
+        })
+        .collect();
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests;
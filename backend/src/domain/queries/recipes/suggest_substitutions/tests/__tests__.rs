@@ -0,0 +1,129 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        entities::{
+            ingredient::{
+                types::{Allergens, DietViolations, SubstituteRatio},
+                Ingredient, IngredientSubstitute,
+            },
+            recipe::IngredientUnit,
+        },
+        queries::recipes::suggest_substitutions::{
+            suggest_substitutions, SuggestSubstitutionsError,
+        },
+        repositories::{
+            ingredients::{IngredientRepository, IngredientRepositoryService},
+            recipe::{RecipeRepository, RecipeRepositoryService},
+        },
+    },
+    test_utils::{insert_all_ingredients_of_recipe, recipe_fixture},
+};
+
+pub async fn suggesting_substitutions_works(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+) {
+    let recipe = recipe_fixture();
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
+    repo.insert(recipe.clone()).await.unwrap();
+
+    let tofu = recipe.ingredients.first().unwrap().ingredient.clone();
+    let seitan = Ingredient {
+        id: Uuid::from_u128(100),
+        name: "Seitan".try_into().unwrap(),
+        description: "Wheat gluten".try_into().unwrap(),
+        diet_violations: vec![DietViolations::GlutenFree].into(),
+        parent_id: None,
+        allergens: Allergens::new(),
+    };
+    let chicken = Ingredient {
+        id: Uuid::from_u128(101),
+        name: "Chicken".try_into().unwrap(),
+        description: "It's chicken".try_into().unwrap(),
+        diet_violations: vec![DietViolations::Vegan, DietViolations::Vegetarian].into(),
+        parent_id: None,
+        allergens: Allergens::new(),
+    };
+
+    for (substitute, ratio) in [(seitan.clone(), 0.5), (chicken, 1.0)] {
+        ingredient_repo.insert(substitute.clone()).await.unwrap();
+        ingredient_repo
+            .insert_substitute(
+                &tofu,
+                &IngredientSubstitute {
+                    substitute,
+                    ratio: SubstituteRatio(ratio),
+                    note: None,
+                },
+            )
+            .await
+            .unwrap();
+    }
+
+    let repo: RecipeRepositoryService = Arc::new(Box::new(repo));
+    let ingredient_repo: IngredientRepositoryService = Arc::new(Box::new(ingredient_repo));
+
+    let result = suggest_substitutions(repo, ingredient_repo, &recipe.id, &[DietViolations::Vegan])
+        .await
+        .unwrap();
+
+    // Every ingredient of the fixture violates the vegan diet
+    assert_eq!(result.len(), recipe.ingredients.len());
+
+    let suggestion = result
+        .iter()
+        .find(|s| s.ingredient.ingredient.id == tofu.id)
+        .unwrap();
+
+    assert_eq!(suggestion.substitutes.len(), 1);
+    assert_eq!(suggestion.substitutes[0].substitute.substitute, seitan);
+    assert_eq!(
+        suggestion.substitutes[0].amount,
+        IngredientUnit::Grams(200.0)
+    );
+
+    assert!(result
+        .iter()
+        .filter(|s| s.ingredient.ingredient.id != tofu.id)
+        .all(|s| s.substitutes.is_empty()));
+}
+
+pub async fn suggesting_substitutions_without_diets_errors(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+) {
+    let recipe = recipe_fixture();
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
+    repo.insert(recipe.clone()).await.unwrap();
+
+    let repo: RecipeRepositoryService = Arc::new(Box::new(repo));
+    let ingredient_repo: IngredientRepositoryService = Arc::new(Box::new(ingredient_repo));
+
+    let error = suggest_substitutions(repo, ingredient_repo, &recipe.id, &[])
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, SuggestSubstitutionsError::NoDiets));
+}
+
+pub async fn suggesting_substitutions_for_nonexistent_recipe_errors(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+) {
+    let repo: RecipeRepositoryService = Arc::new(Box::new(repo));
+    let ingredient_repo: IngredientRepositoryService = Arc::new(Box::new(ingredient_repo));
+
+    let error = suggest_substitutions(
+        repo,
+        ingredient_repo,
+        &Uuid::from_u128(999),
+        &[DietViolations::Vegan],
+    )
+    .await
+    .unwrap_err();
+
+    assert!(matches!(error, SuggestSubstitutionsError::NotFound(id) if id == Uuid::from_u128(999)));
+}
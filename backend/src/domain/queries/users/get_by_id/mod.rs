@@ -0,0 +1,36 @@
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::user::User,
+    repositories::users::{errors::GetUserByIdError, UserRepositoryService},
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum GetUserError {
+    #[error("User with ID {0} was not found")]
+    NotFound(Uuid),
+    #[error(transparent)]
+    Internal(#[from] eyre::Error),
+}
+
+impl From<GetUserByIdError> for GetUserError {
+    fn from(value: GetUserByIdError) -> Self {
+        match value {
+            GetUserByIdError::NotFound(id) => Self::NotFound(id),
+            e => Self::Internal(e.into()),
+        }
+    }
+}
+
+#[tracing::instrument("[QUERY] Get user by ID", skip(repo))]
+pub async fn get_user_by_id(
+    repo: UserRepositoryService,
+    input: &Uuid,
+) -> Result<User, GetUserError> {
+    let result = repo.get_by_id(input).await?;
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,33 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::user::{types::PasswordHash, User},
+    queries::users::get_by_id::{get_user_by_id, GetUserError},
+    repositories::users::{UserRepository, UserRepositoryService},
+};
+
+pub async fn get_by_id_returns_user(repo: impl UserRepository) {
+    repo.insert(User {
+        id: Uuid::from_u128(1),
+        username: "testuser".try_into().unwrap(),
+        email: "testuser@example.com".try_into().unwrap(),
+        password: PasswordHash::from_password("meaningless").unwrap(),
+    })
+    .await
+    .unwrap();
+
+    let repo: UserRepositoryService = Arc::new(Box::new(repo));
+    let result = get_user_by_id(repo, &Uuid::from_u128(1)).await.unwrap();
+
+    assert_eq!(result.username, "testuser".try_into().unwrap());
+    assert_eq!(result.email, "testuser@example.com".try_into().unwrap());
+}
+
+pub async fn get_by_id_returns_error_when_missing(repo: impl UserRepository) {
+    let repo: UserRepositoryService = Arc::new(Box::new(repo));
+    let result = get_user_by_id(repo, &Uuid::from_u128(1)).await.unwrap_err();
+
+    assert!(matches!(result, GetUserError::NotFound(id) if id == Uuid::from_u128(1)));
+}
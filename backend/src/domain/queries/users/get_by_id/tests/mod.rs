@@ -0,0 +1,37 @@
+mod __tests__;
+
+mod in_memory {
+    use super::__tests__;
+    use crate::domain::repositories::users::in_memory::InMemoryUserRepository;
+
+    #[tokio::test]
+    async fn get_by_id_returns_user() {
+        let repo = InMemoryUserRepository::new();
+        __tests__::get_by_id_returns_user(repo).await;
+    }
+
+    #[tokio::test]
+    async fn get_by_id_returns_error_when_missing() {
+        let repo = InMemoryUserRepository::new();
+        __tests__::get_by_id_returns_error_when_missing(repo).await;
+    }
+}
+
+mod sql {
+    use sqlx::PgPool;
+
+    use super::__tests__;
+    use crate::domain::repositories::users::postgres::PostgresUserRepository;
+
+    #[sqlx::test]
+    async fn get_by_id_returns_user(pool: PgPool) {
+        let repo = PostgresUserRepository::new(pool);
+        __tests__::get_by_id_returns_user(repo).await;
+    }
+
+    #[sqlx::test]
+    async fn get_by_id_returns_error_when_missing(pool: PgPool) {
+        let repo = PostgresUserRepository::new(pool);
+        __tests__::get_by_id_returns_error_when_missing(repo).await;
+    }
+}
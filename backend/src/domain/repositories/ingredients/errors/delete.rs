@@ -1,9 +1,13 @@
 use eyre::eyre;
 use std::sync::PoisonError;
 use thiserror::Error;
+use uuid::Uuid;
 
 #[derive(Error, Debug)]
 pub enum DeleteIngredientError {
+    #[error("The ingredient with ID {0} has been changed in the meantime")]
+    Outdated(Uuid),
+
     #[error(transparent)]
     UnknownError(#[from] eyre::Error),
 }
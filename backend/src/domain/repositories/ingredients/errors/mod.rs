@@ -2,10 +2,14 @@ mod delete;
 mod get;
 mod get_all;
 mod insert;
+mod nutrition;
+mod substitutes;
 mod update;
 
 pub use delete::*;
 pub use get::*;
 pub use get_all::*;
 pub use insert::*;
+pub use nutrition::*;
+pub use substitutes::*;
 pub use update::*;
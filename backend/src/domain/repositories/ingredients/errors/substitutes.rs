@@ -0,0 +1,84 @@
+use eyre::eyre;
+use std::sync::PoisonError;
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::domain::entities::ingredient::errors::ValidationError;
+
+#[derive(Error, Debug)]
+pub enum GetSubstitutesError {
+    #[error(transparent)]
+    ValidationError(#[from] ValidationError),
+
+    #[error(transparent)]
+    UnknownError(#[from] eyre::Error),
+}
+
+impl<T> From<PoisonError<T>> for GetSubstitutesError {
+    fn from(_value: PoisonError<T>) -> Self {
+        eyre!("Ingredient repository lock was poisoned during a previous access and can no longer be locked").into()
+    }
+}
+
+impl From<sqlx::Error> for GetSubstitutesError {
+    fn from(e: sqlx::Error) -> Self {
+        Self::UnknownError(e.into())
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum InsertSubstituteError {
+    #[error("The ingredient with ID of {0} is already a substitute")]
+    Conflict(Uuid),
+
+    #[error(transparent)]
+    UnknownError(#[from] eyre::Error),
+}
+
+impl<T> From<PoisonError<T>> for InsertSubstituteError {
+    fn from(_value: PoisonError<T>) -> Self {
+        eyre!("Ingredient repository lock was poisoned during a previous access and can no longer be locked").into()
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum UpdateSubstituteError {
+    #[error("The ingredient with ID of {0} is not a substitute")]
+    NotFound(Uuid),
+
+    #[error(transparent)]
+    UnknownError(#[from] eyre::Error),
+}
+
+impl<T> From<PoisonError<T>> for UpdateSubstituteError {
+    fn from(_value: PoisonError<T>) -> Self {
+        eyre!("Ingredient repository lock was poisoned during a previous access and can no longer be locked").into()
+    }
+}
+
+impl From<sqlx::Error> for UpdateSubstituteError {
+    fn from(e: sqlx::Error) -> Self {
+        Self::UnknownError(e.into())
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum DeleteSubstituteError {
+    #[error("The ingredient with ID of {0} is not a substitute")]
+    NotFound(Uuid),
+
+    #[error(transparent)]
+    UnknownError(#[from] eyre::Error),
+}
+
+impl<T> From<PoisonError<T>> for DeleteSubstituteError {
+    fn from(_value: PoisonError<T>) -> Self {
+        eyre!("Ingredient repository lock was poisoned during a previous access and can no longer be locked").into()
+    }
+}
+
+impl From<sqlx::Error> for DeleteSubstituteError {
+    fn from(e: sqlx::Error) -> Self {
+        Self::UnknownError(e.into())
+    }
+}
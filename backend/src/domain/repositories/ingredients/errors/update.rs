@@ -1,11 +1,15 @@
 use eyre::eyre;
 use std::sync::PoisonError;
 use thiserror::Error;
+use uuid::Uuid;
 
 use crate::domain::entities::ingredient::errors::ValidationError;
 
 #[derive(Error, Debug)]
 pub enum UpdateIngredientError {
+    #[error("The ingredient with ID {0} has been changed in the meantime")]
+    Outdated(Uuid),
+
     #[error("The ingredient with field {0} of the given value already exists")]
     Conflict(String),
 
@@ -1,4 +1,5 @@
 use std::{
+    cmp::Reverse,
     collections::{BTreeMap, HashSet},
     sync::Mutex,
 };
@@ -6,19 +7,34 @@ use std::{
 use async_trait::async_trait;
 use uuid::Uuid;
 
-use crate::domain::entities::ingredient::{
-    errors::ValidationError, Ingredient, IngredientChangeset,
+use crate::domain::entities::{
+    ingredient::{
+        errors::ValidationError, nutrition::IngredientNutrition, types::SubstituteRatio,
+        Ingredient, IngredientChangeset, IngredientSort, IngredientSubstitute,
+    },
+    outbox::OutboxMessage,
+    pagination::{Page, PageRequest},
 };
+use crate::domain::repositories::outbox::in_memory::InMemoryOutboxRepository;
 
 use super::{
     errors::{
-        DeleteIngredientError, GetAllIngredientsError, GetIngredientByIdError,
-        InsertIngredientError, UpdateIngredientError,
+        DeleteIngredientError, DeleteNutritionError, DeleteSubstituteError, GetAllIngredientsError,
+        GetIngredientByIdError, GetNutritionError, GetSubstitutesError, InsertIngredientError,
+        InsertSubstituteError, SetNutritionError, UpdateIngredientError, UpdateSubstituteError,
     },
     IngredientRepository,
 };
 
-pub struct InMemoryIngredientRepository(pub Mutex<BTreeMap<Uuid, Ingredient>>);
+/// Ratio and note of a substitute, keyed by the IDs of the ingredient and its substitute
+type SubstituteRecords = BTreeMap<(Uuid, Uuid), (SubstituteRatio, Option<String>)>;
+
+pub struct InMemoryIngredientRepository(
+    pub Mutex<BTreeMap<Uuid, Ingredient>>,
+    pub Mutex<SubstituteRecords>,
+    pub InMemoryOutboxRepository,
+    pub Mutex<BTreeMap<Uuid, IngredientNutrition>>,
+);
 
 #[async_trait]
 impl IngredientRepository for InMemoryIngredientRepository {
@@ -43,10 +59,37 @@ impl IngredientRepository for InMemoryIngredientRepository {
         };
 
         lock.insert(ingredient.id, ingredient.clone());
+        self.2.push(OutboxMessage::ingredient_added(&ingredient))?;
 
         Ok(ingredient)
     }
 
+    #[tracing::instrument(
+        "[INGREDIENT REPOSITORY] [IN MEMORY] Insert multiple ingredients",
+        skip(self, ingredients)
+    )]
+    async fn insert_all(
+        &self,
+        ingredients: Vec<Ingredient>,
+    ) -> Result<Vec<Result<Ingredient, InsertIngredientError>>, InsertIngredientError> {
+        let mut lock = self.0.lock()?;
+        let mut results = vec![];
+
+        for ingredient in ingredients {
+            if lock.contains_key(&ingredient.id) {
+                results.push(Err(InsertIngredientError::Conflict("id".to_string())));
+            } else if lock.values().any(|x| x.name == ingredient.name) {
+                results.push(Err(InsertIngredientError::Conflict("name".to_string())));
+            } else {
+                lock.insert(ingredient.id, ingredient.clone());
+                self.2.push(OutboxMessage::ingredient_added(&ingredient))?;
+                results.push(Ok(ingredient));
+            }
+        }
+
+        Ok(results)
+    }
+
     #[tracing::instrument(
         "[INGREDIENT REPOSITORY] [IN MEMORY] Get ingredient with ID",
         skip(self)
@@ -62,6 +105,25 @@ impl IngredientRepository for InMemoryIngredientRepository {
         Ok(ingredient.clone())
     }
 
+    #[tracing::instrument(
+        "[INGREDIENT REPOSITORY] [IN MEMORY] Get all ingredients by name",
+        skip(self)
+    )]
+    async fn get_all_by_name(
+        &self,
+        names: &[String],
+    ) -> Result<Vec<Ingredient>, GetAllIngredientsError> {
+        let lock = self.0.lock()?;
+
+        let names: Vec<String> = names.iter().map(|name| name.to_lowercase()).collect();
+
+        Ok(lock
+            .values()
+            .filter(|ingredient| names.contains(&ingredient.name.0.to_lowercase()))
+            .cloned()
+            .collect())
+    }
+
     #[tracing::instrument("[INGREDIENT REPOSITORY] [IN MEMORY] Get all ingredients", skip(self))]
     async fn get_all(&self) -> Result<Vec<Ingredient>, GetAllIngredientsError> {
         let lock = self.0.lock()?;
@@ -69,6 +131,30 @@ impl IngredientRepository for InMemoryIngredientRepository {
         Ok(lock.values().cloned().collect())
     }
 
+    #[tracing::instrument(
+        "[INGREDIENT REPOSITORY] [IN MEMORY] Get a page of ingredients",
+        skip(self)
+    )]
+    async fn get_page(
+        &self,
+        request: &PageRequest<IngredientSort>,
+    ) -> Result<Page<Ingredient>, GetAllIngredientsError> {
+        let lock = self.0.lock()?;
+
+        let mut result = lock.values().cloned().collect::<Vec<_>>();
+
+        match request.sort {
+            IngredientSort::Name => {
+                result.sort_by(|a, b| a.name.cmp(&b.name).then(a.id.cmp(&b.id)))
+            }
+            IngredientSort::CreatedAt => result.sort_by_key(|x| Reverse(x.id)),
+        }
+
+        Ok(Page::from_sorted(result, request, |ingredient| {
+            ingredient.id
+        }))
+    }
+
     #[tracing::instrument("[INGREDIENT REPOSITORY] [IN MEMORY] Update ingredient", skip(self))]
     async fn update(
         &self,
@@ -78,19 +164,37 @@ impl IngredientRepository for InMemoryIngredientRepository {
         let mut lock = self.0.lock()?;
         let id = &ingredient.id;
 
+        let old_ingredient = ingredient;
         let ingredient =
             lock.get_mut(id)
                 .ok_or(UpdateIngredientError::UnknownError(eyre::eyre!(
                 "For some reason this ingredient wasn't found, even though we made sure it was."
             )))?;
 
+        if ingredient != old_ingredient {
+            return Err(UpdateIngredientError::Outdated(*id));
+        }
+
         let name: Option<String> = changeset.name.map(|n| n.to_string());
         let description: Option<String> = changeset.description.map(|n| n.to_string());
         let diet_violations: Option<Vec<String>> = changeset.diet_violations.map(|df| df.into());
+        let parent_id = changeset.parent_id;
+        let allergens = changeset.allergens;
 
-        if name.is_none() && description.is_none() && diet_violations.is_none() {
+        if name.is_none()
+            && description.is_none()
+            && diet_violations.is_none()
+            && parent_id.is_none()
+            && allergens.is_none()
+        {
             return Err(UpdateIngredientError::ValidationError(
-                ValidationError::EmptyField(vec!["name", "description", "diet_violations"]),
+                ValidationError::EmptyField(vec![
+                    "name",
+                    "description",
+                    "diet_violations",
+                    "parent_id",
+                    "allergens",
+                ]),
             ));
         };
 
@@ -106,14 +210,205 @@ impl IngredientRepository for InMemoryIngredientRepository {
             ingredient.diet_violations = new_diets.into();
         }
 
+        if let Some(new_parent_id) = parent_id {
+            ingredient.parent_id = new_parent_id;
+        }
+
+        if let Some(new_allergens) = allergens {
+            ingredient.allergens = new_allergens;
+        }
+
+        self.2.push(OutboxMessage::ingredient_updated(
+            old_ingredient,
+            ingredient,
+        ))?;
+
         Ok(())
     }
 
     #[tracing::instrument("[INGREDIENT REPOSITORY] [IN MEMORY] Delete an ingredient", skip(self))]
     async fn delete(&self, ingredient: Ingredient) -> Result<(), DeleteIngredientError> {
         let mut lock = self.0.lock()?;
+
+        if lock.get(&ingredient.id).is_some_and(|i| i != &ingredient) {
+            return Err(DeleteIngredientError::Outdated(ingredient.id));
+        }
+
         lock.remove(&ingredient.id);
 
+        lock.values_mut()
+            .filter(|x| x.parent_id == Some(ingredient.id))
+            .for_each(|x| x.parent_id = None);
+
+        self.1.lock()?.retain(|(id, substitute_id), _| {
+            id != &ingredient.id && substitute_id != &ingredient.id
+        });
+        self.3.lock()?.remove(&ingredient.id);
+
+        self.2
+            .push(OutboxMessage::ingredient_deleted(&ingredient))?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(
+        "[INGREDIENT REPOSITORY] [IN MEMORY] Get variants of an ingredient",
+        skip(self)
+    )]
+    async fn get_variants(&self, id: &Uuid) -> Result<Vec<Ingredient>, GetAllIngredientsError> {
+        let lock = self.0.lock()?;
+
+        let mut result: Vec<Ingredient> = vec![];
+        let mut parents = vec![*id];
+
+        while let Some(parent_id) = parents.pop() {
+            lock.values()
+                .filter(|x| x.parent_id == Some(parent_id))
+                .for_each(|x| {
+                    if x.id != *id && !result.iter().any(|r| r.id == x.id) {
+                        parents.push(x.id);
+                        result.push(x.clone());
+                    }
+                });
+        }
+
+        result.sort_by(|a, b| a.name.cmp(&b.name));
+
+        Ok(result)
+    }
+
+    #[tracing::instrument(
+        "[INGREDIENT REPOSITORY] [IN MEMORY] Get substitutes of ingredients",
+        skip(self)
+    )]
+    async fn get_substitutes(
+        &self,
+        ingredient_ids: &[Uuid],
+    ) -> Result<BTreeMap<Uuid, Vec<IngredientSubstitute>>, GetSubstitutesError> {
+        let ingredients = self.0.lock()?;
+        let substitutes = self.1.lock()?;
+
+        let mut result: BTreeMap<Uuid, Vec<IngredientSubstitute>> = substitutes
+            .iter()
+            .filter(|((id, _), _)| ingredient_ids.contains(id))
+            .filter_map(|((id, substitute_id), (ratio, note))| {
+                let substitute = ingredients.get(substitute_id)?.clone();
+
+                Some((
+                    *id,
+                    IngredientSubstitute {
+                        substitute,
+                        ratio: *ratio,
+                        note: note.clone(),
+                    },
+                ))
+            })
+            .fold(BTreeMap::new(), |mut acc, (id, substitute)| {
+                acc.entry(id).or_insert_with(Vec::new).push(substitute);
+                acc
+            });
+
+        for substitutes in result.values_mut() {
+            substitutes.sort_by(|a, b| a.substitute.name.cmp(&b.substitute.name));
+        }
+
+        Ok(result)
+    }
+
+    #[tracing::instrument("[INGREDIENT REPOSITORY] [IN MEMORY] Insert a substitute", skip(self))]
+    async fn insert_substitute(
+        &self,
+        ingredient: &Ingredient,
+        substitute: &IngredientSubstitute,
+    ) -> Result<(), InsertSubstituteError> {
+        let mut lock = self.1.lock()?;
+        let key = (ingredient.id, substitute.substitute.id);
+
+        if lock.contains_key(&key) {
+            return Err(InsertSubstituteError::Conflict(substitute.substitute.id));
+        }
+
+        lock.insert(key, (substitute.ratio, substitute.note.clone()));
+
+        Ok(())
+    }
+
+    #[tracing::instrument("[INGREDIENT REPOSITORY] [IN MEMORY] Update a substitute", skip(self))]
+    async fn update_substitute(
+        &self,
+        ingredient: &Ingredient,
+        substitute: &IngredientSubstitute,
+    ) -> Result<(), UpdateSubstituteError> {
+        let mut lock = self.1.lock()?;
+
+        let record = lock
+            .get_mut(&(ingredient.id, substitute.substitute.id))
+            .ok_or(UpdateSubstituteError::NotFound(substitute.substitute.id))?;
+
+        *record = (substitute.ratio, substitute.note.clone());
+
+        Ok(())
+    }
+
+    #[tracing::instrument("[INGREDIENT REPOSITORY] [IN MEMORY] Delete a substitute", skip(self))]
+    async fn delete_substitute(
+        &self,
+        ingredient: &Ingredient,
+        substitute_id: &Uuid,
+    ) -> Result<(), DeleteSubstituteError> {
+        let mut lock = self.1.lock()?;
+
+        lock.remove(&(ingredient.id, *substitute_id))
+            .ok_or(DeleteSubstituteError::NotFound(*substitute_id))?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(
+        "[INGREDIENT REPOSITORY] [IN MEMORY] Get nutrition facts of ingredients",
+        skip(self)
+    )]
+    async fn get_nutrition(
+        &self,
+        ingredient_ids: &[Uuid],
+    ) -> Result<BTreeMap<Uuid, IngredientNutrition>, GetNutritionError> {
+        let lock = self.3.lock()?;
+
+        Ok(lock
+            .iter()
+            .filter(|(id, _)| ingredient_ids.contains(id))
+            .map(|(id, nutrition)| (*id, *nutrition))
+            .collect())
+    }
+
+    #[tracing::instrument(
+        "[INGREDIENT REPOSITORY] [IN MEMORY] Set nutrition facts of an ingredient",
+        skip(self)
+    )]
+    async fn set_nutrition(
+        &self,
+        ingredient: &Ingredient,
+        nutrition: &IngredientNutrition,
+    ) -> Result<(), SetNutritionError> {
+        if !self.0.lock()?.contains_key(&ingredient.id) {
+            return Err(SetNutritionError::NotFound(ingredient.id));
+        }
+
+        self.3.lock()?.insert(ingredient.id, *nutrition);
+
+        Ok(())
+    }
+
+    #[tracing::instrument(
+        "[INGREDIENT REPOSITORY] [IN MEMORY] Delete nutrition facts of an ingredient",
+        skip(self)
+    )]
+    async fn delete_nutrition(&self, ingredient: &Ingredient) -> Result<(), DeleteNutritionError> {
+        self.3
+            .lock()?
+            .remove(&ingredient.id)
+            .ok_or(DeleteNutritionError::NotFound(ingredient.id))?;
+
         Ok(())
     }
 
@@ -149,6 +444,12 @@ impl InMemoryIngredientRepository {
     pub fn new() -> Self {
         BTreeMap::new().into()
     }
+
+    /// Writes the messages about the changes into the given outbox, instead of one of its own.
+    pub fn with_outbox(mut self, outbox: InMemoryOutboxRepository) -> Self {
+        self.2 = outbox;
+        self
+    }
 }
 
 impl Default for InMemoryIngredientRepository {
@@ -159,6 +460,11 @@ impl Default for InMemoryIngredientRepository {
 
 impl From<BTreeMap<Uuid, Ingredient>> for InMemoryIngredientRepository {
     fn from(value: BTreeMap<Uuid, Ingredient>) -> Self {
-        Self(value.into())
+        Self(
+            value.into(),
+            Mutex::default(),
+            InMemoryOutboxRepository::new(),
+            Mutex::default(),
+        )
     }
 }
@@ -2,31 +2,93 @@ pub mod errors;
 pub mod in_memory;
 pub mod postgres;
 
-use std::sync::Arc;
+use std::{collections::BTreeMap, sync::Arc};
 
 use async_trait::async_trait;
 use mockall::mock;
 use uuid::Uuid;
 
-use crate::domain::entities::ingredient::{Ingredient, IngredientChangeset};
+use crate::domain::entities::{
+    ingredient::{
+        nutrition::IngredientNutrition, Ingredient, IngredientChangeset, IngredientSort,
+        IngredientSubstitute,
+    },
+    pagination::{Page, PageRequest},
+};
 
 use self::errors::{
-    DeleteIngredientError, GetAllIngredientsError, GetIngredientByIdError, InsertIngredientError,
-    UpdateIngredientError,
+    DeleteIngredientError, DeleteNutritionError, DeleteSubstituteError, GetAllIngredientsError,
+    GetIngredientByIdError, GetNutritionError, GetSubstitutesError, InsertIngredientError,
+    InsertSubstituteError, SetNutritionError, UpdateIngredientError, UpdateSubstituteError,
 };
 
 #[async_trait]
 pub trait IngredientRepository: Send + Sync + 'static {
     async fn insert(&self, ingredient: Ingredient) -> Result<Ingredient, InsertIngredientError>;
+    /// Inserts the ingredients all at once, returning the outcome for each of them in the same order.
+    /// Conflicts are reported for the ingredient which caused them, any other error undoes the whole batch.
+    async fn insert_all(
+        &self,
+        ingredients: Vec<Ingredient>,
+    ) -> Result<Vec<Result<Ingredient, InsertIngredientError>>, InsertIngredientError>;
     async fn get_by_id(&self, id: &Uuid) -> Result<Ingredient, GetIngredientByIdError>;
     async fn get_all_by_id(&self, ids: &[Uuid]) -> Result<Vec<Ingredient>, GetAllIngredientsError>;
+    /// Returns the ingredients with any of the given names, ignoring case.
+    /// Names which don't belong to any ingredient are skipped.
+    async fn get_all_by_name(
+        &self,
+        names: &[String],
+    ) -> Result<Vec<Ingredient>, GetAllIngredientsError>;
     async fn get_all(&self) -> Result<Vec<Ingredient>, GetAllIngredientsError>;
+    async fn get_page(
+        &self,
+        request: &PageRequest<IngredientSort>,
+    ) -> Result<Page<Ingredient>, GetAllIngredientsError>;
+    /// Fails with `Outdated` when the stored ingredient is no longer the same as the given one.
     async fn update(
         &self,
         ingredient: &Ingredient,
         changeset: IngredientChangeset,
     ) -> Result<(), UpdateIngredientError>;
+    /// Fails with `Outdated` when the stored ingredient is no longer the same as the given one.
     async fn delete(&self, ingredient: Ingredient) -> Result<(), DeleteIngredientError>;
+    /// Returns every variant of the ingredient, including the variants of its variants.
+    async fn get_variants(&self, id: &Uuid) -> Result<Vec<Ingredient>, GetAllIngredientsError>;
+
+    /// Returns the substitutes of every given ingredient, keyed by the ID of the ingredient they substitute.
+    /// Ingredients without substitutes are left out.
+    async fn get_substitutes(
+        &self,
+        ingredient_ids: &[Uuid],
+    ) -> Result<BTreeMap<Uuid, Vec<IngredientSubstitute>>, GetSubstitutesError>;
+    async fn insert_substitute(
+        &self,
+        ingredient: &Ingredient,
+        substitute: &IngredientSubstitute,
+    ) -> Result<(), InsertSubstituteError>;
+    async fn update_substitute(
+        &self,
+        ingredient: &Ingredient,
+        substitute: &IngredientSubstitute,
+    ) -> Result<(), UpdateSubstituteError>;
+    async fn delete_substitute(
+        &self,
+        ingredient: &Ingredient,
+        substitute_id: &Uuid,
+    ) -> Result<(), DeleteSubstituteError>;
+    /// Returns the nutrition facts of every given ingredient, keyed by its ID.
+    /// Ingredients without nutrition facts are left out.
+    async fn get_nutrition(
+        &self,
+        ingredient_ids: &[Uuid],
+    ) -> Result<BTreeMap<Uuid, IngredientNutrition>, GetNutritionError>;
+    /// Sets the nutrition facts of the ingredient, replacing the ones it had before.
+    async fn set_nutrition(
+        &self,
+        ingredient: &Ingredient,
+        nutrition: &IngredientNutrition,
+    ) -> Result<(), SetNutritionError>;
+    async fn delete_nutrition(&self, ingredient: &Ingredient) -> Result<(), DeleteNutritionError>;
 }
 mock! {
     pub IngredientRepository {}
@@ -34,15 +96,57 @@ mock! {
     #[async_trait]
     impl IngredientRepository for IngredientRepository {
         async fn insert(&self, ingredient: Ingredient) -> Result<Ingredient, InsertIngredientError>;
+        async fn insert_all(
+            &self,
+            ingredients: Vec<Ingredient>,
+        ) -> Result<Vec<Result<Ingredient, InsertIngredientError>>, InsertIngredientError>;
         async fn get_by_id(&self, id: &Uuid) -> Result<Ingredient, GetIngredientByIdError>;
         async fn get_all_by_id(&self, ids: &[Uuid]) -> Result<Vec<Ingredient>, GetAllIngredientsError>;
+        async fn get_all_by_name(
+            &self,
+            names: &[String],
+        ) -> Result<Vec<Ingredient>, GetAllIngredientsError>;
         async fn get_all(&self) -> Result<Vec<Ingredient>, GetAllIngredientsError>;
+        async fn get_page(
+            &self,
+            request: &PageRequest<IngredientSort>,
+        ) -> Result<Page<Ingredient>, GetAllIngredientsError>;
         async fn update(
             &self,
             ingredient: &Ingredient,
             changeset: IngredientChangeset,
         ) -> Result<(), UpdateIngredientError>;
         async fn delete(&self, ingredient: Ingredient) -> Result<(), DeleteIngredientError>;
+        async fn get_variants(&self, id: &Uuid) -> Result<Vec<Ingredient>, GetAllIngredientsError>;
+        async fn get_substitutes(
+            &self,
+            ingredient_ids: &[Uuid],
+        ) -> Result<BTreeMap<Uuid, Vec<IngredientSubstitute>>, GetSubstitutesError>;
+        async fn insert_substitute(
+            &self,
+            ingredient: &Ingredient,
+            substitute: &IngredientSubstitute,
+        ) -> Result<(), InsertSubstituteError>;
+        async fn update_substitute(
+            &self,
+            ingredient: &Ingredient,
+            substitute: &IngredientSubstitute,
+        ) -> Result<(), UpdateSubstituteError>;
+        async fn delete_substitute(
+            &self,
+            ingredient: &Ingredient,
+            substitute_id: &Uuid,
+        ) -> Result<(), DeleteSubstituteError>;
+        async fn get_nutrition(
+            &self,
+            ingredient_ids: &[Uuid],
+        ) -> Result<BTreeMap<Uuid, IngredientNutrition>, GetNutritionError>;
+        async fn set_nutrition(
+            &self,
+            ingredient: &Ingredient,
+            nutrition: &IngredientNutrition,
+        ) -> Result<(), SetNutritionError>;
+        async fn delete_nutrition(&self, ingredient: &Ingredient) -> Result<(), DeleteNutritionError>;
     }
 }
 
@@ -1,17 +1,29 @@
-use std::{collections::HashMap, sync::OnceLock};
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::OnceLock,
+};
 
-use crate::domain::entities::ingredient::{
-    errors::ValidationError, Ingredient, IngredientChangeset, IngredientModel,
+use crate::domain::entities::{
+    ingredient::{
+        errors::ValidationError,
+        nutrition::{IngredientNutrition, IngredientNutritionModel},
+        Ingredient, IngredientChangeset, IngredientModel, IngredientSort, IngredientSubstitute,
+    },
+    outbox::OutboxMessage,
+    pagination::{Page, PageRequest},
+    recipe::units::Density,
 };
+use crate::domain::repositories::outbox::postgres::insert_message;
 use async_trait::async_trait;
 use rayon::iter::{IntoParallelIterator, IntoParallelRefIterator, ParallelIterator};
-use sqlx::{error::Error as SQLXError, PgPool};
+use sqlx::{error::Error as SQLXError, Connection, PgConnection, PgPool};
 use uuid::Uuid;
 
 use super::{
     errors::{
-        DeleteIngredientError, GetAllIngredientsError, GetIngredientByIdError,
-        InsertIngredientError, UpdateIngredientError,
+        DeleteIngredientError, DeleteNutritionError, DeleteSubstituteError, GetAllIngredientsError,
+        GetIngredientByIdError, GetNutritionError, GetSubstitutesError, InsertIngredientError,
+        InsertSubstituteError, SetNutritionError, UpdateIngredientError, UpdateSubstituteError,
     },
     IngredientRepository,
 };
@@ -28,6 +40,22 @@ fn constraint_to_field(field: &str) -> &str {
     m.get(field).unwrap_or(&field)
 }
 
+/// Locks the row of the ingredient until the end of the transaction,
+/// and checks whether it's still the same as the given ingredient.
+async fn lock_ingredient(conn: &mut PgConnection, ingredient: &Ingredient) -> eyre::Result<bool> {
+    let current = sqlx::query_file_as!(
+        IngredientModel,
+        "queries/ingredients/lock_ingredient.sql",
+        ingredient.id
+    )
+    .fetch_optional(&mut *conn)
+    .await?
+    .map(Ingredient::try_from)
+    .transpose()?;
+
+    Ok(current.as_ref() == Some(ingredient))
+}
+
 #[async_trait]
 impl IngredientRepository for PostgresIngredientRepository {
     #[tracing::instrument(
@@ -42,6 +70,13 @@ impl IngredientRepository for PostgresIngredientRepository {
             .into_iter()
             .map(|d| d.to_string())
             .collect();
+        let allergens: Vec<String> = ingredient.allergens.clone().into();
+
+        let mut tx = self
+            .0
+            .begin()
+            .await
+            .map_err(|e| InsertIngredientError::UnknownError(e.into()))?;
 
         let ingredient = sqlx::query_file_as!(
             IngredientModel,
@@ -49,9 +84,11 @@ impl IngredientRepository for PostgresIngredientRepository {
             ingredient.id,
             &ingredient.name,
             &ingredient.description,
-            &diet_violations
+            &diet_violations,
+            ingredient.parent_id,
+            &allergens
         )
-        .fetch_one(&self.0)
+        .fetch_one(&mut *tx)
         .await
         .map_err(|e| match e {
             SQLXError::Database(dberror) if dberror.is_unique_violation() => {
@@ -61,8 +98,90 @@ impl IngredientRepository for PostgresIngredientRepository {
             }
             _ => InsertIngredientError::UnknownError(e.into()),
         })?;
+        let ingredient: Ingredient = ingredient.try_into()?;
 
-        Ok(ingredient.try_into()?)
+        insert_message(&mut *tx, &OutboxMessage::ingredient_added(&ingredient))
+            .await
+            .map_err(|e| InsertIngredientError::UnknownError(e.into()))?;
+
+        tx.commit()
+            .await
+            .map_err(|e| InsertIngredientError::UnknownError(e.into()))?;
+
+        Ok(ingredient)
+    }
+
+    #[tracing::instrument(
+        "[INGREDIENT REPOSITORY] [POSTGRES] Insert multiple ingredients",
+        skip(self, ingredients)
+    )]
+    async fn insert_all(
+        &self,
+        ingredients: Vec<Ingredient>,
+    ) -> Result<Vec<Result<Ingredient, InsertIngredientError>>, InsertIngredientError> {
+        let mut tx = self
+            .0
+            .begin()
+            .await
+            .map_err(|e| InsertIngredientError::UnknownError(e.into()))?;
+        let mut results = vec![];
+
+        for ingredient in ingredients {
+            let diet_violations: Vec<String> = ingredient.diet_violations.clone().into();
+            let allergens: Vec<String> = ingredient.allergens.clone().into();
+
+            // Every ingredient gets a savepoint, so that a conflict only undoes that one
+            let mut savepoint = tx
+                .begin()
+                .await
+                .map_err(|e| InsertIngredientError::UnknownError(e.into()))?;
+
+            let inserted = sqlx::query_file_as!(
+                IngredientModel,
+                "queries/ingredients/insert_ingredient.sql",
+                ingredient.id,
+                &ingredient.name,
+                &ingredient.description,
+                &diet_violations,
+                ingredient.parent_id,
+                &allergens
+            )
+            .fetch_one(&mut *savepoint)
+            .await;
+
+            let inserted = match inserted {
+                Ok(inserted) => inserted,
+                Err(SQLXError::Database(dberror)) if dberror.is_unique_violation() => {
+                    let field = constraint_to_field(dberror.constraint().unwrap_or_default());
+                    results.push(Err(InsertIngredientError::Conflict(field.to_string())));
+
+                    savepoint
+                        .rollback()
+                        .await
+                        .map_err(|e| InsertIngredientError::UnknownError(e.into()))?;
+                    continue;
+                }
+                Err(e) => return Err(InsertIngredientError::UnknownError(e.into())),
+            };
+            let inserted: Ingredient = inserted.try_into()?;
+
+            insert_message(&mut *savepoint, &OutboxMessage::ingredient_added(&inserted))
+                .await
+                .map_err(|e| InsertIngredientError::UnknownError(e.into()))?;
+
+            savepoint
+                .commit()
+                .await
+                .map_err(|e| InsertIngredientError::UnknownError(e.into()))?;
+
+            results.push(Ok(inserted));
+        }
+
+        tx.commit()
+            .await
+            .map_err(|e| InsertIngredientError::UnknownError(e.into()))?;
+
+        Ok(results)
     }
 
     #[tracing::instrument(
@@ -85,6 +204,30 @@ impl IngredientRepository for PostgresIngredientRepository {
         Ok(ingredient.try_into()?)
     }
 
+    #[tracing::instrument(
+        "[INGREDIENT REPOSITORY] [POSTGRES] Get all ingredients by name",
+        skip(self)
+    )]
+    async fn get_all_by_name(
+        &self,
+        names: &[String],
+    ) -> Result<Vec<Ingredient>, GetAllIngredientsError> {
+        let names: Vec<String> = names.iter().map(|name| name.to_lowercase()).collect();
+
+        let ingredients = sqlx::query_file_as!(
+            IngredientModel,
+            "queries/ingredients/get_ingredients_by_name.sql",
+            &names
+        )
+        .fetch_all(&self.0)
+        .await?
+        .into_iter()
+        .map(Ingredient::try_from)
+        .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(ingredients)
+    }
+
     #[tracing::instrument("[INGREDIENT REPOSITORY] [POSTGRES] Get all ingredients", skip(self))]
     async fn get_all(&self) -> Result<Vec<Ingredient>, GetAllIngredientsError> {
         let ingredients = sqlx::query_file_as!(
@@ -100,6 +243,51 @@ impl IngredientRepository for PostgresIngredientRepository {
         Ok(ingredients)
     }
 
+    #[tracing::instrument(
+        "[INGREDIENT REPOSITORY] [POSTGRES] Get a page of ingredients",
+        skip(self)
+    )]
+    async fn get_page(
+        &self,
+        request: &PageRequest<IngredientSort>,
+    ) -> Result<Page<Ingredient>, GetAllIngredientsError> {
+        let limit = i64::from(request.limit) + 1;
+
+        let result = match request.sort {
+            IngredientSort::Name => {
+                sqlx::query_file_as!(
+                    IngredientModel,
+                    "queries/ingredients/get_ingredients_page_by_name.sql",
+                    request.cursor,
+                    limit
+                )
+                .fetch_all(&self.0)
+                .await?
+            }
+            IngredientSort::CreatedAt => {
+                sqlx::query_file_as!(
+                    IngredientModel,
+                    "queries/ingredients/get_ingredients_page_by_created_at.sql",
+                    request.cursor,
+                    limit
+                )
+                .fetch_all(&self.0)
+                .await?
+            }
+        };
+
+        let ingredients = result
+            .into_iter()
+            .map(Ingredient::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Page::from_overfetched(
+            ingredients,
+            request.limit,
+            |ingredient| ingredient.id,
+        ))
+    }
+
     #[tracing::instrument("[INGREDIENT REPOSITORY] [POSTGRES] Update ingredient", skip(self))]
     async fn update(
         &self,
@@ -112,14 +300,31 @@ impl IngredientRepository for PostgresIngredientRepository {
         let name: Option<String> = changeset.name.map(|n| n.to_string());
         let description: Option<String> = changeset.description.map(|n| n.to_string());
         let diet_violations: Option<Vec<String>> = changeset.diet_violations.map(|df| df.into());
+        let parent_id = changeset.parent_id;
+        let allergens: Option<Vec<String>> = changeset.allergens.map(|a| a.into());
 
-        if name.is_none() && description.is_none() && diet_violations.is_none() {
+        if name.is_none()
+            && description.is_none()
+            && diet_violations.is_none()
+            && parent_id.is_none()
+            && allergens.is_none()
+        {
             return Err(UpdateIngredientError::ValidationError(
-                ValidationError::EmptyField(vec!["name", "description", "diet_violations"]),
+                ValidationError::EmptyField(vec![
+                    "name",
+                    "description",
+                    "diet_violations",
+                    "parent_id",
+                    "allergens",
+                ]),
             ));
         };
 
-        let tx = self.0.begin().await?;
+        let mut tx = self.0.begin().await?;
+
+        if !lock_ingredient(&mut tx, ingredient).await? {
+            return Err(UpdateIngredientError::Outdated(ingredient.id));
+        }
 
         if let Some(name) = name {
             if name != ingredient_to_update.name {
@@ -133,7 +338,7 @@ impl IngredientRepository for PostgresIngredientRepository {
                     id,
                     name,
                 )
-                .execute(&self.0)
+                .execute(&mut *tx)
                 .await?;
             }
         };
@@ -150,7 +355,7 @@ impl IngredientRepository for PostgresIngredientRepository {
                     id,
                     description,
                 )
-                .execute(&self.0)
+                .execute(&mut *tx)
                 .await?;
             }
         };
@@ -167,11 +372,60 @@ impl IngredientRepository for PostgresIngredientRepository {
                     id,
                     &diet_violations
                 )
-                .execute(&self.0)
+                .execute(&mut *tx)
+                .await?;
+            }
+        };
+
+        if let Some(parent_id) = parent_id {
+            if parent_id != ingredient_to_update.parent_id {
+                sqlx::query!(
+                    r#"
+                    UPDATE ingredients
+                    SET
+                    parent_id = $2
+                    WHERE id = $1
+                    "#,
+                    id,
+                    parent_id
+                )
+                .execute(&mut *tx)
                 .await?;
             }
         };
 
+        if let Some(allergens) = allergens {
+            if allergens != ingredient_to_update.allergens {
+                sqlx::query!(
+                    r#"
+                    UPDATE ingredients
+                    SET
+                    allergens = $2
+                    WHERE id = $1
+                    "#,
+                    id,
+                    &allergens
+                )
+                .execute(&mut *tx)
+                .await?;
+            }
+        };
+
+        let updated_ingredient: Ingredient = sqlx::query_file_as!(
+            IngredientModel,
+            "queries/ingredients/get_ingredient_by_id.sql",
+            id
+        )
+        .fetch_one(&mut *tx)
+        .await?
+        .try_into()?;
+
+        insert_message(
+            &mut *tx,
+            &OutboxMessage::ingredient_updated(ingredient, &updated_ingredient),
+        )
+        .await?;
+
         tx.commit().await?;
 
         Ok(())
@@ -179,10 +433,215 @@ impl IngredientRepository for PostgresIngredientRepository {
 
     #[tracing::instrument("[INGREDIENT REPOSITORY] [POSTGRES] Delete an ingredient", skip(self))]
     async fn delete(&self, ingredient: Ingredient) -> Result<(), DeleteIngredientError> {
+        let mut tx = self.0.begin().await?;
+
+        if !lock_ingredient(&mut tx, &ingredient).await? {
+            return Err(DeleteIngredientError::Outdated(ingredient.id));
+        }
+
         sqlx::query_file!("queries/ingredients/delete_ingredient.sql", ingredient.id)
+            .execute(&mut *tx)
+            .await?;
+
+        insert_message(&mut *tx, &OutboxMessage::ingredient_deleted(&ingredient)).await?;
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(
+        "[INGREDIENT REPOSITORY] [POSTGRES] Get variants of an ingredient",
+        skip(self)
+    )]
+    async fn get_variants(&self, id: &Uuid) -> Result<Vec<Ingredient>, GetAllIngredientsError> {
+        let ingredients =
+            sqlx::query_file_as!(IngredientModel, "queries/ingredients/get_variants.sql", id)
+                .fetch_all(&self.0)
+                .await?
+                .into_iter()
+                .map(Ingredient::try_from)
+                .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(ingredients)
+    }
+
+    #[tracing::instrument(
+        "[INGREDIENT REPOSITORY] [POSTGRES] Get substitutes of ingredients",
+        skip(self)
+    )]
+    async fn get_substitutes(
+        &self,
+        ingredient_ids: &[Uuid],
+    ) -> Result<BTreeMap<Uuid, Vec<IngredientSubstitute>>, GetSubstitutesError> {
+        let rows = sqlx::query_file!("queries/ingredients/get_substitutes.sql", ingredient_ids)
+            .fetch_all(&self.0)
+            .await?;
+
+        let mut result: BTreeMap<Uuid, Vec<IngredientSubstitute>> = BTreeMap::new();
+
+        for row in rows {
+            let substitute = IngredientModel {
+                id: row.id,
+                name: row.name,
+                description: row.description,
+                diet_violations: row.diet_violations,
+                parent_id: row.parent_id,
+                allergens: row.allergens,
+            };
+
+            result
+                .entry(row.ingredient_id)
+                .or_default()
+                .push(IngredientSubstitute {
+                    substitute: substitute.try_into()?,
+                    ratio: row.ratio.try_into()?,
+                    note: row.note,
+                });
+        }
+
+        Ok(result)
+    }
+
+    #[tracing::instrument("[INGREDIENT REPOSITORY] [POSTGRES] Insert a substitute", skip(self))]
+    async fn insert_substitute(
+        &self,
+        ingredient: &Ingredient,
+        substitute: &IngredientSubstitute,
+    ) -> Result<(), InsertSubstituteError> {
+        sqlx::query_file!(
+            "queries/ingredients/insert_substitute.sql",
+            ingredient.id,
+            substitute.substitute.id,
+            *substitute.ratio,
+            substitute.note
+        )
+        .execute(&self.0)
+        .await
+        .map_err(|e| match e {
+            SQLXError::Database(dberror) if dberror.is_unique_violation() => {
+                InsertSubstituteError::Conflict(substitute.substitute.id)
+            }
+            e => InsertSubstituteError::UnknownError(e.into()),
+        })?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument("[INGREDIENT REPOSITORY] [POSTGRES] Update a substitute", skip(self))]
+    async fn update_substitute(
+        &self,
+        ingredient: &Ingredient,
+        substitute: &IngredientSubstitute,
+    ) -> Result<(), UpdateSubstituteError> {
+        let result = sqlx::query_file!(
+            "queries/ingredients/update_substitute.sql",
+            ingredient.id,
+            substitute.substitute.id,
+            *substitute.ratio,
+            substitute.note
+        )
+        .execute(&self.0)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(UpdateSubstituteError::NotFound(substitute.substitute.id));
+        }
+
+        Ok(())
+    }
+
+    #[tracing::instrument("[INGREDIENT REPOSITORY] [POSTGRES] Delete a substitute", skip(self))]
+    async fn delete_substitute(
+        &self,
+        ingredient: &Ingredient,
+        substitute_id: &Uuid,
+    ) -> Result<(), DeleteSubstituteError> {
+        let result = sqlx::query_file!(
+            "queries/ingredients/delete_substitute.sql",
+            ingredient.id,
+            substitute_id
+        )
+        .execute(&self.0)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(DeleteSubstituteError::NotFound(*substitute_id));
+        }
+
+        Ok(())
+    }
+
+    #[tracing::instrument(
+        "[INGREDIENT REPOSITORY] [POSTGRES] Get nutrition facts of ingredients",
+        skip(self)
+    )]
+    async fn get_nutrition(
+        &self,
+        ingredient_ids: &[Uuid],
+    ) -> Result<BTreeMap<Uuid, IngredientNutrition>, GetNutritionError> {
+        let rows = sqlx::query_file_as!(
+            IngredientNutritionModel,
+            "queries/ingredients/get_nutrition.sql",
+            ingredient_ids
+        )
+        .fetch_all(&self.0)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| (row.ingredient_id, row.into()))
+            .collect())
+    }
+
+    #[tracing::instrument(
+        "[INGREDIENT REPOSITORY] [POSTGRES] Set nutrition facts of an ingredient",
+        skip(self)
+    )]
+    async fn set_nutrition(
+        &self,
+        ingredient: &Ingredient,
+        nutrition: &IngredientNutrition,
+    ) -> Result<(), SetNutritionError> {
+        let facts = &nutrition.per_100g;
+
+        sqlx::query_file!(
+            "queries/ingredients/set_nutrition.sql",
+            ingredient.id,
+            facts.energy_kcal,
+            facts.protein,
+            facts.fat,
+            facts.carbohydrates,
+            facts.fiber,
+            facts.sugar,
+            facts.salt,
+            nutrition.density.map(|Density(density)| density)
+        )
+        .execute(&self.0)
+        .await
+        .map_err(|e| match e {
+            SQLXError::Database(dberror) if dberror.is_foreign_key_violation() => {
+                SetNutritionError::NotFound(ingredient.id)
+            }
+            e => SetNutritionError::UnknownError(e.into()),
+        })?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(
+        "[INGREDIENT REPOSITORY] [POSTGRES] Delete nutrition facts of an ingredient",
+        skip(self)
+    )]
+    async fn delete_nutrition(&self, ingredient: &Ingredient) -> Result<(), DeleteNutritionError> {
+        let result = sqlx::query_file!("queries/ingredients/delete_nutrition.sql", ingredient.id)
             .execute(&self.0)
             .await?;
 
+        if result.rows_affected() == 0 {
+            return Err(DeleteNutritionError::NotFound(ingredient.id));
+        }
+
         Ok(())
     }
 
@@ -0,0 +1,30 @@
+use eyre::eyre;
+use sqlx::Error as SQLXError;
+use std::sync::PoisonError;
+use thiserror::Error;
+
+use crate::domain::entities::meal_plan::errors::ValidationError;
+
+#[derive(Error, Debug)]
+pub enum InsertMealPlanError {
+    #[error("The meal plan with field {0} of the given value already exists")]
+    Conflict(String),
+
+    #[error(transparent)]
+    ValidationError(#[from] ValidationError),
+
+    #[error(transparent)]
+    UnknownError(#[from] eyre::Error),
+}
+
+impl<T> From<PoisonError<T>> for InsertMealPlanError {
+    fn from(_value: PoisonError<T>) -> Self {
+        eyre!("Meal plan repository lock was poisoned during a previous access and can no longer be locked").into()
+    }
+}
+
+impl From<SQLXError> for InsertMealPlanError {
+    fn from(e: SQLXError) -> Self {
+        Self::UnknownError(e.into())
+    }
+}
@@ -0,0 +1,34 @@
+use eyre::eyre;
+use sqlx::Error as SQLXError;
+use std::sync::PoisonError;
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::domain::entities::meal_plan::errors::ValidationError;
+
+#[derive(Error, Debug)]
+pub enum UpdateMealPlanError {
+    #[error("The meal plan with ID of {0} was not found")]
+    NotFound(Uuid),
+
+    #[error("The meal plan with field {0} of the given value already exists")]
+    Conflict(String),
+
+    #[error(transparent)]
+    ValidationError(#[from] ValidationError),
+
+    #[error(transparent)]
+    UnknownError(#[from] eyre::Error),
+}
+
+impl<T> From<PoisonError<T>> for UpdateMealPlanError {
+    fn from(_value: PoisonError<T>) -> Self {
+        eyre!("Meal plan repository lock was poisoned during a previous access and can no longer be locked").into()
+    }
+}
+
+impl From<SQLXError> for UpdateMealPlanError {
+    fn from(e: SQLXError) -> Self {
+        Self::UnknownError(e.into())
+    }
+}
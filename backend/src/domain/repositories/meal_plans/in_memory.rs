@@ -0,0 +1,136 @@
+use std::{collections::BTreeMap, sync::Mutex};
+
+use async_trait::async_trait;
+use chrono::Utc;
+use uuid::Uuid;
+
+use crate::domain::entities::meal_plan::MealPlan;
+
+use super::{
+    errors::{
+        DeleteMealPlanError, GetAllMealPlansError, GetMealPlanByIdError, InsertMealPlanError,
+        UpdateMealPlanError,
+    },
+    MealPlanRepository,
+};
+
+pub struct InMemoryMealPlanRepository(pub Mutex<BTreeMap<Uuid, MealPlan>>);
+
+/// Whether another plan of the same user already starts on the same day.
+fn starts_on_taken(plans: &BTreeMap<Uuid, MealPlan>, input: &MealPlan) -> bool {
+    plans
+        .values()
+        .any(|x| x.id != input.id && x.owner_id == input.owner_id && x.starts_on == input.starts_on)
+}
+
+#[async_trait]
+impl MealPlanRepository for InMemoryMealPlanRepository {
+    #[tracing::instrument(
+        "[MEAL PLAN REPOSITORY] [IN MEMORY] Insert a new meal plan",
+        skip(self)
+    )]
+    async fn insert(&self, input: MealPlan) -> Result<MealPlan, InsertMealPlanError> {
+        let mut lock = self.0.lock()?;
+
+        if lock.contains_key(&input.id) {
+            tracing::error!("The meal plan with ID {} already exists.", input.id);
+            return Err(InsertMealPlanError::Conflict("id".to_string()));
+        }
+
+        if starts_on_taken(&lock, &input) {
+            tracing::error!(
+                "The user already has a meal plan starting on {}.",
+                input.starts_on
+            );
+            return Err(InsertMealPlanError::Conflict("starts_on".to_string()));
+        }
+
+        let input = input.validated()?;
+        lock.insert(input.id, input.clone());
+
+        Ok(input)
+    }
+
+    #[tracing::instrument("[MEAL PLAN REPOSITORY] [IN MEMORY] Get meal plan with ID", skip(self))]
+    async fn get_by_id(&self, id: &Uuid) -> Result<MealPlan, GetMealPlanByIdError> {
+        let lock = self.0.lock()?;
+
+        let plan = lock.get(id).ok_or(GetMealPlanByIdError::NotFound(*id))?;
+
+        Ok(plan.clone())
+    }
+
+    #[tracing::instrument(
+        "[MEAL PLAN REPOSITORY] [IN MEMORY] Get all meal plans of a user",
+        skip(self)
+    )]
+    async fn get_all_of_user(
+        &self,
+        owner_id: &Uuid,
+    ) -> Result<Vec<MealPlan>, GetAllMealPlansError> {
+        let lock = self.0.lock()?;
+
+        let mut plans: Vec<MealPlan> = lock
+            .values()
+            .filter(|x| x.belongs_to(owner_id))
+            .cloned()
+            .collect();
+        plans.sort_by_key(|x| x.starts_on);
+
+        Ok(plans)
+    }
+
+    #[tracing::instrument("[MEAL PLAN REPOSITORY] [IN MEMORY] Update a meal plan", skip(self))]
+    async fn update(&self, input: MealPlan) -> Result<MealPlan, UpdateMealPlanError> {
+        let mut lock = self.0.lock()?;
+
+        if !lock.contains_key(&input.id) {
+            return Err(UpdateMealPlanError::NotFound(input.id));
+        }
+
+        if starts_on_taken(&lock, &input) {
+            tracing::error!(
+                "The user already has a meal plan starting on {}.",
+                input.starts_on
+            );
+            return Err(UpdateMealPlanError::Conflict("starts_on".to_string()));
+        }
+
+        let input = MealPlan {
+            updated_at: Utc::now(),
+            ..input
+        }
+        .validated()?;
+        lock.insert(input.id, input.clone());
+
+        Ok(input)
+    }
+
+    #[tracing::instrument("[MEAL PLAN REPOSITORY] [IN MEMORY] Delete a meal plan", skip(self))]
+    async fn delete(&self, input: &MealPlan) -> Result<(), DeleteMealPlanError> {
+        let mut lock = self.0.lock()?;
+
+        lock.remove(&input.id)
+            .ok_or(DeleteMealPlanError::NotFound(input.id))?;
+
+        Ok(())
+    }
+}
+
+impl InMemoryMealPlanRepository {
+    pub fn new() -> Self {
+        BTreeMap::new().into()
+    }
+}
+
+impl Default for InMemoryMealPlanRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<BTreeMap<Uuid, MealPlan>> for InMemoryMealPlanRepository {
+    fn from(value: BTreeMap<Uuid, MealPlan>) -> Self {
+        Self(value.into())
+    }
+}
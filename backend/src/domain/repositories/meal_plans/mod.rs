@@ -0,0 +1,29 @@
+pub mod errors;
+pub mod in_memory;
+pub mod postgres;
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::entities::meal_plan::MealPlan;
+
+use self::errors::{
+    DeleteMealPlanError, GetAllMealPlansError, GetMealPlanByIdError, InsertMealPlanError,
+    UpdateMealPlanError,
+};
+
+#[async_trait]
+pub trait MealPlanRepository: Send + Sync + 'static {
+    async fn insert(&self, input: MealPlan) -> Result<MealPlan, InsertMealPlanError>;
+    async fn get_by_id(&self, id: &Uuid) -> Result<MealPlan, GetMealPlanByIdError>;
+    /// Returns the plans of the user, the earliest ones first.
+    async fn get_all_of_user(&self, owner_id: &Uuid)
+        -> Result<Vec<MealPlan>, GetAllMealPlansError>;
+    /// Replaces the start and the meals of the plan with the given ones.
+    async fn update(&self, input: MealPlan) -> Result<MealPlan, UpdateMealPlanError>;
+    async fn delete(&self, input: &MealPlan) -> Result<(), DeleteMealPlanError>;
+}
+
+pub type MealPlanRepositoryService = Arc<Box<dyn MealPlanRepository>>;
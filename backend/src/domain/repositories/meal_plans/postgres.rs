@@ -0,0 +1,221 @@
+use std::{collections::HashMap, sync::OnceLock};
+
+use async_trait::async_trait;
+use itertools::Itertools;
+use sqlx::{error::Error as SQLXError, PgConnection, PgPool};
+use uuid::Uuid;
+
+use crate::domain::entities::meal_plan::{MealPlan, MealPlanModel, PlannedMeal, PlannedMealModel};
+
+use super::{
+    errors::{
+        DeleteMealPlanError, GetAllMealPlansError, GetMealPlanByIdError, InsertMealPlanError,
+        UpdateMealPlanError,
+    },
+    MealPlanRepository,
+};
+
+pub struct PostgresMealPlanRepository(pub PgPool);
+
+/// Maps unique constraint names to the fields they guard, the same way the user repository does.
+fn constraint_to_field(field: &str) -> &str {
+    static HASHMAP: OnceLock<HashMap<&str, &str>> = OnceLock::new();
+    let m = HASHMAP.get_or_init(|| {
+        HashMap::from_iter([
+            ("meal_plans_owner_id_starts_on_key", "starts_on"),
+            ("meal_plans_pkey", "id"),
+        ])
+    });
+    m.get(field).unwrap_or(&field)
+}
+
+/// The field guarded by the unique constraint the error violates, if it is such an error.
+fn conflicting_field(error: &SQLXError) -> Option<String> {
+    match error {
+        SQLXError::Database(dberror) if dberror.is_unique_violation() => {
+            Some(constraint_to_field(dberror.constraint().unwrap_or_default()).to_string())
+        }
+        _ => None,
+    }
+}
+
+async fn insert_meals(
+    conn: &mut PgConnection,
+    meal_plan_id: Uuid,
+    meals: &[PlannedMeal],
+) -> Result<(), SQLXError> {
+    for meal in meals {
+        sqlx::query_file!(
+            "queries/meal_plans/insert_planned_meal.sql",
+            meal_plan_id,
+            meal.date,
+            meal.slot.to_string(),
+            meal.recipe_id,
+            i32::from(meal.servings)
+        )
+        .execute(&mut *conn)
+        .await?;
+    }
+
+    Ok(())
+}
+
+/// Fetches the meals of all of the given plans at once, and puts each plan back together.
+async fn with_meals(
+    conn: &mut PgConnection,
+    plans: Vec<MealPlanModel>,
+) -> Result<Vec<MealPlan>, GetAllMealPlansError> {
+    let ids: Vec<Uuid> = plans.iter().map(|x| x.id).collect();
+
+    let mut meals = sqlx::query_file_as!(
+        PlannedMealModel,
+        "queries/meal_plans/get_meals_for_meal_plans.sql",
+        &ids
+    )
+    .fetch_all(&mut *conn)
+    .await?
+    .into_iter()
+    .into_group_map_by(|x| x.meal_plan_id);
+
+    Ok(plans
+        .into_iter()
+        .map(|plan| {
+            let meals = meals.remove(&plan.id).unwrap_or_default();
+            plan.with_meals(meals)
+        })
+        .collect::<Result<Vec<_>, _>>()?)
+}
+
+async fn get_meal_plan(
+    conn: &mut PgConnection,
+    id: &Uuid,
+) -> Result<MealPlan, GetMealPlanByIdError> {
+    let plan = sqlx::query_file_as!(MealPlanModel, "queries/meal_plans/get_meal_plan.sql", id)
+        .fetch_one(&mut *conn)
+        .await
+        .map_err(|e| match e {
+            SQLXError::RowNotFound => GetMealPlanByIdError::NotFound(*id),
+            _ => GetMealPlanByIdError::UnknownError(e.into()),
+        })?;
+
+    let meals = sqlx::query_file_as!(
+        PlannedMealModel,
+        "queries/meal_plans/get_meals_for_meal_plans.sql",
+        &[*id]
+    )
+    .fetch_all(&mut *conn)
+    .await?;
+
+    Ok(plan.with_meals(meals)?)
+}
+
+#[async_trait]
+impl MealPlanRepository for PostgresMealPlanRepository {
+    #[tracing::instrument("[MEAL PLAN REPOSITORY] [POSTGRES] Insert a new meal plan", skip(self))]
+    async fn insert(&self, input: MealPlan) -> Result<MealPlan, InsertMealPlanError> {
+        let input = input.validated()?;
+        let mut tx = self.0.begin().await?;
+
+        let plan = sqlx::query_file_as!(
+            MealPlanModel,
+            "queries/meal_plans/insert_meal_plan.sql",
+            input.id,
+            input.owner_id,
+            input.starts_on
+        )
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| match conflicting_field(&e) {
+            Some(field) => InsertMealPlanError::Conflict(field),
+            None => InsertMealPlanError::UnknownError(e.into()),
+        })?;
+
+        insert_meals(&mut tx, plan.id, &input.meals).await?;
+
+        tx.commit().await?;
+
+        Ok(MealPlan {
+            meals: input.meals,
+            ..plan.with_meals(vec![])?
+        })
+    }
+
+    #[tracing::instrument("[MEAL PLAN REPOSITORY] [POSTGRES] Get meal plan with ID", skip(self))]
+    async fn get_by_id(&self, id: &Uuid) -> Result<MealPlan, GetMealPlanByIdError> {
+        let mut conn = self.0.acquire().await?;
+
+        get_meal_plan(&mut conn, id).await
+    }
+
+    #[tracing::instrument(
+        "[MEAL PLAN REPOSITORY] [POSTGRES] Get all meal plans of a user",
+        skip(self)
+    )]
+    async fn get_all_of_user(
+        &self,
+        owner_id: &Uuid,
+    ) -> Result<Vec<MealPlan>, GetAllMealPlansError> {
+        let mut conn = self.0.acquire().await?;
+
+        let plans = sqlx::query_file_as!(
+            MealPlanModel,
+            "queries/meal_plans/get_meal_plans_of_user.sql",
+            owner_id
+        )
+        .fetch_all(&mut *conn)
+        .await?;
+
+        with_meals(&mut conn, plans).await
+    }
+
+    #[tracing::instrument("[MEAL PLAN REPOSITORY] [POSTGRES] Update a meal plan", skip(self))]
+    async fn update(&self, input: MealPlan) -> Result<MealPlan, UpdateMealPlanError> {
+        let input = input.validated()?;
+        let mut tx = self.0.begin().await?;
+
+        let plan = sqlx::query_file_as!(
+            MealPlanModel,
+            "queries/meal_plans/update_meal_plan.sql",
+            input.id,
+            input.starts_on
+        )
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| match (&e, conflicting_field(&e)) {
+            (SQLXError::RowNotFound, _) => UpdateMealPlanError::NotFound(input.id),
+            (_, Some(field)) => UpdateMealPlanError::Conflict(field),
+            _ => UpdateMealPlanError::UnknownError(e.into()),
+        })?;
+
+        sqlx::query_file!("queries/meal_plans/delete_meals_of_meal_plan.sql", input.id)
+            .execute(&mut *tx)
+            .await?;
+        insert_meals(&mut tx, plan.id, &input.meals).await?;
+
+        tx.commit().await?;
+
+        Ok(MealPlan {
+            meals: input.meals,
+            ..plan.with_meals(vec![])?
+        })
+    }
+
+    #[tracing::instrument("[MEAL PLAN REPOSITORY] [POSTGRES] Delete a meal plan", skip(self))]
+    async fn delete(&self, input: &MealPlan) -> Result<(), DeleteMealPlanError> {
+        let result = sqlx::query_file!("queries/meal_plans/delete_meal_plan.sql", input.id)
+            .execute(&self.0)
+            .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(DeleteMealPlanError::NotFound(input.id));
+        }
+
+        Ok(())
+    }
+}
+
+impl PostgresMealPlanRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self(pool)
+    }
+}
@@ -1,2 +1,6 @@
 pub mod ingredients;
+pub mod meal_plans;
+pub mod outbox;
+pub mod pantry;
 pub mod recipe;
+pub mod users;
@@ -0,0 +1,123 @@
+use std::{
+    collections::HashSet,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use eyre::eyre;
+use uuid::Uuid;
+
+use crate::domain::entities::outbox::OutboxMessage;
+
+use super::{OutboxRepository, OutboxRepositoryService};
+
+#[derive(Debug, Clone)]
+struct OutboxRecord {
+    message: OutboxMessage,
+    available_at: DateTime<Utc>,
+    published_at: Option<DateTime<Utc>>,
+}
+
+/// The in-memory repositories share one of these, so that the writes and the relay see the same messages.
+/// Cloning it gives another handle to the same outbox.
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryOutboxRepository(Arc<Mutex<Vec<OutboxRecord>>>);
+
+impl InMemoryOutboxRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn service(self) -> OutboxRepositoryService {
+        Arc::new(Box::new(self))
+    }
+
+    /// Stores a message in the outbox.
+    /// Meant to be called while holding the lock of the repository making the change.
+    pub fn push(&self, message: OutboxMessage) -> eyre::Result<()> {
+        let mut lock = self.0.lock().map_err(|e| eyre!("{e}"))?;
+
+        lock.push(OutboxRecord {
+            message,
+            available_at: Utc::now(),
+            published_at: None,
+        });
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl OutboxRepository for InMemoryOutboxRepository {
+    #[tracing::instrument("[OUTBOX REPOSITORY] [IN MEMORY] Claim pending messages", skip(self))]
+    async fn claim_pending(&self, limit: i64, lease: Duration) -> eyre::Result<Vec<OutboxMessage>> {
+        let mut lock = self.0.lock().map_err(|e| eyre!("{e}"))?;
+        let now = Utc::now();
+
+        // Keys of the messages which are being retried or published elsewhere, the later ones wait for them
+        let mut waiting = HashSet::new();
+
+        let claimed = lock
+            .iter_mut()
+            .filter(|r| r.published_at.is_none())
+            .filter(|r| {
+                if r.available_at > now {
+                    waiting.insert(r.message.key.clone());
+                }
+                r.available_at <= now && !waiting.contains(&r.message.key)
+            })
+            .take(limit.try_into()?)
+            .map(|r| {
+                r.available_at = now + lease;
+                r.message.clone()
+            })
+            .collect();
+
+        Ok(claimed)
+    }
+
+    #[tracing::instrument(
+        "[OUTBOX REPOSITORY] [IN MEMORY] Mark message as published",
+        skip(self)
+    )]
+    async fn mark_published(&self, id: &Uuid) -> eyre::Result<()> {
+        let mut lock = self.0.lock().map_err(|e| eyre!("{e}"))?;
+
+        if let Some(record) = lock.iter_mut().find(|r| r.message.id == *id) {
+            record.published_at = Some(Utc::now());
+        }
+
+        Ok(())
+    }
+
+    #[tracing::instrument("[OUTBOX REPOSITORY] [IN MEMORY] Mark message as failed", skip(self))]
+    async fn mark_failed(&self, id: &Uuid, error: &str, retry_in: Duration) -> eyre::Result<()> {
+        let mut lock = self.0.lock().map_err(|e| eyre!("{e}"))?;
+
+        if let Some(record) = lock.iter_mut().find(|r| r.message.id == *id) {
+            record.message.attempts += 1;
+            record.available_at = Utc::now() + retry_in;
+        }
+
+        Ok(())
+    }
+
+    #[tracing::instrument(
+        "[OUTBOX REPOSITORY] [IN MEMORY] Delete published messages",
+        skip(self)
+    )]
+    async fn delete_published(&self, older_than: Duration) -> eyre::Result<u64> {
+        let mut lock = self.0.lock().map_err(|e| eyre!("{e}"))?;
+        let cutoff = Utc::now() - older_than;
+        let before = lock.len();
+
+        lock.retain(|r| {
+            r.published_at
+                .is_none_or(|published_at| published_at > cutoff)
+        });
+
+        Ok((before - lock.len()) as u64)
+    }
+}
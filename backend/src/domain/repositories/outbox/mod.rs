@@ -0,0 +1,31 @@
+pub mod in_memory;
+pub mod postgres;
+
+use std::{sync::Arc, time::Duration};
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::entities::outbox::OutboxMessage;
+
+/// The outbox is written to by the other repositories, in the same transaction as the change that caused the message.
+/// This repository is the reading side of it, used by the relay to publish the messages.
+#[async_trait]
+pub trait OutboxRepository: Send + Sync + 'static {
+    /// Claims up to `limit` unpublished messages, oldest first.
+    /// Claimed messages are hidden from other relays for the duration of the `lease`,
+    /// after which they become available again unless they were marked as published.
+    /// A message isn't claimed while an earlier message with the same key is hidden,
+    /// so that the messages about an entity get published in order.
+    async fn claim_pending(&self, limit: i64, lease: Duration) -> eyre::Result<Vec<OutboxMessage>>;
+
+    async fn mark_published(&self, id: &Uuid) -> eyre::Result<()>;
+
+    /// Records a failed publishing attempt. The message will be retried after `retry_in`.
+    async fn mark_failed(&self, id: &Uuid, error: &str, retry_in: Duration) -> eyre::Result<()>;
+
+    /// Deletes the messages which were published at least `older_than` ago, returning how many there were.
+    async fn delete_published(&self, older_than: Duration) -> eyre::Result<u64>;
+}
+
+pub type OutboxRepositoryService = Arc<Box<dyn OutboxRepository>>;
@@ -0,0 +1,105 @@
+use std::{sync::Arc, time::Duration};
+
+use async_trait::async_trait;
+use sqlx::{PgExecutor, PgPool};
+use uuid::Uuid;
+
+use crate::domain::entities::outbox::OutboxMessage;
+
+use super::{OutboxRepository, OutboxRepositoryService};
+
+pub struct PostgresOutboxRepository(pub PgPool);
+
+/// Stores a message in the outbox.
+/// Meant to be called with the transaction of the change the message is about.
+pub async fn insert_message(
+    executor: impl PgExecutor<'_>,
+    message: &OutboxMessage,
+) -> Result<(), sqlx::Error> {
+    sqlx::query_file!(
+        "queries/outbox/insert_message.sql",
+        message.id,
+        message.topic,
+        message.key,
+        message.payload
+    )
+    .execute(executor)
+    .await?;
+
+    Ok(())
+}
+
+#[async_trait]
+impl OutboxRepository for PostgresOutboxRepository {
+    #[tracing::instrument("[OUTBOX REPOSITORY] [POSTGRES] Claim pending messages", skip(self))]
+    async fn claim_pending(&self, limit: i64, lease: Duration) -> eyre::Result<Vec<OutboxMessage>> {
+        let mut tx = self.0.begin().await?;
+
+        // Relays claim one after another, otherwise one could claim a message
+        // while another is still claiming an earlier message about the same entity
+        sqlx::query_file!("queries/outbox/lock_claims.sql")
+            .execute(&mut *tx)
+            .await?;
+
+        let mut messages = sqlx::query_file_as!(
+            OutboxMessage,
+            "queries/outbox/claim_pending_messages.sql",
+            limit,
+            lease.as_secs_f64()
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        // RETURNING doesn't keep the order of the subquery
+        messages.sort_by_key(|m| m.id);
+
+        Ok(messages)
+    }
+
+    #[tracing::instrument("[OUTBOX REPOSITORY] [POSTGRES] Mark message as published", skip(self))]
+    async fn mark_published(&self, id: &Uuid) -> eyre::Result<()> {
+        sqlx::query_file!("queries/outbox/mark_message_published.sql", id)
+            .execute(&self.0)
+            .await?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument("[OUTBOX REPOSITORY] [POSTGRES] Mark message as failed", skip(self))]
+    async fn mark_failed(&self, id: &Uuid, error: &str, retry_in: Duration) -> eyre::Result<()> {
+        sqlx::query_file!(
+            "queries/outbox/mark_message_failed.sql",
+            id,
+            error,
+            retry_in.as_secs_f64()
+        )
+        .execute(&self.0)
+        .await?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument("[OUTBOX REPOSITORY] [POSTGRES] Delete published messages", skip(self))]
+    async fn delete_published(&self, older_than: Duration) -> eyre::Result<u64> {
+        let result = sqlx::query_file!(
+            "queries/outbox/delete_published_messages.sql",
+            older_than.as_secs_f64()
+        )
+        .execute(&self.0)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+}
+
+impl PostgresOutboxRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self(pool)
+    }
+
+    pub fn service(self) -> OutboxRepositoryService {
+        Arc::new(Box::new(self))
+    }
+}
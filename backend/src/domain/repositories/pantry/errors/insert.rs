@@ -0,0 +1,31 @@
+use eyre::eyre;
+use sqlx::Error as SQLXError;
+use std::sync::PoisonError;
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::domain::entities::recipe::errors::ValidationError;
+
+#[derive(Error, Debug)]
+pub enum InsertPantryItemError {
+    #[error("The ingredient with ID of {0} is already in the pantry")]
+    Conflict(Uuid),
+
+    #[error(transparent)]
+    ValidationError(#[from] ValidationError),
+
+    #[error(transparent)]
+    UnknownError(#[from] eyre::Error),
+}
+
+impl<T> From<PoisonError<T>> for InsertPantryItemError {
+    fn from(_value: PoisonError<T>) -> Self {
+        eyre!("Pantry repository lock was poisoned during a previous access and can no longer be locked").into()
+    }
+}
+
+impl From<SQLXError> for InsertPantryItemError {
+    fn from(e: SQLXError) -> Self {
+        Self::UnknownError(e.into())
+    }
+}
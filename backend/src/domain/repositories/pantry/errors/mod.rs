@@ -0,0 +1,9 @@
+mod delete;
+mod get;
+mod insert;
+mod update;
+
+pub use delete::*;
+pub use get::*;
+pub use insert::*;
+pub use update::*;
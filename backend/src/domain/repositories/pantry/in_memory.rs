@@ -0,0 +1,111 @@
+use std::{collections::BTreeMap, sync::Mutex};
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::entities::pantry::PantryItem;
+
+use super::{
+    errors::{
+        DeletePantryItemError, GetPantryError, GetPantryItemError, InsertPantryItemError,
+        UpdatePantryItemError,
+    },
+    PantryRepository,
+};
+
+/// Items are keyed by the owner, and then by the ingredient.
+pub struct InMemoryPantryRepository(pub Mutex<BTreeMap<(Uuid, Uuid), PantryItem>>);
+
+fn key(item: &PantryItem) -> (Uuid, Uuid) {
+    (item.owner_id, item.ingredient.id)
+}
+
+#[async_trait]
+impl PantryRepository for InMemoryPantryRepository {
+    #[tracing::instrument("[PANTRY REPOSITORY] [IN MEMORY] Insert a new pantry item", skip(self))]
+    async fn insert(&self, input: PantryItem) -> Result<PantryItem, InsertPantryItemError> {
+        let mut lock = self.0.lock()?;
+
+        if lock.contains_key(&key(&input)) {
+            tracing::error!(
+                "The ingredient with ID {} is already in the pantry.",
+                input.ingredient.id
+            );
+            return Err(InsertPantryItemError::Conflict(input.ingredient.id));
+        }
+
+        lock.insert(key(&input), input.clone());
+
+        Ok(input)
+    }
+
+    #[tracing::instrument("[PANTRY REPOSITORY] [IN MEMORY] Get a pantry item", skip(self))]
+    async fn get(
+        &self,
+        owner_id: &Uuid,
+        ingredient_id: &Uuid,
+    ) -> Result<PantryItem, GetPantryItemError> {
+        let lock = self.0.lock()?;
+
+        let item = lock
+            .get(&(*owner_id, *ingredient_id))
+            .ok_or(GetPantryItemError::NotFound(*ingredient_id))?;
+
+        Ok(item.clone())
+    }
+
+    #[tracing::instrument("[PANTRY REPOSITORY] [IN MEMORY] Get the pantry of a user", skip(self))]
+    async fn get_all_of_user(&self, owner_id: &Uuid) -> Result<Vec<PantryItem>, GetPantryError> {
+        let lock = self.0.lock()?;
+
+        let mut items: Vec<PantryItem> = lock
+            .values()
+            .filter(|x| &x.owner_id == owner_id)
+            .cloned()
+            .collect();
+        items.sort_by_key(|x| x.ingredient.name.0.to_lowercase());
+
+        Ok(items)
+    }
+
+    #[tracing::instrument("[PANTRY REPOSITORY] [IN MEMORY] Update a pantry item", skip(self))]
+    async fn update(&self, input: PantryItem) -> Result<PantryItem, UpdatePantryItemError> {
+        let mut lock = self.0.lock()?;
+
+        let item = lock
+            .get_mut(&key(&input))
+            .ok_or(UpdatePantryItemError::NotFound(input.ingredient.id))?;
+        item.amount = input.amount;
+        item.expires_on = input.expires_on;
+
+        Ok(item.clone())
+    }
+
+    #[tracing::instrument("[PANTRY REPOSITORY] [IN MEMORY] Delete a pantry item", skip(self))]
+    async fn delete(&self, input: &PantryItem) -> Result<(), DeletePantryItemError> {
+        let mut lock = self.0.lock()?;
+
+        lock.remove(&key(input))
+            .ok_or(DeletePantryItemError::NotFound(input.ingredient.id))?;
+
+        Ok(())
+    }
+}
+
+impl InMemoryPantryRepository {
+    pub fn new() -> Self {
+        BTreeMap::new().into()
+    }
+}
+
+impl Default for InMemoryPantryRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<BTreeMap<(Uuid, Uuid), PantryItem>> for InMemoryPantryRepository {
+    fn from(value: BTreeMap<(Uuid, Uuid), PantryItem>) -> Self {
+        Self(value.into())
+    }
+}
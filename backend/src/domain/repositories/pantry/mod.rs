@@ -0,0 +1,32 @@
+pub mod errors;
+pub mod in_memory;
+pub mod postgres;
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::entities::pantry::PantryItem;
+
+use self::errors::{
+    DeletePantryItemError, GetPantryError, GetPantryItemError, InsertPantryItemError,
+    UpdatePantryItemError,
+};
+
+#[async_trait]
+pub trait PantryRepository: Send + Sync + 'static {
+    async fn insert(&self, input: PantryItem) -> Result<PantryItem, InsertPantryItemError>;
+    async fn get(
+        &self,
+        owner_id: &Uuid,
+        ingredient_id: &Uuid,
+    ) -> Result<PantryItem, GetPantryItemError>;
+    /// Returns everything in the pantry of the user, sorted by the name of the ingredient.
+    async fn get_all_of_user(&self, owner_id: &Uuid) -> Result<Vec<PantryItem>, GetPantryError>;
+    /// Replaces the amount and the expiry date of the item.
+    async fn update(&self, input: PantryItem) -> Result<PantryItem, UpdatePantryItemError>;
+    async fn delete(&self, input: &PantryItem) -> Result<(), DeletePantryItemError>;
+}
+
+pub type PantryRepositoryService = Arc<Box<dyn PantryRepository>>;
@@ -0,0 +1,128 @@
+use async_trait::async_trait;
+use sqlx::{error::Error as SQLXError, PgPool};
+use uuid::Uuid;
+
+use crate::domain::entities::{
+    ingredient::IngredientModel,
+    pantry::{PantryItem, PantryItemModel},
+};
+
+use super::{
+    errors::{
+        DeletePantryItemError, GetPantryError, GetPantryItemError, InsertPantryItemError,
+        UpdatePantryItemError,
+    },
+    PantryRepository,
+};
+
+pub struct PostgresPantryRepository(pub PgPool);
+
+#[async_trait]
+impl PantryRepository for PostgresPantryRepository {
+    #[tracing::instrument("[PANTRY REPOSITORY] [POSTGRES] Insert a new pantry item", skip(self))]
+    async fn insert(&self, input: PantryItem) -> Result<PantryItem, InsertPantryItemError> {
+        let amount = serde_json::to_value(&input.amount)
+            .map_err(|e| InsertPantryItemError::UnknownError(e.into()))?;
+
+        sqlx::query_file!(
+            "queries/pantry/insert_pantry_item.sql",
+            input.owner_id,
+            input.ingredient.id,
+            amount,
+            input.expires_on
+        )
+        .execute(&self.0)
+        .await
+        .map_err(|e| match e {
+            SQLXError::Database(dberror) if dberror.is_unique_violation() => {
+                InsertPantryItemError::Conflict(input.ingredient.id)
+            }
+            _ => InsertPantryItemError::UnknownError(e.into()),
+        })?;
+
+        Ok(input)
+    }
+
+    #[tracing::instrument("[PANTRY REPOSITORY] [POSTGRES] Get a pantry item", skip(self))]
+    async fn get(
+        &self,
+        owner_id: &Uuid,
+        ingredient_id: &Uuid,
+    ) -> Result<PantryItem, GetPantryItemError> {
+        let item = sqlx::query_file_as!(
+            PantryItemModel,
+            "queries/pantry/get_pantry_item.sql",
+            owner_id,
+            ingredient_id
+        )
+        .fetch_one(&self.0)
+        .await
+        .map_err(|e| match e {
+            SQLXError::RowNotFound => GetPantryItemError::NotFound(*ingredient_id),
+            _ => GetPantryItemError::UnknownError(e.into()),
+        })?;
+
+        Ok(item.try_into()?)
+    }
+
+    #[tracing::instrument("[PANTRY REPOSITORY] [POSTGRES] Get the pantry of a user", skip(self))]
+    async fn get_all_of_user(&self, owner_id: &Uuid) -> Result<Vec<PantryItem>, GetPantryError> {
+        let items = sqlx::query_file_as!(
+            PantryItemModel,
+            "queries/pantry/get_pantry_of_user.sql",
+            owner_id
+        )
+        .fetch_all(&self.0)
+        .await?;
+
+        Ok(items
+            .into_iter()
+            .map(PantryItem::try_from)
+            .collect::<Result<Vec<_>, _>>()?)
+    }
+
+    #[tracing::instrument("[PANTRY REPOSITORY] [POSTGRES] Update a pantry item", skip(self))]
+    async fn update(&self, input: PantryItem) -> Result<PantryItem, UpdatePantryItemError> {
+        let amount = serde_json::to_value(&input.amount)
+            .map_err(|e| UpdatePantryItemError::UnknownError(e.into()))?;
+
+        let result = sqlx::query_file!(
+            "queries/pantry/update_pantry_item.sql",
+            input.owner_id,
+            input.ingredient.id,
+            amount,
+            input.expires_on
+        )
+        .execute(&self.0)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(UpdatePantryItemError::NotFound(input.ingredient.id));
+        }
+
+        Ok(input)
+    }
+
+    #[tracing::instrument("[PANTRY REPOSITORY] [POSTGRES] Delete a pantry item", skip(self))]
+    async fn delete(&self, input: &PantryItem) -> Result<(), DeletePantryItemError> {
+        let result = sqlx::query_file!(
+            "queries/pantry/delete_pantry_item.sql",
+            input.owner_id,
+            input.ingredient.id
+        )
+        .execute(&self.0)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(DeletePantryItemError::NotFound(input.ingredient.id));
+        }
+
+        Ok(())
+    }
+}
+
+impl PostgresPantryRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self(pool)
+    }
+}
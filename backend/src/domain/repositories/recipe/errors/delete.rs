@@ -2,9 +2,13 @@ use eyre::eyre;
 use sqlx::Error as SQLXError;
 use std::sync::PoisonError;
 use thiserror::Error;
+use uuid::Uuid;
 
 #[derive(Error, Debug)]
 pub enum DeleteRecipeError {
+    #[error("The recipe with ID {0} has been changed in the meantime")]
+    Outdated(Uuid),
+
     #[error(transparent)]
     UnknownError(#[from] eyre::Error),
 }
@@ -2,11 +2,15 @@ use eyre::eyre;
 use sqlx::Error as SQLXError;
 use std::sync::PoisonError;
 use thiserror::Error;
+use uuid::Uuid;
 
 use crate::domain::repositories::recipe::errors::constraint_to_field;
 
 #[derive(Error, Debug)]
 pub enum AddIngredientIntoRecipeError {
+    #[error("The recipe with ID {0} has been changed in the meantime")]
+    Outdated(Uuid),
+
     #[error("The recipe with field {0} of the given value already exists")]
     Conflict(String),
 
@@ -2,11 +2,15 @@ use eyre::eyre;
 use sqlx::Error as SQLXError;
 use std::sync::PoisonError;
 use thiserror::Error;
+use uuid::Uuid;
 
 use crate::domain::entities::recipe::errors::ValidationError;
 
 #[derive(Error, Debug)]
 pub enum UpdateIngredientInRecipeError {
+    #[error("The recipe with ID {0} has been changed in the meantime")]
+    Outdated(Uuid),
+
     #[error(transparent)]
     ValidationError(ValidationError),
 
@@ -18,10 +18,14 @@ mod delete;
 mod get;
 mod ingredients;
 mod insert;
+mod revisions;
+mod search;
 mod update;
 
 pub use delete::*;
 pub use get::*;
 pub use ingredients::*;
 pub use insert::*;
+pub use revisions::*;
+pub use search::*;
 pub use update::*;
@@ -0,0 +1,44 @@
+use eyre::eyre;
+use sqlx::Error as SQLXError;
+use std::sync::PoisonError;
+use thiserror::Error;
+
+use crate::domain::entities::recipe::errors::ValidationError;
+
+use super::GetAllRecipesError;
+
+#[derive(Error, Debug)]
+pub enum SearchRecipesError {
+    #[error(transparent)]
+    ValidationError(#[from] ValidationError),
+
+    #[error(transparent)]
+    UnknownError(#[from] eyre::Error),
+}
+
+impl<T> From<PoisonError<T>> for SearchRecipesError {
+    fn from(_value: PoisonError<T>) -> Self {
+        eyre!("Recipe repository lock was poisoned during a previous access and can no longer be locked").into()
+    }
+}
+
+impl From<SQLXError> for SearchRecipesError {
+    fn from(e: SQLXError) -> Self {
+        Self::UnknownError(e.into())
+    }
+}
+
+impl From<serde_json::Error> for SearchRecipesError {
+    fn from(e: serde_json::Error) -> Self {
+        Self::UnknownError(e.into())
+    }
+}
+
+impl From<GetAllRecipesError> for SearchRecipesError {
+    fn from(value: GetAllRecipesError) -> Self {
+        match value {
+            GetAllRecipesError::ValidationError(e) => Self::ValidationError(e),
+            GetAllRecipesError::UnknownError(e) => Self::UnknownError(e),
+        }
+    }
+}
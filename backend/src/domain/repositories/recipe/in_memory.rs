@@ -4,6 +4,7 @@ use eyre::eyre;
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 
 use std::{
+    cmp::Reverse,
     collections::HashMap,
     sync::{Arc, Mutex},
 };
@@ -12,19 +13,52 @@ use uuid::Uuid;
 use crate::domain::{
     entities::{
         ingredient::Ingredient,
-        recipe::{IngredientUnit, IngredientWithAmount, Recipe, RecipeChangeset},
+        outbox::OutboxMessage,
+        pagination::{Page, PageRequest},
+        recipe::{
+            revisions::{RecipeRevision, RevisionChange},
+            IngredientUnit, IngredientWithAmount, Recipe, RecipeChangeset, RecipeSearchQuery,
+            RecipeSort,
+        },
+    },
+    repositories::{
+        outbox::in_memory::InMemoryOutboxRepository, recipe::errors::InsertRecipeError,
     },
-    repositories::recipe::errors::InsertRecipeError,
 };
 
 use super::{
     errors::{
-        AddIngredientIntoRecipeError, DeleteIngredientFromRecipeError, DeleteRecipeError, GetAllRecipesError, GetRecipeByIdError, UpdateIngredientInRecipeError, UpdateRecipeError
+        AddIngredientIntoRecipeError, DeleteIngredientFromRecipeError, DeleteRecipeError,
+        GetAllRecipesError, GetRecipeByIdError, GetRecipeRevisionsError, RestoreRecipeError,
+        SearchRecipesError, UpdateIngredientInRecipeError, UpdateRecipeError,
     },
     RecipeRepository, RecipeRepositoryService,
 };
 
-pub struct InMemoryRecipeRepository(pub Mutex<HashMap<uuid::Uuid, Recipe>>);
+pub struct InMemoryRecipeRepository(
+    pub Mutex<HashMap<uuid::Uuid, Recipe>>,
+    pub InMemoryOutboxRepository,
+    pub Mutex<HashMap<uuid::Uuid, Vec<RecipeRevision>>>,
+);
+
+/// A naive stand-in for full-text search: every word of the query has to appear somewhere in the recipe.
+/// Returns how relevant the recipe is, weighing matches in the name higher than the ones in the steps.
+fn text_rank(recipe: &Recipe, text: &str) -> Option<usize> {
+    let name = recipe.name.to_lowercase();
+    let description = recipe.description.to_lowercase();
+    let steps = recipe.steps.as_ref().join(" ").to_lowercase();
+
+    text.split_whitespace()
+        .map(|word| word.to_lowercase())
+        .map(|word| {
+            let rank = 3 * name.matches(&word).count()
+                + 2 * description.matches(&word).count()
+                + steps.matches(&word).count();
+
+            (rank > 0).then_some(rank)
+        })
+        .sum()
+}
 
 #[async_trait]
 impl RecipeRepository for InMemoryRecipeRepository {
@@ -37,6 +71,10 @@ impl RecipeRepository for InMemoryRecipeRepository {
         };
 
         lock.insert(input.id, input.clone());
+        self.1.push(OutboxMessage::recipe_created(&input))?;
+        self.2
+            .lock()?
+            .insert(input.id, vec![RecipeRevision::initial(&input)]);
 
         Ok(())
     }
@@ -55,22 +93,68 @@ impl RecipeRepository for InMemoryRecipeRepository {
     async fn get_all(&self) -> Result<Vec<Recipe>, GetAllRecipesError> {
         let lock = self.0.lock()?;
 
-        let result = lock
+        let result = lock.values().cloned().collect::<Vec<_>>();
+
+        Ok(result)
+    }
+
+    async fn get_page(
+        &self,
+        request: &PageRequest<RecipeSort>,
+    ) -> Result<Page<Recipe>, GetAllRecipesError> {
+        let lock = self.0.lock()?;
+
+        let mut result = lock.values().cloned().collect::<Vec<_>>();
+
+        match request.sort {
+            RecipeSort::Name => result.sort_by(|a, b| a.name.cmp(&b.name).then(a.id.cmp(&b.id))),
+            RecipeSort::CreatedAt => result.sort_by_key(|x| Reverse(x.id)),
+            RecipeSort::UpdatedAt => {
+                result.sort_by(|a, b| b.updated_at.cmp(&a.updated_at).then(b.id.cmp(&a.id)))
+            }
+        }
+
+        Ok(Page::from_sorted(result, request, |recipe| recipe.id))
+    }
+
+    async fn search(&self, query: &RecipeSearchQuery) -> Result<Vec<Recipe>, SearchRecipesError> {
+        let lock = self.0.lock()?;
+
+        let mut result = lock
             .values()
-            .cloned()
+            .filter(|recipe| query.filters_match(recipe))
+            .filter_map(|recipe| match &query.text {
+                Some(text) => text_rank(recipe, text).map(|rank| (rank, recipe)),
+                None => Some((0, recipe)),
+            })
             .collect::<Vec<_>>();
 
-        Ok(result)
+        result.sort_by(|(a_rank, a), (b_rank, b)| b_rank.cmp(a_rank).then(b.id.cmp(&a.id)));
+
+        Ok(result
+            .into_iter()
+            .take(query.limit as usize)
+            .map(|(_, recipe)| recipe.clone())
+            .collect())
     }
 
     async fn delete(&self, recipe: &Recipe) -> Result<(), DeleteRecipeError> {
         let id = &recipe.id;
         let mut lock = self.0.lock()?;
 
+        if lock
+            .get(id)
+            .is_some_and(|stored| stored.updated_at != recipe.updated_at)
+        {
+            return Err(DeleteRecipeError::Outdated(*id));
+        }
+
         lock.remove(id)
             .ok_or(DeleteRecipeError::UnknownError(eyre!(
                 "The recipe could not be found somehow"
             )))?;
+        self.1.push(OutboxMessage::recipe_deleted(recipe))?;
+        self.2.lock()?.remove(id);
 
         Ok(())
     }
@@ -79,7 +163,9 @@ impl RecipeRepository for InMemoryRecipeRepository {
         &self,
         recipe: &Recipe,
         changeset: RecipeChangeset,
+        author_id: &Uuid,
     ) -> Result<(), UpdateRecipeError> {
+        let old_recipe = recipe;
         let mut lock = self.0.lock()?;
         let recipe = lock
             .get_mut(&recipe.id)
@@ -87,6 +173,10 @@ impl RecipeRepository for InMemoryRecipeRepository {
                 "The recipe could not be found somehow"
             )))?;
 
+        if recipe.updated_at != old_recipe.updated_at {
+            return Err(UpdateRecipeError::Outdated(recipe.id));
+        }
+
         let mut updated = false;
 
         if let Some(v) = changeset.name {
@@ -118,6 +208,13 @@ impl RecipeRepository for InMemoryRecipeRepository {
             recipe.updated_at = Utc::now();
         };
 
+        self.1
+            .push(OutboxMessage::recipe_updated(old_recipe, recipe))?;
+
+        if let Some(change) = RevisionChange::updated(old_recipe, recipe) {
+            self.record_revision(old_recipe, recipe, change, author_id)?;
+        }
+
         Ok(())
     }
 
@@ -125,7 +222,9 @@ impl RecipeRepository for InMemoryRecipeRepository {
         &self,
         recipe: &Recipe,
         ingredient: IngredientWithAmount,
+        author_id: &Uuid,
     ) -> Result<(), AddIngredientIntoRecipeError> {
+        let old_recipe = recipe;
         let mut lock = self.0.lock()?;
         let recipe = lock
             .get_mut(&recipe.id)
@@ -133,9 +232,23 @@ impl RecipeRepository for InMemoryRecipeRepository {
                 "Recipe is not in the repo"
             )))?;
 
+        if recipe.updated_at != old_recipe.updated_at {
+            return Err(AddIngredientIntoRecipeError::Outdated(recipe.id));
+        }
+
+        self.1
+            .push(OutboxMessage::recipe_ingredient_added(recipe, &ingredient))?;
+        let ingredient_id = ingredient.ingredient.id;
         recipe.ingredients.push(ingredient);
         recipe.updated_at = Utc::now();
 
+        self.record_revision(
+            old_recipe,
+            recipe,
+            RevisionChange::IngredientAdded { ingredient_id },
+            author_id,
+        )?;
+
         Ok(())
     }
 
@@ -143,7 +256,9 @@ impl RecipeRepository for InMemoryRecipeRepository {
         &self,
         recipe: &Recipe,
         ingredient: &IngredientWithAmount,
+        author_id: &Uuid,
     ) -> Result<(), DeleteIngredientFromRecipeError> {
+        let old_recipe = recipe;
         let mut lock = self.0.lock()?;
         let recipe =
             lock.get_mut(&recipe.id)
@@ -151,6 +266,10 @@ impl RecipeRepository for InMemoryRecipeRepository {
                     "Recipe is not in the repo somehow"
                 )))?;
 
+        if recipe.updated_at != old_recipe.updated_at {
+            return Err(DeleteIngredientFromRecipeError::Outdated(recipe.id));
+        }
+
         let new_ingredients: Vec<_> = recipe
             .ingredients
             .iter()
@@ -163,6 +282,17 @@ impl RecipeRepository for InMemoryRecipeRepository {
             .map_err(DeleteIngredientFromRecipeError::ValidationError)?;
         recipe.updated_at = Utc::now();
 
+        self.1
+            .push(OutboxMessage::recipe_ingredient_removed(recipe, ingredient))?;
+        self.record_revision(
+            old_recipe,
+            recipe,
+            RevisionChange::IngredientRemoved {
+                ingredient_id: ingredient.ingredient.id,
+            },
+            author_id,
+        )?;
+
         Ok(())
     }
 
@@ -171,7 +301,9 @@ impl RecipeRepository for InMemoryRecipeRepository {
         recipe: &Recipe,
         ingredient: &IngredientWithAmount,
         new_amount: &IngredientUnit,
+        author_id: &Uuid,
     ) -> Result<(), UpdateIngredientInRecipeError> {
+        let old_recipe = recipe;
         let mut lock = self.0.lock()?;
         let recipe =
             lock.get_mut(&recipe.id)
@@ -179,6 +311,10 @@ impl RecipeRepository for InMemoryRecipeRepository {
                     "Recipe is not in the repo somehow"
                 )))?;
 
+        if recipe.updated_at != old_recipe.updated_at {
+            return Err(UpdateIngredientInRecipeError::Outdated(recipe.id));
+        }
+
         let ingredient = recipe
             .ingredients
             .iter_mut()
@@ -187,12 +323,76 @@ impl RecipeRepository for InMemoryRecipeRepository {
                 "Ingredient somehow is not in the recipe, but the command made sure there was."
             )))?;
 
+        let old_ingredient = ingredient.clone();
         ingredient.amount = new_amount.clone();
+        let new_ingredient = ingredient.clone();
         recipe.updated_at = Utc::now();
 
+        self.1
+            .push(OutboxMessage::recipe_ingredient_amount_changed(
+                recipe,
+                &old_ingredient,
+                &new_ingredient,
+            ))?;
+        self.record_revision(
+            old_recipe,
+            recipe,
+            RevisionChange::IngredientAmountChanged {
+                ingredient_id: new_ingredient.ingredient.id,
+            },
+            author_id,
+        )?;
+
+        Ok(())
+    }
+
+    async fn restore(
+        &self,
+        recipe: &Recipe,
+        restored: Recipe,
+        revision: i32,
+        author_id: &Uuid,
+    ) -> Result<(), RestoreRecipeError> {
+        let mut lock = self.0.lock()?;
+        let current = lock
+            .get_mut(&recipe.id)
+            .ok_or(RestoreRecipeError::UnknownError(eyre!(
+                "Recipe is not in the repo somehow"
+            )))?;
+
+        if current.updated_at != recipe.updated_at {
+            return Err(RestoreRecipeError::Outdated(recipe.id));
+        }
+
+        *current = Recipe {
+            id: recipe.id,
+            owner_id: current.owner_id,
+            created_at: current.created_at,
+            updated_at: Utc::now(),
+            ..restored
+        };
+
+        self.1
+            .push(OutboxMessage::recipe_updated(recipe, current))?;
+        self.record_revision(
+            recipe,
+            current,
+            RevisionChange::Reverted { revision },
+            author_id,
+        )?;
+
         Ok(())
     }
 
+    async fn get_revisions(
+        &self,
+        recipe_id: &Uuid,
+    ) -> Result<Vec<RecipeRevision>, GetRecipeRevisionsError> {
+        let lock = self.2.lock()?;
+
+        Ok(lock.get(recipe_id).cloned().unwrap_or_default())
+    }
+
     async fn recipes_containing_ingredient_exist(
         &self,
         ingredient: Ingredient,
@@ -206,6 +406,31 @@ impl RecipeRepository for InMemoryRecipeRepository {
 
         Ok(some_recipe_with_ingredient.is_some())
     }
+
+    async fn refresh_ingredient(&self, ingredient: &Ingredient) -> eyre::Result<u64> {
+        let mut lock = self.0.lock().map_err(|_| eyre!("Poison issue"))?;
+        let mut refreshed = 0;
+
+        // Unlike in Postgres, the recipes here hold their own copies of the ingredients
+        for recipe in lock.values_mut() {
+            let mut uses_ingredient = false;
+
+            for i in recipe
+                .ingredients
+                .iter_mut()
+                .filter(|i| i.ingredient.id == ingredient.id)
+            {
+                i.ingredient = ingredient.clone();
+                uses_ingredient = true;
+            }
+
+            if uses_ingredient {
+                refreshed += 1;
+            }
+        }
+
+        Ok(refreshed)
+    }
 }
 
 impl Default for InMemoryRecipeRepository {
@@ -216,16 +441,48 @@ impl Default for InMemoryRecipeRepository {
 
 impl InMemoryRecipeRepository {
     pub fn new() -> Self {
-        Self(Mutex::new(HashMap::new()))
+        Self::from(HashMap::new())
     }
 
     pub fn service(self) -> RecipeRepositoryService {
         Arc::new(Box::new(self))
     }
+
+    /// Stores the revisions for a change of the recipe from `before` to `after`.
+    fn record_revision(
+        &self,
+        before: &Recipe,
+        after: &Recipe,
+        change: RevisionChange,
+        author_id: &Uuid,
+    ) -> eyre::Result<()> {
+        let mut lock = self
+            .2
+            .lock()
+            .map_err(|_| eyre!("Recipe repository lock was poisoned"))?;
+        let revisions = lock.entry(after.id).or_default();
+
+        let latest = revisions.last().map(|r| r.number);
+        revisions.extend(RecipeRevision::for_change(
+            latest, before, after, change, author_id,
+        ));
+
+        Ok(())
+    }
+
+    /// Writes the messages about the changes into the given outbox, instead of one of its own.
+    pub fn with_outbox(mut self, outbox: InMemoryOutboxRepository) -> Self {
+        self.1 = outbox;
+        self
+    }
 }
 
 impl From<HashMap<uuid::Uuid, Recipe>> for InMemoryRecipeRepository {
     fn from(value: HashMap<uuid::Uuid, Recipe>) -> Self {
-        Self(Mutex::new(value))
+        Self(
+            Mutex::new(value),
+            InMemoryOutboxRepository::new(),
+            Mutex::new(HashMap::new()),
+        )
     }
 }
@@ -4,7 +4,11 @@ pub mod postgres;
 
 use crate::domain::entities::{
     ingredient::Ingredient,
-    recipe::{IngredientUnit, IngredientWithAmount, Recipe, RecipeChangeset},
+    pagination::{Page, PageRequest},
+    recipe::{
+        revisions::RecipeRevision, IngredientUnit, IngredientWithAmount, Recipe, RecipeChangeset,
+        RecipeSearchQuery, RecipeSort,
+    },
 };
 use async_trait::async_trait;
 use errors::{AddIngredientIntoRecipeError, GetAllRecipesError};
@@ -12,49 +16,88 @@ use std::sync::Arc;
 use uuid::Uuid;
 
 use self::errors::{
-    DeleteIngredientFromRecipeError, DeleteRecipeError, GetRecipeByIdError, InsertRecipeError,
+    DeleteIngredientFromRecipeError, DeleteRecipeError, GetRecipeByIdError,
+    GetRecipeRevisionsError, InsertRecipeError, RestoreRecipeError, SearchRecipesError,
     UpdateIngredientInRecipeError, UpdateRecipeError,
 };
 
 #[async_trait]
 pub trait RecipeRepository: Send + Sync + 'static {
-    // TODO: Include user information
+    /// Stores the recipe along with its first revision, authored by its owner.
     async fn insert(&self, input: Recipe) -> Result<(), InsertRecipeError>;
 
     async fn get_by_id(&self, id: &Uuid) -> Result<Recipe, GetRecipeByIdError>;
     async fn get_all(&self) -> Result<Vec<Recipe>, GetAllRecipesError>;
+    async fn get_page(
+        &self,
+        request: &PageRequest<RecipeSort>,
+    ) -> Result<Page<Recipe>, GetAllRecipesError>;
+    /// Returns the recipes matching the query, the most relevant ones first.
+    async fn search(&self, query: &RecipeSearchQuery) -> Result<Vec<Recipe>, SearchRecipesError>;
 
+    /// Fails with `Outdated` when the recipe has been changed since the given one was fetched.
     async fn delete(&self, recipe: &Recipe) -> Result<(), DeleteRecipeError>;
 
+    // Every change below is recorded as a new revision of the recipe, made by the given author.
+
+    /// Fails with `Outdated` when the recipe has been changed since the given one was fetched.
     async fn update(
         &self,
         recipe: &Recipe,
         changeset: RecipeChangeset,
+        author_id: &Uuid,
     ) -> Result<(), UpdateRecipeError>;
 
+    /// Fails with `Outdated` when the recipe has been changed since the given one was fetched.
     async fn add_ingredient(
         &self,
         recipe: &Recipe,
         ingredient: IngredientWithAmount,
+        author_id: &Uuid,
     ) -> Result<(), AddIngredientIntoRecipeError>;
 
+    /// Fails with `Outdated` when the recipe has been changed since the given one was fetched.
     async fn delete_ingredient(
         &self,
         recipe: &Recipe,
         ingredient: &IngredientWithAmount,
+        author_id: &Uuid,
     ) -> Result<(), DeleteIngredientFromRecipeError>;
 
+    /// Fails with `Outdated` when the recipe has been changed since the given one was fetched.
     async fn update_ingredient_amount(
         &self,
         recipe: &Recipe,
         ingredient: &IngredientWithAmount,
         new_amount: &IngredientUnit,
+        author_id: &Uuid,
     ) -> Result<(), UpdateIngredientInRecipeError>;
 
+    /// Replaces the contents of the recipe with the ones it had in the given revision.
+    /// Fails with `Outdated` when the recipe has been changed since the given one was fetched.
+    async fn restore(
+        &self,
+        recipe: &Recipe,
+        restored: Recipe,
+        revision: i32,
+        author_id: &Uuid,
+    ) -> Result<(), RestoreRecipeError>;
+
+    /// Returns the revisions of the recipe, the oldest one first.
+    /// Recipes which haven't changed since before revisions were tracked have none.
+    async fn get_revisions(
+        &self,
+        recipe_id: &Uuid,
+    ) -> Result<Vec<RecipeRevision>, GetRecipeRevisionsError>;
+
     async fn recipes_containing_ingredient_exist(
         &self,
         ingredient: Ingredient,
     ) -> eyre::Result<bool>;
+
+    /// Brings the recipes using the ingredient up to date after it has changed.
+    /// Leaves their `updated_at`, and so their version, alone. Returns how many recipes were refreshed.
+    async fn refresh_ingredient(&self, ingredient: &Ingredient) -> eyre::Result<u64>;
 }
 
 pub type RecipeRepositoryService = Arc<Box<dyn RecipeRepository>>;
@@ -2,32 +2,125 @@ use std::collections::BTreeMap;
 use std::sync::Arc;
 
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use eyre::eyre;
-use futures::future::{join_all, try_join_all};
+use futures::future::try_join_all;
 use itertools::Itertools;
+#[allow(unused_imports)]
 use rayon::iter::{IntoParallelIterator, ParallelBridge, ParallelIterator};
-use sqlx::PgPool;
+use sqlx::{PgConnection, PgExecutor, PgPool};
 use uuid::Uuid;
 
 use crate::domain::entities::ingredient::{Ingredient, IngredientModel};
+use crate::domain::entities::outbox::OutboxMessage;
+use crate::domain::entities::pagination::{Page, PageRequest};
 use crate::domain::entities::recipe::{
+    revisions::{RecipeRevision, RevisionChange},
     IngredientUnit, IngredientWithAmount, IngredientWithAmountModel, Recipe, RecipeChangeset,
+    RecipeSearchQuery, RecipeSort,
 };
 
 use super::errors::{
     AddIngredientIntoRecipeError, DeleteIngredientFromRecipeError, DeleteRecipeError,
-    GetAllRecipesError, UpdateIngredientInRecipeError, UpdateRecipeError,
+    GetAllRecipesError, GetRecipeRevisionsError, RestoreRecipeError, SearchRecipesError,
+    UpdateIngredientInRecipeError, UpdateRecipeError,
 };
 use super::RecipeRepositoryService;
 use super::{
     errors::{GetRecipeByIdError, InsertRecipeError},
     RecipeRepository,
 };
+use crate::domain::repositories::outbox::postgres::insert_message;
 
 pub struct PostgresRecipeRepository(pub PgPool);
 
+struct RecipeModel {
+    id: Uuid,
+    name: String,
+    description: String,
+    steps: Vec<String>,
+    time: serde_json::Value,
+    servings: serde_json::Value,
+    owner_id: Option<Uuid>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+struct RecipeRevisionModel {
+    recipe_id: Uuid,
+    number: i32,
+    author_id: Option<Uuid>,
+    created_at: DateTime<Utc>,
+    change: serde_json::Value,
+    snapshot: serde_json::Value,
+}
+
+impl TryFrom<RecipeRevisionModel> for RecipeRevision {
+    type Error = serde_json::Error;
+    fn try_from(value: RecipeRevisionModel) -> Result<Self, Self::Error> {
+        Ok(Self {
+            recipe_id: value.recipe_id,
+            number: value.number,
+            author_id: value.author_id,
+            created_at: value.created_at,
+            change: serde_json::from_value(value.change)?,
+            recipe: serde_json::from_value(value.snapshot)?,
+        })
+    }
+}
+
+async fn insert_revision(
+    executor: impl PgExecutor<'_>,
+    revision: &RecipeRevision,
+) -> eyre::Result<()> {
+    sqlx::query_file!(
+        "queries/recipes/insert_revision.sql",
+        revision.recipe_id,
+        revision.number,
+        revision.author_id,
+        revision.created_at,
+        serde_json::to_value(&revision.change)?,
+        serde_json::to_value(&revision.recipe)?
+    )
+    .execute(executor)
+    .await?;
+
+    Ok(())
+}
+
+/// Stores the revisions for a change of the recipe from `before` to `after`, numbered after the latest stored one.
+/// The recipe has to be locked with [lock_recipe] first, or concurrent changes would take the same numbers.
+async fn record_revision(
+    conn: &mut PgConnection,
+    before: &Recipe,
+    after: &Recipe,
+    change: RevisionChange,
+    author_id: &Uuid,
+) -> eyre::Result<()> {
+    let latest =
+        sqlx::query_file_scalar!("queries/recipes/get_latest_revision_number.sql", after.id)
+            .fetch_one(&mut *conn)
+            .await?;
+
+    for revision in RecipeRevision::for_change(latest, before, after, change, author_id) {
+        insert_revision(&mut *conn, &revision).await?;
+    }
+
+    Ok(())
+}
+
+/// Locks the row of the recipe until the end of the transaction,
+/// and checks whether it hasn't changed since the given recipe was fetched.
+async fn lock_recipe(conn: &mut PgConnection, recipe: &Recipe) -> Result<bool, sqlx::Error> {
+    let updated_at = sqlx::query_file_scalar!("queries/recipes/lock_recipe.sql", recipe.id)
+        .fetch_optional(&mut *conn)
+        .await?;
+
+    Ok(updated_at == Some(recipe.updated_at))
+}
+
 async fn insert_ingredient(
-    pool: &PgPool,
+    executor: impl PgExecutor<'_>,
     id: Uuid,
     ingredient: &IngredientWithAmount,
 ) -> Result<(), AddIngredientIntoRecipeError> {
@@ -42,19 +135,122 @@ async fn insert_ingredient(
         ingredient.notes,
         ingredient.optional
     )
-    .execute(pool)
+    .execute(executor)
     .await
     .map_err(AddIngredientIntoRecipeError::from)?;
 
     Ok(())
 }
 
-async fn update_timestamps_in_recipe(pool: &PgPool, id: Uuid) {
+async fn update_timestamps_in_recipe(executor: impl PgExecutor<'_>, id: Uuid) {
     let _ = sqlx::query_file!("queries/recipes/update_recipe_timestamps.sql", id)
-        .execute(pool)
+        .execute(executor)
         .await;
 }
 
+/// Fetches a single recipe with its ingredients. Takes a connection, so it can also read from inside a transaction.
+async fn get_recipe(conn: &mut PgConnection, id: &Uuid) -> Result<Recipe, GetRecipeByIdError> {
+    let result = sqlx::query_file!("queries/recipes/get_recipe.sql", id)
+        .fetch_one(&mut *conn)
+        .await
+        .map_err(|e| GetRecipeByIdError::with_id(id, e))?;
+
+    let result_ingredients = sqlx::query_file_as!(
+        IngredientWithAmountModel,
+        "queries/recipes/get_ingredients_for_recipe.sql",
+        id
+    )
+    .fetch_all(&mut *conn)
+    .await?;
+
+    let ingredients = result_ingredients
+        .iter()
+        .map(IngredientWithAmount::try_from)
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(GetRecipeByIdError::from)?;
+
+    let time = serde_json::from_value(result.time)?;
+
+    let servings = serde_json::from_value(result.servings)?;
+
+    let recipe = Recipe {
+        id: result.id,
+        name: result.name,
+        description: result.description,
+        steps: result.steps.try_into()?,
+        time,
+        servings,
+        ingredients: ingredients.try_into()?,
+        owner_id: result.owner_id,
+        created_at: result.created_at,
+        updated_at: result.updated_at,
+    };
+
+    Ok(recipe)
+}
+
+/// Fetches the ingredients for the given recipe rows and puts the full recipes together.
+async fn hydrate_recipes(
+    pool: &PgPool,
+    result: Vec<RecipeModel>,
+) -> Result<Vec<Recipe>, GetAllRecipesError> {
+    let recipe_ids: Vec<Uuid> = result.iter().map(|recipe| recipe.id).collect();
+
+    tracing::info!("Fetching all ingredients for fetched recipes");
+    let ingredients_for_recipes = sqlx::query_file_as!(
+        IngredientWithAmountModel,
+        "queries/recipes/get_ingredients_for_many_recipes.sql",
+        &recipe_ids
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(|e| GetAllRecipesError::UnknownError(e.into()))?;
+
+    let data_grouped = ingredients_for_recipes
+        .into_iter()
+        .chunk_by(|elt| elt.recipe_id)
+        .into_iter()
+        .fold(BTreeMap::new(), |mut acc, (key, chunk)| {
+            acc.insert(key, chunk.collect::<Vec<_>>());
+            acc
+        });
+
+    let recipes_ft: Vec<_> = result
+        .into_par_iter()
+        .map(async |recipe| {
+            let ingredients = data_grouped
+                .get(&recipe.id)
+                .ok_or_else(|| eyre!("could not find recipes for this recipe id"))?
+                .iter()
+                .map(IngredientWithAmount::try_from)
+                .collect::<Result<Vec<_>, _>>()?;
+
+            let time = serde_json::from_value(recipe.time)?;
+
+            let servings = serde_json::from_value(recipe.servings)?;
+
+            let recipe = Recipe {
+                id: recipe.id,
+                name: recipe.name,
+                description: recipe.description,
+                steps: recipe.steps.try_into()?,
+                time,
+                servings,
+                ingredients: ingredients.try_into()?,
+                owner_id: recipe.owner_id,
+                created_at: recipe.created_at,
+                updated_at: recipe.updated_at,
+            };
+
+            Ok::<Recipe, GetAllRecipesError>(recipe)
+        })
+        .collect();
+
+    let recipes = try_join_all(recipes_ft).await?;
+
+    Ok(recipes)
+}
+
 #[async_trait]
 impl RecipeRepository for PostgresRecipeRepository {
     #[tracing::instrument("[RECIPE REPOSITORY] [POSTGRES] Insert new recipe", skip(self))]
@@ -65,7 +261,7 @@ impl RecipeRepository for PostgresRecipeRepository {
         let servings = serde_json::to_value(&input.servings)
             .map_err(|e| InsertRecipeError::UnknownError(e.into()))?;
 
-        let tx = self.0.begin().await.map_err(InsertRecipeError::from)?;
+        let mut tx = self.0.begin().await.map_err(InsertRecipeError::from)?;
 
         let result = sqlx::query_file!(
             "queries/recipes/insert_recipe.sql",
@@ -75,21 +271,21 @@ impl RecipeRepository for PostgresRecipeRepository {
             &input.steps.as_ref(),
             time,
             servings,
-            serde_json::json!({})
+            serde_json::json!({}),
+            input.owner_id
         )
-        .fetch_one(&self.0)
+        .fetch_one(&mut *tx)
         .await
         .map_err(InsertRecipeError::from)?;
 
-        join_all(
-            input
-                .ingredients
-                .iter()
-                .map(|i| insert_ingredient(&self.0, result.id, i)),
-        )
-        .await
-        .into_iter()
-        .collect::<Result<Vec<()>, AddIngredientIntoRecipeError>>()?;
+        for ingredient in input.ingredients.iter() {
+            insert_ingredient(&mut *tx, result.id, ingredient).await?;
+        }
+
+        insert_message(&mut *tx, &OutboxMessage::recipe_created(&input))
+            .await
+            .map_err(InsertRecipeError::from)?;
+        insert_revision(&mut *tx, &RecipeRevision::initial(&input)).await?;
 
         tx.commit().await.map_err(InsertRecipeError::from)?;
 
@@ -98,123 +294,129 @@ impl RecipeRepository for PostgresRecipeRepository {
 
     #[tracing::instrument("[RECIPE REPOSITORY] [POSTGRES] Get recipe by ID", skip(self))]
     async fn get_by_id(&self, id: &Uuid) -> Result<Recipe, GetRecipeByIdError> {
-        let result = sqlx::query_file!("queries/recipes/get_recipe.sql", id)
-            .fetch_one(&self.0)
-            .await
-            .map_err(|e| GetRecipeByIdError::with_id(id, e))?;
-
-        let result_ingredients = sqlx::query_file_as!(
-            IngredientWithAmountModel,
-            "queries/recipes/get_ingredients_for_recipe.sql",
-            id
-        )
-        .fetch_all(&self.0)
-        .await?;
+        let mut conn = self.0.acquire().await?;
 
-        let ingredients = result_ingredients
-            .iter()
-            .map(IngredientWithAmount::try_from)
-            .collect::<Result<Vec<_>, _>>()
-            .map_err(GetRecipeByIdError::from)?;
-
-        let time = serde_json::from_value(result.time)?;
-
-        let servings = serde_json::from_value(result.servings)?;
-
-        let recipe = Recipe {
-            id: result.id,
-            name: result.name,
-            description: result.description,
-            steps: result.steps.try_into()?,
-            time,
-            servings,
-            ingredients: ingredients.try_into()?,
-            created_at: result.created_at,
-            updated_at: result.updated_at,
-        };
-
-        Ok(recipe)
+        get_recipe(&mut conn, id).await
     }
 
     #[tracing::instrument("[RECIPE REPOSITORY] [POSTGRES] Get all recipes", skip(self))]
     async fn get_all(&self) -> Result<Vec<Recipe>, GetAllRecipesError> {
         tracing::info!("Fetching all recipes");
-        let result = sqlx::query_file!("queries/recipes/get_all_recipes.sql")
+        let result = sqlx::query_file_as!(RecipeModel, "queries/recipes/get_all_recipes.sql")
             .fetch_all(&self.0)
             .await
             .map_err(|e| GetAllRecipesError::UnknownError(e.into()))?;
 
-        let recipe_ids: Vec<Uuid> = result.iter().map(|recipe| recipe.id).collect();
+        hydrate_recipes(&self.0, result).await
+    }
 
-        tracing::info!("Fetching all ingredients for fetched recipes");
-        let ingredients_for_recipes = sqlx::query_file_as!(
-            IngredientWithAmountModel,
-            "queries/recipes/get_ingredients_for_many_recipes.sql",
-            &recipe_ids
-        )
-        .fetch_all(&self.0)
-        .await
-        .map_err(|e| GetAllRecipesError::UnknownError(e.into()))?;
+    #[tracing::instrument("[RECIPE REPOSITORY] [POSTGRES] Get a page of recipes", skip(self))]
+    async fn get_page(
+        &self,
+        request: &PageRequest<RecipeSort>,
+    ) -> Result<Page<Recipe>, GetAllRecipesError> {
+        let limit = i64::from(request.limit) + 1;
+
+        let result = match request.sort {
+            RecipeSort::Name => {
+                sqlx::query_file_as!(
+                    RecipeModel,
+                    "queries/recipes/get_recipes_page_by_name.sql",
+                    request.cursor,
+                    limit
+                )
+                .fetch_all(&self.0)
+                .await?
+            }
+            RecipeSort::CreatedAt => {
+                sqlx::query_file_as!(
+                    RecipeModel,
+                    "queries/recipes/get_recipes_page_by_created_at.sql",
+                    request.cursor,
+                    limit
+                )
+                .fetch_all(&self.0)
+                .await?
+            }
+            RecipeSort::UpdatedAt => {
+                sqlx::query_file_as!(
+                    RecipeModel,
+                    "queries/recipes/get_recipes_page_by_updated_at.sql",
+                    request.cursor,
+                    limit
+                )
+                .fetch_all(&self.0)
+                .await?
+            }
+        };
 
-        let data_grouped = ingredients_for_recipes
-            .into_iter()
-            .chunk_by(|elt| elt.recipe_id)
-            .into_iter()
-            .fold(BTreeMap::new(), |mut acc, (key, chunk)| {
-                acc.insert(key, chunk.collect::<Vec<_>>());
-                acc
-            });
-
-        let recipes_ft: Vec<_> = result
-            .into_par_iter()
-            .map(async |recipe| {
-                let ingredients = data_grouped
-                    .get(&recipe.id)
-                    .ok_or_else(|| eyre!("could not find recipes for this recipe id"))?
-                    .iter()
-                    .map(IngredientWithAmount::try_from)
-                    .collect::<Result<Vec<_>, _>>()?;
-
-                let time = serde_json::from_value(recipe.time)?;
-
-                let servings = serde_json::from_value(recipe.servings)?;
-
-                let recipe = Recipe {
-                    id: recipe.id,
-                    name: recipe.name,
-                    description: recipe.description,
-                    steps: recipe.steps.try_into()?,
-                    time,
-                    servings,
-                    ingredients: ingredients.try_into()?,
-                    created_at: recipe.created_at,
-                    updated_at: recipe.updated_at,
-                };
-
-                Ok::<Recipe, GetAllRecipesError>(recipe)
-            })
+        let recipes = hydrate_recipes(&self.0, result).await?;
+
+        Ok(Page::from_overfetched(recipes, request.limit, |recipe| {
+            recipe.id
+        }))
+    }
+
+    #[tracing::instrument("[RECIPE REPOSITORY] [POSTGRES] Search recipes", skip(self))]
+    async fn search(&self, query: &RecipeSearchQuery) -> Result<Vec<Recipe>, SearchRecipesError> {
+        let diets: Vec<String> = query.diets.iter().map(|d| d.to_string()).collect();
+        let free_from: Vec<String> = query.free_from.iter().map(|a| a.to_string()).collect();
+        let max_total_time = query
+            .max_total_time
+            .map(|t| i64::try_from(t.as_secs()).unwrap_or(i64::MAX));
+
+        // Pairs of an ingredient matching an included one and the included one itself
+        let (including, including_roots): (Vec<Uuid>, Vec<Uuid>) = query
+            .including_ingredients
+            .iter()
+            .flat_map(|root| query.matching_ingredients(root).map(|id| (*id, *root)))
+            .unzip();
+        let excluding: Vec<Uuid> = query
+            .excluding_ingredients
+            .iter()
+            .flat_map(|id| query.matching_ingredients(id))
+            .cloned()
             .collect();
 
-        let recipes = try_join_all(recipes_ft).await?;
+        let result = sqlx::query_file_as!(
+            RecipeModel,
+            "queries/recipes/search_recipes.sql",
+            query.text,
+            &including,
+            &excluding,
+            &diets,
+            max_total_time,
+            &including_roots,
+            &free_from,
+            i64::from(query.limit)
+        )
+        .fetch_all(&self.0)
+        .await?;
 
-        Ok(recipes)
+        Ok(hydrate_recipes(&self.0, result).await?)
     }
 
     #[tracing::instrument("[RECIPE REPOSITORY] [POSTGRES] Delete recipe", skip(self))]
     async fn delete(&self, recipe: &Recipe) -> Result<(), DeleteRecipeError> {
-        let tx = self.0.begin().await?;
+        let mut tx = self.0.begin().await?;
+
+        if !lock_recipe(&mut tx, recipe).await? {
+            return Err(DeleteRecipeError::Outdated(recipe.id));
+        }
 
         sqlx::query_file!(
             "queries/recipes/delete_ingredients_for_recipe.sql",
             recipe.id
         )
-        .execute(&self.0)
+        .execute(&mut *tx)
         .await?;
 
         sqlx::query_file!("queries/recipes/delete_recipe.sql", recipe.id)
-            .execute(&self.0)
+            .execute(&mut *tx)
             .await?;
 
+        insert_message(&mut *tx, &OutboxMessage::recipe_deleted(recipe)).await?;
+
         tx.commit().await?;
 
         Ok(())
@@ -225,11 +427,20 @@ impl RecipeRepository for PostgresRecipeRepository {
         &self,
         recipe: &Recipe,
         changeset: RecipeChangeset,
+        author_id: &Uuid,
     ) -> Result<(), UpdateRecipeError> {
         let id = &recipe.id;
-        let tx = self.0.begin().await?;
+        let mut tx = self.0.begin().await?;
         let mut updated = false;
 
+        if !lock_recipe(&mut tx, recipe).await? {
+            return Err(UpdateRecipeError::Outdated(recipe.id));
+        }
+        // Read under the lock, so the revision starts from what's actually stored
+        let before = get_recipe(&mut tx, &recipe.id)
+            .await
+            .map_err(|e| UpdateRecipeError::UnknownError(e.into()))?;
+
         if let Some(value) = changeset.name {
             if value != recipe.name {
                 sqlx::query!(
@@ -241,7 +452,7 @@ impl RecipeRepository for PostgresRecipeRepository {
                     id,
                     value
                 )
-                .execute(&self.0)
+                .execute(&mut *tx)
                 .await?;
                 updated = true;
             }
@@ -258,7 +469,7 @@ impl RecipeRepository for PostgresRecipeRepository {
                     id,
                     value
                 )
-                .execute(&self.0)
+                .execute(&mut *tx)
                 .await?;
                 updated = true;
             }
@@ -278,7 +489,7 @@ impl RecipeRepository for PostgresRecipeRepository {
                     id,
                     value
                 )
-                .execute(&self.0)
+                .execute(&mut *tx)
                 .await?;
                 updated = true;
             }
@@ -298,7 +509,7 @@ impl RecipeRepository for PostgresRecipeRepository {
                     id,
                     value
                 )
-                .execute(&self.0)
+                .execute(&mut *tx)
                 .await?;
                 updated = true;
             }
@@ -317,14 +528,27 @@ impl RecipeRepository for PostgresRecipeRepository {
                     id,
                     value
                 )
-                .execute(&self.0)
+                .execute(&mut *tx)
                 .await?;
                 updated = true;
             }
         }
 
         if updated {
-            update_timestamps_in_recipe(&self.0, *id).await;
+            update_timestamps_in_recipe(&mut *tx, *id).await;
+        }
+
+        let updated_recipe = get_recipe(&mut tx, id)
+            .await
+            .map_err(|e| UpdateRecipeError::UnknownError(e.into()))?;
+        insert_message(
+            &mut *tx,
+            &OutboxMessage::recipe_updated(recipe, &updated_recipe),
+        )
+        .await?;
+
+        if let Some(change) = RevisionChange::updated(&before, &updated_recipe) {
+            record_revision(&mut tx, &before, &updated_recipe, change, author_id).await?;
         }
 
         tx.commit()
@@ -337,9 +561,42 @@ impl RecipeRepository for PostgresRecipeRepository {
         &self,
         recipe: &Recipe,
         ingredient: IngredientWithAmount,
+        author_id: &Uuid,
     ) -> Result<(), AddIngredientIntoRecipeError> {
-        insert_ingredient(&self.0, recipe.id, &ingredient).await?;
-        update_timestamps_in_recipe(&self.0, recipe.id).await;
+        let mut tx = self.0.begin().await?;
+
+        if !lock_recipe(&mut tx, recipe).await? {
+            return Err(AddIngredientIntoRecipeError::Outdated(recipe.id));
+        }
+        // Read under the lock, so the revision starts from what's actually stored
+        let before = get_recipe(&mut tx, &recipe.id)
+            .await
+            .map_err(|e| AddIngredientIntoRecipeError::UnknownError(e.into()))?;
+
+        insert_ingredient(&mut *tx, recipe.id, &ingredient).await?;
+        update_timestamps_in_recipe(&mut *tx, recipe.id).await;
+
+        insert_message(
+            &mut *tx,
+            &OutboxMessage::recipe_ingredient_added(recipe, &ingredient),
+        )
+        .await?;
+
+        let updated_recipe = get_recipe(&mut tx, &recipe.id)
+            .await
+            .map_err(|e| AddIngredientIntoRecipeError::UnknownError(e.into()))?;
+        record_revision(
+            &mut tx,
+            &before,
+            &updated_recipe,
+            RevisionChange::IngredientAdded {
+                ingredient_id: ingredient.ingredient.id,
+            },
+            author_id,
+        )
+        .await?;
+
+        tx.commit().await?;
 
         Ok(())
     }
@@ -348,16 +605,49 @@ impl RecipeRepository for PostgresRecipeRepository {
         &self,
         recipe: &Recipe,
         ingredient: &IngredientWithAmount,
+        author_id: &Uuid,
     ) -> Result<(), DeleteIngredientFromRecipeError> {
+        let mut tx = self.0.begin().await?;
+
+        if !lock_recipe(&mut tx, recipe).await? {
+            return Err(DeleteIngredientFromRecipeError::Outdated(recipe.id));
+        }
+        // Read under the lock, so the revision starts from what's actually stored
+        let before = get_recipe(&mut tx, &recipe.id)
+            .await
+            .map_err(|e| DeleteIngredientFromRecipeError::UnknownError(e.into()))?;
+
         sqlx::query_file!(
             "queries/recipes/delete_ingredient_from_recipe_by_id.sql",
             recipe.id,
             ingredient.ingredient.id
         )
-        .execute(&self.0)
+        .execute(&mut *tx)
+        .await?;
+
+        update_timestamps_in_recipe(&mut *tx, recipe.id).await;
+
+        insert_message(
+            &mut *tx,
+            &OutboxMessage::recipe_ingredient_removed(recipe, ingredient),
+        )
+        .await?;
+
+        let updated_recipe = get_recipe(&mut tx, &recipe.id)
+            .await
+            .map_err(|e| DeleteIngredientFromRecipeError::UnknownError(e.into()))?;
+        record_revision(
+            &mut tx,
+            &before,
+            &updated_recipe,
+            RevisionChange::IngredientRemoved {
+                ingredient_id: ingredient.ingredient.id,
+            },
+            author_id,
+        )
         .await?;
 
-        update_timestamps_in_recipe(&self.0, recipe.id).await;
+        tx.commit().await?;
 
         Ok(())
     }
@@ -367,8 +657,17 @@ impl RecipeRepository for PostgresRecipeRepository {
         recipe: &Recipe,
         ingredient: &IngredientWithAmount,
         new_amount: &IngredientUnit,
+        author_id: &Uuid,
     ) -> Result<(), UpdateIngredientInRecipeError> {
-        let tx = self.0.begin().await?;
+        let mut tx = self.0.begin().await?;
+
+        if !lock_recipe(&mut tx, recipe).await? {
+            return Err(UpdateIngredientInRecipeError::Outdated(recipe.id));
+        }
+        // Read under the lock, so the revision starts from what's actually stored
+        let before = get_recipe(&mut tx, &recipe.id)
+            .await
+            .map_err(|e| UpdateIngredientInRecipeError::UnknownError(e.into()))?;
 
         let amount = serde_json::to_value(new_amount)?;
 
@@ -378,16 +677,131 @@ impl RecipeRepository for PostgresRecipeRepository {
             ingredient.ingredient.id,
             amount
         )
-        .execute(&self.0)
+        .execute(&mut *tx)
+        .await?;
+
+        update_timestamps_in_recipe(&mut *tx, recipe.id).await;
+
+        let updated_ingredient = IngredientWithAmount {
+            amount: new_amount.clone(),
+            ..ingredient.clone()
+        };
+        insert_message(
+            &mut *tx,
+            &OutboxMessage::recipe_ingredient_amount_changed(
+                recipe,
+                ingredient,
+                &updated_ingredient,
+            ),
+        )
+        .await?;
+
+        let updated_recipe = get_recipe(&mut tx, &recipe.id)
+            .await
+            .map_err(|e| UpdateIngredientInRecipeError::UnknownError(e.into()))?;
+        record_revision(
+            &mut tx,
+            &before,
+            &updated_recipe,
+            RevisionChange::IngredientAmountChanged {
+                ingredient_id: ingredient.ingredient.id,
+            },
+            author_id,
+        )
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument("[RECIPE REPOSITORY] [POSTGRES] Restore recipe", skip(self))]
+    async fn restore(
+        &self,
+        recipe: &Recipe,
+        restored: Recipe,
+        revision: i32,
+        author_id: &Uuid,
+    ) -> Result<(), RestoreRecipeError> {
+        let time = serde_json::to_value(&restored.time)?;
+        let servings = serde_json::to_value(&restored.servings)?;
+
+        let mut tx = self.0.begin().await?;
+
+        if !lock_recipe(&mut tx, recipe).await? {
+            return Err(RestoreRecipeError::Outdated(recipe.id));
+        }
+        // Read under the lock, so the revision starts from what's actually stored
+        let before = get_recipe(&mut tx, &recipe.id)
+            .await
+            .map_err(|e| RestoreRecipeError::UnknownError(e.into()))?;
+
+        sqlx::query_file!(
+            "queries/recipes/restore_recipe.sql",
+            recipe.id,
+            restored.name,
+            restored.description,
+            &restored.steps.as_ref(),
+            time,
+            servings
+        )
+        .execute(&mut *tx)
         .await?;
 
-        update_timestamps_in_recipe(&self.0, recipe.id).await;
+        sqlx::query_file!(
+            "queries/recipes/delete_ingredients_for_recipe.sql",
+            recipe.id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        for ingredient in restored.ingredients.iter() {
+            insert_ingredient(&mut *tx, recipe.id, ingredient)
+                .await
+                .map_err(|e| RestoreRecipeError::UnknownError(e.into()))?;
+        }
+
+        let restored_recipe = get_recipe(&mut tx, &recipe.id)
+            .await
+            .map_err(|e| RestoreRecipeError::UnknownError(e.into()))?;
+        insert_message(
+            &mut *tx,
+            &OutboxMessage::recipe_updated(recipe, &restored_recipe),
+        )
+        .await?;
+        record_revision(
+            &mut tx,
+            &before,
+            &restored_recipe,
+            RevisionChange::Reverted { revision },
+            author_id,
+        )
+        .await?;
 
         tx.commit().await?;
 
         Ok(())
     }
 
+    #[tracing::instrument("[RECIPE REPOSITORY] [POSTGRES] Get revisions of a recipe", skip(self))]
+    async fn get_revisions(
+        &self,
+        recipe_id: &Uuid,
+    ) -> Result<Vec<RecipeRevision>, GetRecipeRevisionsError> {
+        let result = sqlx::query_file_as!(
+            RecipeRevisionModel,
+            "queries/recipes/get_revisions_for_recipe.sql",
+            recipe_id
+        )
+        .fetch_all(&self.0)
+        .await?;
+
+        Ok(result
+            .into_iter()
+            .map(RecipeRevision::try_from)
+            .collect::<Result<_, _>>()?)
+    }
+
     async fn recipes_containing_ingredient_exist(
         &self,
         ingredient: Ingredient,
@@ -401,6 +815,22 @@ impl RecipeRepository for PostgresRecipeRepository {
 
         Ok(recipes_using_ingredient.is_some())
     }
+
+    #[tracing::instrument(
+        "[RECIPE REPOSITORY] [POSTGRES] Refresh recipes using an ingredient",
+        skip(self)
+    )]
+    async fn refresh_ingredient(&self, ingredient: &Ingredient) -> eyre::Result<u64> {
+        // The recipes read their ingredients from the ingredients table, so they're always up to date
+        let count = sqlx::query_file_scalar!(
+            "queries/recipes/count_recipes_using_ingredient.sql",
+            ingredient.id
+        )
+        .fetch_one(&self.0)
+        .await?;
+
+        Ok(count as u64)
+    }
 }
 
 impl PostgresRecipeRepository {
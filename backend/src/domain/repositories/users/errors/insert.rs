@@ -0,0 +1,23 @@
+use eyre::eyre;
+use std::sync::PoisonError;
+use thiserror::Error;
+
+use crate::domain::entities::user::errors::ValidationError;
+
+#[derive(Error, Debug)]
+pub enum InsertUserError {
+    #[error("The user with field {0} of the given value already exists")]
+    Conflict(String),
+
+    #[error(transparent)]
+    ValidationError(#[from] ValidationError),
+
+    #[error(transparent)]
+    UnknownError(#[from] eyre::Error),
+}
+
+impl<T> From<PoisonError<T>> for InsertUserError {
+    fn from(_value: PoisonError<T>) -> Self {
+        eyre!("User repository lock was poisoned during a previous access and can no longer be locked").into()
+    }
+}
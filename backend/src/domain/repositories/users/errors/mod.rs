@@ -0,0 +1,5 @@
+mod get;
+mod insert;
+
+pub use get::*;
+pub use insert::*;
@@ -0,0 +1,79 @@
+use std::{collections::BTreeMap, sync::Mutex};
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::entities::user::User;
+
+use super::{
+    errors::{GetUserByIdError, GetUserByUsernameError, InsertUserError},
+    UserRepository,
+};
+
+pub struct InMemoryUserRepository(pub Mutex<BTreeMap<Uuid, User>>);
+
+#[async_trait]
+impl UserRepository for InMemoryUserRepository {
+    #[tracing::instrument("[USER REPOSITORY] [IN MEMORY] Insert a new user", skip(self))]
+    async fn insert(&self, user: User) -> Result<User, InsertUserError> {
+        let mut lock = self.0.lock()?;
+
+        if lock.contains_key(&user.id) {
+            tracing::error!("The user with ID {} already exists.", user.id);
+            return Err(InsertUserError::Conflict("id".to_string()));
+        };
+
+        if lock.values().any(|x| x.username == user.username) {
+            tracing::error!("The user with username {} already exists.", user.username);
+            return Err(InsertUserError::Conflict("username".to_string()));
+        };
+
+        if lock.values().any(|x| x.email == user.email) {
+            tracing::error!("The user with email {} already exists.", user.email);
+            return Err(InsertUserError::Conflict("email".to_string()));
+        };
+
+        lock.insert(user.id, user.clone());
+
+        Ok(user)
+    }
+
+    #[tracing::instrument("[USER REPOSITORY] [IN MEMORY] Get user with ID", skip(self))]
+    async fn get_by_id(&self, id: &Uuid) -> Result<User, GetUserByIdError> {
+        let lock = self.0.lock()?;
+
+        let user = lock.get(id).ok_or(GetUserByIdError::NotFound(*id))?;
+
+        Ok(user.clone())
+    }
+
+    #[tracing::instrument("[USER REPOSITORY] [IN MEMORY] Get user with username", skip(self))]
+    async fn get_by_username(&self, username: &str) -> Result<User, GetUserByUsernameError> {
+        let lock = self.0.lock()?;
+
+        let user = lock
+            .values()
+            .find(|x| x.username.as_str() == username)
+            .ok_or_else(|| GetUserByUsernameError::NotFound(username.to_string()))?;
+
+        Ok(user.clone())
+    }
+}
+
+impl InMemoryUserRepository {
+    pub fn new() -> Self {
+        BTreeMap::new().into()
+    }
+}
+
+impl Default for InMemoryUserRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<BTreeMap<Uuid, User>> for InMemoryUserRepository {
+    fn from(value: BTreeMap<Uuid, User>) -> Self {
+        Self(value.into())
+    }
+}
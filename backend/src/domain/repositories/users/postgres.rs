@@ -0,0 +1,92 @@
+use std::{collections::HashMap, sync::OnceLock};
+
+use async_trait::async_trait;
+use sqlx::{error::Error as SQLXError, PgPool};
+use uuid::Uuid;
+
+use crate::domain::entities::user::{User, UserModel};
+
+use super::{
+    errors::{GetUserByIdError, GetUserByUsernameError, InsertUserError},
+    UserRepository,
+};
+
+pub struct PostgresUserRepository(pub PgPool);
+
+/// Maps unique constraint names to the fields they guard, the same way the ingredient repository does.
+fn constraint_to_field(field: &str) -> &str {
+    static HASHMAP: OnceLock<HashMap<&str, &str>> = OnceLock::new();
+    let m = HASHMAP.get_or_init(|| {
+        HashMap::from_iter([
+            ("users_username_key", "username"),
+            ("users_email_key", "email"),
+            ("users_pkey", "id"),
+        ])
+    });
+    m.get(field).unwrap_or(&field)
+}
+
+#[async_trait]
+impl UserRepository for PostgresUserRepository {
+    #[tracing::instrument("[USER REPOSITORY] [POSTGRES] Insert a new user", skip(self))]
+    async fn insert(&self, user: User) -> Result<User, InsertUserError> {
+        let user: UserModel = user.into();
+
+        let user = sqlx::query_file_as!(
+            UserModel,
+            "queries/users/insert_user.sql",
+            user.id,
+            user.username,
+            user.email,
+            user.password_hash
+        )
+        .fetch_one(&self.0)
+        .await
+        .map_err(|e| match e {
+            SQLXError::Database(dberror) if dberror.is_unique_violation() => {
+                InsertUserError::Conflict(
+                    constraint_to_field(dberror.constraint().unwrap_or_default()).to_string(),
+                )
+            }
+            _ => InsertUserError::UnknownError(e.into()),
+        })?;
+
+        Ok(user.try_into()?)
+    }
+
+    #[tracing::instrument("[USER REPOSITORY] [POSTGRES] Get user with ID", skip(self))]
+    async fn get_by_id(&self, id: &Uuid) -> Result<User, GetUserByIdError> {
+        let user = sqlx::query_file_as!(UserModel, "queries/users/get_user_by_id.sql", id)
+            .fetch_one(&self.0)
+            .await
+            .map_err(|e| match e {
+                SQLXError::RowNotFound => GetUserByIdError::NotFound(*id),
+                _ => GetUserByIdError::UnknownError(e.into()),
+            })?;
+
+        Ok(user.try_into()?)
+    }
+
+    #[tracing::instrument("[USER REPOSITORY] [POSTGRES] Get user with username", skip(self))]
+    async fn get_by_username(&self, username: &str) -> Result<User, GetUserByUsernameError> {
+        let user = sqlx::query_file_as!(
+            UserModel,
+            "queries/users/get_user_by_username.sql",
+            username
+        )
+        .fetch_one(&self.0)
+        .await
+        .map_err(|e| match e {
+            SQLXError::RowNotFound => GetUserByUsernameError::NotFound(username.to_string()),
+            _ => GetUserByUsernameError::UnknownError(e.into()),
+        })?;
+
+        Ok(user.try_into()?)
+    }
+}
+
+impl PostgresUserRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self(pool)
+    }
+}
@@ -0,0 +1,107 @@
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use eyre::eyre;
+use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver, UnboundedSender};
+
+use crate::domain::{entities::outbox::OutboxMessage, services::message::MessageService};
+
+use super::{DeadLetter, MessageConsumer, ReceivedMessage};
+
+/// Creates a message service and a consumer connected to each other, with no broker in between.
+/// Whatever gets published with the service is received by the consumer.
+pub fn channel() -> (ChannelMessageService, ChannelMessageConsumer) {
+    let (sender, receiver) = unbounded_channel();
+
+    (
+        ChannelMessageService(sender),
+        ChannelMessageConsumer {
+            receiver,
+            topics: vec![],
+            dead_letters: DeadLetters::default(),
+        },
+    )
+}
+
+#[derive(Debug, Clone)]
+pub struct ChannelMessageService(UnboundedSender<ReceivedMessage>);
+
+impl ChannelMessageService {
+    /// Sends a message straight to the consumer, as if it came from the message bus
+    pub fn send(&self, message: ReceivedMessage) -> eyre::Result<()> {
+        self.0
+            .send(message)
+            .map_err(|_| eyre!("The consumer is gone"))
+    }
+}
+
+#[async_trait]
+impl MessageService for ChannelMessageService {
+    async fn publish(&self, message: &OutboxMessage) -> eyre::Result<()> {
+        self.send(message.into())
+    }
+}
+
+/// The messages the [ChannelMessageConsumer] has set aside.
+/// Cloning it gives another handle to the same list, so it can be checked after the consumer was moved away.
+#[derive(Debug, Clone, Default)]
+pub struct DeadLetters(Arc<Mutex<Vec<DeadLetter>>>);
+
+impl DeadLetters {
+    pub fn all(&self) -> eyre::Result<Vec<DeadLetter>> {
+        let lock = self.0.lock().map_err(|e| eyre!("{e}"))?;
+
+        Ok(lock.clone())
+    }
+}
+
+pub struct ChannelMessageConsumer {
+    receiver: UnboundedReceiver<ReceivedMessage>,
+    topics: Vec<String>,
+    dead_letters: DeadLetters,
+}
+
+impl ChannelMessageConsumer {
+    pub fn dead_letters(&self) -> DeadLetters {
+        self.dead_letters.clone()
+    }
+}
+
+#[async_trait]
+impl MessageConsumer for ChannelMessageConsumer {
+    async fn subscribe(&mut self, topics: &[String]) -> eyre::Result<()> {
+        self.topics = topics.to_vec();
+
+        Ok(())
+    }
+
+    async fn next_message(&mut self) -> eyre::Result<Option<ReceivedMessage>> {
+        // Messages from topics nobody subscribed to wouldn't reach a real consumer either
+        while let Some(message) = self.receiver.recv().await {
+            if self.topics.contains(&message.topic) {
+                return Ok(Some(message));
+            }
+        }
+
+        Ok(None)
+    }
+
+    async fn commit(&mut self) -> eyre::Result<()> {
+        // Received messages are gone from the channel, there's nothing to acknowledge
+        Ok(())
+    }
+
+    async fn dead_letter(&mut self, letter: DeadLetter) -> eyre::Result<()> {
+        let mut lock = self.dead_letters.0.lock().map_err(|e| eyre!("{e}"))?;
+
+        lock.push(letter);
+
+        Ok(())
+    }
+
+    async fn close(&mut self) -> eyre::Result<()> {
+        self.receiver.close();
+
+        Ok(())
+    }
+}
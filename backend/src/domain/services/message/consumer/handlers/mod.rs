@@ -0,0 +1,59 @@
+use async_trait::async_trait;
+use common::events::{Event, EventType};
+use eyre::eyre;
+
+use crate::domain::repositories::{
+    ingredients::{errors::GetIngredientByIdError, IngredientRepositoryService},
+    recipe::RecipeRepositoryService,
+};
+
+use super::{MessageHandler, MessageHandlers, ReceivedMessage};
+
+/// The handlers the backend runs with, when the recipes keep copies of their ingredients
+pub fn default_handlers(
+    ingredient_repository: IngredientRepositoryService,
+    recipe_repository: RecipeRepositoryService,
+) -> MessageHandlers {
+    MessageHandlers::new().with_handler(
+        EventType::IngredientUpdated.topic(),
+        RefreshRecipeIngredientsHandler {
+            ingredient_repository,
+            recipe_repository,
+        },
+    )
+}
+
+/// Refreshes the recipes using an ingredient once it has changed
+pub struct RefreshRecipeIngredientsHandler {
+    pub ingredient_repository: IngredientRepositoryService,
+    pub recipe_repository: RecipeRepositoryService,
+}
+
+#[async_trait]
+impl MessageHandler for RefreshRecipeIngredientsHandler {
+    #[tracing::instrument("[HANDLER] Refresh recipes using an updated ingredient", skip_all)]
+    async fn handle(&self, message: &ReceivedMessage) -> eyre::Result<()> {
+        let Event::IngredientUpdated(change) = message.envelope()?.event else {
+            return Err(eyre!("Expected an ingredient.updated event"));
+        };
+
+        // The event might be stale by now, so the current state of the ingredient is used instead
+        let ingredient = match self.ingredient_repository.get_by_id(&change.new.id).await {
+            Ok(ingredient) => ingredient,
+            Err(GetIngredientByIdError::NotFound(_)) => return Ok(()),
+            Err(e) => return Err(e.into()),
+        };
+
+        let refreshed = self
+            .recipe_repository
+            .refresh_ingredient(&ingredient)
+            .await?;
+        tracing::info!(
+            "Refreshed {} recipes using ingredient {}",
+            refreshed,
+            ingredient.id
+        );
+
+        Ok(())
+    }
+}
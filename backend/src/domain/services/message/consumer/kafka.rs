@@ -0,0 +1,120 @@
+use std::time::Duration;
+
+use async_trait::async_trait;
+use eyre::eyre;
+use rdkafka::{
+    consumer::{CommitMode, Consumer, StreamConsumer},
+    message::{Header, OwnedHeaders},
+    producer::{FutureProducer, FutureRecord},
+    Message,
+};
+
+use crate::configuration::MessagingSettings;
+
+use super::{DeadLetter, MessageConsumer, ReceivedMessage};
+
+pub struct KafkaMessageConsumer {
+    consumer: StreamConsumer,
+    producer: FutureProducer,
+    settings: MessagingSettings,
+    /// Topic, partition and offset of the message being handled
+    in_flight: Option<(String, i32, i64)>,
+}
+
+impl KafkaMessageConsumer {
+    pub fn new(settings: &MessagingSettings) -> eyre::Result<Self> {
+        let consumer: StreamConsumer = settings.consumer_config().create()?;
+        let producer: FutureProducer = settings.producer_config().create()?;
+
+        Ok(Self {
+            consumer,
+            producer,
+            settings: settings.clone(),
+            in_flight: None,
+        })
+    }
+}
+
+#[async_trait]
+impl MessageConsumer for KafkaMessageConsumer {
+    async fn subscribe(&mut self, topics: &[String]) -> eyre::Result<()> {
+        let topics: Vec<String> = topics.iter().map(|t| self.settings.topic(t)).collect();
+        let topics: Vec<&str> = topics.iter().map(String::as_str).collect();
+        self.consumer.subscribe(&topics)?;
+
+        Ok(())
+    }
+
+    async fn next_message(&mut self) -> eyre::Result<Option<ReceivedMessage>> {
+        let message = self.consumer.recv().await?;
+
+        self.in_flight = Some((
+            message.topic().to_string(),
+            message.partition(),
+            message.offset(),
+        ));
+
+        // The handlers don't need to know about the prefix
+        let topic = message.topic();
+        let topic = topic
+            .strip_prefix(&self.settings.topic_prefix)
+            .unwrap_or(topic);
+
+        Ok(Some(ReceivedMessage {
+            topic: topic.to_string(),
+            key: message
+                .key()
+                .map(|key| String::from_utf8_lossy(key).into_owned()),
+            payload: message
+                .payload()
+                .map(|payload| String::from_utf8_lossy(payload).into_owned())
+                .unwrap_or_default(),
+        }))
+    }
+
+    async fn commit(&mut self) -> eyre::Result<()> {
+        if let Some((topic, partition, offset)) = self.in_flight.take() {
+            self.consumer.store_offset(&topic, partition, offset)?;
+        }
+
+        Ok(())
+    }
+
+    async fn dead_letter(&mut self, letter: DeadLetter) -> eyre::Result<()> {
+        let headers = OwnedHeaders::new()
+            .insert(Header {
+                key: "original_topic",
+                value: Some(&self.settings.topic(&letter.message.topic)),
+            })
+            .insert(Header {
+                key: "error",
+                value: Some(&letter.error),
+            });
+        let dead_letter_topic = self.settings.topic(&self.settings.dead_letter_topic);
+        let mut record = FutureRecord::to(&dead_letter_topic)
+            .payload(&letter.message.payload)
+            .headers(headers);
+
+        if let Some(key) = &letter.message.key {
+            record = record.key(key);
+        }
+
+        self.producer
+            .send(record, Duration::from_secs(0))
+            .await
+            .map_err(|e| eyre!("Could not send message to the dead letter topic, {:#?}", e))?;
+
+        Ok(())
+    }
+
+    async fn close(&mut self) -> eyre::Result<()> {
+        // Nothing might have been stored since the last automatic commit, which isn't a problem
+        if let Err(e) = self.consumer.commit_consumer_state(CommitMode::Sync) {
+            tracing::debug!("Nothing to commit before leaving the consumer group: {}", e);
+        }
+
+        self.consumer.unsubscribe();
+
+        Ok(())
+    }
+}
@@ -0,0 +1,270 @@
+pub mod channel;
+pub mod handlers;
+pub mod kafka;
+
+use std::{collections::HashMap, sync::Arc, time::Duration};
+
+use async_trait::async_trait;
+use common::events::EventEnvelope;
+use mockall::mock;
+use tokio::{sync::watch, task::JoinHandle};
+
+use crate::domain::entities::outbox::OutboxMessage;
+
+const RECEIVE_RETRY_INTERVAL: Duration = Duration::from_secs(1);
+const DEAD_LETTER_RETRY_INTERVAL: Duration = Duration::from_secs(1);
+/// How many times a message is handed to the handlers before it's moved to the dead letters
+const HANDLE_ATTEMPTS: u32 = 3;
+/// Waited after the first failed attempt at handling a message, and doubled after every next one
+const HANDLE_RETRY_INTERVAL: Duration = Duration::from_millis(100);
+
+/// A message received from the message bus
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReceivedMessage {
+    pub topic: String,
+    pub key: Option<String>,
+    pub payload: String,
+}
+
+impl ReceivedMessage {
+    pub fn envelope(&self) -> eyre::Result<EventEnvelope> {
+        Ok(serde_json::from_str(&self.payload)?)
+    }
+}
+
+impl From<&OutboxMessage> for ReceivedMessage {
+    fn from(value: &OutboxMessage) -> Self {
+        Self {
+            topic: value.topic.clone(),
+            key: Some(value.key.clone()),
+            payload: value.payload.to_string(),
+        }
+    }
+}
+
+/// A message that one of the handlers failed to handle, along with the reason
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeadLetter {
+    pub message: ReceivedMessage,
+    pub error: String,
+}
+
+/// Reacts to the messages of the topic it was registered for in [MessageHandlers].
+/// Messages can be delivered more than once, so handling the same one twice should be harmless.
+#[async_trait]
+pub trait MessageHandler: Send + Sync + 'static {
+    async fn handle(&self, message: &ReceivedMessage) -> eyre::Result<()>;
+}
+
+mock! {
+    pub MessageHandler {}
+
+    #[async_trait]
+    impl MessageHandler for MessageHandler {
+        async fn handle(&self, message: &ReceivedMessage) -> eyre::Result<()>;
+    }
+}
+
+pub type MessageHandlerImpl = Arc<Box<dyn MessageHandler>>;
+
+/// The handlers, by the topic they handle
+#[derive(Clone, Default)]
+pub struct MessageHandlers(HashMap<String, Vec<MessageHandlerImpl>>);
+
+impl MessageHandlers {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_handler(mut self, topic: &str, handler: impl MessageHandler) -> Self {
+        self.0
+            .entry(topic.to_string())
+            .or_default()
+            .push(Arc::new(Box::new(handler)));
+
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// The topics to subscribe to
+    pub fn topics(&self) -> Vec<String> {
+        let mut topics: Vec<_> = self.0.keys().cloned().collect();
+        topics.sort();
+        topics
+    }
+
+    /// Runs every handler of the topic of the message, stopping at the first one that fails.
+    pub async fn dispatch(&self, message: &ReceivedMessage) -> eyre::Result<()> {
+        let Some(handlers) = self.0.get(&message.topic) else {
+            tracing::debug!("No handlers for messages from {}", message.topic);
+            return Ok(());
+        };
+
+        for handler in handlers {
+            handler.handle(message).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Gives the handlers a few attempts at the message, in case they failed for a reason that goes away on its own.
+    async fn dispatch_with_retries(&self, message: &ReceivedMessage) -> eyre::Result<()> {
+        let mut interval = HANDLE_RETRY_INTERVAL;
+        let mut attempt = 1;
+
+        loop {
+            match self.dispatch(message).await {
+                Err(e) if attempt < HANDLE_ATTEMPTS => {
+                    tracing::warn!(
+                        "Could not handle a message from {} (attempt {}/{}), retrying: {}",
+                        message.topic,
+                        attempt,
+                        HANDLE_ATTEMPTS,
+                        e
+                    );
+                    tokio::time::sleep(interval).await;
+                    interval *= 2;
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
+/// Where the messages come from.
+/// The [MessageDispatcher] drives it, so implementations only have to deal with the message bus itself.
+#[async_trait]
+pub trait MessageConsumer: Send + 'static {
+    async fn subscribe(&mut self, topics: &[String]) -> eyre::Result<()>;
+
+    /// Waits for the next message. Returns `None` once there won't be any more messages.
+    async fn next_message(&mut self) -> eyre::Result<Option<ReceivedMessage>>;
+
+    /// Acknowledges the last message returned by `next_message`, so it isn't delivered to the group again.
+    async fn commit(&mut self) -> eyre::Result<()>;
+
+    /// Sets aside a message that couldn't be handled, so it doesn't block the ones after it.
+    async fn dead_letter(&mut self, letter: DeadLetter) -> eyre::Result<()>;
+
+    /// Called once the dispatcher stops, before the consumer is dropped.
+    async fn close(&mut self) -> eyre::Result<()>;
+}
+
+mock! {
+    pub MessageConsumer {}
+
+    #[async_trait]
+    impl MessageConsumer for MessageConsumer {
+        async fn subscribe(&mut self, topics: &[String]) -> eyre::Result<()>;
+        async fn next_message(&mut self) -> eyre::Result<Option<ReceivedMessage>>;
+        async fn commit(&mut self) -> eyre::Result<()>;
+        async fn dead_letter(&mut self, letter: DeadLetter) -> eyre::Result<()>;
+        async fn close(&mut self) -> eyre::Result<()>;
+    }
+}
+
+/// Hands the messages of a [MessageConsumer] over to the [MessageHandlers].
+/// A message is only committed after it was handled or dead-lettered, so every message is handled at least once.
+pub struct MessageDispatcher {
+    consumer: Box<dyn MessageConsumer>,
+    handlers: MessageHandlers,
+}
+
+/// Stops a spawned [MessageDispatcher]
+pub struct ConsumerHandle {
+    shutdown: watch::Sender<bool>,
+    task: JoinHandle<eyre::Result<()>>,
+}
+
+impl ConsumerHandle {
+    /// Lets the dispatcher finish the message it's handling, and waits for it to stop.
+    pub async fn shutdown(self) -> eyre::Result<()> {
+        // Fails only if the dispatcher has stopped already
+        let _ = self.shutdown.send(true);
+
+        self.task.await?
+    }
+}
+
+impl MessageDispatcher {
+    pub fn new(consumer: Box<dyn MessageConsumer>, handlers: MessageHandlers) -> Self {
+        Self { consumer, handlers }
+    }
+
+    /// Handles the message, or moves it to the dead letters, and commits it.
+    /// Failing to do so never stops the dispatcher, since nothing would start it again.
+    async fn process(&mut self, message: ReceivedMessage, shutdown: &mut watch::Receiver<bool>) {
+        if let Err(e) = self.handlers.dispatch_with_retries(&message).await {
+            tracing::error!(
+                "Could not handle a message from {}, moving it to the dead letters: {}",
+                message.topic,
+                e
+            );
+            let letter = DeadLetter {
+                message,
+                error: e.to_string(),
+            };
+
+            while let Err(e) = self.consumer.dead_letter(letter.clone()).await {
+                tracing::error!(
+                    "Could not move a message to the dead letters, retrying: {}",
+                    e
+                );
+
+                // Left uncommitted, the message is delivered again after a restart
+                tokio::select! {
+                    _ = shutdown.changed() => return,
+                    _ = tokio::time::sleep(DEAD_LETTER_RETRY_INTERVAL) => {}
+                }
+            }
+        }
+
+        // The next successful commit acknowledges this message as well, so there's no point in retrying
+        if let Err(e) = self.consumer.commit().await {
+            tracing::error!(
+                "Could not commit a message, it may be delivered again: {}",
+                e
+            );
+        }
+    }
+
+    /// Handles messages until the consumer runs out of them, or until `shutdown` becomes `true`.
+    #[tracing::instrument("[SERVICE] Consuming messages", skip_all)]
+    pub async fn run(mut self, mut shutdown: watch::Receiver<bool>) -> eyre::Result<()> {
+        self.consumer.subscribe(&self.handlers.topics()).await?;
+
+        while !*shutdown.borrow() {
+            let next = tokio::select! {
+                // A dropped sender can't ask for a shutdown anymore, so it's treated the same as one
+                _ = shutdown.changed() => break,
+                next = self.consumer.next_message() => next,
+            };
+
+            match next {
+                Ok(Some(message)) => self.process(message, &mut shutdown).await,
+                Ok(None) => break,
+                Err(e) => {
+                    tracing::error!("Could not receive a message: {}", e);
+                    tokio::time::sleep(RECEIVE_RETRY_INTERVAL).await;
+                }
+            }
+        }
+
+        self.consumer.close().await
+    }
+
+    /// Keeps handling messages in the background, until the returned handle is used to stop it.
+    pub fn spawn(self) -> ConsumerHandle {
+        let (shutdown, receiver) = watch::channel(false);
+        let task = tokio::spawn(self.run(receiver));
+
+        ConsumerHandle { shutdown, task }
+    }
+}
+
+#[cfg(test)]
+mod tests;
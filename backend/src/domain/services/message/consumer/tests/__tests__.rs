@@ -0,0 +1,244 @@
+use std::{sync::Arc, time::Duration};
+
+use eyre::eyre;
+use tokio::sync::watch;
+
+use crate::{
+    domain::{
+        entities::{
+            ingredient::{types::IngredientName, IngredientChangeset},
+            outbox::OutboxMessage,
+        },
+        repositories::{
+            ingredients::IngredientRepository, outbox::OutboxRepository, recipe::RecipeRepository,
+        },
+        services::message::{
+            consumer::{
+                channel::channel, handlers::default_handlers, MessageDispatcher, MessageHandlers,
+                MockMessageConsumer, MockMessageHandler, ReceivedMessage, HANDLE_ATTEMPTS,
+            },
+            relay::OutboxRelay,
+        },
+    },
+    test_utils::{ingredient_fixture, insert_all_ingredients_of_recipe, recipe_fixture},
+};
+
+pub async fn messages_reach_the_handlers_of_their_topic() {
+    let (service, consumer) = channel();
+    let dead_letters = consumer.dead_letters();
+
+    let mut handler = MockMessageHandler::new();
+    handler
+        .expect_handle()
+        .withf(|m| m.topic == "deepdish.ingredient.added")
+        .times(1)
+        .returning(|_| Ok(()));
+    let handlers = MessageHandlers::new().with_handler("deepdish.ingredient.added", handler);
+
+    service
+        .send((&OutboxMessage::ingredient_added(&ingredient_fixture())).into())
+        .unwrap();
+    service
+        .send((&OutboxMessage::ingredient_deleted(&ingredient_fixture())).into())
+        .unwrap();
+    drop(service);
+
+    let (_shutdown, receiver) = watch::channel(false);
+    MessageDispatcher::new(Box::new(consumer), handlers)
+        .run(receiver)
+        .await
+        .unwrap();
+
+    assert!(dead_letters.all().unwrap().is_empty());
+}
+
+pub async fn failed_messages_end_up_in_the_dead_letters() {
+    let (service, consumer) = channel();
+    let dead_letters = consumer.dead_letters();
+
+    let mut handler = MockMessageHandler::new();
+    // The failing message gets every attempt, the succeeding one only needs the first
+    handler
+        .expect_handle()
+        .times(HANDLE_ATTEMPTS as usize + 1)
+        .returning(|m| match m.key {
+            Some(_) => Err(eyre!("Something went wrong")),
+            None => Ok(()),
+        });
+    let handlers = MessageHandlers::new().with_handler("deepdish.ingredient.added", handler);
+
+    let failing = ReceivedMessage::from(&OutboxMessage::ingredient_added(&ingredient_fixture()));
+    let mut succeeding = failing.clone();
+    succeeding.key = None;
+    service.send(failing.clone()).unwrap();
+    service.send(succeeding).unwrap();
+    drop(service);
+
+    let (_shutdown, receiver) = watch::channel(false);
+    MessageDispatcher::new(Box::new(consumer), handlers)
+        .run(receiver)
+        .await
+        .unwrap();
+
+    let dead_letters = dead_letters.all().unwrap();
+    assert_eq!(dead_letters.len(), 1);
+    assert_eq!(dead_letters[0].message, failing);
+    assert_eq!(dead_letters[0].error, "Something went wrong");
+}
+
+pub async fn messages_that_fail_only_once_are_retried() {
+    let (service, consumer) = channel();
+    let dead_letters = consumer.dead_letters();
+
+    let mut failed = false;
+    let mut handler = MockMessageHandler::new();
+    handler.expect_handle().times(2).returning(move |_| {
+        if failed {
+            return Ok(());
+        }
+        failed = true;
+        Err(eyre!("Something went wrong"))
+    });
+    let handlers = MessageHandlers::new().with_handler("deepdish.ingredient.added", handler);
+
+    service
+        .send((&OutboxMessage::ingredient_added(&ingredient_fixture())).into())
+        .unwrap();
+    drop(service);
+
+    let (_shutdown, receiver) = watch::channel(false);
+    MessageDispatcher::new(Box::new(consumer), handlers)
+        .run(receiver)
+        .await
+        .unwrap();
+
+    assert!(dead_letters.all().unwrap().is_empty());
+}
+
+/// A consumer that receives `messages` and then runs out, and subscribes and closes without trouble
+fn consumer_receiving(messages: Vec<ReceivedMessage>) -> MockMessageConsumer {
+    let mut messages = messages.into_iter();
+    let mut consumer = MockMessageConsumer::new();
+    consumer.expect_subscribe().returning(|_| Ok(()));
+    consumer
+        .expect_next_message()
+        .returning(move || Ok(messages.next()));
+    consumer.expect_close().times(1).returning(|| Ok(()));
+    consumer
+}
+
+pub async fn failing_to_dead_letter_does_not_stop_the_dispatcher() {
+    let message = ReceivedMessage::from(&OutboxMessage::ingredient_added(&ingredient_fixture()));
+    let mut consumer = consumer_receiving(vec![message.clone()]);
+
+    let mut failed = false;
+    consumer
+        .expect_dead_letter()
+        .withf(move |letter| letter.message == message)
+        .times(2)
+        .returning(move |_| {
+            if failed {
+                return Ok(());
+            }
+            failed = true;
+            Err(eyre!("The broker is gone"))
+        });
+    consumer.expect_commit().times(1).returning(|| Ok(()));
+
+    let mut handler = MockMessageHandler::new();
+    handler
+        .expect_handle()
+        .returning(|_| Err(eyre!("Something went wrong")));
+    let handlers = MessageHandlers::new().with_handler("deepdish.ingredient.added", handler);
+
+    let (_shutdown, receiver) = watch::channel(false);
+    MessageDispatcher::new(Box::new(consumer), handlers)
+        .run(receiver)
+        .await
+        .unwrap();
+}
+
+pub async fn failing_to_commit_does_not_stop_the_dispatcher() {
+    let message = ReceivedMessage::from(&OutboxMessage::ingredient_added(&ingredient_fixture()));
+    let mut consumer = consumer_receiving(vec![message.clone(), message]);
+    consumer
+        .expect_commit()
+        .times(2)
+        .returning(|| Err(eyre!("The broker is gone")));
+
+    let mut handler = MockMessageHandler::new();
+    handler.expect_handle().times(2).returning(|_| Ok(()));
+    let handlers = MessageHandlers::new().with_handler("deepdish.ingredient.added", handler);
+
+    let (_shutdown, receiver) = watch::channel(false);
+    MessageDispatcher::new(Box::new(consumer), handlers)
+        .run(receiver)
+        .await
+        .unwrap();
+}
+
+pub async fn shutting_down_stops_the_dispatcher() {
+    let (_service, consumer) = channel();
+
+    let handle = MessageDispatcher::new(Box::new(consumer), MessageHandlers::new()).spawn();
+
+    tokio::time::timeout(Duration::from_secs(5), handle.shutdown())
+        .await
+        .expect("The dispatcher did not stop")
+        .unwrap();
+}
+
+pub async fn updated_ingredients_get_refreshed_in_recipes(
+    ingredient_repo: impl IngredientRepository,
+    recipe_repo: impl RecipeRepository,
+    outbox: impl OutboxRepository,
+) {
+    let recipe = recipe_fixture();
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
+    recipe_repo.insert(recipe.clone()).await.unwrap();
+    let recipe = recipe_repo.get_by_id(&recipe.id).await.unwrap();
+
+    let ingredient = recipe.ingredients[0].ingredient.clone();
+    ingredient_repo
+        .update(
+            &ingredient,
+            IngredientChangeset {
+                name: Some(IngredientName("Silken tofu".to_string())),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+
+    let ingredient_repo: Arc<Box<dyn IngredientRepository>> = Arc::new(Box::new(ingredient_repo));
+    let recipe_repo: Arc<Box<dyn RecipeRepository>> = Arc::new(Box::new(recipe_repo));
+    let (service, consumer) = channel();
+    let dead_letters = consumer.dead_letters();
+
+    OutboxRelay::new(Arc::new(Box::new(outbox)), Arc::new(Box::new(service)))
+        .relay_pending()
+        .await
+        .unwrap();
+
+    // The relay is gone along with its end of the channel, so the dispatcher stops once it's done
+    let (_shutdown, receiver) = watch::channel(false);
+    MessageDispatcher::new(
+        Box::new(consumer),
+        default_handlers(ingredient_repo, recipe_repo.clone()),
+    )
+    .run(receiver)
+    .await
+    .unwrap();
+
+    let refreshed = recipe_repo.get_by_id(&recipe.id).await.unwrap();
+    let refreshed_ingredient = refreshed
+        .ingredients
+        .iter()
+        .find(|i| i.ingredient.id == ingredient.id)
+        .unwrap();
+
+    assert!(dead_letters.all().unwrap().is_empty());
+    assert_eq!(refreshed_ingredient.ingredient.name.0, "Silken tofu");
+    // Clients holding the version of the recipe shouldn't have it rejected over a change to an ingredient
+    assert_eq!(refreshed.version(), recipe.version());
+}
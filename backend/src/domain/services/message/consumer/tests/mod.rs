@@ -0,0 +1,76 @@
+mod __tests__;
+
+mod in_memory {
+    use super::__tests__;
+
+    use crate::domain::repositories::{
+        ingredients::in_memory::InMemoryIngredientRepository,
+        outbox::in_memory::InMemoryOutboxRepository, recipe::in_memory::InMemoryRecipeRepository,
+    };
+
+    #[tokio::test]
+    async fn messages_reach_the_handlers_of_their_topic() {
+        __tests__::messages_reach_the_handlers_of_their_topic().await
+    }
+
+    #[tokio::test]
+    async fn failed_messages_end_up_in_the_dead_letters() {
+        __tests__::failed_messages_end_up_in_the_dead_letters().await
+    }
+
+    #[tokio::test]
+    async fn messages_that_fail_only_once_are_retried() {
+        __tests__::messages_that_fail_only_once_are_retried().await
+    }
+
+    #[tokio::test]
+    async fn failing_to_dead_letter_does_not_stop_the_dispatcher() {
+        __tests__::failing_to_dead_letter_does_not_stop_the_dispatcher().await
+    }
+
+    #[tokio::test]
+    async fn failing_to_commit_does_not_stop_the_dispatcher() {
+        __tests__::failing_to_commit_does_not_stop_the_dispatcher().await
+    }
+
+    #[tokio::test]
+    async fn shutting_down_stops_the_dispatcher() {
+        __tests__::shutting_down_stops_the_dispatcher().await
+    }
+
+    #[tokio::test]
+    async fn updated_ingredients_get_refreshed_in_recipes() {
+        let outbox = InMemoryOutboxRepository::new();
+        let ingredient_repo = InMemoryIngredientRepository::new().with_outbox(outbox.clone());
+        let recipe_repo = InMemoryRecipeRepository::new().with_outbox(outbox.clone());
+        __tests__::updated_ingredients_get_refreshed_in_recipes(
+            ingredient_repo,
+            recipe_repo,
+            outbox,
+        )
+        .await
+    }
+}
+
+mod sql {
+    use super::__tests__;
+    use sqlx::PgPool;
+
+    use crate::domain::repositories::{
+        ingredients::postgres::PostgresIngredientRepository,
+        outbox::postgres::PostgresOutboxRepository, recipe::postgres::PostgresRecipeRepository,
+    };
+
+    #[sqlx::test]
+    async fn updated_ingredients_get_refreshed_in_recipes(pool: PgPool) {
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let recipe_repo = PostgresRecipeRepository::new(pool.clone());
+        let outbox = PostgresOutboxRepository::new(pool);
+        __tests__::updated_ingredients_get_refreshed_in_recipes(
+            ingredient_repo,
+            recipe_repo,
+            outbox,
+        )
+        .await
+    }
+}
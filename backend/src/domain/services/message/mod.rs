@@ -1,17 +1,29 @@
+pub mod consumer;
 pub mod kafka;
+pub mod relay;
 pub mod stub;
 
 use std::sync::Arc;
 
 use async_trait::async_trait;
+use mockall::mock;
 
-use crate::domain::entities::ingredient::Ingredient;
+use crate::domain::entities::outbox::OutboxMessage;
 
+/// Publishes messages to the message bus.
+/// The messages aren't sent from the commands directly, they go through the outbox and the [relay::OutboxRelay].
 #[async_trait]
 pub trait MessageService: Send + Sync + 'static {
-    async fn ingredient_added(&self, ing: &Ingredient) -> eyre::Result<()>;
-    async fn ingredient_deleted(&self, ing: &Ingredient) -> eyre::Result<()>;
-    async fn ingredient_updated(&self, old_ing: &Ingredient, new_ing: &Ingredient) -> eyre::Result<()>;
+    async fn publish(&self, message: &OutboxMessage) -> eyre::Result<()>;
+}
+
+mock! {
+    pub MessageService {}
+
+    #[async_trait]
+    impl MessageService for MessageService {
+        async fn publish(&self, message: &OutboxMessage) -> eyre::Result<()>;
+    }
 }
 
 pub type MessageServiceImpl = Arc<Box<dyn MessageService>>;
@@ -0,0 +1,141 @@
+use std::{collections::HashSet, time::Duration};
+
+use tokio::{task::JoinHandle, time::Instant};
+
+use crate::domain::repositories::outbox::OutboxRepositoryService;
+
+use super::MessageServiceImpl;
+
+/// Drains the outbox into the message service.
+/// A message is only marked as published once the message service has accepted it,
+/// so every message is delivered at least once, and possibly more than once.
+/// Messages with the same key are published in the order they were written in.
+#[derive(Clone)]
+pub struct OutboxRelay {
+    outbox: OutboxRepositoryService,
+    message_service: MessageServiceImpl,
+    batch_size: i64,
+    poll_interval: Duration,
+    lease: Duration,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    retention: Duration,
+    cleanup_interval: Duration,
+}
+
+impl OutboxRelay {
+    pub fn new(outbox: OutboxRepositoryService, message_service: MessageServiceImpl) -> Self {
+        Self {
+            outbox,
+            message_service,
+            batch_size: 100,
+            poll_interval: Duration::from_secs(1),
+            lease: Duration::from_secs(30),
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(5 * 60),
+            retention: Duration::from_secs(7 * 24 * 60 * 60),
+            cleanup_interval: Duration::from_secs(60 * 60),
+        }
+    }
+
+    /// Sets how long claimed messages stay hidden from the other relays.
+    pub fn with_lease(mut self, lease: Duration) -> Self {
+        self.lease = lease;
+        self
+    }
+
+    /// Sets how long published messages are kept around before they're deleted.
+    pub fn with_retention(mut self, retention: Duration) -> Self {
+        self.retention = retention;
+        self
+    }
+
+    /// Sets how long to wait before retrying a failed message.
+    /// The wait doubles with every failed attempt, up to `max`.
+    pub fn with_backoff(mut self, initial: Duration, max: Duration) -> Self {
+        self.initial_backoff = initial;
+        self.max_backoff = max;
+        self
+    }
+
+    fn backoff(&self, attempts: i32) -> Duration {
+        self.initial_backoff
+            .saturating_mul(1 << attempts.clamp(0, 16))
+            .min(self.max_backoff)
+    }
+
+    /// Publishes a single batch of pending messages and returns how many of them got published.
+    #[tracing::instrument("[SERVICE] Relaying messages from the outbox", skip(self))]
+    pub async fn relay_pending(&self) -> eyre::Result<usize> {
+        let messages = self
+            .outbox
+            .claim_pending(self.batch_size, self.lease)
+            .await?;
+        let mut published = 0;
+        // Once a message fails, the later ones with the same key wait for it to be retried
+        let mut failed_keys = HashSet::new();
+
+        for message in messages {
+            if failed_keys.contains(&message.key) {
+                continue;
+            }
+
+            match self.message_service.publish(&message).await {
+                Ok(()) => {
+                    self.outbox.mark_published(&message.id).await?;
+                    published += 1;
+                }
+                Err(e) => {
+                    let retry_in = self.backoff(message.attempts);
+                    tracing::warn!(
+                        "Could not publish message {} to {}, retrying in {:?}: {}",
+                        message.id,
+                        message.topic,
+                        retry_in,
+                        e
+                    );
+                    self.outbox
+                        .mark_failed(&message.id, &e.to_string(), retry_in)
+                        .await?;
+                    failed_keys.insert(message.key);
+                }
+            }
+        }
+
+        Ok(published)
+    }
+
+    /// Deletes the messages which were published longer ago than the retention, and returns how many there were.
+    #[tracing::instrument("[SERVICE] Cleaning up published messages", skip(self))]
+    pub async fn clean_up(&self) -> eyre::Result<u64> {
+        self.outbox.delete_published(self.retention).await
+    }
+
+    /// Keeps relaying messages in the background, until the runtime shuts down.
+    pub fn spawn(self) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut next_cleanup = Instant::now();
+
+            loop {
+                if Instant::now() >= next_cleanup {
+                    if let Err(e) = self.clean_up().await {
+                        tracing::error!("Could not clean up published messages: {}", e);
+                    }
+                    next_cleanup = Instant::now() + self.cleanup_interval;
+                }
+
+                match self.relay_pending().await {
+                    // A full batch means there are probably more messages waiting
+                    Ok(published) if published as i64 == self.batch_size => continue,
+                    Ok(_) => {}
+                    Err(e) => tracing::error!("Could not relay messages from the outbox: {}", e),
+                }
+
+                tokio::time::sleep(self.poll_interval).await;
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,217 @@
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use common::events::EventType;
+use eyre::eyre;
+use mockall::Sequence;
+
+use crate::{
+    domain::{
+        repositories::{ingredients::IngredientRepository, outbox::OutboxRepository},
+        services::message::{relay::OutboxRelay, stub::StubMessageService, MockMessageService},
+    },
+    test_utils::{ingredient_fixture, substitute_fixture},
+};
+
+pub async fn pending_messages_get_published_once(
+    repo: impl IngredientRepository,
+    outbox: impl OutboxRepository,
+) {
+    repo.insert(ingredient_fixture()).await.unwrap();
+
+    let mut message_service = MockMessageService::new();
+    message_service
+        .expect_publish()
+        .withf(|m| m.topic == "deepdish.ingredient.added")
+        .times(1)
+        .returning(|_| Ok(()));
+
+    let relay = OutboxRelay::new(
+        Arc::new(Box::new(outbox)),
+        Arc::new(Box::new(message_service)),
+    );
+
+    assert_eq!(relay.relay_pending().await.unwrap(), 1);
+    assert_eq!(relay.relay_pending().await.unwrap(), 0);
+}
+
+pub async fn failed_messages_get_retried(
+    repo: impl IngredientRepository,
+    outbox: impl OutboxRepository,
+) {
+    repo.insert(ingredient_fixture()).await.unwrap();
+
+    let mut message_service = MockMessageService::new();
+    let mut seq = Sequence::new();
+    message_service
+        .expect_publish()
+        .times(1)
+        .in_sequence(&mut seq)
+        .returning(|_| Err(eyre!("Kafka is down")));
+    message_service
+        .expect_publish()
+        .withf(|m| m.attempts == 1)
+        .times(1)
+        .in_sequence(&mut seq)
+        .returning(|_| Ok(()));
+
+    let relay = OutboxRelay::new(
+        Arc::new(Box::new(outbox)),
+        Arc::new(Box::new(message_service)),
+    )
+    .with_backoff(Duration::ZERO, Duration::ZERO);
+
+    assert_eq!(relay.relay_pending().await.unwrap(), 0);
+    assert_eq!(relay.relay_pending().await.unwrap(), 1);
+}
+
+pub async fn claimed_messages_are_hidden_until_the_lease_expires(
+    repo: impl IngredientRepository,
+    outbox: impl OutboxRepository,
+) {
+    repo.insert(ingredient_fixture()).await.unwrap();
+
+    let claimed = outbox
+        .claim_pending(10, Duration::from_secs(60))
+        .await
+        .unwrap();
+    assert_eq!(claimed.len(), 1);
+
+    let claimed_again = outbox
+        .claim_pending(10, Duration::from_secs(60))
+        .await
+        .unwrap();
+    assert!(claimed_again.is_empty());
+}
+
+pub async fn published_messages_are_wrapped_in_an_envelope(
+    repo: impl IngredientRepository,
+    outbox: impl OutboxRepository,
+) {
+    let ingredient = repo.insert(ingredient_fixture()).await.unwrap();
+    repo.delete(ingredient).await.unwrap();
+
+    let message_service = StubMessageService::new();
+    let relay = OutboxRelay::new(
+        Arc::new(Box::new(outbox)),
+        Arc::new(Box::new(message_service.clone())),
+    );
+
+    assert_eq!(relay.relay_pending().await.unwrap(), 2);
+
+    let published = message_service.published().unwrap();
+    let event_types: Vec<_> = published.iter().map(|e| e.event_type()).collect();
+    assert_eq!(
+        event_types,
+        vec![EventType::IngredientAdded, EventType::IngredientDeleted]
+    );
+    assert!(published.iter().all(|e| e.schema_version == 1));
+    assert_ne!(published[0].event_id, published[1].event_id);
+}
+
+pub async fn messages_with_the_same_key_stay_in_order_when_one_fails(
+    repo: impl IngredientRepository,
+    outbox: impl OutboxRepository,
+) {
+    let ingredient = repo.insert(ingredient_fixture()).await.unwrap();
+    repo.delete(ingredient).await.unwrap();
+    repo.insert(substitute_fixture()).await.unwrap();
+
+    let published = Arc::new(Mutex::new(vec![]));
+    let mut message_service = MockMessageService::new();
+    message_service.expect_publish().returning({
+        let published = published.clone();
+        move |m| {
+            let event = m.envelope()?;
+            let key = m.key.contains(&ingredient_fixture().id.to_string());
+
+            if key && event.event_type() == EventType::IngredientAdded && m.attempts == 0 {
+                return Err(eyre!("Kafka is down"));
+            }
+
+            published.lock().unwrap().push((key, event.event_type()));
+            Ok(())
+        }
+    });
+
+    let relay = OutboxRelay::new(
+        Arc::new(Box::new(outbox)),
+        Arc::new(Box::new(message_service)),
+    )
+    .with_backoff(Duration::ZERO, Duration::ZERO)
+    .with_lease(Duration::ZERO);
+
+    assert_eq!(relay.relay_pending().await.unwrap(), 1);
+    assert_eq!(relay.relay_pending().await.unwrap(), 2);
+
+    // `true` marks the events about the ingredient whose first message failed
+    assert_eq!(
+        *published.lock().unwrap(),
+        vec![
+            (false, EventType::IngredientAdded),
+            (true, EventType::IngredientAdded),
+            (true, EventType::IngredientDeleted),
+        ]
+    );
+}
+
+pub async fn messages_wait_for_earlier_claimed_messages_with_the_same_key(
+    repo: impl IngredientRepository,
+    outbox: impl OutboxRepository,
+) {
+    let ingredient = repo.insert(ingredient_fixture()).await.unwrap();
+    repo.delete(ingredient).await.unwrap();
+
+    let claimed = outbox
+        .claim_pending(1, Duration::from_secs(60))
+        .await
+        .unwrap();
+    assert_eq!(claimed.len(), 1);
+
+    repo.insert(substitute_fixture()).await.unwrap();
+
+    let claimed_again = outbox
+        .claim_pending(10, Duration::from_secs(60))
+        .await
+        .unwrap();
+    let event_ids: Vec<_> = claimed_again
+        .iter()
+        .map(|m| m.envelope().unwrap().event_type())
+        .collect();
+
+    // Only the message about the other ingredient, the deletion waits for the first message
+    assert_eq!(event_ids, vec![EventType::IngredientAdded]);
+    assert!(claimed_again[0]
+        .key
+        .contains(&substitute_fixture().id.to_string()));
+}
+
+pub async fn published_messages_get_cleaned_up(
+    repo: impl IngredientRepository,
+    outbox: impl OutboxRepository,
+) {
+    let outbox: Arc<Box<dyn OutboxRepository>> = Arc::new(Box::new(outbox));
+    repo.insert(ingredient_fixture()).await.unwrap();
+
+    let relay = OutboxRelay::new(
+        outbox.clone(),
+        Arc::new(Box::new(StubMessageService::new())),
+    )
+    .with_retention(Duration::ZERO);
+
+    assert_eq!(relay.relay_pending().await.unwrap(), 1);
+
+    repo.insert(substitute_fixture()).await.unwrap();
+
+    assert_eq!(relay.clean_up().await.unwrap(), 1);
+    assert_eq!(relay.clean_up().await.unwrap(), 0);
+
+    // The message which wasn't published yet is left alone
+    let pending = outbox
+        .claim_pending(10, Duration::from_secs(60))
+        .await
+        .unwrap();
+    assert_eq!(pending.len(), 1);
+}
@@ -0,0 +1,118 @@
+mod __tests__;
+
+mod in_memory {
+    use super::__tests__;
+
+    use crate::domain::repositories::{
+        ingredients::in_memory::InMemoryIngredientRepository,
+        outbox::in_memory::InMemoryOutboxRepository,
+    };
+
+    #[tokio::test]
+    async fn pending_messages_get_published_once() {
+        let outbox = InMemoryOutboxRepository::new();
+        let repo = InMemoryIngredientRepository::new().with_outbox(outbox.clone());
+        __tests__::pending_messages_get_published_once(repo, outbox).await
+    }
+
+    #[tokio::test]
+    async fn failed_messages_get_retried() {
+        let outbox = InMemoryOutboxRepository::new();
+        let repo = InMemoryIngredientRepository::new().with_outbox(outbox.clone());
+        __tests__::failed_messages_get_retried(repo, outbox).await
+    }
+
+    #[tokio::test]
+    async fn claimed_messages_are_hidden_until_the_lease_expires() {
+        let outbox = InMemoryOutboxRepository::new();
+        let repo = InMemoryIngredientRepository::new().with_outbox(outbox.clone());
+        __tests__::claimed_messages_are_hidden_until_the_lease_expires(repo, outbox).await
+    }
+
+    #[tokio::test]
+    async fn published_messages_are_wrapped_in_an_envelope() {
+        let outbox = InMemoryOutboxRepository::new();
+        let repo = InMemoryIngredientRepository::new().with_outbox(outbox.clone());
+        __tests__::published_messages_are_wrapped_in_an_envelope(repo, outbox).await
+    }
+
+    #[tokio::test]
+    async fn messages_with_the_same_key_stay_in_order_when_one_fails() {
+        let outbox = InMemoryOutboxRepository::new();
+        let repo = InMemoryIngredientRepository::new().with_outbox(outbox.clone());
+        __tests__::messages_with_the_same_key_stay_in_order_when_one_fails(repo, outbox).await
+    }
+
+    #[tokio::test]
+    async fn messages_wait_for_earlier_claimed_messages_with_the_same_key() {
+        let outbox = InMemoryOutboxRepository::new();
+        let repo = InMemoryIngredientRepository::new().with_outbox(outbox.clone());
+        __tests__::messages_wait_for_earlier_claimed_messages_with_the_same_key(repo, outbox).await
+    }
+
+    #[tokio::test]
+    async fn published_messages_get_cleaned_up() {
+        let outbox = InMemoryOutboxRepository::new();
+        let repo = InMemoryIngredientRepository::new().with_outbox(outbox.clone());
+        __tests__::published_messages_get_cleaned_up(repo, outbox).await
+    }
+}
+
+mod sql {
+    use super::__tests__;
+    use sqlx::PgPool;
+
+    use crate::domain::repositories::{
+        ingredients::postgres::PostgresIngredientRepository,
+        outbox::postgres::PostgresOutboxRepository,
+    };
+
+    #[sqlx::test]
+    async fn pending_messages_get_published_once(pool: PgPool) {
+        let repo = PostgresIngredientRepository::new(pool.clone());
+        let outbox = PostgresOutboxRepository::new(pool);
+        __tests__::pending_messages_get_published_once(repo, outbox).await
+    }
+
+    #[sqlx::test]
+    async fn failed_messages_get_retried(pool: PgPool) {
+        let repo = PostgresIngredientRepository::new(pool.clone());
+        let outbox = PostgresOutboxRepository::new(pool);
+        __tests__::failed_messages_get_retried(repo, outbox).await
+    }
+
+    #[sqlx::test]
+    async fn claimed_messages_are_hidden_until_the_lease_expires(pool: PgPool) {
+        let repo = PostgresIngredientRepository::new(pool.clone());
+        let outbox = PostgresOutboxRepository::new(pool);
+        __tests__::claimed_messages_are_hidden_until_the_lease_expires(repo, outbox).await
+    }
+
+    #[sqlx::test]
+    async fn published_messages_are_wrapped_in_an_envelope(pool: PgPool) {
+        let repo = PostgresIngredientRepository::new(pool.clone());
+        let outbox = PostgresOutboxRepository::new(pool);
+        __tests__::published_messages_are_wrapped_in_an_envelope(repo, outbox).await
+    }
+
+    #[sqlx::test]
+    async fn messages_with_the_same_key_stay_in_order_when_one_fails(pool: PgPool) {
+        let repo = PostgresIngredientRepository::new(pool.clone());
+        let outbox = PostgresOutboxRepository::new(pool);
+        __tests__::messages_with_the_same_key_stay_in_order_when_one_fails(repo, outbox).await
+    }
+
+    #[sqlx::test]
+    async fn messages_wait_for_earlier_claimed_messages_with_the_same_key(pool: PgPool) {
+        let repo = PostgresIngredientRepository::new(pool.clone());
+        let outbox = PostgresOutboxRepository::new(pool);
+        __tests__::messages_wait_for_earlier_claimed_messages_with_the_same_key(repo, outbox).await
+    }
+
+    #[sqlx::test]
+    async fn published_messages_get_cleaned_up(pool: PgPool) {
+        let repo = PostgresIngredientRepository::new(pool.clone());
+        let outbox = PostgresOutboxRepository::new(pool);
+        __tests__::published_messages_get_cleaned_up(repo, outbox).await
+    }
+}
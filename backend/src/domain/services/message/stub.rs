@@ -1,20 +1,38 @@
+use std::sync::{Arc, Mutex};
+
 use async_trait::async_trait;
+use common::events::EventEnvelope;
+use eyre::eyre;
 
-use crate::domain::entities::ingredient::Ingredient;
+use crate::domain::entities::outbox::OutboxMessage;
 
 use super::MessageService;
 
-pub struct StubMessageService;
+/// Doesn't publish anything, only keeps the envelopes around so tests can look at them
+#[derive(Debug, Clone, Default)]
+pub struct StubMessageService(Arc<Mutex<Vec<EventEnvelope>>>);
 
-#[async_trait]
-impl MessageService for StubMessageService {
-    async fn ingredient_added(&self, _ing: &Ingredient) -> eyre::Result<()> {
-        Ok(())
+impl StubMessageService {
+    pub fn new() -> Self {
+        Self::default()
     }
-    async fn ingredient_deleted(&self, _ing: &Ingredient) -> eyre::Result<()> {
-        Ok(())
+
+    /// The envelopes published so far, oldest first
+    pub fn published(&self) -> eyre::Result<Vec<EventEnvelope>> {
+        let lock = self.0.lock().map_err(|e| eyre!("{e}"))?;
+
+        Ok(lock.clone())
     }
-    async fn ingredient_updated(&self, _old_ing: &Ingredient, _new_ing: &Ingredient) -> eyre::Result<()> {
+}
+
+#[async_trait]
+impl MessageService for StubMessageService {
+    async fn publish(&self, message: &OutboxMessage) -> eyre::Result<()> {
+        let envelope = message.envelope()?;
+        let mut lock = self.0.lock().map_err(|e| eyre!("{e}"))?;
+
+        lock.push(envelope);
+
         Ok(())
     }
 }
@@ -18,7 +18,16 @@ async fn main() -> Result<()> {
         app_builder = app_builder.with_postgres_database(PgPool::connect_lazy_with(db.with_db()));
     };
 
-    let app = app_builder.with_kafka("localhost:9092").build()?;
+    if let Some(messaging) = config.messaging {
+        app_builder = app_builder.with_messaging(messaging);
+    } else {
+        tracing::warn!("No messaging settings, events will not leave this process");
+    };
+
+    let app = app_builder
+        .with_redis_session_store(&config.session.get_redis_connection_string())
+        .with_session_key(config.session.key.clone())
+        .build()?;
 
     let listener = config.application.get_listener().await?;
     app.serve(listener).await?;
@@ -1,15 +1,26 @@
 use std::{collections::BTreeMap, time::Duration};
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
+use common::events::Event;
 use futures::future::join_all;
 
 use crate::domain::commands::recipes::update::UpdateRecipe;
+use crate::domain::entities::ingredient::nutrition::{IngredientNutrition, NutritionFacts};
+use crate::domain::entities::meal_plan::{MealPlan, MealSlot, PlannedMeal};
+use crate::domain::entities::pantry::PantryItem;
 use crate::domain::entities::recipe::{IngredientUnit, IngredientWithAmount, ServingsType};
+use crate::domain::entities::user::{types::PasswordHash, User};
 use crate::domain::entities::{
-    ingredient::{types::DietViolations, Ingredient},
+    ingredient::{
+        types::{Allergen, Allergens, DietViolations, WhichDiets},
+        Ingredient, IngredientSubstitute,
+    },
     recipe::Recipe,
 };
-use crate::domain::repositories::ingredients::IngredientRepository;
+use crate::domain::repositories::{
+    ingredients::IngredientRepository, outbox::OutboxRepository, recipe::RecipeRepository,
+    users::UserRepository,
+};
 
 pub fn ingredient_fixture() -> Ingredient {
     Ingredient {
@@ -17,6 +28,29 @@ pub fn ingredient_fixture() -> Ingredient {
         name: "Beef".try_into().unwrap(),
         description: "You killed a cow for it".try_into().unwrap(),
         diet_violations: vec![DietViolations::GlutenFree].into(),
+        parent_id: None,
+        allergens: Allergens::new(),
+    }
+}
+
+/// An ingredient which can stand in for the ingredient fixture.
+pub fn substitute_fixture() -> Ingredient {
+    Ingredient {
+        id: uuid::Uuid::from_u128(65),
+        name: "Seitan".try_into().unwrap(),
+        description: "Wheat gluten pretending to be meat".try_into().unwrap(),
+        diet_violations: WhichDiets::new(),
+        parent_id: None,
+        allergens: Allergens::new(),
+    }
+}
+
+pub fn user_fixture() -> User {
+    User {
+        id: uuid::Uuid::from_u128(32),
+        username: "testuser".try_into().unwrap(),
+        email: "testuser@example.com".try_into().unwrap(),
+        password: PasswordHash::from_password("meaningless").unwrap(),
     }
 }
 
@@ -33,6 +67,7 @@ pub fn recipe_fixture() -> Recipe {
             ("Cook time".to_string(), Duration::from_secs(10 * 60))
         ]),
         servings: ServingsType::Exact(4),
+        owner_id: None,
         ingredients: vec![
             IngredientWithAmount {
                 ingredient: Ingredient {
@@ -44,6 +79,8 @@ pub fn recipe_fixture() -> Recipe {
                         DietViolations::Vegetarian,
                         DietViolations::GlutenFree
                     ].into(),
+                    parent_id: None,
+                    allergens: vec![Allergen::Soy].into(),
                 },
                 amount: IngredientUnit::Grams(400.0),
                 notes: None,
@@ -59,6 +96,8 @@ pub fn recipe_fixture() -> Recipe {
                         DietViolations::Vegetarian,
                         DietViolations::GlutenFree
                     ].into(),
+                    parent_id: None,
+                    allergens: Allergens::new(),
                 },
                 amount: IngredientUnit::Other{unit: "head".to_string(), amount: 1.0},
                 notes: None,
@@ -74,6 +113,8 @@ pub fn recipe_fixture() -> Recipe {
                         DietViolations::Vegetarian,
                         DietViolations::GlutenFree
                     ].into(),
+                    parent_id: None,
+                    allergens: Allergens::new(),
                 },
                 amount: IngredientUnit::Other{ unit: "clove".to_string(), amount: 4.0 },
                 notes: None,
@@ -95,6 +136,59 @@ pub fn recipe_fixture() -> Recipe {
     }
 }
 
+/// A week of the fixture recipe, owned by the fixture user.
+pub fn meal_plan_fixture() -> MealPlan {
+    let starts_on = NaiveDate::from_ymd_opt(2024, 10, 28).unwrap();
+
+    MealPlan {
+        id: uuid::Uuid::from_u128(16),
+        owner_id: user_fixture().id,
+        starts_on,
+        meals: vec![
+            PlannedMeal {
+                date: starts_on,
+                slot: MealSlot::Dinner,
+                recipe_id: recipe_fixture().id,
+                servings: 2,
+            },
+            PlannedMeal {
+                date: starts_on + chrono::Days::new(2),
+                slot: MealSlot::Lunch,
+                recipe_id: recipe_fixture().id,
+                servings: 4,
+            },
+        ],
+        created_at: DateTime::<Utc>::from_timestamp_nanos(1_662_921_288_000_000_000),
+        updated_at: DateTime::<Utc>::from_timestamp_nanos(1_662_921_288_000_000_000),
+    }
+}
+
+/// Some of the tofu the fixture recipe needs, in the pantry of the fixture user.
+pub fn pantry_item_fixture() -> PantryItem {
+    PantryItem {
+        owner_id: user_fixture().id,
+        ingredient: recipe_fixture().ingredients[0].ingredient.clone(),
+        amount: IngredientUnit::Grams(250.0),
+        expires_on: None,
+    }
+}
+
+/// What 100 g of the tofu of the fixture recipe are made of.
+pub fn nutrition_fixture() -> IngredientNutrition {
+    IngredientNutrition {
+        per_100g: NutritionFacts {
+            energy_kcal: 144.0,
+            protein: 15.8,
+            fat: 8.7,
+            carbohydrates: 2.8,
+            fiber: 2.3,
+            sugar: 0.6,
+            salt: 0.03,
+        },
+        density: None,
+    }
+}
+
 pub fn recipe_changeset() -> UpdateRecipe {
     UpdateRecipe {
         name: Some("WE UPDATED THIS THING".to_string()),
@@ -127,3 +221,66 @@ pub async fn insert_all_ingredients(
     )
     .await;
 }
+
+/// Inserts the ingredient fixture, and the substitute fixture as its substitute.
+pub async fn insert_substitute_fixture(
+    ingredient_repo: &impl IngredientRepository,
+    note: Option<String>,
+) {
+    ingredient_repo.insert(ingredient_fixture()).await.unwrap();
+    ingredient_repo.insert(substitute_fixture()).await.unwrap();
+    ingredient_repo
+        .insert_substitute(
+            &ingredient_fixture(),
+            &IngredientSubstitute {
+                substitute: substitute_fixture(),
+                ratio: Default::default(),
+                note,
+            },
+        )
+        .await
+        .unwrap();
+}
+
+/// Inserts the owner and the recipe of the meal plan fixture, so that the plan can be stored.
+pub async fn insert_meal_plan_dependencies(
+    user_repo: &impl UserRepository,
+    recipe_repo: &impl RecipeRepository,
+    ingredient_repo: &impl IngredientRepository,
+) {
+    let recipe = recipe_fixture();
+
+    user_repo.insert(user_fixture()).await.unwrap();
+    insert_all_ingredients_of_recipe(ingredient_repo, &recipe).await;
+    recipe_repo.insert(recipe).await.unwrap();
+}
+
+/// Inserts the fixture user, and the ingredients of the fixture recipe which pantry items can be made of.
+pub async fn insert_pantry_dependencies(
+    user_repo: &impl UserRepository,
+    ingredient_repo: &impl IngredientRepository,
+) {
+    user_repo.insert(user_fixture()).await.unwrap();
+    insert_all_ingredients_of_recipe(ingredient_repo, &recipe_fixture()).await;
+}
+
+/// Inserts the fixture user, and hands them the fixture recipe, so that they're allowed to change it.
+pub async fn owned_recipe_fixture(user_repo: &impl UserRepository) -> Recipe {
+    let owner = user_repo.insert(user_fixture()).await.unwrap();
+
+    Recipe {
+        owner_id: Some(owner.id),
+        ..recipe_fixture()
+    }
+}
+
+/// The events of the messages waiting in the outbox, oldest first.
+pub async fn pending_events(outbox: &impl OutboxRepository) -> Vec<Event> {
+    outbox
+        .claim_pending(100, Duration::from_secs(60))
+        .await
+        .unwrap()
+        .iter()
+        .map(|message| message.envelope().unwrap().event)
+        .collect()
+}
@@ -1,3 +1,5 @@
+use std::collections::{BTreeMap, HashMap};
+
 use color_eyre::Result;
 use opentelemetry::propagation::TextMapCompositePropagator;
 use opentelemetry::trace::TracerProvider as _;
@@ -8,7 +10,7 @@ use opentelemetry_sdk::propagation::{BaggagePropagator, TraceContextPropagator};
 use opentelemetry_sdk::trace::SdkTracerProvider;
 use opentelemetry_sdk::Resource;
 use tracing::level_filters::LevelFilter;
-use tracing_opentelemetry::MetricsLayer;
+use tracing_opentelemetry::{MetricsLayer, OpenTelemetrySpanExt};
 use tracing_subscriber::{layer::SubscriberExt, Registry};
 use tracing_subscriber::{EnvFilter, Layer};
 
@@ -117,3 +119,16 @@ pub fn init_tracing() -> Result<()> {
 
     Ok(())
 }
+
+/// The trace context of the current span, in the form it would be sent over HTTP headers.
+/// Empty when there is no active trace.
+pub fn current_trace_context() -> BTreeMap<String, String> {
+    let context = tracing::Span::current().context();
+    let mut carrier = HashMap::new();
+
+    opentelemetry::global::get_text_map_propagator(|propagator| {
+        propagator.inject_context(&context, &mut carrier)
+    });
+
+    carrier.into_iter().collect()
+}
@@ -0,0 +1,96 @@
+use common::{CreateIngredientDTO, IngredientImportReportDTO};
+use reqwest::{Client, StatusCode};
+use serde_json::json;
+
+use crate::setup::TestApp;
+
+#[tokio::test]
+async fn importing_ingredients_from_json_reports_every_row() {
+    let app = TestApp::new().await;
+    let client = Client::new();
+
+    let response = client
+        .post(app.get_base("ingredient/bulk"))
+        .json(&json!([
+            { "name": "Tomato", "description": "Squishy", "diet_violations": ["gluten_free"] },
+            { "name": "Tomato", "description": "Squishy again" },
+            { "name": "", "description": "Nameless" },
+        ]))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let report: IngredientImportReportDTO = response.json().await.unwrap();
+
+    assert_eq!(
+        (report.created, report.conflicts, report.invalid),
+        (1, 1, 1)
+    );
+    assert_eq!(
+        report
+            .rows
+            .iter()
+            .map(|row| row.status.as_str())
+            .collect::<Vec<_>>(),
+        vec!["created", "conflict", "invalid"]
+    );
+}
+
+#[tokio::test]
+async fn exported_ingredients_can_be_imported_from_csv() {
+    let app = TestApp::new().await;
+    let client = Client::new();
+
+    let response = client
+        .post(app.get_base("ingredient/bulk"))
+        .header("Content-Type", "text/csv")
+        .body("name,description,diet_violations\nTomato,Squishy,gluten_free\nBeef,Not vegan,vegan;vegetarian\n")
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let report: IngredientImportReportDTO = response.json().await.unwrap();
+    assert_eq!(report.created, 2);
+
+    let response = client
+        .get(app.get_base("ingredient/export?format=json"))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let exported: Vec<CreateIngredientDTO> = response.json().await.unwrap();
+    assert_eq!(
+        exported.iter().map(|i| i.name.as_str()).collect::<Vec<_>>(),
+        vec!["Beef", "Tomato"]
+    );
+
+    let response = client
+        .get(app.get_base("ingredient/export?format=csv"))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(response
+        .text()
+        .await
+        .unwrap()
+        .contains("Beef,Not vegan,vegan;vegetarian,"));
+}
+
+#[tokio::test]
+async fn exporting_ingredients_in_an_unknown_format_fails() {
+    let app = TestApp::new().await;
+
+    let response = Client::new()
+        .get(app.get_base("ingredient/export?format=xml"))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
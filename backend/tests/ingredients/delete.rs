@@ -26,7 +26,12 @@ async fn deleting_works() {
 
     let ingredient_delete_path = app.get_base(&format!("ingredient/{}", ingredient.id));
 
-    let response = client.delete(ingredient_delete_path).send().await.unwrap();
+    let response = client
+        .delete(ingredient_delete_path)
+        .header("If-Match", "*")
+        .send()
+        .await
+        .unwrap();
 
     assert_eq!(response.status(), StatusCode::OK);
 }
@@ -38,7 +43,12 @@ async fn deleting_nonexistent_ingredient_errors() {
 
     let ingredient_delete_path = app.get_base(&format!("ingredient/{}", Uuid::nil()));
 
-    let response = client.delete(ingredient_delete_path).send().await.unwrap();
+    let response = client
+        .delete(ingredient_delete_path)
+        .header("If-Match", "*")
+        .send()
+        .await
+        .unwrap();
 
     assert_eq!(response.status(), StatusCode::NOT_FOUND);
 }
@@ -46,7 +56,7 @@ async fn deleting_nonexistent_ingredient_errors() {
 #[tokio::test]
 async fn deleting_ingredient_in_use_by_recipe_errors() {
     let app = TestApp::new().await;
-    let client = Client::new();
+    let client = app.authenticated_client("testuser").await;
     let ingredient_create_path = app.get_base("ingredient");
     let recipe_create_path = app.get_base("recipe");
 
@@ -73,7 +83,12 @@ async fn deleting_ingredient_in_use_by_recipe_errors() {
 
     let ingredient_delete_path = app.get_base(&format!("ingredient/{}", ingredient.id));
 
-    let response = client.delete(ingredient_delete_path).send().await.unwrap();
+    let response = client
+        .delete(ingredient_delete_path)
+        .header("If-Match", "*")
+        .send()
+        .await
+        .unwrap();
 
     assert_eq!(response.status(), StatusCode::CONFLICT);
 }
@@ -1,6 +1,6 @@
 use crate::setup::TestApp;
 use backend::domain::entities::ingredient::{types::DietViolations, IngredientModel};
-use common::IngredientDTO;
+use common::{IngredientDTO, PagedDTO};
 use uuid::Uuid;
 
 #[tokio::test]
@@ -13,9 +13,10 @@ async fn getting_all_with_empty_database_returns_empty_array() {
 
     assert_eq!(request.status(), 200);
 
-    let body = request.json::<Vec<()>>().await.unwrap();
+    let body = request.json::<PagedDTO<IngredientDTO>>().await.unwrap();
 
-    assert_eq!(body, vec![]);
+    assert_eq!(body.items, vec![]);
+    assert_eq!(body.next_cursor, None);
 }
 
 #[tokio::test]
@@ -30,6 +31,8 @@ async fn getting_all_with_full_database_returns_in_array() {
             DietViolations::Vegan.to_string(),
             DietViolations::Vegetarian.to_string(),
         ],
+        parent_id: None,
+        allergens: vec![],
     }];
 
     let tx = app.db.begin().await.unwrap();
@@ -64,8 +67,8 @@ async fn getting_all_with_full_database_returns_in_array() {
 
     assert_eq!(request.status(), 200);
 
-    let body = request.json::<Vec<IngredientDTO>>().await.unwrap();
+    let body = request.json::<PagedDTO<IngredientDTO>>().await.unwrap();
     let check: Vec<IngredientDTO> = ingredients.into_iter().map(|i| i.into()).collect();
 
-    assert_eq!(body, check);
+    assert_eq!(body.items, check);
 }
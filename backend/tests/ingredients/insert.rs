@@ -34,6 +34,9 @@ async fn inserting_ingredient_succeeds() {
             "vegetarian".to_string(),
             "gluten_free".to_string(),
         ],
+        substitutes: None,
+        parent_id: None,
+        allergens: vec![],
     };
 
     assert_eq!(body.name, expected_body.name);
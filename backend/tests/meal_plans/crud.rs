@@ -0,0 +1,174 @@
+use common::{ingredients::IngredientDTO, MealPlanDTO, RecipeDTO};
+use pretty_assertions::assert_eq;
+use reqwest::{Client, StatusCode};
+
+use crate::{
+    fixtures::{ingredient::ingredient_fixture, recipe::recipe_fixture},
+    setup::TestApp,
+};
+
+#[tokio::test]
+async fn meal_plans_can_be_created_changed_and_deleted() {
+    let app = TestApp::new().await;
+    let client = app.authenticated_client("testuser").await;
+
+    let ingredient: IngredientDTO = client
+        .post(app.get_base("ingredient"))
+        .json(&ingredient_fixture())
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let recipe: RecipeDTO = client
+        .post(app.get_base("recipe"))
+        .json(&recipe_fixture(std::slice::from_ref(&ingredient)))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let result = client
+        .post(app.get_base("meal-plan"))
+        .json(&serde_json::json!({
+            "starts_on": "2024-10-28",
+            "meals": [
+                { "date": "2024-10-29", "slot": "dinner", "recipe_id": recipe.id, "servings": 2 },
+                { "date": "2024-10-28", "slot": "lunch", "recipe_id": recipe.id, "servings": 1 },
+            ]
+        }))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(result.status(), StatusCode::CREATED);
+
+    let plan: MealPlanDTO = result.json().await.unwrap();
+
+    assert_eq!(plan.ends_on.to_string(), "2024-11-03");
+    assert_eq!(plan.meals.len(), 2);
+    assert_eq!(plan.meals[0].date.to_string(), "2024-10-28");
+
+    let plans: Vec<MealPlanDTO> = client
+        .get(app.get_base("meal-plan"))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    assert_eq!(plans.len(), 1);
+    assert_eq!(plans[0].id, plan.id);
+
+    let result = client
+        .put(app.get_base(&format!("meal-plan/{}", plan.id)))
+        .json(&serde_json::json!({
+            "meals": [
+                { "date": "2024-11-03", "slot": "breakfast", "recipe_id": recipe.id, "servings": 4 },
+            ]
+        }))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(result.status(), StatusCode::OK);
+
+    let result: MealPlanDTO = client
+        .get(app.get_base(&format!("meal-plan/{}", plan.id)))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    assert_eq!(result.meals.len(), 1);
+    assert_eq!(result.meals[0].servings, 4);
+
+    let result = client
+        .delete(app.get_base(&format!("meal-plan/{}", plan.id)))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(result.status(), StatusCode::OK);
+
+    let result = client
+        .get(app.get_base(&format!("meal-plan/{}", plan.id)))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(result.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn meal_plans_are_private() {
+    let app = TestApp::new().await;
+    let owner = app.authenticated_client("testuser").await;
+    let someone_else = app.authenticated_client("otheruser").await;
+
+    let plan: MealPlanDTO = owner
+        .post(app.get_base("meal-plan"))
+        .json(&serde_json::json!({ "starts_on": "2024-10-28", "meals": [] }))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let result = someone_else
+        .get(app.get_base(&format!("meal-plan/{}", plan.id)))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(result.status(), StatusCode::FORBIDDEN);
+
+    let result = someone_else
+        .delete(app.get_base(&format!("meal-plan/{}", plan.id)))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(result.status(), StatusCode::FORBIDDEN);
+
+    let result = Client::new()
+        .get(app.get_base("meal-plan"))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(result.status(), StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn meals_outside_of_the_week_are_rejected() {
+    let app = TestApp::new().await;
+    let client = app.authenticated_client("testuser").await;
+
+    let result = client
+        .post(app.get_base("meal-plan"))
+        .json(&serde_json::json!({
+            "starts_on": "2024-10-28",
+            "meals": [
+                {
+                    "date": "2024-11-04",
+                    "slot": "dinner",
+                    "recipe_id": uuid::Uuid::nil(),
+                    "servings": 2
+                },
+            ]
+        }))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(result.status(), StatusCode::BAD_REQUEST);
+}
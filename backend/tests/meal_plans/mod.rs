@@ -0,0 +1,2 @@
+mod crud;
+mod shopping_list;
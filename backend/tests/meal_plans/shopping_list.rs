@@ -0,0 +1,63 @@
+use common::{ingredients::IngredientDTO, MealPlanDTO, RecipeDTO, ShoppingListDTO};
+use pretty_assertions::assert_eq;
+use reqwest::StatusCode;
+
+use crate::{
+    fixtures::{ingredient::ingredient_fixture, recipe::recipe_fixture},
+    setup::TestApp,
+};
+
+#[tokio::test]
+async fn shopping_list_of_a_meal_plan_adds_up_its_meals() {
+    let app = TestApp::new().await;
+    let client = app.authenticated_client("testuser").await;
+
+    let ingredient: IngredientDTO = client
+        .post(app.get_base("ingredient"))
+        .json(&ingredient_fixture())
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let recipe: RecipeDTO = client
+        .post(app.get_base("recipe"))
+        .json(&recipe_fixture(std::slice::from_ref(&ingredient)))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let plan: MealPlanDTO = client
+        .post(app.get_base("meal-plan"))
+        .json(&serde_json::json!({
+            "starts_on": "2024-10-28",
+            "meals": [
+                { "date": "2024-10-28", "slot": "dinner", "recipe_id": recipe.id, "servings": 2 },
+                { "date": "2024-10-30", "slot": "lunch", "recipe_id": recipe.id, "servings": 1 },
+            ]
+        }))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let result = client
+        .get(app.get_base(&format!("meal-plan/{}/shopping-list", plan.id)))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(result.status(), StatusCode::OK);
+
+    let result: ShoppingListDTO = result.json().await.unwrap();
+
+    // The fixture serves 1, with 100 g of the ingredient
+    assert_eq!(result.text, format!("- {}: 300 g", ingredient.name));
+}
@@ -1,4 +1,8 @@
+#![allow(clippy::cloned_ref_to_slice_refs, clippy::useless_borrows_in_formatting)]
+
 pub mod fixtures;
 mod ingredients;
+mod meal_plans;
+mod pantry;
 mod recipes;
 mod setup;
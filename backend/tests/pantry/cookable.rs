@@ -0,0 +1,63 @@
+use common::{ingredients::IngredientDTO, CookableRecipeDTO, IngredientUnitDTO, RecipeDTO};
+use pretty_assertions::assert_eq;
+use reqwest::StatusCode;
+
+use crate::{
+    fixtures::{ingredient::ingredient_fixture, recipe::recipe_fixture},
+    setup::TestApp,
+};
+
+#[tokio::test]
+async fn cookable_recipes_list_what_is_missing_from_the_pantry() {
+    let app = TestApp::new().await;
+    let client = app.authenticated_client("testuser").await;
+
+    let ingredient: IngredientDTO = client
+        .post(app.get_base("ingredient"))
+        .json(&ingredient_fixture())
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let recipe: RecipeDTO = client
+        .post(app.get_base("recipe"))
+        .json(&recipe_fixture(std::slice::from_ref(&ingredient)))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    // The recipe needs 100 g
+    let result = client
+        .post(app.get_base("pantry"))
+        .json(&serde_json::json!({
+            "ingredient_id": ingredient.id,
+            "amount": { "_type": "grams", "amount": 40.0 },
+        }))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(result.status(), StatusCode::CREATED);
+
+    let result = client
+        .get(app.get_base("recipe/cookable"))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(result.status(), StatusCode::OK);
+
+    let recipes: Vec<CookableRecipeDTO> = result.json().await.unwrap();
+
+    assert_eq!(recipes.len(), 1);
+    assert_eq!(recipes[0].recipe.id, recipe.id);
+    assert_eq!(recipes[0].coverage, 0.0);
+    assert_eq!(recipes[0].missing.len(), 1);
+    assert_eq!(recipes[0].missing[0].amount, IngredientUnitDTO::Grams(60.0));
+}
@@ -0,0 +1,104 @@
+use common::{ingredients::IngredientDTO, IngredientUnitDTO, PantryItemDTO};
+use pretty_assertions::assert_eq;
+use reqwest::{Client, StatusCode};
+
+use crate::{fixtures::ingredient::ingredient_fixture, setup::TestApp};
+
+#[tokio::test]
+async fn pantry_items_can_be_added_changed_and_removed() {
+    let app = TestApp::new().await;
+    let client = app.authenticated_client("testuser").await;
+
+    let ingredient: IngredientDTO = client
+        .post(app.get_base("ingredient"))
+        .json(&ingredient_fixture())
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let result = client
+        .post(app.get_base("pantry"))
+        .json(&serde_json::json!({
+            "ingredient_id": ingredient.id,
+            "amount": { "_type": "grams", "amount": 500.0 },
+            "expires_on": "2024-11-10",
+        }))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(result.status(), StatusCode::CREATED);
+
+    let item: PantryItemDTO = result.json().await.unwrap();
+
+    assert_eq!(item.ingredient.id, ingredient.id);
+    assert_eq!(item.amount, IngredientUnitDTO::Grams(500.0));
+
+    let result = client
+        .post(app.get_base("pantry"))
+        .json(&serde_json::json!({
+            "ingredient_id": ingredient.id,
+            "amount": { "_type": "grams", "amount": 100.0 },
+        }))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(result.status(), StatusCode::CONFLICT);
+
+    let result = client
+        .put(app.get_base(&format!("pantry/{}", ingredient.id)))
+        .json(&serde_json::json!({
+            "amount": { "_type": "grams", "amount": 200.0 },
+        }))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(result.status(), StatusCode::OK);
+
+    let pantry: Vec<PantryItemDTO> = client
+        .get(app.get_base("pantry"))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    assert_eq!(pantry.len(), 1);
+    assert_eq!(pantry[0].amount, IngredientUnitDTO::Grams(200.0));
+    assert_eq!(pantry[0].expires_on, None);
+
+    let result = client
+        .delete(app.get_base(&format!("pantry/{}", ingredient.id)))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(result.status(), StatusCode::OK);
+
+    let result = client
+        .delete(app.get_base(&format!("pantry/{}", ingredient.id)))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(result.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn the_pantry_needs_a_login() {
+    let app = TestApp::new().await;
+
+    let result = Client::new()
+        .get(app.get_base("pantry"))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(result.status(), StatusCode::UNAUTHORIZED);
+}
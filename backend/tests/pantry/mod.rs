@@ -0,0 +1,2 @@
+mod cookable;
+mod crud;
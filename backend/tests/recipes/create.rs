@@ -2,7 +2,7 @@ use assert_json_diff::assert_json_include;
 use common::{ingredients::IngredientDTO, RecipeDTO};
 use futures::future::join_all;
 use pretty_assertions::assert_eq;
-use reqwest::{Client, StatusCode};
+use reqwest::StatusCode;
 
 use crate::{
     fixtures::{
@@ -15,7 +15,7 @@ use crate::{
 #[tokio::test]
 async fn inserts_recipe_correctly() {
     let app = TestApp::new().await;
-    let client = Client::new();
+    let client = app.authenticated_client("testuser").await;
     let ingredient_create_path = app.get_base("ingredient");
     let recipe_create_path = app.get_base("recipe");
 
@@ -74,7 +74,7 @@ async fn inserts_recipe_correctly() {
 #[tokio::test]
 async fn inserting_recipe_with_multiple_ingredients_generates_diet_violations_correctly() {
     let app = TestApp::new().await;
-    let client = Client::new();
+    let client = app.authenticated_client("testuser").await;
     let ingredient_create_path = app.get_base("ingredient");
     let recipe_create_path = app.get_base("recipe");
 
@@ -133,7 +133,7 @@ async fn inserting_recipe_with_multiple_ingredients_generates_diet_violations_co
 #[tokio::test]
 async fn inserting_recipe_with_incorrect_ingredients_fails() {
     let app = TestApp::new().await;
-    let client = Client::new();
+    let client = app.authenticated_client("testuser").await;
     let recipe_create_path = app.get_base("recipe");
 
     let data = serde_json::json!({
@@ -178,7 +178,7 @@ async fn inserting_recipe_with_incorrect_ingredients_fails() {
 #[tokio::test]
 async fn inserting_recipe_with_partially_incorrect_ingredients() {
     let app = TestApp::new().await;
-    let client = Client::new();
+    let client = app.authenticated_client("testuser").await;
     let recipe_create_path = app.get_base("recipe");
     let ingredient_create_path = app.get_base("ingredient");
 
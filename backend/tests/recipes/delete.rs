@@ -1,6 +1,6 @@
 use common::{IngredientDTO, RecipeDTO};
 use futures::future::join_all;
-use reqwest::{Client, StatusCode};
+use reqwest::StatusCode;
 
 use crate::{
     fixtures::{ingredient::ingredient_fixture, recipe::recipe_fixture},
@@ -10,7 +10,7 @@ use crate::{
 #[tokio::test]
 async fn deleting_a_recipe_works() {
     let app = TestApp::new().await;
-    let client = Client::new();
+    let client = app.authenticated_client("testuser").await;
     let ingredient_create_path = app.get_base("ingredient");
     let recipe_create_path = app.get_base("recipe");
 
@@ -43,7 +43,12 @@ async fn deleting_a_recipe_works() {
         .unwrap();
 
     let recipe_delete_path = app.get_base(&format!("recipe/{}", inserted_recipe.id));
-    let result = client.delete(recipe_delete_path).send().await.unwrap();
+    let result = client
+        .delete(recipe_delete_path)
+        .header("If-Match", "*")
+        .send()
+        .await
+        .unwrap();
 
     assert_eq!(result.status(), StatusCode::OK)
 }
@@ -51,10 +56,65 @@ async fn deleting_a_recipe_works() {
 #[tokio::test]
 async fn deleting_a_nonexistent_recipe_errors() {
     let app = TestApp::new().await;
-    let client = Client::new();
+    let client = app.authenticated_client("testuser").await;
 
     let recipe_delete_path = app.get_base(&format!("recipe/{}", uuid::Uuid::nil()));
-    let result = client.delete(recipe_delete_path).send().await.unwrap();
+    let result = client
+        .delete(recipe_delete_path)
+        .header("If-Match", "*")
+        .send()
+        .await
+        .unwrap();
 
     assert_eq!(result.status(), StatusCode::NOT_FOUND)
 }
+
+#[tokio::test]
+async fn deleting_someone_elses_recipe_is_forbidden() {
+    let app = TestApp::new().await;
+    let owner = app.authenticated_client("owner").await;
+    let intruder = app.authenticated_client("intruder").await;
+    let ingredient_create_path = app.get_base("ingredient");
+    let recipe_create_path = app.get_base("recipe");
+
+    let ingredient: IngredientDTO = owner
+        .post(&ingredient_create_path)
+        .json(&ingredient_fixture())
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let data = recipe_fixture(std::slice::from_ref(&ingredient));
+
+    let inserted_recipe: RecipeDTO = owner
+        .post(&recipe_create_path)
+        .json(&data)
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let recipe_delete_path = app.get_base(&format!("recipe/{}", inserted_recipe.id));
+    let result = intruder
+        .delete(&recipe_delete_path)
+        .header("If-Match", "*")
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(result.status(), StatusCode::FORBIDDEN);
+
+    let result = owner
+        .delete(&recipe_delete_path)
+        .header("If-Match", "*")
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(result.status(), StatusCode::OK);
+}
@@ -2,7 +2,7 @@ use assert_json_diff::assert_json_include;
 use common::{ingredients::IngredientDTO, RecipeDTO};
 use futures::future::join_all;
 use pretty_assertions::assert_eq;
-use reqwest::{Client, StatusCode};
+use reqwest::StatusCode;
 use uuid::Uuid;
 
 use crate::{
@@ -13,7 +13,7 @@ use crate::{
 #[tokio::test]
 async fn getting_recipe_by_id_works_correctly() {
     let app = TestApp::new().await;
-    let client = Client::new();
+    let client = app.authenticated_client("testuser").await;
     let ingredient_create_path = app.get_base("ingredient");
     let recipe_create_path = app.get_base("recipe");
 
@@ -81,7 +81,7 @@ async fn getting_recipe_by_id_works_correctly() {
 #[tokio::test]
 async fn getting_nonexistent_recipe_fails() {
     let app = TestApp::new().await;
-    let client = Client::new();
+    let client = app.authenticated_client("testuser").await;
 
     let get_recipe_by_id_route = app.get_base(&format!("recipe/{}", Uuid::nil()));
 
@@ -96,3 +96,83 @@ async fn getting_nonexistent_recipe_fails() {
 
     assert_eq!(body.kind, "NotFound");
 }
+
+#[tokio::test]
+async fn getting_recipe_with_unknown_unit_system_fails() {
+    let app = TestApp::new().await;
+    let client = app.authenticated_client("testuser").await;
+
+    let get_recipe_by_id_route = app.get_base(&format!("recipe/{}", Uuid::nil()));
+
+    let result = client
+        .get(get_recipe_by_id_route)
+        .query(&[("units", "imperial")])
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(result.status(), StatusCode::BAD_REQUEST);
+
+    let body = result
+        .json::<common::error::ErrorMessageWithJsonValue>()
+        .await
+        .unwrap();
+
+    assert_eq!(body.kind, "InvalidUnitSystem");
+}
+
+#[tokio::test]
+async fn exporting_recipe_as_markdown_works() {
+    let app = TestApp::new().await;
+    let client = app.authenticated_client("testuser").await;
+
+    let ingredient: IngredientDTO = client
+        .post(app.get_base("ingredient"))
+        .json(&ingredient_fixture())
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let recipe: RecipeDTO = client
+        .post(app.get_base("recipe"))
+        .json(&recipe_fixture(&[ingredient]))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let response = client
+        .get(app.get_base(&format!("recipe/{}/export?format=markdown", recipe.id)))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.headers()["content-type"],
+        "text/markdown; charset=utf-8"
+    );
+
+    let document = response.text().await.unwrap();
+    assert!(document.starts_with("# A diced cucumber\n"));
+    assert!(document.contains("- 100 g Cucumber\n"));
+}
+
+#[tokio::test]
+async fn exporting_recipe_in_unknown_format_fails() {
+    let app = TestApp::new().await;
+    let client = app.authenticated_client("testuser").await;
+
+    let result = client
+        .get(app.get_base(&format!("recipe/{}/export?format=pdf", Uuid::nil())))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(result.status(), StatusCode::BAD_REQUEST);
+}
@@ -0,0 +1,127 @@
+use common::{ingredients::IngredientDTO, ImportedRecipeDTO};
+use pretty_assertions::assert_eq;
+use reqwest::StatusCode;
+
+use crate::{fixtures::ingredient::ingredient_fixture, setup::TestApp};
+
+#[tokio::test]
+async fn imports_recipe_from_html_page() {
+    let app = TestApp::new().await;
+    let client = app.authenticated_client("testuser").await;
+
+    let cucumber: IngredientDTO = client
+        .post(app.get_base("ingredient"))
+        .json(&ingredient_fixture())
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let page = format!(
+        r#"<html><head><script type="application/ld+json">{{
+            "@context": "https://schema.org",
+            "@type": "Recipe",
+            "name": "A diced cucumber",
+            "recipeYield": "2 servings",
+            "prepTime": "PT10M",
+            "recipeIngredient": ["1 {}, diced", "1 tsp salt", "pepper to taste"],
+            "recipeInstructions": [{{ "@type": "HowToStep", "text": "Dice the cucumber" }}]
+        }}</script></head></html>"#,
+        cucumber.name
+    );
+
+    let response = client
+        .post(app.get_base("recipe/import/jsonld"))
+        .header("Content-Type", "text/html")
+        .body(page)
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    let result: ImportedRecipeDTO = response.json().await.unwrap();
+
+    assert_eq!(result.recipe.name, "A diced cucumber");
+    assert_eq!(result.recipe.steps, vec!["Dice the cucumber"]);
+    assert_eq!(result.recipe.ingredients.len(), 2);
+    assert!(result
+        .recipe
+        .ingredients
+        .iter()
+        .any(|i| i.ingredient.id == cucumber.id && i.notes.as_deref() == Some("diced")));
+    assert_eq!(result.unparsed_ingredients, vec!["pepper to taste"]);
+}
+
+#[tokio::test]
+async fn importing_a_document_without_a_recipe_fails() {
+    let app = TestApp::new().await;
+    let client = app.authenticated_client("testuser").await;
+
+    let response = client
+        .post(app.get_base("recipe/import/jsonld"))
+        .body(r#"{ "@type": "WebPage" }"#)
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn imports_recipe_from_cooklang() {
+    let app = TestApp::new().await;
+    let client = app.authenticated_client("testuser").await;
+
+    let cucumber: IngredientDTO = client
+        .post(app.get_base("ingredient"))
+        .json(&ingredient_fixture())
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let recipe = format!(
+        ">> title: A diced cucumber\n>> servings: 2\n\nDice the @{}{{1}}(diced) and season with @salt{{1%tsp}} and @pepper.",
+        cucumber.name
+    );
+
+    let response = client
+        .post(app.get_base("recipe/import/cooklang"))
+        .body(recipe)
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    let result: ImportedRecipeDTO = response.json().await.unwrap();
+
+    assert_eq!(result.recipe.name, "A diced cucumber");
+    assert_eq!(result.recipe.ingredients.len(), 2);
+    assert!(result
+        .recipe
+        .ingredients
+        .iter()
+        .any(|i| i.ingredient.id == cucumber.id && i.notes.as_deref() == Some("diced")));
+    assert_eq!(result.unparsed_ingredients, vec!["@pepper"]);
+}
+
+#[tokio::test]
+async fn importing_cooklang_without_a_title_fails() {
+    let app = TestApp::new().await;
+    let client = app.authenticated_client("testuser").await;
+
+    let response = client
+        .post(app.get_base("recipe/import/cooklang"))
+        .body("Boil @water{1%l}.")
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
@@ -3,7 +3,7 @@ use common::{
 };
 use futures::future::join_all;
 use pretty_assertions::assert_eq;
-use reqwest::{Client, StatusCode};
+use reqwest::StatusCode;
 use uuid::Uuid;
 
 use crate::{
@@ -17,7 +17,7 @@ use crate::{
 #[tokio::test]
 async fn adding_an_ingredient_to_a_recipe_works() {
     let app = TestApp::new().await;
-    let client = Client::new();
+    let client = app.authenticated_client("testuser").await;
     let ingredient_create_path = app.get_base("ingredient");
     let recipe_create_path = app.get_base("recipe");
 
@@ -67,6 +67,7 @@ async fn adding_an_ingredient_to_a_recipe_works() {
 
     let result = client
         .post(&ingredient_add_path)
+        .header("If-Match", "*")
         .json(&ingredient_to_add)
         .send()
         .await
@@ -89,7 +90,7 @@ async fn adding_an_ingredient_to_a_recipe_works() {
 #[tokio::test]
 async fn adding_a_nonexistent_ingredient_to_a_recipe_fails() {
     let app = TestApp::new().await;
-    let client = Client::new();
+    let client = app.authenticated_client("testuser").await;
     let ingredient_create_path = app.get_base("ingredient");
     let recipe_create_path = app.get_base("recipe");
 
@@ -138,6 +139,7 @@ async fn adding_a_nonexistent_ingredient_to_a_recipe_fails() {
 
     let result = client
         .post(&ingredient_add_path)
+        .header("If-Match", "*")
         .json(&ingredient_to_add)
         .send()
         .await
@@ -149,7 +151,7 @@ async fn adding_a_nonexistent_ingredient_to_a_recipe_fails() {
 #[tokio::test]
 async fn adding_an_ingredient_to_a_nonexistent_recipe_fails() {
     let app = TestApp::new().await;
-    let client = Client::new();
+    let client = app.authenticated_client("testuser").await;
     let ingredient_create_path = app.get_base("ingredient");
 
     let ingredients_to_create = [ingredient_fixture()];
@@ -183,6 +185,7 @@ async fn adding_an_ingredient_to_a_nonexistent_recipe_fails() {
 
     let result = client
         .post(&ingredient_add_path)
+        .header("If-Match", "*")
         .json(&ingredient_to_add)
         .send()
         .await
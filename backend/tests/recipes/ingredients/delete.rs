@@ -1,7 +1,7 @@
 use common::{error::ErrorMessage, IngredientDTO, RecipeDTO};
 use futures::future::join_all;
 use pretty_assertions::assert_eq;
-use reqwest::{Client, StatusCode};
+use reqwest::StatusCode;
 use uuid::Uuid;
 
 use crate::{
@@ -15,7 +15,7 @@ use crate::{
 #[tokio::test]
 pub async fn deleting_an_existing_ingredient_works() {
     let app = TestApp::new().await;
-    let client = Client::new();
+    let client = app.authenticated_client("testuser").await;
     let ingredient_create_path = app.get_base("ingredient");
     let recipe_create_path = app.get_base("recipe");
 
@@ -57,7 +57,12 @@ pub async fn deleting_an_existing_ingredient_works() {
         app.get_base(&format!("recipe/{}/ingredient/{}", result.id, meat.id));
     let get_recipe_path = app.get_base(&format!("recipe/{}", result.id));
 
-    let result = client.delete(&ingredient_delete_path).send().await.unwrap();
+    let result = client
+        .delete(&ingredient_delete_path)
+        .header("If-Match", "*")
+        .send()
+        .await
+        .unwrap();
 
     assert_eq!(result.status(), StatusCode::OK);
 
@@ -76,7 +81,7 @@ pub async fn deleting_an_existing_ingredient_works() {
 #[tokio::test]
 pub async fn deleting_an_ingredient_that_doesnt_appear_in_recipe_errors() {
     let app = TestApp::new().await;
-    let client = Client::new();
+    let client = app.authenticated_client("testuser").await;
     let ingredient_create_path = app.get_base("ingredient");
     let recipe_create_path = app.get_base("recipe");
 
@@ -121,7 +126,12 @@ pub async fn deleting_an_ingredient_that_doesnt_appear_in_recipe_errors() {
     let ingredient_delete_path =
         app.get_base(&format!("recipe/{}/ingredient/{}", result.id, evil.id));
 
-    let result = client.delete(&ingredient_delete_path).send().await.unwrap();
+    let result = client
+        .delete(&ingredient_delete_path)
+        .header("If-Match", "*")
+        .send()
+        .await
+        .unwrap();
 
     assert_eq!(result.status(), StatusCode::BAD_REQUEST);
 
@@ -133,7 +143,7 @@ pub async fn deleting_an_ingredient_that_doesnt_appear_in_recipe_errors() {
 #[tokio::test]
 pub async fn deleting_an_ingredient_in_recipe_that_doesnt_exist_errors() {
     let app = TestApp::new().await;
-    let client = Client::new();
+    let client = app.authenticated_client("testuser").await;
     let ingredient_create_path = app.get_base("ingredient");
 
     let ingredients_to_create = [
@@ -163,7 +173,12 @@ pub async fn deleting_an_ingredient_in_recipe_that_doesnt_exist_errors() {
     let ingredient_delete_path =
         app.get_base(&format!("recipe/{}/ingredient/{}", Uuid::nil(), veg.id));
 
-    let result = client.delete(&ingredient_delete_path).send().await.unwrap();
+    let result = client
+        .delete(&ingredient_delete_path)
+        .header("If-Match", "*")
+        .send()
+        .await
+        .unwrap();
 
     assert_eq!(result.status(), StatusCode::NOT_FOUND);
 
@@ -175,7 +190,7 @@ pub async fn deleting_an_ingredient_in_recipe_that_doesnt_exist_errors() {
 #[tokio::test]
 pub async fn deleting_the_last_ingredient_in_recipe_errors() {
     let app = TestApp::new().await;
-    let client = Client::new();
+    let client = app.authenticated_client("testuser").await;
     let ingredient_create_path = app.get_base("ingredient");
     let recipe_create_path = app.get_base("recipe");
 
@@ -216,7 +231,12 @@ pub async fn deleting_the_last_ingredient_in_recipe_errors() {
     let ingredient_delete_path =
         app.get_base(&format!("recipe/{}/ingredient/{}", result.id, veg.id));
 
-    let result = client.delete(&ingredient_delete_path).send().await.unwrap();
+    let result = client
+        .delete(&ingredient_delete_path)
+        .header("If-Match", "*")
+        .send()
+        .await
+        .unwrap();
 
     assert_eq!(result.status(), StatusCode::UNPROCESSABLE_ENTITY);
 
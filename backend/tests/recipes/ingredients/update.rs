@@ -1,7 +1,7 @@
 use assert_json_diff::assert_json_include;
 use common::{error::ErrorMessage, IngredientDTO, IngredientUnitDTO, RecipeDTO};
 use futures::future::join_all;
-use reqwest::{Client, StatusCode};
+use reqwest::StatusCode;
 use uuid::Uuid;
 
 use crate::{
@@ -15,7 +15,7 @@ use crate::{
 #[tokio::test]
 async fn updating_an_ingredient_in_a_recipe_works() {
     let app = TestApp::new().await;
-    let client = Client::new();
+    let client = app.authenticated_client("testuser").await;
     let ingredient_create_path = app.get_base("ingredient");
     let recipe_create_path = app.get_base("recipe");
 
@@ -53,6 +53,7 @@ async fn updating_an_ingredient_in_a_recipe_works() {
 
     let result = client
         .put(&ingredient_update_path)
+        .header("If-Match", "*")
         .json(&ingredient_to_add)
         .send()
         .await
@@ -84,7 +85,7 @@ async fn updating_an_ingredient_in_a_recipe_works() {
 #[tokio::test]
 async fn updating_a_nonexistent_ingredient_in_a_recipe_errors() {
     let app = TestApp::new().await;
-    let client = Client::new();
+    let client = app.authenticated_client("testuser").await;
     let ingredient_create_path = app.get_base("ingredient");
     let recipe_create_path = app.get_base("recipe");
 
@@ -129,6 +130,7 @@ async fn updating_a_nonexistent_ingredient_in_a_recipe_errors() {
 
     let result = client
         .put(&ingredient_update_path)
+        .header("If-Match", "*")
         .json(&ingredient_to_add)
         .send()
         .await
@@ -144,7 +146,7 @@ async fn updating_a_nonexistent_ingredient_in_a_recipe_errors() {
 #[tokio::test]
 async fn updating_an_ingredient_in_a_nonexistent_recipe_errors() {
     let app = TestApp::new().await;
-    let client = Client::new();
+    let client = app.authenticated_client("testuser").await;
     let ingredient_create_path = app.get_base("ingredient");
 
     let ingredient: IngredientDTO = client
@@ -167,6 +169,7 @@ async fn updating_an_ingredient_in_a_nonexistent_recipe_errors() {
 
     let result = client
         .put(&ingredient_update_path)
+        .header("If-Match", "*")
         .json(&ingredient_to_add)
         .send()
         .await
@@ -1,5 +1,10 @@
 mod create;
 mod delete;
 mod get_by_id;
+mod import;
 mod ingredients;
+mod nutrition;
+mod revisions;
+mod search;
+mod shopping_list;
 mod update;
@@ -0,0 +1,116 @@
+use common::{ingredients::IngredientDTO, IngredientNutritionDTO, RecipeDTO, RecipeNutritionDTO};
+use pretty_assertions::assert_eq;
+use reqwest::StatusCode;
+
+use crate::{
+    fixtures::{ingredient::ingredient_fixture, recipe::recipe_fixture},
+    setup::TestApp,
+};
+
+#[tokio::test]
+async fn recipe_nutrition_is_computed_from_its_ingredients() {
+    let app = TestApp::new().await;
+    let client = app.authenticated_client("testuser").await;
+
+    let ingredient: IngredientDTO = client
+        .post(app.get_base("ingredient"))
+        .json(&ingredient_fixture())
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let result = client
+        .put(app.get_base(&format!("ingredient/{}/nutrition", ingredient.id)))
+        .json(&serde_json::json!({
+            "per_100g": {
+                "energy_kcal": 250.0,
+                "protein": 26.0,
+                "fat": 15.0,
+                "carbohydrates": 0.0,
+                "fiber": 0.0,
+                "sugar": 0.0,
+                "salt": 0.2,
+            },
+        }))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(result.status(), StatusCode::OK);
+
+    let nutrition: IngredientNutritionDTO = client
+        .get(app.get_base(&format!("ingredient/{}/nutrition", ingredient.id)))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    assert_eq!(nutrition.per_100g.energy_kcal, 250.0);
+    assert_eq!(nutrition.density, None);
+
+    let recipe: RecipeDTO = client
+        .post(app.get_base("recipe"))
+        .json(&recipe_fixture(std::slice::from_ref(&ingredient)))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let result = client
+        .get(app.get_base(&format!("recipe/{}/nutrition", recipe.id)))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(result.status(), StatusCode::OK);
+
+    // The recipe uses 100 g for a single serving
+    let nutrition: RecipeNutritionDTO = result.json().await.unwrap();
+
+    assert_eq!(nutrition.total.energy_kcal, 250.0);
+    assert_eq!(nutrition.per_serving.protein, 26.0);
+    assert!(nutrition.unconverted.is_empty());
+    assert!(nutrition.missing.is_empty());
+}
+
+#[tokio::test]
+async fn negative_nutrition_facts_are_rejected() {
+    let app = TestApp::new().await;
+    let client = app.authenticated_client("testuser").await;
+
+    let ingredient: IngredientDTO = client
+        .post(app.get_base("ingredient"))
+        .json(&ingredient_fixture())
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let result = client
+        .put(app.get_base(&format!("ingredient/{}/nutrition", ingredient.id)))
+        .json(&serde_json::json!({
+            "per_100g": {
+                "energy_kcal": -1.0,
+                "protein": 0.0,
+                "fat": 0.0,
+                "carbohydrates": 0.0,
+                "fiber": 0.0,
+                "sugar": 0.0,
+                "salt": 0.0,
+            },
+        }))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(result.status(), StatusCode::BAD_REQUEST);
+}
@@ -0,0 +1,144 @@
+use common::{ingredients::IngredientDTO, RecipeDTO, RecipeDiffDTO, RecipeRevisionDTO};
+use futures::future::join_all;
+use pretty_assertions::assert_eq;
+use reqwest::StatusCode;
+
+use crate::{
+    fixtures::{ingredient::ingredient_fixture, recipe::recipe_fixture},
+    setup::TestApp,
+};
+
+#[tokio::test]
+async fn changes_are_recorded_and_can_be_reverted() {
+    let app = TestApp::new().await;
+    let client = app.authenticated_client("testuser").await;
+    let ingredient_create_path = app.get_base("ingredient");
+    let recipe_create_path = app.get_base("recipe");
+
+    let ingredients_input = [ingredient_fixture()];
+
+    let ingredients: Vec<IngredientDTO> =
+        join_all(ingredients_input.iter().map(|ingredient| async {
+            client
+                .post(&ingredient_create_path)
+                .json(&ingredient.clone())
+                .send()
+                .await
+                .unwrap()
+                .json()
+                .await
+                .unwrap()
+        }))
+        .await;
+
+    let data = recipe_fixture(&ingredients);
+
+    let recipe: RecipeDTO = client
+        .post(&recipe_create_path)
+        .json(&data)
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let result = client
+        .put(app.get_base(&format!("recipe/{}", recipe.id)))
+        .header("If-Match", "*")
+        .json(&serde_json::json!({ "name": "WE UPDATED THIS THING" }))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(result.status(), StatusCode::OK);
+
+    let revisions: Vec<RecipeRevisionDTO> = client
+        .get(app.get_base(&format!("recipe/{}/revisions", recipe.id)))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    assert_eq!(
+        revisions.iter().map(|r| r.number).collect::<Vec<_>>(),
+        vec![1, 2]
+    );
+
+    let diff: RecipeDiffDTO = client
+        .get(app.get_base(&format!("recipe/{}/revisions/diff?from=1&to=2", recipe.id)))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let name = diff.name.unwrap();
+    assert_eq!(name.old, recipe.name);
+    assert_eq!(name.new, "WE UPDATED THIS THING");
+
+    let result = client
+        .post(app.get_base(&format!("recipe/{}/revisions/1/revert", recipe.id)))
+        .header("If-Match", "*")
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(result.status(), StatusCode::OK);
+
+    let result: RecipeDTO = result.json().await.unwrap();
+
+    assert_eq!(result.name, recipe.name);
+
+    let result = client
+        .get(app.get_base(&format!("recipe/{}/revisions/3", recipe.id)))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(result.status(), StatusCode::OK);
+
+    let result: serde_json::Value = result.json().await.unwrap();
+
+    assert_eq!(
+        result["change"],
+        serde_json::json!({ "kind": "reverted", "revision": 1 })
+    );
+    assert_eq!(result["recipe"]["name"], recipe.name);
+}
+
+#[tokio::test]
+async fn getting_a_nonexistent_revision_fails() {
+    let app = TestApp::new().await;
+    let client = app.authenticated_client("testuser").await;
+    let ingredient: IngredientDTO = client
+        .post(app.get_base("ingredient"))
+        .json(&ingredient_fixture())
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let recipe: RecipeDTO = client
+        .post(app.get_base("recipe"))
+        .json(&recipe_fixture(&[ingredient]))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let result = client
+        .get(app.get_base(&format!("recipe/{}/revisions/2", recipe.id)))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(result.status(), StatusCode::NOT_FOUND);
+}
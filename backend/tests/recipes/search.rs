@@ -0,0 +1,134 @@
+use common::{ingredients::IngredientDTO, RecipeDTO};
+use pretty_assertions::assert_eq;
+use reqwest::StatusCode;
+
+use crate::{
+    fixtures::{ingredient::ingredient_fixture, recipe::recipe_fixture},
+    setup::TestApp,
+};
+
+#[tokio::test]
+async fn searching_recipes_works() {
+    let app = TestApp::new().await;
+    let client = app.authenticated_client("testuser").await;
+
+    let ingredient: IngredientDTO = client
+        .post(app.get_base("ingredient"))
+        .json(&ingredient_fixture())
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let created: RecipeDTO = client
+        .post(app.get_base("recipe"))
+        .json(&recipe_fixture(std::slice::from_ref(&ingredient)))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let result: Vec<RecipeDTO> = client
+        .get(app.get_base("recipe/search"))
+        .query(&[
+            ("q", "cucumber"),
+            ("ingredients", &ingredient.id.to_string()),
+        ])
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    assert_eq!(result.len(), 1);
+    assert_eq!(result[0].id, created.id);
+
+    let result: Vec<RecipeDTO> = client
+        .get(app.get_base("recipe/search"))
+        .query(&[("exclude_ingredients", ingredient.id.to_string())])
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    assert!(result.is_empty());
+}
+
+#[tokio::test]
+async fn searching_by_allergens_works() {
+    let app = TestApp::new().await;
+    let client = app.authenticated_client("testuser").await;
+
+    let mut body = ingredient_fixture();
+    body["allergens"] = serde_json::json!(["celery"]);
+    let ingredient: IngredientDTO = client
+        .post(app.get_base("ingredient"))
+        .json(&body)
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    assert_eq!(ingredient.allergens, vec!["celery".to_string()]);
+
+    let created: RecipeDTO = client
+        .post(app.get_base("recipe"))
+        .json(&recipe_fixture(std::slice::from_ref(&ingredient)))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    assert_eq!(created.allergens, vec!["celery".to_string()]);
+
+    let result: Vec<RecipeDTO> = client
+        .get(app.get_base("recipe/search"))
+        .query(&[("free_from", "celery,mustard")])
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    assert!(result.is_empty());
+
+    let result: Vec<RecipeDTO> = client
+        .get(app.get_base("recipe/search"))
+        .query(&[("free_from", "mustard")])
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    assert_eq!(result.len(), 1);
+    assert_eq!(result[0].id, created.id);
+}
+
+#[tokio::test]
+async fn searching_with_invalid_parameters_fails() {
+    let app = TestApp::new().await;
+    let client = app.authenticated_client("testuser").await;
+
+    let result = client
+        .get(app.get_base("recipe/search"))
+        .query(&[("diets", "carnivore")])
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(result.status(), StatusCode::BAD_REQUEST);
+}
@@ -0,0 +1,83 @@
+use common::{ingredients::IngredientDTO, RecipeDTO, ShoppingListDTO};
+use pretty_assertions::assert_eq;
+use reqwest::{Client, StatusCode};
+use uuid::Uuid;
+
+use crate::{
+    fixtures::{ingredient::ingredient_fixture, recipe::recipe_fixture},
+    setup::TestApp,
+};
+
+#[tokio::test]
+async fn making_a_shopping_list_works() {
+    let app = TestApp::new().await;
+    let client = app.authenticated_client("testuser").await;
+
+    let ingredient: IngredientDTO = client
+        .post(app.get_base("ingredient"))
+        .json(&ingredient_fixture())
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let recipe: RecipeDTO = client
+        .post(app.get_base("recipe"))
+        .json(&recipe_fixture(std::slice::from_ref(&ingredient)))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    // The fixture serves 1, with 100 g of the ingredient
+    let result = client
+        .post(app.get_base("shopping-list"))
+        .json(&serde_json::json!({
+            "recipes": [
+                { "recipe_id": recipe.id, "servings": 3 },
+                { "recipe_id": recipe.id },
+            ]
+        }))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(result.status(), StatusCode::OK);
+
+    let result: ShoppingListDTO = result.json().await.unwrap();
+
+    assert_eq!(result.items.len(), 1);
+    assert_eq!(result.items[0].ingredient.id, ingredient.id);
+    assert!(result.optional.is_empty());
+    assert_eq!(result.text, format!("- {}: 400 g", ingredient.name));
+}
+
+#[tokio::test]
+async fn making_a_shopping_list_of_nonexistent_recipes_errors() {
+    let app = TestApp::new().await;
+    let client = Client::new();
+
+    let result = client
+        .post(app.get_base("shopping-list"))
+        .json(&serde_json::json!({
+            "recipes": [{ "recipe_id": Uuid::nil() }]
+        }))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(result.status(), StatusCode::NOT_FOUND);
+
+    let result = client
+        .post(app.get_base("shopping-list"))
+        .json(&serde_json::json!({ "recipes": [] }))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(result.status(), StatusCode::BAD_REQUEST);
+}
@@ -2,7 +2,7 @@ use assert_json_diff::assert_json_include;
 use common::{ingredients::IngredientDTO, RecipeDTO};
 use futures::future::join_all;
 use pretty_assertions::assert_eq;
-use reqwest::{Client, StatusCode};
+use reqwest::StatusCode;
 use uuid::Uuid;
 
 use crate::{
@@ -13,7 +13,7 @@ use crate::{
 #[tokio::test]
 async fn updates_recipe_correctly() {
     let app = TestApp::new().await;
-    let client = Client::new();
+    let client = app.authenticated_client("testuser").await;
     let ingredient_create_path = app.get_base("ingredient");
     let recipe_create_path = app.get_base("recipe");
 
@@ -62,6 +62,7 @@ async fn updates_recipe_correctly() {
 
     let result = client
         .put(&recipe_update_path)
+        .header("If-Match", "*")
         .json(&changeset)
         .send()
         .await
@@ -97,7 +98,7 @@ async fn updates_recipe_correctly() {
 #[tokio::test]
 async fn updating_nonexistent_recipe_errors() {
     let app = TestApp::new().await;
-    let client = Client::new();
+    let client = app.authenticated_client("testuser").await;
     let changeset = serde_json::json!({
         "name": "WE NEED THIS TO FAIL",
     });
@@ -106,6 +107,7 @@ async fn updating_nonexistent_recipe_errors() {
 
     let result = client
         .put(&recipe_update_path)
+        .header("If-Match", "*")
         .json(&changeset)
         .send()
         .await
@@ -117,7 +119,7 @@ async fn updating_nonexistent_recipe_errors() {
 #[tokio::test]
 async fn updating_empty_changeset_errors() {
     let app = TestApp::new().await;
-    let client = Client::new();
+    let client = app.authenticated_client("testuser").await;
     let ingredient_create_path = app.get_base("ingredient");
     let recipe_create_path = app.get_base("recipe");
 
@@ -155,6 +157,7 @@ async fn updating_empty_changeset_errors() {
 
     let result = client
         .put(&recipe_update_path)
+        .header("If-Match", "*")
         .json(&changeset)
         .send()
         .await
@@ -162,3 +165,131 @@ async fn updating_empty_changeset_errors() {
 
     assert_eq!(result.status(), StatusCode::BAD_REQUEST);
 }
+
+#[tokio::test]
+async fn updating_requires_the_current_version() {
+    let app = TestApp::new().await;
+    let client = app.authenticated_client("testuser").await;
+    let ingredient: IngredientDTO = client
+        .post(app.get_base("ingredient"))
+        .json(&ingredient_fixture())
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let recipe: RecipeDTO = client
+        .post(app.get_base("recipe"))
+        .json(&recipe_fixture(&[ingredient]))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let recipe_path = app.get_base(&format!("recipe/{}", recipe.id));
+    let changeset = serde_json::json!({ "name": "WE UPDATED THIS THING" });
+
+    let result = client
+        .put(&recipe_path)
+        .json(&changeset)
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(result.status(), StatusCode::PRECONDITION_REQUIRED);
+
+    let etag = client
+        .get(&recipe_path)
+        .send()
+        .await
+        .unwrap()
+        .headers()
+        .get("ETag")
+        .unwrap()
+        .to_str()
+        .unwrap()
+        .to_string();
+
+    let result = client
+        .put(&recipe_path)
+        .header("If-Match", &etag)
+        .json(&changeset)
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(result.status(), StatusCode::OK);
+    assert_ne!(result.headers().get("ETag").unwrap(), etag.as_str());
+
+    let result = client
+        .put(&recipe_path)
+        .header("If-Match", &etag)
+        .json(&serde_json::json!({ "name": "SOMEONE ELSE'S CHANGE" }))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(result.status(), StatusCode::PRECONDITION_FAILED);
+}
+
+#[tokio::test]
+async fn weak_etags_do_not_match() {
+    let app = TestApp::new().await;
+    let client = app.authenticated_client("testuser").await;
+    let ingredient: IngredientDTO = client
+        .post(app.get_base("ingredient"))
+        .json(&ingredient_fixture())
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let recipe: RecipeDTO = client
+        .post(app.get_base("recipe"))
+        .json(&recipe_fixture(&[ingredient]))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let recipe_path = app.get_base(&format!("recipe/{}", recipe.id));
+    let etag = client
+        .get(&recipe_path)
+        .send()
+        .await
+        .unwrap()
+        .headers()
+        .get("ETag")
+        .unwrap()
+        .to_str()
+        .unwrap()
+        .to_string();
+
+    let result = client
+        .put(&recipe_path)
+        .header("If-Match", format!("W/{etag}"))
+        .json(&serde_json::json!({ "name": "WE UPDATED THIS THING" }))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(result.status(), StatusCode::PRECONDITION_FAILED);
+
+    let result = client
+        .put(&recipe_path)
+        .header("If-Match", format!("\"outdated\", {etag}"))
+        .json(&serde_json::json!({ "name": "WE UPDATED THIS THING" }))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(result.status(), StatusCode::OK);
+}
@@ -1,9 +1,11 @@
 use std::{net::SocketAddr, time::Duration};
 
-use backend::api::AppBuilder;
+use backend::{api::AppBuilder, configuration::MessagingSettings};
+use common::user::CreateNewUserDTO;
+use reqwest::Client;
 use sqlx::{pool::PoolOptions, postgres::PgConnectOptions, PgPool, Postgres};
 use testcontainers::{runners::AsyncRunner, ContainerAsync};
-use testcontainers_modules::kafka::Kafka as KafkaContainer;
+use testcontainers_modules::kafka::{Kafka as KafkaContainer, KAFKA_PORT};
 use testcontainers_modules::postgres::Postgres as PostgresContainer;
 use tokio::net::TcpListener;
 
@@ -50,7 +52,11 @@ impl TestApp {
 
         let app = AppBuilder::new()
             .with_postgres_database(db.clone())
-            .with_kafka(&kafka_node.get_host().await.to_string())
+            .with_messaging(MessagingSettings::with_brokers(&format!(
+                "{}:{}",
+                kafka_node.get_host().await,
+                kafka_node.get_host_port_ipv4(KAFKA_PORT).await
+            )))
             .build()
             .unwrap();
 
@@ -69,4 +75,24 @@ impl TestApp {
     pub fn get_base(&self, rest: &str) -> String {
         format!("http://{}/{}", self.addr, rest)
     }
+
+    /// Signs up a new user and returns a client that carries their session cookie.
+    pub async fn authenticated_client(&self, username: &str) -> Client {
+        let client = Client::builder().cookie_store(true).build().unwrap();
+
+        client
+            .post(self.get_base("user/signup"))
+            .json(&CreateNewUserDTO {
+                username: username.to_string(),
+                email: format!("{username}@example.com"),
+                password: "meaningless".to_string(),
+            })
+            .send()
+            .await
+            .unwrap()
+            .error_for_status()
+            .unwrap();
+
+        client
+    }
 }
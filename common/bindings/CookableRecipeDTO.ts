@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { IngredientWithAmountDTO } from "./IngredientWithAmountDTO";
+import type { RecipeDTO } from "./RecipeDTO";
+
+export interface CookableRecipeDTO { recipe: RecipeDTO, coverage: number, missing: Array<IngredientWithAmountDTO>, }
\ No newline at end of file
@@ -1,3 +1,3 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 
-export interface CreateIngredientDTO { name: string, description: string, diet_violations?: Array<string>, }
\ No newline at end of file
+export interface CreateIngredientDTO { name: string, description: string, diet_violations?: Array<string>, parent_id?: string, allergens?: Array<string>, }
\ No newline at end of file
@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { PlannedMealDTO } from "./PlannedMealDTO";
+
+export interface CreateMealPlanDTO { starts_on: string, meals: Array<PlannedMealDTO>, }
\ No newline at end of file
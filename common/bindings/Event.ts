@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ChangeDTO } from "./ChangeDTO";
+import type { IngredientDTO } from "./IngredientDTO";
+import type { RecipeDTO } from "./RecipeDTO";
+import type { RecipeIngredientChangeDTO } from "./RecipeIngredientChangeDTO";
+import type { RecipeIngredientDTO } from "./RecipeIngredientDTO";
+
+export type Event = { "type": "ingredient.added", "payload": IngredientDTO } | { "type": "ingredient.updated", "payload": ChangeDTO<IngredientDTO> } | { "type": "ingredient.deleted", "payload": IngredientDTO } | { "type": "recipe.created", "payload": RecipeDTO } | { "type": "recipe.updated", "payload": ChangeDTO<RecipeDTO> } | { "type": "recipe.deleted", "payload": RecipeDTO } | { "type": "recipe.ingredient_added", "payload": RecipeIngredientDTO } | { "type": "recipe.ingredient_removed", "payload": RecipeIngredientDTO } | { "type": "recipe.ingredient_amount_changed", "payload": RecipeIngredientChangeDTO };
\ No newline at end of file
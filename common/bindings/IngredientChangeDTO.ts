@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { SnapshotIngredientDTO } from "./SnapshotIngredientDTO";
+
+export interface IngredientChangeDTO { old: SnapshotIngredientDTO, new: SnapshotIngredientDTO, }
\ No newline at end of file
@@ -1,3 +1,4 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { IngredientSubstituteDTO } from "./IngredientSubstituteDTO";
 
-export interface IngredientDTO { id: string, name: string, description: string, diet_violations: Array<string>, }
\ No newline at end of file
+export interface IngredientDTO { id: string, name: string, description: string, diet_violations: Array<string>, allergens: Array<string>, parent_id: string | null, substitutes?: Array<IngredientSubstituteDTO>, }
\ No newline at end of file
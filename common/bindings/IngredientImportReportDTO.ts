@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { IngredientImportRowDTO } from "./IngredientImportRowDTO";
+
+export interface IngredientImportReportDTO { created: number, conflicts: number, invalid: number, rows: Array<IngredientImportRowDTO>, }
\ No newline at end of file
@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { IngredientDTO } from "./IngredientDTO";
+
+export interface IngredientImportRowDTO { status: string, ingredient?: IngredientDTO, message?: string, }
\ No newline at end of file
@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { NutritionFactsDTO } from "./NutritionFactsDTO";
+
+export interface IngredientNutritionDTO { per_100g: NutritionFactsDTO, density: number | null, }
\ No newline at end of file
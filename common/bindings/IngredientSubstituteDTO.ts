@@ -0,0 +1,3 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export interface IngredientSubstituteDTO { substitute_id: string, name: string, diet_violations: Array<string>, ratio: number, note: string | null, }
\ No newline at end of file
@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { IngredientSubstituteDTO } from "./IngredientSubstituteDTO";
+
+export interface IngredientTreeDTO { id: string, name: string, description: string, diet_violations: Array<string>, allergens: Array<string>, parent_id: string | null, substitutes?: Array<IngredientSubstituteDTO>, variants: Array<IngredientTreeDTO>, }
\ No newline at end of file
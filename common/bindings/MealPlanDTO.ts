@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { PlannedMealDTO } from "./PlannedMealDTO";
+
+export interface MealPlanDTO { id: string, owner_id: string, starts_on: string, ends_on: string, meals: Array<PlannedMealDTO>, created_at: string, updated_at: string, }
\ No newline at end of file
@@ -0,0 +1,3 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export interface NutritionFactsDTO { energy_kcal: number, protein: number, fat: number, carbohydrates: number, fiber: number, sugar: number, salt: number, }
\ No newline at end of file
@@ -0,0 +1,3 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export interface PagedDTO<T> { items: Array<T>, next_cursor: string | null, }
\ No newline at end of file
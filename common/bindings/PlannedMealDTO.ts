@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { MealSlotDTO } from "./MealSlotDTO";
+
+export interface PlannedMealDTO { date: string, slot: MealSlotDTO, recipe_id: string, servings: number, }
\ No newline at end of file
@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { IngredientChangeDTO } from "./IngredientChangeDTO";
+import type { ServingsChangeDTO } from "./ServingsChangeDTO";
+import type { SnapshotIngredientDTO } from "./SnapshotIngredientDTO";
+import type { StepsChangeDTO } from "./StepsChangeDTO";
+import type { TextChangeDTO } from "./TextChangeDTO";
+import type { TimeChangeDTO } from "./TimeChangeDTO";
+
+export interface RecipeDiffDTO { from: number, to: number, name: TextChangeDTO | null, description: TextChangeDTO | null, steps: StepsChangeDTO | null, time: TimeChangeDTO | null, servings: ServingsChangeDTO | null, ingredients_added: Array<SnapshotIngredientDTO>, ingredients_removed: Array<SnapshotIngredientDTO>, ingredients_changed: Array<IngredientChangeDTO>, }
\ No newline at end of file
@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { IngredientWithAmountDTO } from "./IngredientWithAmountDTO";
+
+export interface RecipeIngredientChangeDTO { recipe_id: string, old: IngredientWithAmountDTO, new: IngredientWithAmountDTO, }
\ No newline at end of file
@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { IngredientWithAmountDTO } from "./IngredientWithAmountDTO";
+
+export interface RecipeIngredientDTO { recipe_id: string, ingredient: IngredientWithAmountDTO, }
\ No newline at end of file
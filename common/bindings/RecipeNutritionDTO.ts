@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { IngredientDTO } from "./IngredientDTO";
+import type { IngredientWithAmountDTO } from "./IngredientWithAmountDTO";
+import type { NutritionFactsDTO } from "./NutritionFactsDTO";
+
+export interface RecipeNutritionDTO { total: NutritionFactsDTO, per_serving: NutritionFactsDTO, unconverted: Array<IngredientWithAmountDTO>, missing: Array<IngredientDTO>, }
\ No newline at end of file
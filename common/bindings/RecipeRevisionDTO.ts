@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { RevisionChangeDTO } from "./RevisionChangeDTO";
+
+export interface RecipeRevisionDTO { number: number, author_id: string | null, created_at: string, change: RevisionChangeDTO, }
\ No newline at end of file
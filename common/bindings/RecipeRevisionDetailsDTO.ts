@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { RecipeSnapshotDTO } from "./RecipeSnapshotDTO";
+import type { RevisionChangeDTO } from "./RevisionChangeDTO";
+
+export interface RecipeRevisionDetailsDTO { number: number, author_id: string | null, created_at: string, change: RevisionChangeDTO, recipe: RecipeSnapshotDTO, }
\ No newline at end of file
@@ -0,0 +1,3 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export interface RecipeSearchParamsDTO { q: string | null, ingredients: string | null, exclude_ingredients: string | null, diets: string | null, free_from: string | null, max_time: bigint | null, limit: number | null, }
\ No newline at end of file
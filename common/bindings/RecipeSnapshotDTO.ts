@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ServingsTypeDTO } from "./ServingsTypeDTO";
+import type { SnapshotIngredientDTO } from "./SnapshotIngredientDTO";
+
+export interface RecipeSnapshotDTO { name: string, description: string, steps: Array<string>, time: Record<string, number>, servings: ServingsTypeDTO, ingredients: Array<SnapshotIngredientDTO>, }
\ No newline at end of file
@@ -0,0 +1,3 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type RevisionChangeDTO = { "kind": "created" } | { "kind": "updated", fields: Array<string>, } | { "kind": "ingredient_added", ingredient_id: string, } | { "kind": "ingredient_removed", ingredient_id: string, } | { "kind": "ingredient_amount_changed", ingredient_id: string, } | { "kind": "reverted", revision: number, };
\ No newline at end of file
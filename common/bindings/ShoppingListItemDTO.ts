@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { IngredientDTO } from "./IngredientDTO";
+import type { IngredientUnitDTO } from "./IngredientUnitDTO";
+
+export interface ShoppingListItemDTO { ingredient: IngredientDTO, amounts: Array<IngredientUnitDTO>, }
\ No newline at end of file
@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { IngredientWithAmountDTO } from "./IngredientWithAmountDTO";
+import type { SuggestedSubstituteDTO } from "./SuggestedSubstituteDTO";
+
+export interface SubstitutionSuggestionDTO { ingredient: IngredientWithAmountDTO, substitutes: Array<SuggestedSubstituteDTO>, }
\ No newline at end of file
@@ -5,7 +5,7 @@ use ts_rs::TS;
 use utoipa::ToSchema;
 
 #[derive(Serialize, Deserialize, ToSchema, TS, Debug)]
-#[aliases(ErrorMessageWithJsonValue = ErrorMessage<Value>)]
+#[aliases(ErrorMessageWithJsonValue = ErrorMessage<Value>, ErrorMessageWithString = ErrorMessage<String>)]
 #[ts(export)]
 pub struct ErrorMessage<T: Serialize> {
     pub kind: String,
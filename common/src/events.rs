@@ -0,0 +1,252 @@
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
+use schemars::{
+    gen::SchemaGenerator,
+    schema::{RootSchema, Schema, SchemaObject},
+    JsonSchema,
+};
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use uuid::Uuid;
+
+use crate::{IngredientDTO, IngredientWithAmountDTO, RecipeDTO};
+
+/// Every message published on the message bus is wrapped in an envelope.
+/// The same event can be delivered more than once, consumers can use `event_id` to skip the ones they've already seen.
+/// ts-rs can't flatten enums, so there are no TypeScript bindings for this one, only [Event].
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema)]
+pub struct EventEnvelope {
+    pub event_id: Uuid,
+    /// Bumped whenever the payload of the event type changes in a way that isn't backwards compatible
+    pub schema_version: u32,
+    pub occurred_at: DateTime<Utc>,
+    /// W3C trace context (`traceparent` and `tracestate`) of the request that caused the event
+    pub trace_context: BTreeMap<String, String>,
+    #[serde(flatten)]
+    pub event: Event,
+}
+
+impl EventEnvelope {
+    pub fn event_type(&self) -> EventType {
+        self.event.event_type()
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema, TS)]
+#[serde(tag = "type", content = "payload")]
+#[ts(export)]
+pub enum Event {
+    #[serde(rename = "ingredient.added")]
+    IngredientAdded(IngredientDTO),
+    #[serde(rename = "ingredient.updated")]
+    IngredientUpdated(ChangeDTO<IngredientDTO>),
+    #[serde(rename = "ingredient.deleted")]
+    IngredientDeleted(IngredientDTO),
+    #[serde(rename = "recipe.created")]
+    RecipeCreated(RecipeDTO),
+    #[serde(rename = "recipe.updated")]
+    RecipeUpdated(ChangeDTO<RecipeDTO>),
+    #[serde(rename = "recipe.deleted")]
+    RecipeDeleted(RecipeDTO),
+    #[serde(rename = "recipe.ingredient_added")]
+    RecipeIngredientAdded(RecipeIngredientDTO),
+    #[serde(rename = "recipe.ingredient_removed")]
+    RecipeIngredientRemoved(RecipeIngredientDTO),
+    #[serde(rename = "recipe.ingredient_amount_changed")]
+    RecipeIngredientAmountChanged(RecipeIngredientChangeDTO),
+}
+
+impl Event {
+    pub fn event_type(&self) -> EventType {
+        match self {
+            Self::IngredientAdded(_) => EventType::IngredientAdded,
+            Self::IngredientUpdated(_) => EventType::IngredientUpdated,
+            Self::IngredientDeleted(_) => EventType::IngredientDeleted,
+            Self::RecipeCreated(_) => EventType::RecipeCreated,
+            Self::RecipeUpdated(_) => EventType::RecipeUpdated,
+            Self::RecipeDeleted(_) => EventType::RecipeDeleted,
+            Self::RecipeIngredientAdded(_) => EventType::RecipeIngredientAdded,
+            Self::RecipeIngredientRemoved(_) => EventType::RecipeIngredientRemoved,
+            Self::RecipeIngredientAmountChanged(_) => EventType::RecipeIngredientAmountChanged,
+        }
+    }
+}
+
+/// The state of something before and after a change
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema, TS)]
+#[ts(export)]
+pub struct ChangeDTO<T: TS> {
+    pub old: T,
+    pub new: T,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema, TS)]
+#[ts(export)]
+pub struct RecipeIngredientDTO {
+    pub recipe_id: Uuid,
+    pub ingredient: IngredientWithAmountDTO,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema, TS)]
+#[ts(export)]
+pub struct RecipeIngredientChangeDTO {
+    pub recipe_id: Uuid,
+    pub old: IngredientWithAmountDTO,
+    pub new: IngredientWithAmountDTO,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum EventType {
+    IngredientAdded,
+    IngredientUpdated,
+    IngredientDeleted,
+    RecipeCreated,
+    RecipeUpdated,
+    RecipeDeleted,
+    RecipeIngredientAdded,
+    RecipeIngredientRemoved,
+    RecipeIngredientAmountChanged,
+}
+
+impl EventType {
+    pub const ALL: [EventType; 9] = [
+        Self::IngredientAdded,
+        Self::IngredientUpdated,
+        Self::IngredientDeleted,
+        Self::RecipeCreated,
+        Self::RecipeUpdated,
+        Self::RecipeDeleted,
+        Self::RecipeIngredientAdded,
+        Self::RecipeIngredientRemoved,
+        Self::RecipeIngredientAmountChanged,
+    ];
+
+    /// The value of the `type` field of the envelope
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::IngredientAdded => "ingredient.added",
+            Self::IngredientUpdated => "ingredient.updated",
+            Self::IngredientDeleted => "ingredient.deleted",
+            Self::RecipeCreated => "recipe.created",
+            Self::RecipeUpdated => "recipe.updated",
+            Self::RecipeDeleted => "recipe.deleted",
+            Self::RecipeIngredientAdded => "recipe.ingredient_added",
+            Self::RecipeIngredientRemoved => "recipe.ingredient_removed",
+            Self::RecipeIngredientAmountChanged => "recipe.ingredient_amount_changed",
+        }
+    }
+
+    /// The topic the events of this type get published to
+    pub fn topic(&self) -> &'static str {
+        match self {
+            Self::IngredientAdded => "deepdish.ingredient.added",
+            Self::IngredientUpdated => "deepdish.ingredient.updated",
+            Self::IngredientDeleted => "deepdish.ingredient.deleted",
+            Self::RecipeCreated => "deepdish.recipe.created",
+            Self::RecipeUpdated => "deepdish.recipe.updated",
+            Self::RecipeDeleted => "deepdish.recipe.deleted",
+            Self::RecipeIngredientAdded => "deepdish.recipe.ingredient_added",
+            Self::RecipeIngredientRemoved => "deepdish.recipe.ingredient_removed",
+            Self::RecipeIngredientAmountChanged => "deepdish.recipe.ingredient_amount_changed",
+        }
+    }
+
+    pub fn from_topic(topic: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|t| t.topic() == topic)
+    }
+
+    pub fn schema_version(&self) -> u32 {
+        1
+    }
+
+    /// JSON Schema of the whole envelope of an event of this type
+    pub fn json_schema(&self) -> RootSchema {
+        match self {
+            Self::IngredientAdded | Self::IngredientDeleted => {
+                single_event_schema::<IngredientDTO>(*self)
+            }
+            Self::IngredientUpdated => single_event_schema::<ChangeDTO<IngredientDTO>>(*self),
+            Self::RecipeCreated | Self::RecipeDeleted => single_event_schema::<RecipeDTO>(*self),
+            Self::RecipeUpdated => single_event_schema::<ChangeDTO<RecipeDTO>>(*self),
+            Self::RecipeIngredientAdded | Self::RecipeIngredientRemoved => {
+                single_event_schema::<RecipeIngredientDTO>(*self)
+            }
+            Self::RecipeIngredientAmountChanged => {
+                single_event_schema::<RecipeIngredientChangeDTO>(*self)
+            }
+        }
+    }
+}
+
+// [EventEnvelope] narrowed down to a single payload type, only used to generate the schemas.
+// Not a doc comment, since it would end up in the description of every schema.
+#[derive(JsonSchema)]
+#[allow(dead_code)]
+struct SingleEventEnvelope<P> {
+    event_id: Uuid,
+    schema_version: u32,
+    occurred_at: DateTime<Utc>,
+    trace_context: BTreeMap<String, String>,
+    #[schemars(rename = "type")]
+    event_type: String,
+    payload: P,
+}
+
+fn single_event_schema<P: JsonSchema>(event_type: EventType) -> RootSchema {
+    let mut schema = SchemaGenerator::default().into_root_schema_for::<SingleEventEnvelope<P>>();
+    let metadata = schema.schema.metadata();
+    metadata.title = Some(event_type.name().to_string());
+    metadata.description = Some(format!(
+        "Envelope of the {} event, published to {}",
+        event_type.name(),
+        event_type.topic()
+    ));
+
+    let constant = |value: serde_json::Value| {
+        Schema::Object(SchemaObject {
+            const_value: Some(value),
+            ..Default::default()
+        })
+    };
+    let properties = &mut schema.schema.object().properties;
+    properties.insert("type".to_string(), constant(event_type.name().into()));
+    properties.insert(
+        "schema_version".to_string(),
+        constant(event_type.schema_version().into()),
+    );
+
+    schema
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, path::Path};
+
+    use super::EventType;
+
+    /// Writes the schemas next to the TypeScript bindings, so consumers can pick them up.
+    /// Fails when they differ from the ones which were there before, so that changes to the events don't go unnoticed.
+    #[test]
+    fn export_event_schemas() {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("schemas/events");
+        fs::create_dir_all(&dir).unwrap();
+
+        let mut outdated = vec![];
+
+        for event_type in EventType::ALL {
+            let path = dir.join(format!("{}.json", event_type.name()));
+            let schema = serde_json::to_string_pretty(&event_type.json_schema()).unwrap() + "\n";
+
+            if fs::read_to_string(&path).ok().as_ref() != Some(&schema) {
+                fs::write(&path, schema).unwrap();
+                outdated.push(path.display().to_string());
+            }
+        }
+
+        assert!(
+            outdated.is_empty(),
+            "The event schemas were outdated and have been regenerated, commit them: {outdated:?}"
+        );
+    }
+}
@@ -1,6 +1,7 @@
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use ts_rs::TS;
-use utoipa::ToSchema;
+use utoipa::{IntoParams, ToSchema};
 use uuid::Uuid;
 
 #[derive(Serialize, Deserialize, Debug, ToSchema, TS)]
@@ -10,15 +11,58 @@ pub struct CreateIngredientDTO {
     pub description: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub diet_violations: Option<Vec<String>>,
+    /// The ingredient this one is a variant of
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<Uuid>,
+    /// Any of the 14 major allergens, i.e. `milk` or `cereals_with_gluten`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allergens: Option<Vec<String>>,
 }
 
-#[derive(Serialize, Deserialize, Debug, ToSchema, TS, PartialEq, Eq, Clone)]
+#[derive(Serialize, Deserialize, Debug, ToSchema, TS, PartialEq, Clone, JsonSchema)]
 #[ts(export)]
 pub struct IngredientDTO {
     pub id: Uuid,
     pub name: String,
     pub description: String,
     pub diet_violations: Vec<String>,
+    #[serde(default)]
+    pub allergens: Vec<String>,
+    #[serde(default)]
+    pub parent_id: Option<Uuid>,
+    /// Only filled in when looking at a single ingredient
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub substitutes: Option<Vec<IngredientSubstituteDTO>>,
+}
+
+#[derive(Serialize, Deserialize, Debug, ToSchema, TS, PartialEq, Clone, JsonSchema)]
+#[ts(export)]
+pub struct IngredientSubstituteDTO {
+    pub substitute_id: Uuid,
+    pub name: String,
+    pub diet_violations: Vec<String>,
+    /// How much of the substitute replaces one unit of the original ingredient
+    pub ratio: f64,
+    pub note: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, ToSchema, TS)]
+#[ts(export)]
+pub struct CreateIngredientSubstituteDTO {
+    pub substitute_id: Uuid,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ratio: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, ToSchema, TS)]
+#[ts(export)]
+pub struct UpdateIngredientSubstituteDTO {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ratio: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, Debug, ToSchema, TS)]
@@ -39,4 +83,53 @@ pub struct UpdateIngredientDTO {
     pub description: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub diet_violations: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<Uuid>,
+    /// Makes the ingredient stand on its own again, takes precedence over `parent_id`
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub detach_parent: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allergens: Option<Vec<String>>,
+}
+
+/// An ingredient together with all of its variants, nested as deep as they go.
+#[derive(Serialize, Deserialize, Debug, ToSchema, TS, PartialEq, Clone)]
+#[ts(export)]
+pub struct IngredientTreeDTO {
+    #[serde(flatten)]
+    pub ingredient: IngredientDTO,
+    pub variants: Vec<IngredientTreeDTO>,
+}
+
+/// What happened to a single ingredient of a bulk import.
+#[derive(Serialize, Deserialize, Debug, ToSchema, TS, PartialEq, Clone)]
+#[ts(export)]
+pub struct IngredientImportRowDTO {
+    /// Either `created`, `conflict` or `invalid`
+    pub status: String,
+    /// The ingredient, if it was created
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ingredient: Option<IngredientDTO>,
+    /// Why the ingredient wasn't created
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+/// The outcome of a bulk import, with a row for every ingredient in the order they were given.
+#[derive(Serialize, Deserialize, Debug, ToSchema, TS, PartialEq, Clone)]
+#[ts(export)]
+pub struct IngredientImportReportDTO {
+    pub created: usize,
+    pub conflicts: usize,
+    pub invalid: usize,
+    pub rows: Vec<IngredientImportRowDTO>,
+}
+
+/// Query parameters for exporting the ingredients.
+#[derive(Deserialize, Serialize, IntoParams, TS, Debug)]
+#[into_params(parameter_in = Query)]
+#[ts(export)]
+pub struct ExportIngredientsParamsDTO {
+    /// Either `csv` or `json`
+    pub format: String,
 }
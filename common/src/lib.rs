@@ -1,7 +1,16 @@
 pub mod error;
+pub mod events;
 pub mod ingredients;
+pub mod meal_plans;
+pub mod nutrition;
+pub mod pagination;
+pub mod pantry;
 pub mod recipes;
 pub mod user;
 
 pub use ingredients::*;
+pub use meal_plans::*;
+pub use nutrition::*;
+pub use pagination::*;
+pub use pantry::*;
 pub use recipes::*;
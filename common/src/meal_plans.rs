@@ -0,0 +1,58 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(PartialEq, Debug, Clone, Copy, Serialize, Deserialize, ToSchema, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export)]
+pub enum MealSlotDTO {
+    Breakfast,
+    Lunch,
+    Dinner,
+    Snack,
+}
+
+#[derive(Deserialize, Serialize, ToSchema, TS, Debug, Clone)]
+#[ts(export)]
+pub struct PlannedMealDTO {
+    #[schema(value_type = String, format = Date)]
+    pub date: NaiveDate,
+    pub slot: MealSlotDTO,
+    pub recipe_id: Uuid,
+    pub servings: u16,
+}
+
+/// A week of meals, starting on the given day.
+#[derive(Deserialize, Serialize, ToSchema, TS, Debug)]
+#[ts(export)]
+pub struct CreateMealPlanDTO {
+    #[schema(value_type = String, format = Date)]
+    pub starts_on: NaiveDate,
+    pub meals: Vec<PlannedMealDTO>,
+}
+
+/// The meals replace all of the meals of the plan.
+#[derive(Deserialize, Serialize, ToSchema, TS, Debug)]
+#[ts(export)]
+pub struct UpdateMealPlanDTO {
+    #[schema(value_type = Option<String>, format = Date)]
+    pub starts_on: Option<NaiveDate>,
+    pub meals: Option<Vec<PlannedMealDTO>>,
+}
+
+#[derive(Deserialize, Serialize, ToSchema, TS, Debug)]
+#[ts(export)]
+pub struct MealPlanDTO {
+    pub id: Uuid,
+    pub owner_id: Uuid,
+    #[schema(value_type = String, format = Date)]
+    pub starts_on: NaiveDate,
+    /// The last day of the plan, a week after it starts
+    #[schema(value_type = String, format = Date)]
+    pub ends_on: NaiveDate,
+    pub meals: Vec<PlannedMealDTO>,
+    pub created_at: String,
+    pub updated_at: String,
+}
@@ -0,0 +1,38 @@
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use utoipa::ToSchema;
+
+use crate::{IngredientDTO, IngredientWithAmountDTO};
+
+/// Energy in kilocalories, everything else in grams.
+#[derive(Serialize, Deserialize, Debug, ToSchema, TS, PartialEq, Clone, Copy)]
+#[ts(export)]
+pub struct NutritionFactsDTO {
+    pub energy_kcal: f64,
+    pub protein: f64,
+    pub fat: f64,
+    pub carbohydrates: f64,
+    pub fiber: f64,
+    pub sugar: f64,
+    pub salt: f64,
+}
+
+#[derive(Serialize, Deserialize, Debug, ToSchema, TS, PartialEq, Clone)]
+#[ts(export)]
+pub struct IngredientNutritionDTO {
+    pub per_100g: NutritionFactsDTO,
+    /// Grams per mililiter, needed to count amounts measured by volume
+    #[serde(default)]
+    pub density: Option<f64>,
+}
+
+#[derive(Serialize, Deserialize, Debug, ToSchema, TS)]
+#[ts(export)]
+pub struct RecipeNutritionDTO {
+    pub total: NutritionFactsDTO,
+    pub per_serving: NutritionFactsDTO,
+    /// Ingredients whose amount could not be weighed, e.g. heads of broccoli, left out of the totals
+    pub unconverted: Vec<IngredientWithAmountDTO>,
+    /// Ingredients without any nutrition facts, left out of the totals
+    pub missing: Vec<IngredientDTO>,
+}
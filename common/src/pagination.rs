@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::{IngredientDTO, RecipeDTO};
+
+/// Query parameters for paginated listings.
+/// `cursor` is the `next_cursor` returned with the previous page.
+/// The endpoints document their parameters themselves, since each of them sorts by different fields.
+#[derive(Serialize, Deserialize, Debug, Default, TS)]
+#[ts(export)]
+pub struct PageParamsDTO {
+    pub limit: Option<u32>,
+    pub cursor: Option<Uuid>,
+    pub sort: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, ToSchema, TS)]
+#[aliases(
+    PagedIngredientsDTO = PagedDTO<IngredientDTO>,
+    PagedRecipesDTO = PagedDTO<RecipeDTO>
+)]
+#[ts(export)]
+pub struct PagedDTO<T: Serialize> {
+    pub items: Vec<T>,
+    /// Missing when there are no more pages
+    pub next_cursor: Option<Uuid>,
+}
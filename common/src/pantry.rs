@@ -0,0 +1,35 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::{IngredientDTO, IngredientUnitDTO};
+
+#[derive(Deserialize, Serialize, ToSchema, TS, Debug)]
+#[ts(export)]
+pub struct PantryItemDTO {
+    pub ingredient: IngredientDTO,
+    pub amount: IngredientUnitDTO,
+    /// Expired items aren't used when looking for recipes to cook
+    #[schema(value_type = Option<String>, format = Date)]
+    pub expires_on: Option<NaiveDate>,
+}
+
+#[derive(Deserialize, Serialize, ToSchema, TS, Debug)]
+#[ts(export)]
+pub struct AddPantryItemDTO {
+    pub ingredient_id: Uuid,
+    pub amount: IngredientUnitDTO,
+    #[schema(value_type = Option<String>, format = Date)]
+    pub expires_on: Option<NaiveDate>,
+}
+
+/// Replaces both the amount and the expiry date of the item.
+#[derive(Deserialize, Serialize, ToSchema, TS, Debug)]
+#[ts(export)]
+pub struct UpdatePantryItemDTO {
+    pub amount: IngredientUnitDTO,
+    #[schema(value_type = Option<String>, format = Date)]
+    pub expires_on: Option<NaiveDate>,
+}
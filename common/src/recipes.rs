@@ -1,12 +1,14 @@
 use std::collections::BTreeMap;
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use ts_rs::TS;
+use utoipa::{IntoParams, ToSchema};
 use uuid::Uuid;
 
-use crate::IngredientDTO;
+use crate::{IngredientDTO, IngredientSubstituteDTO};
 
-#[derive(Deserialize, Serialize, Debug, TS)]
+#[derive(Deserialize, Serialize, Debug, ToSchema, TS)]
 #[ts(export)]
 pub struct CreateRecipeDTO {
     pub name: String,
@@ -18,7 +20,7 @@ pub struct CreateRecipeDTO {
     pub servings: ServingsTypeDTO,
 }
 
-#[derive(Deserialize, Serialize, TS, Debug)]
+#[derive(Deserialize, Serialize, ToSchema, TS, Debug, Clone, PartialEq, JsonSchema)]
 #[ts(export)]
 pub struct RecipeDTO {
     pub id: String,
@@ -29,12 +31,15 @@ pub struct RecipeDTO {
     pub time: BTreeMap<String, u64>,
     pub ingredients: Vec<IngredientWithAmountDTO>,
     pub servings: ServingsTypeDTO,
+    pub owner_id: Option<Uuid>,
     pub created_at: String,
     pub updated_at: String,
     pub diet_violations: Vec<String>,
+    #[serde(default)]
+    pub allergens: Vec<String>,
 }
 
-#[derive(PartialEq, Debug, Clone, Serialize, Deserialize, TS)]
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize, ToSchema, TS, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 #[ts(export)]
 pub enum ServingsTypeDTO {
@@ -42,7 +47,7 @@ pub enum ServingsTypeDTO {
     Exact(u16),
 }
 
-#[derive(Deserialize, Serialize, TS, Debug)]
+#[derive(Deserialize, Serialize, ToSchema, TS, Debug)]
 #[ts(export)]
 pub struct IngredientAmountDTO {
     pub ingredient_id: Uuid,
@@ -51,7 +56,7 @@ pub struct IngredientAmountDTO {
     pub notes: Option<String>,
 }
 
-#[derive(Deserialize, Serialize, TS, Debug)]
+#[derive(Deserialize, Serialize, ToSchema, TS, Debug, Clone, PartialEq, JsonSchema)]
 #[ts(export)]
 pub struct IngredientWithAmountDTO {
     pub ingredient: IngredientDTO,
@@ -60,7 +65,7 @@ pub struct IngredientWithAmountDTO {
     pub notes: Option<String>,
 }
 
-#[derive(PartialEq, Debug, Clone, Serialize, Deserialize, TS)]
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize, ToSchema, TS, JsonSchema)]
 #[serde(tag = "_type", content = "amount", rename_all = "snake_case")]
 #[ts(export)]
 pub enum IngredientUnitDTO {
@@ -71,7 +76,7 @@ pub enum IngredientUnitDTO {
     Other { amount: f64, unit: String },
 }
 
-#[derive(Deserialize, Serialize, TS, Debug)]
+#[derive(Deserialize, Serialize, ToSchema, TS, Debug)]
 #[ts(export)]
 pub struct UpdateRecipeDTO {
     pub name: Option<String>,
@@ -81,3 +86,255 @@ pub struct UpdateRecipeDTO {
     pub time: Option<BTreeMap<String, u64>>,
     pub servings: Option<ServingsTypeDTO>,
 }
+
+/// Query parameters for searching recipes.
+/// Lists are passed as comma-separated values, e.g. `?ingredients=<id>,<id>&diets=vegan`.
+#[derive(Deserialize, Serialize, IntoParams, TS, Debug, Default)]
+#[into_params(parameter_in = Query)]
+#[ts(export)]
+pub struct RecipeSearchParamsDTO {
+    pub q: Option<String>,
+    pub ingredients: Option<String>,
+    pub exclude_ingredients: Option<String>,
+    pub diets: Option<String>,
+    /// Allergens none of the ingredients may contain, e.g. `milk,peanuts`
+    pub free_from: Option<String>,
+    /// Maximum total time in seconds
+    pub max_time: Option<u64>,
+    /// How many of the most relevant recipes to return, 20 by default and 100 at most
+    pub limit: Option<u32>,
+}
+
+/// Query parameters for viewing a single recipe.
+#[derive(Deserialize, Serialize, IntoParams, TS, Debug, Default)]
+#[into_params(parameter_in = Query)]
+#[ts(export)]
+pub struct RecipeViewParamsDTO {
+    /// Either `metric` or `us`, keeps the original units when missing
+    pub units: Option<String>,
+}
+
+/// Query parameters for scaling a recipe to a different amount of servings.
+#[derive(Deserialize, Serialize, IntoParams, TS, Debug)]
+#[into_params(parameter_in = Query)]
+#[ts(export)]
+pub struct ScaleRecipeParamsDTO {
+    pub servings: u16,
+}
+
+/// Query parameters for exporting a recipe.
+#[derive(Deserialize, Serialize, IntoParams, TS, Debug)]
+#[into_params(parameter_in = Query)]
+#[ts(export)]
+pub struct ExportRecipeParamsDTO {
+    /// Either `jsonld`, `markdown` or `cooklang`
+    pub format: String,
+}
+
+/// Query parameters for suggesting substitutions in a recipe.
+/// Diets are passed as comma-separated values, e.g. `?diets=vegan,gluten_free`.
+#[derive(Deserialize, Serialize, IntoParams, TS, Debug, Default)]
+#[into_params(parameter_in = Query)]
+#[ts(export)]
+pub struct SubstitutionParamsDTO {
+    pub diets: String,
+}
+
+/// A recipe imported from another format, along with the ingredient lines which couldn't be added to it.
+#[derive(Deserialize, Serialize, ToSchema, TS, Debug)]
+#[ts(export)]
+pub struct ImportedRecipeDTO {
+    pub recipe: RecipeDTO,
+    pub unparsed_ingredients: Vec<String>,
+}
+
+/// An ingredient of a recipe which violates at least one of the requested diets,
+/// together with the substitutes that don't.
+#[derive(Deserialize, Serialize, ToSchema, TS, Debug)]
+#[ts(export)]
+pub struct SubstitutionSuggestionDTO {
+    pub ingredient: IngredientWithAmountDTO,
+    pub substitutes: Vec<SuggestedSubstituteDTO>,
+}
+
+#[derive(Deserialize, Serialize, ToSchema, TS, Debug)]
+#[ts(export)]
+pub struct SuggestedSubstituteDTO {
+    pub substitute: IngredientSubstituteDTO,
+    /// The original amount adjusted by the ratio of the substitute
+    pub amount: IngredientUnitDTO,
+}
+
+/// What was done to a recipe to get to a revision.
+#[derive(Deserialize, Serialize, ToSchema, TS, Debug, Clone, PartialEq)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+#[ts(export)]
+pub enum RevisionChangeDTO {
+    Created,
+    /// Fields of the recipe itself were changed
+    Updated {
+        fields: Vec<String>,
+    },
+    IngredientAdded {
+        ingredient_id: Uuid,
+    },
+    IngredientRemoved {
+        ingredient_id: Uuid,
+    },
+    IngredientAmountChanged {
+        ingredient_id: Uuid,
+    },
+    /// The recipe was brought back to how it was in an earlier revision
+    Reverted {
+        revision: i32,
+    },
+}
+
+#[derive(Deserialize, Serialize, ToSchema, TS, Debug)]
+#[ts(export)]
+pub struct RecipeRevisionDTO {
+    pub number: i32,
+    /// The user who made the change
+    pub author_id: Option<Uuid>,
+    pub created_at: String,
+    pub change: RevisionChangeDTO,
+}
+
+/// The contents of a recipe at some point in time.
+#[derive(Deserialize, Serialize, ToSchema, TS, Debug)]
+#[ts(export)]
+pub struct RecipeSnapshotDTO {
+    pub name: String,
+    pub description: String,
+    pub steps: Vec<String>,
+    #[ts(type = "Record<string, number>")]
+    pub time: BTreeMap<String, u64>,
+    pub servings: ServingsTypeDTO,
+    pub ingredients: Vec<SnapshotIngredientDTO>,
+}
+
+/// An ingredient of a recipe, with the name it had at the time.
+#[derive(Deserialize, Serialize, ToSchema, TS, Debug)]
+#[ts(export)]
+pub struct SnapshotIngredientDTO {
+    pub ingredient_id: Uuid,
+    pub name: String,
+    pub amount: IngredientUnitDTO,
+    pub notes: Option<String>,
+    pub optional: bool,
+}
+
+/// A revision along with how the recipe looked after it.
+#[derive(Deserialize, Serialize, ToSchema, TS, Debug)]
+#[ts(export)]
+pub struct RecipeRevisionDetailsDTO {
+    #[serde(flatten)]
+    pub revision: RecipeRevisionDTO,
+    pub recipe: RecipeSnapshotDTO,
+}
+
+#[derive(Deserialize, Serialize, ToSchema, TS, Debug)]
+#[ts(export)]
+pub struct TextChangeDTO {
+    pub old: String,
+    pub new: String,
+}
+
+#[derive(Deserialize, Serialize, ToSchema, TS, Debug)]
+#[ts(export)]
+pub struct StepsChangeDTO {
+    pub old: Vec<String>,
+    pub new: Vec<String>,
+}
+
+#[derive(Deserialize, Serialize, ToSchema, TS, Debug)]
+#[ts(export)]
+pub struct TimeChangeDTO {
+    #[ts(type = "Record<string, number>")]
+    pub old: BTreeMap<String, u64>,
+    #[ts(type = "Record<string, number>")]
+    pub new: BTreeMap<String, u64>,
+}
+
+#[derive(Deserialize, Serialize, ToSchema, TS, Debug)]
+#[ts(export)]
+pub struct ServingsChangeDTO {
+    pub old: ServingsTypeDTO,
+    pub new: ServingsTypeDTO,
+}
+
+#[derive(Deserialize, Serialize, ToSchema, TS, Debug)]
+#[ts(export)]
+pub struct IngredientChangeDTO {
+    pub old: SnapshotIngredientDTO,
+    pub new: SnapshotIngredientDTO,
+}
+
+/// How a recipe differs between two of its revisions. Fields which are the same in both are `null`.
+#[derive(Deserialize, Serialize, ToSchema, TS, Debug)]
+#[ts(export)]
+pub struct RecipeDiffDTO {
+    pub from: i32,
+    pub to: i32,
+    pub name: Option<TextChangeDTO>,
+    pub description: Option<TextChangeDTO>,
+    pub steps: Option<StepsChangeDTO>,
+    pub time: Option<TimeChangeDTO>,
+    pub servings: Option<ServingsChangeDTO>,
+    pub ingredients_added: Vec<SnapshotIngredientDTO>,
+    pub ingredients_removed: Vec<SnapshotIngredientDTO>,
+    /// Ingredients in both revisions, but with a different amount, notes, or whether they're optional
+    pub ingredients_changed: Vec<IngredientChangeDTO>,
+}
+
+/// Query parameters for comparing two revisions of a recipe.
+#[derive(Deserialize, Serialize, IntoParams, TS, Debug)]
+#[into_params(parameter_in = Query)]
+#[ts(export)]
+pub struct DiffRevisionsParamsDTO {
+    pub from: i32,
+    pub to: i32,
+}
+
+/// A recipe to shop for. The servings of the recipe itself are used when they're left out.
+#[derive(Deserialize, Serialize, ToSchema, TS, Debug)]
+#[ts(export)]
+pub struct ShoppingListRecipeDTO {
+    pub recipe_id: Uuid,
+    pub servings: Option<u16>,
+}
+
+#[derive(Deserialize, Serialize, ToSchema, TS, Debug)]
+#[ts(export)]
+pub struct CreateShoppingListDTO {
+    pub recipes: Vec<ShoppingListRecipeDTO>,
+}
+
+/// An ingredient to buy. Amounts in units which can't be added up with each other are listed separately.
+#[derive(Deserialize, Serialize, ToSchema, TS, Debug)]
+#[ts(export)]
+pub struct ShoppingListItemDTO {
+    pub ingredient: IngredientDTO,
+    pub amounts: Vec<IngredientUnitDTO>,
+}
+
+/// The ingredients needed for a couple of recipes, along with the list written down as plain text.
+#[derive(Deserialize, Serialize, ToSchema, TS, Debug)]
+#[ts(export)]
+pub struct ShoppingListDTO {
+    pub items: Vec<ShoppingListItemDTO>,
+    /// Ingredients which the recipes can do without
+    pub optional: Vec<ShoppingListItemDTO>,
+    pub text: String,
+}
+
+/// A recipe along with how much of it can be made from what's in the pantry.
+#[derive(Deserialize, Serialize, ToSchema, TS, Debug)]
+#[ts(export)]
+pub struct CookableRecipeDTO {
+    pub recipe: RecipeDTO,
+    /// The share of the required ingredients the pantry has enough of, from 0 to 1
+    pub coverage: f64,
+    /// Required ingredients to get, with the amount that is still missing
+    pub missing: Vec<IngredientWithAmountDTO>,
+}